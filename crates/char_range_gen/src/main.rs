@@ -1,8 +1,28 @@
 #![allow(clippy::type_complexity)]
 
+mod ucd;
+
 use std::convert::TryFrom;
 
 fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        None => print_char_fn_ranges(),
+        Some("ucd") => match args.get(2) {
+            Some(ucd_dir) => ucd::print_property_tables(std::path::Path::new(ucd_dir)),
+            None => {
+                eprintln!("Usage: char_range_gen ucd <UCD directory>");
+                std::process::exit(1);
+            }
+        },
+        Some(arg) => {
+            eprintln!("Unknown argument {:?}", arg);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn print_char_fn_ranges() {
     for (f, name) in FNS.iter() {
         let ranges = generate_char_fn_ranges(*f);
         println!("pub static {}: [(u32, u32); {}] = [", name, ranges.len());
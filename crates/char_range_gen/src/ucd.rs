@@ -0,0 +1,227 @@
+//! Generates Unicode general category, script, block, and age tables for `$$p{...}` regexes, grapheme cluster
+//! break tables for `match_unit = grapheme;`, and canonical decomposition tables for
+//! `normalization_insensitive = true;`, from the Unicode Character Database:
+//!
+//! ```text
+//! cargo run -p char_range_gen -- ucd <UCD directory> > crates/lexgen/src/unicode_tables.rs
+//! ```
+//!
+//! The directory should have `UnicodeData.txt`, `Scripts.txt`, `Blocks.txt`, `DerivedAge.txt`,
+//! `extracted/DerivedGeneralCategory.txt`, `auxiliary/GraphemeBreakProperty.txt`, and
+//! `emoji/emoji-data.txt`, as in
+//! https://www.unicode.org/Public/UCD/latest/ucd/.
+//!
+//! Only the general categories with two-letter names are generated. lexgen computes the groups
+//! (e.g. `L` for all letters) from these.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub fn print_property_tables(ucd_dir: &Path) {
+    let (version, categories) =
+        read_property_file(&ucd_dir.join("extracted").join("DerivedGeneralCategory.txt"));
+    let (scripts_version, scripts) = read_property_file(&ucd_dir.join("Scripts.txt"));
+    let (age_version, ages) = read_property_file(&ucd_dir.join("DerivedAge.txt"));
+    let (grapheme_version, grapheme_cluster_breaks) =
+        read_property_file(&ucd_dir.join("auxiliary").join("GraphemeBreakProperty.txt"));
+    assert!(
+        version.is_some()
+            && version == scripts_version
+            && version == age_version
+            && version == grapheme_version,
+        "UCD files are from different Unicode versions"
+    );
+    let version = version.unwrap();
+
+    // emoji-data.txt doesn't have the version in the file name
+    let (_, mut emoji_properties) =
+        read_property_file(&ucd_dir.join("emoji").join("emoji-data.txt"));
+    emoji_properties.retain(|property, _| property == "Extended_Pictographic");
+
+    println!(
+        "// Generated by char_range_gen from the Unicode Character Database {}, do not edit.",
+        version
+    );
+
+    print_tables("GC", "GENERAL_CATEGORIES", &categories);
+    print_tables("SC", "SCRIPTS", &scripts);
+
+    // Blocks are not checked for the version: they rarely change, and only by adding new blocks
+    let (blocks_version, blocks) = read_property_file(&ucd_dir.join("Blocks.txt"));
+    println!();
+    println!(
+        "// Blocks are from the Unicode Character Database {}",
+        blocks_version.as_deref().unwrap_or("(unknown version)")
+    );
+    print_tables("BLK", "BLOCKS", &blocks);
+
+    print_tables("AGE", "AGES", &ages);
+    print_tables("GCB", "GRAPHEME_CLUSTER_BREAKS", &grapheme_cluster_breaks);
+    print_tables("EMOJI", "EMOJI_PROPERTIES", &emoji_properties);
+
+    print_normalization_tables(&ucd_dir.join("UnicodeData.txt"));
+}
+
+/// Print the canonical combining classes (non-zero ones, as ranges) and the canonical
+/// decompositions (one level, as in `UnicodeData.txt`) of characters
+fn print_normalization_tables(path: &Path) {
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Unable to read {:?}: {}", path, err));
+
+    let mut combining_classes: Vec<(u32, u32, u8)> = vec![];
+    let mut decompositions: Vec<(u32, Vec<u32>)> = vec![];
+
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.split(';').collect();
+        if fields.len() < 6 {
+            continue;
+        }
+
+        let code_point = parse_code_point(fields[0]);
+
+        let combining_class: u8 = fields[3]
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid combining class in line {:?}", line));
+        if combining_class != 0 {
+            match combining_classes.last_mut() {
+                Some((_, end, class)) if *end + 1 == code_point && *class == combining_class => {
+                    *end = code_point
+                }
+                _ => combining_classes.push((code_point, code_point, combining_class)),
+            }
+        }
+
+        // Compatibility decompositions start with a tag, e.g. `<compat>`
+        let decomposition = fields[5];
+        if !decomposition.is_empty() && !decomposition.starts_with('<') {
+            decompositions.push((
+                code_point,
+                decomposition.split(' ').map(parse_code_point).collect(),
+            ));
+        }
+    }
+
+    println!();
+    println!(
+        "pub static COMBINING_CLASSES: [(u32, u32, u8); {}] = [",
+        combining_classes.len()
+    );
+    for (start, end, class) in &combining_classes {
+        println!("    ({}, {}, {}),", start, end, class);
+    }
+    println!("];");
+
+    println!();
+    println!(
+        "pub static CANONICAL_DECOMPOSITIONS: [(u32, &[u32]); {}] = [",
+        decompositions.len()
+    );
+    for (code_point, decomposition) in &decompositions {
+        println!("    ({}, &{:?}),", code_point, decomposition);
+    }
+    println!("];");
+}
+
+/// Read a UCD file with `<code points> ; <value>` lines. Returns the Unicode version of the file
+/// when the first line has it, and the ranges of code points for each value. Ranges are sorted, merged when adjacent, and
+/// exclude surrogates as they're not valid `char`s.
+fn read_property_file(path: &Path) -> (Option<String>, BTreeMap<String, Vec<(u32, u32)>>) {
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Unable to read {:?}: {}", path, err));
+
+    // First line is the file name with the version, e.g. `# Scripts-16.0.0.txt`
+    let version = contents
+        .lines()
+        .next()
+        .and_then(|line| line.strip_suffix(".txt"))
+        .and_then(|line| line.rsplit_once('-'))
+        .map(|(_, version)| version.to_owned());
+
+    let mut values: BTreeMap<String, Vec<(u32, u32)>> = BTreeMap::new();
+
+    for line in contents.lines() {
+        let line = match line.split_once('#') {
+            Some((line, _comment)) => line,
+            None => line,
+        };
+
+        let (code_points, value) = match line.split_once(';') {
+            Some((code_points, value)) => (code_points.trim(), value.trim()),
+            None => continue,
+        };
+
+        let (start, end) = match code_points.split_once("..") {
+            Some((start, end)) => (parse_code_point(start), parse_code_point(end)),
+            None => {
+                let code_point = parse_code_point(code_points);
+                (code_point, code_point)
+            }
+        };
+
+        let ranges = values.entry(value.to_owned()).or_default();
+        // Skip surrogates
+        for (start, end) in [(start, end.min(0xD7FF)), (start.max(0xE000), end)] {
+            if start <= end {
+                ranges.push((start, end));
+            }
+        }
+    }
+
+    for ranges in values.values_mut() {
+        ranges.sort_unstable();
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges.drain(..) {
+            match merged.last_mut() {
+                Some((_, last_end)) if *last_end + 1 == start => *last_end = end,
+                _ => merged.push((start, end)),
+            }
+        }
+        *ranges = merged;
+    }
+
+    values.retain(|_, ranges| !ranges.is_empty());
+
+    (version, values)
+}
+
+fn parse_code_point(str: &str) -> u32 {
+    u32::from_str_radix(str, 16).unwrap_or_else(|_| panic!("Invalid code point {:?}", str))
+}
+
+/// Print a table for each value, and a table of the value names (sorted) to their tables
+fn print_tables(prefix: &str, name: &str, values: &BTreeMap<String, Vec<(u32, u32)>>) {
+    // Block names have spaces and dashes (`Latin-1 Supplement`), ages have dots (`12.0`)
+    let table_name = |value: &str| {
+        format!(
+            "{}_{}",
+            prefix,
+            value
+                .to_uppercase()
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        )
+    };
+
+    for (value, ranges) in values {
+        println!();
+        println!(
+            "pub static {}: [(u32, u32); {}] = [",
+            table_name(value),
+            ranges.len()
+        );
+        for (start, end) in ranges {
+            println!("    ({}, {}),", start, end);
+        }
+        println!("];");
+    }
+
+    println!();
+    println!(
+        "pub static {}: [(&str, &[(u32, u32)]); {}] = [",
+        name,
+        values.len()
+    );
+    for value in values.keys() {
+        println!("    ({:?}, &{}),", value, table_name(value));
+    }
+    println!("];");
+}
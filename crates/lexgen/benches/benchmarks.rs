@@ -1,3 +1,6 @@
+// Tests of the included file are not run here, so the helpers they use are unused
+#![allow(dead_code)]
+
 // Hacky, but this is the only way I could find to share the lexer in both tests and benchmarks
 include!("../tests/lua_5_1.rs");
 
@@ -5,9 +8,9 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 #[inline(never)]
 fn lex_lua(s: &str) {
-    let mut lexer = Lexer::new(s);
-    while let Some(next) = lexer.next() {
-        next;
+    let lexer = Lexer::new(s);
+    for next in lexer {
+        drop(next);
     }
 }
 
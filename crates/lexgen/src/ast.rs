@@ -1,7 +1,11 @@
 //! Proc macro AST definition and parser implementations
 
+use crate::builtin::{BUILTIN_RANGES, PERL_CLASSES};
+use crate::collections::Map;
 use crate::semantic_action_table::{SemanticActionIdx, SemanticActionTable};
+use crate::unicode_properties;
 
+use quote::ToTokens;
 use syn::parse::ParseStream;
 
 use std::fmt;
@@ -13,16 +17,21 @@ pub struct Var(pub String);
 pub struct Builtin(pub String);
 
 pub struct Lexer {
-    pub public: bool,
+    /// Attributes before the first line, e.g. `#[derive(Clone, Debug)]`, added to the lexer struct
+    pub attrs: Vec<syn::Attribute>,
+    pub visibility: syn::Visibility,
     pub type_name: syn::Ident,
+    /// Type and const parameters of the lexer, e.g. `<N: Num>` in `Lexer<N: Num> -> Token<N>;`
+    pub generics: syn::Generics,
     pub user_state_type: Option<syn::Type>,
     pub token_type: syn::Type,
     pub rules: Vec<Rule>,
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum Rule {
     /// `let <ident> = <regex>;`
-    Binding { var: Var, re: RegexCtx },
+    Binding { var: syn::Ident, re: RegexCtx },
 
     /// `type Error = UserError;`
     ErrorType {
@@ -30,19 +39,354 @@ pub enum Rule {
         ty: syn::Type,
     },
 
+    /// `type Feedback = TypeNames;`
+    FeedbackType {
+        /// Type on the RHS
+        ty: syn::Type,
+    },
+
+    /// `type Interner = Symbols;`
+    InternerType {
+        /// Type on the RHS
+        ty: syn::Type,
+    },
+
+    /// `<name> = <value>;`, e.g. `driver = state_fns;`
+    Option {
+        name: syn::Ident,
+        option: LexerOption,
+    },
+
     /// A list of named rules at the top level: `rule <Ident> { <rules> },`
     RuleSet {
         name: syn::Ident,
         rules: Vec<SingleRule>,
+        /// Whether the rule set is declared with the `exclusive` modifier, which disables rules
+        /// of the `skip` option in the rule set
+        exclusive: bool,
+        /// Whether the rule set is declared with the `nested` modifier
+        nested: bool,
     },
 
     /// Set of rules without a name
-    UnnamedRules { rules: Vec<SingleRule> },
+    UnnamedRules {
+        /// Span of the first rule, used in error messages
+        span: proc_macro2::Span,
+        rules: Vec<SingleRule>,
+    },
+
+    /// A rule added to multiple rule sets: `<<Ident>, ...> <rule>`. The rule is added to the end
+    /// of the rule sets, after the rules defined in the rule sets.
+    SharedRule {
+        rule_sets: Vec<syn::Ident>,
+        rule: SingleRule,
+    },
+
+    /// Inline tests of the lexer: `tests { "<input>" => [<token>, ...], "<input>" => error@<n>, }`
+    Tests { cases: Vec<TestCase> },
+}
+
+/// A test case in a `tests { ... }` section
+pub struct TestCase {
+    /// Input of the lexer
+    pub input: syn::LitStr,
+    pub expected: TestExpectation,
+}
+
+pub enum TestExpectation {
+    /// `[<token>, ...]`: the lexer returns these tokens, without errors
+    Tokens(Vec<syn::Expr>),
+
+    /// `error@<n>`: the lexer fails with an error at byte index `n`, after returning any tokens
+    /// before the error
+    Error(syn::LitInt),
+}
+
+/// Options that control code generation, declared with `<name> = <value>;` syntax at the top
+/// level
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum LexerOption {
+    /// `driver = match_loop;` or `driver = state_fns;`
+    Driver(DriverStyle),
+
+    /// `const_fn = true;`
+    ConstFn(bool),
+
+    /// `encoding = ascii;` or `encoding = unicode;`
+    Encoding(Encoding),
+
+    /// `layout_profile = "<path>";`
+    LayoutProfile(syn::LitStr),
+
+    /// `ambiguity_warnings = true;`
+    AmbiguityWarnings(bool),
+
+    /// `statistics = true;`
+    Statistics(bool),
+
+    /// `snapshot = "<path>";`
+    Snapshot(String),
+
+    /// `init_coverage = ascii;` or `init_coverage = unicode;`
+    InitCoverage(Encoding),
+
+    /// `case_insensitive = true;`
+    CaseInsensitive(bool),
+
+    /// `match_unit = char;` or `match_unit = grapheme;`
+    MatchUnit(MatchUnit),
+
+    /// `normalization_insensitive = true;`
+    NormalizationInsensitive(bool),
+
+    /// `invalid_utf8 = error;`, `invalid_utf8 = replace;`, or `invalid_utf8 = rule;`
+    InvalidUtf8(InvalidUtf8),
+
+    /// `line_terminators = lf;` or `line_terminators = unicode;`
+    LineTerminators(LineTerminators),
+
+    /// `perl_classes = ascii;` or `perl_classes = unicode;`
+    PerlClasses(Encoding),
+
+    /// `range_checks = auto;`, `range_checks = if_chain;`, `range_checks = binary_search;`, or
+    /// `range_checks = table;`
+    RangeChecks(RangeChecks),
+
+    /// `tie_breaking = order;`, `tie_breaking = priority;`, or `tie_breaking = error;`
+    TieBreaking(TieBreaking),
+
+    /// `lalrpop = true;`
+    Lalrpop(bool),
+
+    /// `dfa_table = true;`
+    DfaTable(bool),
+
+    /// `grammar_json = true;`
+    GrammarJson(bool),
+
+    /// `tag_rule_sets = true;`
+    TagRuleSets(bool),
+
+    /// `next_token = true;`
+    NextToken(bool),
+
+    /// `token_kind = true;`
+    TokenKind(bool),
+
+    /// `spanned_tokens = true;`
+    SpannedTokens(bool),
+
+    /// `cancellation = true;`
+    Cancellation(bool),
+
+    /// `counters = true;`
+    Counters(bool),
+
+    /// `indentation = { newline: <expr>, indent: <expr>, dedent: <expr>, whitespace: <...> };`
+    Indentation(Indentation),
+
+    /// `on_error = <path>;`
+    OnError(syn::Path),
+
+    /// `rule_enum_name = <name>;`, `error_type_name = <name>;`, ...
+    TypeName(GeneratedType, syn::Ident),
+
+    /// `skip = [<regex>, ...];`. `action` is the semantic action of the skip rules, which is
+    /// `RuleRhs::None`.
+    Skip {
+        regexes: Vec<RegexCtx>,
+        action: SemanticActionIdx,
+    },
+}
+
+/// Types generated for a lexer. Named after the lexer by default, renamed with the
+/// `<type>_name = <name>;` options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedType {
+    /// Rule set enum, `<lexer name>Rule`, renamed with `rule_enum_name`
+    RuleEnum,
+
+    /// Rule group enum, `<lexer name>Group`, renamed with `group_enum_name`
+    GroupEnum,
+
+    /// Token kind enum, `<lexer name>TokenKind`, renamed with `token_kind_name`
+    TokenKind,
+
+    /// LALRPOP item type alias, `<lexer name>Spanned`, renamed with `spanned_type_name`
+    Spanned,
+
+    /// LALRPOP error type alias, `<lexer name>ParseError`, renamed with `error_type_name`
+    ParseError,
+}
+
+impl GeneratedType {
+    fn from_option_name(name: &str) -> Option<GeneratedType> {
+        match name {
+            "rule_enum_name" => Some(GeneratedType::RuleEnum),
+            "group_enum_name" => Some(GeneratedType::GroupEnum),
+            "token_kind_name" => Some(GeneratedType::TokenKind),
+            "spanned_type_name" => Some(GeneratedType::Spanned),
+            "error_type_name" => Some(GeneratedType::ParseError),
+            _ => None,
+        }
+    }
+
+    /// Name of the type when it's not renamed, added to the lexer name
+    pub fn default_suffix(self) -> &'static str {
+        match self {
+            GeneratedType::RuleEnum => "Rule",
+            GeneratedType::GroupEnum => "Group",
+            GeneratedType::TokenKind => "TokenKind",
+            GeneratedType::Spanned => "Spanned",
+            GeneratedType::ParseError => "ParseError",
+        }
+    }
+}
+
+/// How the generated `next` method drives the state machine
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DriverStyle {
+    /// A single `loop { match state { ... } }` with code for all states in the `match` arms
+    #[default]
+    MatchLoop,
+
+    /// A method for each state, called from a `loop { match state { ... } }`
+    StateFns,
+}
+
+/// Alphabet of the lexer
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Any Unicode scalar value
+    #[default]
+    Unicode,
+
+    /// Only ASCII characters (0 to 127). Other characters in the input are lexing errors.
+    Ascii,
+}
+
+/// What `_` matches
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MatchUnit {
+    /// A Unicode scalar value (a `char`)
+    #[default]
+    Char,
+
+    /// An extended grapheme cluster. Only available with the `unicode` feature.
+    Grapheme,
+}
+
+/// How lexers created with `new_from_bytes` handle invalid UTF-8
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidUtf8 {
+    /// Raise an error with the location of the invalid bytes
+    Error,
+
+    /// Replace invalid bytes with U+FFFD REPLACEMENT CHARACTER
+    Replace,
+
+    /// Run the `$$invalid_utf8` rule
+    Rule,
+}
+
+/// Characters that increment the line number in locations
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineTerminators {
+    /// Only `\n`
+    #[default]
+    Lf,
+
+    /// `\n`, `\r`, `\r\n` (as one line terminator), NEL (U+0085), LS (U+2028), and PS (U+2029)
+    Unicode,
+}
+
+/// Tokens generated from indentation of lines in indentation-sensitive lexers, and the
+/// characters allowed in indentation
+#[derive(Debug, Clone)]
+pub struct Indentation {
+    pub newline: syn::Expr,
+    pub indent: syn::Expr,
+    pub dedent: syn::Expr,
+    pub whitespace: IndentationWhitespace,
+}
+
+/// Characters allowed in indentation
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IndentationWhitespace {
+    /// Only spaces, tabs are errors
+    #[default]
+    Spaces,
+
+    /// Only tabs, spaces are errors
+    Tabs,
+
+    /// Spaces and tabs, a tab advances the indentation to the next multiple of the width
+    TabWidth(u32),
+}
+
+/// Name of the builtin used as the left-hand side of the rule for invalid UTF-8, with
+/// `invalid_utf8 = rule;`
+pub const INVALID_UTF8_BUILTIN: &str = "invalid_utf8";
+
+/// How checks for character ranges are compiled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RangeChecks {
+    /// Choose one of the representations below for each state, based on the number of ranges
+    /// in the state's transitions
+    #[default]
+    Auto,
+
+    /// A chain of comparisons: `('a'..='z').contains(&x) || ...`
+    IfChain,
+
+    /// Binary search in a sorted array of ranges
+    BinarySearch,
+
+    /// Lookup in a 128-entry table. Only used for ranges of ASCII characters, other ranges use
+    /// binary search.
+    Table,
+}
+
+/// How a match is chosen when multiple rules match the same longest input
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreaking {
+    /// The rule listed first in the rule set is chosen
+    #[default]
+    Order,
+
+    /// The rule with the highest `priority <int>` is chosen. Rules without a priority have
+    /// priority 0, rules with the same priority are chosen by order.
+    Priority,
+
+    /// Rules matching the same input are a compile error
+    Error,
 }
 
+#[derive(Clone)]
 pub struct SingleRule {
     pub lhs: RegexCtx,
     pub rhs: SemanticActionIdx,
+    /// Span of the first token of the rule, used in diagnostics
+    pub span: proc_macro2::Span,
+    /// Matcher function of `fn <matcher>` rules, which are matched by calling the function instead
+    /// of with a regex. `lhs` is an empty string in these rules.
+    pub matcher: Option<syn::Path>,
+    /// Guard function of `<regex> if <guard>` rules. The rule only matches when the guard returns
+    /// `true` for the user state, otherwise the next rule matching the same input is used.
+    pub guard: Option<syn::Path>,
+    /// Whether this is a `fail <rhs>` rule, which runs when no rule in the rule set matches
+    /// instead of raising an `InvalidToken` error. `lhs` is an empty string in these rules.
+    pub fail: bool,
+    /// Priority of `<regex> priority <int>` rules, with the span of the priority. Only allowed with
+    /// `tie_breaking = priority;`.
+    pub priority: Option<(i64, proc_macro2::Span)>,
+    /// Whether this is a `<regex> shortest` rule, which is accepted as soon as it matches, instead
+    /// of trying to match a longer input
+    pub shortest: bool,
+    /// Name of the `group <name> { ... }` the rule is in. The rule only matches when the group is
+    /// enabled in the lexer, otherwise the next rule matching the same input is used.
+    pub group: Option<syn::Ident>,
 }
 
 /// Regular expression with optional right context (lookahead)
@@ -50,9 +394,14 @@ pub struct SingleRule {
 pub struct RegexCtx {
     pub re: Regex,
     pub right_ctx: Option<Regex>,
+    /// Non-ASCII characters in literals of the regexes, with the spans of the literals. Regexes
+    /// don't have spans, these are used to report the characters in lexers with
+    /// `encoding = ascii;`.
+    pub non_ascii_chars: Vec<(char, proc_macro2::Span)>,
 }
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum RuleRhs {
     None,
     Rhs { expr: syn::Expr, kind: RuleKind },
@@ -74,7 +423,7 @@ pub enum RuleKind {
 impl fmt::Debug for Lexer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Lexer")
-            .field("public", &self.public)
+            .field("visibility", &self.visibility.to_token_stream().to_string())
             .field("type_name", &self.type_name.to_string())
             .field("token_type", &"...")
             .field("rules", &self.rules)
@@ -87,19 +436,60 @@ impl fmt::Debug for Rule {
         match self {
             Rule::Binding { var, re } => f
                 .debug_struct("Rule::Binding")
-                .field("var", var)
+                .field("var", &var.to_string())
                 .field("re", re)
                 .finish(),
-            Rule::RuleSet { name, rules } => f
+            Rule::RuleSet {
+                name,
+                rules,
+                exclusive,
+                nested,
+            } => f
                 .debug_struct("Rule::RuleSet")
+                .field("exclusive", exclusive)
                 .field("name", &name.to_string())
+                .field("nested", nested)
                 .field("rules", rules)
                 .finish(),
-            Rule::UnnamedRules { rules } => f
+            Rule::UnnamedRules { span: _, rules } => f
                 .debug_struct("Rule::UnnamedRules")
                 .field("rules", rules)
                 .finish(),
+            Rule::SharedRule { rule_sets, rule } => f
+                .debug_struct("Rule::SharedRule")
+                .field(
+                    "rule_sets",
+                    &rule_sets
+                        .iter()
+                        .map(|name| name.to_string())
+                        .collect::<Vec<_>>(),
+                )
+                .field("rule", rule)
+                .finish(),
             Rule::ErrorType { ty } => f.debug_struct("Rule::ErrorType").field("ty", ty).finish(),
+            Rule::FeedbackType { ty } => f
+                .debug_struct("Rule::FeedbackType")
+                .field("ty", ty)
+                .finish(),
+            Rule::InternerType { ty } => f
+                .debug_struct("Rule::InternerType")
+                .field("ty", ty)
+                .finish(),
+            Rule::Option { name, option } => f
+                .debug_struct("Rule::Option")
+                .field("name", &name.to_string())
+                .field("option", option)
+                .finish(),
+            Rule::Tests { cases } => f
+                .debug_struct("Rule::Tests")
+                .field(
+                    "inputs",
+                    &cases
+                        .iter()
+                        .map(|case| case.input.value())
+                        .collect::<Vec<_>>(),
+                )
+                .finish(),
         }
     }
 }
@@ -109,11 +499,28 @@ impl fmt::Debug for SingleRule {
         f.debug_struct("SingleRule")
             .field("lhs", &self.lhs)
             .field("rhs", &"...")
+            .field(
+                "matcher",
+                &self
+                    .matcher
+                    .as_ref()
+                    .map(|path| path.to_token_stream().to_string()),
+            )
+            .field(
+                "guard",
+                &self
+                    .guard
+                    .as_ref()
+                    .map(|path| path.to_token_stream().to_string()),
+            )
+            .field("priority", &self.priority.map(|(priority, _)| priority))
+            .field("shortest", &self.shortest)
+            .field("group", &self.group.as_ref().map(|group| group.to_string()))
             .finish()
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Regex {
     Builtin(Builtin),
     Var(Var),
@@ -131,12 +538,122 @@ pub enum Regex {
     /// Difference, or exclusion: characters in the first regex, excluding characters in the second
     /// regex.
     Diff(Box<Regex>, Box<Regex>),
+
+    /// A part of a rule's regex bound to a name, `(<regex> as <name>)`. The part of the match is
+    /// available in the semantic action as a variable with the name.
+    Capture(Box<Regex>, String),
 }
 
-#[derive(Debug, Clone)]
+impl Regex {
+    /// Calls `f` on variables used in the regex. Does not look into definitions of the variables.
+    pub fn for_each_var<F: FnMut(&Var)>(&self, f: &mut F) {
+        match self {
+            Regex::Var(var) => f(var),
+            Regex::Builtin(_)
+            | Regex::Char(_)
+            | Regex::String(_)
+            | Regex::CharSet(_)
+            | Regex::Any
+            | Regex::EndOfInput => {}
+            Regex::ZeroOrMore(re)
+            | Regex::OneOrMore(re)
+            | Regex::ZeroOrOne(re)
+            | Regex::Capture(re, _) => re.for_each_var(f),
+            Regex::Concat(re1, re2) | Regex::Or(re1, re2) | Regex::Diff(re1, re2) => {
+                re1.for_each_var(f);
+                re2.for_each_var(f);
+            }
+        }
+    }
+
+    /// Replace built-in regexes with `f`, including in character sets. `f` returns `None` to keep
+    /// the built-in. Does not look into definitions of variables.
+    pub fn map_builtins<F>(&self, f: &mut F) -> Regex
+    where
+        F: FnMut(&Builtin) -> Option<Regex>,
+    {
+        let mut go = |re: &Regex| Box::new(re.map_builtins(f));
+
+        match self {
+            Regex::Builtin(builtin) => f(builtin).unwrap_or_else(|| self.clone()),
+            Regex::Var(_)
+            | Regex::Char(_)
+            | Regex::String(_)
+            | Regex::CharSet(_)
+            | Regex::Any
+            | Regex::EndOfInput => self.clone(),
+            Regex::ZeroOrMore(re) => Regex::ZeroOrMore(go(re)),
+            Regex::OneOrMore(re) => Regex::OneOrMore(go(re)),
+            Regex::ZeroOrOne(re) => Regex::ZeroOrOne(go(re)),
+            Regex::Concat(re1, re2) => Regex::Concat(go(re1), go(re2)),
+            Regex::Or(re1, re2) => Regex::Or(go(re1), go(re2)),
+            Regex::Diff(re1, re2) => Regex::Diff(go(re1), go(re2)),
+            Regex::Capture(re, name) => Regex::Capture(go(re), name.clone()),
+        }
+    }
+
+    /// Replace regexes outside of character sets (`#`) with `f`. `f` is called on regexes other
+    /// than repetitions, concatenations, and alternations, and returns `None` to keep the regex.
+    ///
+    /// Variables are replaced with their definitions, as the same variables can be used in
+    /// character sets.
+    // Only used with the `unicode` feature
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    pub fn map_outside_char_sets<F>(&self, bindings: &Map<Var, Regex>, f: &F) -> Regex
+    where
+        F: Fn(&Regex) -> Option<Regex>,
+    {
+        let go = |re: &Regex| Box::new(re.map_outside_char_sets(bindings, f));
+
+        match self {
+            Regex::Var(var) => bindings
+                .get(var)
+                .unwrap_or_else(|| panic!("Unbound variable {:?}", var.0))
+                .map_outside_char_sets(bindings, f),
+
+            Regex::Builtin(_)
+            | Regex::Char(_)
+            | Regex::String(_)
+            | Regex::CharSet(_)
+            | Regex::Any
+            | Regex::EndOfInput
+            | Regex::Diff(_, _) => f(self).unwrap_or_else(|| self.clone()),
+
+            Regex::ZeroOrMore(re) => Regex::ZeroOrMore(go(re)),
+            Regex::OneOrMore(re) => Regex::OneOrMore(go(re)),
+            Regex::ZeroOrOne(re) => Regex::ZeroOrOne(go(re)),
+            Regex::Concat(re1, re2) => Regex::Concat(go(re1), go(re2)),
+            Regex::Or(re1, re2) => Regex::Or(go(re1), go(re2)),
+            Regex::Capture(re, name) => Regex::Capture(go(re), name.clone()),
+        }
+    }
+
+    /// Whether the regex has captures, `(<regex> as <name>)`. Does not look into definitions of
+    /// variables, as variables can't have captures.
+    pub fn has_captures(&self) -> bool {
+        match self {
+            Regex::Capture(_, _) => true,
+            Regex::Builtin(_)
+            | Regex::Var(_)
+            | Regex::Char(_)
+            | Regex::String(_)
+            | Regex::CharSet(_)
+            | Regex::Any
+            | Regex::EndOfInput => false,
+            Regex::ZeroOrMore(re) | Regex::OneOrMore(re) | Regex::ZeroOrOne(re) => {
+                re.has_captures()
+            }
+            Regex::Concat(re1, re2) | Regex::Or(re1, re2) | Regex::Diff(re1, re2) => {
+                re1.has_captures() || re2.has_captures()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CharSet(pub Vec<CharOrRange>);
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CharOrRange {
     Char(char),
     Range(char, char),
@@ -144,19 +661,73 @@ pub enum CharOrRange {
 
 /// Parses a regex with optional right context: `re_ctx -> re [> re]`
 fn parse_regex_ctx(input: ParseStream) -> syn::Result<RegexCtx> {
+    let start = input.cursor();
     let re = parse_regex(input)?;
-    if input.peek(syn::token::Gt) {
+    let right_ctx = if input.peek(syn::token::Gt) {
         input.parse::<syn::token::Gt>()?;
-        let right_ctx = parse_regex(input)?;
-        Ok(RegexCtx {
-            re,
-            right_ctx: Some(right_ctx),
-        })
+        Some(parse_regex(input)?)
     } else {
-        Ok(RegexCtx {
-            re,
-            right_ctx: None,
-        })
+        None
+    };
+    let mut non_ascii_chars = vec![];
+    find_non_ascii_chars(start, Some(input.cursor()), &mut non_ascii_chars);
+    Ok(RegexCtx {
+        re,
+        right_ctx,
+        non_ascii_chars,
+    })
+}
+
+/// Parses a regex without right context, in options and modifiers
+fn parse_regex_no_ctx(input: ParseStream) -> syn::Result<RegexCtx> {
+    let start = input.cursor();
+    let re = parse_regex(input)?;
+    let mut non_ascii_chars = vec![];
+    find_non_ascii_chars(start, Some(input.cursor()), &mut non_ascii_chars);
+    Ok(RegexCtx {
+        re,
+        right_ctx: None,
+        non_ascii_chars,
+    })
+}
+
+/// Adds non-ASCII characters in the literals of a parsed regex to `chars`, with the spans of the
+/// literals. The regex is the tokens from `cursor` until `end`, or the end of the group when `end`
+/// is `None`. All literals in a regex are characters, strings, or code points (`0x61`).
+fn find_non_ascii_chars(
+    mut cursor: syn::buffer::Cursor,
+    end: Option<syn::buffer::Cursor>,
+    chars: &mut Vec<(char, proc_macro2::Span)>,
+) {
+    while !cursor.eof() && Some(cursor) != end {
+        use proc_macro2::Delimiter;
+        if let Some((inner, _, next)) = [
+            Delimiter::Parenthesis,
+            Delimiter::Bracket,
+            Delimiter::Brace,
+            Delimiter::None,
+        ]
+        .iter()
+        .find_map(|delimiter| cursor.group(*delimiter))
+        {
+            find_non_ascii_chars(inner, None, chars);
+            cursor = next;
+        } else if let Some((lit, next)) = cursor.literal() {
+            let char = match syn::Lit::new(lit.clone()) {
+                syn::Lit::Char(char) => Some(char.value()),
+                syn::Lit::Str(str) => str.value().chars().find(|char| !char.is_ascii()),
+                syn::Lit::Int(int) => int.base10_parse::<u32>().ok().and_then(char::from_u32),
+                _ => None,
+            };
+            if let Some(char) = char.filter(|char| !char.is_ascii()) {
+                chars.push((char, lit.span()));
+            }
+            cursor = next;
+        } else if let Some((_, next)) = cursor.token_tree() {
+            cursor = next;
+        } else {
+            break;
+        }
     }
 }
 
@@ -221,27 +792,140 @@ fn parse_regex_2(input: ParseStream) -> syn::Result<Regex> {
 
 // re_3 -> re_4 | re_4 # re_4 (left associative)
 fn parse_regex_3(input: ParseStream) -> syn::Result<Regex> {
+    let span = input.span();
     let mut re = parse_regex_4(input)?;
 
     while input.peek(syn::token::Pound) {
+        check_char_set_operand(&re, span)?;
         let _ = input.parse::<syn::token::Pound>()?;
+        let span_2 = input.span();
         let re_2 = parse_regex_4(input)?;
+        check_char_set_operand(&re_2, span_2)?;
         re = Regex::Diff(Box::new(re), Box::new(re_2));
     }
 
     Ok(re)
 }
 
-// re_4 -> ( re_0 ) | $ | $x | $$x | _ | 'x' | "..." | [...]
+/// Check that an operand of `#` is a character set. `span` is the span of the first token of the
+/// operand. Variables are checked when compiling the regex.
+fn check_char_set_operand(re: &Regex, span: proc_macro2::Span) -> syn::Result<()> {
+    let error = |what: &str| {
+        Err(syn::Error::new(
+            span,
+            format!("{} cannot be used in character sets (`#`)", what),
+        ))
+    };
+
+    match re {
+        Regex::Builtin(_) | Regex::Var(_) | Regex::Char(_) | Regex::CharSet(_) | Regex::Any => {
+            Ok(())
+        }
+        Regex::String(_) => error("Strings"),
+        Regex::ZeroOrMore(_) => error("`*`"),
+        Regex::OneOrMore(_) => error("`+`"),
+        Regex::ZeroOrOne(_) => error("`?`"),
+        Regex::Concat(_, _) => error("Concatenation (`<re1> <re2>`)"),
+        Regex::EndOfInput => error("`$`"),
+        Regex::Capture(_, _) => error("Captures (`(<regex> as <name>)`)"),
+        Regex::Or(re1, re2) | Regex::Diff(re1, re2) => {
+            check_char_set_operand(re1, span)?;
+            check_char_set_operand(re2, span)
+        }
+    }
+}
+
+// `{<name>}` of `$$p{<name>}`
+fn parse_unicode_property(input: ParseStream) -> syn::Result<Regex> {
+    let braced;
+    syn::braced!(braced in input);
+    let name = braced.parse::<syn::Ident>()?;
+    if !unicode_properties::ENABLED {
+        return Err(syn::Error::new(
+            name.span(),
+            "Unicode properties (`$$p{...}`) require the \"unicode\" feature of lexgen",
+        ));
+    }
+    if braced.peek(syn::token::Eq) {
+        return parse_unicode_property_value(&braced, name);
+    }
+    if unicode_properties::property_ranges(&name.to_string()).is_none() {
+        return Err(syn::Error::new(
+            name.span(),
+            format!(
+                "Unknown Unicode general category or script {:?}",
+                name.to_string()
+            ),
+        ));
+    }
+    Ok(Regex::Builtin(Builtin(format!("p{{{}}}", name))))
+}
+
+// `$$p{Block=<name>}` and `$$p{Age=<version>}`, after the property name
+fn parse_unicode_property_value(input: ParseStream, property: syn::Ident) -> syn::Result<Regex> {
+    let _ = input.parse::<syn::token::Eq>()?;
+    let (value, span) = match property.to_string().as_str() {
+        "Block" | "blk" => {
+            let value = input.parse::<syn::Ident>()?;
+            (value.to_string(), value.span())
+        }
+        "Age" | "age" => {
+            let value = input.parse::<syn::LitFloat>()?;
+            (value.base10_digits().to_owned(), value.span())
+        }
+        _ => {
+            return Err(syn::Error::new(
+                property.span(),
+                format!(
+                    "Unknown Unicode property {:?}, expected `Block` or `Age`",
+                    property.to_string()
+                ),
+            ))
+        }
+    };
+    let name = format!("{}={}", property, value);
+    if unicode_properties::property_ranges(&name).is_none() {
+        return Err(syn::Error::new(
+            span,
+            format!("Unknown Unicode {} {:?}", property, value),
+        ));
+    }
+    Ok(Regex::Builtin(Builtin(format!("p{{{}}}", name))))
+}
+
+// re_4 -> ( re_0 ) | $ | $x | $$x | $$p{x} | $$p{x=y} | _ | 'x' | "..." | [...]
 fn parse_regex_4(input: ParseStream) -> syn::Result<Regex> {
     if input.peek(syn::token::Paren) {
         let parenthesized;
         syn::parenthesized!(parenthesized in input);
-        parse_regex(&parenthesized) // no right ctx
+        let re = parse_regex(&parenthesized)?; // no right ctx
+        if parenthesized.parse::<syn::token::As>().is_ok() {
+            let name = parenthesized.parse::<syn::Ident>()?;
+            Ok(Regex::Capture(Box::new(re), name.to_string()))
+        } else {
+            Ok(re)
+        }
     } else if input.peek(syn::token::Dollar) {
         let _ = input.parse::<syn::token::Dollar>()?;
         if input.parse::<syn::token::Dollar>().is_ok() {
             let ident = input.parse::<syn::Ident>()?;
+            if ident == "p" && input.peek(syn::token::Brace) {
+                return parse_unicode_property(input);
+            }
+            if ident == INVALID_UTF8_BUILTIN {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "`$$invalid_utf8` can only be used as the whole left-hand side of a rule",
+                ));
+            }
+            if !BUILTIN_RANGES.iter().any(|(name, _)| ident == name)
+                && !PERL_CLASSES.iter().any(|name| ident == name)
+            {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("Unknown built-in regex {:?}", ident.to_string()),
+                ));
+            }
             Ok(Regex::Builtin(Builtin(ident.to_string())))
         } else {
             match input.parse::<syn::Ident>() {
@@ -263,9 +947,9 @@ fn parse_regex_4(input: ParseStream) -> syn::Result<Regex> {
     } else if input.parse::<syn::token::Underscore>().is_ok() {
         Ok(Regex::Any)
     } else {
-        Err(syn::Error::new(
-            proc_macro2::Span::call_site(),
-            "Unable to parse regex",
+        Err(input.error(
+            "Expected a regex: a character, a string, a character set `[...]`, `_`, `$`, \
+            a variable `$name`, a built-in regex `$$name`, or a regex in parentheses",
         ))
     }
 }
@@ -279,21 +963,149 @@ fn parse_charset(input: ParseStream) -> syn::Result<CharSet> {
 }
 
 fn parse_char_or_range(input: ParseStream) -> syn::Result<CharOrRange> {
-    let char = input.parse::<syn::LitChar>()?.value();
+    if !input.peek(syn::LitChar) && !input.peek(syn::LitInt) {
+        return Err(input.error(
+            "Expected a character or a character range (`'a'-'z'`) in a character set. \
+            Characters can also be given as code points (`0x41`, `0x0-0x1F`)",
+        ));
+    }
+    let (char, lit) = parse_charset_char(input)?;
     if input.peek(syn::token::Sub) {
-        let _ = input.parse::<syn::token::Sub>()?;
-        let char2 = input.parse::<syn::LitChar>()?.value();
+        let sub = input.parse::<syn::token::Sub>()?;
+        let (char2, lit2) = parse_charset_char(input)?;
+
+        let error = |msg: String| syn::Error::new_spanned(quote::quote!(#lit #sub #lit2), msg);
+
+        if char > char2 {
+            return Err(error(format!(
+                "Invalid character range: {:?} is greater than {:?}",
+                char, char2
+            )));
+        }
+
+        // Characters can't be surrogates, but a range can contain the surrogate range
+        if (char as u32) < 0xD800 && (char2 as u32) > 0xDFFF {
+            return Err(error(format!(
+                "Character range contains surrogates (U+D800 to U+DFFF), which are not \
+                characters. Split the range into {}-'\\u{{d7ff}}' and '\\u{{e000}}'-{}",
+                quote::quote!(#lit),
+                quote::quote!(#lit2),
+            )));
+        }
+
         Ok(CharOrRange::Range(char, char2))
     } else {
         Ok(CharOrRange::Char(char))
     }
 }
 
+/// A character in a character set: a character literal (`'a'`, `'\u{0}'`), or a code point as an
+/// integer literal (`0x61`, `97`)
+fn parse_charset_char(input: ParseStream) -> syn::Result<(char, syn::Lit)> {
+    let lit = input.parse::<syn::Lit>()?;
+    let char = match &lit {
+        syn::Lit::Char(char) => char.value(),
+        syn::Lit::Int(int) => {
+            if !int.suffix().is_empty() {
+                return Err(syn::Error::new(
+                    int.span(),
+                    "Code points in character sets can't have a type suffix",
+                ));
+            }
+            int.base10_parse::<u32>()
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| {
+                    syn::Error::new(
+                        int.span(),
+                        format!(
+                            "{} is not a valid code point: code points are at most 0x10FFFF, \
+                            and surrogates (0xD800 to 0xDFFF) are not characters",
+                            int
+                        ),
+                    )
+                })?
+        }
+        _ => {
+            return Err(syn::Error::new(
+                lit.span(),
+                "Expected a character or a code point",
+            ))
+        }
+    };
+    Ok((char, lit))
+}
+
 fn parse_single_rule(
     input: ParseStream,
     semantic_action_table: &mut SemanticActionTable,
 ) -> syn::Result<SingleRule> {
-    let lhs = parse_regex_ctx(input)?;
+    let span = input.span();
+    let mut matcher = None;
+    let mut guard = None;
+    let mut fail = false;
+    let mut priority = None;
+    let mut shortest = false;
+    let lhs = if is_fail_rule(input) {
+        input.parse::<syn::Ident>()?;
+        fail = true;
+        RegexCtx {
+            re: Regex::String(String::new()),
+            right_ctx: None,
+            non_ascii_chars: vec![],
+        }
+    } else if input.parse::<syn::token::Fn>().is_ok() {
+        matcher = Some(input.parse::<syn::Path>()?);
+        RegexCtx {
+            re: Regex::String(String::new()),
+            right_ctx: None,
+            non_ascii_chars: vec![],
+        }
+    } else if is_invalid_utf8_rule(input) {
+        input.parse::<syn::token::Dollar>()?;
+        input.parse::<syn::token::Dollar>()?;
+        input.parse::<syn::Ident>()?;
+        RegexCtx {
+            re: Regex::Builtin(Builtin(INVALID_UTF8_BUILTIN.to_owned())),
+            right_ctx: None,
+            non_ascii_chars: vec![],
+        }
+    } else {
+        let lhs = parse_regex_ctx(input)?;
+        if input.parse::<syn::token::If>().is_ok() {
+            guard = Some(input.parse::<syn::Path>()?);
+        }
+        // Modifiers, in any order
+        loop {
+            if is_rule_modifier(input, "priority") {
+                let (value, span) = parse_priority(input)?;
+                if priority.replace((value, span)).is_some() {
+                    return Err(syn::Error::new(
+                        span,
+                        "Rule has multiple `priority` modifiers",
+                    ));
+                }
+            } else if is_rule_modifier(input, "shortest") {
+                let modifier = input.parse::<syn::Ident>()?;
+                if shortest {
+                    return Err(syn::Error::new(
+                        modifier.span(),
+                        "Rule has multiple `shortest` modifiers",
+                    ));
+                }
+                if guard.is_some() || lhs.right_ctx.is_some() {
+                    return Err(syn::Error::new(
+                        modifier.span(),
+                        "`shortest` rules can't have a guard or a right context",
+                    ));
+                }
+                shortest = true;
+            } else {
+                break;
+            }
+        }
+        lhs
+    };
 
     let rhs = if input.parse::<syn::token::Comma>().is_ok() {
         RuleRhs::None
@@ -315,12 +1127,112 @@ fn parse_single_rule(
         input.parse::<syn::token::Comma>()?;
         RuleRhs::Rhs { expr, kind }
     } else {
-        panic!("Expected one of `,`, `=>`, `=?`, or `=` after a regex");
+        return Err(input.error("Expected one of `,`, `=>`, `=?`, or `=` after a regex"));
     };
 
+    if fail && matches!(rhs, RuleRhs::None) {
+        return Err(syn::Error::new(
+            span,
+            "`fail` rules should have a right-hand side: `=>`, `=?`, or `=`",
+        ));
+    }
+
     let rhs = semantic_action_table.add(rhs);
 
-    Ok(SingleRule { lhs, rhs })
+    Ok(SingleRule {
+        lhs,
+        rhs,
+        span,
+        matcher,
+        guard,
+        fail,
+        priority,
+        shortest,
+        group: None,
+    })
+}
+
+/// Parses a single rule, or the rules of a group: `group <name> { <rules> }`
+fn parse_rule_or_group(
+    input: ParseStream,
+    semantic_action_table: &mut SemanticActionTable,
+    rules: &mut Vec<SingleRule>,
+) -> syn::Result<()> {
+    if !is_group(input) {
+        rules.push(parse_single_rule(input, semantic_action_table)?);
+        return Ok(());
+    }
+
+    input.parse::<syn::Ident>()?;
+    let name = input.parse::<syn::Ident>()?;
+    let braced;
+    syn::braced!(braced in input);
+    while !braced.is_empty() {
+        if is_group(&braced) {
+            return Err(syn::Error::new(braced.span(), "Groups can't be nested"));
+        }
+        let mut rule = parse_single_rule(&braced, semantic_action_table)?;
+        let invalid_utf8 = matches!(&rule.lhs.re, Regex::Builtin(Builtin(builtin)) if builtin == INVALID_UTF8_BUILTIN);
+        if rule.fail || rule.matcher.is_some() || invalid_utf8 || rule.shortest {
+            return Err(syn::Error::new(
+                rule.span,
+                "`fail`, `fn`, `$$invalid_utf8`, and `shortest` rules can't be in groups",
+            ));
+        }
+        rule.group = Some(name.clone());
+        rules.push(rule);
+    }
+    // Consume optional trailing comma
+    let _ = input.parse::<syn::token::Comma>();
+    Ok(())
+}
+
+/// Whether the input starts with a group: `group <name> { ... }`
+fn is_group(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Ident>()
+        .is_ok_and(|ident| ident == "group")
+        && fork.parse::<syn::Ident>().is_ok()
+        && fork.peek(syn::token::Brace)
+}
+
+/// Whether the input starts with the rule modifier `name`: `priority` or `shortest`
+fn is_rule_modifier(input: ParseStream, name: &str) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Ident>().is_ok_and(|ident| ident == name)
+}
+
+/// Parses `priority <int>`, where the integer can be negative
+fn parse_priority(input: ParseStream) -> syn::Result<(i64, proc_macro2::Span)> {
+    let keyword = input.parse::<syn::Ident>()?;
+    let negative = input.parse::<syn::token::Sub>().is_ok();
+    let lit = input.parse::<syn::LitInt>()?;
+    let priority = lit.base10_parse::<i64>()?;
+    let priority = if negative { -priority } else { priority };
+    Ok((priority, keyword.span()))
+}
+
+/// Whether the rule is a `fail <rhs>` rule
+fn is_fail_rule(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Ident>()
+        .is_ok_and(|ident| ident == "fail")
+        && (fork.peek(syn::token::Comma)
+            || fork.peek(syn::token::FatArrow)
+            || fork.peek(syn::token::Eq))
+}
+
+/// Whether the rule starts with `$$invalid_utf8` and a rule right-hand side
+fn is_invalid_utf8_rule(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::token::Dollar>().is_ok()
+        && fork.parse::<syn::token::Dollar>().is_ok()
+        && fork
+            .parse::<syn::Ident>()
+            .is_ok_and(|ident| ident == INVALID_UTF8_BUILTIN)
+        && (fork.peek(syn::token::Comma)
+            || fork.peek(syn::token::FatArrow)
+            || fork.peek(syn::token::Eq))
 }
 
 fn parse_rule(
@@ -334,13 +1246,19 @@ fn parse_rule(
         input.parse::<syn::token::Eq>()?;
         let re = parse_regex_ctx(input)?;
         input.parse::<syn::token::Semi>()?;
-        Ok(Rule::Binding {
-            var: Var(var.to_string()),
-            re,
-        })
-    } else if input.peek(syn::Ident) {
+        Ok(Rule::Binding { var, re })
+    } else if input.peek(syn::Ident) && !is_fail_rule(input) && !is_group(input) {
         // Name rules
         let ident = input.parse::<syn::Ident>()?;
+        if input.peek(syn::token::Eq) {
+            input.parse::<syn::token::Eq>()?;
+            let option = parse_option(&ident, input, semantic_action_table)?;
+            input.parse::<syn::token::Semi>()?;
+            return Ok(Rule::Option {
+                name: ident,
+                option,
+            });
+        }
         if ident != "rule" {
             return Err(syn::Error::new(
                 ident.span(),
@@ -348,68 +1266,672 @@ fn parse_rule(
             ));
         }
         let rule_name = input.parse::<syn::Ident>()?;
+        let mut single_rules = vec![];
+        let mut exclusive = false;
+        let mut nested = false;
+        while input.peek(syn::Ident) {
+            let modifier = input.parse::<syn::Ident>()?;
+            let duplicate = if modifier == "exclusive" {
+                std::mem::replace(&mut exclusive, true)
+            } else if modifier == "nested" {
+                single_rules.extend(parse_nested_delimiters(
+                    &modifier,
+                    input,
+                    semantic_action_table,
+                )?);
+                std::mem::replace(&mut nested, true)
+            } else {
+                return Err(syn::Error::new(
+                    modifier.span(),
+                    "Unknown rule set modifier, expected \"nested\" or \"exclusive\"",
+                ));
+            };
+            if duplicate {
+                return Err(syn::Error::new(
+                    modifier.span(),
+                    format!("Duplicate rule set modifier \"{}\"", modifier),
+                ));
+            }
+        }
         let braced;
         syn::braced!(braced in input);
-        let mut single_rules = vec![];
         while !braced.is_empty() {
-            single_rules.push(parse_single_rule(&braced, semantic_action_table)?);
+            parse_rule_or_group(&braced, semantic_action_table, &mut single_rules)?;
         }
         // Consume optional trailing comma
         let _ = input.parse::<syn::token::Comma>();
         Ok(Rule::RuleSet {
             name: rule_name,
             rules: single_rules,
+            exclusive,
+            nested,
         })
     } else if input.parse::<syn::token::Type>().is_ok() {
         let ident = input.parse::<syn::Ident>()?;
-        if ident != "Error" {
-            panic!("Error type syntax is: `type Error = ...;`");
+        if ident != "Error" && ident != "Feedback" && ident != "Interner" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "Type declaration syntax is: `type Error = ...;`, `type Feedback = ...;`, or \
+                 `type Interner = ...;`",
+            ));
         }
         input.parse::<syn::token::Eq>()?;
         let ty = input.parse::<syn::Type>()?;
         input.parse::<syn::token::Semi>()?;
-        Ok(Rule::ErrorType { ty })
+        if ident == "Error" {
+            Ok(Rule::ErrorType { ty })
+        } else if ident == "Feedback" {
+            Ok(Rule::FeedbackType { ty })
+        } else {
+            Ok(Rule::InternerType { ty })
+        }
+    } else if input.peek(syn::token::Lt) {
+        parse_shared_rule(input, semantic_action_table)
     } else {
+        let span = input.span();
         let mut single_rules = vec![];
-        while !input.is_empty() {
-            single_rules.push(parse_single_rule(input, semantic_action_table)?);
+        while !input.is_empty()
+            && !peek_lexer_header(input)
+            && !input.peek(syn::token::Lt)
+            && !peek_tests_block(input)
+        {
+            parse_rule_or_group(input, semantic_action_table, &mut single_rules)?;
         }
         Ok(Rule::UnnamedRules {
+            span,
             rules: single_rules,
         })
     }
 }
 
-pub fn make_lexer_parser(
+/// Parses a rule added to multiple rule sets: `<<Ident>, ...> <rule>`
+fn parse_shared_rule(
+    input: ParseStream,
     semantic_action_table: &mut SemanticActionTable,
-) -> impl FnOnce(ParseStream) -> Result<Lexer, syn::Error> + '_ {
-    |input: ParseStream| {
-        let public = input.parse::<syn::token::Pub>().is_ok();
-        let type_name = input.parse::<syn::Ident>()?;
-
-        let user_state_type = if input.peek(syn::token::Paren) {
-            let parenthesized;
-            syn::parenthesized!(parenthesized in input);
-            Some(parenthesized.parse::<syn::Type>()?)
+) -> syn::Result<Rule> {
+    let lt = input.parse::<syn::token::Lt>()?;
+    let mut rule_sets: Vec<syn::Ident> = vec![];
+    while !input.peek(syn::token::Gt) {
+        let name = input.parse::<syn::Ident>()?;
+        if rule_sets.contains(&name) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("Rule set \"{}\" is listed multiple times", name),
+            ));
+        }
+        rule_sets.push(name);
+        if !input.peek(syn::token::Gt) {
+            input.parse::<syn::token::Comma>()?;
+        }
+    }
+    input.parse::<syn::token::Gt>()?;
+    if rule_sets.is_empty() {
+        return Err(syn::Error::new(
+            lt.span,
+            "Expected at least one rule set name: `<<Ident>, ...> <rule>`",
+        ));
+    }
+    let rule = parse_single_rule(input, semantic_action_table)?;
+    Ok(Rule::SharedRule { rule_sets, rule })
+}
+
+/// Parses the `nested(<open>, <close>)` modifier of a rule set, after the rule set name. Returns
+/// rules for the delimiters, which maintain the nesting depth in the lexer: `<open>` increments
+/// the depth, `<close>` decrements it, or switches back to the rule set that switched to the
+/// nested rule set when the depth is zero.
+fn parse_nested_delimiters(
+    modifier: &syn::Ident,
+    input: ParseStream,
+    semantic_action_table: &mut SemanticActionTable,
+) -> syn::Result<Vec<SingleRule>> {
+    let parenthesized;
+    syn::parenthesized!(parenthesized in input);
+    let open = parse_regex_no_ctx(&parenthesized)?;
+    parenthesized.parse::<syn::token::Comma>()?;
+    let close = parse_regex_no_ctx(&parenthesized)?;
+    let _ = parenthesized.parse::<syn::token::Comma>();
+    if !parenthesized.is_empty() {
+        return Err(parenthesized.error("Expected `nested(<open regex>, <close regex>)`"));
+    }
+
+    let span = modifier.span();
+    let open_action = semantic_action_table.add(RuleRhs::Rhs {
+        expr: syn::parse_quote_spanned!(span=> |lexer| lexer.__nested_open()),
+        kind: RuleKind::Infallible,
+    });
+    let close_action = semantic_action_table.add(RuleRhs::Rhs {
+        expr: syn::parse_quote_spanned!(span=> |lexer| lexer.__nested_close()),
+        kind: RuleKind::Infallible,
+    });
+
+    Ok(vec![
+        SingleRule {
+            lhs: open,
+            rhs: open_action,
+            span,
+            matcher: None,
+            guard: None,
+            fail: false,
+            priority: None,
+            shortest: false,
+            group: None,
+        },
+        SingleRule {
+            lhs: close,
+            rhs: close_action,
+            span,
+            matcher: None,
+            guard: None,
+            fail: false,
+            priority: None,
+            shortest: false,
+            group: None,
+        },
+    ])
+}
+
+/// Options that can be written without a value in an options block, as a shorthand for
+/// `<name> = true`
+const FLAG_OPTIONS: &[&str] = &[
+    "ambiguity_warnings",
+    "cancellation",
+    "case_insensitive",
+    "const_fn",
+    "counters",
+    "dfa_table",
+    "grammar_json",
+    "lalrpop",
+    "next_token",
+    "normalization_insensitive",
+    "statistics",
+    "spanned_tokens",
+    "tag_rule_sets",
+    "token_kind",
+];
+
+/// Whether the input starts with an options block: `options { ... }`
+fn peek_options_block(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Ident>()
+        .is_ok_and(|ident| ident == "options")
+        && fork.peek(syn::token::Brace)
+}
+
+/// Whether the input starts with a tests section: `tests { ... }`
+fn peek_tests_block(input: ParseStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Ident>()
+        .is_ok_and(|ident| ident == "tests")
+        && fork.peek(syn::token::Brace)
+}
+
+/// Parses a tests section: `tests { "<input>" => [<token>, ...], "<input>" => error@<n>, ... }`
+fn parse_tests_block(input: ParseStream) -> syn::Result<Rule> {
+    input.parse::<syn::Ident>()?;
+    let braced;
+    syn::braced!(braced in input);
+
+    let mut cases = vec![];
+    while !braced.is_empty() {
+        let case_input = braced.parse::<syn::LitStr>()?;
+        braced.parse::<syn::token::FatArrow>()?;
+        let expected = if braced.peek(syn::token::Bracket) {
+            let bracketed;
+            syn::bracketed!(bracketed in braced);
+            let mut tokens = vec![];
+            while !bracketed.is_empty() {
+                tokens.push(bracketed.parse::<syn::Expr>()?);
+                if !bracketed.is_empty() {
+                    bracketed.parse::<syn::token::Comma>()?;
+                }
+            }
+            TestExpectation::Tokens(tokens)
         } else {
-            None
+            let ident = braced.parse::<syn::Ident>()?;
+            if ident != "error" {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "Expected a list of tokens `[...]` or an error location `error@<byte index>`",
+                ));
+            }
+            braced.parse::<syn::token::At>()?;
+            let byte_idx = braced.parse::<syn::LitInt>()?;
+            byte_idx.base10_parse::<usize>()?;
+            TestExpectation::Error(byte_idx)
         };
+        cases.push(TestCase {
+            input: case_input,
+            expected,
+        });
 
-        input.parse::<syn::token::RArrow>()?;
-        let token_type = input.parse::<syn::Type>()?;
-        input.parse::<syn::token::Semi>()?;
+        if !braced.is_empty() {
+            braced.parse::<syn::token::Comma>()?;
+        }
+    }
 
-        let mut rules = vec![];
-        while !input.is_empty() {
-            rules.push(parse_rule(input, semantic_action_table)?);
+    Ok(Rule::Tests { cases })
+}
+
+/// Parses an options block: `options { <name> = <value>, <name>, ... }`. Options without a value
+/// are set to `true`. Each option in the block is the same as a `<name> = <value>;` option.
+fn parse_options_block(
+    input: ParseStream,
+    semantic_action_table: &mut SemanticActionTable,
+) -> syn::Result<Vec<Rule>> {
+    input.parse::<syn::Ident>()?;
+    let braced;
+    syn::braced!(braced in input);
+
+    let mut rules = vec![];
+    while !braced.is_empty() {
+        let name = braced.parse::<syn::Ident>()?;
+        let option = if braced.parse::<syn::token::Eq>().is_ok() {
+            parse_option(&name, &braced, semantic_action_table)?
+        } else if FLAG_OPTIONS.contains(&name.to_string().as_str()) {
+            let value = syn::LitBool::new(true, name.span());
+            syn::parse::Parser::parse2(
+                |input: ParseStream| parse_option(&name, input, semantic_action_table),
+                value.into_token_stream(),
+            )?
+        } else {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("Option \"{}\" needs a value: `{} = <value>`", name, name),
+            ));
+        };
+        rules.push(Rule::Option { name, option });
+
+        if !braced.is_empty() {
+            braced.parse::<syn::token::Comma>()?;
         }
+    }
+
+    Ok(rules)
+}
 
-        Ok(Lexer {
-            public,
-            type_name,
-            user_state_type,
-            token_type,
-            rules,
+/// Parses value of an option, after `<name> =`
+fn parse_option(
+    name: &syn::Ident,
+    input: ParseStream,
+    semantic_action_table: &mut SemanticActionTable,
+) -> syn::Result<LexerOption> {
+    if name == "driver" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "match_loop" {
+            Ok(LexerOption::Driver(DriverStyle::MatchLoop))
+        } else if value == "state_fns" {
+            Ok(LexerOption::Driver(DriverStyle::StateFns))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown driver style, expected \"match_loop\" or \"state_fns\"",
+            ))
+        }
+    } else if name == "encoding" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "ascii" {
+            Ok(LexerOption::Encoding(Encoding::Ascii))
+        } else if value == "unicode" {
+            Ok(LexerOption::Encoding(Encoding::Unicode))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown encoding, expected \"ascii\" or \"unicode\"",
+            ))
+        }
+    } else if name == "init_coverage" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "ascii" {
+            Ok(LexerOption::InitCoverage(Encoding::Ascii))
+        } else if value == "unicode" {
+            Ok(LexerOption::InitCoverage(Encoding::Unicode))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown character set, expected \"ascii\" or \"unicode\"",
+            ))
+        }
+    } else if name == "match_unit" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "char" {
+            Ok(LexerOption::MatchUnit(MatchUnit::Char))
+        } else if value == "grapheme" {
+            if !unicode_properties::ENABLED {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "`match_unit = grapheme;` requires the \"unicode\" feature of lexgen",
+                ));
+            }
+            Ok(LexerOption::MatchUnit(MatchUnit::Grapheme))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown match unit, expected \"char\" or \"grapheme\"",
+            ))
+        }
+    } else if name == "normalization_insensitive" {
+        let value = input.parse::<syn::LitBool>()?;
+        if value.value && !unicode_properties::ENABLED {
+            return Err(syn::Error::new(
+                value.span(),
+                "`normalization_insensitive = true;` requires the \"unicode\" feature of lexgen",
+            ));
+        }
+        Ok(LexerOption::NormalizationInsensitive(value.value))
+    } else if name == "invalid_utf8" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "error" {
+            Ok(LexerOption::InvalidUtf8(InvalidUtf8::Error))
+        } else if value == "replace" {
+            Ok(LexerOption::InvalidUtf8(InvalidUtf8::Replace))
+        } else if value == "rule" {
+            Ok(LexerOption::InvalidUtf8(InvalidUtf8::Rule))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown invalid UTF-8 handling, expected \"error\", \"replace\", or \"rule\"",
+            ))
+        }
+    } else if name == "line_terminators" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "lf" {
+            Ok(LexerOption::LineTerminators(LineTerminators::Lf))
+        } else if value == "unicode" {
+            Ok(LexerOption::LineTerminators(LineTerminators::Unicode))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown line terminators, expected \"lf\" or \"unicode\"",
+            ))
+        }
+    } else if name == "perl_classes" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "ascii" {
+            Ok(LexerOption::PerlClasses(Encoding::Ascii))
+        } else if value == "unicode" {
+            Ok(LexerOption::PerlClasses(Encoding::Unicode))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown character set, expected \"ascii\" or \"unicode\"",
+            ))
+        }
+    } else if name == "const_fn" {
+        Ok(LexerOption::ConstFn(input.parse::<syn::LitBool>()?.value))
+    } else if name == "range_checks" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "auto" {
+            Ok(LexerOption::RangeChecks(RangeChecks::Auto))
+        } else if value == "if_chain" {
+            Ok(LexerOption::RangeChecks(RangeChecks::IfChain))
+        } else if value == "binary_search" {
+            Ok(LexerOption::RangeChecks(RangeChecks::BinarySearch))
+        } else if value == "table" {
+            Ok(LexerOption::RangeChecks(RangeChecks::Table))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown range check style, expected \"auto\", \"if_chain\", \"binary_search\", or \"table\"",
+            ))
+        }
+    } else if name == "tie_breaking" {
+        let value = input.parse::<syn::Ident>()?;
+        if value == "order" {
+            Ok(LexerOption::TieBreaking(TieBreaking::Order))
+        } else if value == "priority" {
+            Ok(LexerOption::TieBreaking(TieBreaking::Priority))
+        } else if value == "error" {
+            Ok(LexerOption::TieBreaking(TieBreaking::Error))
+        } else {
+            Err(syn::Error::new(
+                value.span(),
+                "Unknown tie-breaking policy, expected \"order\", \"priority\", or \"error\"",
+            ))
+        }
+    } else if name == "ambiguity_warnings" {
+        Ok(LexerOption::AmbiguityWarnings(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "statistics" {
+        Ok(LexerOption::Statistics(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "layout_profile" {
+        Ok(LexerOption::LayoutProfile(input.parse::<syn::LitStr>()?))
+    } else if name == "snapshot" {
+        Ok(LexerOption::Snapshot(input.parse::<syn::LitStr>()?.value()))
+    } else if name == "case_insensitive" {
+        Ok(LexerOption::CaseInsensitive(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "lalrpop" {
+        Ok(LexerOption::Lalrpop(input.parse::<syn::LitBool>()?.value))
+    } else if name == "dfa_table" {
+        Ok(LexerOption::DfaTable(input.parse::<syn::LitBool>()?.value))
+    } else if name == "grammar_json" {
+        Ok(LexerOption::GrammarJson(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "tag_rule_sets" {
+        Ok(LexerOption::TagRuleSets(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "next_token" {
+        Ok(LexerOption::NextToken(input.parse::<syn::LitBool>()?.value))
+    } else if name == "spanned_tokens" {
+        Ok(LexerOption::SpannedTokens(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "token_kind" {
+        Ok(LexerOption::TokenKind(input.parse::<syn::LitBool>()?.value))
+    } else if name == "counters" {
+        Ok(LexerOption::Counters(input.parse::<syn::LitBool>()?.value))
+    } else if name == "cancellation" {
+        Ok(LexerOption::Cancellation(
+            input.parse::<syn::LitBool>()?.value,
+        ))
+    } else if name == "indentation" {
+        Ok(LexerOption::Indentation(parse_indentation(input)?))
+    } else if name == "on_error" {
+        Ok(LexerOption::OnError(input.parse::<syn::Path>()?))
+    } else if let Some(ty) = GeneratedType::from_option_name(&name.to_string()) {
+        Ok(LexerOption::TypeName(ty, input.parse::<syn::Ident>()?))
+    } else if name == "skip" {
+        let bracketed;
+        syn::bracketed!(bracketed in input);
+        let mut regexes = vec![];
+        while !bracketed.is_empty() {
+            regexes.push(parse_regex_no_ctx(&bracketed)?);
+            if !bracketed.is_empty() {
+                bracketed.parse::<syn::token::Comma>()?;
+            }
+        }
+        Ok(LexerOption::Skip {
+            regexes,
+            action: semantic_action_table.add(RuleRhs::None),
         })
+    } else {
+        Err(syn::Error::new(
+            name.span(),
+            format!("Unknown option \"{}\"", name),
+        ))
+    }
+}
+
+/// Parses value of the `indentation` option:
+/// `{ newline: <expr>, indent: <expr>, dedent: <expr>, whitespace: <whitespace> }`, where
+/// `whitespace` is optional and one of `spaces`, `tabs`, or `tab_width(<int>)`
+fn parse_indentation(input: ParseStream) -> syn::Result<Indentation> {
+    let braced;
+    let brace_token = syn::braced!(braced in input);
+
+    let mut newline: Option<syn::Expr> = None;
+    let mut indent: Option<syn::Expr> = None;
+    let mut dedent: Option<syn::Expr> = None;
+    let mut whitespace: Option<IndentationWhitespace> = None;
+
+    while !braced.is_empty() {
+        let field = braced.parse::<syn::Ident>()?;
+        braced.parse::<syn::token::Colon>()?;
+
+        let duplicate = if field == "newline" {
+            newline.replace(braced.parse::<syn::Expr>()?).is_some()
+        } else if field == "indent" {
+            indent.replace(braced.parse::<syn::Expr>()?).is_some()
+        } else if field == "dedent" {
+            dedent.replace(braced.parse::<syn::Expr>()?).is_some()
+        } else if field == "whitespace" {
+            let value = braced.parse::<syn::Ident>()?;
+            let value = if value == "spaces" {
+                IndentationWhitespace::Spaces
+            } else if value == "tabs" {
+                IndentationWhitespace::Tabs
+            } else if value == "tab_width" {
+                let parenthesized;
+                syn::parenthesized!(parenthesized in braced);
+                let width = parenthesized.parse::<syn::LitInt>()?;
+                match width.base10_parse::<u32>()? {
+                    0 => return Err(syn::Error::new(width.span(), "Tab width can't be 0")),
+                    width => IndentationWhitespace::TabWidth(width),
+                }
+            } else {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "Unknown indentation whitespace, expected \"spaces\", \"tabs\", or \"tab_width(<width>)\"",
+                ));
+            };
+            whitespace.replace(value).is_some()
+        } else {
+            return Err(syn::Error::new(
+                field.span(),
+                "Unknown field, expected \"newline\", \"indent\", \"dedent\", or \"whitespace\"",
+            ));
+        };
+
+        if duplicate {
+            return Err(syn::Error::new(
+                field.span(),
+                format!("Duplicate field \"{}\"", field),
+            ));
+        }
+
+        if !braced.is_empty() {
+            braced.parse::<syn::token::Comma>()?;
+        }
+    }
+
+    let missing_field = |name: &str| {
+        syn::Error::new(
+            brace_token.span,
+            format!("Missing field \"{}\" in `indentation` option", name),
+        )
+    };
+
+    Ok(Indentation {
+        newline: newline.ok_or_else(|| missing_field("newline"))?,
+        indent: indent.ok_or_else(|| missing_field("indent"))?,
+        dedent: dedent.ok_or_else(|| missing_field("dedent"))?,
+        whitespace: whitespace.unwrap_or_default(),
+    })
+}
+
+/// Parser for a single lexer, used in tests
+#[cfg(test)]
+pub fn make_lexer_parser(
+    semantic_action_table: &mut SemanticActionTable,
+) -> impl FnOnce(ParseStream) -> Result<Lexer, syn::Error> + '_ {
+    |input: ParseStream| parse_lexer(input, semantic_action_table)
+}
+
+/// Parses a `lexer!` invocation with one or more lexers, e.g. a strict lexer for a compiler and a
+/// lenient one for an IDE. `let` bindings before the first lexer are shared by all lexers, and
+/// added to the rules of each lexer. Each lexer has its own semantic action table.
+pub fn parse_lexers(input: ParseStream) -> syn::Result<Vec<(Lexer, SemanticActionTable)>> {
+    let mut shared_bindings: Vec<(syn::Ident, RegexCtx)> = vec![];
+    while input.peek(syn::token::Let) {
+        if let Rule::Binding { var, re } = parse_rule(input, &mut SemanticActionTable::new())? {
+            shared_bindings.push((var, re));
+        }
+    }
+
+    let mut lexers = vec![];
+    loop {
+        let mut semantic_action_table = SemanticActionTable::new();
+        let mut lexer = parse_lexer(input, &mut semantic_action_table)?;
+        lexer.rules.splice(
+            0..0,
+            shared_bindings.iter().map(|(var, re)| Rule::Binding {
+                var: var.clone(),
+                re: re.clone(),
+            }),
+        );
+        lexers.push((lexer, semantic_action_table));
+        if input.is_empty() {
+            return Ok(lexers);
+        }
+    }
+}
+
+/// Whether the input starts with a lexer header,
+/// `[#[...]] [pub] <name> [<generics>] [(<state>)] -> <token>;`, which ends the rules of the previous lexer in invocations with multiple lexers
+fn peek_lexer_header(input: ParseStream) -> bool {
+    input.peek(syn::token::Pound)
+        || input.peek(syn::token::Pub)
+        || (input.peek(syn::Ident)
+            && (input.peek2(syn::token::RArrow)
+                || input.peek2(syn::token::Paren)
+                || input.peek2(syn::token::Lt)))
+}
+
+fn parse_lexer(
+    input: ParseStream,
+    semantic_action_table: &mut SemanticActionTable,
+) -> syn::Result<Lexer> {
+    let attrs = input.call(syn::Attribute::parse_outer)?;
+    let visibility = input.parse::<syn::Visibility>()?;
+    let type_name = input.parse::<syn::Ident>()?;
+
+    let generics = input.parse::<syn::Generics>()?;
+    if let Some(lifetime) = generics
+        .lifetimes()
+        .find(|param| param.lifetime.ident == "input")
+    {
+        return Err(syn::Error::new(
+            lifetime.lifetime.span(),
+            "`'input` is the lifetime of the input and can't be declared as a parameter",
+        ));
     }
+
+    let user_state_type = if input.peek(syn::token::Paren) {
+        let parenthesized;
+        syn::parenthesized!(parenthesized in input);
+        Some(parenthesized.parse::<syn::Type>()?)
+    } else {
+        None
+    };
+
+    input.parse::<syn::token::RArrow>()?;
+    let token_type = input.parse::<syn::Type>()?;
+    input.parse::<syn::token::Semi>()?;
+
+    let mut rules = vec![];
+    while !input.is_empty() && !peek_lexer_header(input) {
+        if peek_options_block(input) {
+            rules.extend(parse_options_block(input, semantic_action_table)?);
+        } else if peek_tests_block(input) {
+            rules.push(parse_tests_block(input)?);
+        } else {
+            rules.push(parse_rule(input, semantic_action_table)?);
+        }
+    }
+
+    Ok(Lexer {
+        attrs,
+        visibility,
+        type_name,
+        generics,
+        user_state_type,
+        token_type,
+        rules,
+    })
 }
@@ -1,5 +1,8 @@
 #![allow(non_camel_case_types)]
 
+use crate::ast::{Builtin, CharOrRange, CharSet, Encoding, Regex};
+use crate::unicode_properties;
+
 // NB. We use this type instead of storing `&'static [...]`s directly to make debugging easier.
 #[derive(Debug, Clone, Copy)]
 pub enum BuiltinCharRange {
@@ -76,3 +79,59 @@ impl BuiltinCharRange {
         }
     }
 }
+
+/// Perl-style classes `$$d` (digits), `$$w` (word characters), `$$s` (whitespace), and their
+/// negations. These are replaced with other regexes for the `perl_classes` option, see
+/// `perl_class`.
+pub static PERL_CLASSES: [&str; 6] = ["d", "D", "w", "W", "s", "S"];
+
+/// Definition of a Perl-style class, with the same characters as in the `regex` crate. Returns
+/// `None` for names other than `PERL_CLASSES`, and for Unicode `$$d` and `$$w` without the
+/// `unicode` feature.
+pub fn perl_class(name: &str, encoding: Encoding) -> Option<Regex> {
+    let builtin = |name: &str| Regex::Builtin(Builtin(name.to_owned()));
+    let or = |re1: Regex, re2: Regex| Regex::Or(Box::new(re1), Box::new(re2));
+
+    let class = match (name.to_ascii_lowercase().as_str(), encoding) {
+        ("d", Encoding::Ascii) => builtin("ascii_digit"),
+        ("w", Encoding::Ascii) => Regex::CharSet(CharSet(vec![
+            CharOrRange::Range('a', 'z'),
+            CharOrRange::Range('A', 'Z'),
+            CharOrRange::Range('0', '9'),
+            CharOrRange::Char('_'),
+        ])),
+        // Unlike `$$ascii_whitespace`, includes vertical tab
+        ("s", Encoding::Ascii) => Regex::CharSet(CharSet(vec![
+            CharOrRange::Range('\t', '\r'),
+            CharOrRange::Char(' '),
+        ])),
+        ("d", Encoding::Unicode) if unicode_properties::ENABLED => builtin("p{Nd}"),
+        // Alphabetic, marks, decimal numbers, connector punctuation, and Join_Control. Alphabetic
+        // characters are letters, letter numbers, mostly marks, and the circled and squared
+        // letters below. `$$alphabetic` is not used as it's generated from an older Unicode
+        // version than the general category tables.
+        ("w", Encoding::Unicode) if unicode_properties::ENABLED => [
+            builtin("p{M}"),
+            builtin("p{Nd}"),
+            builtin("p{Nl}"),
+            builtin("p{Pc}"),
+            Regex::CharSet(CharSet(vec![
+                CharOrRange::Range('\u{200C}', '\u{200D}'),
+                CharOrRange::Range('\u{24B6}', '\u{24E9}'),
+                CharOrRange::Range('\u{1F130}', '\u{1F149}'),
+                CharOrRange::Range('\u{1F150}', '\u{1F169}'),
+                CharOrRange::Range('\u{1F170}', '\u{1F189}'),
+            ])),
+        ]
+        .into_iter()
+        .fold(builtin("p{L}"), or),
+        ("s", Encoding::Unicode) => builtin("whitespace"),
+        _ => return None,
+    };
+
+    if name.chars().all(|c| c.is_ascii_uppercase()) {
+        Some(Regex::Diff(Box::new(Regex::Any), Box::new(class)))
+    } else {
+        Some(class)
+    }
+}
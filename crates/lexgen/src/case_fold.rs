@@ -0,0 +1,86 @@
+//! Unicode simple case folding, used to compile case-insensitive lexers (`case_insensitive = true;`)
+//!
+//! Two characters match case-insensitively when they have the same simple case folding, e.g. `k`,
+//! `K`, and `K` (Kelvin sign), or `ß` and `ẞ`. Full case foldings that map a character to multiple
+//! characters (`ß` to `ss`, `İ` to `i̇`) are not used, so `ß` doesn't match `SS`, and `İ` only
+//! matches itself.
+//!
+//! Case foldings are computed from the case mappings of the standard library, as simple case
+//! folding of a character is the lowercase of its uppercase when both are single characters, with
+//! the exception of Turkic `ı`.
+
+use crate::collections::Map;
+
+use std::sync::OnceLock;
+
+/// Characters that have the same simple case folding as another character, sorted, mapped to the
+/// other characters with the same case folding
+static CASE_FOLD_CLASSES: OnceLock<Vec<(char, Vec<char>)>> = OnceLock::new();
+
+/// Characters that match `char` case-insensitively, other than `char`
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn case_fold_class(char: char) -> &'static [char] {
+    let classes = case_fold_classes();
+    match classes.binary_search_by_key(&char, |(char, _)| *char) {
+        Ok(idx) => &classes[idx].1,
+        Err(_) => &[],
+    }
+}
+
+/// Characters in the inclusive range that match other characters case-insensitively, with the
+/// other characters
+pub fn case_fold_classes_in_range(
+    start: u32,
+    end: u32,
+) -> impl Iterator<Item = (char, &'static [char])> {
+    let classes = case_fold_classes();
+    let first = classes.partition_point(|(char, _)| (*char as u32) < start);
+    classes[first..]
+        .iter()
+        .take_while(move |(char, _)| *char as u32 <= end)
+        .map(|(char, class)| (*char, class.as_slice()))
+}
+
+fn case_fold_classes() -> &'static [(char, Vec<char>)] {
+    CASE_FOLD_CLASSES.get_or_init(|| {
+        // Maps case foldings to the characters with the case folding
+        let mut folds: Map<char, Vec<char>> = Default::default();
+        for char in (0..=char::MAX as u32).filter_map(char::from_u32) {
+            let fold = simple_case_fold(char);
+            if fold != char {
+                folds.entry(fold).or_insert_with(|| vec![fold]).push(char);
+            }
+        }
+
+        let mut classes: Vec<(char, Vec<char>)> = vec![];
+        for class in folds.into_values() {
+            for char in &class {
+                let others = class
+                    .iter()
+                    .copied()
+                    .filter(|other| other != char)
+                    .collect();
+                classes.push((*char, others));
+            }
+        }
+        classes.sort_unstable();
+        classes
+    })
+}
+
+fn simple_case_fold(char: char) -> char {
+    // Uppercase of Turkic dotless `ı` is `I`, but it's only folded to `i` with Turkic foldings
+    if char == 'ı' {
+        return char;
+    }
+    let upper = single_char(char.to_uppercase()).unwrap_or(char);
+    single_char(upper.to_lowercase()).unwrap_or(upper)
+}
+
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let char = chars.next()?;
+    match chars.next() {
+        Some(_) => None,
+        None => Some(char),
+    }
+}
@@ -0,0 +1,1014 @@
+//! Compilation of a lexer definition to a DFA. Shared by the `lexer!` macro and the `lexgen` CLI.
+
+use crate::ast::{
+    Builtin, Encoding, InvalidUtf8, Lexer, Regex, RegexCtx, Rule, SingleRule, TieBreaking, Var,
+    INVALID_UTF8_BUILTIN,
+};
+use crate::builtin::{perl_class, PERL_CLASSES};
+use crate::collections::{Map, Set};
+use crate::dfa::analysis::{conflicts, uncovered_chars};
+use crate::dfa::{StateIdx as DfaStateIdx, DFA};
+use crate::diagnostics::RuleInfo;
+use crate::display::CharRangesDisplay;
+use crate::nfa::{AcceptingState, NFA};
+use crate::nfa_to_dfa::{nfa_to_dfa, nfas_to_dfas};
+use crate::options::Options;
+use crate::regex_simplify::simplify;
+use crate::right_ctx::RightCtxDFAs;
+use crate::semantic_action_table::SemanticActionIdx;
+#[cfg(feature = "unicode")]
+use crate::{ast::MatchUnit, grapheme, normalization};
+
+use std::collections::hash_map::Entry;
+
+use proc_macro2::Span;
+use syn::spanned::Spanned;
+
+/// Groups are enabled and disabled with bits of a `u64` in the lexer
+const MAX_GROUPS: usize = 64;
+
+/// A lexer definition compiled to a DFA, before simplification and code generation
+pub struct CompiledLexer {
+    pub attrs: Vec<syn::Attribute>,
+    pub visibility: syn::Visibility,
+    pub type_name: syn::Ident,
+    pub generics: syn::Generics,
+    pub user_state_type: Option<syn::Type>,
+    pub user_error_type: Option<syn::Type>,
+    pub token_type: syn::Type,
+    pub options: Options,
+
+    /// Names of rule sets, in the order they're defined. First one is always `Init`.
+    pub rule_set_names: Vec<syn::Ident>,
+
+    /// NFAs of rule sets in `rule_set_names`
+    pub nfas: Vec<NFA<SemanticActionIdx>>,
+
+    /// DFA of all rule sets
+    pub dfa: DFA<DfaStateIdx, SemanticActionIdx>,
+
+    /// Maps rule set names to their initial states in `dfa`
+    pub rule_sets: Map<String, DfaStateIdx>,
+
+    /// DFAs generated for right contexts
+    pub right_ctx_dfas: RightCtxDFAs<DfaStateIdx>,
+
+    /// All rules in the lexer, used in diagnostics
+    pub rules: Vec<RuleInfo>,
+
+    /// Semantic action of the `$$invalid_utf8` rule, with `invalid_utf8 = rule;`
+    pub invalid_utf8_action: Option<SemanticActionIdx>,
+
+    /// Maps rule set names to matcher functions and semantic actions of their `fn <matcher>`
+    /// rules, in the order they're defined
+    pub custom_matchers: Map<String, Vec<(syn::Path, SemanticActionIdx)>>,
+
+    /// Guard functions of `<regex> if <guard>` rules
+    pub guards: Map<SemanticActionIdx, syn::Path>,
+
+    /// Names of `group <name> { ... }` groups, in the order they're first used. Index of a group
+    /// in this list is its bit in the lexer's disabled groups mask.
+    pub group_names: Vec<syn::Ident>,
+
+    /// Maps semantic actions of rules in groups to indices of their groups in `group_names`
+    pub rule_groups: Map<SemanticActionIdx, usize>,
+
+    /// Maps rule set names to semantic actions of their `fail <rhs>` rules
+    pub fail_actions: Map<String, SemanticActionIdx>,
+
+    /// Type of the parser feedback, `type Feedback = ...;`
+    pub feedback_type: Option<syn::Type>,
+
+    /// Type of the interner used by `intern` in semantic actions, `type Interner = ...;`
+    pub interner_type: Option<syn::Type>,
+
+    /// DFAs of the parts of rules with captures (`(<regex> as <name>)`), with the capture names
+    /// of the captured parts, used to split the matches of the rules into the parts
+    pub captures: Map<SemanticActionIdx, CaptureParts<DFA<DfaStateIdx, ()>>>,
+}
+
+/// Parts of the regex of a rule with captures, with the capture names of the captured parts. See
+/// `capture_parts`.
+pub type CaptureParts<R> = Vec<(Option<String>, R)>;
+
+/// Returns a function that describes the rule of an accepting state, used in DOT graphs and DFA
+/// reports
+pub fn accepting_state_label(
+    rules: &[RuleInfo],
+) -> impl Fn(&AcceptingState<SemanticActionIdx>) -> String {
+    let rule_descriptions: Map<SemanticActionIdx, String> = rules
+        .iter()
+        .map(|rule| (rule.action, rule.describe()))
+        .collect();
+
+    move |accepting_state| {
+        let mut description = rule_descriptions[&accepting_state.value].clone();
+        if let Some(right_ctx) = accepting_state.right_ctx {
+            description.push_str(&format!(" (right ctx {})", right_ctx.as_usize()));
+        }
+        if accepting_state.guarded {
+            description.push_str(" (guard)");
+        }
+        description
+    }
+}
+
+/// Returns a function that names rules, used to name DFA states in DOT graphs and DFA reports
+pub fn rule_name(rules: &[RuleInfo]) -> impl Fn(&SemanticActionIdx) -> String {
+    let rule_names: Map<SemanticActionIdx, String> = rules
+        .iter()
+        .map(|rule| (rule.action, rule.name()))
+        .collect();
+
+    move |rule| rule_names[rule].clone()
+}
+
+pub fn compile(lexer: Lexer) -> syn::Result<CompiledLexer> {
+    let Lexer {
+        attrs,
+        visibility,
+        type_name,
+        generics,
+        user_state_type,
+        token_type,
+        rules: top_level_rules,
+    } = lexer;
+
+    // DFAs generated for right contexts
+    let mut right_ctx_dfas = RightCtxDFAs::new();
+
+    let mut bindings: Map<Var, Regex> = Default::default();
+
+    // NFAs of rule sets, in the order they're defined. First one is always `Init`. These are
+    // determinized in parallel after processing all rules, as they're independent of each other.
+    let mut rule_set_nfas: Vec<(syn::Ident, NFA<SemanticActionIdx>)> = vec![];
+
+    let mut rules: Vec<RuleInfo> = vec![];
+
+    let mut user_error_type: Option<syn::Type> = None;
+
+    let mut feedback_type: Option<syn::Type> = None;
+    let mut interner_type: Option<syn::Type> = None;
+
+    let mut invalid_utf8_action: Option<SemanticActionIdx> = None;
+
+    let mut custom_matchers: Map<String, Vec<(syn::Path, SemanticActionIdx)>> = Default::default();
+
+    let mut guards: Map<SemanticActionIdx, syn::Path> = Default::default();
+    let mut group_names: Vec<syn::Ident> = vec![];
+    let mut rule_groups: Map<SemanticActionIdx, usize> = Default::default();
+
+    let mut fail_actions: Map<String, SemanticActionIdx> = Default::default();
+
+    let mut captures: Map<SemanticActionIdx, CaptureParts<DFA<DfaStateIdx, ()>>> =
+        Default::default();
+
+    let mut options = Options::default();
+
+    let have_named_rules = top_level_rules
+        .iter()
+        .any(|rule| matches!(rule, Rule::RuleSet { .. }));
+
+    // Options are set first, as they affect how rules are compiled
+    let (option_rules, top_level_rules): (Vec<Rule>, Vec<Rule>) = top_level_rules
+        .into_iter()
+        .partition(|rule| matches!(rule, Rule::Option { .. }));
+
+    for rule in option_rules {
+        if let Rule::Option { name, option } = rule {
+            options.set(&name, option)?;
+        }
+    }
+
+    // Rules added to multiple rule sets, grouped by rule set, in the order the rule sets are first
+    // listed. The identifier of the first use of the rule set name is used to report undefined
+    // rule sets.
+    let (shared_rules, top_level_rules): (Vec<Rule>, Vec<Rule>) = top_level_rules
+        .into_iter()
+        .partition(|rule| matches!(rule, Rule::SharedRule { .. }));
+
+    let mut shared_rules_by_rule_set: Vec<(syn::Ident, Vec<SingleRule>)> = vec![];
+
+    for rule in shared_rules {
+        if let Rule::SharedRule { rule_sets, rule } = rule {
+            for name in rule_sets {
+                match shared_rules_by_rule_set
+                    .iter_mut()
+                    .find(|(rule_set_name, _)| *rule_set_name == name)
+                {
+                    Some((_, rules)) => rules.push(rule.clone()),
+                    None => shared_rules_by_rule_set.push((name, vec![rule.clone()])),
+                }
+            }
+        }
+    }
+
+    // Variables are processed before the rules, so that rules can use variables defined after them
+    let (binding_rules, top_level_rules): (Vec<Rule>, Vec<Rule>) = top_level_rules
+        .into_iter()
+        .partition(|rule| matches!(rule, Rule::Binding { .. }));
+
+    let mut binding_names: Vec<syn::Ident> = Vec::with_capacity(binding_rules.len());
+
+    for rule in binding_rules {
+        if let Rule::Binding { var, re } = rule {
+            match bindings.entry(Var(var.to_string())) {
+                Entry::Occupied(_) => {
+                    return Err(syn::Error::new(
+                        var.span(),
+                        format!("Variable {:?} is defined multiple times", var.to_string()),
+                    ));
+                }
+                Entry::Vacant(entry) => {
+                    // TODO: Check that regex doesn't have right context
+                    check_ascii(&re, &options)?;
+                    if re.re.has_captures() {
+                        return Err(syn::Error::new(
+                            var.span(),
+                            "Captures (`(<regex> as <name>)`) can't be used in variables",
+                        ));
+                    }
+                    let bound_var = entry.key().clone();
+                    entry.insert(simplify(&resolve_perl_classes(
+                        &re.re,
+                        &options,
+                        var.span(),
+                    )?));
+
+                    // Variables can be used before they're defined, so a binding can introduce a
+                    // cycle. Any cycle goes through the binding that introduced it, so it's
+                    // enough to check cycles from the new binding.
+                    if let Some(cycle) = find_binding_cycle(&bindings, &bound_var) {
+                        let cycle: Vec<&str> = cycle.iter().map(|var| var.0.as_str()).collect();
+                        return Err(syn::Error::new(
+                            var.span(),
+                            format!(
+                                "Variable {:?} is defined in terms of itself: {}",
+                                var.to_string(),
+                                cycle.join(" -> "),
+                            ),
+                        ));
+                    }
+                }
+            }
+            binding_names.push(var);
+        }
+    }
+
+    // Report undefined variables here, before NFA construction, which expects all variables used
+    // in regexes to be defined
+    for name in &binding_names {
+        check_vars_defined(&bindings[&Var(name.to_string())], &bindings, name.span())?;
+    }
+
+    for rule in top_level_rules {
+        match rule {
+            Rule::RuleSet {
+                name,
+                rules: mut rule_set,
+                exclusive,
+                nested,
+            } => {
+                if name != "Init" && rule_set_nfas.is_empty() {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "First rule set should be named \"Init\"",
+                    ));
+                }
+
+                if rule_set_nfas
+                    .iter()
+                    .any(|(rule_set_name, _)| *rule_set_name == name)
+                {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!("Rule set {:?} is defined multiple times", name.to_string()),
+                    ));
+                }
+
+                // The nesting depth is kept in the lexer, which `next_token` creates for each token
+                if nested && options.next_token() {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "`nested` rule sets are not supported with `next_token = true;`",
+                    ));
+                }
+
+                rule_set.extend(take_shared_rules(&mut shared_rules_by_rule_set, &name));
+
+                if !exclusive {
+                    rule_set.extend(skip_rules(&options));
+                }
+
+                let rule_set =
+                    take_invalid_utf8_rule(&name, rule_set, &options, &mut invalid_utf8_action)?;
+
+                let rule_set =
+                    take_custom_matchers(&name.to_string(), rule_set, &mut custom_matchers);
+
+                let rule_set = take_fail_rule(&name.to_string(), rule_set, &mut fail_actions)?;
+
+                let nfa = compile_rules(
+                    &name.to_string(),
+                    rule_set,
+                    &bindings,
+                    &options,
+                    &mut right_ctx_dfas,
+                    &mut rules,
+                    &mut guards,
+                    &mut group_names,
+                    &mut rule_groups,
+                    &mut captures,
+                )?;
+                rule_set_nfas.push((name, nfa));
+            }
+            Rule::UnnamedRules {
+                span,
+                rules: mut rule_set,
+            } => {
+                if !rule_set_nfas.is_empty() || have_named_rules {
+                    return Err(syn::Error::new(
+                        span,
+                        "Unnamed rules cannot be mixed with named rules. Make sure to either \
+                        have all your rules in `rule ... { ... }` syntax, or remove `rule`s \
+                        entirely and have your rules at the top-level.",
+                    ));
+                }
+
+                rule_set.extend(take_shared_rules(
+                    &mut shared_rules_by_rule_set,
+                    &syn::Ident::new("Init", span),
+                ));
+
+                rule_set.extend(skip_rules(&options));
+
+                let rule_set = take_invalid_utf8_rule(
+                    &syn::Ident::new("Init", span),
+                    rule_set,
+                    &options,
+                    &mut invalid_utf8_action,
+                )?;
+
+                let rule_set = take_custom_matchers("Init", rule_set, &mut custom_matchers);
+
+                let rule_set = take_fail_rule("Init", rule_set, &mut fail_actions)?;
+
+                let nfa = compile_rules(
+                    "Init",
+                    rule_set,
+                    &bindings,
+                    &options,
+                    &mut right_ctx_dfas,
+                    &mut rules,
+                    &mut guards,
+                    &mut group_names,
+                    &mut rule_groups,
+                    &mut captures,
+                )?;
+                rule_set_nfas.push((syn::Ident::new("Init", span), nfa));
+            }
+            Rule::ErrorType { ty } => match user_error_type {
+                None => {
+                    user_error_type = Some(ty);
+                }
+                Some(_) => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "Error type defined multiple times",
+                    ));
+                }
+            },
+            Rule::FeedbackType { ty } => match feedback_type {
+                None => {
+                    feedback_type = Some(ty);
+                }
+                Some(_) => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "Feedback type defined multiple times",
+                    ));
+                }
+            },
+            Rule::InternerType { ty } => match interner_type {
+                None => {
+                    interner_type = Some(ty);
+                }
+                Some(_) => {
+                    return Err(syn::Error::new(
+                        ty.span(),
+                        "Interner type defined multiple times",
+                    ));
+                }
+            },
+            Rule::Binding { .. }
+            | Rule::Option { .. }
+            | Rule::SharedRule { .. }
+            | Rule::Tests { .. } => {}
+        }
+    }
+
+    // Shared rules of defined rule sets are taken above, report the first undefined rule set
+    if let Some((name, _)) = shared_rules_by_rule_set.first() {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("Rule set \"{}\" is not defined", name),
+        ));
+    }
+
+    // There should be a rule with name "Init"
+    if rule_set_nfas.is_empty() {
+        return Err(syn::Error::new(
+            type_name.span(),
+            "There should be a rule set named \"Init\"",
+        ));
+    }
+
+    if let Some((InvalidUtf8::Rule, span)) = options.invalid_utf8() {
+        if invalid_utf8_action.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "`invalid_utf8 = rule;` requires a `$$invalid_utf8` rule in rule set \"Init\"",
+            ));
+        }
+    }
+
+    let (rule_set_names, mut nfas): (Vec<syn::Ident>, Vec<NFA<SemanticActionIdx>>) =
+        rule_set_nfas.into_iter().unzip();
+
+    if options.encoding() == Encoding::Ascii {
+        for nfa in &mut nfas {
+            nfa.restrict_to_ascii();
+        }
+    }
+
+    let mut dfa: Option<DFA<DfaStateIdx, SemanticActionIdx>> = None;
+    let mut rule_sets: Map<String, DfaStateIdx> = Default::default();
+
+    for (name, dfa_) in rule_set_names.iter().zip(nfas_to_dfas(&nfas)) {
+        let initial_state = match dfa.as_mut() {
+            None => dfa.insert(dfa_).initial_state(),
+            Some(dfa) => dfa.add_dfa(dfa_),
+        };
+        rule_sets.insert(name.to_string(), initial_state);
+    }
+
+    let mut dfa = dfa.unwrap();
+
+    match options.tie_breaking() {
+        TieBreaking::Order => {}
+        TieBreaking::Priority => {
+            let priorities: Map<SemanticActionIdx, i64> = rules
+                .iter()
+                .map(|rule| (rule.action, rule.priority))
+                .collect();
+            dfa.sort_accepting_states(|accepting_state| {
+                std::cmp::Reverse(priorities[&accepting_state.value])
+            });
+        }
+        TieBreaking::Error => check_ties(&dfa, &rules)?,
+    }
+
+    let shortest_actions: Set<SemanticActionIdx> = rules
+        .iter()
+        .filter(|rule| rule.shortest)
+        .map(|rule| rule.action)
+        .collect();
+
+    if !shortest_actions.is_empty() {
+        dfa.stop_at_accepting_states(|accepting_state| {
+            shortest_actions.contains(&accepting_state.value)
+        });
+    }
+
+    if let Some((coverage, span)) = options.init_coverage() {
+        let max = match coverage {
+            Encoding::Ascii => '\x7F',
+            Encoding::Unicode => char::MAX,
+        };
+
+        let uncovered = uncovered_chars(&dfa, max);
+
+        if !uncovered.is_empty() {
+            // Unicode ranges can be long, show the first few
+            const MAX_RANGES: usize = 10;
+            let more = if uncovered.len() > MAX_RANGES {
+                format!(" and {} more ranges", uncovered.len() - MAX_RANGES)
+            } else {
+                String::new()
+            };
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "Rule set \"Init\" doesn't handle characters {}{}. Add rules for the \
+                    characters, or a catch-all rule with `_`.",
+                    CharRangesDisplay(&uncovered[..uncovered.len().min(MAX_RANGES)]),
+                    more,
+                ),
+            ));
+        }
+    }
+
+    Ok(CompiledLexer {
+        attrs,
+        visibility,
+        type_name,
+        generics,
+        user_state_type,
+        user_error_type,
+        token_type,
+        options,
+        rule_set_names,
+        nfas,
+        dfa,
+        rule_sets,
+        right_ctx_dfas,
+        rules,
+        invalid_utf8_action,
+        custom_matchers,
+        guards,
+        group_names,
+        rule_groups,
+        fail_actions,
+        feedback_type,
+        interner_type,
+        captures,
+    })
+}
+
+/// Removes the rules added to rule set `name` with `<...> <rule>` syntax from `shared_rules`
+fn take_shared_rules(
+    shared_rules: &mut Vec<(syn::Ident, Vec<SingleRule>)>,
+    name: &syn::Ident,
+) -> Vec<SingleRule> {
+    match shared_rules
+        .iter()
+        .position(|(rule_set_name, _)| rule_set_name == name)
+    {
+        Some(idx) => shared_rules.remove(idx).1,
+        None => vec![],
+    }
+}
+
+/// Reports rules that match the same input as another rule, for `tie_breaking = error;`. The
+/// error is reported at the first such rule.
+fn check_ties(dfa: &DFA<DfaStateIdx, SemanticActionIdx>, rules: &[RuleInfo]) -> syn::Result<()> {
+    let conflicts = conflicts(dfa);
+
+    let rules_by_action: Map<SemanticActionIdx, &RuleInfo> =
+        rules.iter().map(|rule| (rule.action, rule)).collect();
+
+    for rule in rules {
+        // Rules of the `skip` option share a semantic action, ties between them are fine
+        let tie = conflicts
+            .iter()
+            .filter(|((winner, loser), _)| *loser == rule.action && *winner != rule.action)
+            .map(|((winner, _), input)| (rules_by_action[winner], input))
+            .min_by_key(|(winner, _)| (&winner.rule_set, winner.position));
+
+        if let Some((winner, input)) = tie {
+            let input = match input {
+                Some(input) => format!("{:?}", input),
+                None => "the same input".to_owned(),
+            };
+            return Err(syn::Error::new(
+                rule.span,
+                format!(
+                    "This rule and {} both match {}. Rules can't match the same input with \
+                    `tie_breaking = error;`.",
+                    winner.describe(),
+                    input,
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the `$$invalid_utf8` rule from the rules of a rule set. Invalid UTF-8 is not a
+/// character, so the rule is not compiled to the DFA, and the lexer calls its semantic action
+/// directly.
+fn take_invalid_utf8_rule(
+    rule_set_name: &syn::Ident,
+    rules: Vec<SingleRule>,
+    options: &Options,
+    invalid_utf8_action: &mut Option<SemanticActionIdx>,
+) -> syn::Result<Vec<SingleRule>> {
+    let mut other_rules = Vec::with_capacity(rules.len());
+
+    for rule in rules {
+        if !matches!(&rule.lhs.re, Regex::Builtin(Builtin(name)) if name == INVALID_UTF8_BUILTIN) {
+            other_rules.push(rule);
+            continue;
+        }
+
+        if !matches!(options.invalid_utf8(), Some((InvalidUtf8::Rule, _))) {
+            return Err(syn::Error::new(
+                rule.span,
+                "`$$invalid_utf8` rules require `invalid_utf8 = rule;`",
+            ));
+        }
+
+        if rule_set_name != "Init" {
+            return Err(syn::Error::new(
+                rule.span,
+                "`$$invalid_utf8` rule should be in rule set \"Init\"",
+            ));
+        }
+
+        if invalid_utf8_action.replace(rule.rhs).is_some() {
+            return Err(syn::Error::new(
+                rule.span,
+                "`$$invalid_utf8` rule is defined multiple times",
+            ));
+        }
+    }
+
+    Ok(other_rules)
+}
+
+/// Removes `fn <matcher>` rules from the rules of a rule set. These are not compiled to the DFA,
+/// the lexer calls their matchers at the start of each match in the rule set.
+fn take_custom_matchers(
+    rule_set_name: &str,
+    rules: Vec<SingleRule>,
+    custom_matchers: &mut Map<String, Vec<(syn::Path, SemanticActionIdx)>>,
+) -> Vec<SingleRule> {
+    let mut other_rules = Vec::with_capacity(rules.len());
+
+    for rule in rules {
+        match rule.matcher {
+            Some(matcher) => custom_matchers
+                .entry(rule_set_name.to_owned())
+                .or_default()
+                .push((matcher, rule.rhs)),
+            None => other_rules.push(rule),
+        }
+    }
+
+    other_rules
+}
+
+/// Removes the `fail <rhs>` rule from the rules of a rule set. The rule doesn't match any input,
+/// the lexer calls its semantic action when no other rule in the rule set matches.
+fn take_fail_rule(
+    rule_set_name: &str,
+    rules: Vec<SingleRule>,
+    fail_actions: &mut Map<String, SemanticActionIdx>,
+) -> syn::Result<Vec<SingleRule>> {
+    let mut other_rules = Vec::with_capacity(rules.len());
+
+    for rule in rules {
+        if !rule.fail {
+            other_rules.push(rule);
+            continue;
+        }
+
+        if fail_actions
+            .insert(rule_set_name.to_owned(), rule.rhs)
+            .is_some()
+        {
+            return Err(syn::Error::new(
+                rule.span,
+                format!("Rule set {:?} has multiple `fail` rules", rule_set_name),
+            ));
+        }
+    }
+
+    Ok(other_rules)
+}
+
+/// Rules of the `skip` option, added to the end of rule sets without the `exclusive` modifier
+fn skip_rules(options: &Options) -> Vec<SingleRule> {
+    match options.skip() {
+        None => vec![],
+        Some((regexes, action, span)) => regexes
+            .iter()
+            .map(|re| SingleRule {
+                lhs: re.clone(),
+                rhs: action,
+                span,
+                matcher: None,
+                guard: None,
+                fail: false,
+                priority: None,
+                shortest: false,
+                group: None,
+            })
+            .collect(),
+    }
+}
+
+fn compile_rules(
+    rule_set_name: &str,
+    rules: Vec<SingleRule>,
+    bindings: &Map<Var, Regex>,
+    options: &Options,
+    right_ctx_dfas: &mut RightCtxDFAs<DfaStateIdx>,
+    rule_infos: &mut Vec<RuleInfo>,
+    guards: &mut Map<SemanticActionIdx, syn::Path>,
+    group_names: &mut Vec<syn::Ident>,
+    rule_groups: &mut Map<SemanticActionIdx, usize>,
+    captures: &mut Map<SemanticActionIdx, CaptureParts<DFA<DfaStateIdx, ()>>>,
+) -> syn::Result<NFA<SemanticActionIdx>> {
+    let mut nfa: NFA<SemanticActionIdx> = NFA::new();
+
+    for (
+        rule_idx,
+        SingleRule {
+            lhs,
+            rhs,
+            span,
+            guard,
+            priority,
+            shortest,
+            group,
+            ..
+        },
+    ) in rules.into_iter().enumerate()
+    {
+        if let Some((_, priority_span)) = priority {
+            if options.tie_breaking() != TieBreaking::Priority {
+                return Err(syn::Error::new(
+                    priority_span,
+                    "Rule priorities require `tie_breaking = priority;`",
+                ));
+            }
+        }
+
+        rule_infos.push(RuleInfo {
+            action: rhs,
+            span,
+            rule_set: rule_set_name.to_owned(),
+            position: rule_idx + 1,
+            priority: priority.map_or(0, |(priority, _)| priority),
+            shortest,
+            right_ctx: lhs.right_ctx.is_some(),
+        });
+
+        check_ascii(&lhs, options)?;
+
+        let RegexCtx { re, right_ctx, .. } = lhs;
+
+        check_vars_defined(&re, bindings, span)?;
+        if let Some(right_ctx) = &right_ctx {
+            check_vars_defined(right_ctx, bindings, span)?;
+        }
+
+        let re = resolve_perl_classes(&re, options, span)?;
+        let re = simplify(&apply_unicode_options(re, bindings, options));
+
+        if let Some(parts) = capture_parts(&re, span)? {
+            let dfas = parts
+                .into_iter()
+                .map(|(name, part)| {
+                    let mut nfa: NFA<()> = NFA::new();
+                    nfa.add_regex(bindings, &part, None, ());
+                    if options.case_insensitive() {
+                        nfa.case_fold();
+                    }
+                    (name, nfa_to_dfa(&nfa))
+                })
+                .collect();
+            captures.insert(rhs, dfas);
+        }
+
+        let right_ctx = match right_ctx {
+            None => None,
+            Some(right_ctx) => {
+                if right_ctx.has_captures() {
+                    return Err(syn::Error::new(
+                        span,
+                        "Captures (`(<regex> as <name>)`) can't be used in right contexts",
+                    ));
+                }
+                let right_ctx = resolve_perl_classes(&right_ctx, options, span)?;
+                Some(right_ctx_dfas.new_right_ctx(
+                    bindings,
+                    &simplify(&apply_unicode_options(right_ctx, bindings, options)),
+                    options.case_insensitive(),
+                ))
+            }
+        };
+
+        // Rules in groups are guarded by the group's bit in the lexer
+        let guarded = guard.is_some() || group.is_some();
+        nfa.add_guarded_regex(bindings, &re, right_ctx, guarded, rhs);
+
+        if let Some(guard) = guard {
+            guards.insert(rhs, guard);
+        }
+
+        if let Some(group) = group {
+            let group_idx = match group_names.iter().position(|name| *name == group) {
+                Some(group_idx) => group_idx,
+                None => {
+                    if group_names.len() == MAX_GROUPS {
+                        return Err(syn::Error::new(
+                            group.span(),
+                            format!("Lexers can have at most {} groups", MAX_GROUPS),
+                        ));
+                    }
+                    group_names.push(group);
+                    group_names.len() - 1
+                }
+            };
+            rule_groups.insert(rhs, group_idx);
+        }
+    }
+
+    if options.case_insensitive() {
+        nfa.case_fold();
+    }
+
+    Ok(nfa)
+}
+
+/// Split the regex of a rule with captures into the parts before, between, and after the
+/// captures, and the captured parts. Returns `None` when the regex doesn't have captures.
+///
+/// Captures can only be used in the top-level concatenation of the regex, as the parts are matched
+/// one after another after the rule matches.
+fn capture_parts(re: &Regex, span: Span) -> syn::Result<Option<CaptureParts<Regex>>> {
+    if !re.has_captures() {
+        return Ok(None);
+    }
+
+    fn concat_items<'a>(re: &'a Regex, items: &mut Vec<&'a Regex>) {
+        match re {
+            Regex::Concat(re1, re2) => {
+                concat_items(re1, items);
+                concat_items(re2, items);
+            }
+            _ => items.push(re),
+        }
+    }
+
+    let mut items = vec![];
+    concat_items(re, &mut items);
+
+    let mut parts: CaptureParts<Regex> = vec![];
+
+    for item in items {
+        match item {
+            Regex::Capture(captured, name) => {
+                if captured.has_captures() {
+                    return Err(syn::Error::new(
+                        span,
+                        format!(
+                            "Capture {:?} has captures in it. Captures can't be nested.",
+                            name
+                        ),
+                    ));
+                }
+                if parts
+                    .iter()
+                    .any(|(part_name, _)| part_name.as_ref() == Some(name))
+                {
+                    return Err(syn::Error::new(
+                        span,
+                        format!("Capture {:?} is defined multiple times", name),
+                    ));
+                }
+                parts.push((Some(name.clone()), (**captured).clone()));
+            }
+            _ if item.has_captures() => {
+                return Err(syn::Error::new(
+                    span,
+                    "Captures (`(<regex> as <name>)`) can only be used in the top-level \
+                    concatenation of a rule's regex, not in `|`, `*`, `+`, or `?`",
+                ));
+            }
+            _ => match parts.last_mut() {
+                Some((None, part)) => {
+                    *part = Regex::Concat(Box::new(part.clone()), Box::new(item.clone()));
+                }
+                _ => parts.push((None, item.clone())),
+            },
+        }
+    }
+
+    Ok(Some(parts))
+}
+
+/// Reports the first non-ASCII character used in `re` in lexers with `encoding = ascii;`. Non-ASCII
+/// parts of character sets and built-in regexes are removed from the NFA, but a non-ASCII
+/// character given in a regex could never match.
+fn check_ascii(re: &RegexCtx, options: &Options) -> syn::Result<()> {
+    if options.encoding() != Encoding::Ascii {
+        return Ok(());
+    }
+    match re.non_ascii_chars.first() {
+        None => Ok(()),
+        Some((char, span)) => Err(syn::Error::new(
+            *span,
+            format!("Non-ASCII character {:?} used in an ASCII lexer", char),
+        )),
+    }
+}
+
+/// Reports the first variable used in `re` that is not defined. `span` is the span of the rule or
+/// variable the regex belongs to, used in the error.
+fn check_vars_defined(re: &Regex, bindings: &Map<Var, Regex>, span: Span) -> syn::Result<()> {
+    let mut undefined: Option<Var> = None;
+
+    re.for_each_var(&mut |var| {
+        if undefined.is_none() && !bindings.contains_key(var) {
+            undefined = Some(var.clone());
+        }
+    });
+
+    match undefined {
+        None => Ok(()),
+        Some(var) => Err(syn::Error::new(
+            span,
+            format!("Variable {:?} is not defined", var.0),
+        )),
+    }
+}
+
+/// Replace Perl-style classes (`$$d`, `$$w`, `$$s`, and their negations) with their definitions
+/// for the `perl_classes` option. `span` is the span of the rule or variable, used in errors.
+fn resolve_perl_classes(re: &Regex, options: &Options, span: Span) -> syn::Result<Regex> {
+    let mut unsupported = false;
+
+    let re = re.map_builtins(&mut |Builtin(name)| {
+        if !PERL_CLASSES.contains(&name.as_str()) {
+            return None;
+        }
+        let class = perl_class(name, options.perl_classes());
+        unsupported |= class.is_none();
+        class
+    });
+
+    if unsupported {
+        return Err(syn::Error::new(
+            span,
+            "Unicode `$$d` and `$$w` require the \"unicode\" feature of lexgen. Use \
+            `perl_classes = ascii;` for ASCII digits and word characters.",
+        ));
+    }
+
+    Ok(re)
+}
+
+/// Rewrite a regex for the `match_unit` and `normalization_insensitive` options. These are only
+/// available with the `unicode` feature.
+#[cfg_attr(not(feature = "unicode"), allow(unused_variables, unused_mut))]
+fn apply_unicode_options(mut re: Regex, bindings: &Map<Var, Regex>, options: &Options) -> Regex {
+    #[cfg(feature = "unicode")]
+    {
+        if options.match_unit() == MatchUnit::Grapheme {
+            re = grapheme::any_to_grapheme_clusters(&re, bindings);
+        }
+        if options.normalization_insensitive() {
+            re = normalization::expand_canonical_equivalents(&re, bindings);
+        }
+    }
+    re
+}
+
+/// If `var` is used in its own definition, directly or via other variables, returns the variables
+/// in the cycle, starting and ending with `var`.
+pub fn find_binding_cycle(bindings: &Map<Var, Regex>, var: &Var) -> Option<Vec<Var>> {
+    fn go(
+        bindings: &Map<Var, Regex>,
+        target: &Var,
+        current: &Var,
+        path: &mut Vec<Var>,
+        visited: &mut Set<Var>,
+    ) -> bool {
+        let re = match bindings.get(current) {
+            Some(re) => re,
+            // Undefined variable, reported by `check_vars_defined`
+            None => return false,
+        };
+
+        let mut found = false;
+        re.for_each_var(&mut |used| {
+            if found {
+                return;
+            }
+            if used == target {
+                path.push(used.clone());
+                found = true;
+            } else if visited.insert(used.clone()) {
+                path.push(used.clone());
+                if go(bindings, target, used, path, visited) {
+                    found = true;
+                } else {
+                    path.pop();
+                }
+            }
+        });
+        found
+    }
+
+    let mut path = vec![var.clone()];
+    let mut visited: Set<Var> = Default::default();
+    if go(bindings, var, var, &mut path, &mut visited) {
+        Some(path)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,146 @@
+//! Implements `#[derive(Lexer)]`, which generates a lexer from regexes in `#[lexer(...)]`
+//! attributes of the variants of a token enum.
+//!
+//! The derive is a frontend for `lexer!`: the attributes are translated to a `lexer!` definition,
+//! which is then compiled as usual. Given
+//!
+//! ```ignore
+//! #[derive(Lexer)]
+//! #[lexer(skip = [' ' '\n']+)]
+//! enum Token<'input> {
+//!     #[lexer("+")]
+//!     Plus,
+//!     #[lexer(['a'-'z']+)]
+//!     Ident(&'input str),
+//! }
+//! ```
+//!
+//! the lexer definition is
+//!
+//! ```ignore
+//! TokenLexer -> Token<'input>;
+//! [' ' '\n']+,
+//! "+" = Token::Plus,
+//! ['a'-'z']+ => |lexer| {
+//!     let match_ = lexer.match_();
+//!     lexer.return_(Token::Ident(::std::convert::From::from(match_)))
+//! },
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::ParseStream;
+use syn::spanned::Spanned;
+
+/// Generate the `lexer!` definition for the token enum.
+pub fn lexer_definition(input: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        syn::Data::Struct(syn::DataStruct { struct_token, .. }) => {
+            return Err(syn::Error::new(
+                struct_token.span(),
+                "`#[derive(Lexer)]` is only supported on enums",
+            ))
+        }
+        syn::Data::Union(syn::DataUnion { union_token, .. }) => {
+            return Err(syn::Error::new(
+                union_token.span(),
+                "`#[derive(Lexer)]` is only supported on enums",
+            ))
+        }
+    };
+
+    check_generics(&input.generics)?;
+
+    let token_name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut lexer_name = format_ident!("{}Lexer", token_name);
+    let mut rules: Vec<TokenStream> = vec![];
+
+    for attr in lexer_attrs(&input.attrs) {
+        attr.parse_args_with(|input: ParseStream| {
+            let key = input.parse::<syn::Ident>()?;
+            input.parse::<syn::token::Eq>()?;
+            if key == "name" {
+                lexer_name = input.parse::<syn::Ident>()?;
+            } else if key == "skip" {
+                let regex = input.parse::<TokenStream>()?;
+                rules.push(quote!(#regex,));
+            } else {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "Unknown `lexer` attribute, expected `name = <ident>` or `skip = <regex>`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    for variant in &data.variants {
+        let variant_name = &variant.ident;
+
+        for attr in lexer_attrs(&variant.attrs) {
+            let regex = attr.parse_args::<TokenStream>()?;
+            if regex.is_empty() {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "Expected a regex: `#[lexer(<regex>)]`",
+                ));
+            }
+
+            match &variant.fields {
+                syn::Fields::Unit => {
+                    rules.push(quote!(#regex = #token_name::#variant_name,));
+                }
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    rules.push(quote!(
+                        #regex => |lexer| {
+                            let match_ = lexer.match_();
+                            lexer.return_(#token_name::#variant_name(
+                                ::std::convert::From::from(match_)
+                            ))
+                        },
+                    ));
+                }
+                fields => {
+                    return Err(syn::Error::new(
+                        fields.span(),
+                        "Variants with regexes should have no fields, or one unnamed field that \
+                        implements `From<&str>`",
+                    ))
+                }
+            }
+        }
+    }
+
+    let visibility = &input.vis;
+
+    Ok(quote!(
+        #visibility #lexer_name -> #token_name #ty_generics;
+        #(#rules)*
+    ))
+}
+
+/// `#[lexer(...)]` attributes
+fn lexer_attrs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("lexer"))
+}
+
+/// The only generic parameter allowed is the `'input` lifetime, which is the lifetime of the
+/// lexer's input.
+fn check_generics(generics: &syn::Generics) -> syn::Result<()> {
+    for param in &generics.params {
+        match param {
+            syn::GenericParam::Lifetime(lifetime) if lifetime.lifetime.ident == "input" => {}
+            _ => {
+                return Err(syn::Error::new(
+                    param.span(),
+                    "Token enums with `#[derive(Lexer)]` can only have an `'input` lifetime \
+                    parameter",
+                ))
+            }
+        }
+    }
+    Ok(())
+}
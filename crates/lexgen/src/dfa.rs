@@ -1,11 +1,18 @@
+pub mod analysis;
 pub mod codegen;
+// Used in tests and the CLI
+#[cfg_attr(not(test), allow(dead_code))]
+pub mod equivalence;
+pub mod report;
 pub mod simplify;
+pub mod snapshot;
 
-#[cfg(test)]
+// Used in tests and the CLI
+#[cfg_attr(not(test), allow(dead_code))]
 pub mod simulate;
 
-use crate::collections::{Map, Set};
-use crate::nfa::AcceptingState;
+use crate::dot::Graph;
+use crate::nfa::{AcceptingState, Provenance};
 use crate::range_map::{Range, RangeMap};
 
 use std::convert::TryFrom;
@@ -35,35 +42,71 @@ pub struct State<T, A> {
     // Is this the initial state of a rule set? This is important as failure transitions in initial
     // states consume the current character, but failure transitions in other states don't. (#12)
     initial: bool,
-    char_transitions: Map<char, T>,
-    range_transitions: RangeMap<T>,
+    // Transitions on characters, sorted by the characters. Transitions on single characters are
+    // ranges of one character.
+    transitions: RangeMap<T>,
     any_transition: Option<T>,
     end_of_input_transition: Option<T>,
     accepting: Vec<AcceptingState<A>>,
     // Predecessors of the state, used to inline code for a state with one predecessor in the
-    // predecessor's code
-    predecessors: Set<StateIdx>,
+    // predecessor's code. Sorted, without duplicates.
+    predecessors: Vec<StateIdx>,
+    // Rules of the NFA states of the state, with their positions in the rules. Used to name states
+    // in debug output.
+    provenance: Vec<Provenance<A>>,
 }
 
 impl<T, A> State<T, A> {
     fn new() -> State<T, A> {
         State {
             initial: false,
-            char_transitions: Default::default(),
-            range_transitions: Default::default(),
+            transitions: Default::default(),
             any_transition: None,
             end_of_input_transition: None,
             accepting: vec![],
-            predecessors: Default::default(),
+            predecessors: vec![],
+            provenance: vec![],
+        }
+    }
+
+    fn add_predecessor(&mut self, state: StateIdx) {
+        if let Err(idx) = self.predecessors.binary_search(&state) {
+            self.predecessors.insert(idx, state);
+        }
+    }
+
+    fn remove_predecessor(&mut self, state: StateIdx) {
+        if let Ok(idx) = self.predecessors.binary_search(&state) {
+            self.predecessors.remove(idx);
         }
     }
 
     fn has_no_transitions(&self) -> bool {
-        self.char_transitions.is_empty()
-            && self.range_transitions.is_empty()
+        self.transitions.is_empty()
             && self.any_transition.is_none()
             && self.end_of_input_transition.is_none()
     }
+
+    /// Next state of a character transition, excluding the `_` transition. O(log n) in the number
+    /// of transitions.
+    fn next(&self, char: char) -> Option<&T> {
+        self.transitions.get(char)
+    }
+
+    /// Transitions on single characters, sorted
+    fn char_transitions(&self) -> impl Iterator<Item = (char, &T)> {
+        self.transitions
+            .iter()
+            .filter(|range| range.start == range.end)
+            .map(|range| (char::try_from(range.start).unwrap(), &range.value))
+    }
+
+    /// Transitions on ranges of more than one character, sorted
+    fn range_transitions(&self) -> impl Iterator<Item = &Range<T>> {
+        self.transitions
+            .iter()
+            .filter(|range| range.start != range.end)
+    }
 }
 
 impl<A> DFA<StateIdx, A> {
@@ -86,51 +129,120 @@ impl<A> DFA<StateIdx, A> {
         self.states[state.0].accepting.push(accept);
     }
 
+    /// Stable sorts accepting states of all states by `key`. The first accepting state of a state
+    /// is tried first, so this changes which rule is chosen when multiple rules match an input.
+    pub fn sort_accepting_states<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&AcceptingState<A>) -> K,
+    {
+        for state in &mut self.states {
+            state.accepting.sort_by_key(&mut key);
+        }
+    }
+
+    /// Removes transitions of non-initial states where `f` holds for the first accepting state of
+    /// the state. The lexer accepts these states as soon as they're reached, instead of trying to
+    /// match a longer input. Used for `shortest` rules.
+    pub fn stop_at_accepting_states<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&AcceptingState<A>) -> bool,
+    {
+        for state_idx in 0..self.states.len() {
+            let state = &mut self.states[state_idx];
+
+            if state.initial || !state.accepting.first().is_some_and(&mut f) {
+                continue;
+            }
+
+            let next_states: Vec<StateIdx> = state
+                .transitions
+                .iter()
+                .map(|range| range.value)
+                .chain(state.any_transition)
+                .chain(state.end_of_input_transition)
+                .collect();
+
+            state.transitions = RangeMap::new();
+            state.any_transition = None;
+            state.end_of_input_transition = None;
+
+            for next in next_states {
+                self.states[next.0].remove_predecessor(StateIdx(state_idx));
+            }
+        }
+    }
+
+    /// Add provenance of an NFA state to a DFA state. When the state already has a provenance with
+    /// the same rule, the smaller position is kept.
+    pub fn add_provenance(&mut self, state: StateIdx, provenance: Provenance<A>)
+    where
+        A: PartialEq,
+    {
+        let state_provenance = &mut self.states[state.0].provenance;
+        match state_provenance
+            .iter_mut()
+            .find(|old| old.rule == provenance.rule)
+        {
+            Some(old) => old.position = old.position.min(provenance.position),
+            None => state_provenance.push(provenance),
+        }
+    }
+
+    /// Name of a state, derived from the rules of the NFA states that the state is created from,
+    /// e.g. `Init:2@1+Init:3@1` for a state one character into rules 2 and 3 of rule set `Init`.
+    /// `rule_name` is used to name rules. Returns `None` for states that are not created from
+    /// rules, e.g. initial states.
+    pub fn state_name<F: Fn(&A) -> String>(&self, state: StateIdx, rule_name: F) -> Option<String> {
+        let provenance = &self.states[state.0].provenance;
+        if provenance.is_empty() {
+            return None;
+        }
+
+        let names: Vec<String> = provenance
+            .iter()
+            .map(|provenance| format!("{}@{}", rule_name(&provenance.rule), provenance.position))
+            .collect();
+
+        Some(names.join("+"))
+    }
+
     pub fn new_state(&mut self) -> StateIdx {
         let new_state_idx = StateIdx(self.states.len());
         self.states.push(State::new());
         new_state_idx
     }
 
-    #[cfg(test)]
+    #[cfg_attr(not(test), allow(dead_code))]
     pub fn is_accepting_state(&self, state: StateIdx) -> bool {
         !self.states[state.0].accepting.is_empty()
     }
 
-    pub fn add_char_transition(&mut self, state: StateIdx, char: char, next: StateIdx) {
-        let old = self.states[state.0].char_transitions.insert(char, next);
-        assert!(
-            old.is_none(),
-            "state={:?}, char={:?}, old={:?}, new={:?}",
-            state,
-            char,
-            old,
-            next
-        );
-
-        self.states[next.0].predecessors.insert(state);
+    /// Accepting states of the state, in the order they're tried
+    pub fn accepting_states(&self, state: StateIdx) -> &[AcceptingState<A>] {
+        &self.states[state.0].accepting
     }
 
-    pub fn set_range_transitions(&mut self, state: StateIdx, range_map: RangeMap<StateIdx>) {
-        assert!(self.states[state.0].range_transitions.is_empty());
+    pub fn set_transitions(&mut self, state: StateIdx, range_map: RangeMap<StateIdx>) {
+        assert!(self.states[state.0].transitions.is_empty());
 
         for range in range_map.iter() {
-            self.states[range.value.0].predecessors.insert(state);
+            self.states[range.value.0].add_predecessor(state);
         }
 
-        self.states[state.0].range_transitions = range_map;
+        self.states[state.0].transitions = range_map;
     }
 
     pub fn set_any_transition(&mut self, state: StateIdx, next: StateIdx) {
         assert!(self.states[state.0].any_transition.is_none());
         self.states[state.0].any_transition = Some(next);
-        self.states[next.0].predecessors.insert(state);
+        self.states[next.0].add_predecessor(state);
     }
 
     pub fn set_end_of_input_transition(&mut self, state: StateIdx, next: StateIdx) {
         assert!(self.states[state.0].end_of_input_transition.is_none());
         self.states[state.0].end_of_input_transition = Some(next);
-        self.states[next.0].predecessors.insert(state);
+        self.states[next.0].add_predecessor(state);
     }
 }
 
@@ -145,6 +257,17 @@ impl<T, A> DFA<T, A> {
             .enumerate()
             .map(|(state_idx, state)| (StateIdx(state_idx), state))
     }
+
+    pub fn num_states(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn num_range_transitions(&self) -> usize {
+        self.states
+            .iter()
+            .map(|state| state.range_transitions().count())
+            .sum()
+    }
 }
 
 impl<T, A> FromIterator<(StateIdx, State<T, A>)> for DFA<T, A> {
@@ -172,24 +295,19 @@ impl<A> DFA<StateIdx, A> {
 
         for State {
             initial,
-            char_transitions,
-            range_transitions,
+            transitions,
             any_transition,
             end_of_input_transition,
             accepting,
             predecessors,
+            provenance,
         } in other.states
         {
-            let mut new_char_transitions: Map<char, StateIdx> = Default::default();
             let mut new_any_transition: Option<StateIdx> = None;
             let mut new_end_of_input_transition: Option<StateIdx> = None;
 
-            for (char, next) in char_transitions {
-                new_char_transitions.insert(char, StateIdx(next.0 + n_current_states));
-            }
-
-            let new_range_transitions =
-                range_transitions.map(|state_idx| StateIdx(state_idx.0 + n_current_states));
+            let new_transitions =
+                transitions.map(|state_idx| StateIdx(state_idx.0 + n_current_states));
 
             if let Some(next) = any_transition {
                 new_any_transition = Some(StateIdx(next.0 + n_current_states));
@@ -206,12 +324,12 @@ impl<A> DFA<StateIdx, A> {
 
             self.states.push(State {
                 initial,
-                char_transitions: new_char_transitions,
-                range_transitions: new_range_transitions,
+                transitions: new_transitions,
                 any_transition: new_any_transition,
                 end_of_input_transition: new_end_of_input_transition,
                 accepting,
                 predecessors,
+                provenance,
             });
         }
 
@@ -219,6 +337,65 @@ impl<A> DFA<StateIdx, A> {
     }
 }
 
+impl<A> DFA<StateIdx, A> {
+    /// Generate a Graphviz DOT graph of the DFA. `label` is used to label accepting states,
+    /// `rule_name` is used to name states, see `state_name`.
+    pub fn to_dot<F, N>(&self, name: &str, label: F, rule_name: N) -> String
+    where
+        F: Fn(&AcceptingState<A>) -> String,
+        N: Fn(&A) -> String,
+    {
+        self.to_graph(label, rule_name).render_dot(name)
+    }
+
+    /// Generate a Mermaid state diagram of the DFA. `label` is used to label accepting states,
+    /// `rule_name` is used to name states, see `state_name`.
+    pub fn to_mermaid<F, N>(&self, label: F, rule_name: N) -> String
+    where
+        F: Fn(&AcceptingState<A>) -> String,
+        N: Fn(&A) -> String,
+    {
+        self.to_graph(label, rule_name).render_mermaid()
+    }
+
+    fn to_graph<F, N>(&self, label: F, rule_name: N) -> Graph
+    where
+        F: Fn(&AcceptingState<A>) -> String,
+        N: Fn(&A) -> String,
+    {
+        let mut graph = Graph::new();
+
+        for (state_idx, state) in self.states.iter().enumerate() {
+            graph.add_node(
+                state.initial,
+                self.state_name(StateIdx(state_idx), &rule_name),
+                state.accepting.iter().map(&label).collect(),
+            );
+
+            for Range { start, end, value } in state.transitions.iter() {
+                let start = char::try_from(*start).unwrap();
+                let end = char::try_from(*end).unwrap();
+                let label = if start == end {
+                    format!("{:?}", start)
+                } else {
+                    format!("{:?}-{:?}", start, end)
+                };
+                graph.add_edge(state_idx, value.0, label);
+            }
+
+            if let Some(next) = state.any_transition {
+                graph.add_edge(state_idx, next.0, "_".to_owned());
+            }
+
+            if let Some(next) = state.end_of_input_transition {
+                graph.add_edge(state_idx, next.0, "$".to_owned());
+            }
+        }
+
+        graph
+    }
+}
+
 use std::fmt::{self, Display, Formatter};
 
 impl Display for StateIdx {
@@ -232,12 +409,12 @@ impl<A> Display for DFA<StateIdx, A> {
         for (state_idx, state) in self.states.iter().enumerate() {
             let State {
                 initial,
-                char_transitions,
-                range_transitions,
+                transitions,
                 any_transition,
                 end_of_input_transition,
                 accepting,
                 predecessors: _,
+                provenance: _,
             } = state;
 
             if !accepting.is_empty() {
@@ -256,30 +433,20 @@ impl<A> Display for DFA<StateIdx, A> {
 
             let mut first = true;
 
-            for (char, next) in char_transitions.iter() {
+            for Range { start, end, value } in transitions.iter() {
                 if !first {
                     write!(f, "      ")?;
                 } else {
                     first = false;
                 }
 
-                writeln!(f, "{:?} -> {}", char, next)?;
-            }
-
-            for Range { start, end, value } in range_transitions.iter() {
-                if !first {
-                    write!(f, "      ")?;
+                let start = char::try_from(*start).unwrap();
+                let end = char::try_from(*end).unwrap();
+                if start == end {
+                    writeln!(f, "{:?} -> {}", start, value)?;
                 } else {
-                    first = false;
+                    writeln!(f, "{:?} - {:?} -> {}", start, end, value)?;
                 }
-
-                writeln!(
-                    f,
-                    "{:?} - {:?} -> {}",
-                    char::try_from(*start).unwrap(),
-                    char::try_from(*end).unwrap(),
-                    value,
-                )?;
             }
 
             if let Some(next) = any_transition {
@@ -300,8 +467,7 @@ impl<A> Display for DFA<StateIdx, A> {
                 writeln!(f, "$ -> {}", next)?;
             }
 
-            if char_transitions.is_empty()
-                && range_transitions.is_empty()
+            if transitions.is_empty()
                 && any_transition.is_none()
                 && end_of_input_transition.is_none()
             {
@@ -0,0 +1,214 @@
+//! Analyses on DFAs used to report problems in lexer definitions and generate test inputs
+
+use super::snapshot::char_ranges;
+use super::{State, StateIdx, DFA};
+use crate::collections::{Map, Set};
+use crate::nfa::AcceptingState;
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Returns values of accepting states that are the match for at least one input.
+///
+/// When a DFA state accepts with multiple values, the first one without a right context wins, and
+/// values after it are never matched in that state. Values before it, with right contexts, can
+/// still be matched depending on the input after the match.
+pub fn matched_values<A: Copy + Eq + Hash>(dfa: &DFA<StateIdx, A>) -> Set<A> {
+    let mut values: Set<A> = Default::default();
+
+    for state in &dfa.states {
+        for accepting_state in &state.accepting {
+            values.insert(accepting_state.value);
+            if !accepting_state.is_conditional() {
+                break;
+            }
+        }
+    }
+
+    values
+}
+
+/// Returns values of accepting states that can match the empty string in the `Init` rule set.
+///
+/// The initial state accepts the values of rules that match the empty string. The empty match is
+/// taken when the lexer is stuck in the initial state, which can happen when the state doesn't
+/// have an 'any' transition, or an end-of-input transition.
+///
+/// Other rule sets are not checked, as a rule matching the empty string is commonly used to switch
+/// to another rule set.
+pub fn empty_matches<A: Copy + Eq + Hash>(dfa: &DFA<StateIdx, A>) -> Set<A> {
+    let state = &dfa.states[dfa.initial_state().0];
+
+    if state.any_transition.is_some() && state.end_of_input_transition.is_some() {
+        return Default::default();
+    }
+
+    state
+        .accepting
+        .iter()
+        .map(|accepting_state| accepting_state.value)
+        .collect()
+}
+
+/// Returns ranges of characters up to `max` that the initial state of the `Init` rule set doesn't
+/// have a transition for. Lexing fails on these characters at the beginning of a token.
+pub fn uncovered_chars<A>(dfa: &DFA<StateIdx, A>, max: char) -> Vec<(char, char)> {
+    let state = &dfa.states[dfa.initial_state().0];
+
+    if state.any_transition.is_some() {
+        return vec![];
+    }
+
+    let mut uncovered: Vec<(char, char)> = vec![];
+
+    let mut add_range = |start: u32, end: u32| {
+        // Skip surrogates
+        for (start, end) in [(start, end.min(0xD7FF)), (start.max(0xE000), end)] {
+            if start <= end {
+                uncovered.push((char::from_u32(start).unwrap(), char::from_u32(end).unwrap()));
+            }
+        }
+    };
+
+    // Start of the next range to check
+    let mut next = 0;
+
+    for (start, end, _) in char_ranges(state) {
+        if start > max as u32 {
+            break;
+        }
+        if start > next {
+            add_range(next, start - 1);
+        }
+        next = end + 1;
+    }
+
+    if next <= max as u32 {
+        add_range(next, max as u32);
+    }
+
+    uncovered
+}
+
+/// Returns pairs of values `(winner, loser)` where an input is accepted with both values and
+/// `winner` is chosen over `loser`, mapped to a shortest such input. The input is `None` when the
+/// state accepting both values can only be reached with an end-of-input transition.
+pub fn conflicts<A: Copy + Eq + Hash>(dfa: &DFA<StateIdx, A>) -> Map<(A, A), Option<String>> {
+    let inputs = shortest_inputs(dfa);
+
+    let mut conflicts: Map<(A, A), Option<String>> = Default::default();
+
+    for (state, input) in dfa.states.iter().zip(inputs.iter()) {
+        let winner_idx = match state
+            .accepting
+            .iter()
+            .position(|accepting_state| !accepting_state.is_conditional())
+        {
+            Some(winner_idx) => winner_idx,
+            None => continue,
+        };
+
+        let winner = state.accepting[winner_idx].value;
+
+        for loser in &state.accepting[winner_idx + 1..] {
+            let entry = conflicts.entry((winner, loser.value)).or_insert(None);
+            let shorter = match (&entry, input) {
+                (_, None) => false,
+                (None, Some(_)) => true,
+                (Some(old), Some(new)) => new.chars().count() < old.chars().count(),
+            };
+            if shorter {
+                *entry = input.clone();
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// For each accepting state, a shortest input that takes an initial state to the state, with the
+/// accepting states that can be matched in the state (see `matched_values`). Lexing the input with
+/// the rule set of the initial state matches one of the values, so the inputs can be used as test
+/// inputs that cover all matched rules. Values with a right context are only matched when the
+/// input is followed by the right context.
+///
+/// States that are only reachable with end-of-input transitions are skipped. Inputs are sorted by
+/// length, with inputs of the same length in state order.
+// Used in tests and the CLI
+#[cfg_attr(not(test), allow(dead_code))]
+pub fn accepting_inputs<A: Copy>(dfa: &DFA<StateIdx, A>) -> Vec<(String, Vec<AcceptingState<A>>)> {
+    let inputs = shortest_inputs(dfa);
+
+    let mut accepting_inputs: Vec<(String, Vec<AcceptingState<A>>)> = vec![];
+
+    for (state, input) in dfa.states.iter().zip(inputs) {
+        let input = match input {
+            Some(input) if !state.accepting.is_empty() => input,
+            _ => continue,
+        };
+
+        let mut accepting_states = vec![];
+        for accepting_state in &state.accepting {
+            accepting_states.push(*accepting_state);
+            if !accepting_state.is_conditional() {
+                break;
+            }
+        }
+
+        accepting_inputs.push((input, accepting_states));
+    }
+
+    accepting_inputs.sort_by_key(|(input, _)| input.chars().count());
+
+    accepting_inputs
+}
+
+/// For each state, a shortest input that takes an initial state to the state. `None` for states
+/// that are only reachable with end-of-input transitions.
+fn shortest_inputs<A>(dfa: &DFA<StateIdx, A>) -> Vec<Option<String>> {
+    let mut inputs: Vec<Option<String>> = vec![None; dfa.states.len()];
+    let mut work_list: VecDeque<usize> = VecDeque::new();
+
+    for (state_idx, state) in dfa.states.iter().enumerate() {
+        if state.initial {
+            inputs[state_idx] = Some(String::new());
+            work_list.push_back(state_idx);
+        }
+    }
+
+    while let Some(state_idx) = work_list.pop_front() {
+        let state = &dfa.states[state_idx];
+
+        let char_transitions = state.char_transitions().map(|(char, next)| (char, *next));
+
+        let range_transitions = state
+            .range_transitions()
+            .map(|range| (char::from_u32(range.start).unwrap(), range.value));
+
+        let any_transition = state
+            .any_transition
+            .and_then(|next| Some((any_char(state)?, next)));
+
+        for (char, StateIdx(next)) in char_transitions
+            .chain(range_transitions)
+            .chain(any_transition)
+        {
+            if inputs[next].is_none() {
+                let mut input = inputs[state_idx].clone().unwrap();
+                input.push(char);
+                inputs[next] = Some(input);
+                work_list.push_back(next);
+            }
+        }
+    }
+
+    inputs
+}
+
+/// A character that takes the 'any' transition of the state, if one exists
+fn any_char<A>(state: &State<StateIdx, A>) -> Option<char> {
+    ('a'..='z')
+        .chain('0'..='9')
+        .chain((0..=char::MAX as u32).filter_map(char::from_u32))
+        .find(|char| state.next(*char).is_none())
+}
@@ -1,14 +1,23 @@
+mod captures;
+pub mod const_fn;
 mod ctx;
+mod layout;
 mod search_table;
+pub mod table;
 
 use ctx::CgCtx;
 
 use super::simplify::Trans;
 use super::{State, StateIdx, DFA};
 
-use crate::ast::{RuleKind, RuleRhs};
+use crate::ast::{
+    DriverStyle, Encoding, GeneratedType, Indentation, IndentationWhitespace, InvalidUtf8,
+    LineTerminators, RangeChecks, RuleKind, RuleRhs,
+};
 use crate::collections::{Map, Set};
+use crate::compile::CaptureParts;
 use crate::nfa::AcceptingState;
+use crate::options::Options;
 use crate::range_map::{Range, RangeMap};
 use crate::right_ctx::{RightCtxDFAs, RightCtxIdx};
 use crate::semantic_action_table::{SemanticActionIdx, SemanticActionTable};
@@ -31,6 +40,11 @@ use quote::{quote, ToTokens};
 // have a slightly larger number here.
 const MAX_GUARD_SIZE: usize = 9;
 
+// Min. number of ranges in a state for using 128-entry tables for ASCII ranges in the state, in
+// lexers that handle Unicode input. In ASCII lexers tables are used whenever a guard checks more
+// than one range.
+const MIN_TABLE_RANGES: usize = 4;
+
 pub fn reify(
     dfa: DFA<Trans<SemanticActionIdx>, SemanticActionIdx>,
     right_ctx_dfas: &RightCtxDFAs<StateIdx>,
@@ -39,36 +53,167 @@ pub fn reify(
     user_error_type: Option<syn::Type>,
     rule_states: Map<String, StateIdx>,
     lexer_name: syn::Ident,
+    generics: syn::Generics,
     token_type: syn::Type,
-    public: bool,
-) -> TokenStream {
-    let rule_name_enum_name =
-        syn::Ident::new(&(lexer_name.to_string() + "Rule"), lexer_name.span());
+    attrs: Vec<syn::Attribute>,
+    visibility: syn::Visibility,
+    options: &Options,
+    invalid_utf8_action: Option<SemanticActionIdx>,
+    custom_matchers: Map<String, Vec<(syn::Path, SemanticActionIdx)>>,
+    guards: Map<SemanticActionIdx, syn::Path>,
+    group_names: Vec<syn::Ident>,
+    rule_groups: Map<SemanticActionIdx, usize>,
+    fail_actions: Map<String, SemanticActionIdx>,
+    feedback_type: Option<syn::Type>,
+    interner_type: Option<syn::Type>,
+    captures: &Map<SemanticActionIdx, CaptureParts<DFA<StateIdx, ()>>>,
+) -> syn::Result<TokenStream> {
+    let rule_name_enum_name = options.type_name(GeneratedType::RuleEnum, &lexer_name);
 
     let rule_name_idents: Vec<syn::Ident> = rule_states
         .keys()
         .map(|rule_name| syn::Ident::new(rule_name, Span::call_site()))
         .collect();
 
-    let visibility = if public { quote!(pub) } else { quote!() };
+    let visibility = visibility.into_token_stream();
 
     let mut ctx = CgCtx::new(
         &dfa,
         semantic_actions,
         lexer_name,
+        generics.clone(),
         token_type,
         user_error_type,
         rule_states,
+        options.encoding() == Encoding::Ascii,
+        options.range_checks(),
+        options.invalid_utf8().is_some(),
+        custom_matchers,
+        guards,
+        rule_groups,
+        fail_actions,
+        feedback_type.is_some(),
+        options.tag_rule_sets(),
+        options.on_error().cloned(),
+        options.cancellation(),
     );
 
+    let (lifetime_params, lifetime_args) = lifetime_params_args(&generics);
+    let (generic_params, generic_args) = generic_params_args(&generics);
+
+    // Lexers created from iterators don't borrow input, but can have other lifetimes
+    let static_impl_lifetimes: Vec<&syn::LifetimeDef> = generics.lifetimes().collect();
+
+    // Type aliases can't have bounds, only the parameter names
+    let generic_alias_params: Vec<TokenStream> = generics
+        .lifetimes()
+        .map(|param| param.lifetime.to_token_stream())
+        .chain(
+            generics
+                .type_params()
+                .map(|param| param.ident.to_token_stream()),
+        )
+        .chain(generics.const_params().map(|param| {
+            let ident = &param.ident;
+            let ty = &param.ty;
+            quote!(const #ident: #ty)
+        }))
+        .collect();
+    let generic_alias_params = quote!(#(, #generic_alias_params)*);
+
     let user_state_type = user_state_type
         .map(|ty| ty.into_token_stream())
         .unwrap_or(quote!(()));
 
-    let match_arms = generate_state_arms(&mut ctx, dfa);
+    // Constructors without a user state argument need `Default` for the user state. With generic
+    // parameters the bound is on the constructors, so that a user state that doesn't implement
+    // `Default` can still be used with the `_with_state` constructors.
+    let default_state_bound = if generics.params.is_empty() {
+        quote!()
+    } else {
+        quote!(where #user_state_type: ::std::default::Default)
+    };
+
+    // Parser feedback is stored in the second field of the lexer struct, initialized with
+    // `Default`
+    let (feedback_field, feedback_init, feedback_method) = match &feedback_type {
+        None => (quote!(), quote!(), quote!()),
+        Some(ty) => (
+            quote!(, #ty),
+            quote!(, ::std::default::Default::default()),
+            quote!(
+                #visibility fn feedback(&mut self) -> &mut #ty {
+                    &mut self.1
+                }
+            ),
+        ),
+    };
+
+    // The interner is stored in the last field of the lexer struct, set with `with_interner`
+    let (interner_field, interner_init, interner_methods) = match &interner_type {
+        None => (quote!(), quote!(), quote!()),
+        Some(ty) => {
+            let field = syn::Index::from(if feedback_type.is_some() { 2 } else { 1 });
+            (
+                quote!(, ::std::option::Option<#ty>),
+                quote!(, ::std::option::Option::None),
+                quote!(
+                    #visibility fn with_interner(mut self, interner: #ty) -> Self {
+                        self.#field = ::std::option::Option::Some(interner);
+                        self
+                    }
+
+                    #visibility fn interner(&mut self) -> ::std::option::Option<&mut #ty> {
+                        self.#field.as_mut()
+                    }
+
+                    fn intern(&mut self, string: &str) -> <#ty as ::lexgen_util::Interner>::Symbol {
+                        let interner = self.#field.as_mut().expect("Lexer doesn't have an interner, set it with `with_interner`");
+                        ::lexgen_util::Interner::intern(interner, string)
+                    }
+                ),
+            )
+        }
+    };
+
+    let (profile, profile_dependency) = match options.layout_profile() {
+        None => (None, quote!()),
+        Some(path) => {
+            let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+            let full_path = std::path::Path::new(&manifest_dir).join(path.value());
+            let profile = std::fs::read_to_string(&full_path).map_err(|err| {
+                syn::Error::new(
+                    path.span(),
+                    format!("Unable to read layout profile {:?}: {}", full_path, err),
+                )
+            })?;
+            // Make sure the lexer is recompiled when the profile changes
+            let full_path = full_path.to_string_lossy();
+            (
+                Some(profile),
+                quote!(
+                    const _: &[u8] = include_bytes!(#full_path);
+                ),
+            )
+        }
+    };
+
+    let state_arms = generate_state_arms(&mut ctx, dfa, profile.as_deref());
 
     let switch_method = generate_switch(&ctx, &rule_name_enum_name);
 
+    let next_token = if options.next_token() {
+        generate_next_token(
+            &ctx,
+            &rule_name_enum_name,
+            &visibility,
+            &user_state_type,
+            &default_state_bound,
+        )
+    } else {
+        quote!()
+    };
+
     let token_type = ctx.token_type();
 
     let error_type = match ctx.user_error_type() {
@@ -85,35 +230,13 @@ pub fn reify(
         }
     };
 
-    let semantic_action_fns = generate_semantic_action_fns(&ctx, &semantic_action_fn_ret_ty);
+    let semantic_action_fns =
+        generate_semantic_action_fns(&ctx, &semantic_action_fn_ret_ty, captures);
 
     let right_ctx_fns = generate_right_ctx_fns(&mut ctx, right_ctx_dfas);
 
-    let search_tables = ctx.take_search_tables();
-
-    let binary_search_fn = if search_tables.is_empty() {
-        quote!()
-    } else {
-        quote!(
-            fn binary_search(c: char, table: &[(char, char)]) -> bool {
-                table
-                    .binary_search_by(|(start, end)| match c.cmp(start) {
-                        std::cmp::Ordering::Greater => {
-                            if c <= *end {
-                                std::cmp::Ordering::Equal
-                            } else {
-                                std::cmp::Ordering::Less
-                            }
-                        }
-                        std::cmp::Ordering::Equal => std::cmp::Ordering::Equal,
-                        std::cmp::Ordering::Less => std::cmp::Ordering::Greater,
-                    })
-                    .is_ok()
-            }
-        )
-    };
-
-    let search_tables: Vec<TokenStream> = search_tables
+    let search_tables: Vec<TokenStream> = ctx
+        .take_search_tables()
         .iter()
         .map(|(ranges, ident)| {
             let n_ranges = ranges.len();
@@ -122,6 +245,7 @@ pub fn reify(
                 .map(|(start, end)| quote!((#start, #end)))
                 .collect();
             quote!(
+                #[allow(non_upper_case_globals)]
                 static #ident: [(char, char); #n_ranges] = [
                     #(#pairs),*
                 ];
@@ -129,29 +253,447 @@ pub fn reify(
         })
         .collect();
 
+    let ascii_tables: Vec<TokenStream> = ctx
+        .take_ascii_tables()
+        .iter()
+        .map(|(ranges, ident)| {
+            let mut table = [false; 128];
+            for (start, end) in ranges {
+                for char in *start..=*end {
+                    table[char as usize] = true;
+                }
+            }
+            quote!(
+                #[allow(non_upper_case_globals)]
+                static #ident: [bool; 128] = [
+                    #(#table),*
+                ];
+            )
+        })
+        .collect();
+
     let token_type = ctx.token_type();
     let lexer_name = ctx.lexer_name();
 
-    quote!(
+    // `OPTIONS` parameter of the `lexgen_util::Lexer`, for options that need work for every
+    // character. Omitted when no bits are set, to use the default.
+    let mut option_bits = vec![];
+    if options.invalid_utf8().is_some() {
+        option_bits.push(quote!(::lexgen_util::OPTION_INVALID_UTF8));
+    }
+    if options.line_terminators() == LineTerminators::Unicode {
+        option_bits.push(quote!(::lexgen_util::OPTION_UNICODE_LINE_TERMINATORS));
+    }
+    if options.indentation().is_some() {
+        option_bits.push(quote!(::lexgen_util::OPTION_INDENTATION));
+    }
+    let lexer_options = if option_bits.is_empty() {
+        quote!()
+    } else {
+        quote!(, { #(#option_bits)|* })
+    };
+
+    let counters = if options.counters() {
+        quote!(.with_counters())
+    } else {
+        quote!()
+    };
+
+    let indentation = match options.indentation() {
+        None => quote!(),
+        Some(Indentation {
+            newline,
+            indent,
+            dedent,
+            whitespace,
+        }) => {
+            let whitespace = match whitespace {
+                IndentationWhitespace::Spaces => {
+                    quote!(::lexgen_util::IndentationWhitespace::Spaces)
+                }
+                IndentationWhitespace::Tabs => quote!(::lexgen_util::IndentationWhitespace::Tabs),
+                IndentationWhitespace::TabWidth(width) => {
+                    quote!(::lexgen_util::IndentationWhitespace::TabWidth(#width))
+                }
+            };
+            quote!(
+                .with_indentation(#whitespace, || #newline, || #indent, || #dedent)
+            )
+        }
+    };
+
+    let bytes_constructors = match options.invalid_utf8() {
+        None => quote!(),
+        Some((invalid_utf8, _)) => {
+            let invalid_utf8 = match invalid_utf8 {
+                InvalidUtf8::Error => quote!(::lexgen_util::InvalidUtf8::Error),
+                InvalidUtf8::Replace => quote!(::lexgen_util::InvalidUtf8::Replace),
+                InvalidUtf8::Rule => {
+                    let action = ctx.semantic_action_fn_ident(invalid_utf8_action.unwrap());
+                    quote!(::lexgen_util::InvalidUtf8::Rule(#action))
+                }
+            };
+            quote!(
+                #[allow(missing_docs)]
+                impl<'input #lifetime_params #generic_params> #lexer_name<'input #lifetime_args, ::lexgen_util::Utf8Chars<'input> #generic_args> {
+                    #visibility fn new_from_bytes(input: &'input [u8]) -> Self #default_state_bound {
+                        #lexer_name(::lexgen_util::Lexer::new_from_bytes(input, #invalid_utf8) #indentation #counters #feedback_init #interner_init)
+                    }
+
+                    #visibility fn new_from_bytes_with_state(input: &'input [u8], user_state: #user_state_type) -> Self {
+                        #lexer_name(::lexgen_util::Lexer::new_from_bytes_with_state(input, #invalid_utf8, user_state) #indentation #counters #feedback_init #interner_init)
+                    }
+                }
+            )
+        }
+    };
+
+    // With `tag_rule_sets`, tokens are yielded with the rule set they were matched in
+    let yielded_token_type = if options.tag_rule_sets() {
+        quote!(::lexgen_util::Tagged<#rule_name_enum_name, #token_type>)
+    } else {
+        quote!(#token_type)
+    };
+
+    let item_type = quote!(Result<(::lexgen_util::Loc, #yielded_token_type, ::lexgen_util::Loc), ::lexgen_util::LexerError<#error_type>>);
+
+    // Type aliases for LALRPOP grammars and parsers: the lexer item type is the token triple
+    // LALRPOP expects from external lexers, parsers return `lalrpop_util::ParseError`s with the
+    // lexer errors as user errors
+    let lalrpop_aliases = if options.lalrpop() {
+        let spanned_name = options.type_name(GeneratedType::Spanned, lexer_name);
+        let parse_error_name = options.type_name(GeneratedType::ParseError, lexer_name);
+        quote!(
+            #[allow(missing_docs, dead_code)]
+            #visibility type #spanned_name<'input #generic_alias_params> = #item_type;
+
+            #[allow(missing_docs, dead_code)]
+            #visibility type #parse_error_name<'input #generic_alias_params> = ::lalrpop_util::ParseError<
+                ::lexgen_util::Loc,
+                #yielded_token_type,
+                ::lexgen_util::LexerError<#error_type>,
+            >;
+        )
+    } else {
+        quote!()
+    };
+
+    let (driver_items, driver_next) = match options.driver() {
+        DriverStyle::MatchLoop => {
+            let match_arms: Vec<TokenStream> = state_arms
+                .into_iter()
+                .map(|StateArm { pat, code, .. }| quote!(#pat => { #code }))
+                .collect();
+
+            (
+                quote!(),
+                quote!(
+                    loop {
+                        if self.0.__done {
+                            return None;
+                        }
+
+                        // println!("state = {:?}, next char = {:?}", self.0.__state, self.0.peek());
+                        match self.0.__state {
+                            #(#match_arms,)*
+                        }
+                    }
+                ),
+            )
+        }
+
+        DriverStyle::StateFns => {
+            // Each state function returns `Some` when the state yields a value, `None` when the
+            // state switches to another state. Returning `None` from `next` sets `__done`, so we
+            // don't need to distinguish the two cases of `None`.
+            let mut state_fns: Vec<TokenStream> = Vec::with_capacity(state_arms.len());
+            let mut match_arms: Vec<TokenStream> = Vec::with_capacity(state_arms.len());
+
+            for StateArm {
+                pat,
+                state_idx,
+                code,
+            } in state_arms
+            {
+                let fn_name = syn::Ident::new(&format!("__state_{}", state_idx), Span::call_site());
+                state_fns.push(quote!(
+                    fn #fn_name(&mut self) -> Option<#item_type> {
+                        #code
+                        None
+                    }
+                ));
+                match_arms.push(quote!(#pat => self.#fn_name()));
+            }
+
+            (
+                quote!(
+                    #[allow(missing_docs, unreachable_code)]
+                    impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> #lexer_name<'input #lifetime_args, I #generic_args> {
+                        #(#state_fns)*
+                    }
+                ),
+                quote!(
+                    loop {
+                        if self.0.__done {
+                            return None;
+                        }
+
+                        let item = match self.0.__state {
+                            #(#match_arms,)*
+                        };
+
+                        if item.is_some() {
+                            return item;
+                        }
+                    }
+                ),
+            )
+        }
+    };
+
+    // With `indentation`, tokens returned by the state machine go through the indentation tracker
+    // in `lexgen_util::Lexer`, which adds `NEWLINE`, `INDENT`, and `DEDENT` tokens
+    let indentation_on_error = match options.on_error() {
+        None => quote!(),
+        Some(_) => {
+            // Other errors are passed to the hook when they're raised by the state machine
+            let on_error = generate_on_error_call(
+                &ctx,
+                &quote!(*err),
+                &quote!((err.location, err.location)),
+                &quote!(self.0.__initial_state),
+            );
+            quote!(
+                if let Some(Err(err)) = &item {
+                    if matches!(
+                        err.kind,
+                        ::lexgen_util::LexerErrorKind::InvalidIndentation
+                            | ::lexgen_util::LexerErrorKind::InconsistentDedent
+                    ) {
+                        #on_error
+                    }
+                }
+            )
+        }
+    };
+
+    // Body of the `next` method, and the items it uses
+    let (next_items, next_body) = match options.indentation() {
+        None => (driver_items, driver_next),
+        Some(_) => (
+            quote!(
+                #driver_items
+
+                #[allow(missing_docs)]
+                impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> #lexer_name<'input #lifetime_args, I #generic_args> {
+                    fn __next_token(&mut self) -> Option<#item_type> {
+                        #driver_next
+                    }
+                }
+            ),
+            quote!(
+                let item = match self.0.indentation_pending() {
+                    Some(item) => Some(item),
+                    None => {
+                        let item = self.__next_token();
+                        self.0.indentation_next(item)
+                    }
+                };
+                #indentation_on_error
+                item
+            ),
+        ),
+    };
+
+    // With `spanned_tokens`, the tuples are converted to `lexgen_util::Spanned` in `next`
+    let driver = if options.spanned_tokens() {
+        quote!(
+            #next_items
+
+            #[allow(missing_docs)]
+            impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> #lexer_name<'input #lifetime_args, I #generic_args> {
+                fn __next_tuple(&mut self) -> Option<#item_type> {
+                    #next_body
+                }
+            }
+
+            #[automatically_derived]
+            impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> Iterator for #lexer_name<'input #lifetime_args, I #generic_args> {
+                type Item = Result<::lexgen_util::Spanned<#yielded_token_type>, ::lexgen_util::LexerError<#error_type>>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.__next_tuple().map(|item| item.map(::lexgen_util::Spanned::from))
+                }
+            }
+        )
+    } else {
+        quote!(
+            #next_items
+
+            #[automatically_derived]
+            impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> Iterator for #lexer_name<'input #lifetime_args, I #generic_args> {
+                type Item = #item_type;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    #next_body
+                }
+            }
+        )
+    };
+
+    // `fill` lexes tokens into a vector, for consumers that process tokens in bulk.
+    // `TokenSource::next_spanned` yields the same tuples as `fill`, for lexers chosen at runtime.
+    let fill_next = if options.spanned_tokens() {
+        quote!(self.__next_tuple())
+    } else {
+        quote!(::std::iter::Iterator::next(self))
+    };
+
+    let fill = quote!(
+        #[allow(missing_docs)]
+        impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> #lexer_name<'input #lifetime_args, I #generic_args> {
+            #visibility fn fill(
+                &mut self,
+                tokens: &mut Vec<(::lexgen_util::Loc, #yielded_token_type, ::lexgen_util::Loc)>,
+                n: usize,
+            ) -> Result<usize, ::lexgen_util::LexerError<#error_type>> {
+                tokens.reserve(n);
+                let mut n_tokens = 0;
+                while n_tokens < n {
+                    match #fill_next {
+                        None => break,
+                        Some(Ok(token)) => {
+                            tokens.push(token);
+                            n_tokens += 1;
+                        }
+                        Some(Err(err)) => return Err(err),
+                    }
+                }
+                Ok(n_tokens)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> ::lexgen_util::TokenSource for #lexer_name<'input #lifetime_args, I #generic_args> {
+            type Token = #yielded_token_type;
+            type Error = #error_type;
+
+            fn next_spanned(&mut self) -> Option<#item_type> {
+                #fill_next
+            }
+        }
+    );
+
+    // With `cancellation = true;`, the flag checked at the start of each match is set with
+    // `set_cancellation_flag`
+    let cancellation_method = if options.cancellation() {
+        quote!(
+            #visibility fn set_cancellation_flag(&mut self, flag: ::std::sync::Arc<::std::sync::atomic::AtomicBool>) {
+                self.0.set_cancellation_flag(flag)
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    // With `counters = true;`, counters collected at runtime are returned by `stats`
+    let stats_method = if options.counters() {
+        quote!(
+            #visibility fn stats(&self) -> ::lexgen_util::LexerStats {
+                self.0.stats()
+            }
+        )
+    } else {
+        quote!()
+    };
+
+    // Rule groups, `group <name> { ... }`, are enabled and disabled with `with_group`. Index of a
+    // group in `group_names` is its bit in the disabled groups mask.
+    let (group_enum, group_method) = if group_names.is_empty() {
+        (quote!(), quote!())
+    } else {
+        let group_enum_name = options.type_name(GeneratedType::GroupEnum, lexer_name);
+        let group_bits = (0..group_names.len()).map(|group| 1u64 << group);
+        (
+            quote!(
+                // An enum for the rule groups, enabled by default
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                #[allow(missing_docs)]
+                #visibility enum #group_enum_name {
+                    #(#group_names,)*
+                }
+            ),
+            quote!(
+                #visibility fn with_group(mut self, group: #group_enum_name, enabled: bool) -> Self {
+                    let bit: u64 = match group {
+                        #(#group_enum_name::#group_names => #group_bits,)*
+                    };
+                    if enabled {
+                        self.0.__disabled_groups &= !bit;
+                    } else {
+                        self.0.__disabled_groups |= bit;
+                    }
+                    self
+                }
+            ),
+        )
+    };
+
+    // Compile-time check that the lexer is `Send` and `Sync` when the input iterator and the types
+    // in the lexer are. The bounds are higher-ranked so that they're not rejected as trivially
+    // false when a type is not `Send` or `Sync`.
+    let feedback_bound = feedback_type
+        .as_ref()
+        .map(|ty| quote!(for<'__send_sync> #ty: Send + Sync,));
+    let interner_bound = interner_type
+        .as_ref()
+        .map(|ty| quote!(for<'__send_sync> #ty: Send + Sync,));
+    let send_sync_assertion = quote!(
+        const _: () = {
+            fn __assert_send_sync<T: Send + Sync>() {}
+
+            #[allow(dead_code, clippy::multiple_bound_locations)]
+            fn __lexer_is_send_sync<'input #lifetime_params, I: Iterator<Item = char> + Clone + Send + Sync #generic_params>()
+            where
+                for<'__send_sync> #token_type: Send + Sync,
+                for<'__send_sync> #user_state_type: Send + Sync,
+                for<'__send_sync> #error_type: Send + Sync,
+                #feedback_bound
+                #interner_bound
+            {
+                __assert_send_sync::<#lexer_name<'input #lifetime_args, I #generic_args>>();
+            }
+        };
+    );
+
+    Ok(quote!(
         // An enum for the rule sets in the DFA. `Init` is the initial, unnamed rule set.
-        #[derive(Clone, Copy)]
-        enum #rule_name_enum_name {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(missing_docs)]
+        #visibility enum #rule_name_enum_name {
             #(#rule_name_idents,)*
         }
 
-        #visibility struct #lexer_name<'input, I: Iterator<Item = char> + Clone>(
+        #group_enum
+
+        #(#attrs)*
+        #visibility struct #lexer_name<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params>(
             ::lexgen_util::Lexer<
                 'input,
                 I,
                 #token_type,
                 #user_state_type,
                 #error_type,
-                #lexer_name<'input, I>
+                #lexer_name<'input #lifetime_args, I #generic_args>
+                #lexer_options
             >
+            #feedback_field
+            #interner_field
         );
 
         // Methods below for using in semantic actions
-        impl<'input, I: Iterator<Item = char> + Clone> #lexer_name<'input, I> {
+        #[allow(missing_docs)]
+        impl<'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params> #lexer_name<'input #lifetime_args, I #generic_args> {
             fn switch_and_return<T>(&mut self, rule: #rule_name_enum_name, token: T) -> ::lexgen_util::SemanticActionResult<T> {
                 self.switch::<T>(rule);
                 ::lexgen_util::SemanticActionResult::Return(token)
@@ -167,6 +709,22 @@ pub fn reify(
                 ::lexgen_util::SemanticActionResult::Continue
             }
 
+            // Semantic actions of opening and closing delimiters of `nested` rule sets
+            fn __nested_open<T>(&mut self) -> ::lexgen_util::SemanticActionResult<T> {
+                self.0.__nested_depth += 1;
+                ::lexgen_util::SemanticActionResult::Continue
+            }
+
+            fn __nested_close<T>(&mut self) -> ::lexgen_util::SemanticActionResult<T> {
+                if self.0.__nested_depth == 0 {
+                    self.0.__state = self.0.__nested_return_state;
+                    self.0.__initial_state = self.0.__state;
+                } else {
+                    self.0.__nested_depth -= 1;
+                }
+                ::lexgen_util::SemanticActionResult::Continue
+            }
+
             fn state(&mut self) -> &mut #user_state_type {
                 self.0.state()
             }
@@ -179,57 +737,120 @@ pub fn reify(
                 self.0.match_()
             }
 
+            fn match_bytes(&self) -> &'input [u8] {
+                self.0.match_bytes()
+            }
+
             fn match_loc(&self) -> (::lexgen_util::Loc, ::lexgen_util::Loc) {
                 self.0.match_loc()
             }
 
+            fn input(&self) -> &'input str {
+                self.0.input()
+            }
+
+            fn skip_to(&mut self, loc: ::lexgen_util::Loc) {
+                self.0.skip_to(loc)
+            }
+
+            fn scan<R>(&mut self, scanner: impl FnOnce(&mut dyn ::lexgen_util::Cursor<'input>) -> R) -> R {
+                scanner(&mut self.0)
+            }
+
+            #visibility fn location(&self) -> ::lexgen_util::Loc {
+                self.0.location()
+            }
+
+            fn set_location(&mut self, file: &str, line: u32) {
+                self.0.set_location(file, line)
+            }
+
+            #visibility fn file(&self, loc: ::lexgen_util::Loc) -> Option<&str> {
+                self.0.file(loc)
+            }
+
+            #visibility fn set_progress_callback(
+                &mut self,
+                interval: ::lexgen_util::ProgressInterval,
+                callback: impl Fn(::lexgen_util::Loc) + Send + Sync + 'static,
+            ) {
+                self.0.set_progress_callback(interval, callback)
+            }
+
+            #feedback_method
+
+            #interner_methods
+
+            #cancellation_method
+
+            #stats_method
+
+            #group_method
+
             fn peek(&mut self) -> Option<char> {
                 self.0.peek()
             }
         }
 
-        impl<'input> #lexer_name<'input, ::std::str::Chars<'input>> {
-            #visibility fn new(input: &'input str) -> Self {
-                #lexer_name(::lexgen_util::Lexer::new(input))
+        #[allow(missing_docs)]
+        impl<'input #lifetime_params #generic_params> #lexer_name<'input #lifetime_args, ::std::str::Chars<'input> #generic_args> {
+            #visibility fn new(input: &'input str) -> Self #default_state_bound {
+                #lexer_name(::lexgen_util::Lexer::new(input) #indentation #counters #feedback_init #interner_init)
             }
 
             #visibility fn new_with_state(input: &'input str, user_state: #user_state_type) -> Self {
-                #lexer_name(::lexgen_util::Lexer::new_with_state(input, user_state))
+                #lexer_name(::lexgen_util::Lexer::new_with_state(input, user_state) #indentation #counters #feedback_init #interner_init)
             }
+
+            #visibility fn new_at(input: &'input str, start: ::lexgen_util::Loc) -> Self #default_state_bound {
+                #lexer_name(::lexgen_util::Lexer::new_at(input, start) #indentation #counters #feedback_init #interner_init)
+            }
+
+            #visibility fn new_at_with_state(input: &'input str, start: ::lexgen_util::Loc, user_state: #user_state_type) -> Self {
+                #lexer_name(::lexgen_util::Lexer::new_at_with_state(input, start, user_state) #indentation #counters #feedback_init #interner_init)
+            }
+
+            #next_token
         }
 
-        impl<I: Iterator<Item = char> + Clone> #lexer_name<'static, I> {
-            #visibility fn new_from_iter(iter: I) -> Self {
-                #lexer_name(::lexgen_util::Lexer::new_from_iter(iter))
+        #[allow(missing_docs)]
+        impl<#(#static_impl_lifetimes,)* I: Iterator<Item = char> + Clone #generic_params> #lexer_name<'static #lifetime_args, I #generic_args> {
+            #visibility fn new_from_iter(iter: I) -> Self #default_state_bound {
+                #lexer_name(::lexgen_util::Lexer::new_from_iter(iter) #indentation #counters #feedback_init #interner_init)
             }
 
             #visibility fn new_from_iter_with_state(iter: I, user_state: #user_state_type) -> Self {
-                #lexer_name(::lexgen_util::Lexer::new_from_iter_with_state(iter, user_state))
+                #lexer_name(::lexgen_util::Lexer::new_from_iter_with_state(iter, user_state) #indentation #counters #feedback_init #interner_init)
             }
         }
 
+        #[allow(missing_docs)]
+        impl<'input #lifetime_params #generic_params> #lexer_name<'input #lifetime_args, ::lexgen_util::SingleByteChars<'input> #generic_args> {
+            #visibility fn new_from_single_byte(input: &'input [u8], encoding: ::lexgen_util::SingleByteEncoding) -> Self #default_state_bound {
+                #lexer_name(::lexgen_util::Lexer::new_from_single_byte(input, encoding) #indentation #counters #feedback_init #interner_init)
+            }
+
+            #visibility fn new_from_single_byte_with_state(input: &'input [u8], encoding: ::lexgen_util::SingleByteEncoding, user_state: #user_state_type) -> Self {
+                #lexer_name(::lexgen_util::Lexer::new_from_single_byte_with_state(input, encoding, user_state) #indentation #counters #feedback_init #interner_init)
+            }
+        }
+
+        #bytes_constructors
+
+        #lalrpop_aliases
+
         #(#search_tables)*
-        #binary_search_fn
+        #(#ascii_tables)*
+        #profile_dependency
         #semantic_action_fns
         #(#right_ctx_fns)*
 
-        impl<'input, I: Iterator<Item = char> + Clone> Iterator for #lexer_name<'input, I> {
-            type Item = Result<(::lexgen_util::Loc, #token_type, ::lexgen_util::Loc), ::lexgen_util::LexerError<#error_type>>;
+        #driver
 
-            fn next(&mut self) -> Option<Self::Item> {
-                loop {
-                    if self.0.__done {
-                        return None;
-                    }
+        #fill
 
-                    // println!("state = {:?}, next char = {:?}", self.0.__state, self.0.peek());
-                    match self.0.__state {
-                        #(#match_arms,)*
-                    }
-                }
-            }
-        }
-    )
+        #send_sync_assertion
+    ))
 }
 
 fn generate_switch(ctx: &CgCtx, enum_name: &syn::Ident) -> TokenStream {
@@ -244,8 +865,32 @@ fn generate_switch(ctx: &CgCtx, enum_name: &syn::Ident) -> TokenStream {
         ));
     }
 
+    // Maps initial states to rule sets, for tagging tokens with `tag_rule_sets` and for `on_error`
+    let rule_set_fn = if ctx.rule_set_fn() {
+        let mut rule_set_arms: Vec<TokenStream> = vec![];
+        for (rule_name, state_idx) in ctx.rule_states().iter() {
+            let StateIdx(state_idx) = ctx.renumber_state(*state_idx);
+            let rule_ident = syn::Ident::new(rule_name, Span::call_site());
+            rule_set_arms.push(quote!(#state_idx => #enum_name::#rule_ident));
+        }
+        quote!(
+            fn __rule_set(state: usize) -> #enum_name {
+                match state {
+                    #(#rule_set_arms,)*
+                    _ => unreachable!(),
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
     quote!(
+        #rule_set_fn
+
         fn switch<A>(&mut self, rule: #enum_name) -> ::lexgen_util::SemanticActionResult<A> {
+            // Rule set to switch back to when a `nested` rule set is closed
+            self.0.__nested_return_state = self.0.__initial_state;
             match rule {
                 #(#arms,)*
             }
@@ -255,37 +900,118 @@ fn generate_switch(ctx: &CgCtx, enum_name: &syn::Ident) -> TokenStream {
     )
 }
 
-/// Generate arms of `match self.__state { ... }` of a DFA.
+/// Generates `next_token`, which lexes one token at the location of a `lexgen_util::CharCursor`,
+/// with the user state and the rule set passed by the caller instead of stored in a lexer.
+/// Lexers with other state are rejected with `next_token = true;`, see `lexer_definition`.
+fn generate_next_token(
+    ctx: &CgCtx,
+    enum_name: &syn::Ident,
+    visibility: &TokenStream,
+    user_state_type: &TokenStream,
+    default_state_bound: &TokenStream,
+) -> TokenStream {
+    let mut rule_set_arms: Vec<TokenStream> = vec![];
+    for (rule_name, state_idx) in ctx.rule_states().iter() {
+        let StateIdx(state_idx) = ctx.renumber_state(*state_idx);
+        let rule_ident = syn::Ident::new(rule_name, Span::call_site());
+        rule_set_arms.push(quote!(#state_idx => #enum_name::#rule_ident));
+    }
+
+    quote!(
+        #visibility fn next_token(
+            cursor: &mut impl ::lexgen_util::CharCursor<'input>,
+            user_state: &mut #user_state_type,
+            rule_set: &mut #enum_name,
+        ) -> Option<<Self as Iterator>::Item> #default_state_bound {
+            let mut lexer = Self::new_at_with_state(
+                cursor.input(),
+                cursor.loc(),
+                ::std::mem::take(user_state),
+            );
+            lexer.switch::<()>(*rule_set);
+
+            let item = Iterator::next(&mut lexer);
+
+            *user_state = ::std::mem::take(lexer.0.state());
+            // After the end of the input the lexer is done, continue in `Init` so that the next
+            // call returns `None` instead of the same error
+            *rule_set = if lexer.0.__done {
+                #enum_name::Init
+            } else {
+                match lexer.0.__initial_state {
+                    #(#rule_set_arms,)*
+                    _ => unreachable!(),
+                }
+            };
+            cursor.set_loc(lexer.0.location());
+            item
+        }
+    )
+}
+
+/// Code for a non-inlined state in the DFA
+struct StateArm {
+    /// Pattern for the state in `match self.__state { ... }`
+    pat: TokenStream,
+
+    /// Index of the state in the generated code
+    state_idx: usize,
+
+    /// Code for the state, without braces
+    code: TokenStream,
+}
+
+/// Generate code for the states of a DFA, to be used in `match self.__state { ... }` arms or state
+/// functions.
+///
+/// States are generated hottest first, see `layout::hot_state_order`.
 fn generate_state_arms(
     ctx: &mut CgCtx,
     dfa: DFA<Trans<SemanticActionIdx>, SemanticActionIdx>,
-) -> Vec<TokenStream> {
+    profile: Option<&str>,
+) -> Vec<StateArm> {
     let DFA { states } = dfa;
 
-    let mut match_arms: Vec<TokenStream> = vec![];
+    let mut state_arms: Vec<StateArm> = vec![];
 
-    let n_states = states.len();
+    let init = ctx
+        .rule_states()
+        .get("Init")
+        .copied()
+        .unwrap_or(StateIdx(0));
+
+    for state_idx in layout::hot_state_order(&states, init, profile) {
+        let state = &states[state_idx];
 
-    for (state_idx, state) in states.iter().enumerate() {
         if state.predecessors.len() == 1 && !state.initial {
             continue;
         }
 
-        let state_code: TokenStream = generate_state_arm(ctx, state_idx, state, &states);
+        let mut state_code: TokenStream = generate_state_arm(ctx, state_idx, state, &states);
+
+        // With `cancellation = true;`, check the cancellation flag at the start of each match.
+        // Initial states are only entered from the main loop at the start of a match, transitions
+        // back to an initial state are inlined.
+        if state.initial && ctx.cancellation() {
+            let check = generate_cancellation_check(ctx);
+            state_code = quote!(#check #state_code);
+        }
 
         let StateIdx(state_idx) = ctx.renumber_state(StateIdx(state_idx));
-        let state_idx_pat = if state_idx == n_states - ctx.n_inlined_states() - 1 {
-            quote!(_)
-        } else {
-            quote!(#state_idx)
-        };
 
-        match_arms.push(quote!(
-            #state_idx_pat => { #state_code }
-        ));
+        state_arms.push(StateArm {
+            pat: quote!(#state_idx),
+            state_idx,
+            code: state_code,
+        });
     }
 
-    match_arms
+    // Last arm matches all other states, to make the `match` exhaustive
+    if let Some(last_arm) = state_arms.last_mut() {
+        last_arm.pat = quote!(_);
+    }
+
+    state_arms
 }
 
 // NB. Does not generate braces around the code
@@ -297,22 +1023,92 @@ fn generate_state_arm(
 ) -> TokenStream {
     let State {
         initial,
-        char_transitions,
-        range_transitions,
+        transitions,
         any_transition,
         end_of_input_transition,
         accepting,
         predecessors: _,
+        provenance: _,
     } = state;
 
+    let custom_matchers = ctx.has_custom_matchers();
+
+    // When no rule matches, run the `fail` rule of the current rule set, if it has one
+    let fail_arms: Vec<TokenStream> = ctx
+        .fail_actions()
+        .map(|(state, action)| {
+            let StateIdx(state) = ctx.renumber_state(state);
+            let action_fn = ctx.semantic_action_fn_ident(action);
+            let call = generate_semantic_action_call(ctx, &action_fn.to_token_stream(), false);
+            quote!(#state => #call,)
+        })
+        .collect();
+
+    let on_error = generate_on_error_call(
+        ctx,
+        &quote!(err),
+        &quote!(self.0.match_loc()),
+        &quote!(self.0.__initial_state),
+    );
+
+    let raise = if fail_arms.is_empty() {
+        quote!({
+            #on_error
+            return Some(Err(err))
+        })
+    } else {
+        quote!(match self.0.__initial_state {
+            #(#fail_arms)*
+            _ => {
+                #on_error
+                return Some(Err(err))
+            }
+        })
+    };
+
+    let action = generate_semantic_action_call(ctx, &quote!(semantic_action), custom_matchers);
+
     let fail = || -> TokenStream {
-        let action = generate_semantic_action_call(&quote!(semantic_action));
+        // When regex rules fail, a custom matcher may still have matched
+        let err = if custom_matchers {
+            quote!(match self.0.take_custom_match(true) {
+                None => #raise,
+                Some(semantic_action) => #action,
+            })
+        } else {
+            raise.clone()
+        };
         quote!(match self.0.backtrack() {
-            Err(err) => return Some(Err(err)),
+            Err(err) => #err,
             Ok(semantic_action) => #action,
         })
     };
 
+    // Run matchers of `fn <matcher>` rules at the start of each match in the rule set
+    let run_custom_matchers = {
+        let matchers: Vec<TokenStream> = ctx
+            .custom_matchers(StateIdx(state_idx))
+            .iter()
+            .map(|(matcher, action)| {
+                let action_fn = ctx.semantic_action_fn_ident(*action);
+                quote!(self.0.run_custom_matcher(#matcher, #action_fn);)
+            })
+            .collect();
+        if matchers.is_empty() {
+            quote!()
+        } else {
+            quote!(
+                if self.0.start_custom_matchers() {
+                    #(#matchers)*
+                }
+            )
+        }
+    };
+
+    if let Some((chain, last_transition)) = linear_chain(state_idx, states) {
+        return generate_linear_chain(ctx, states, &chain, last_transition, fail());
+    }
+
     // When we can't take char or range transitions, take the 'any' transition if it exists, or
     // fail (backtrack or raise error)
     let default_action = any_transition
@@ -320,13 +1116,8 @@ fn generate_state_arm(
         .map(|any_transition| generate_any_transition(ctx, states, any_transition, fail()))
         .unwrap_or_else(fail);
 
-    let state_char_arms = generate_state_char_arms(
-        ctx,
-        states,
-        char_transitions,
-        range_transitions,
-        &default_action,
-    );
+    let state_char_arms =
+        generate_state_char_arms(ctx, states, *initial, transitions, &default_action);
 
     // In initial state (rule `Init`) unhandled end-of-input yields `None`. In other states we
     // expect to see a end-of-input handler, or fail with "unexpected end-of-input".
@@ -354,6 +1145,15 @@ fn generate_state_arm(
         #end_of_input_action
     );
 
+    // With byte input `next` also returns `None` for invalid UTF-8, which fails like a character
+    // without a transition. `backtrack` handles invalid UTF-8 at the beginning of a match.
+    let invalid_utf8_arm = if ctx.byte_input() {
+        let fail = fail();
+        quote!(None if self.0.invalid_utf8() => { #fail })
+    } else {
+        quote!()
+    };
+
     if state_idx == 0 {
         assert!(initial);
 
@@ -361,7 +1161,10 @@ fn generate_state_arm(
         quote!(
             self.reset_match();
 
+            #run_custom_matchers
+
             match self.0.next() {
+                #invalid_utf8_arm
                 None => {
                     #end_of_input_action
                 }
@@ -377,18 +1180,13 @@ fn generate_state_arm(
         let mut rhss: Vec<(TokenStream, TokenStream)> = Vec::with_capacity(accepting.len());
         let mut default = quote!();
 
-        for AcceptingState { value, right_ctx } in accepting.iter() {
-            match right_ctx {
-                Some(right_ctx) => {
-                    let right_ctx_fn = right_ctx_fn_name(ctx.lexer_name(), right_ctx);
-                    let semantic_fn = ctx.semantic_action_fn_ident(*value);
-                    rhss.push((
-                        quote!(#right_ctx_fn(self.0.__iter.clone())),
-                        quote!(self.0.set_accepting_state(#semantic_fn)),
-                    ));
+        for accepting_state in accepting.iter() {
+            let semantic_fn = ctx.semantic_action_fn_ident(accepting_state.value);
+            match accepting_condition(ctx, accepting_state) {
+                Some(cond) => {
+                    rhss.push((cond, quote!(self.0.set_accepting_state(#semantic_fn))));
                 }
                 None => {
-                    let semantic_fn = ctx.semantic_action_fn_ident(*value);
                     default = quote!(self.0.set_accepting_state(#semantic_fn););
                     break;
                 }
@@ -402,9 +1200,29 @@ fn generate_state_arm(
         }
 
         quote!(
-            #set_accepting_state
+            #run_custom_matchers
+
+            #set_accepting_state
+
+            match self.0.next() {
+                #invalid_utf8_arm
+                None => {
+                    #end_of_input_action
+                }
+                Some(char) => {
+                    match char {
+                        #(#state_char_arms,)*
+                    }
+                }
+            }
+        )
+    } else {
+        // Non-accepting state
+        quote!(
+            #run_custom_matchers
 
             match self.0.next() {
+                #invalid_utf8_arm
                 None => {
                     #end_of_input_action
                 }
@@ -415,21 +1233,97 @@ fn generate_state_arm(
                 }
             }
         )
-    } else {
-        // Non-accepting state
-        quote!(match self.0.next() {
-            None => {
-                #end_of_input_action
+    }
+}
+
+/// Whether a state can be fused with its neighbors in a linear chain: the state is not initial or
+/// accepting, and only has one char transition.
+fn is_chain_link(state: &State<Trans<SemanticActionIdx>, SemanticActionIdx>) -> bool {
+    !state.initial
+        && state.accepting.is_empty()
+        && state.transitions.len() == 1
+        && state.char_transitions().next().is_some()
+        && state.any_transition.is_none()
+        && state.end_of_input_transition.is_none()
+}
+
+/// If the state is the first state of a chain of at least two links (see `is_chain_link`), where
+/// each link except the first one is inlined in its predecessor, returns the string matched by the
+/// chain and the transition of the last link in the chain.
+///
+/// Chains like this are common in keyword and operator rules, e.g. `"return"`. Instead of
+/// generating one state per character we match the whole string at once.
+fn linear_chain(
+    state_idx: usize,
+    states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
+) -> Option<(String, &Trans<SemanticActionIdx>)> {
+    let mut state = &states[state_idx];
+
+    if !is_chain_link(state) {
+        return None;
+    }
+
+    let mut chain = String::new();
+
+    loop {
+        let (char, trans) = state.char_transitions().next().unwrap();
+        chain.push(char);
+
+        match trans {
+            Trans::Trans(StateIdx(next_state))
+                if *next_state != state_idx
+                    && states[*next_state].predecessors.len() == 1
+                    && is_chain_link(&states[*next_state]) =>
+            {
+                state = &states[*next_state];
             }
-            Some(char) => {
-                match char {
-                    #(#state_char_arms,)*
+            _ => {
+                if chain.chars().count() < 2 {
+                    return None;
                 }
+                return Some((chain, trans));
             }
-        })
+        }
     }
 }
 
+fn generate_linear_chain(
+    ctx: &mut CgCtx,
+    states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
+    chain: &str,
+    last_transition: &Trans<SemanticActionIdx>,
+    fail: TokenStream,
+) -> TokenStream {
+    let next = match last_transition {
+        Trans::Trans(StateIdx(next_state)) => {
+            if states[*next_state].predecessors.len() == 1 {
+                generate_state_arm(ctx, *next_state, &states[*next_state], states)
+            } else {
+                let StateIdx(next_state) = ctx.renumber_state(StateIdx(*next_state));
+                quote!(self.0.__state = #next_state;)
+            }
+        }
+        Trans::Accept(accepting_states) => test_right_ctxs(ctx, accepting_states, fail.clone()),
+    };
+
+    // Links in the chain don't have end-of-input transitions, so end-of-input fails the same way
+    // it fails in a single state
+    quote!(
+        match self.0.consume_str(#chain) {
+            Ok(()) => {
+                #next
+            }
+            Err(None) => {
+                self.0.__done = true; // don't handle end-of-input again
+                #fail
+            }
+            Err(Some(_)) => {
+                #fail
+            }
+        }
+    )
+}
+
 fn generate_any_transition(
     ctx: &mut CgCtx,
     states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
@@ -458,19 +1352,40 @@ fn generate_any_transition(
 fn generate_state_char_arms(
     ctx: &mut CgCtx,
     states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
-    char_transitions: &Map<char, Trans<SemanticActionIdx>>,
-    range_transitions: &RangeMap<Trans<SemanticActionIdx>>,
+    initial: bool,
+    transitions: &RangeMap<Trans<SemanticActionIdx>>,
     // RHS of the default alternative for this `match` (_ => <default_rhs>)
     default_rhs: &TokenStream,
 ) -> Vec<TokenStream> {
+    if initial && use_dispatch_table(ctx, transitions) {
+        return vec![generate_state_dispatch_arm(
+            ctx,
+            states,
+            transitions,
+            default_rhs,
+        )];
+    }
+
+    let range_checks = state_range_checks(ctx, transitions);
+
+    if range_checks == RangeChecks::BinarySearch {
+        return vec![generate_state_search_arm(
+            ctx,
+            states,
+            transitions,
+            default_rhs,
+        )];
+    }
+
     // Arms of the `match` for the current character
     let mut state_char_arms: Vec<TokenStream> = vec![];
 
     // Collect characters for next states, to be able to use or patterns in arms and reduce code
     // size
     let mut state_chars: Map<StateIdx, Vec<char>> = Default::default();
-    for (char, next) in char_transitions {
-        match next {
+    for range in transitions.iter().filter(|range| range.start == range.end) {
+        let char = char::try_from(range.start).unwrap();
+        match &range.value {
             Trans::Accept(accepting) => {
                 let action_code = test_right_ctxs(ctx, accepting, default_rhs.clone());
                 state_char_arms.push(quote!(
@@ -479,7 +1394,7 @@ fn generate_state_char_arms(
                     }
                 ));
             }
-            Trans::Trans(state_idx) => state_chars.entry(*state_idx).or_default().push(*char),
+            Trans::Trans(state_idx) => state_chars.entry(*state_idx).or_default().push(char),
         }
     }
 
@@ -506,7 +1421,7 @@ fn generate_state_char_arms(
     // Same as above for range transitions. Use chain of "or"s for ranges with same transition.
     let mut state_ranges: Map<StateIdx, Vec<(char, char)>> = Default::default();
 
-    for range in range_transitions.iter() {
+    for range in transitions.iter().filter(|range| range.start != range.end) {
         match &range.value {
             Trans::Trans(state_idx) => state_ranges.entry(*state_idx).or_default().push((
                 char::try_from(range.start).unwrap(),
@@ -530,24 +1445,7 @@ fn generate_state_char_arms(
 
     // Add range transitions
     for (StateIdx(next_state), ranges) in state_ranges.into_iter() {
-        let guard = if ranges.len() > MAX_GUARD_SIZE {
-            let binary_search_table_id = ctx.add_search_table(ranges);
-
-            quote!(binary_search(x, &#binary_search_table_id))
-        } else {
-            let range_checks: Vec<TokenStream> = ranges
-                .into_iter()
-                .map(|(range_begin, range_end)| {
-                    if range_begin == range_end {
-                        quote!(x == #range_begin)
-                    } else {
-                        quote!((x >= #range_begin && x <= #range_end))
-                    }
-                })
-                .collect();
-
-            quote!(#(#range_checks)||*)
-        };
+        let guard = generate_range_guard(ctx, ranges, range_checks);
 
         let next = if states[next_state].predecessors.len() == 1 {
             generate_state_arm(ctx, next_state, &states[next_state], states)
@@ -570,10 +1468,163 @@ fn generate_state_char_arms(
     state_char_arms
 }
 
+/// Generate one arm for `match char { ... }` that finds the transition for the character with
+/// binary search over all transitions of the state, and then matches on the index of the
+/// transition's target.
+fn generate_state_search_arm(
+    ctx: &mut CgCtx,
+    states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
+    transitions: &RangeMap<Trans<SemanticActionIdx>>,
+    default_rhs: &TokenStream,
+) -> TokenStream {
+    let (targets, transition_targets) = transition_targets(transitions);
+
+    let search_table: Vec<TokenStream> = transitions
+        .iter()
+        .zip(transition_targets)
+        .map(|(range, target_idx)| {
+            let start = char::try_from(range.start).unwrap();
+            let end = char::try_from(range.end).unwrap();
+            quote!((#start, #end, #target_idx))
+        })
+        .collect();
+
+    let target_arms = generate_target_arms(ctx, states, &targets, default_rhs);
+
+    quote!(
+        x => match ::lexgen_util::binary_search_transitions(x, &[#(#search_table),*]) {
+            #(#target_arms)*
+            _ => { #default_rhs }
+        }
+    )
+}
+
+/// Whether to compile the transitions of an initial state to a dispatch table, see
+/// `generate_state_dispatch_arm`. Dispatch tables are used for initial states with many
+/// transitions on characters below U+0100, unless overridden with the `range_checks` option.
+fn use_dispatch_table<A>(ctx: &CgCtx, transitions: &RangeMap<Trans<A>>) -> bool {
+    ctx.range_checks() == RangeChecks::Auto
+        && transitions.iter().filter(|range| range.start < 256).count() > MAX_GUARD_SIZE
+        && transition_targets(transitions).0.len() < usize::from(u8::MAX)
+}
+
+/// Generate one arm for `match char { ... }` of an initial state, that looks up the target of the
+/// transition for characters below U+0100 in a 256-entry table, like hand-written lexers dispatch
+/// on the first character of a token. Targets of other characters are found with binary search.
+fn generate_state_dispatch_arm(
+    ctx: &mut CgCtx,
+    states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
+    transitions: &RangeMap<Trans<SemanticActionIdx>>,
+    default_rhs: &TokenStream,
+) -> TokenStream {
+    let (targets, transition_targets) = transition_targets(transitions);
+
+    // `u8::MAX` for characters without a transition
+    let mut dispatch_table: Vec<u8> = vec![u8::MAX; 256];
+    let mut search_table: Vec<TokenStream> = vec![];
+
+    for (range, target_idx) in transitions.iter().zip(transition_targets) {
+        for char in range.start..=range.end.min(255) {
+            dispatch_table[char as usize] = target_idx as u8;
+        }
+        if range.end >= 256 {
+            let start = char::try_from(range.start.max(256)).unwrap();
+            let end = char::try_from(range.end).unwrap();
+            search_table.push(quote!((#start, #end, #target_idx)));
+        }
+    }
+
+    let search = if search_table.is_empty() {
+        quote!(None)
+    } else {
+        quote!(::lexgen_util::binary_search_transitions(x, &[#(#search_table),*]))
+    };
+
+    let target_arms = generate_target_arms(ctx, states, &targets, default_rhs);
+
+    quote!(
+        x => {
+            static DISPATCH: [u8; 256] = [#(#dispatch_table),*];
+            let target = if (x as u32) < 256 {
+                match DISPATCH[x as usize] {
+                    u8::MAX => None,
+                    target => Some(target as usize),
+                }
+            } else {
+                #search
+            };
+            match target {
+                #(#target_arms)*
+                _ => { #default_rhs }
+            }
+        }
+    )
+}
+
+/// Targets of the transitions of a state, and the index of the target of each transition.
+/// Transitions to the same state share a target, accepting transitions have their own targets.
+fn transition_targets<A>(transitions: &RangeMap<Trans<A>>) -> (Vec<&Trans<A>>, Vec<usize>) {
+    let mut targets: Vec<&Trans<A>> = vec![];
+    let mut transition_targets: Vec<usize> = Vec::with_capacity(transitions.len());
+
+    for range in transitions.iter() {
+        let target_idx = match &range.value {
+            Trans::Trans(state_idx) => targets
+                .iter()
+                .position(|target| matches!(target, Trans::Trans(target) if target == state_idx)),
+            Trans::Accept(_) => None,
+        }
+        .unwrap_or_else(|| {
+            targets.push(&range.value);
+            targets.len() - 1
+        });
+
+        transition_targets.push(target_idx);
+    }
+
+    (targets, transition_targets)
+}
+
+/// Generate `Some(<target index>) => { ... }` arms for taking the transitions to the targets
+fn generate_target_arms(
+    ctx: &mut CgCtx,
+    states: &[State<Trans<SemanticActionIdx>, SemanticActionIdx>],
+    targets: &[&Trans<SemanticActionIdx>],
+    default_rhs: &TokenStream,
+) -> Vec<TokenStream> {
+    targets
+        .iter()
+        .enumerate()
+        .map(|(target_idx, target)| {
+            let code = match target {
+                Trans::Accept(accepting) => test_right_ctxs(ctx, accepting, default_rhs.clone()),
+                Trans::Trans(StateIdx(next_state)) => {
+                    if states[*next_state].predecessors.len() == 1 {
+                        generate_state_arm(ctx, *next_state, &states[*next_state], states)
+                    } else {
+                        let StateIdx(next_state) = ctx.renumber_state(StateIdx(*next_state));
+                        quote!(
+                            self.0.__state = #next_state;
+                        )
+                    }
+                }
+            };
+            quote!(
+                Some(#target_idx) => {
+                    #code
+                }
+            )
+        })
+        .collect()
+}
+
 /// Generate call to the semantic action function with the given index and handle the result.
 fn generate_rhs_code(ctx: &CgCtx, action: SemanticActionIdx) -> TokenStream {
-    let semantic_action_call =
-        generate_semantic_action_call(&ctx.semantic_action_fn_ident(action).into_token_stream());
+    let semantic_action_call = generate_semantic_action_call(
+        ctx,
+        &ctx.semantic_action_fn_ident(action).into_token_stream(),
+        ctx.has_custom_matchers(),
+    );
 
     quote!(
         self.0.reset_accepting_state();
@@ -581,17 +1632,63 @@ fn generate_rhs_code(ctx: &CgCtx, action: SemanticActionIdx) -> TokenStream {
     )
 }
 
-/// Generate call to the given semantic action function and handle the result.
-fn generate_semantic_action_call(action_fn: &TokenStream) -> TokenStream {
+/// Generate call to the given semantic action function and handle the result. With
+/// `custom_matchers`, a longer match of a `fn <matcher>` rule is taken instead. With
+/// `tag_rule_sets`, the token is tagged with the rule set of the match, before the semantic action
+/// can switch to another rule set. With `on_error`, errors are passed to the hook with the rule set
+/// of the match as well.
+fn generate_semantic_action_call(
+    ctx: &CgCtx,
+    action_fn: &TokenStream,
+    custom_matchers: bool,
+) -> TokenStream {
+    let save_rule_set = if ctx.rule_set_fn() {
+        quote!(let rule_set = self.0.__initial_state;)
+    } else {
+        quote!()
+    };
+
+    let token = if ctx.tag_rule_sets() {
+        quote!(::lexgen_util::Tagged {
+            rule_set: Self::__rule_set(rule_set),
+            token: tok,
+        })
+    } else {
+        quote!(tok)
+    };
+
+    let on_error = generate_on_error_call(
+        ctx,
+        &quote!(err),
+        &quote!((match_start, match_end)),
+        &quote!(rule_set),
+    );
+
     let map_res = quote!(match res {
-        Ok(tok) => Ok((match_start, tok, match_end)),
-        Err(err) => Err(::lexgen_util::LexerError {
-            location: self.match_loc().0,
-            kind: ::lexgen_util::LexerErrorKind::Custom(err),
-        }),
+        Ok(tok) => {
+            self.0.__token_returned(match_end);
+            Ok((match_start, #token, match_end))
+        }
+        Err(err) => {
+            let err = ::lexgen_util::LexerError {
+                location: self.match_loc().0,
+                kind: ::lexgen_util::LexerErrorKind::Custom(err),
+            };
+            #on_error
+            Err(err)
+        }
     });
 
-    quote!(match #action_fn(self) {
+    let call = if custom_matchers {
+        quote!(match self.0.take_custom_match(false) {
+            Some(semantic_action) => semantic_action(self),
+            None => #action_fn(self),
+        })
+    } else {
+        quote!(#action_fn(self))
+    };
+
+    quote!({ #save_rule_set match #call {
         ::lexgen_util::SemanticActionResult::Continue => {
             self.0.__state = self.0.__initial_state;
         }
@@ -601,15 +1698,57 @@ fn generate_semantic_action_call(action_fn: &TokenStream) -> TokenStream {
             self.0.reset_match();
             return Some(#map_res);
         }
-    })
+    }})
+}
+
+/// Generate the check of the cancellation flag, which ends the input with a `Cancelled` error
+fn generate_cancellation_check(ctx: &CgCtx) -> TokenStream {
+    let on_error = generate_on_error_call(
+        ctx,
+        &quote!(err),
+        &quote!((err.location, err.location)),
+        &quote!(self.0.__initial_state),
+    );
+    quote!(
+        if self.0.__cancelled() {
+            self.0.__done = true;
+            let err = ::lexgen_util::LexerError {
+                location: self.0.location(),
+                kind: ::lexgen_util::LexerErrorKind::Cancelled,
+            };
+            #on_error
+            return Some(Err(err));
+        }
+    )
+}
+
+/// Generate call to the `on_error` hook, when the lexer has one, with the given error, span of the
+/// error, and initial state of the rule set
+fn generate_on_error_call(
+    ctx: &CgCtx,
+    err: &TokenStream,
+    span: &TokenStream,
+    rule_set_state: &TokenStream,
+) -> TokenStream {
+    match ctx.on_error() {
+        None => quote!(),
+        Some(hook) => quote!({
+            let on_error_span = #span;
+            let on_error_rule_set = Self::__rule_set(#rule_set_state);
+            #hook(self.0.state(), &#err, on_error_span, on_error_rule_set);
+        }),
+    }
 }
 
 fn generate_semantic_action_fns(
     ctx: &CgCtx,
     semantic_action_fn_ret_ty: &TokenStream,
+    captures: &Map<SemanticActionIdx, CaptureParts<DFA<StateIdx, ()>>>,
 ) -> TokenStream {
     let lexer_name = ctx.lexer_name();
     let token_type = ctx.token_type();
+    let (lifetime_params, lifetime_args) = lifetime_params_args(ctx.generics());
+    let (generic_params, generic_args) = generic_params_args(ctx.generics());
 
     let fns: Vec<TokenStream> = ctx
         .iter_semantic_actions()
@@ -618,19 +1757,28 @@ fn generate_semantic_action_fns(
 
             let rhs = match action {
                 RuleRhs::None => {
-                    quote!(|__lexer: &mut #lexer_name<'input, I>| __lexer.continue_().map_token(Ok))
+                    quote!(|__lexer: &mut #lexer_name<'input #lifetime_args, I #generic_args>| __lexer.continue_().map_token(Ok))
                 }
 
                 RuleRhs::Rhs { expr, kind } => {
                     match kind {
                         RuleKind::Simple => {
-                            quote!(|__lexer: &'lexer mut #lexer_name<'input, I>| __lexer.return_(#expr).map_token(Ok))
+                            quote!(|__lexer: &'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>| __lexer.return_(#expr).map_token(Ok))
                         }
                         RuleKind::Fallible => quote!(#expr),
+                        RuleKind::Infallible if captures.contains_key(&idx) => {
+                            quote!(|__lexer: &'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>| {
+                                let semantic_action:
+                                    &dyn Fn(&'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>) -> ::lexgen_util::SemanticActionResult<#token_type> =
+                                        &#expr;
+
+                                semantic_action(__lexer).map_token(Ok)
+                            })
+                        }
                         RuleKind::Infallible => {
-                            quote!(|__lexer: &'lexer mut #lexer_name<'input, I>| {
+                            quote!(|__lexer: &'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>| {
                                 let semantic_action:
-                                    fn(&'lexer mut #lexer_name<'input, I>) -> ::lexgen_util::SemanticActionResult<#token_type> =
+                                    fn(&'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>) -> ::lexgen_util::SemanticActionResult<#token_type> =
                                         #expr;
 
                                 semantic_action(__lexer).map_token(Ok)
@@ -640,13 +1788,28 @@ fn generate_semantic_action_fns(
                 }
             };
 
-            quote!(
-                #[allow(non_snake_case)]
-                fn #ident<'lexer, 'input, I: Iterator<Item = char> + Clone>(lexer: &'lexer mut #lexer_name<'input, I>) -> #semantic_action_fn_ret_ty {
-                    let action: fn(&'lexer mut #lexer_name<'input, I>) -> #semantic_action_fn_ret_ty = #rhs;
-                    action(lexer)
+            // Actions of rules with captures are closures capturing the captured parts of the
+            // match, which can't be coerced to `fn` types
+            match captures.get(&idx) {
+                None => quote!(
+                    #[allow(non_snake_case)]
+                    fn #ident<'lexer, 'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params>(lexer: &'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>) -> #semantic_action_fn_ret_ty {
+                        let action: fn(&'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>) -> #semantic_action_fn_ret_ty = #rhs;
+                        action(lexer)
+                    }
+                ),
+                Some(parts) => {
+                    let bindings = captures::capture_bindings(parts);
+                    quote!(
+                        #[allow(non_snake_case)]
+                        fn #ident<'lexer, 'input #lifetime_params, I: Iterator<Item = char> + Clone #generic_params>(__lexer: &'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>) -> #semantic_action_fn_ret_ty {
+                            #bindings
+                            let action: &dyn Fn(&'lexer mut #lexer_name<'input #lifetime_args, I #generic_args>) -> #semantic_action_fn_ret_ty = &#rhs;
+                            action(__lexer)
+                        }
+                    )
                 }
-            )
+            }
         })
         .collect();
 
@@ -720,16 +1883,15 @@ fn generate_right_ctx_state_arm(
 ) -> TokenStream {
     let State {
         initial: _,
-        char_transitions,
-        range_transitions,
+        transitions,
         any_transition,
         end_of_input_transition,
         accepting,
         predecessors: _,
+        provenance: _,
     } = state;
 
-    let state_char_arms =
-        generate_right_ctx_state_char_arms(ctx, states, char_transitions, range_transitions);
+    let state_char_arms = generate_right_ctx_state_char_arms(ctx, states, transitions);
 
     // Make sure right contexts don't have right contexts. We don't allow this in the syntax
     // currently.
@@ -768,8 +1930,7 @@ fn generate_right_ctx_state_arm(
 fn generate_right_ctx_state_char_arms(
     ctx: &mut CgCtx,
     states: &[State<StateIdx, ()>],
-    char_transitions: &Map<char, StateIdx>,
-    range_transitions: &RangeMap<StateIdx>,
+    transitions: &RangeMap<StateIdx>,
 ) -> Vec<TokenStream> {
     // Arms of the `match` for the current character
     let mut state_char_arms: Vec<TokenStream> = vec![];
@@ -781,11 +1942,13 @@ fn generate_right_ctx_state_char_arms(
     // Set of chars that transition to an accepting state
     let mut accept_chars: Set<char> = Default::default();
 
-    for (char, next) in char_transitions {
+    for range in transitions.iter().filter(|range| range.start == range.end) {
+        let char = char::try_from(range.start).unwrap();
+        let next = range.value;
         if states[next.0].accepting.is_empty() {
-            state_chars.entry(*next).or_default().push(*char);
+            state_chars.entry(next).or_default().push(char);
         } else {
-            accept_chars.insert(*char);
+            accept_chars.insert(char);
         }
     }
 
@@ -804,11 +1967,13 @@ fn generate_right_ctx_state_char_arms(
     let mut state_ranges: Map<StateIdx, Vec<(char, char)>> = Default::default();
     let mut accept_ranges: Set<(char, char)> = Default::default();
 
+    let range_checks = state_range_checks(ctx, transitions);
+
     for Range {
         start,
         end,
         value: next,
-    } in range_transitions.iter()
+    } in transitions.iter().filter(|range| range.start != range.end)
     {
         let start = char::try_from(*start).unwrap();
         let end = char::try_from(*end).unwrap();
@@ -822,39 +1987,13 @@ fn generate_right_ctx_state_char_arms(
 
     // Add range transitions
     for (StateIdx(next_state), ranges) in state_ranges.into_iter() {
-        let guard = if ranges.len() > MAX_GUARD_SIZE {
-            let binary_search_table_id = ctx.add_search_table(ranges);
-
-            quote!(binary_search(x, &#binary_search_table_id))
-        } else {
-            let range_checks: Vec<TokenStream> = ranges
-                .into_iter()
-                .map(|(range_begin, range_end)| {
-                    inclusive_range_contains(quote!(x), range_begin, range_end)
-                })
-                .collect();
-
-            quote!(#(#range_checks)||*)
-        };
+        let guard = generate_range_guard(ctx, ranges, range_checks);
 
         state_char_arms.push(quote!(x if #guard => state = #next_state));
     }
 
     if !accept_ranges.is_empty() {
-        let guard = if accept_ranges.len() > MAX_GUARD_SIZE {
-            let binary_search_table_id = ctx.add_search_table(accept_ranges.into_iter().collect());
-
-            quote!(binary_search(x, &#binary_search_table_id))
-        } else {
-            let range_checks: Vec<TokenStream> = accept_ranges
-                .into_iter()
-                .map(|(range_begin, range_end)| {
-                    inclusive_range_contains(quote!(x), range_begin, range_end)
-                })
-                .collect();
-
-            quote!(#(#range_checks)||*)
-        };
+        let guard = generate_range_guard(ctx, accept_ranges.into_iter().collect(), range_checks);
 
         state_char_arms.push(quote!(x if #guard => return true));
     }
@@ -870,13 +2009,10 @@ fn test_right_ctxs(
     let mut alts: Vec<(TokenStream, TokenStream)> = Vec::with_capacity(accepting_states.len());
     let mut default = default_rhs;
 
-    for AcceptingState { value, right_ctx } in accepting_states {
-        let action_code = generate_rhs_code(ctx, *value);
-        match right_ctx {
-            Some(right_ctx) => {
-                let right_ctx_fn = right_ctx_fn_name(ctx.lexer_name(), right_ctx);
-                alts.push((quote!(#right_ctx_fn(self.0.__iter.clone())), action_code));
-            }
+    for accepting_state in accepting_states {
+        let action_code = generate_rhs_code(ctx, accepting_state.value);
+        match accepting_condition(ctx, accepting_state) {
+            Some(cond) => alts.push((cond, action_code)),
             None => {
                 default = action_code;
                 break;
@@ -893,10 +2029,143 @@ fn test_right_ctxs(
     action_code
 }
 
+/// Condition for accepting a rule with a right context or a guard, `None` for other rules
+fn accepting_condition(
+    ctx: &CgCtx,
+    accepting_state: &AcceptingState<SemanticActionIdx>,
+) -> Option<TokenStream> {
+    let right_ctx = accepting_state.right_ctx.map(|right_ctx| {
+        let right_ctx_fn = right_ctx_fn_name(ctx.lexer_name(), &right_ctx);
+        quote!(#right_ctx_fn(self.0.__iter.clone()))
+    });
+
+    let guard = ctx.guard(accepting_state.value).map(|guard| {
+        if ctx.has_feedback() {
+            quote!({
+                let match_ = self.0.match_();
+                #guard(self.0.state(), &self.1, match_)
+            })
+        } else {
+            quote!(#guard(self.0.state()))
+        }
+    });
+
+    // Group check is cheapest, so done first
+    let group = ctx.rule_group(accepting_state.value).map(|group| {
+        let bit = 1u64 << group;
+        quote!((self.0.__disabled_groups & #bit == 0))
+    });
+
+    let mut conds = group.into_iter().chain(guard).chain(right_ctx);
+    let first = conds.next()?;
+    Some(conds.fold(first, |cond, next| quote!(#cond && #next)))
+}
+
+/// Choose how to compile range checks of a state with the given transitions. Only ranges of more
+/// than one character are taken into account, single characters are matched with patterns.
+///
+/// Unless overridden with the `range_checks` option, this uses chains of comparisons for states
+/// with a few ranges, tables for states with many ASCII ranges, and binary search for states with
+/// many ranges. Never returns `RangeChecks::Auto`.
+fn state_range_checks<A>(ctx: &CgCtx, transitions: &RangeMap<A>) -> RangeChecks {
+    match ctx.range_checks() {
+        RangeChecks::Auto => {
+            let ranges = || transitions.iter().filter(|range| range.start != range.end);
+            let n_ranges = ranges().count();
+            let all_ascii = ranges().all(|range| range.end < 128);
+
+            if all_ascii && (ctx.ascii() || n_ranges >= MIN_TABLE_RANGES) {
+                RangeChecks::Table
+            } else if n_ranges > MAX_GUARD_SIZE {
+                RangeChecks::BinarySearch
+            } else {
+                RangeChecks::IfChain
+            }
+        }
+        range_checks => range_checks,
+    }
+}
+
+/// Generate a guard that checks whether `x` is in one of the given ranges.
+///
+/// Guards for a single range are always compiled to a comparison. Tables are only used when all
+/// ranges are ASCII, binary search is used otherwise.
+fn generate_range_guard(
+    ctx: &mut CgCtx,
+    mut ranges: Vec<(char, char)>,
+    range_checks: RangeChecks,
+) -> TokenStream {
+    ranges.sort();
+
+    let all_ascii = ranges.iter().all(|(_, end)| end.is_ascii());
+
+    match range_checks {
+        RangeChecks::Table if ranges.len() > 1 && all_ascii => {
+            let ascii_table_id = ctx.add_ascii_table(ranges);
+
+            quote!((x as u32) < 128 && #ascii_table_id[x as usize])
+        }
+        RangeChecks::Table | RangeChecks::BinarySearch if ranges.len() > 1 => {
+            let binary_search_table_id = ctx.add_search_table(ranges);
+
+            quote!(::lexgen_util::binary_search_char_ranges(x, &#binary_search_table_id))
+        }
+        _ => {
+            let range_checks: Vec<TokenStream> = ranges
+                .into_iter()
+                .map(|(range_begin, range_end)| {
+                    inclusive_range_contains(quote!(x), range_begin, range_end)
+                })
+                .collect();
+
+            quote!(#(#range_checks)||*)
+        }
+    }
+}
+
+/// Checks `value` is in the range. Common ASCII ranges are checked with `char` methods, which
+/// Clippy suggests over the range checks in the lexer's crate.
 fn inclusive_range_contains(value: TokenStream, range_start: char, range_end: char) -> TokenStream {
-    if range_start == range_end {
-        quote!(#value == #range_start)
-    } else {
-        quote!((#range_start..=#range_end).contains(&#value))
+    match (range_start, range_end) {
+        ('0', '9') => quote!(#value.is_ascii_digit()),
+        ('a', 'z') => quote!(#value.is_ascii_lowercase()),
+        ('A', 'Z') => quote!(#value.is_ascii_uppercase()),
+        _ if range_start == range_end => quote!(#value == #range_start),
+        _ => quote!((#range_start..=#range_end).contains(&#value)),
+    }
+}
+
+/// Lifetime parameters of a lexer, as a comma-prefixed list of parameters (with bounds) and
+/// arguments, to be added after `'input`.
+pub fn lifetime_params_args(generics: &syn::Generics) -> (TokenStream, TokenStream) {
+    let params: Vec<&syn::LifetimeDef> = generics.lifetimes().collect();
+    let args: Vec<&syn::Lifetime> = generics.lifetimes().map(|param| &param.lifetime).collect();
+    (quote!(#(, #params)*), quote!(#(, #args)*))
+}
+
+/// Type and const parameters of a lexer, as a comma-prefixed list of parameters (with bounds,
+/// without defaults) and arguments, to be added after the iterator type.
+pub fn generic_params_args(generics: &syn::Generics) -> (TokenStream, TokenStream) {
+    let mut params: Vec<TokenStream> = vec![];
+    let mut args: Vec<&syn::Ident> = vec![];
+
+    for param in generics.type_params() {
+        let ident = &param.ident;
+        let bounds = &param.bounds;
+        if bounds.is_empty() {
+            params.push(quote!(#ident));
+        } else {
+            params.push(quote!(#ident: #bounds));
+        }
+        args.push(ident);
+    }
+
+    for param in generics.const_params() {
+        let ident = &param.ident;
+        let ty = &param.ty;
+        params.push(quote!(const #ident: #ty));
+        args.push(ident);
     }
+
+    (quote!(#(, #params)*), quote!(#(, #args)*))
 }
@@ -0,0 +1,102 @@
+//! Generates code binding captures of rules (`(<regex> as <name>)`) in semantic actions.
+//!
+//! The parts of a rule's regex before, between, and after the captures, and the captured parts,
+//! are compiled to separate DFAs. After the rule matches, `lexgen_util::split_match` runs the DFAs
+//! on the match in one pass to find the boundaries of the parts.
+
+use crate::dfa::{State, StateIdx, DFA};
+use crate::range_map::Range;
+
+use std::convert::TryFrom;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+/// Generate `let` statements binding the captured parts of the match of a rule, to be used in
+/// semantic action functions, where the lexer is `__lexer`.
+pub fn capture_bindings(parts: &[(Option<String>, DFA<StateIdx, ()>)]) -> TokenStream {
+    let part_tables: Vec<TokenStream> = parts.iter().map(|(_, dfa)| part_table(dfa)).collect();
+
+    let num_bounds = parts.len() + 1;
+    let num_states: usize = parts.iter().map(|(_, dfa)| dfa.states.len()).sum();
+
+    let bindings: Vec<TokenStream> = parts
+        .iter()
+        .enumerate()
+        .filter_map(|(part_idx, (name, _))| {
+            let name = syn::Ident::new(name.as_ref()?, Span::call_site());
+            let end_idx = part_idx + 1;
+            Some(quote!(
+                #[allow(unused_variables)]
+                let #name: &'input str = &__match[__bounds[#part_idx]..__bounds[#end_idx]];
+            ))
+        })
+        .collect();
+
+    quote!(
+        const PARTS: &[&[::lexgen_util::CaptureState]] = &[#(#part_tables),*];
+        let __match = __lexer.match_();
+        let mut __bounds = [0usize; #num_bounds];
+        let __split = ::lexgen_util::split_match::<#num_states, #num_bounds>(
+            __match,
+            PARTS,
+            &mut __bounds,
+        );
+        debug_assert!(__split, "Parts of a rule with captures don't match the rule's match");
+        #(#bindings)*
+    )
+}
+
+fn part_table(dfa: &DFA<StateIdx, ()>) -> TokenStream {
+    let states: Vec<TokenStream> = dfa
+        .states
+        .iter()
+        .map(|state| {
+            let State {
+                initial: _,
+                transitions,
+                any_transition,
+                end_of_input_transition,
+                accepting,
+                predecessors: _,
+                provenance: _,
+            } = state;
+
+            let transitions: Vec<TokenStream> = transitions
+                .iter()
+                .map(
+                    |Range {
+                         start,
+                         end,
+                         value: StateIdx(next),
+                     }| {
+                        let start = char::try_from(*start).unwrap();
+                        let end = char::try_from(*end).unwrap();
+                        quote!((#start, #end, #next))
+                    },
+                )
+                .collect();
+
+            let any = match any_transition {
+                Some(StateIdx(next)) => quote!(Some(#next)),
+                None => quote!(None),
+            };
+
+            let end_of_input = match end_of_input_transition {
+                Some(StateIdx(next)) => quote!(Some(#next)),
+                None => quote!(None),
+            };
+
+            let accepting = !accepting.is_empty();
+
+            quote!(::lexgen_util::CaptureState {
+                transitions: &[#(#transitions),*],
+                any: #any,
+                end_of_input: #end_of_input,
+                accepting: #accepting,
+            })
+        })
+        .collect();
+
+    quote!(&[#(#states),*])
+}
@@ -0,0 +1,209 @@
+//! Generates a `const fn` version of a lexer, for lexing in `const` contexts.
+//!
+//! Semantic actions can't be called in `const fn`s, so this is only supported for lexers with
+//! only "simple" rules (`<regex> = <token>,`) and rules without a right-hand side (`<regex>,`), one
+//! rule set, no right contexts, and no user state.
+
+use super::MAX_GUARD_SIZE;
+use crate::ast::{RuleKind, RuleRhs};
+use crate::dfa::{State, StateIdx, DFA};
+use crate::range_map::Range;
+use crate::semantic_action_table::{SemanticActionIdx, SemanticActionTable};
+
+use std::convert::TryFrom;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generate `next_const` associated function of the lexer. `dfa` should be the DFA before
+/// simplification.
+pub fn reify_const_fn(
+    dfa: &DFA<StateIdx, SemanticActionIdx>,
+    semantic_actions: &SemanticActionTable,
+    lexer_name: &syn::Ident,
+    generics: &syn::Generics,
+    token_type: &syn::Type,
+    visibility: &syn::Visibility,
+) -> TokenStream {
+    let (lifetime_params, lifetime_args) = super::lifetime_params_args(generics);
+    let (generic_params, generic_args) = super::generic_params_args(generics);
+
+    // Value of `next_state` when a state doesn't have a transition for the current character
+    let stuck = quote!(usize::MAX);
+
+    let mut transition_arms: Vec<TokenStream> = Vec::with_capacity(dfa.states.len());
+    let mut accepting_arms: Vec<TokenStream> = vec![];
+
+    for (state_idx, state) in dfa.states.iter().enumerate() {
+        let State {
+            initial: _,
+            transitions,
+            any_transition,
+            end_of_input_transition,
+            accepting,
+            predecessors: _,
+            provenance: _,
+        } = state;
+
+        let mut char_arms: Vec<TokenStream> = vec![];
+        let mut search_table: Vec<TokenStream> = vec![];
+
+        for Range {
+            start,
+            end,
+            value: StateIdx(next),
+        } in transitions.iter()
+        {
+            let start = char::try_from(*start).unwrap();
+            let end = char::try_from(*end).unwrap();
+            if start == end {
+                char_arms.push(quote!(#start => #next));
+            } else {
+                char_arms.push(quote!(#start..=#end => #next));
+            }
+            search_table.push(quote!((#start, #end, #next)));
+        }
+
+        let default = match any_transition {
+            Some(StateIdx(next)) => quote!(#next),
+            None => stuck.clone(),
+        };
+
+        let end_of_input = match end_of_input_transition {
+            Some(StateIdx(next)) => quote!(#next),
+            None => stuck.clone(),
+        };
+
+        // Range patterns are fine for a small number of ranges. For large number of ranges (e.g.
+        // built-in regexes like `$$alphabetic`) we use binary search over the transitions.
+        let match_char = if char_arms.len() > MAX_GUARD_SIZE
+            && transitions.iter().any(|range| range.start != range.end)
+        {
+            quote!(
+                match ::lexgen_util::binary_search_transitions(char, &[#(#search_table),*]) {
+                    Some(next) => next,
+                    None => #default,
+                }
+            )
+        } else {
+            quote!(match char {
+                #(#char_arms,)*
+                _ => #default,
+            })
+        };
+
+        transition_arms.push(quote!(
+            #state_idx => {
+                if idx < bytes.len() {
+                    let (char, char_len) = ::lexgen_util::decode_utf8_char(bytes, idx);
+                    idx += char_len;
+                    #match_char
+                } else {
+                    at_end_of_input = true;
+                    #end_of_input
+                }
+            }
+        ));
+
+        // Without right contexts, guards, and groups (reported in `lexer_definition`) the first
+        // accepting value always wins, as in the `next` method. An accepting initial state is also
+        // reported in `lexer_definition`, so we don't check for a match before the first
+        // transition.
+        if let Some(accepting) = accepting.first() {
+            assert!(accepting.right_ctx.is_none());
+            assert_ne!(state_idx, dfa.initial_state().0);
+            let action_idx = accepting.value.as_usize();
+            accepting_arms.push(quote!(
+                #state_idx => {
+                    last_match = Some((#action_idx, idx));
+                }
+            ));
+        }
+    }
+
+    let mut action_arms: Vec<TokenStream> = vec![];
+
+    for (action_idx, action) in semantic_actions.iter() {
+        let action_idx = action_idx.as_usize();
+        match action {
+            RuleRhs::None => action_arms.push(quote!(
+                #action_idx => {
+                    match_start = match_end;
+                    continue;
+                }
+            )),
+            RuleRhs::Rhs {
+                expr,
+                kind: RuleKind::Simple,
+            } => action_arms.push(quote!(
+                #action_idx => return Some(Ok((match_start, #expr, match_end)))
+            )),
+            // Reported in `lexer_definition`
+            RuleRhs::Rhs { .. } => unreachable!(),
+        }
+    }
+
+    quote!(
+        #[allow(missing_docs)]
+        impl<'input #lifetime_params #generic_params> #lexer_name<'input #lifetime_args, ::std::str::Chars<'input> #generic_args> {
+            /// Lex the next token starting at the given byte index, in a `const` context.
+            ///
+            /// Returns `None` at the end of the input, the token and its start and end byte
+            /// indices on success, and the error location and the byte index to continue lexing
+            /// from on failure.
+            #[allow(dead_code, unreachable_patterns, clippy::manual_range_patterns)]
+            #visibility const fn next_const(
+                input: &'input str,
+                byte_idx: usize,
+            ) -> Option<Result<(usize, #token_type, usize), (usize, usize)>> {
+                let bytes = input.as_bytes();
+                let mut match_start = byte_idx;
+
+                loop {
+                    if match_start >= bytes.len() {
+                        return None;
+                    }
+
+                    let mut state: usize = 0;
+                    let mut idx = match_start;
+                    let mut at_end_of_input = false;
+
+                    // Semantic action index and end of the last match
+                    let mut last_match: Option<(usize, usize)> = None;
+
+                    loop {
+                        let next_state: usize = match state {
+                            #(#transition_arms,)*
+                            _ => #stuck,
+                        };
+
+                        if next_state == #stuck {
+                            break;
+                        }
+
+                        state = next_state;
+
+                        match state {
+                            #(#accepting_arms,)*
+                            _ => {}
+                        }
+
+                        if at_end_of_input {
+                            break;
+                        }
+                    }
+
+                    let (action, match_end) = match last_match {
+                        None => return Some(Err((match_start, idx))),
+                        Some(last_match) => last_match,
+                    };
+
+                    match action {
+                        #(#action_arms,)*
+                        _ => panic!("lexgen: unknown semantic action"),
+                    }
+                }
+            }
+        }
+    )
+}
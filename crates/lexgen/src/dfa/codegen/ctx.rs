@@ -1,7 +1,7 @@
 use super::search_table::SearchTableSet;
 use super::StateIdx;
 use super::DFA;
-use crate::ast::RuleRhs;
+use crate::ast::{RangeChecks, RuleRhs};
 use crate::collections::Map;
 use crate::dfa::simplify::Trans;
 use crate::semantic_action_table::{SemanticActionIdx, SemanticActionTable};
@@ -14,6 +14,9 @@ pub struct CgCtx {
     /// Name of the lexer: `MyLexer` in `lexer! { MyLexer -> MyToken; }`
     lexer_name: syn::Ident,
 
+    /// Type and const parameters of the lexer: `<N: Num>` in `lexer! { MyLexer<N: Num> -> ... }`
+    generics: syn::Generics,
+
     /// Type of the values the lexer will produce: `MyToken` in `lexer! { MyLexer -> MyToken; }`
     token_type: syn::Type,
 
@@ -33,6 +36,43 @@ pub struct CgCtx {
     /// decremented 1, so state 6 becomes 5 etc.
     inlined_states: Vec<StateIdx>,
 
+    /// Whether the lexer only handles ASCII input (`encoding = ascii;`)
+    ascii: bool,
+
+    /// How to compile range checks (`range_checks = ...;`)
+    range_checks: RangeChecks,
+
+    /// Whether the lexer can lex bytes (`invalid_utf8 = ...;`). With byte input, `next` returns
+    /// `None` for invalid UTF-8 as well as for end-of-input.
+    byte_input: bool,
+
+    /// Maps initial states of rule sets with `fn <matcher>` rules to the matchers and semantic
+    /// actions of the rules
+    custom_matchers: Map<StateIdx, Vec<(syn::Path, SemanticActionIdx)>>,
+
+    /// Guard functions of `<regex> if <guard>` rules
+    guards: Map<SemanticActionIdx, syn::Path>,
+
+    /// Maps semantic actions of rules in `group <name> { ... }` groups to the bits of the groups
+    /// in the lexer's disabled groups mask
+    rule_groups: Map<SemanticActionIdx, usize>,
+
+    /// Maps initial states of rule sets with `fail <rhs>` rules to the semantic actions of the
+    /// rules
+    fail_actions: Map<StateIdx, SemanticActionIdx>,
+
+    /// Whether the lexer has a parser feedback (`type Feedback = ...;`), which is passed to guards
+    feedback: bool,
+
+    /// Whether tokens are tagged with their rule sets, `tag_rule_sets = true;`
+    tag_rule_sets: bool,
+
+    /// Function called with errors before they're returned, `on_error = <path>;`
+    on_error: Option<syn::Path>,
+
+    /// Whether to check the cancellation flag before each token, `cancellation = true;`
+    cancellation: bool,
+
     /// Mutable parts of the codegen state
     codegen_state: CgState,
 }
@@ -40,6 +80,9 @@ pub struct CgCtx {
 struct CgState {
     /// Binary search tables generated so far
     search_tables: SearchTableSet,
+
+    /// Dense tables for ASCII characters generated so far. Only used in ASCII lexers.
+    ascii_tables: SearchTableSet,
 }
 
 impl CgCtx {
@@ -47,10 +90,32 @@ impl CgCtx {
         dfa: &DFA<Trans<SemanticActionIdx>, SemanticActionIdx>,
         semantic_action_table: SemanticActionTable,
         lexer_name: syn::Ident,
+        generics: syn::Generics,
         token_type: syn::Type,
         user_error_type: Option<syn::Type>,
         rule_states: Map<String, StateIdx>,
+        ascii: bool,
+        range_checks: RangeChecks,
+        byte_input: bool,
+        custom_matchers: Map<String, Vec<(syn::Path, SemanticActionIdx)>>,
+        guards: Map<SemanticActionIdx, syn::Path>,
+        rule_groups: Map<SemanticActionIdx, usize>,
+        fail_actions: Map<String, SemanticActionIdx>,
+        feedback: bool,
+        tag_rule_sets: bool,
+        on_error: Option<syn::Path>,
+        cancellation: bool,
     ) -> CgCtx {
+        let custom_matchers = custom_matchers
+            .into_iter()
+            .map(|(rule_set_name, matchers)| (rule_states[&rule_set_name], matchers))
+            .collect();
+
+        let fail_actions = fail_actions
+            .into_iter()
+            .map(|(rule_set_name, action)| (rule_states[&rule_set_name], action))
+            .collect();
+
         let inlined_states: Vec<StateIdx> = dfa
             .states
             .iter()
@@ -66,14 +131,27 @@ impl CgCtx {
 
         CgCtx {
             semantic_action_table,
-            lexer_name,
             token_type,
             user_error_type,
             rule_states,
             inlined_states,
+            ascii,
+            range_checks,
+            byte_input,
+            custom_matchers,
+            guards,
+            rule_groups,
+            fail_actions,
+            feedback,
+            tag_rule_sets,
+            on_error,
+            cancellation,
             codegen_state: CgState {
-                search_tables: SearchTableSet::new(),
+                search_tables: SearchTableSet::new(&lexer_name),
+                ascii_tables: ascii_table_set(&lexer_name),
             },
+            lexer_name,
+            generics,
         }
     }
 
@@ -81,6 +159,10 @@ impl CgCtx {
         &self.lexer_name
     }
 
+    pub fn generics(&self) -> &syn::Generics {
+        &self.generics
+    }
+
     /// Renumber a state index taking inlined states into account.
     pub fn renumber_state(&self, state: StateIdx) -> StateIdx {
         match self.inlined_states.binary_search(&state) {
@@ -88,10 +170,6 @@ impl CgCtx {
         }
     }
 
-    pub fn n_inlined_states(&self) -> usize {
-        self.inlined_states.len()
-    }
-
     pub fn token_type(&self) -> &syn::Type {
         &self.token_type
     }
@@ -105,7 +183,84 @@ impl CgCtx {
     }
 
     pub fn take_search_tables(&mut self) -> SearchTableSet {
-        std::mem::replace(&mut self.codegen_state.search_tables, SearchTableSet::new())
+        std::mem::replace(
+            &mut self.codegen_state.search_tables,
+            SearchTableSet::new(&self.lexer_name),
+        )
+    }
+
+    pub fn ascii(&self) -> bool {
+        self.ascii
+    }
+
+    pub fn range_checks(&self) -> RangeChecks {
+        self.range_checks
+    }
+
+    pub fn byte_input(&self) -> bool {
+        self.byte_input
+    }
+
+    /// Matchers and semantic actions of `fn <matcher>` rules of the rule set with the given initial
+    /// state
+    pub fn custom_matchers(&self, state: StateIdx) -> &[(syn::Path, SemanticActionIdx)] {
+        self.custom_matchers
+            .get(&state)
+            .map(|matchers| matchers.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Guard function of the rule with the given semantic action
+    pub fn guard(&self, action: SemanticActionIdx) -> Option<&syn::Path> {
+        self.guards.get(&action)
+    }
+
+    /// Bit of the group of the rule with the given semantic action, if the rule is in a group
+    pub fn rule_group(&self, action: SemanticActionIdx) -> Option<usize> {
+        self.rule_groups.get(&action).copied()
+    }
+
+    pub fn has_feedback(&self) -> bool {
+        self.feedback
+    }
+
+    pub fn tag_rule_sets(&self) -> bool {
+        self.tag_rule_sets
+    }
+
+    pub fn on_error(&self) -> Option<&syn::Path> {
+        self.on_error.as_ref()
+    }
+
+    pub fn cancellation(&self) -> bool {
+        self.cancellation
+    }
+
+    /// Whether to generate the `__rule_set` function, which maps initial states to rule sets
+    pub fn rule_set_fn(&self) -> bool {
+        self.tag_rule_sets || self.on_error.is_some()
+    }
+
+    /// Initial states of rule sets with `fail <rhs>` rules, with the semantic actions of the rules
+    pub fn fail_actions(&self) -> impl Iterator<Item = (StateIdx, SemanticActionIdx)> + '_ {
+        self.fail_actions
+            .iter()
+            .map(|(state, action)| (*state, *action))
+    }
+
+    pub fn has_custom_matchers(&self) -> bool {
+        !self.custom_matchers.is_empty()
+    }
+
+    pub fn add_ascii_table(&mut self, ranges: Vec<(char, char)>) -> syn::Ident {
+        self.codegen_state.ascii_tables.add_table(ranges)
+    }
+
+    pub fn take_ascii_tables(&mut self) -> SearchTableSet {
+        std::mem::replace(
+            &mut self.codegen_state.ascii_tables,
+            ascii_table_set(&self.lexer_name),
+        )
     }
 
     pub fn rule_states(&self) -> &Map<String, StateIdx> {
@@ -123,3 +278,7 @@ impl CgCtx {
         )
     }
 }
+
+fn ascii_table_set(lexer_name: &syn::Ident) -> SearchTableSet {
+    SearchTableSet::with_prefix(format!("{}_ASCII_TABLE", lexer_name))
+}
@@ -0,0 +1,136 @@
+//! Ordering of states in the generated code.
+//!
+//! States are generated in the order returned by `hot_state_order`, so that code and tables of the
+//! states that are used most often are close to each other in the generated code.
+
+use super::super::simplify::Trans;
+use super::super::{State, StateIdx};
+
+use std::collections::VecDeque;
+
+/// Returns indices of the states in `states`, hottest first.
+///
+/// Without a profile, states are ordered by their distance (in number of transitions) from the
+/// initial state `init`, then from the other initial states. States close to an initial state are
+/// visited in every token, while states far away are only visited in long tokens.
+///
+/// With a profile (a sample input), states are ordered by the number of times they are visited
+/// when running the DFA on the sample, with distance used to break ties.
+pub fn hot_state_order<A>(
+    states: &[State<Trans<A>, A>],
+    init: StateIdx,
+    profile: Option<&str>,
+) -> Vec<usize> {
+    let distances = distances(states, init);
+
+    let visits = match profile {
+        Some(profile) => visit_counts(states, init, profile),
+        None => vec![0; states.len()],
+    };
+
+    let mut order: Vec<usize> = (0..states.len()).collect();
+
+    order.sort_by_key(|&state_idx| {
+        (
+            std::cmp::Reverse(visits[state_idx]),
+            distances[state_idx],
+            state_idx,
+        )
+    });
+
+    order
+}
+
+/// Distance of each state from `init`. States that are not reachable from `init` are ordered after
+/// the reachable ones, by their distance from the closest initial state.
+fn distances<A>(states: &[State<Trans<A>, A>], init: StateIdx) -> Vec<usize> {
+    let mut distances: Vec<usize> = vec![usize::MAX; states.len()];
+
+    let mut work_list: VecDeque<usize> = VecDeque::new();
+    distances[init.0] = 0;
+    work_list.push_back(init.0);
+    bfs(states, &mut distances, &mut work_list);
+
+    // Other rule sets, after all states reachable from `init`
+    let offset = distances
+        .iter()
+        .filter(|distance| **distance != usize::MAX)
+        .max()
+        .map(|max| max + 1)
+        .unwrap_or(0);
+
+    for (state_idx, state) in states.iter().enumerate() {
+        if state.initial && distances[state_idx] == usize::MAX {
+            distances[state_idx] = offset;
+            work_list.push_back(state_idx);
+        }
+    }
+    bfs(states, &mut distances, &mut work_list);
+
+    distances
+}
+
+fn bfs<A>(states: &[State<Trans<A>, A>], distances: &mut [usize], work_list: &mut VecDeque<usize>) {
+    while let Some(state_idx) = work_list.pop_front() {
+        let distance = distances[state_idx] + 1;
+        for next in successors(&states[state_idx]) {
+            if distances[next] == usize::MAX {
+                distances[next] = distance;
+                work_list.push_back(next);
+            }
+        }
+    }
+}
+
+fn successors<A>(state: &State<Trans<A>, A>) -> impl Iterator<Item = usize> + '_ {
+    state
+        .transitions
+        .iter()
+        .map(|range| &range.value)
+        .chain(state.any_transition.iter())
+        .chain(state.end_of_input_transition.iter())
+        .filter_map(|next| match next {
+            Trans::Trans(StateIdx(next)) => Some(*next),
+            Trans::Accept(_) => None,
+        })
+}
+
+/// Number of times each state is visited when running the DFA on `input`.
+///
+/// This is an approximation of the actual lexer: after accepting, or when stuck, we restart from
+/// `init` with the next character, without backtracking. Rule set switches in semantic actions are
+/// not taken into account.
+fn visit_counts<A>(states: &[State<Trans<A>, A>], init: StateIdx, input: &str) -> Vec<u64> {
+    let mut visits: Vec<u64> = vec![0; states.len()];
+
+    let mut state_idx = init.0;
+    visits[state_idx] += 1;
+
+    let mut chars = input.chars().peekable();
+    while let Some(char) = chars.peek().copied() {
+        let state = &states[state_idx];
+
+        let next = state.next(char).or(state.any_transition.as_ref());
+
+        match next {
+            Some(Trans::Trans(StateIdx(next))) => {
+                chars.next();
+                state_idx = *next;
+            }
+            Some(Trans::Accept(_)) => {
+                chars.next();
+                state_idx = init.0;
+            }
+            None => {
+                if state_idx == init.0 {
+                    chars.next();
+                }
+                state_idx = init.0;
+            }
+        }
+
+        visits[state_idx] += 1;
+    }
+
+    visits
+}
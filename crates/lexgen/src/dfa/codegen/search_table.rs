@@ -4,12 +4,21 @@ use std::collections::hash_map::Entry;
 
 pub struct SearchTableSet {
     tables: Map<Vec<(char, char)>, syn::Ident>,
+
+    /// Prefix of the generated table names. Starts with the lexer name, so tables of lexers
+    /// defined in the same module don't clash.
+    prefix: String,
 }
 
 impl SearchTableSet {
-    pub fn new() -> SearchTableSet {
+    pub fn new(lexer_name: &syn::Ident) -> SearchTableSet {
+        SearchTableSet::with_prefix(format!("{}_RANGE_TABLE", lexer_name))
+    }
+
+    pub fn with_prefix(prefix: String) -> SearchTableSet {
         SearchTableSet {
             tables: Default::default(),
+            prefix,
         }
     }
 
@@ -19,7 +28,7 @@ impl SearchTableSet {
             Entry::Occupied(entry) => entry.get().clone(),
             Entry::Vacant(entry) => {
                 let ident = syn::Ident::new(
-                    &format!("RANGE_TABLE_{}", n_tables),
+                    &format!("{}_{}", self.prefix, n_tables),
                     proc_macro2::Span::call_site(),
                 );
                 entry.insert(ident.clone());
@@ -33,8 +42,4 @@ impl SearchTableSet {
             .iter()
             .map(|(ranges, ident)| (ranges.as_slice(), ident))
     }
-
-    pub fn is_empty(&self) -> bool {
-        self.tables.is_empty()
-    }
 }
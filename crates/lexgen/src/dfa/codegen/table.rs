@@ -0,0 +1,140 @@
+//! Generates a `dfa_table` associated function that returns the DFA of a lexer as a
+//! `lexgen_util::DfaTable`, which can be serialized and run with `lexgen_util::TableLexer`.
+//!
+//! Semantic actions can't be stored in tables, tokens in the table are the right-hand sides of
+//! the rules as strings. So this is only supported for lexers with only "simple" rules (`<regex> =
+//! <token>,`) and rules without a right-hand side (`<regex>,`), one rule set, and no right
+//! contexts.
+
+use crate::ast::{RuleKind, RuleRhs};
+use crate::dfa::{State, StateIdx, DFA};
+use crate::diagnostics::RuleInfo;
+use crate::display::tokens_to_string;
+use crate::range_map::Range;
+use crate::semantic_action_table::{SemanticActionIdx, SemanticActionTable};
+
+use std::convert::TryFrom;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+/// Generate `dfa_table` associated function of the lexer. `dfa` should be the DFA before
+/// simplification.
+pub fn reify_dfa_table(
+    dfa: &DFA<StateIdx, SemanticActionIdx>,
+    semantic_actions: &SemanticActionTable,
+    rules: &[RuleInfo],
+    lexer_name: &syn::Ident,
+    generics: &syn::Generics,
+    visibility: &syn::Visibility,
+) -> syn::Result<TokenStream> {
+    let (lifetime_params, lifetime_args) = super::lifetime_params_args(generics);
+    let (generic_params, generic_args) = super::generic_params_args(generics);
+
+    let rule_span = |action: SemanticActionIdx| {
+        rules
+            .iter()
+            .find(|rule| rule.action == action)
+            .map(|rule| rule.span)
+            .unwrap_or_else(Span::call_site)
+    };
+
+    let mut tokens: Vec<TokenStream> = vec![];
+
+    for (action_idx, action) in semantic_actions.iter() {
+        tokens.push(match action {
+            RuleRhs::None => quote!(None),
+            RuleRhs::Rhs {
+                expr,
+                kind: RuleKind::Simple,
+            } => {
+                let token = tokens_to_string(expr);
+                quote!(Some(#token.to_owned()))
+            }
+            RuleRhs::Rhs { .. } => {
+                return Err(syn::Error::new(
+                    rule_span(action_idx),
+                    "Only rules without a right-hand side (`<regex>,`) and simple rules \
+                    (`<regex> = <token>,`) are supported with `dfa_table = true;`",
+                ))
+            }
+        });
+    }
+
+    let mut states: Vec<TokenStream> = Vec::with_capacity(dfa.states.len());
+
+    for state in &dfa.states {
+        let State {
+            initial: _,
+            transitions,
+            any_transition,
+            end_of_input_transition,
+            accepting,
+            predecessors: _,
+            provenance: _,
+        } = state;
+
+        let transitions: Vec<TokenStream> = transitions
+            .iter()
+            .map(
+                |Range {
+                     start,
+                     end,
+                     value: StateIdx(next),
+                 }| {
+                    let start = char::try_from(*start).unwrap();
+                    let end = char::try_from(*end).unwrap();
+                    quote!((#start, #end, #next))
+                },
+            )
+            .collect();
+
+        let any = match any_transition {
+            Some(StateIdx(next)) => quote!(Some(#next)),
+            None => quote!(None),
+        };
+
+        let end_of_input = match end_of_input_transition {
+            Some(StateIdx(next)) => quote!(Some(#next)),
+            None => quote!(None),
+        };
+
+        let accepting = match accepting.first() {
+            None => quote!(None),
+            Some(accepting) => {
+                if accepting.right_ctx.is_some() {
+                    return Err(syn::Error::new(
+                        rule_span(accepting.value),
+                        "Right contexts are not supported with `dfa_table = true;`",
+                    ));
+                }
+                let action_idx = accepting.value.as_usize();
+                quote!(Some(#action_idx))
+            }
+        };
+
+        states.push(quote!(
+            ::lexgen_util::DfaTableState {
+                transitions: vec![#(#transitions),*],
+                any: #any,
+                end_of_input: #end_of_input,
+                accepting: #accepting,
+            }
+        ));
+    }
+
+    Ok(quote!(
+        #[allow(missing_docs)]
+        impl<'input #lifetime_params #generic_params> #lexer_name<'input #lifetime_args, ::std::str::Chars<'input> #generic_args> {
+            /// The DFA of the lexer as data, to be serialized, or run with
+            /// `lexgen_util::TableLexer`.
+            #[allow(dead_code)]
+            #visibility fn dfa_table() -> ::lexgen_util::DfaTable {
+                ::lexgen_util::DfaTable {
+                    states: vec![#(#states),*],
+                    tokens: vec![#(#tokens),*],
+                }
+            }
+        }
+    ))
+}
@@ -0,0 +1,204 @@
+//! Checking whether two lexers tokenize all inputs the same way, for refactoring lexer
+//! definitions.
+//!
+//! Two lexers are equivalent when, for every rule set, every input is matched by equivalent rules
+//! in both lexers. Which rules are equivalent is decided by the caller with a key function on
+//! accepting states (e.g. rules with the same semantic action and right context). The lexers are
+//! compared by walking the product of their DFAs in breadth-first order, so a shortest input that
+//! is lexed differently is found.
+
+use super::snapshot::char_ranges;
+use super::{State, StateIdx, DFA};
+use crate::collections::Set;
+use crate::nfa::AcceptingState;
+
+use std::collections::VecDeque;
+
+/// A difference between two lexers
+#[derive(Debug)]
+pub enum Difference<'a, A> {
+    /// A rule set is only defined in one of the lexers
+    RuleSet { name: String, in_first: bool },
+
+    /// `input` is matched by different rules in the lexers
+    Input {
+        rule_set: String,
+
+        input: String,
+
+        /// Whether the difference is in matching the end of input after `input` (`$`)
+        end_of_input: bool,
+
+        /// Accepting states that can be matched for the input in the lexers, see
+        /// `analysis::matched_values`. Empty when the lexer doesn't match the input.
+        first: &'a [AcceptingState<A>],
+        second: &'a [AcceptingState<A>],
+    },
+}
+
+/// Find a difference between two lexers. `rule_sets1` and `rule_sets2` are the rule set names
+/// with their initial states, in the order they're defined. Rule sets with the same name are
+/// compared. `key1` and `key2` map accepting states to keys that are equal for equivalent rules.
+///
+/// Returns `None` when the lexers are equivalent. Otherwise returns the difference in the first
+/// rule set that differs, with a shortest input.
+pub fn find_difference<'a, A, K, F1, F2>(
+    dfa1: &'a DFA<StateIdx, A>,
+    rule_sets1: &[(String, StateIdx)],
+    key1: F1,
+    dfa2: &'a DFA<StateIdx, A>,
+    rule_sets2: &[(String, StateIdx)],
+    key2: F2,
+) -> Option<Difference<'a, A>>
+where
+    K: Eq,
+    F1: Fn(&AcceptingState<A>) -> K,
+    F2: Fn(&AcceptingState<A>) -> K,
+{
+    for (name, _) in rule_sets2 {
+        if !rule_sets1.iter().any(|(name1, _)| name1 == name) {
+            return Some(Difference::RuleSet {
+                name: name.clone(),
+                in_first: false,
+            });
+        }
+    }
+
+    for (name, initial_state1) in rule_sets1 {
+        let initial_state2 = match rule_sets2.iter().find(|(name2, _)| name2 == name) {
+            Some((_, initial_state2)) => *initial_state2,
+            None => {
+                return Some(Difference::RuleSet {
+                    name: name.clone(),
+                    in_first: true,
+                })
+            }
+        };
+
+        let states1 = &dfa1.states;
+        let states2 = &dfa2.states;
+
+        let matches = |state1: Option<StateIdx>, state2: Option<StateIdx>| {
+            let first = state1
+                .map(|state| matched(&states1[state.0]))
+                .unwrap_or(&[]);
+            let second = state2
+                .map(|state| matched(&states2[state.0]))
+                .unwrap_or(&[]);
+            let same = first.len() == second.len()
+                && first
+                    .iter()
+                    .zip(second.iter())
+                    .all(|(accepting1, accepting2)| key1(accepting1) == key2(accepting2));
+            (first, second, same)
+        };
+
+        let mut visited: Set<(Option<StateIdx>, Option<StateIdx>)> = Default::default();
+        let mut work_list: VecDeque<(Option<StateIdx>, Option<StateIdx>, String)> = VecDeque::new();
+
+        visited.insert((Some(*initial_state1), Some(initial_state2)));
+        work_list.push_back((Some(*initial_state1), Some(initial_state2), String::new()));
+
+        while let Some((state1, state2, input)) = work_list.pop_front() {
+            let (first, second, same) = matches(state1, state2);
+            if !same {
+                return Some(Difference::Input {
+                    rule_set: name.clone(),
+                    input,
+                    end_of_input: false,
+                    first,
+                    second,
+                });
+            }
+
+            let state1 = state1.map(|state| &states1[state.0]);
+            let state2 = state2.map(|state| &states2[state.0]);
+
+            let end_of_input1 = state1.and_then(|state| state.end_of_input_transition);
+            let end_of_input2 = state2.and_then(|state| state.end_of_input_transition);
+            if end_of_input1.is_some() || end_of_input2.is_some() {
+                let (first, second, same) = matches(end_of_input1, end_of_input2);
+                if !same {
+                    return Some(Difference::Input {
+                        rule_set: name.clone(),
+                        input,
+                        end_of_input: true,
+                        first,
+                        second,
+                    });
+                }
+            }
+
+            for (char, next1, next2) in product_transitions(state1, state2) {
+                if visited.insert((next1, next2)) {
+                    let mut next_input = input.clone();
+                    next_input.push(char);
+                    work_list.push_back((next1, next2, next_input));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Accepting states of a state that can be matched: the states up to and including the first one
+/// without a right context or a guard
+fn matched<A>(state: &State<StateIdx, A>) -> &[AcceptingState<A>] {
+    match state
+        .accepting
+        .iter()
+        .position(|accepting_state| !accepting_state.is_conditional())
+    {
+        Some(winner_idx) => &state.accepting[..=winner_idx],
+        None => &state.accepting,
+    }
+}
+
+/// Transitions of a pair of states, as an example character for each set of characters that takes
+/// the states to the same pair of states. `None` is the failure state. Pairs where both states
+/// fail are not returned.
+fn product_transitions<A>(
+    state1: Option<&State<StateIdx, A>>,
+    state2: Option<&State<StateIdx, A>>,
+) -> Vec<(char, Option<StateIdx>, Option<StateIdx>)> {
+    let ranges1 = state1.map(char_ranges).unwrap_or_default();
+    let ranges2 = state2.map(char_ranges).unwrap_or_default();
+
+    let mut boundaries: Vec<u32> = vec![0, char::MAX as u32 + 1];
+    for (start, end, _) in ranges1.iter().chain(ranges2.iter()) {
+        boundaries.push(*start);
+        boundaries.push(*end + 1);
+    }
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let next = |state: Option<&State<StateIdx, A>>, ranges: &[(u32, u32, StateIdx)], char: u32| {
+        ranges
+            .iter()
+            .find(|(start, end, _)| *start <= char && char <= *end)
+            .map(|(_, _, next)| *next)
+            .or_else(|| state.and_then(|state| state.any_transition))
+    };
+
+    boundaries
+        .windows(2)
+        .filter_map(|window| {
+            let (start, end) = (window[0], window[1] - 1);
+            let next1 = next(state1, &ranges1, start);
+            let next2 = next(state2, &ranges2, start);
+            if next1.is_none() && next2.is_none() {
+                return None;
+            }
+            Some((example_char(start, end)?, next1, next2))
+        })
+        .collect()
+}
+
+/// A character in the inclusive range, preferring letters and digits for readable examples
+fn example_char(start: u32, end: u32) -> Option<char> {
+    ('a'..='z')
+        .chain('0'..='9')
+        .find(|char| start <= *char as u32 && *char as u32 <= end)
+        .or_else(|| (start..=end).find_map(char::from_u32))
+}
@@ -0,0 +1,173 @@
+//! Human-readable report of a DFA, for debugging and tuning lexer definitions.
+//!
+//! When the environment variable `LEXGEN_REPORT_DIR` is set, a report for each lexer is written to
+//! `<lexer>.txt` in the directory.
+
+use super::{StateIdx, DFA};
+use crate::ast::DriverStyle;
+use crate::collections::{Map, Set};
+use crate::display::CharRangesDisplay;
+use crate::nfa::AcceptingState;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// Generate a report for the DFA. `driver` is the driver the lexer is generated with, `rule_sets`
+/// maps rule set names to their initial states, `label` is used to describe values of accepting
+/// states, and `rule_name` is used to name states.
+pub fn report<A, F, N>(
+    name: &str,
+    dfa: &DFA<StateIdx, A>,
+    driver: DriverStyle,
+    rule_sets: &Map<String, StateIdx>,
+    label: F,
+    rule_name: N,
+) -> String
+where
+    F: Fn(&AcceptingState<A>) -> String,
+    N: Fn(&A) -> String,
+{
+    let mut out = summary(name, dfa);
+    out.push_str(&driver_note(driver, dfa.states.len()));
+
+    let rule_set_names: Map<StateIdx, &str> = rule_sets
+        .iter()
+        .map(|(name, state)| (*state, name.as_str()))
+        .collect();
+
+    for (state_idx, state) in dfa.states.iter().enumerate() {
+        writeln!(out).unwrap();
+
+        match rule_set_names.get(&StateIdx(state_idx)) {
+            Some(rule_set) => writeln!(out, "State {} (initial state of {})", state_idx, rule_set),
+            None => match dfa.state_name(StateIdx(state_idx), &rule_name) {
+                Some(state_name) => writeln!(out, "State {} ({})", state_idx, state_name),
+                None => writeln!(out, "State {}", state_idx),
+            },
+        }
+        .unwrap();
+
+        for accepting_state in &state.accepting {
+            writeln!(out, "  accepts: {}", label(accepting_state)).unwrap();
+        }
+
+        // Merge transitions to the same state
+        let mut transitions: BTreeMap<StateIdx, Vec<(char, char)>> = Default::default();
+
+        for range in state.transitions.iter() {
+            transitions.entry(range.value).or_default().push((
+                char::from_u32(range.start).unwrap(),
+                char::from_u32(range.end).unwrap(),
+            ));
+        }
+
+        for (next, mut ranges) in transitions {
+            ranges.sort();
+            writeln!(out, "  {} -> {}", CharRangesDisplay(&ranges), next).unwrap();
+        }
+
+        if let Some(next) = state.any_transition {
+            writeln!(out, "  _ -> {}", next).unwrap();
+        }
+
+        if let Some(next) = state.end_of_input_transition {
+            writeln!(out, "  $ -> {}", next).unwrap();
+        }
+    }
+
+    out
+}
+
+/// Number of states, transitions, and character classes in the DFA, and estimated size of a dense
+/// transition table for it. This is the first part of the report.
+pub fn summary<A>(name: &str, dfa: &DFA<StateIdx, A>) -> String {
+    let mut out = String::new();
+
+    let n_states = dfa.states.len();
+
+    let n_transitions: usize = dfa
+        .states
+        .iter()
+        .map(|state| {
+            state.transitions.len()
+                + usize::from(state.any_transition.is_some())
+                + usize::from(state.end_of_input_transition.is_some())
+        })
+        .sum();
+
+    // Characters that are not distinguished by any of the states form a class. A dense transition
+    // table would have a column for each class.
+    let mut boundaries: Set<u32> = Default::default();
+    boundaries.insert(0);
+    for state in &dfa.states {
+        for range in state.transitions.iter() {
+            boundaries.insert(range.start);
+            boundaries.insert(range.end + 1);
+        }
+    }
+
+    // Each interval between two boundaries is in one class, but multiple intervals can be in the
+    // same class
+    let classes: Set<Vec<Option<StateIdx>>> = boundaries
+        .iter()
+        .filter_map(|boundary| char::from_u32(*boundary))
+        .map(|char| {
+            dfa.states
+                .iter()
+                .map(|state| state.next(char).or(state.any_transition.as_ref()).copied())
+                .collect()
+        })
+        .collect();
+    let n_classes = classes.len();
+
+    let state_idx_size = if n_states <= 1 << 8 {
+        1
+    } else if n_states <= 1 << 16 {
+        2
+    } else {
+        4
+    };
+
+    writeln!(out, "Lexer: {}", name).unwrap();
+    writeln!(out, "States: {}", n_states).unwrap();
+    writeln!(out, "Transitions: {}", n_transitions).unwrap();
+    writeln!(out, "Character classes: {}", n_classes).unwrap();
+    writeln!(
+        out,
+        "Estimated dense transition table size: {} states * {} classes * {} bytes = {} bytes",
+        n_states,
+        n_classes,
+        state_idx_size,
+        n_states * n_classes * state_idx_size,
+    )
+    .unwrap();
+
+    out
+}
+
+/// Which driver the lexer is generated with, and when to try the other one. See the `driver`
+/// option in README.
+fn driver_note(driver: DriverStyle, n_states: usize) -> String {
+    match driver {
+        DriverStyle::MatchLoop => format!(
+            "Driver: match_loop\n\
+             Note: code for all {} states is generated in a single `match`. Large `match`es can be \
+             difficult to optimize; if the lexer is slow, try `driver = state_fns;` and benchmark \
+             both on a representative input.\n",
+            n_states
+        ),
+        DriverStyle::StateFns => format!(
+            "Driver: state_fns\n\
+             Note: code for each of the {} states is generated as a separate method. If calls to \
+             the methods are not inlined, try `driver = match_loop;` and benchmark both on a \
+             representative input.\n",
+            n_states
+        ),
+    }
+}
+
+/// Directory to write reports to, from the `LEXGEN_REPORT_DIR` environment variable
+pub fn report_dir() -> Option<PathBuf> {
+    std::env::var_os("LEXGEN_REPORT_DIR").map(PathBuf::from)
+}
@@ -44,20 +44,15 @@ pub fn simplify<K, A: Clone>(
         .map(|(_state_idx, state)| {
             let State {
                 initial,
-                char_transitions,
-                range_transitions,
+                transitions,
                 any_transition,
                 end_of_input_transition,
                 accepting,
                 predecessors,
+                provenance,
             } = state;
 
-            let char_transitions = char_transitions
-                .into_iter()
-                .map(|(char, next)| (char, map_transition(next)))
-                .collect();
-
-            let range_transitions = range_transitions.map(map_transition);
+            let transitions = transitions.map(map_transition);
 
             let any_transition = any_transition.map(map_transition);
 
@@ -77,12 +72,12 @@ pub fn simplify<K, A: Clone>(
 
             State {
                 initial,
-                char_transitions,
-                range_transitions,
+                transitions,
                 any_transition,
                 end_of_input_transition,
                 accepting,
                 predecessors,
+                provenance,
             }
         })
         .collect();
@@ -2,7 +2,6 @@ use super::{StateIdx, DFA};
 
 pub use crate::nfa::simulate::{ErrorLoc, Matches};
 use crate::nfa::AcceptingState;
-use crate::range_map::Range;
 use crate::right_ctx::RightCtxDFAs;
 
 impl<A: Copy> DFA<StateIdx, A> {
@@ -58,7 +57,10 @@ impl<A: Copy> DFA<StateIdx, A> {
                         state = next_state;
 
                         // Check for accepting state
-                        for AcceptingState { value, right_ctx } in &self.states[state.0].accepting {
+                        for AcceptingState {
+                            value, right_ctx, ..
+                        } in &self.states[state.0].accepting
+                        {
                             match right_ctx {
                                 None => {
                                     last_match =
@@ -83,7 +85,10 @@ impl<A: Copy> DFA<StateIdx, A> {
             if let Some(next) = next_end_of_input(self, state) {
                 // Check for accepting state
                 state = next;
-                for AcceptingState { value, right_ctx } in &self.states[state.0].accepting {
+                for AcceptingState {
+                    value, right_ctx, ..
+                } in &self.states[state.0].accepting
+                {
                     match right_ctx {
                         None => {
                             values.push((&input[match_start..], *value));
@@ -130,22 +135,15 @@ impl<A: Copy> DFA<StateIdx, A> {
 fn next<A>(dfa: &DFA<StateIdx, A>, state: StateIdx, char: char) -> Option<StateIdx> {
     let state = &dfa.states[state.0];
 
-    if let Some(next) = state.char_transitions.get(&char) {
+    if let Some(next) = state.next(char) {
         return Some(*next);
     }
 
-    for range in state.range_transitions.iter() {
-        let Range { start, end, value } = range;
-        if char as u32 >= *start && char as u32 <= *end {
-            return Some(*value);
-        }
-    }
-
     if let Some(next) = state.any_transition {
         return Some(next);
     }
 
-    return None;
+    None
 }
 
 fn next_end_of_input<A>(dfa: &DFA<StateIdx, A>, state: StateIdx) -> Option<StateIdx> {
@@ -154,17 +152,14 @@ fn next_end_of_input<A>(dfa: &DFA<StateIdx, A>, state: StateIdx) -> Option<State
 
 // Similar to `simulate`, but does not keep track of the last match as we don't need "longest
 // match" semantics and backtracking
-pub fn simulate_right_ctx(
-    dfa: &DFA<StateIdx, ()>,
-    mut char_indices: std::str::CharIndices,
-) -> bool {
+pub fn simulate_right_ctx(dfa: &DFA<StateIdx, ()>, char_indices: std::str::CharIndices) -> bool {
     let mut state = dfa.initial_state();
 
     if dfa.is_accepting_state(state) {
         return true;
     }
 
-    while let Some((_, char)) = char_indices.next() {
+    for (_, char) in char_indices {
         match next(dfa, state, char) {
             None => {
                 // Stuck
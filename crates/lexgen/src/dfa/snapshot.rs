@@ -0,0 +1,317 @@
+//! Canonical textual form of a lexer's DFA, for committing as a golden file and catching
+//! unintended changes in lexer definitions.
+//!
+//! The DFA is minimized, and states are numbered in breadth-first order starting from the initial
+//! states of rule sets, in the order the rule sets are defined, visiting transitions in character
+//! order. The snapshot of a lexer therefore only changes when the tokens it matches change, not
+//! when the definition is refactored (e.g. a regex is moved to a variable) or when lexgen
+//! generates the DFA differently.
+//!
+//! With the `snapshot = "<path>";` option, the snapshot is compared with the file at the path and
+//! differences are reported as compile errors. When the file doesn't exist, or the environment
+//! variable `LEXGEN_UPDATE_SNAPSHOTS` is set, the file is written instead.
+
+use super::{State, StateIdx, DFA};
+use crate::collections::Map;
+use crate::display::CharRangesDisplay;
+use crate::nfa::AcceptingState;
+use crate::right_ctx::RightCtxDFAs;
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Write;
+use std::hash::Hash;
+
+/// Generate the snapshot of a lexer. `rule_sets` are the rule set names with their initial states,
+/// in the order they're defined. `label` is used to describe values of accepting states.
+pub fn snapshot<A, F>(
+    name: &str,
+    dfa: &DFA<StateIdx, A>,
+    rule_sets: &[(String, StateIdx)],
+    right_ctx_dfas: &RightCtxDFAs<StateIdx>,
+    label: F,
+) -> String
+where
+    F: Fn(&AcceptingState<A>) -> String,
+{
+    let mut out = String::new();
+
+    writeln!(out, "Lexer: {}", name).unwrap();
+
+    let initial_states: Vec<StateIdx> = rule_sets.iter().map(|(_, state)| *state).collect();
+    let (states, initial_states) = canonicalize(dfa, &initial_states, &label);
+
+    for ((rule_set, _), state) in rule_sets.iter().zip(initial_states) {
+        writeln!(out, "Rule set {}: state {}", rule_set, state).unwrap();
+    }
+
+    write_states(&mut out, &states, "");
+
+    for (right_ctx_idx, right_ctx_dfa) in right_ctx_dfas.iter() {
+        writeln!(out).unwrap();
+        writeln!(out, "Right context {}", right_ctx_idx.as_usize()).unwrap();
+        write_right_ctx(&mut out, right_ctx_dfa, "  ");
+    }
+
+    out
+}
+
+/// Generate the snapshot of a right context DFA. Right contexts are compared with their snapshots
+/// when checking lexer equivalence.
+// Only used in the CLI
+#[allow(dead_code)]
+pub fn right_ctx_snapshot(dfa: &DFA<StateIdx, ()>) -> String {
+    let mut out = String::new();
+    write_right_ctx(&mut out, dfa, "");
+    out
+}
+
+fn write_right_ctx(out: &mut String, dfa: &DFA<StateIdx, ()>, indent: &str) {
+    let (states, _) = canonicalize(dfa, &[dfa.initial_state()], &|_| "right context".to_owned());
+
+    write_states(out, &states, indent);
+}
+
+/// Path of the snapshot file, relative to the crate root when not absolute
+pub fn snapshot_path(path: &str) -> std::path::PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    std::path::Path::new(&manifest_dir).join(path)
+}
+
+/// Compare `snapshot` with the contents of the file at `path`. Returns `Ok` when they're the same,
+/// or when the file is (re)written because it doesn't exist or `LEXGEN_UPDATE_SNAPSHOTS` is set.
+/// Otherwise returns an error message describing the first difference.
+pub fn check_snapshot(path: &std::path::Path, snapshot: &str) -> Result<(), String> {
+    let update = std::env::var_os("LEXGEN_UPDATE_SNAPSHOTS").is_some();
+
+    let old_snapshot = match std::fs::read_to_string(path) {
+        Ok(old_snapshot) if !update => old_snapshot,
+        _ => {
+            return std::fs::write(path, snapshot)
+                .map_err(|err| format!("Unable to write snapshot {:?}: {}", path, err));
+        }
+    };
+
+    if old_snapshot == snapshot {
+        return Ok(());
+    }
+
+    let mut old_lines = old_snapshot.lines();
+    let mut new_lines = snapshot.lines();
+    let mut line = 1;
+    loop {
+        let old_line = old_lines.next();
+        let new_line = new_lines.next();
+        if old_line != new_line {
+            return Err(format!(
+                "DFA of the lexer doesn't match snapshot {:?}. First difference at line {}: \
+                expected {:?}, found {:?}. Set LEXGEN_UPDATE_SNAPSHOTS=1 to update the snapshot.",
+                path,
+                line,
+                old_line.unwrap_or("<end of file>"),
+                new_line.unwrap_or("<end of file>"),
+            ));
+        }
+        if old_line.is_none() {
+            // Only line endings differ
+            return Ok(());
+        }
+        line += 1;
+    }
+}
+
+/// A state of a minimized DFA, with transitions to indices of states in canonical order
+struct CanonicalState {
+    accepting: Vec<String>,
+    // Sorted, non-overlapping inclusive character ranges
+    transitions: Vec<(u32, u32, usize)>,
+    any_transition: Option<usize>,
+    end_of_input_transition: Option<usize>,
+}
+
+/// Transitions of a state to blocks of a partition of the DFA states, used as the key when
+/// splitting blocks
+#[derive(PartialEq, Eq, Hash)]
+struct Transitions {
+    ranges: Vec<(u32, u32, usize)>,
+    any: Option<usize>,
+    end_of_input: Option<usize>,
+}
+
+/// Minimize the DFA and number the states reachable from `initial_states` in breadth-first order.
+/// Returns the states in canonical order, and the indices of `initial_states`.
+fn canonicalize<A>(
+    dfa: &DFA<StateIdx, A>,
+    initial_states: &[StateIdx],
+    label: &dyn Fn(&AcceptingState<A>) -> String,
+) -> (Vec<CanonicalState>, Vec<usize>) {
+    let states = &dfa.states;
+
+    let char_ranges: Vec<Vec<(u32, u32, StateIdx)>> = states.iter().map(char_ranges).collect();
+
+    // Moore's algorithm: start with states partitioned by the values they accept, split blocks
+    // until all states in a block have transitions to the same blocks.
+    let (mut blocks, mut n_blocks) = partition(states.iter().map(|state| {
+        (
+            state.initial,
+            state.accepting.iter().map(label).collect::<Vec<_>>(),
+        )
+    }));
+
+    loop {
+        let (new_blocks, new_n_blocks) =
+            partition(states.iter().zip(char_ranges.iter()).enumerate().map(
+                |(state_idx, (state, ranges))| {
+                    (blocks[state_idx], transitions(state, ranges, &blocks))
+                },
+            ));
+
+        blocks = new_blocks;
+
+        if new_n_blocks == n_blocks {
+            break;
+        }
+
+        n_blocks = new_n_blocks;
+    }
+
+    // A state of each block
+    let mut representatives: Vec<usize> = vec![usize::MAX; n_blocks];
+    for (state_idx, block) in blocks.iter().enumerate().rev() {
+        representatives[*block] = state_idx;
+    }
+
+    // Number blocks in breadth-first order
+    let mut canonical_indices: Map<usize, usize> = Default::default();
+    let mut work_list: VecDeque<usize> = VecDeque::new();
+
+    let mut visit = |block: usize, work_list: &mut VecDeque<usize>| -> usize {
+        let n_visited = canonical_indices.len();
+        *canonical_indices.entry(block).or_insert_with(|| {
+            work_list.push_back(block);
+            n_visited
+        })
+    };
+
+    let initial_indices: Vec<usize> = initial_states
+        .iter()
+        .map(|state| visit(blocks[state.0], &mut work_list))
+        .collect();
+
+    let mut canonical_states: Vec<CanonicalState> = vec![];
+
+    while let Some(block) = work_list.pop_front() {
+        let state_idx = representatives[block];
+        let state = &states[state_idx];
+
+        let Transitions {
+            ranges,
+            any,
+            end_of_input,
+        } = transitions(state, &char_ranges[state_idx], &blocks);
+
+        let transitions = ranges
+            .into_iter()
+            .map(|(start, end, next)| (start, end, visit(next, &mut work_list)))
+            .collect();
+        let any_transition = any.map(|next| visit(next, &mut work_list));
+        let end_of_input_transition = end_of_input.map(|next| visit(next, &mut work_list));
+
+        canonical_states.push(CanonicalState {
+            accepting: state.accepting.iter().map(label).collect(),
+            transitions,
+            any_transition,
+            end_of_input_transition,
+        });
+    }
+
+    (canonical_states, initial_indices)
+}
+
+/// Assign a block to each key, with equal keys in the same block. Returns blocks of the keys and
+/// the number of blocks.
+fn partition<K: Eq + Hash>(keys: impl Iterator<Item = K>) -> (Vec<usize>, usize) {
+    let mut block_indices: Map<K, usize> = Default::default();
+
+    let blocks = keys
+        .map(|key| {
+            let n_blocks = block_indices.len();
+            *block_indices.entry(key).or_insert(n_blocks)
+        })
+        .collect();
+
+    (blocks, block_indices.len())
+}
+
+/// Character transitions of a state as sorted, non-overlapping ranges
+pub(super) fn char_ranges<A>(state: &State<StateIdx, A>) -> Vec<(u32, u32, StateIdx)> {
+    state
+        .transitions
+        .iter()
+        .map(|range| (range.start, range.end, range.value))
+        .collect()
+}
+
+/// Transitions of a state to blocks, with adjacent ranges to the same block merged
+fn transitions<A>(
+    state: &State<StateIdx, A>,
+    char_ranges: &[(u32, u32, StateIdx)],
+    blocks: &[usize],
+) -> Transitions {
+    let mut ranges: Vec<(u32, u32, usize)> = vec![];
+
+    for (start, end, next) in char_ranges {
+        let next = blocks[next.0];
+        match ranges.last_mut() {
+            Some((_, last_end, last_next)) if *last_end + 1 == *start && *last_next == next => {
+                *last_end = *end;
+            }
+            _ => ranges.push((*start, *end, next)),
+        }
+    }
+
+    Transitions {
+        ranges,
+        any: state.any_transition.map(|next| blocks[next.0]),
+        end_of_input: state.end_of_input_transition.map(|next| blocks[next.0]),
+    }
+}
+
+fn write_states(out: &mut String, states: &[CanonicalState], indent: &str) {
+    for (state_idx, state) in states.iter().enumerate() {
+        writeln!(out).unwrap();
+        writeln!(out, "{}State {}", indent, state_idx).unwrap();
+
+        for accepting in &state.accepting {
+            writeln!(out, "{}  accepts: {}", indent, accepting).unwrap();
+        }
+
+        // Merge ranges to the same state
+        let mut transitions: BTreeMap<usize, Vec<(char, char)>> = Default::default();
+
+        for (start, end, next) in &state.transitions {
+            transitions.entry(*next).or_default().push((
+                char::from_u32(*start).unwrap(),
+                char::from_u32(*end).unwrap(),
+            ));
+        }
+
+        for (next, ranges) in transitions {
+            writeln!(
+                out,
+                "{}  {} -> {}",
+                indent,
+                CharRangesDisplay(&ranges),
+                next
+            )
+            .unwrap();
+        }
+
+        if let Some(next) = state.any_transition {
+            writeln!(out, "{}  _ -> {}", indent, next).unwrap();
+        }
+
+        if let Some(next) = state.end_of_input_transition {
+            writeln!(out, "{}  $ -> {}", indent, next).unwrap();
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Reporting warnings from the proc macro.
+//!
+//! Proc macros can't emit warnings on stable Rust, so we generate code that uses a deprecated
+//! item, with the warning message as the deprecation note, at the given span.
+
+use crate::collections::Map;
+use crate::dfa::analysis::{conflicts, empty_matches, matched_values};
+use crate::dfa::{StateIdx, DFA};
+use crate::semantic_action_table::SemanticActionIdx;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+
+/// A rule in the lexer definition
+pub struct RuleInfo {
+    pub action: SemanticActionIdx,
+
+    /// Span of the first token of the rule
+    pub span: Span,
+
+    /// Name of the rule set the rule is defined in
+    pub rule_set: String,
+
+    /// Position of the rule in the rule set, starting from 1
+    pub position: usize,
+
+    /// Priority of the rule, `priority <int>`. 0 when the rule doesn't have a priority.
+    pub priority: i64,
+
+    /// Whether the rule is a `shortest` rule
+    pub shortest: bool,
+
+    /// Whether the rule has a right context, `<regex> > <regex>`
+    pub right_ctx: bool,
+}
+
+impl RuleInfo {
+    pub fn describe(&self) -> String {
+        format!("rule {} in rule set {}", self.position, self.rule_set)
+    }
+
+    /// Short name of the rule, used in state names
+    pub fn name(&self) -> String {
+        format!("{}:{}", self.rule_set, self.position)
+    }
+}
+
+/// Why `winner` is chosen over `loser` when both match the same input
+fn tie_reason(winner: &RuleInfo, loser: &RuleInfo) -> &'static str {
+    if winner.priority > loser.priority {
+        "it has a higher priority"
+    } else {
+        "it's listed first"
+    }
+}
+
+pub fn warning(span: Span, message: &str) -> TokenStream {
+    quote_spanned!(span=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const lexgen_warning: () = ();
+            lexgen_warning
+        };
+    )
+}
+
+/// Warnings for rules that match the empty string, rules that are never matched, and with
+/// `ambiguities`, for rules that match the same input as a rule listed before them. Returns the rules with the warning messages, in the
+/// order the rules are defined.
+pub fn rule_warnings<'a>(
+    dfa: &DFA<StateIdx, SemanticActionIdx>,
+    rules: &'a [RuleInfo],
+    ambiguities: bool,
+) -> Vec<(&'a RuleInfo, String)> {
+    let matched = matched_values(dfa);
+    let empty_matches = empty_matches(dfa);
+    let conflicts = conflicts(dfa);
+
+    let rules_by_action: Map<SemanticActionIdx, &RuleInfo> =
+        rules.iter().map(|rule| (rule.action, rule)).collect();
+
+    // Conflicts for each losing rule, sorted by the winning rule
+    let mut losses: Map<SemanticActionIdx, Vec<(SemanticActionIdx, Option<&str>)>> =
+        Default::default();
+
+    for ((winner, loser), input) in conflicts.iter() {
+        losses
+            .entry(*loser)
+            .or_default()
+            .push((*winner, input.as_deref()));
+    }
+
+    for losses in losses.values_mut() {
+        losses.sort_by_key(|(winner, _)| *winner);
+    }
+
+    let mut warnings = vec![];
+
+    for rule in rules {
+        if empty_matches.contains(&rule.action) {
+            warnings.push((
+                rule,
+                "This rule matches the empty string: when no other rule matches, the lexer \
+                matches this rule without consuming input and loops forever"
+                    .to_owned(),
+            ));
+        }
+
+        let losses = match losses.get(&rule.action) {
+            Some(losses) => losses.as_slice(),
+            None => &[],
+        };
+
+        if !matched.contains(&rule.action) {
+            // Report the shortest example
+            let example = losses
+                .iter()
+                .filter_map(|(winner, input)| Some((rules_by_action[winner], (*input)?)))
+                .min_by_key(|(_, input)| input.chars().count());
+
+            let message = match example {
+                Some((winner, input)) if winner.priority > rule.priority => format!(
+                    "This rule is never matched: rules with a higher priority always match the \
+                    same input, e.g. {:?} is matched by {}",
+                    input,
+                    winner.describe(),
+                ),
+                Some((winner, input)) => format!(
+                    "This rule is never matched: rules listed before it always match the same \
+                    input, e.g. {:?} is matched by {}",
+                    input,
+                    winner.describe(),
+                ),
+                None => "This rule is never matched: rules listed before it always match the \
+                    same input"
+                    .to_owned(),
+            };
+
+            warnings.push((rule, message));
+        } else if ambiguities {
+            for (winner, input) in losses {
+                let winner = rules_by_action[winner];
+                let message = match input {
+                    Some(input) => format!(
+                        "This rule is ambiguous with {}: both match {:?}, the other rule is \
+                        chosen as {}",
+                        winner.describe(),
+                        input,
+                        tie_reason(winner, rule),
+                    ),
+                    None => format!(
+                        "This rule is ambiguous with {}: both match the same input, the other \
+                        rule is chosen as {}",
+                        winner.describe(),
+                        tie_reason(winner, rule),
+                    ),
+                };
+
+                warnings.push((rule, message));
+            }
+        }
+    }
+
+    warnings
+}
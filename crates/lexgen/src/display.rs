@@ -1,15 +1,11 @@
-use std::collections::{BTreeSet, HashSet};
+use std::collections::HashSet;
 use std::fmt::{self, Display, Formatter};
 
-pub struct BTreeSetDisplay<'a, A: Display>(pub &'a BTreeSet<A>);
+use quote::ToTokens;
 
-pub struct HashSetDisplay<'a, A: Display, S>(pub &'a HashSet<A, S>);
+use crate::ast::{CharOrRange, Regex};
 
-impl<'a, A: Display> Display for BTreeSetDisplay<'a, A> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        display_set(f, self.0.len(), &mut self.0.iter())
-    }
-}
+pub struct HashSetDisplay<'a, A: Display, S>(pub &'a HashSet<A, S>);
 
 impl<'a, A: Display, S> Display for HashSetDisplay<'a, A, S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -33,3 +29,124 @@ fn display_set<A: Display>(
 
     write!(f, "}}")
 }
+
+/// Displays a sorted list of inclusive character ranges as `'a'-'z', '_'`, merging adjacent
+/// ranges.
+pub struct CharRangesDisplay<'a>(pub &'a [(char, char)]);
+
+impl<'a> Display for CharRangesDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut merged: Vec<(char, char)> = vec![];
+
+        for (start, end) in self.0.iter().copied() {
+            match merged.last_mut() {
+                Some((_, last_end)) if *last_end as u32 + 1 >= start as u32 => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+
+        for (range_idx, (start, end)) in merged.iter().enumerate() {
+            if range_idx != 0 {
+                write!(f, ", ")?;
+            }
+            if start == end {
+                write!(f, "{:?}", start)?;
+            } else {
+                write!(f, "{:?}-{:?}", start, end)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays a regex in the lexer definition syntax, e.g. `['a'-'z'] ($x | '_')*`. Parentheses are
+/// only added where needed, so the output may differ from the definition.
+pub struct RegexDisplay<'a>(pub &'a Regex);
+
+impl<'a> Display for RegexDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        display_regex(f, self.0, 0)
+    }
+}
+
+// Binding powers: alternation 0, concatenation 1, repetition 2, difference 3
+fn display_regex(f: &mut Formatter<'_>, regex: &Regex, prec: u8) -> fmt::Result {
+    let (regex_prec, left, op, right): (u8, &Regex, &str, &Regex) = match regex {
+        Regex::Builtin(builtin) => return write!(f, "$${}", builtin.0),
+        Regex::Var(var) => return write!(f, "${}", var.0),
+        Regex::Char(char) => return write!(f, "{:?}", char),
+        Regex::String(str) => return write!(f, "{:?}", str),
+        Regex::CharSet(set) => {
+            write!(f, "[")?;
+            for (item_idx, item) in set.0.iter().enumerate() {
+                if item_idx != 0 {
+                    write!(f, " ")?;
+                }
+                match item {
+                    CharOrRange::Char(char) => write!(f, "{:?}", char)?,
+                    CharOrRange::Range(start, end) => write!(f, "{:?}-{:?}", start, end)?,
+                }
+            }
+            return write!(f, "]");
+        }
+        Regex::Any => return write!(f, "_"),
+        Regex::EndOfInput => return write!(f, "$"),
+        Regex::Capture(re, name) => {
+            write!(f, "(")?;
+            display_regex(f, re, 0)?;
+            return write!(f, " as {})", name);
+        }
+        Regex::ZeroOrMore(re) | Regex::OneOrMore(re) | Regex::ZeroOrOne(re) => {
+            if prec > 2 {
+                write!(f, "(")?;
+            }
+            display_regex(f, re, 2)?;
+            write!(
+                f,
+                "{}",
+                match regex {
+                    Regex::ZeroOrMore(_) => '*',
+                    Regex::OneOrMore(_) => '+',
+                    _ => '?',
+                }
+            )?;
+            if prec > 2 {
+                write!(f, ")")?;
+            }
+            return Ok(());
+        }
+        Regex::Concat(re1, re2) => (1, re1, " ", re2),
+        Regex::Or(re1, re2) => (0, re1, " | ", re2),
+        Regex::Diff(re1, re2) => (3, re1, " # ", re2),
+    };
+
+    if prec > regex_prec {
+        write!(f, "(")?;
+    }
+    // Operators are left associative
+    display_regex(f, left, regex_prec)?;
+    write!(f, "{}", op)?;
+    display_regex(f, right, regex_prec + 1)?;
+    if prec > regex_prec {
+        write!(f, ")")?;
+    }
+    Ok(())
+}
+
+/// Print types and paths without the spaces `TokenStream`'s `Display` adds between tokens, e.g.
+/// `Token<'input>` instead of `Token < 'input >`
+pub fn tokens_to_string<T: ToTokens>(tokens: &T) -> String {
+    tokens
+        .to_token_stream()
+        .to_string()
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace("& ", "&")
+}
@@ -0,0 +1,165 @@
+//! Graphviz DOT and Mermaid output for NFAs and DFAs, for debugging lexer definitions.
+//!
+//! When the environment variable `LEXGEN_DOT_DIR` is set, NFAs of rule sets and the final DFA are
+//! written to DOT files in the directory, with names `<lexer>_<rule set>.nfa.dot` and
+//! `<lexer>.dfa.dot`.
+//!
+//! When `LEXGEN_MERMAID_DIR` is set, the final DFA is written as a Mermaid state diagram to
+//! `<lexer>.dfa.mmd` in the directory.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+/// A state machine graph, built by `NFA::to_dot`, `DFA::to_dot`, and `DFA::to_mermaid`
+pub struct Graph {
+    /// States: whether the state is initial, name of the state, and labels of values of the state
+    /// if it's accepting
+    nodes: Vec<(bool, Option<String>, Vec<String>)>,
+
+    /// Maps (source, target) states to labels of transitions between them
+    edges: BTreeMap<(usize, usize), Vec<String>>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph {
+            nodes: vec![],
+            edges: Default::default(),
+        }
+    }
+
+    /// Add a state. States should be added in order of their indices. States are labelled with
+    /// their indices, and `name` when available.
+    pub fn add_node(&mut self, initial: bool, name: Option<String>, accepting: Vec<String>) {
+        self.nodes.push((initial, name, accepting));
+    }
+
+    pub fn add_edge(&mut self, source: usize, target: usize, label: String) {
+        self.edges.entry((source, target)).or_default().push(label);
+    }
+
+    pub fn render_dot(&self, name: &str) -> String {
+        let mut out = String::new();
+
+        writeln!(out, "digraph \"{}\" {{", escape(name)).unwrap();
+        writeln!(out, "    rankdir=LR;").unwrap();
+        writeln!(out, "    node [shape=circle];").unwrap();
+
+        for (node_idx, (initial, name, accepting)) in self.nodes.iter().enumerate() {
+            let mut label = node_idx.to_string();
+            if let Some(name) = name {
+                label.push_str("\\n");
+                label.push_str(&escape(name));
+            }
+            for value in accepting {
+                label.push_str("\\n");
+                label.push_str(&escape(value));
+            }
+
+            let shape = if accepting.is_empty() {
+                ""
+            } else {
+                ", shape=doublecircle"
+            };
+
+            writeln!(out, "    {} [label=\"{}\"{}];", node_idx, label, shape).unwrap();
+
+            if *initial {
+                writeln!(out, "    start{} [shape=point];", node_idx).unwrap();
+                writeln!(out, "    start{} -> {};", node_idx, node_idx).unwrap();
+            }
+        }
+
+        for ((source, target), labels) in &self.edges {
+            let labels: Vec<String> = labels.iter().map(|label| escape(label)).collect();
+            writeln!(
+                out,
+                "    {} -> {} [label=\"{}\"];",
+                source,
+                target,
+                labels.join(", ")
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "}}").unwrap();
+
+        out
+    }
+
+    /// Render as a Mermaid `stateDiagram-v2`. Accepting states have a transition to the final
+    /// state `[*]`.
+    pub fn render_mermaid(&self) -> String {
+        let mut out = String::new();
+
+        writeln!(out, "stateDiagram-v2").unwrap();
+        writeln!(out, "    direction LR").unwrap();
+
+        for (node_idx, (initial, name, accepting)) in self.nodes.iter().enumerate() {
+            let mut label = node_idx.to_string();
+            if let Some(name) = name {
+                label.push_str("<br>");
+                label.push_str(&escape_mermaid(name));
+            }
+            for value in accepting {
+                label.push_str("<br>");
+                label.push_str(&escape_mermaid(value));
+            }
+
+            writeln!(out, "    s{} : {}", node_idx, label).unwrap();
+
+            if *initial {
+                writeln!(out, "    [*] --> s{}", node_idx).unwrap();
+            }
+
+            if !accepting.is_empty() {
+                writeln!(out, "    s{} --> [*]", node_idx).unwrap();
+            }
+        }
+
+        for ((source, target), labels) in &self.edges {
+            let labels: Vec<String> = labels.iter().map(|label| escape_mermaid(label)).collect();
+            writeln!(
+                out,
+                "    s{} --> s{} : {}",
+                source,
+                target,
+                labels.join(", ")
+            )
+            .unwrap();
+        }
+
+        out
+    }
+}
+
+fn escape(str: &str) -> String {
+    str.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mermaid labels can't contain some characters, which are written as entity codes
+fn escape_mermaid(str: &str) -> String {
+    let mut escaped = String::with_capacity(str.len());
+    for char in str.chars() {
+        match char {
+            '#' => escaped.push_str("#35;"),
+            ';' => escaped.push_str("#59;"),
+            ':' => escaped.push_str("#58;"),
+            '<' => escaped.push_str("#lt;"),
+            '>' => escaped.push_str("#gt;"),
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+/// Directory to write DOT files to, from the `LEXGEN_DOT_DIR` environment variable
+pub fn dot_dir() -> Option<PathBuf> {
+    std::env::var_os("LEXGEN_DOT_DIR").map(PathBuf::from)
+}
+
+/// Directory to write Mermaid diagrams to, from the `LEXGEN_MERMAID_DIR` environment variable
+pub fn mermaid_dir() -> Option<PathBuf> {
+    std::env::var_os("LEXGEN_MERMAID_DIR").map(PathBuf::from)
+}
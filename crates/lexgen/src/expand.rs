@@ -0,0 +1,17 @@
+//! Writing generated code to files for reading, with the `LEXGEN_EXPAND_DIR` environment variable
+
+use std::path::PathBuf;
+
+/// Directory to write generated code to, from the `LEXGEN_EXPAND_DIR` environment variable
+pub fn expand_dir() -> Option<PathBuf> {
+    std::env::var_os("LEXGEN_EXPAND_DIR").map(PathBuf::from)
+}
+
+/// Format generated items with `prettyplease`. Falls back to the unformatted tokens if the code
+/// can't be parsed as items, which would be a bug in code generation.
+pub fn pretty_print(code: &proc_macro2::TokenStream) -> String {
+    match syn::parse2::<syn::File>(code.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => code.to_string(),
+    }
+}
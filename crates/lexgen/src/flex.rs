@@ -0,0 +1,918 @@
+//! Converts flex scanner definitions (`.l` files) to lexgen lexer definitions, for `lexgen
+//! --from-flex`.
+//!
+//! Supported subset of flex:
+//!
+//! - Definitions (`name regex`) are converted to let bindings.
+//! - Start conditions (`%s` and `%x`) are converted to rule sets. `INITIAL` is `Init`. Rules
+//!   without start conditions are added to `Init` and inclusive (`%s`) start conditions, rules
+//!   with `<*>` are added to all rule sets. Start condition scopes (`<SC>{ ... }`) are supported.
+//! - Patterns: characters, escapes, `"..."`, `.`, `[...]` (with negation and POSIX classes like
+//!   `[:alpha:]`), `{name}`, `*`, `+`, `?`, `{n}`, `{n,}`, `{n,m}`, `|`, `(...)`, trailing context
+//!   (`r/s`, `r$`), and `<<EOF>>`.
+//! - Empty actions (`;`) are converted to rules that skip the match, and actions that only switch
+//!   to a start condition (`BEGIN(SC);`) are converted to `lexer.switch(...)`. Other actions are
+//!   C code, they're copied to comments above their rules, with `todo!()` as the semantic
+//!   action. `|` actions use the action of the next rule.
+//!
+//! `%{ ... %}` code blocks, indented code, `%option`s, and the user code section are ignored.
+
+use std::fmt::Write;
+
+/// An error in a flex file, with the line number (starting from 1)
+#[derive(Debug)]
+pub struct FlexError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for FlexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.line, self.message)
+    }
+}
+
+fn error<A>(line: usize, message: impl Into<String>) -> Result<A, FlexError> {
+    Err(FlexError {
+        line,
+        message: message.into(),
+    })
+}
+
+/// Convert the flex file to a `lexer!` definition
+pub fn convert(source: &str) -> Result<String, FlexError> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut line_idx = 0;
+
+    // Definitions section
+    let mut definitions: Vec<(String, Regex)> = vec![];
+
+    // Start conditions, with whether they're exclusive, in the order they're declared. `INITIAL`
+    // is the first one.
+    let mut start_conditions: Vec<(String, bool)> = vec![("INITIAL".to_owned(), false)];
+
+    while line_idx < lines.len() {
+        let line = lines[line_idx];
+        let line_number = line_idx + 1;
+        line_idx += 1;
+
+        if line.trim_end() == "%%" {
+            break;
+        }
+
+        if line.starts_with("%{") || line.starts_with("%top{") {
+            skip_code_block(&lines, &mut line_idx);
+        } else if line.starts_with("/*") {
+            skip_comment(&lines, line_idx - 1, &mut line_idx);
+        } else if line.trim().is_empty() || line.starts_with([' ', '\t']) {
+            // Indented lines are code
+        } else if let Some(rest) = line.strip_prefix('%') {
+            let mut words = rest.split_whitespace();
+            let exclusive = match words.next() {
+                Some("s" | "S") => false,
+                Some("x" | "X") => true,
+                // Options, `%pointer`, `%array`, ...
+                _ => continue,
+            };
+            for name in words {
+                start_conditions.push((name.to_owned(), exclusive));
+            }
+        } else {
+            let name_end = line.find(|c: char| c.is_whitespace()).unwrap_or(line.len());
+            let name = &line[..name_end];
+            if !is_name(name) {
+                return error(line_number, format!("Invalid definition name {:?}", name));
+            }
+            let pattern = line[name_end..].trim();
+            if pattern.is_empty() {
+                return error(line_number, format!("Definition {:?} is empty", name));
+            }
+            let regex = PatternParser::new(pattern, line_number).parse_definition()?;
+            definitions.push((binding_name(name), regex));
+        }
+    }
+
+    // Rules section
+    let mut rules: Vec<FlexRule> = vec![];
+
+    // Start conditions of the enclosing start condition scopes
+    let mut scopes: Vec<Vec<String>> = vec![];
+
+    while line_idx < lines.len() {
+        let line = lines[line_idx];
+        let line_number = line_idx + 1;
+        line_idx += 1;
+
+        if line.trim_end() == "%%" {
+            break;
+        }
+
+        if line.starts_with("%{") {
+            skip_code_block(&lines, &mut line_idx);
+            continue;
+        }
+
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if trimmed == "}" && !scopes.is_empty() {
+            scopes.pop();
+            continue;
+        }
+
+        if line.starts_with([' ', '\t']) {
+            if trimmed.starts_with("/*") {
+                skip_comment(&lines, line_idx - 1, &mut line_idx);
+            }
+            // Other indented lines are code
+            continue;
+        }
+
+        // Start conditions of the rule
+        let mut conditions: Vec<String> = scopes.last().cloned().unwrap_or_default();
+        let mut rest = line;
+
+        if let Some(after_open) = rest.strip_prefix('<') {
+            if !after_open.starts_with("<EOF>>") {
+                let close = match after_open.find('>') {
+                    Some(close) => close,
+                    None => return error(line_number, "Unterminated start condition list"),
+                };
+                conditions = after_open[..close]
+                    .split(',')
+                    .map(|condition| condition.trim().to_owned())
+                    .collect();
+                for condition in &conditions {
+                    if condition != "*"
+                        && !start_conditions.iter().any(|(name, _)| name == condition)
+                    {
+                        return error(
+                            line_number,
+                            format!("Undeclared start condition {:?}", condition),
+                        );
+                    }
+                }
+                rest = &after_open[close + 1..];
+
+                // Start condition scope
+                if rest.trim() == "{" {
+                    scopes.push(conditions);
+                    continue;
+                }
+            }
+        }
+
+        let (regex, right_ctx, action_start) = if let Some(after_eof) = rest.strip_prefix("<<EOF>>")
+        {
+            (Regex::EndOfInput, None, after_eof)
+        } else {
+            let pattern_len = pattern_len(rest);
+            let (regex, right_ctx) =
+                PatternParser::new(&rest[..pattern_len], line_number).parse_rule()?;
+            (regex, right_ctx, &rest[pattern_len..])
+        };
+
+        let action = parse_action(&lines, action_start.trim(), line_number, &mut line_idx)?;
+
+        rules.push(FlexRule {
+            conditions,
+            regex,
+            right_ctx,
+            action,
+        });
+    }
+
+    // `|` actions use the action of the next rule
+    for rule_idx in (0..rules.len()).rev() {
+        if let Action::Next = rules[rule_idx].action {
+            let next = match rules.get(rule_idx + 1) {
+                Some(next) => next.action.clone(),
+                None => Action::Code("|".to_owned()),
+            };
+            rules[rule_idx].action = next;
+        }
+    }
+
+    Ok(print_lexer(&definitions, &start_conditions, &rules))
+}
+
+struct FlexRule {
+    /// Start conditions of the rule, empty when the rule doesn't specify any
+    conditions: Vec<String>,
+    regex: Regex,
+    right_ctx: Option<Regex>,
+    action: Action,
+}
+
+#[derive(Clone)]
+enum Action {
+    /// `;`: skip the match
+    Skip,
+
+    /// `|`: the action of the next rule
+    Next,
+
+    /// `BEGIN(SC);`
+    Begin(String),
+
+    /// Other actions, as written
+    Code(String),
+}
+
+/// Skip lines until `%}`
+fn skip_code_block(lines: &[&str], line_idx: &mut usize) {
+    while *line_idx < lines.len() {
+        let line = lines[*line_idx];
+        *line_idx += 1;
+        if line.trim_start().starts_with("%}") {
+            break;
+        }
+    }
+}
+
+/// Skip a comment starting in line `comment_line_idx`
+fn skip_comment(lines: &[&str], comment_line_idx: usize, line_idx: &mut usize) {
+    if lines[comment_line_idx].contains("*/") {
+        return;
+    }
+    while *line_idx < lines.len() {
+        let line = lines[*line_idx];
+        *line_idx += 1;
+        if line.contains("*/") {
+            break;
+        }
+    }
+}
+
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Flex names can have `-`s, Rust identifiers can't
+fn binding_name(name: &str) -> String {
+    name.replace('-', "_")
+}
+
+fn rule_set_name(start_condition: &str) -> &str {
+    if start_condition == "INITIAL" {
+        "Init"
+    } else {
+        start_condition
+    }
+}
+
+/// Length of the pattern at the beginning of a rule line: patterns end at the first whitespace
+/// outside of quotes and brackets.
+fn pattern_len(line: &str) -> usize {
+    let mut in_quotes = false;
+    let mut in_brackets = false;
+    let mut chars = line.char_indices().peekable();
+
+    while let Some((idx, char)) = chars.next() {
+        match char {
+            '\\' => {
+                chars.next();
+            }
+            '"' if !in_brackets => in_quotes = !in_quotes,
+            '[' if !in_quotes && !in_brackets => {
+                in_brackets = true;
+                // `]` and `^]` at the beginning of a class are characters
+                if let Some((_, '^')) = chars.peek() {
+                    chars.next();
+                }
+                if let Some((_, ']')) = chars.peek() {
+                    chars.next();
+                }
+            }
+            '[' if in_brackets && line[idx..].starts_with("[:") => {
+                // POSIX class, skip to `:]`
+                if let Some(end) = line[idx..].find(":]") {
+                    while let Some((next_idx, _)) = chars.peek() {
+                        if *next_idx > idx + end + 1 {
+                            break;
+                        }
+                        chars.next();
+                    }
+                }
+            }
+            ']' if in_brackets => in_brackets = false,
+            c if c.is_whitespace() && !in_quotes && !in_brackets => return idx,
+            _ => {}
+        }
+    }
+
+    line.len()
+}
+
+/// Parse the action of a rule. Actions starting with `{` can span multiple lines.
+fn parse_action(
+    lines: &[&str],
+    action: &str,
+    line_number: usize,
+    line_idx: &mut usize,
+) -> Result<Action, FlexError> {
+    if action == "|" {
+        return Ok(Action::Next);
+    }
+
+    let mut code = action.to_owned();
+
+    if action.starts_with('{') {
+        let mut depth = brace_depth(action);
+        while depth > 0 {
+            if *line_idx >= lines.len() {
+                return error(line_number, "Unterminated action");
+            }
+            let line = lines[*line_idx];
+            *line_idx += 1;
+            code.push('\n');
+            code.push_str(line);
+            depth += brace_depth(line);
+        }
+    }
+
+    // Remove braces and whitespace to recognize simple actions
+    let simple: String = code
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    if simple.is_empty() || simple == ";" {
+        return Ok(Action::Skip);
+    }
+
+    if let Some(condition) = simple
+        .strip_prefix("BEGIN(")
+        .and_then(|rest| rest.strip_suffix(");"))
+        .or_else(|| {
+            simple
+                .strip_prefix("BEGIN")
+                .and_then(|rest| rest.strip_suffix(';'))
+        })
+    {
+        if is_name(condition) {
+            return Ok(Action::Begin(condition.to_owned()));
+        }
+    }
+
+    Ok(Action::Code(code))
+}
+
+/// Change in brace depth in a line of C code, ignoring braces in strings and characters
+fn brace_depth(line: &str) -> i32 {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+    while let Some(char) = chars.next() {
+        match (char, quote) {
+            ('\\', Some(_)) => {
+                chars.next();
+            }
+            ('"' | '\'', None) => quote = Some(char),
+            (c, Some(q)) if c == q => quote = None,
+            ('{', None) => depth += 1,
+            ('}', None) => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
+
+#[derive(Debug, Clone)]
+enum Regex {
+    Char(char),
+    String(String),
+    /// `[...]`, inclusive ranges
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+    /// `.`
+    AnyButNewline,
+    Var(String),
+    Concat(Vec<Regex>),
+    Alt(Vec<Regex>),
+    ZeroOrMore(Box<Regex>),
+    OneOrMore(Box<Regex>),
+    ZeroOrOne(Box<Regex>),
+    /// `<<EOF>>`
+    EndOfInput,
+}
+
+struct PatternParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line_number: usize,
+}
+
+impl<'a> PatternParser<'a> {
+    fn new(pattern: &'a str, line_number: usize) -> Self {
+        PatternParser {
+            chars: pattern.chars().peekable(),
+            line_number,
+        }
+    }
+
+    fn error<A>(&self, message: impl Into<String>) -> Result<A, FlexError> {
+        error(self.line_number, message)
+    }
+
+    fn parse_definition(mut self) -> Result<Regex, FlexError> {
+        let regex = self.parse_alt()?;
+        match self.chars.next() {
+            None => Ok(regex),
+            Some(char) => self.error(format!("Unexpected {:?} in definition", char)),
+        }
+    }
+
+    /// Parse a rule pattern, with optional trailing context
+    fn parse_rule(mut self) -> Result<(Regex, Option<Regex>), FlexError> {
+        if self.chars.peek() == Some(&'^') {
+            return self.error("Beginning of line anchors (`^`) are not supported");
+        }
+
+        let regex = self.parse_alt()?;
+
+        match self.chars.next() {
+            None => Ok((regex, None)),
+            Some('/') => {
+                let right_ctx = self.parse_alt()?;
+                match self.chars.next() {
+                    None => Ok((regex, Some(right_ctx))),
+                    Some(char) => self.error(format!("Unexpected {:?} in pattern", char)),
+                }
+            }
+            Some('$') if self.chars.peek().is_none() => Ok((regex, Some(Regex::Char('\n')))),
+            Some(char) => self.error(format!("Unexpected {:?} in pattern", char)),
+        }
+    }
+
+    fn parse_alt(&mut self) -> Result<Regex, FlexError> {
+        let mut alts = vec![self.parse_concat()?];
+        while self.chars.peek() == Some(&'|') {
+            self.chars.next();
+            alts.push(self.parse_concat()?);
+        }
+        Ok(if alts.len() == 1 {
+            alts.pop().unwrap()
+        } else {
+            Regex::Alt(alts)
+        })
+    }
+
+    fn parse_concat(&mut self) -> Result<Regex, FlexError> {
+        let mut regexes: Vec<Regex> = vec![];
+
+        loop {
+            match self.chars.peek() {
+                None | Some('|' | ')' | '/') => break,
+                // `$` at the end of the pattern is trailing context
+                Some('$') => {
+                    let mut rest = self.chars.clone();
+                    rest.next();
+                    if rest.peek().is_none() {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+
+            let regex = self.parse_repeat()?;
+
+            // Merge consecutive characters to strings
+            match (regexes.last_mut(), regex) {
+                (Some(last @ (Regex::Char(_) | Regex::String(_))), Regex::Char(char)) => {
+                    let mut string = match last {
+                        Regex::Char(last_char) => last_char.to_string(),
+                        Regex::String(string) => std::mem::take(string),
+                        _ => unreachable!(),
+                    };
+                    string.push(char);
+                    *last = Regex::String(string);
+                }
+                (Some(Regex::String(last)), Regex::String(string)) => last.push_str(&string),
+                (Some(Regex::Char(last_char)), Regex::String(string)) => {
+                    let mut merged = last_char.to_string();
+                    merged.push_str(&string);
+                    *regexes.last_mut().unwrap() = Regex::String(merged);
+                }
+                (_, Regex::Concat(concat)) => regexes.extend(concat),
+                (_, regex) => regexes.push(regex),
+            }
+        }
+
+        match regexes.len() {
+            0 => self.error("Empty regex"),
+            1 => Ok(regexes.pop().unwrap()),
+            _ => Ok(Regex::Concat(regexes)),
+        }
+    }
+
+    fn parse_repeat(&mut self) -> Result<Regex, FlexError> {
+        let mut regex = self.parse_atom()?;
+
+        loop {
+            match self.chars.peek() {
+                Some('*') => {
+                    self.chars.next();
+                    regex = Regex::ZeroOrMore(Box::new(regex));
+                }
+                Some('+') => {
+                    self.chars.next();
+                    regex = Regex::OneOrMore(Box::new(regex));
+                }
+                Some('?') => {
+                    self.chars.next();
+                    regex = Regex::ZeroOrOne(Box::new(regex));
+                }
+                Some('{') => {
+                    let mut rest = self.chars.clone();
+                    rest.next();
+                    if !rest.peek().is_some_and(|c| c.is_ascii_digit()) {
+                        break;
+                    }
+                    self.chars.next();
+                    regex = self.parse_bounds(regex)?;
+                }
+                _ => break,
+            }
+        }
+
+        Ok(regex)
+    }
+
+    /// Parse `n}`, `n,}`, or `n,m}` after `{`, and expand the repetition
+    fn parse_bounds(&mut self, regex: Regex) -> Result<Regex, FlexError> {
+        let mut bounds = String::new();
+        loop {
+            match self.chars.next() {
+                Some('}') => break,
+                Some(char) => bounds.push(char),
+                None => return self.error("Unterminated repetition"),
+            }
+        }
+
+        let parse = |bound: &str| bound.trim().parse::<usize>().ok();
+
+        let (min, max) = match bounds.split_once(',') {
+            None => match parse(&bounds) {
+                Some(n) => (n, Some(n)),
+                None => return self.error(format!("Invalid repetition {{{}}}", bounds)),
+            },
+            Some((min, max)) => match (parse(min), max.trim()) {
+                (Some(min), "") => (min, None),
+                (Some(min), max) => match parse(max) {
+                    Some(max) if max >= min => (min, Some(max)),
+                    _ => return self.error(format!("Invalid repetition {{{}}}", bounds)),
+                },
+                (None, _) => return self.error(format!("Invalid repetition {{{}}}", bounds)),
+            },
+        };
+
+        let mut regexes: Vec<Regex> = vec![regex.clone(); min];
+        match max {
+            None => regexes.push(Regex::ZeroOrMore(Box::new(regex))),
+            Some(max) => {
+                for _ in min..max {
+                    regexes.push(Regex::ZeroOrOne(Box::new(regex.clone())));
+                }
+            }
+        }
+
+        match regexes.len() {
+            0 => self.error("Repetition matches only the empty string"),
+            1 => Ok(regexes.pop().unwrap()),
+            _ => Ok(Regex::Concat(regexes)),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Result<Regex, FlexError> {
+        match self.chars.next() {
+            None => self.error("Unexpected end of pattern"),
+            Some('(') => {
+                let regex = self.parse_alt()?;
+                match self.chars.next() {
+                    Some(')') => Ok(regex),
+                    _ => self.error("Unterminated group"),
+                }
+            }
+            Some('[') => self.parse_class(),
+            Some('"') => {
+                let mut string = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some('"') => break,
+                        Some('\\') => string.push(self.parse_escape()?),
+                        Some(char) => string.push(char),
+                        None => return self.error("Unterminated string"),
+                    }
+                }
+                match string.chars().count() {
+                    0 => self.error("Empty string"),
+                    1 => Ok(Regex::Char(string.chars().next().unwrap())),
+                    _ => Ok(Regex::String(string)),
+                }
+            }
+            Some('.') => Ok(Regex::AnyButNewline),
+            Some('{') => {
+                let mut name = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some('}') => break,
+                        Some(char) => name.push(char),
+                        None => return self.error("Unterminated definition reference"),
+                    }
+                }
+                if !is_name(&name) {
+                    return self.error(format!("Invalid definition name {:?}", name));
+                }
+                Ok(Regex::Var(binding_name(&name)))
+            }
+            Some('\\') => Ok(Regex::Char(self.parse_escape()?)),
+            Some(char @ ('*' | '+' | '?' | '^' | ')')) => {
+                self.error(format!("Unexpected {:?} in pattern", char))
+            }
+            Some(char) => Ok(Regex::Char(char)),
+        }
+    }
+
+    /// Parse an escape sequence after `\`
+    fn parse_escape(&mut self) -> Result<char, FlexError> {
+        let char = match self.chars.next() {
+            Some(char) => char,
+            None => return self.error("Unterminated escape sequence"),
+        };
+
+        Ok(match char {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'f' => '\u{c}',
+            'v' => '\u{b}',
+            'a' => '\u{7}',
+            'b' => '\u{8}',
+            'x' => {
+                let mut digits = String::new();
+                while digits.len() < 2 && self.chars.peek().is_some_and(|c| c.is_ascii_hexdigit()) {
+                    digits.push(self.chars.next().unwrap());
+                }
+                match u32::from_str_radix(&digits, 16) {
+                    Ok(code_point) => char::from_u32(code_point).unwrap(),
+                    Err(_) => return self.error("Invalid hexadecimal escape"),
+                }
+            }
+            '0'..='7' => {
+                let mut digits = char.to_string();
+                while digits.len() < 3 && self.chars.peek().is_some_and(|c| ('0'..='7').contains(c))
+                {
+                    digits.push(self.chars.next().unwrap());
+                }
+                char::from_u32(u32::from_str_radix(&digits, 8).unwrap()).unwrap()
+            }
+            _ => char,
+        })
+    }
+
+    /// Parse a character class after `[`
+    fn parse_class(&mut self) -> Result<Regex, FlexError> {
+        let negated = self.chars.peek() == Some(&'^');
+        if negated {
+            self.chars.next();
+        }
+
+        let mut ranges: Vec<(char, char)> = vec![];
+        let mut first = true;
+
+        loop {
+            let start = match self.chars.next() {
+                None => return self.error("Unterminated character class"),
+                Some(']') if !first => break,
+                Some('[') if self.chars.peek() == Some(&':') => {
+                    self.chars.next();
+                    let mut name = String::new();
+                    loop {
+                        match self.chars.next() {
+                            Some(':') if self.chars.peek() == Some(&']') => {
+                                self.chars.next();
+                                break;
+                            }
+                            Some(char) => name.push(char),
+                            None => return self.error("Unterminated character class"),
+                        }
+                    }
+                    match posix_class(&name) {
+                        Some(class_ranges) => ranges.extend_from_slice(class_ranges),
+                        None => return self.error(format!("Unknown character class {:?}", name)),
+                    }
+                    first = false;
+                    continue;
+                }
+                Some('\\') => self.parse_escape()?,
+                Some(char) => char,
+            };
+            first = false;
+
+            // `-` at the end of the class is a character
+            let mut rest = self.chars.clone();
+            if rest.next() == Some('-') && rest.peek().is_some_and(|c| *c != ']') {
+                self.chars.next();
+                let end = match self.chars.next() {
+                    Some('\\') => self.parse_escape()?,
+                    Some(char) => char,
+                    None => return self.error("Unterminated character class"),
+                };
+                if end < start {
+                    return self.error(format!("Invalid range {:?}-{:?}", start, end));
+                }
+                ranges.push((start, end));
+            } else {
+                ranges.push((start, start));
+            }
+        }
+
+        Ok(Regex::Class { negated, ranges })
+    }
+}
+
+fn posix_class(name: &str) -> Option<&'static [(char, char)]> {
+    Some(match name {
+        "alnum" => &[('0', '9'), ('A', 'Z'), ('a', 'z')],
+        "alpha" => &[('A', 'Z'), ('a', 'z')],
+        "blank" => &[(' ', ' '), ('\t', '\t')],
+        "cntrl" => &[('\0', '\u{1f}'), ('\u{7f}', '\u{7f}')],
+        "digit" => &[('0', '9')],
+        "graph" => &[('!', '~')],
+        "lower" => &[('a', 'z')],
+        "print" => &[(' ', '~')],
+        "punct" => &[('!', '/'), (':', '@'), ('[', '`'), ('{', '~')],
+        "space" => &[(' ', ' '), ('\t', '\r')],
+        "upper" => &[('A', 'Z')],
+        "xdigit" => &[('0', '9'), ('A', 'F'), ('a', 'f')],
+        _ => return None,
+    })
+}
+
+// Binding powers, for parenthesizing
+const PREC_ALT: u8 = 0;
+const PREC_CONCAT: u8 = 1;
+const PREC_POSTFIX: u8 = 2;
+
+fn print_regex(regex: &Regex, prec: u8, out: &mut String) {
+    match regex {
+        Regex::Char(char) => write!(out, "{:?}", char).unwrap(),
+        Regex::String(string) => write!(out, "{:?}", string).unwrap(),
+        Regex::Class { negated, ranges } => {
+            if *negated {
+                out.push_str("(_ # ");
+            }
+            out.push('[');
+            for (range_idx, (start, end)) in ranges.iter().enumerate() {
+                if range_idx != 0 {
+                    out.push(' ');
+                }
+                if start == end {
+                    write!(out, "{:?}", start).unwrap();
+                } else {
+                    write!(out, "{:?}-{:?}", start, end).unwrap();
+                }
+            }
+            out.push(']');
+            if *negated {
+                out.push(')');
+            }
+        }
+        Regex::AnyButNewline => out.push_str("(_ # '\\n')"),
+        Regex::Var(name) => write!(out, "${}", name).unwrap(),
+        Regex::EndOfInput => out.push('$'),
+        Regex::Concat(regexes) => {
+            if prec > PREC_CONCAT {
+                out.push('(');
+            }
+            for (regex_idx, regex) in regexes.iter().enumerate() {
+                if regex_idx != 0 {
+                    out.push(' ');
+                }
+                print_regex(regex, PREC_CONCAT + 1, out);
+            }
+            if prec > PREC_CONCAT {
+                out.push(')');
+            }
+        }
+        Regex::Alt(regexes) => {
+            if prec > PREC_ALT {
+                out.push('(');
+            }
+            for (regex_idx, regex) in regexes.iter().enumerate() {
+                if regex_idx != 0 {
+                    out.push_str(" | ");
+                }
+                print_regex(regex, PREC_ALT + 1, out);
+            }
+            if prec > PREC_ALT {
+                out.push(')');
+            }
+        }
+        Regex::ZeroOrMore(inner) | Regex::OneOrMore(inner) | Regex::ZeroOrOne(inner) => {
+            let nested = matches!(
+                **inner,
+                Regex::ZeroOrMore(_) | Regex::OneOrMore(_) | Regex::ZeroOrOne(_)
+            );
+            if nested {
+                out.push('(');
+            }
+            print_regex(inner, PREC_POSTFIX, out);
+            if nested {
+                out.push(')');
+            }
+            out.push(match regex {
+                Regex::ZeroOrMore(_) => '*',
+                Regex::OneOrMore(_) => '+',
+                _ => '?',
+            });
+        }
+    }
+}
+
+fn regex_string(regex: &Regex) -> String {
+    let mut string = String::new();
+    print_regex(regex, PREC_ALT, &mut string);
+    string
+}
+
+fn print_lexer(
+    definitions: &[(String, Regex)],
+    start_conditions: &[(String, bool)],
+    rules: &[FlexRule],
+) -> String {
+    let mut out = String::new();
+
+    out.push_str("lexer! {\n    Lexer -> Token;\n");
+
+    if !definitions.is_empty() {
+        out.push('\n');
+        for (name, regex) in definitions {
+            writeln!(out, "    let {} = {};", name, regex_string(regex)).unwrap();
+        }
+    }
+
+    // Rules are at the top level when there are no start conditions
+    let top_level = start_conditions.len() == 1;
+    let indent = if top_level { "    " } else { "        " };
+
+    for (condition, exclusive) in start_conditions {
+        let condition_rules = rules.iter().filter(|rule| {
+            if rule.conditions.is_empty() {
+                !exclusive
+            } else {
+                rule.conditions
+                    .iter()
+                    .any(|rule_condition| rule_condition == "*" || rule_condition == condition)
+            }
+        });
+
+        out.push('\n');
+        if !top_level {
+            writeln!(out, "    rule {} {{", rule_set_name(condition)).unwrap();
+        }
+
+        for rule in condition_rules {
+            let mut lhs = regex_string(&rule.regex);
+            if let Some(right_ctx) = &rule.right_ctx {
+                write!(lhs, " > {}", regex_string(right_ctx)).unwrap();
+            }
+
+            match &rule.action {
+                Action::Skip => writeln!(out, "{}{},", indent, lhs).unwrap(),
+                Action::Begin(condition) => writeln!(
+                    out,
+                    "{}{} => |lexer| lexer.switch(LexerRule::{}),",
+                    indent,
+                    lhs,
+                    rule_set_name(condition)
+                )
+                .unwrap(),
+                Action::Code(code) => {
+                    for line in code.lines() {
+                        writeln!(out, "{}// {}", indent, line.trim()).unwrap();
+                    }
+                    writeln!(out, "{}{} => |_| todo!(),", indent, lhs).unwrap();
+                }
+                Action::Next => unreachable!(),
+            }
+        }
+
+        if !top_level {
+            out.push_str("    }\n");
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
@@ -0,0 +1,262 @@
+//! Machine-readable description of a lexer, with `grammar_json = true;`: the rule sets, with the
+//! patterns and tokens of the rules and the rule sets the semantic actions switch to, for editors,
+//! documentation generators, and other tools that need to know the tokens of a language. Written
+//! as JSON to `OUT_DIR`.
+
+use crate::ast::{GeneratedType, Lexer, LexerOption, Rule, RuleKind, RuleRhs, SingleRule};
+use crate::display::{tokens_to_string, RegexDisplay};
+use crate::semantic_action_table::SemanticActionTable;
+
+use std::fmt::Write;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+
+/// Generate the JSON description of the lexer. Format:
+///
+/// ```json
+/// {
+///   "name": "Lexer",
+///   "token_type": "Token",
+///   "bindings": [{ "name": "id", "pattern": "['a'-'z']+" }],
+///   "rule_sets": [
+///     {
+///       "name": "Init",
+///       "rules": [
+///         {
+///           "pattern": "$id",
+///           "right_context": null,
+///           "guard": null,
+///           "kind": "simple",
+///           "token": "Token::Id",
+///           "switches_to": []
+///         }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// `kind` is `skip` for rules without a right-hand side (`token` is `null`), `simple` for `=`
+/// rules (`token` is the right-hand side), and `fallible` and `infallible` for `=?` and `=>` rules
+/// (`token` is the semantic action). `switches_to` lists the rule sets the semantic action
+/// switches to. `guard` is the guard function of `<regex> if <guard>` rules. `pattern` is
+/// `fn <matcher>` for custom matchers and `fail` for `fail` rules.
+pub fn grammar_json(lexer: &Lexer, semantic_action_table: &SemanticActionTable) -> String {
+    let rule_enum_name = lexer
+        .rules
+        .iter()
+        .find_map(|rule| match rule {
+            Rule::Option {
+                option: LexerOption::TypeName(GeneratedType::RuleEnum, name),
+                ..
+            } => Some(name.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| {
+            format!(
+                "{}{}",
+                lexer.type_name,
+                GeneratedType::RuleEnum.default_suffix()
+            )
+        });
+
+    let mut bindings: Vec<String> = vec![];
+    let mut rule_sets: Vec<String> = vec![];
+
+    // Rules of the `skip` option, listed at the end of rule sets without the `exclusive` modifier
+    let skip_rules: Vec<SingleRule> = lexer
+        .rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::Option {
+                name,
+                option: LexerOption::Skip { regexes, action },
+            } => Some(regexes.iter().map(|re| SingleRule {
+                lhs: re.clone(),
+                rhs: *action,
+                span: name.span(),
+                matcher: None,
+                guard: None,
+                fail: false,
+                priority: None,
+                shortest: false,
+                group: None,
+            })),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    // Rules added to multiple rule sets with `<...> <rule>`, listed after the rules of the rule sets
+    let shared_rules: Vec<(&[syn::Ident], &SingleRule)> = lexer
+        .rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::SharedRule { rule_sets, rule } => Some((rule_sets.as_slice(), rule)),
+            _ => None,
+        })
+        .collect();
+
+    for rule in &lexer.rules {
+        let (name, rules, skip): (String, &[SingleRule], bool) = match rule {
+            Rule::Binding { var, re } => {
+                bindings.push(format!(
+                    "    {{ \"name\": {}, \"pattern\": {} }}",
+                    json_string(&var.to_string()),
+                    json_string(&RegexDisplay(&re.re).to_string()),
+                ));
+                continue;
+            }
+            Rule::RuleSet {
+                name,
+                rules,
+                exclusive,
+                ..
+            } => (name.to_string(), rules, !exclusive),
+            Rule::UnnamedRules { rules, .. } => ("Init".to_owned(), rules, true),
+            Rule::ErrorType { .. }
+            | Rule::FeedbackType { .. }
+            | Rule::InternerType { .. }
+            | Rule::Option { .. }
+            | Rule::SharedRule { .. }
+            | Rule::Tests { .. } => continue,
+        };
+
+        let mut rule_set = format!(
+            "    {{\n      \"name\": {},\n      \"rules\": [",
+            json_string(&name)
+        );
+
+        for (
+            rule_idx,
+            SingleRule {
+                lhs,
+                rhs,
+                matcher,
+                guard,
+                fail,
+                ..
+            },
+        ) in rules
+            .iter()
+            .chain(
+                shared_rules
+                    .iter()
+                    .filter(|(rule_sets, _)| rule_sets.iter().any(|rule_set| *rule_set == name))
+                    .map(|(_, rule)| *rule),
+            )
+            .chain(skip_rules.iter().filter(|_| skip))
+            .enumerate()
+        {
+            let (kind, token, switches_to) = match semantic_action_table.get(*rhs) {
+                RuleRhs::None => ("skip", None, vec![]),
+                RuleRhs::Rhs { expr, kind } => {
+                    let kind = match kind {
+                        RuleKind::Simple => "simple",
+                        RuleKind::Fallible => "fallible",
+                        RuleKind::Infallible => "infallible",
+                    };
+                    let mut switches_to: Vec<String> = vec![];
+                    find_switches(expr.to_token_stream(), &rule_enum_name, &mut switches_to);
+                    (kind, Some(tokens_to_string(expr)), switches_to)
+                }
+            };
+
+            if rule_idx != 0 {
+                rule_set.push(',');
+            }
+
+            write!(
+                rule_set,
+                "\n        {{\n          \"pattern\": {},\n          \"right_context\": {},\n          \"guard\": {},\n          \
+                \"kind\": \"{}\",\n          \"token\": {},\n          \"switches_to\": [{}]\n        }}",
+                json_string(&match matcher {
+                    Some(matcher) => format!("fn {}", tokens_to_string(matcher)),
+                    None if *fail => "fail".to_owned(),
+                    None => RegexDisplay(&lhs.re).to_string(),
+                }),
+                match &lhs.right_ctx {
+                    Some(right_ctx) => json_string(&RegexDisplay(right_ctx).to_string()),
+                    None => "null".to_owned(),
+                },
+                match guard {
+                    Some(guard) => json_string(&tokens_to_string(guard)),
+                    None => "null".to_owned(),
+                },
+                kind,
+                match token {
+                    Some(token) => json_string(&token),
+                    None => "null".to_owned(),
+                },
+                switches_to
+                    .iter()
+                    .map(|name| json_string(name))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+            .unwrap();
+        }
+
+        if !rules.is_empty() {
+            rule_set.push_str("\n      ");
+        }
+        rule_set.push_str("]\n    }");
+        rule_sets.push(rule_set);
+    }
+
+    format!(
+        "{{\n  \"name\": {},\n  \"token_type\": {},\n  \"bindings\": [{}],\n  \"rule_sets\": [\n{}\n  ]\n}}\n",
+        json_string(&lexer.type_name.to_string()),
+        json_string(&tokens_to_string(&lexer.token_type)),
+        if bindings.is_empty() {
+            String::new()
+        } else {
+            format!("\n{}\n  ", bindings.join(",\n"))
+        },
+        rule_sets.join(",\n"),
+    )
+}
+
+/// Find rule sets switched to in a semantic action: `<Lexer>Rule::<name>` paths
+fn find_switches(tokens: TokenStream, rule_enum_name: &str, switches_to: &mut Vec<String>) {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+
+    for (token_idx, token) in tokens.iter().enumerate() {
+        match token {
+            TokenTree::Group(group) => find_switches(group.stream(), rule_enum_name, switches_to),
+            TokenTree::Ident(ident) if ident == rule_enum_name => {
+                if let [TokenTree::Punct(colon1), TokenTree::Punct(colon2), TokenTree::Ident(rule_set), ..] =
+                    &tokens[token_idx + 1..]
+                {
+                    let rule_set = rule_set.to_string();
+                    if colon1.as_char() == ':'
+                        && colon2.as_char() == ':'
+                        && !switches_to.contains(&rule_set)
+                    {
+                        switches_to.push(rule_set);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn json_string(str: &str) -> String {
+    let mut json = String::with_capacity(str.len() + 2);
+    json.push('"');
+    for char in str.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
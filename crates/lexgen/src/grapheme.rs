@@ -0,0 +1,123 @@
+//! Extended grapheme clusters for `match_unit = grapheme;`, where `_` matches a user-perceived
+//! character (e.g. `é` written as `e` and a combining accent, or an emoji with a skin tone
+//! modifier) instead of a single Unicode scalar value.
+//!
+//! `_` is replaced with a regex for extended grapheme clusters, as defined in [UAX #29], without
+//! the rule for Indic conjuncts (GB9c). `_` in character sets (`#`) still matches a single
+//! character.
+//!
+//! [UAX #29]: https://www.unicode.org/reports/tr29/#Regex_Definitions
+
+use crate::ast::{CharOrRange, CharSet, Regex, Var};
+use crate::collections::Map;
+use crate::unicode_properties::{extended_pictographic_ranges, grapheme_cluster_break_ranges};
+
+/// Replace `_` outside of character sets with a regex for an extended grapheme cluster
+pub fn any_to_grapheme_clusters(re: &Regex, bindings: &Map<Var, Regex>) -> Regex {
+    re.map_outside_char_sets(bindings, &|re| match re {
+        Regex::Any => Some(grapheme_cluster()),
+        _ => None,
+    })
+}
+
+/// Regex for an extended grapheme cluster, with classes of the Grapheme_Cluster_Break property:
+///
+/// ```text
+/// CR LF | [Control CR LF] | Prepend* core [Extend ZWJ SpacingMark]*
+///
+/// core := hangul_syllable
+///       | Regional_Indicator Regional_Indicator
+///       | Extended_Pictographic (Extend* ZWJ Extended_Pictographic)*
+///       | [^Control CR LF]
+///
+/// hangul_syllable := L* (V+ | LV V* | LVT) T* | L+ | T+
+/// ```
+fn grapheme_cluster() -> Regex {
+    let class = |names: &[&str]| {
+        char_set(
+            names
+                .iter()
+                .flat_map(|name| grapheme_cluster_break_ranges(name)),
+        )
+    };
+
+    let cr = || class(&["CR"]);
+    let lf = || class(&["LF"]);
+    let control = || class(&["Control", "CR", "LF"]);
+    let extend = || class(&["Extend"]);
+    let zwj = || class(&["ZWJ"]);
+    let regional_indicator = || class(&["Regional_Indicator"]);
+    let l = || class(&["L"]);
+    let v = || class(&["V"]);
+    let t = || class(&["T"]);
+    let extended_pictographic = || char_set(extended_pictographic_ranges().iter());
+
+    let hangul_syllable = or(vec![
+        concat(vec![
+            star(l()),
+            or(vec![
+                plus(v()),
+                concat(vec![class(&["LV"]), star(v())]),
+                class(&["LVT"]),
+            ]),
+            star(t()),
+        ]),
+        plus(l()),
+        plus(t()),
+    ]);
+
+    let xpicto_sequence = concat(vec![
+        extended_pictographic(),
+        star(concat(vec![star(extend()), zwj(), extended_pictographic()])),
+    ]);
+
+    let core = or(vec![
+        hangul_syllable,
+        concat(vec![regional_indicator(), regional_indicator()]),
+        xpicto_sequence,
+        Regex::Diff(Box::new(Regex::Any), Box::new(control())),
+    ]);
+
+    or(vec![
+        concat(vec![cr(), lf()]),
+        control(),
+        concat(vec![
+            star(class(&["Prepend"])),
+            core,
+            star(class(&["Extend", "ZWJ", "SpacingMark"])),
+        ]),
+    ])
+}
+
+fn char_set<'a>(ranges: impl Iterator<Item = &'a (u32, u32)>) -> Regex {
+    Regex::CharSet(CharSet(
+        ranges
+            .map(|(start, end)| {
+                CharOrRange::Range(
+                    char::from_u32(*start).unwrap(),
+                    char::from_u32(*end).unwrap(),
+                )
+            })
+            .collect(),
+    ))
+}
+
+fn concat(res: Vec<Regex>) -> Regex {
+    res.into_iter()
+        .reduce(|re1, re2| Regex::Concat(Box::new(re1), Box::new(re2)))
+        .unwrap()
+}
+
+fn or(res: Vec<Regex>) -> Regex {
+    res.into_iter()
+        .reduce(|re1, re2| Regex::Or(Box::new(re1), Box::new(re2)))
+        .unwrap()
+}
+
+fn star(re: Regex) -> Regex {
+    Regex::ZeroOrMore(Box::new(re))
+}
+
+fn plus(re: Regex) -> Regex {
+    Regex::OneOrMore(Box::new(re))
+}
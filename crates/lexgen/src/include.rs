@@ -0,0 +1,118 @@
+//! `include_rules!("<path>");` items in lexer definitions, for splitting large lexers across
+//! files. Includes are expanded before parsing, by replacing the items with the contents of the
+//! files, so included files can have anything that can be written in place of the item.
+
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+
+use std::path::{Path, PathBuf};
+
+/// Expands `include_rules!` items at the top level of a lexer definition and in rule sets.
+/// Returns the expanded definition and the paths of the included files.
+pub fn expand_includes(input: TokenStream) -> syn::Result<(TokenStream, Vec<PathBuf>)> {
+    let mut files = vec![];
+    let expanded = expand(input, true, &mut files, &mut vec![])?;
+    Ok((expanded, files))
+}
+
+/// Paths in `include_rules!` are relative to the crate root, as the paths in other options
+pub fn include_path(path: &str) -> PathBuf {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    Path::new(&manifest_dir).join(path)
+}
+
+// `top_level` is set for the top level of the definition, where rule sets (brace groups) are
+// expanded as well. `stack` is the files being included, for detecting cycles.
+fn expand(
+    input: TokenStream,
+    top_level: bool,
+    files: &mut Vec<PathBuf>,
+    stack: &mut Vec<PathBuf>,
+) -> syn::Result<TokenStream> {
+    let tokens: Vec<TokenTree> = input.into_iter().collect();
+    let mut output: Vec<TokenTree> = Vec::with_capacity(tokens.len());
+
+    let mut token_idx = 0;
+    while token_idx < tokens.len() {
+        if let Some(path_lit) = parse_include(&tokens[token_idx..])? {
+            let path = include_path(&path_lit.value());
+
+            if stack.contains(&path) {
+                return Err(syn::Error::new(
+                    path_lit.span(),
+                    format!("{} is included recursively", path.display()),
+                ));
+            }
+
+            let contents = std::fs::read_to_string(&path).map_err(|err| {
+                syn::Error::new(
+                    path_lit.span(),
+                    format!("Unable to read {}: {}", path.display(), err),
+                )
+            })?;
+
+            let included: TokenStream = syn::parse_str(&contents).map_err(|err| {
+                syn::Error::new(
+                    path_lit.span(),
+                    format!("Unable to parse {}: {}", path.display(), err),
+                )
+            })?;
+
+            if !files.contains(&path) {
+                files.push(path.clone());
+            }
+
+            stack.push(path);
+            output.extend(expand(included, top_level, files, stack)?);
+            stack.pop();
+
+            // `include_rules`, `!`, `(...)`, `;`
+            token_idx += 4;
+            continue;
+        }
+
+        match &tokens[token_idx] {
+            TokenTree::Group(group) if top_level && group.delimiter() == Delimiter::Brace => {
+                let mut expanded = Group::new(
+                    Delimiter::Brace,
+                    expand(group.stream(), false, files, stack)?,
+                );
+                expanded.set_span(group.span());
+                output.push(TokenTree::Group(expanded));
+            }
+            token => output.push(token.clone()),
+        }
+
+        token_idx += 1;
+    }
+
+    Ok(output.into_iter().collect())
+}
+
+/// Parses the path of an `include_rules!("<path>");` item at the start of `tokens`. Returns `None`
+/// when the tokens don't start with `include_rules!`.
+fn parse_include(tokens: &[TokenTree]) -> syn::Result<Option<syn::LitStr>> {
+    match tokens {
+        [TokenTree::Ident(ident), TokenTree::Punct(bang), rest @ ..]
+            if ident == "include_rules" && bang.as_char() == '!' =>
+        {
+            let syntax_error = || {
+                syn::Error::new(
+                    ident.span(),
+                    "`include_rules` syntax is: `include_rules!(\"<path>\");`",
+                )
+            };
+
+            match rest {
+                [TokenTree::Group(args), TokenTree::Punct(semi), ..]
+                    if args.delimiter() == Delimiter::Parenthesis && semi.as_char() == ';' =>
+                {
+                    syn::parse2::<syn::LitStr>(args.stream())
+                        .map(Some)
+                        .map_err(|_| syntax_error())
+                }
+                _ => Err(syntax_error()),
+            }
+        }
+        _ => Ok(None),
+    }
+}
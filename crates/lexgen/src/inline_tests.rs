@@ -0,0 +1,108 @@
+//! Tests defined in `tests { ... }` sections of lexer definitions, generated as `#[test]` functions
+//! in a `#[cfg(test)]` module next to the lexer.
+
+use crate::ast::{Lexer, Rule, TestCase, TestExpectation};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+
+/// Generates the `<lexer name>_tests` module with a test for each case in the `tests` sections of
+/// the lexer. Generated before compiling the lexer as it needs the rules.
+pub fn reify_tests(lexer: &Lexer) -> syn::Result<TokenStream> {
+    let cases: Vec<&TestCase> = lexer
+        .rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::Tests { cases } => Some(cases),
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    if cases.is_empty() {
+        return Ok(quote!());
+    }
+
+    // Tests create the lexer with `new`, type parameters can't be inferred from the input
+    if let Some(param) = lexer
+        .generics
+        .params
+        .iter()
+        .find(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+    {
+        return Err(syn::Error::new_spanned(
+            param,
+            "`tests` sections are not supported in lexers with type or const parameters",
+        ));
+    }
+
+    let lexer_name = &lexer.type_name;
+    let mod_name = format_ident!("{}_tests", lexer_name);
+
+    let tests = cases.iter().enumerate().map(|(i, case)| {
+        let test_name = format_ident!("case_{}", i);
+        let input = &case.input;
+        // Panics point to the test case
+        let span = input.span();
+        let body = match &case.expected {
+            TestExpectation::Tokens(expected) => {
+                // Types of the vectors are inferred, the token type can refer to the lexer's
+                // lifetimes. An empty `vec![]` can't be compared without a type.
+                let check = if expected.is_empty() {
+                    quote_spanned!(span=>
+                        assert!(tokens.is_empty(), "Tokens of {:?}: {:?}", #input, tokens);
+                    )
+                } else {
+                    quote_spanned!(span=>
+                        assert_eq!(tokens, vec![#(#expected),*], "Tokens of {:?}", #input);
+                    )
+                };
+                quote_spanned!(span=>
+                    let mut tokens = vec![];
+                    while let Some(token) = ::lexgen_util::TokenSource::next_spanned(&mut lexer) {
+                        match token {
+                            Ok((_, token, _)) => tokens.push(token),
+                            Err(err) => panic!("Lexer error in {:?}: {:?}", #input, err),
+                        }
+                    }
+                    #check
+                )
+            }
+            TestExpectation::Error(byte_idx) => {
+                // Checked when parsing. Unsuffixed so it's compared as a `usize`.
+                let byte_idx =
+                    proc_macro2::Literal::usize_unsuffixed(byte_idx.base10_parse().unwrap());
+                quote_spanned!(span=> loop {
+                    match ::lexgen_util::TokenSource::next_spanned(&mut lexer) {
+                        Some(Ok(_)) => {}
+                        Some(Err(err)) => {
+                            assert_eq!(
+                                err.location.byte_idx, #byte_idx,
+                                "Location of the error in {:?}: {:?}", #input, err
+                            );
+                            break;
+                        }
+                        None => panic!("Expected a lexer error in {:?}", #input),
+                    }
+                })
+            }
+        };
+        quote_spanned!(span=>
+            #[test]
+            fn #test_name() {
+                let mut lexer = #lexer_name::new(#input);
+                #body
+            }
+        )
+    });
+
+    Ok(quote!(
+        #[cfg(test)]
+        #[allow(non_snake_case)]
+        mod #mod_name {
+            use super::*;
+
+            #(#tests)*
+        }
+    ))
+}
@@ -0,0 +1,80 @@
+//! Glue for LALRPOP parsers, with `lalrpop = true;`. The generated code has type aliases for the
+//! parser (see `dfa::codegen`), and the `extern` block for the grammar generated here is written to
+//! `OUT_DIR`, to be copied to the grammar.
+
+use crate::ast::{Lexer, Regex, Rule, RuleKind, RuleRhs, SingleRule};
+use crate::display::tokens_to_string;
+use crate::semantic_action_table::SemanticActionTable;
+
+/// Generate the `extern` block of a LALRPOP grammar that uses the lexer: the location and error
+/// types, and the terminals for tokens returned by simple rules (`<regex> = <path>`).
+///
+/// Terminals are named after the matched string for rules that match a single string or character
+/// (`"+" => Token::Plus`), after the last segment of the token path otherwise (`Ident =>
+/// Token::Ident`). Tokens with fields and tokens returned by semantic actions need to be added to
+/// the grammar manually.
+pub fn extern_block(lexer: &Lexer, semantic_action_table: &SemanticActionTable) -> String {
+    let mut error_type = "std::convert::Infallible".to_owned();
+    let mut terminals: Vec<(String, String)> = vec![];
+
+    for rule in &lexer.rules {
+        let rules: &[SingleRule] = match rule {
+            Rule::ErrorType { ty } => {
+                error_type = tokens_to_string(ty);
+                continue;
+            }
+            Rule::RuleSet { rules, .. } | Rule::UnnamedRules { rules, .. } => rules,
+            Rule::SharedRule { rule, .. } => std::slice::from_ref(rule),
+            Rule::Binding { .. }
+            | Rule::FeedbackType { .. }
+            | Rule::InternerType { .. }
+            | Rule::Option { .. }
+            | Rule::Tests { .. } => continue,
+        };
+
+        for SingleRule {
+            lhs, rhs, matcher, ..
+        } in rules
+        {
+            let path = match semantic_action_table.get(*rhs) {
+                RuleRhs::Rhs {
+                    expr: syn::Expr::Path(path),
+                    kind: RuleKind::Simple,
+                } => path,
+                _ => continue,
+            };
+
+            let terminal = match (&lhs.re, &lhs.right_ctx) {
+                _ if matcher.is_some() => path.path.segments.last().unwrap().ident.to_string(),
+                (Regex::String(str), None) => format!("{:?}", str),
+                (Regex::Char(char), None) => format!("{:?}", char.to_string()),
+                _ => path.path.segments.last().unwrap().ident.to_string(),
+            };
+            let token = tokens_to_string(path);
+
+            if !terminals
+                .iter()
+                .any(|(terminal_, token_)| *terminal_ == terminal || *token_ == token)
+            {
+                terminals.push((terminal, token));
+            }
+        }
+    }
+
+    let mut block = format!(
+        "// LALRPOP `extern` block for `{}`, generated by lexgen. Tokens with fields and tokens\n\
+        // returned by semantic actions need to be added manually.\n\
+        extern {{\n    \
+            type Location = lexgen_util::Loc;\n    \
+            type Error = lexgen_util::LexerError<{}>;\n\n    \
+            enum {} {{\n",
+        lexer.type_name,
+        error_type,
+        tokens_to_string(&lexer.token_type),
+    );
+    for (terminal, token) in terminals {
+        block.push_str(&format!("        {} => {},\n", terminal, token));
+    }
+    block.push_str("    }\n}\n");
+    block
+}
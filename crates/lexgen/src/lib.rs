@@ -11,160 +11,489 @@
 
 mod ast;
 mod builtin;
+mod case_fold;
 mod char_ranges;
 mod collections;
+mod compile;
+mod derive;
 mod dfa;
+mod diagnostics;
 mod display;
+mod dot;
+mod expand;
+mod grammar;
+#[cfg(feature = "unicode")]
+mod grapheme;
+mod include;
+mod inline_tests;
+mod lalrpop;
 mod nfa;
 mod nfa_to_dfa;
+#[cfg(feature = "unicode")]
+mod normalization;
+mod options;
 mod range_map;
+mod regex_simplify;
 mod regex_to_nfa;
 mod right_ctx;
 mod semantic_action_table;
+mod token_kind;
+mod unicode_properties;
+#[cfg(feature = "unicode")]
+mod unicode_tables;
 
 #[cfg(test)]
 mod tests;
 
-use ast::{Lexer, Regex, RegexCtx, Rule, SingleRule, Var};
-use collections::Map;
-use dfa::{StateIdx as DfaStateIdx, DFA};
-use nfa::NFA;
-use nfa_to_dfa::nfa_to_dfa;
-use right_ctx::RightCtxDFAs;
-use semantic_action_table::{SemanticActionIdx, SemanticActionTable};
+use compile::{accepting_state_label, rule_name, CompiledLexer};
+use dfa::StateIdx as DfaStateIdx;
+use semantic_action_table::SemanticActionTable;
 
-use std::collections::hash_map::Entry;
+use std::path::Path;
 
 use proc_macro::TokenStream;
+use quote::quote;
 use syn::parse::Parser;
+use syn::spanned::Spanned;
 
 #[proc_macro]
 pub fn lexer(input: TokenStream) -> TokenStream {
-    let mut semantic_action_table = SemanticActionTable::new();
+    match lexer_(input.into()) {
+        Ok(lexer) => lexer.into(),
+        Err(error) => TokenStream::from(error.to_compile_error()),
+    }
+}
+
+/// Generates a lexer from regexes in `#[lexer(...)]` attributes of the variants of a token enum.
+/// See the README for details.
+#[proc_macro_derive(Lexer, attributes(lexer))]
+pub fn derive_lexer(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    match derive::lexer_definition(&input).and_then(lexer_) {
+        Ok(lexer) => lexer.into(),
+        Err(error) => TokenStream::from(error.to_compile_error()),
+    }
+}
+
+fn lexer_(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let (input, included_files) = include::expand_includes(input)?;
+
+    let lexers = ast::parse_lexers.parse2(input)?;
+
+    let mut generated = proc_macro2::TokenStream::new();
+    for (lexer, semantic_action_table) in lexers {
+        generated.extend(lexer_definition(lexer, semantic_action_table)?);
+    }
 
-    let Lexer {
-        public,
+    // Make sure the lexers are recompiled when the files included with `include_rules!` change
+    for path in included_files {
+        let path = path.to_string_lossy();
+        generated.extend(quote!(
+            const _: &[u8] = include_bytes!(#path);
+        ));
+    }
+
+    Ok(generated)
+}
+
+/// Generates a lexer defined in a `lexer!` invocation
+fn lexer_definition(
+    lexer: ast::Lexer,
+    semantic_action_table: SemanticActionTable,
+) -> syn::Result<proc_macro2::TokenStream> {
+    // Generated before compiling as it needs the rules, written below with `lalrpop = true;`
+    let lalrpop_extern = lalrpop::extern_block(&lexer, &semantic_action_table);
+
+    // Same as above, written below with `grammar_json = true;`
+    let grammar_json = grammar::grammar_json(&lexer, &semantic_action_table);
+
+    // Same as above, generated below with `token_kind = true;`
+    let token_kinds = token_kind::token_kinds(&lexer, &semantic_action_table);
+
+    // Same as above, `#[test]` functions for the `tests { ... }` sections
+    let tests = inline_tests::reify_tests(&lexer)?;
+
+    let CompiledLexer {
+        attrs,
+        visibility,
         type_name,
+        generics,
         user_state_type,
+        user_error_type,
         token_type,
-        rules: top_level_rules,
-    } = match ast::make_lexer_parser(&mut semantic_action_table).parse(input) {
-        Ok(lexer) => lexer,
-        Err(error) => return TokenStream::from(error.to_compile_error()),
-    };
+        options,
+        rule_set_names,
+        nfas,
+        dfa,
+        mut rule_sets,
+        right_ctx_dfas,
+        rules,
+        invalid_utf8_action,
+        custom_matchers,
+        guards,
+        group_names,
+        rule_groups,
+        fail_actions,
+        feedback_type,
+        interner_type,
+        captures,
+    } = compile::compile(lexer)?;
 
-    // Maps DFA names to their initial states in the final DFA
-    let mut dfas: Map<String, dfa::StateIdx> = Default::default();
+    if options.const_fn() {
+        if let Some(user_state_type) = &user_state_type {
+            return Err(syn::Error::new(
+                user_state_type.span(),
+                "User state is not supported with `const_fn = true;`",
+            ));
+        }
+        if let Some(rule_set_name) = rule_set_names.get(1) {
+            return Err(syn::Error::new(
+                rule_set_name.span(),
+                "Rule sets are not supported with `const_fn = true;`",
+            ));
+        }
+        if let Some((matcher, _)) = custom_matchers.values().flatten().next() {
+            return Err(syn::Error::new(
+                matcher.span(),
+                "`fn` rules are not supported with `const_fn = true;`",
+            ));
+        }
+        if let Some(guard) = guards.values().next() {
+            return Err(syn::Error::new(
+                guard.span(),
+                "Rule guards are not supported with `const_fn = true;`",
+            ));
+        }
+        if let Some(group_name) = group_names.first() {
+            return Err(syn::Error::new(
+                group_name.span(),
+                "Rule groups are not supported with `const_fn = true;`",
+            ));
+        }
+        if !fail_actions.is_empty() {
+            return Err(syn::Error::new(
+                type_name.span(),
+                "`fail` rules are not supported with `const_fn = true;`",
+            ));
+        }
+        if let Some(rule) = rules.iter().find(|rule| {
+            !matches!(
+                semantic_action_table.get(rule.action),
+                ast::RuleRhs::None
+                    | ast::RuleRhs::Rhs {
+                        kind: ast::RuleKind::Simple,
+                        ..
+                    }
+            )
+        }) {
+            return Err(syn::Error::new(
+                rule.span,
+                "Only rules without a right-hand side (`<regex>,`) and simple rules \
+                (`<regex> = <token>,`) are supported with `const_fn = true;`",
+            ));
+        }
+        if let Some(rule) = rules.iter().find(|rule| rule.right_ctx) {
+            return Err(syn::Error::new(
+                rule.span,
+                "Right contexts are not supported with `const_fn = true;`",
+            ));
+        }
+        // `next_const` starts a match with a transition from the initial state, so it can't match
+        // the empty string
+        if let Some(accepting) = dfa.accepting_states(dfa.initial_state()).first() {
+            let span = rules
+                .iter()
+                .find(|rule| rule.action == accepting.value)
+                .map_or(type_name.span(), |rule| rule.span);
+            return Err(syn::Error::new(
+                span,
+                "Rules that match the empty string are not supported with `const_fn = true;`",
+            ));
+        }
+    }
 
-    // DFAs generated for right contexts
-    let mut right_ctx_dfas = RightCtxDFAs::new();
+    if options.dfa_table() {
+        if let Some(rule_set_name) = rule_set_names.get(1) {
+            return Err(syn::Error::new(
+                rule_set_name.span(),
+                "Rule sets are not supported with `dfa_table = true;`",
+            ));
+        }
+        if let Some((matcher, _)) = custom_matchers.values().flatten().next() {
+            return Err(syn::Error::new(
+                matcher.span(),
+                "`fn` rules are not supported with `dfa_table = true;`",
+            ));
+        }
+        if let Some(guard) = guards.values().next() {
+            return Err(syn::Error::new(
+                guard.span(),
+                "Rule guards are not supported with `dfa_table = true;`",
+            ));
+        }
+        if let Some(group_name) = group_names.first() {
+            return Err(syn::Error::new(
+                group_name.span(),
+                "Rule groups are not supported with `dfa_table = true;`",
+            ));
+        }
+        if !fail_actions.is_empty() {
+            return Err(syn::Error::new(
+                type_name.span(),
+                "`fail` rules are not supported with `dfa_table = true;`",
+            ));
+        }
+    }
 
-    let mut bindings: Map<Var, Regex> = Default::default();
+    // `next_token` creates a lexer for each token, state kept in the lexer is lost between tokens
+    if options.next_token() {
+        if let Some(group_name) = group_names.first() {
+            return Err(syn::Error::new(
+                group_name.span(),
+                "Rule groups are not supported with `next_token = true;`",
+            ));
+        }
+        if let Some(feedback_type) = &feedback_type {
+            return Err(syn::Error::new(
+                feedback_type.span(),
+                "Feedback types are not supported with `next_token = true;`",
+            ));
+        }
+        if let Some(interner_type) = &interner_type {
+            return Err(syn::Error::new(
+                interner_type.span(),
+                "Interner types are not supported with `next_token = true;`",
+            ));
+        }
+    }
 
-    let mut dfa: Option<DFA<DfaStateIdx, SemanticActionIdx>> = None;
+    // Write automata to DOT and Mermaid files and DFA reports when requested, for debugging. See
+    // `dot` and `dfa::report` modules.
+    let label = accepting_state_label(&rules);
+    let rule_name = rule_name(&rules);
 
-    let mut user_error_type: Option<syn::Type> = None;
+    if let Some(dot_dir) = dot::dot_dir() {
+        for (rule_set_name, nfa) in rule_set_names.iter().zip(nfas.iter()) {
+            let name = format!("{}_{}", type_name, rule_set_name);
+            write_debug_file(
+                &dot_dir,
+                &format!("{}.nfa.dot", name),
+                &nfa.to_dot(&name, &label),
+            )?;
+        }
 
-    let have_named_rules = top_level_rules
-        .iter()
-        .any(|rule| matches!(rule, Rule::RuleSet { .. }));
-
-    for rule in top_level_rules {
-        match rule {
-            Rule::Binding { var, re } => match bindings.entry(var) {
-                Entry::Occupied(entry) => {
-                    panic!("Variable {:?} is defined multiple times", entry.key().0);
-                }
-                Entry::Vacant(entry) => {
-                    // TODO: Check that regex doesn't have right context
-                    entry.insert(re.re);
-                }
-            },
-            Rule::RuleSet { name, rules } => {
-                if name == "Init" {
-                    let dfa = dfa.insert(compile_rules(rules, &bindings, &mut right_ctx_dfas));
-                    let initial_state = dfa.initial_state();
-
-                    if dfas.insert(name.to_string(), initial_state).is_some() {
-                        panic!("Rule set {:?} is defined multiple times", name.to_string());
-                    }
-                } else {
-                    let dfa = dfa
-                        .as_mut()
-                        .expect("First rule set should be named \"Init\"");
+        let name = type_name.to_string();
+        write_debug_file(
+            &dot_dir,
+            &format!("{}.dfa.dot", name),
+            &dfa.to_dot(&name, &label, &rule_name),
+        )?;
+    }
 
-                    let dfa_ = compile_rules(rules, &bindings, &mut right_ctx_dfas);
+    if let Some(mermaid_dir) = dot::mermaid_dir() {
+        write_debug_file(
+            &mermaid_dir,
+            &format!("{}.dfa.mmd", type_name),
+            &dfa.to_mermaid(&label, &rule_name),
+        )?;
+    }
 
-                    let dfa_idx = dfa.add_dfa(dfa_);
+    if let Some(report_dir) = dfa::report::report_dir() {
+        let name = type_name.to_string();
+        write_debug_file(
+            &report_dir,
+            &format!("{}.txt", name),
+            &dfa::report::report(
+                &name,
+                &dfa,
+                options.driver(),
+                &rule_sets,
+                &label,
+                &rule_name,
+            ),
+        )?;
+    }
 
-                    if dfas.insert(name.to_string(), dfa_idx).is_some() {
-                        panic!("Rule set {:?} is defined multiple times", name.to_string());
-                    }
-                }
-            }
-            Rule::UnnamedRules { rules } => {
-                if dfa.is_some() || have_named_rules {
-                    panic!(
-                        "Unnamed rules cannot be mixed with named rules. Make sure to either \
-                        have all your rules in `rule ... {} ... {}` syntax, or remove `rule`s \
-                        entirely and have your rules at the top-level.",
-                        '{', '}',
-                    );
-                }
-
-                let dfa = dfa.insert(compile_rules(rules, &bindings, &mut right_ctx_dfas));
-                let initial_state = dfa.initial_state();
-                dfas.insert("Init".to_owned(), initial_state);
-            }
-            Rule::ErrorType { ty } => match user_error_type {
-                None => {
-                    user_error_type = Some(ty);
-                }
-                Some(_) => panic!("Error type defined multiple times"),
-            },
+    // Write the `extern` block for LALRPOP grammars. `OUT_DIR` is only set in crates with a build
+    // script, which LALRPOP users have to run LALRPOP.
+    if options.lalrpop() {
+        if let Some(out_dir) = std::env::var_os("OUT_DIR") {
+            write_debug_file(
+                Path::new(&out_dir),
+                &format!("{}.extern.lalrpop", type_name),
+                &lalrpop_extern,
+            )?;
         }
     }
 
-    // There should be a rule with name "Init"
-    if dfas.get("Init").is_none() {
-        panic!(
-            "There should be a rule set named \"Init\". Current rules: {:?}",
-            dfas.keys().collect::<Vec<&String>>()
-        );
+    // Write the JSON description of the lexer for other tools. See `grammar` module.
+    if options.grammar_json() {
+        if let Some(out_dir) = std::env::var_os("OUT_DIR") {
+            write_debug_file(
+                Path::new(&out_dir),
+                &format!("{}.grammar.json", type_name),
+                &grammar_json,
+            )?;
+        }
     }
 
-    let dfa = dfa::simplify::simplify(dfa.unwrap(), &mut dfas);
+    // Compare the DFA with the snapshot, with `snapshot = "<path>";`. See `dfa::snapshot` module.
+    let snapshot = match options.snapshot() {
+        None => quote!(),
+        Some(path) => {
+            let rule_sets: Vec<(String, DfaStateIdx)> = rule_set_names
+                .iter()
+                .map(|name| (name.to_string(), rule_sets[&name.to_string()]))
+                .collect();
+
+            let snapshot = dfa::snapshot::snapshot(
+                &type_name.to_string(),
+                &dfa,
+                &rule_sets,
+                &right_ctx_dfas,
+                &label,
+            );
+
+            let path = dfa::snapshot::snapshot_path(path);
+            dfa::snapshot::check_snapshot(&path, &snapshot)
+                .map_err(|err| syn::Error::new(type_name.span(), err))?;
+
+            // Make sure the lexer is checked again when the snapshot changes
+            let path = path.to_string_lossy();
+            quote!(
+                const _: &[u8] = include_bytes!(#path);
+            )
+        }
+    };
 
-    dfa::codegen::reify(
+    let warnings: Vec<proc_macro2::TokenStream> =
+        diagnostics::rule_warnings(&dfa, &rules, options.ambiguity_warnings())
+            .into_iter()
+            .map(|(rule, message)| diagnostics::warning(rule.span, &message))
+            .collect();
+
+    let const_fn = if options.const_fn() {
+        dfa::codegen::const_fn::reify_const_fn(
+            &dfa,
+            &semantic_action_table,
+            &type_name,
+            &generics,
+            &token_type,
+            &visibility,
+        )
+    } else {
+        quote!()
+    };
+
+    let dfa_table = if options.dfa_table() {
+        dfa::codegen::table::reify_dfa_table(
+            &dfa,
+            &semantic_action_table,
+            &rules,
+            &type_name,
+            &generics,
+            &visibility,
+        )?
+    } else {
+        quote!()
+    };
+
+    let token_kind = if options.token_kind() {
+        token_kind::reify_token_kind(
+            &token_kinds,
+            &options.type_name(ast::GeneratedType::TokenKind, &type_name),
+            &generics,
+            &token_type,
+            &visibility,
+        )?
+    } else {
+        quote!()
+    };
+
+    // Statistics before simplification, reported with `statistics = true;`
+    let nfa_states: Vec<String> = rule_set_names
+        .iter()
+        .zip(nfas.iter())
+        .map(|(rule_set_name, nfa)| format!("{}: {}", rule_set_name, nfa.num_states()))
+        .collect();
+    let nfa_states_total: usize = nfas.iter().map(|nfa| nfa.num_states()).sum();
+    let dfa_states = dfa.num_states();
+
+    let dfa = dfa::simplify::simplify(dfa, &mut rule_sets);
+
+    let simplified_dfa_states = dfa.num_states();
+    let range_transitions = dfa.num_range_transitions();
+
+    let type_name_span = type_name.span();
+    let type_name_str = type_name.to_string();
+
+    let lexer = dfa::codegen::reify(
         dfa,
         &right_ctx_dfas,
         semantic_action_table,
         user_state_type,
         user_error_type,
-        dfas,
+        rule_sets,
         type_name,
+        generics,
         token_type,
-        public,
-    )
-    .into()
-}
+        attrs,
+        visibility,
+        &options,
+        invalid_utf8_action,
+        custom_matchers,
+        guards,
+        group_names,
+        rule_groups,
+        fail_actions,
+        feedback_type,
+        interner_type,
+        &captures,
+    )?;
 
-fn compile_rules(
-    rules: Vec<SingleRule>,
-    bindings: &Map<Var, Regex>,
-    right_ctx_dfas: &mut RightCtxDFAs<DfaStateIdx>,
-) -> DFA<DfaStateIdx, SemanticActionIdx> {
-    let mut nfa: NFA<SemanticActionIdx> = NFA::new();
-
-    for SingleRule { lhs, rhs } in rules {
-        let RegexCtx { re, right_ctx } = lhs;
+    let statistics = if options.statistics() {
+        let code_size =
+            lexer.to_string().len() + const_fn.to_string().len() + dfa_table.to_string().len();
+        diagnostics::warning(
+            type_name_span,
+            &format!(
+                "Lexer statistics: NFA states: {} ({}), DFA states: {}, DFA states after \
+                simplification: {}, range transitions: {}, generated code: {} bytes",
+                nfa_states_total,
+                nfa_states.join(", "),
+                dfa_states,
+                simplified_dfa_states,
+                range_transitions,
+                code_size,
+            ),
+        )
+    } else {
+        quote!()
+    };
 
-        let right_ctx = right_ctx
-            .as_ref()
-            .map(|right_ctx| right_ctx_dfas.new_right_ctx(bindings, right_ctx));
+    let code =
+        quote!(#lexer #const_fn #dfa_table #token_kind #snapshot #tests #(#warnings)* #statistics);
 
-        nfa.add_regex(bindings, &re, right_ctx, rhs);
+    // Write the generated code, formatted, for reading. See `expand` module.
+    if let Some(expand_dir) = expand::expand_dir() {
+        write_debug_file(
+            &expand_dir,
+            &format!("{}.rs", type_name_str),
+            &expand::pretty_print(&code),
+        )?;
     }
 
-    nfa_to_dfa(&nfa)
+    Ok(code)
+}
+
+/// Write a file requested for debugging (DOT graphs, DFA reports) or for other tools (LALRPOP
+/// `extern` blocks, JSON descriptions)
+fn write_debug_file(dir: &Path, file_name: &str, contents: &str) -> syn::Result<()> {
+    let path = dir.join(file_name);
+    std::fs::write(&path, contents).map_err(|err| {
+        syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("Unable to write {:?}: {}", path, err),
+        )
+    })
 }
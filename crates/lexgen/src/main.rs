@@ -0,0 +1,556 @@
+//! `lexgen` command line tool, for developing lexer definitions without building the crate that
+//! uses them.
+//!
+//! Usage: `lexgen [--states] [--coverage] [--snapshot] <definition file> [<input file>]`, or
+//! `lexgen --equivalent <definition file> <definition file>`, or `lexgen --from-flex <flex file>`
+//!
+//! The definition file is either a Rust file with `lexer!` invocations, or a file with just the
+//! contents of a `lexer!` invocation. For each lexer in the file, prints the number of states and
+//! transitions in its DFA, warnings about unreachable and ambiguous rules, and, when an input file
+//! is given, the rules matched when lexing the input with the `Init` rule set. With `--states`,
+//! also prints the states of the DFA, as in the reports written with `LEXGEN_REPORT_DIR`. With
+//! `--coverage`, also prints a shortest input for each accepting state of the DFA, which can be
+//! used as test inputs that cover all rules.
+//!
+//! With `--snapshot`, only prints the DFA snapshots of the lexers, in the format used by the
+//! `snapshot = "<path>";` option.
+//!
+//! With `--equivalent`, compares the lexers in two definition files, and prints a shortest input
+//! that is lexed differently when they're not equivalent. Rules are equivalent when they have the
+//! same semantic action (compared as tokens) and right context.
+//!
+//! With `--from-flex`, converts a flex scanner definition to a `lexer!` definition and prints it.
+//! See the `flex` module for the supported subset of flex.
+//!
+//! Semantic actions are not run, so matches of all rules are printed (including rules that skip
+//! input, e.g. whitespace) and the lexer never switches to another rule set.
+
+#![allow(
+    clippy::collapsible_else_if,
+    clippy::enum_variant_names,
+    clippy::too_many_arguments,
+    clippy::upper_case_acronyms
+)]
+// Modules are shared with the proc macro, code generation is not used here
+#![allow(dead_code)]
+
+mod ast;
+mod builtin;
+mod case_fold;
+mod char_ranges;
+mod collections;
+mod compile;
+mod dfa;
+mod diagnostics;
+mod display;
+mod dot;
+mod flex;
+#[cfg(feature = "unicode")]
+mod grapheme;
+mod include;
+mod nfa;
+mod nfa_to_dfa;
+#[cfg(feature = "unicode")]
+mod normalization;
+mod options;
+mod range_map;
+mod regex_simplify;
+mod regex_to_nfa;
+mod right_ctx;
+mod semantic_action_table;
+mod unicode_properties;
+#[cfg(feature = "unicode")]
+mod unicode_tables;
+
+use ast::{RuleKind, RuleRhs};
+use collections::Map;
+use compile::{accepting_state_label, rule_name, CompiledLexer};
+use dfa::equivalence::Difference;
+use diagnostics::RuleInfo;
+use nfa::AcceptingState;
+use semantic_action_table::{SemanticActionIdx, SemanticActionTable};
+
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
+use syn::parse::Parser;
+
+const USAGE: &str =
+    "Usage: lexgen [--states] [--coverage] [--snapshot] <definition file> [<input file>]
+       lexgen --equivalent <definition file> <definition file>
+       lexgen --from-flex <flex file>";
+
+fn main() {
+    let mut states = false;
+    let mut coverage = false;
+    let mut snapshot = false;
+    let mut equivalent = false;
+    let mut from_flex = false;
+    let mut files: Vec<PathBuf> = vec![];
+
+    for arg in std::env::args_os().skip(1) {
+        if arg == "--states" {
+            states = true;
+        } else if arg == "--coverage" {
+            coverage = true;
+        } else if arg == "--snapshot" {
+            snapshot = true;
+        } else if arg == "--equivalent" {
+            equivalent = true;
+        } else if arg == "--from-flex" {
+            from_flex = true;
+        } else if arg == "--help" || arg == "-h" {
+            println!("{}", USAGE);
+            return;
+        } else {
+            files.push(PathBuf::from(arg));
+        }
+    }
+
+    if equivalent {
+        match files.as_slice() {
+            [path1, path2] => {
+                if !check_equivalence(path1, path2) {
+                    exit(1);
+                }
+                return;
+            }
+            _ => {
+                eprintln!("{}", USAGE);
+                exit(2);
+            }
+        }
+    }
+
+    if from_flex {
+        match files.as_slice() {
+            [path] => match flex::convert(&read_file(path)) {
+                Ok(definition) => {
+                    print!("{}", definition);
+                    return;
+                }
+                Err(err) => {
+                    eprintln!("error: {}:{}", path.display(), err);
+                    exit(1);
+                }
+            },
+            _ => {
+                eprintln!("{}", USAGE);
+                exit(2);
+            }
+        }
+    }
+
+    let (definition_path, input_path) = match files.as_slice() {
+        [definition_path] => (definition_path, None),
+        [definition_path, input_path] => (definition_path, Some(input_path)),
+        _ => {
+            eprintln!("{}", USAGE);
+            exit(2);
+        }
+    };
+
+    let definition = read_file(definition_path);
+    let input = input_path.map(|input_path| read_file(input_path));
+
+    let lexers = match lexer_definitions(&definition) {
+        Ok(lexers) => lexers,
+        Err(err) => {
+            report_error(definition_path, &err);
+            exit(1);
+        }
+    };
+
+    let mut success = true;
+
+    for (invocation_idx, tokens) in lexers.into_iter().enumerate() {
+        if invocation_idx != 0 {
+            println!();
+        }
+
+        let compiled = ast::parse_lexers.parse2(tokens).and_then(|lexers| {
+            lexers
+                .into_iter()
+                .map(|(lexer, _)| compile::compile(lexer))
+                .collect::<syn::Result<Vec<_>>>()
+        });
+
+        let compiled = match compiled {
+            Ok(compiled) => compiled,
+            Err(err) => {
+                report_error(definition_path, &err);
+                success = false;
+                continue;
+            }
+        };
+
+        for (lexer_idx, lexer) in compiled.iter().enumerate() {
+            if lexer_idx != 0 {
+                println!();
+            }
+
+            if snapshot {
+                print_snapshot(lexer);
+                continue;
+            }
+
+            success &= run_lexer(definition_path, lexer, input.as_deref(), states, coverage);
+        }
+    }
+
+    if !success {
+        exit(1);
+    }
+}
+
+/// Returns contents of `lexer!` invocations in a Rust file, or when the file is not a Rust file
+/// with `lexer!` invocations, the whole file. `include_rules!` items are expanded, with paths
+/// relative to `CARGO_MANIFEST_DIR`, or the current directory when it's not set.
+fn lexer_definitions(source: &str) -> syn::Result<Vec<TokenStream>> {
+    let mut lexers = vec![];
+
+    if let Ok(file) = syn::parse_file(source) {
+        find_lexer_macros(&file.items, &mut lexers);
+    }
+
+    if lexers.is_empty() {
+        lexers.push(syn::parse_str(source)?);
+    }
+
+    lexers
+        .into_iter()
+        .map(|lexer| include::expand_includes(lexer).map(|(lexer, _)| lexer))
+        .collect()
+}
+
+fn find_lexer_macros(items: &[syn::Item], lexers: &mut Vec<TokenStream>) {
+    for item in items {
+        match item {
+            syn::Item::Macro(syn::ItemMacro { mac, .. })
+                if mac
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident == "lexer")
+                    .unwrap_or(false) =>
+            {
+                lexers.push(mac.tokens.clone());
+            }
+            syn::Item::Mod(syn::ItemMod {
+                content: Some((_, items)),
+                ..
+            }) => find_lexer_macros(items, lexers),
+            _ => {}
+        }
+    }
+}
+
+/// Print summary and warnings of the lexer, and run it on `input`. Returns whether the input was
+/// lexed successfully.
+fn run_lexer(
+    path: &Path,
+    lexer: &CompiledLexer,
+    input: Option<&str>,
+    states: bool,
+    coverage: bool,
+) -> bool {
+    let name = lexer.type_name.to_string();
+    let label = accepting_state_label(&lexer.rules);
+    let rule_name = rule_name(&lexer.rules);
+
+    if states {
+        print!(
+            "{}",
+            dfa::report::report(
+                &name,
+                &lexer.dfa,
+                lexer.options.driver(),
+                &lexer.rule_sets,
+                &label,
+                &rule_name,
+            )
+        );
+    } else {
+        print!("{}", dfa::report::summary(&name, &lexer.dfa));
+    }
+
+    let warnings = diagnostics::rule_warnings(&lexer.dfa, &lexer.rules, true);
+    if !warnings.is_empty() {
+        println!();
+        for (rule, message) in warnings {
+            println!(
+                "warning: {}: {}: {}",
+                location(path, rule.span),
+                rule.describe(),
+                message
+            );
+        }
+    }
+
+    if coverage {
+        println!();
+        for (input, accepting_states) in dfa::analysis::accepting_inputs(&lexer.dfa) {
+            let rules: Vec<String> = accepting_states.iter().map(&label).collect();
+            println!("{:?} {}", input, rules.join(", "));
+        }
+    }
+
+    let input = match input {
+        Some(input) => input,
+        None => return true,
+    };
+
+    println!();
+
+    let (matches, error) = lexer.dfa.simulate(input, &lexer.right_ctx_dfas);
+
+    let mut offset = 0;
+    for (text, action) in matches {
+        let rule = find_rule(lexer, action);
+        println!(
+            "{}..{} {} {:?}",
+            offset,
+            offset + text.len(),
+            rule.describe(),
+            text
+        );
+        offset += text.len();
+    }
+
+    match error {
+        Some(error_loc) => {
+            println!("error: no rules match the input at byte {}", error_loc);
+            false
+        }
+        None => true,
+    }
+}
+
+fn print_snapshot(lexer: &CompiledLexer) {
+    print!(
+        "{}",
+        dfa::snapshot::snapshot(
+            &lexer.type_name.to_string(),
+            &lexer.dfa,
+            &ordered_rule_sets(lexer),
+            &lexer.right_ctx_dfas,
+            accepting_state_label(&lexer.rules),
+        )
+    );
+}
+
+/// Compare lexers in two definition files, in the order they're defined. Returns whether all
+/// lexers are equivalent.
+fn check_equivalence(path1: &Path, path2: &Path) -> bool {
+    let lexers1 = compile_lexers(path1);
+    let lexers2 = compile_lexers(path2);
+
+    if lexers1.len() != lexers2.len() {
+        println!(
+            "{} defines {} lexers, {} defines {}",
+            path1.display(),
+            lexers1.len(),
+            path2.display(),
+            lexers2.len()
+        );
+        return false;
+    }
+
+    let mut equivalent = true;
+
+    for ((lexer1, actions1), (lexer2, actions2)) in lexers1.iter().zip(lexers2.iter()) {
+        let names = format!("{} and {}", lexer1.type_name, lexer2.type_name);
+
+        let difference = dfa::equivalence::find_difference(
+            &lexer1.dfa,
+            &ordered_rule_sets(lexer1),
+            rule_key(lexer1, actions1),
+            &lexer2.dfa,
+            &ordered_rule_sets(lexer2),
+            rule_key(lexer2, actions2),
+        );
+
+        match difference {
+            None => println!("{} are equivalent", names),
+            Some(Difference::RuleSet { name, in_first }) => {
+                equivalent = false;
+                println!(
+                    "{} differ: rule set {} is only defined in {}",
+                    names,
+                    name,
+                    if in_first { path1 } else { path2 }.display(),
+                );
+            }
+            Some(Difference::Input {
+                rule_set,
+                input,
+                end_of_input,
+                first,
+                second,
+            }) => {
+                equivalent = false;
+                println!(
+                    "{} differ in rule set {} for input {:?}{}:",
+                    names,
+                    rule_set,
+                    input,
+                    if end_of_input {
+                        " followed by end of input"
+                    } else {
+                        ""
+                    },
+                );
+                println!(
+                    "  {}: {}",
+                    path1.display(),
+                    describe_matches(lexer1, actions1, first)
+                );
+                println!(
+                    "  {}: {}",
+                    path2.display(),
+                    describe_matches(lexer2, actions2, second)
+                );
+            }
+        }
+    }
+
+    equivalent
+}
+
+/// Compile lexers in a definition file, with the semantic actions of their rules as strings.
+/// Exits on errors.
+fn compile_lexers(path: &Path) -> Vec<(CompiledLexer, Map<SemanticActionIdx, String>)> {
+    let definition = read_file(path);
+
+    let lexers = lexer_definitions(&definition).and_then(|lexers| {
+        lexers
+            .into_iter()
+            .map(|tokens| ast::parse_lexers.parse2(tokens))
+            .collect::<syn::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .map(|(lexer, semantic_action_table)| {
+                let lexer = compile::compile(lexer)?;
+                Ok((lexer, action_strings(&semantic_action_table)))
+            })
+            .collect::<syn::Result<Vec<_>>>()
+    });
+
+    match lexers {
+        Ok(lexers) => lexers,
+        Err(err) => {
+            report_error(path, &err);
+            exit(1);
+        }
+    }
+}
+
+fn action_strings(semantic_action_table: &SemanticActionTable) -> Map<SemanticActionIdx, String> {
+    semantic_action_table
+        .iter()
+        .map(|(idx, rhs)| {
+            let action = match rhs {
+                RuleRhs::None => ",".to_owned(),
+                RuleRhs::Rhs { expr, kind } => {
+                    let kind = match kind {
+                        RuleKind::Simple => "=",
+                        RuleKind::Fallible => "=?",
+                        RuleKind::Infallible => "=>",
+                    };
+                    format!("{} {}", kind, expr.to_token_stream())
+                }
+            };
+            (idx, action)
+        })
+        .collect()
+}
+
+/// Key of an accepting state for comparing lexers: semantic action of the rule, snapshot of the
+/// right context, and the guard
+fn rule_key<'a>(
+    lexer: &'a CompiledLexer,
+    actions: &'a Map<SemanticActionIdx, String>,
+) -> impl Fn(&AcceptingState<SemanticActionIdx>) -> (&'a str, Option<String>, Option<String>) {
+    let right_ctxs: Vec<String> = lexer
+        .right_ctx_dfas
+        .iter()
+        .map(|(_, dfa)| dfa::snapshot::right_ctx_snapshot(dfa))
+        .collect();
+
+    move |accepting_state| {
+        (
+            actions[&accepting_state.value].as_str(),
+            accepting_state
+                .right_ctx
+                .map(|right_ctx| right_ctxs[right_ctx.as_usize()].clone()),
+            lexer
+                .guards
+                .get(&accepting_state.value)
+                .map(|guard| guard.to_token_stream().to_string()),
+        )
+    }
+}
+
+fn describe_matches(
+    lexer: &CompiledLexer,
+    actions: &Map<SemanticActionIdx, String>,
+    accepting_states: &[AcceptingState<SemanticActionIdx>],
+) -> String {
+    if accepting_states.is_empty() {
+        return "no match".to_owned();
+    }
+
+    let label = accepting_state_label(&lexer.rules);
+
+    accepting_states
+        .iter()
+        .map(|accepting_state| {
+            format!(
+                "{} `{}`",
+                label(accepting_state),
+                actions[&accepting_state.value]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Rule set names with their initial states, in the order they're defined
+fn ordered_rule_sets(lexer: &CompiledLexer) -> Vec<(String, dfa::StateIdx)> {
+    lexer
+        .rule_set_names
+        .iter()
+        .map(|name| (name.to_string(), lexer.rule_sets[&name.to_string()]))
+        .collect()
+}
+
+fn find_rule(lexer: &CompiledLexer, action: SemanticActionIdx) -> &RuleInfo {
+    lexer
+        .rules
+        .iter()
+        .find(|rule| rule.action == action)
+        .unwrap()
+}
+
+fn location(path: &Path, span: Span) -> String {
+    let start = span.start();
+    format!("{}:{}:{}", path.display(), start.line, start.column + 1)
+}
+
+fn report_error(path: &Path, err: &syn::Error) {
+    for err in err.clone() {
+        eprintln!("error: {}: {}", location(path, err.span()), err);
+    }
+}
+
+fn read_file(path: &Path) -> String {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("error: unable to read {}: {}", path.display(), err);
+            exit(1);
+        }
+    }
+}
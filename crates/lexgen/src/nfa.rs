@@ -1,13 +1,18 @@
-#[cfg(test)]
+// Used in tests and the CLI
+#[cfg_attr(not(test), allow(dead_code))]
 pub mod simulate;
 
 use crate::ast::{Regex, Var};
+use crate::case_fold::case_fold_classes_in_range;
 use crate::collections::{Map, Set};
 use crate::display::HashSetDisplay;
+use crate::dot::Graph;
 use crate::range_map::{Range, RangeMap};
 use crate::regex_to_nfa;
 use crate::right_ctx::RightCtxIdx;
 
+use std::collections::VecDeque;
+
 /// Non-deterministic finite automate, parameterized on values of accepting states.
 #[derive(Debug)]
 pub struct NFA<A> {
@@ -20,29 +25,51 @@ pub struct StateIdx(usize);
 
 #[derive(Debug)]
 struct State<A> {
-    char_transitions: Map<char, Set<StateIdx>>,
-    range_transitions: RangeMap<Set<StateIdx>>,
+    /// Transitions on characters. Transitions on single characters are ranges of one character.
+    transitions: RangeMap<Set<StateIdx>>,
     empty_transitions: Set<StateIdx>,
     any_transitions: Set<StateIdx>,
     end_of_input_transitions: Set<StateIdx>,
     accepting: Option<AcceptingState<A>>,
+    provenance: Option<Provenance<A>>,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct AcceptingState<A> {
     pub value: A,
     pub right_ctx: Option<RightCtxIdx>,
+    /// Whether the rule has a guard (`<regex> if <guard>`), which is checked in generated code
+    /// before accepting the rule
+    pub guarded: bool,
+}
+
+impl<A> AcceptingState<A> {
+    /// Whether the state is only accepted when a condition holds: the rule has a right context or
+    /// a guard. When the condition doesn't hold, the next accepting state of a DFA state is tried.
+    pub fn is_conditional(&self) -> bool {
+        self.right_ctx.is_some() || self.guarded
+    }
+}
+
+/// The rule a state is created for, used to name states in debug output
+#[derive(Debug, Clone, Copy)]
+pub struct Provenance<A> {
+    /// Value of the rule's accepting state
+    pub rule: A,
+
+    /// Number of characters the rule matches before reaching the state, on the shortest path
+    pub position: usize,
 }
 
 impl<A> State<A> {
     fn new() -> State<A> {
         State {
-            char_transitions: Default::default(),
-            range_transitions: Default::default(),
+            transitions: Default::default(),
             empty_transitions: Default::default(),
             any_transitions: Default::default(),
             end_of_input_transitions: Default::default(),
             accepting: None,
+            provenance: None,
         }
     }
 }
@@ -54,6 +81,10 @@ impl<A> NFA<A> {
         }
     }
 
+    pub fn num_states(&self) -> usize {
+        self.states.len()
+    }
+
     pub fn initial_state(&self) -> StateIdx {
         StateIdx(0)
     }
@@ -62,18 +93,12 @@ impl<A> NFA<A> {
         self.states[state.0].accepting.as_ref()
     }
 
-    pub fn char_transitions(
-        &self,
-        state: StateIdx,
-    ) -> impl Iterator<Item = (&char, &Set<StateIdx>)> {
-        self.states[state.0].char_transitions.iter()
+    pub fn get_provenance(&self, state: StateIdx) -> Option<&Provenance<A>> {
+        self.states[state.0].provenance.as_ref()
     }
 
-    pub fn range_transitions(
-        &self,
-        state: StateIdx,
-    ) -> impl Iterator<Item = &Range<Set<StateIdx>>> {
-        self.states[state.0].range_transitions.iter()
+    pub fn transitions(&self, state: StateIdx) -> impl Iterator<Item = &Range<Set<StateIdx>>> {
+        self.states[state.0].transitions.iter()
     }
 
     pub fn any_transitions(&self, state: StateIdx) -> impl Iterator<Item = StateIdx> + '_ {
@@ -99,10 +124,26 @@ impl<A> NFA<A> {
         re: &Regex,
         right_ctx: Option<RightCtxIdx>,
         value: A,
-    ) {
+    ) where
+        A: Clone,
+    {
+        self.add_guarded_regex(bindings, re, right_ctx, false, value)
+    }
+
+    /// Same as `add_regex`, for rules that may have a guard
+    pub fn add_guarded_regex(
+        &mut self,
+        bindings: &Map<Var, Regex>,
+        re: &Regex,
+        right_ctx: Option<RightCtxIdx>,
+        guarded: bool,
+        value: A,
+    ) where
+        A: Clone,
+    {
         let re_accepting_state = self.new_state();
 
-        self.make_state_accepting(re_accepting_state, value, right_ctx);
+        self.make_state_accepting(re_accepting_state, value.clone(), right_ctx, guarded);
 
         let re_initial_state = self.new_state();
         let nfa_initial_state = self.initial_state();
@@ -110,16 +151,60 @@ impl<A> NFA<A> {
         self.add_empty_transition(nfa_initial_state, re_initial_state);
 
         regex_to_nfa::add_re(self, bindings, re, re_initial_state, re_accepting_state);
+
+        self.set_provenance(re_accepting_state.0, re_initial_state, value);
     }
 
-    pub fn add_char_transition(&mut self, state: StateIdx, char: char, next: StateIdx) {
-        let not_exists = self.states[state.0]
-            .char_transitions
-            .entry(char)
-            .or_default()
-            .insert(next);
+    /// Set provenance of the states starting from `first_state`, which are created for the rule
+    /// `rule` with initial state `initial_state`. Positions are found with a breadth-first search
+    /// where empty transitions have weight 0, and other transitions have weight 1.
+    fn set_provenance(&mut self, first_state: usize, initial_state: StateIdx, rule: A)
+    where
+        A: Clone,
+    {
+        let mut positions: Vec<Option<usize>> = vec![None; self.states.len()];
+        let mut work_list: VecDeque<(StateIdx, usize)> = VecDeque::new();
+        work_list.push_back((initial_state, 0));
+
+        while let Some((state_idx, position)) = work_list.pop_front() {
+            if positions[state_idx.0].is_some() {
+                continue;
+            }
+            positions[state_idx.0] = Some(position);
+
+            let state = &self.states[state_idx.0];
+
+            for next in &state.empty_transitions {
+                work_list.push_front((*next, position));
+            }
+
+            let non_empty = state
+                .transitions
+                .iter()
+                .flat_map(|range| &range.value)
+                .chain(state.any_transitions.iter())
+                .chain(state.end_of_input_transitions.iter());
+
+            for next in non_empty {
+                work_list.push_back((*next, position + 1));
+            }
+        }
 
-        assert!(not_exists, "add_char_transition");
+        for (state, position) in self.states[first_state..]
+            .iter_mut()
+            .zip(positions[first_state..].iter())
+        {
+            if let Some(position) = position {
+                state.provenance = Some(Provenance {
+                    rule: rule.clone(),
+                    position: *position,
+                });
+            }
+        }
+    }
+
+    pub fn add_char_transition(&mut self, state: StateIdx, char: char, next: StateIdx) {
+        self.add_range_transition(state, char, char, next);
     }
 
     pub fn add_range_transition(
@@ -131,11 +216,11 @@ impl<A> NFA<A> {
     ) {
         let mut set: Set<StateIdx> = Default::default();
         set.insert(next);
-        self.states[state.0].range_transitions.insert(
+        self.states[state.0].transitions.insert(
             range_start as u32,
             range_end as u32,
             set,
-            |values_1, values_2| values_1.extend(values_2.into_iter()),
+            |values_1, values_2| values_1.extend(values_2),
         );
     }
 
@@ -146,9 +231,9 @@ impl<A> NFA<A> {
         let ranges = ranges.map(|()| set.clone());
 
         self.states[state.0]
-            .range_transitions
+            .transitions
             .insert_ranges(ranges.into_iter(), |values_1, values_2| {
-                values_1.extend(values_2.into_iter())
+                values_1.extend(values_2)
             });
     }
 
@@ -170,14 +255,73 @@ impl<A> NFA<A> {
         assert!(not_exists, "add_end_of_input_transition");
     }
 
-    fn make_state_accepting(&mut self, state: StateIdx, value: A, right_ctx: Option<RightCtxIdx>) {
-        let old = self.states[state.0]
-            .accepting
-            .replace(AcceptingState { value, right_ctx });
+    fn make_state_accepting(
+        &mut self,
+        state: StateIdx,
+        value: A,
+        right_ctx: Option<RightCtxIdx>,
+        guarded: bool,
+    ) {
+        let old = self.states[state.0].accepting.replace(AcceptingState {
+            value,
+            right_ctx,
+            guarded,
+        });
 
         assert!(old.is_none(), "make_state_accepting");
     }
 
+    /// Restrict the alphabet of the NFA to ASCII: remove transitions on non-ASCII characters, and
+    /// replace 'any' transitions with transitions on ASCII characters.
+    ///
+    /// Non-ASCII characters given in regexes are reported before building the NFA, so only
+    /// non-ASCII parts of character sets, built-in regexes, and case folding are removed here.
+    pub fn restrict_to_ascii(&mut self) {
+        let mut non_ascii: RangeMap<()> = RangeMap::new();
+        non_ascii.insert(128, char::MAX as u32, (), |_, _| ());
+
+        for state in &mut self.states {
+            state.transitions.remove_ranges(&non_ascii);
+
+            let any_transitions = std::mem::take(&mut state.any_transitions);
+            if !any_transitions.is_empty() {
+                state
+                    .transitions
+                    .insert(0, 127, any_transitions, |values_1, values_2| {
+                        values_1.extend(values_2)
+                    });
+            }
+        }
+    }
+
+    /// Make transitions case-insensitive: add transitions on characters with the same simple case
+    /// folding as the characters of transitions. Should be called before
+    /// `restrict_to_ascii`, as folded characters are added as range transitions, which are then
+    /// removed when not ASCII (e.g. Kelvin sign `K` for `k`).
+    pub fn case_fold(&mut self) {
+        for state in &mut self.states {
+            let mut folded: Vec<Range<Set<StateIdx>>> = vec![];
+
+            for range in state.transitions.iter() {
+                for (_, class) in case_fold_classes_in_range(range.start, range.end) {
+                    for other in class {
+                        if !range.contains(*other) {
+                            folded.push(Range {
+                                start: *other as u32,
+                                end: *other as u32,
+                                value: range.value.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+
+            state
+                .transitions
+                .insert_many(folded, |values_1, values_2| values_1.extend(values_2));
+        }
+    }
+
     pub fn compute_state_closure(&self, states: &Set<StateIdx>) -> Set<StateIdx> {
         let mut worklist: Vec<StateIdx> = states.iter().copied().collect();
         let mut closure: Set<StateIdx> = states.clone();
@@ -199,6 +343,48 @@ impl<A> NFA<A> {
     }
 }
 
+impl<A> NFA<A> {
+    /// Generate a Graphviz DOT graph of the NFA. `label` is used to label accepting states.
+    pub fn to_dot<F: Fn(&AcceptingState<A>) -> String>(&self, name: &str, label: F) -> String {
+        let mut graph = Graph::new();
+
+        for (state_idx, state) in self.states.iter().enumerate() {
+            graph.add_node(
+                state_idx == 0,
+                None,
+                state.accepting.iter().map(&label).collect(),
+            );
+
+            for next in &state.empty_transitions {
+                graph.add_edge(state_idx, next.0, "ε".to_owned());
+            }
+
+            for range in state.transitions.iter() {
+                let start = char::from_u32(range.start).unwrap();
+                let end = char::from_u32(range.end).unwrap();
+                let label = if start == end {
+                    format!("{:?}", start)
+                } else {
+                    format!("{:?}-{:?}", start, end)
+                };
+                for next in &range.value {
+                    graph.add_edge(state_idx, next.0, label.clone());
+                }
+            }
+
+            for next in &state.any_transitions {
+                graph.add_edge(state_idx, next.0, "_".to_owned());
+            }
+
+            for next in &state.end_of_input_transitions {
+                graph.add_edge(state_idx, next.0, "$".to_owned());
+            }
+        }
+
+        graph.render_dot(name)
+    }
+}
+
 use std::fmt::{self, Display, Formatter};
 
 impl Display for StateIdx {
@@ -211,18 +397,19 @@ impl<A> Display for NFA<A> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for (state_idx, state) in self.states.iter().enumerate() {
             let State {
-                char_transitions,
-                range_transitions,
+                transitions,
                 empty_transitions,
                 any_transitions,
                 end_of_input_transitions,
                 accepting,
+                provenance: _,
             } = state;
 
             match accepting {
                 Some(AcceptingState {
                     value: _,
                     right_ctx,
+                    guarded: _,
                 }) => match right_ctx {
                     Some(right_ctx_idx) => {
                         write!(f, "{:>4}", format!("*{}", state_idx),)?;
@@ -249,30 +436,29 @@ impl<A> Display for NFA<A> {
                 writeln!(f, "e -> {}", HashSetDisplay(empty_transitions))?;
             }
 
-            for (char, next) in char_transitions.iter() {
+            for range in transitions.iter() {
                 if !first {
                     write!(f, "     ")?;
                 } else {
                     first = false;
                 }
 
-                writeln!(f, "{:?} -> {}", char, HashSetDisplay(next))?;
-            }
-
-            for range in range_transitions.iter() {
-                if !first {
-                    write!(f, "     ")?;
+                if range.start == range.end {
+                    writeln!(
+                        f,
+                        "{:?} -> {}",
+                        char::from_u32(range.start).unwrap(),
+                        HashSetDisplay(&range.value)
+                    )?;
                 } else {
-                    first = false;
+                    writeln!(
+                        f,
+                        "{:?} - {:?} -> {}",
+                        range.start,
+                        range.end,
+                        HashSetDisplay(&range.value)
+                    )?;
                 }
-
-                writeln!(
-                    f,
-                    "{:?} - {:?} -> {}",
-                    range.start,
-                    range.end,
-                    HashSetDisplay(&range.value)
-                )?;
             }
 
             if !any_transitions.is_empty() {
@@ -294,8 +480,7 @@ impl<A> Display for NFA<A> {
             }
 
             if empty_transitions.is_empty()
-                && char_transitions.is_empty()
-                && range_transitions.is_empty()
+                && transitions.is_empty()
                 && any_transitions.is_empty()
                 && end_of_input_transitions.is_empty()
             {
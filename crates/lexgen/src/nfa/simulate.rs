@@ -72,8 +72,9 @@ impl<A: std::fmt::Debug + Copy> NFA<A> {
                     let mut states_sorted: Vec<StateIdx> = states.iter().copied().collect();
                     states_sorted.sort();
                     for state in states_sorted {
-                        if let Some(AcceptingState { value, right_ctx }) =
-                            &self.states[state.0].accepting
+                        if let Some(AcceptingState {
+                            value, right_ctx, ..
+                        }) = &self.states[state.0].accepting
                         {
                             match right_ctx {
                                 None => {
@@ -103,8 +104,9 @@ impl<A: std::fmt::Debug + Copy> NFA<A> {
                 states_sorted.sort();
 
                 for state in states_sorted {
-                    if let Some(AcceptingState { value, right_ctx }) =
-                        &self.states[state.0].accepting
+                    if let Some(AcceptingState {
+                        value, right_ctx, ..
+                    }) = &self.states[state.0].accepting
                     {
                         match right_ctx {
                             None => {
@@ -155,13 +157,8 @@ fn next<A>(nfa: &NFA<A>, states: &Set<StateIdx>, char: char) -> Set<StateIdx> {
     let mut next_states: Set<StateIdx> = Default::default();
 
     for state in states {
-        // Char transitions
-        if let Some(char_nexts) = nfa.states[state.0].char_transitions.get(&char) {
-            next_states.extend(char_nexts.into_iter());
-        }
-
-        // Range transitions
-        for range in nfa.states[state.0].range_transitions.iter() {
+        // Character transitions
+        for range in nfa.states[state.0].transitions.iter() {
             if char as u32 >= range.start && char as u32 <= range.end {
                 next_states.extend(range.value.clone());
             }
@@ -8,8 +8,52 @@ use crate::nfa::StateIdx as NfaStateIdx;
 
 use std::collections::hash_map::Entry;
 use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Convert a list of independent NFAs (e.g. NFAs of rule sets) to DFAs. NFAs are determinized in
+/// parallel on worker threads. Returned DFAs are in the same order as the NFAs.
+pub fn nfas_to_dfas<A: Clone + PartialEq + Send + Sync>(
+    nfas: &[NFA<A>],
+) -> Vec<DFA<DfaStateIdx, A>> {
+    let n_threads = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(nfas.len());
+
+    if n_threads <= 1 {
+        return nfas.iter().map(nfa_to_dfa).collect();
+    }
+
+    // Index of the next NFA to determinize. Worker threads pick NFAs from this counter until all
+    // NFAs are taken, so one large rule set doesn't block a thread that finished its NFAs early.
+    let next_nfa = AtomicUsize::new(0);
+
+    let dfas: Mutex<Vec<Option<DFA<DfaStateIdx, A>>>> =
+        Mutex::new((0..nfas.len()).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..n_threads {
+            scope.spawn(|| loop {
+                let nfa_idx = next_nfa.fetch_add(1, Ordering::Relaxed);
+                if nfa_idx >= nfas.len() {
+                    break;
+                }
+                let dfa = nfa_to_dfa(&nfas[nfa_idx]);
+                dfas.lock().unwrap()[nfa_idx] = Some(dfa);
+            });
+        }
+    });
 
-pub fn nfa_to_dfa<A: Clone>(nfa: &NFA<A>) -> DFA<DfaStateIdx, A> {
+    dfas.into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|dfa| dfa.unwrap())
+        .collect()
+}
+
+pub fn nfa_to_dfa<A: Clone + PartialEq>(nfa: &NFA<A>) -> DFA<DfaStateIdx, A> {
     let initial_state = nfa.initial_state();
 
     let initial_states: BTreeSet<NfaStateIdx> = {
@@ -46,8 +90,7 @@ pub fn nfa_to_dfa<A: Clone>(nfa: &NFA<A>) -> DFA<DfaStateIdx, A> {
 
         finished_dfa_states.insert(current_dfa_state);
 
-        let mut char_transitions: Map<char, Set<NfaStateIdx>> = Default::default();
-        let mut range_transitions: RangeMap<Set<NfaStateIdx>> = Default::default();
+        let mut transitions: RangeMap<Set<NfaStateIdx>> = Default::default();
         let mut any_transitions: Set<NfaStateIdx> = Default::default();
         let mut end_of_input_transitions: Set<NfaStateIdx> = Default::default();
 
@@ -56,23 +99,18 @@ pub fn nfa_to_dfa<A: Clone>(nfa: &NFA<A>) -> DFA<DfaStateIdx, A> {
                 dfa.make_state_accepting(current_dfa_state, value.clone());
             }
 
-            // Collect char transitions
-            for (char, next_states) in nfa.char_transitions(nfa_state) {
-                char_transitions
-                    .entry(*char)
-                    .or_default()
-                    .extend(next_states.iter().copied());
+            // Positions 0 are in the initial state, which is not named after the rules
+            if let Some(provenance) = nfa.get_provenance(nfa_state) {
+                if provenance.position != 0 {
+                    dfa.add_provenance(current_dfa_state, provenance.clone());
+                }
             }
 
-            // Collect range transitions
-            for range in nfa.range_transitions(nfa_state) {
-                range_transitions.insert(
-                    range.start,
-                    range.end,
-                    range.value.clone(),
-                    |states_1, states_2| states_1.extend(states_2.into_iter()),
-                );
-            }
+            // Collect character transitions. Overlapping ranges of the NFA states are split into
+            // disjoint sub-ranges, mapped to the union of the NFA states of the overlapping ranges.
+            transitions.insert_ranges(nfa.transitions(nfa_state).cloned(), |states_1, states_2| {
+                states_1.extend(states_2)
+            });
 
             // Collect any transitions
             any_transitions.extend(nfa.any_transitions(nfa_state));
@@ -82,38 +120,13 @@ pub fn nfa_to_dfa<A: Clone>(nfa: &NFA<A>) -> DFA<DfaStateIdx, A> {
         }
 
         // Compute closures of transition targets and add transitions to DFA
-        for (char, mut char_states) in char_transitions.into_iter() {
-            // For ranges that also cover the char we need to add the range transitions to the char
-            // transition
-            for range in range_transitions.iter() {
-                if range.contains(char) {
-                    for range_state in &range.value {
-                        char_states.insert(*range_state);
-                    }
-                }
-            }
+        let mut dfa_transitions: Vec<Range<DfaStateIdx>> = Vec::with_capacity(transitions.len());
 
-            // Same for '_' (match any character) transitions
-            for any_next in &any_transitions {
-                char_states.insert(*any_next);
-            }
-
-            let closure: BTreeSet<NfaStateIdx> = nfa
-                .compute_state_closure(&char_states)
-                .into_iter()
-                .collect();
-            let dfa_state = dfa_state_of_nfa_states(&mut dfa, &mut state_map, closure.clone());
-            dfa.add_char_transition(current_dfa_state, char, dfa_state);
-
-            work_list.push(closure);
-        }
-
-        let mut dfa_range_transitions: Vec<Range<DfaStateIdx>> =
-            Vec::with_capacity(range_transitions.len());
-
-        for range in range_transitions.into_iter() {
+        for range in transitions.into_iter() {
             let mut range_states: Set<NfaStateIdx> = range.value;
 
+            // Characters matched by '_' (match any character) transitions are matched by all
+            // character transitions as well
             for any_next in &any_transitions {
                 range_states.insert(*any_next);
             }
@@ -125,18 +138,25 @@ pub fn nfa_to_dfa<A: Clone>(nfa: &NFA<A>) -> DFA<DfaStateIdx, A> {
 
             let dfa_state = dfa_state_of_nfa_states(&mut dfa, &mut state_map, closure.clone());
 
-            dfa_range_transitions.push(Range {
-                start: range.start,
-                end: range.end,
-                value: dfa_state,
-            });
+            // Merge adjacent sub-ranges that lead to the same DFA state, e.g. parts of a range
+            // split by an overlapping range of another rule that doesn't continue the same way
+            match dfa_transitions.last_mut() {
+                Some(last) if last.end + 1 == range.start && last.value == dfa_state => {
+                    last.end = range.end;
+                }
+                _ => dfa_transitions.push(Range {
+                    start: range.start,
+                    end: range.end,
+                    value: dfa_state,
+                }),
+            }
 
             work_list.push(closure);
         }
 
-        dfa.set_range_transitions(
+        dfa.set_transitions(
             current_dfa_state,
-            RangeMap::from_non_overlapping_sorted_ranges(dfa_range_transitions),
+            RangeMap::from_non_overlapping_sorted_ranges(dfa_transitions),
         );
 
         {
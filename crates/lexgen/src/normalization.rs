@@ -0,0 +1,289 @@
+//! Canonical equivalence for `normalization_insensitive = true;`, where strings and characters in
+//! rules match all canonically equivalent strings, e.g. `"é"` matches both precomposed `é` and `e`
+//! followed by a combining acute accent.
+//!
+//! Instead of normalizing the input, string and character regexes are expanded to alternations of
+//! the canonically equivalent strings, so the lexer still matches slices of the original input.
+//! Two strings are canonically equivalent when they have the same canonical decomposition (NFD).
+//! The equivalent strings of a string are found by splitting its NFD into units of a starter
+//! character (combining class 0) and the combining marks after it, and finding the ways to write
+//! each unit: a character that decomposes to the starter and some of the marks, followed by
+//! characters that decompose to the rest of the marks, in any order that keeps marks with the same
+//! combining class in order. Hangul syllables are handled as units of the jamo they decompose to.
+//!
+//! Character sets are not expanded, as a character set matches a single character.
+
+use crate::ast::{Regex, Var};
+use crate::collections::{Map, Set};
+use crate::unicode_tables::{CANONICAL_DECOMPOSITIONS, COMBINING_CLASSES};
+
+use std::sync::OnceLock;
+
+/// Replace strings and characters outside of character sets with alternations of the strings
+/// canonically equivalent to them
+pub fn expand_canonical_equivalents(re: &Regex, bindings: &Map<Var, Regex>) -> Regex {
+    re.map_outside_char_sets(bindings, &|re| match re {
+        Regex::Char(char) => Some(canonical_equivalents_regex(&[*char])),
+        Regex::String(str) if !str.is_empty() => Some(canonical_equivalents_regex(
+            &str.chars().collect::<Vec<_>>(),
+        )),
+        _ => None,
+    })
+}
+
+fn canonical_equivalents_regex(chars: &[char]) -> Regex {
+    units(&nfd(chars))
+        .into_iter()
+        .map(|(starters, marks)| {
+            unit_equivalents(&starters, &marks)
+                .into_iter()
+                .map(Regex::String)
+                .reduce(|re1, re2| Regex::Or(Box::new(re1), Box::new(re2)))
+                .unwrap()
+        })
+        .reduce(|re1, re2| Regex::Concat(Box::new(re1), Box::new(re2)))
+        .unwrap()
+}
+
+// Hangul syllables, see "Hangul Syllable Decomposition" in the Unicode Standard, section 3.12
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+fn is_hangul_l(char: char) -> bool {
+    (L_BASE..L_BASE + L_COUNT).contains(&(char as u32))
+}
+
+fn is_hangul_v(char: char) -> bool {
+    (V_BASE..V_BASE + V_COUNT).contains(&(char as u32))
+}
+
+fn is_hangul_t(char: char) -> bool {
+    // `T_BASE` itself is not a trailing consonant
+    (T_BASE + 1..T_BASE + T_COUNT).contains(&(char as u32))
+}
+
+/// The Hangul syllable of leading consonant `l`, vowel `v`, and optional trailing consonant `t`
+fn hangul_syllable(l: char, v: char, t: Option<char>) -> char {
+    let l_index = l as u32 - L_BASE;
+    let v_index = v as u32 - V_BASE;
+    let t_index = t.map_or(0, |t| t as u32 - T_BASE);
+    char::from_u32(S_BASE + l_index * N_COUNT + v_index * T_COUNT + t_index).unwrap()
+}
+
+fn combining_class(char: char) -> u8 {
+    let char = char as u32;
+    match COMBINING_CLASSES.binary_search_by(|(start, end, _)| {
+        if char < *start {
+            std::cmp::Ordering::Greater
+        } else if char > *end {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    }) {
+        Ok(idx) => COMBINING_CLASSES[idx].2,
+        Err(_) => 0,
+    }
+}
+
+/// Push the full canonical decomposition of `char` to `out`
+fn decompose(char: char, out: &mut Vec<char>) {
+    let code_point = char as u32;
+
+    if (S_BASE..S_BASE + S_COUNT).contains(&code_point) {
+        let s_index = code_point - S_BASE;
+        out.push(char::from_u32(L_BASE + s_index / N_COUNT).unwrap());
+        out.push(char::from_u32(V_BASE + (s_index % N_COUNT) / T_COUNT).unwrap());
+        let t_index = s_index % T_COUNT;
+        if t_index != 0 {
+            out.push(char::from_u32(T_BASE + t_index).unwrap());
+        }
+        return;
+    }
+
+    match CANONICAL_DECOMPOSITIONS.binary_search_by_key(&code_point, |(char, _)| *char) {
+        Ok(idx) => {
+            for char in CANONICAL_DECOMPOSITIONS[idx].1 {
+                decompose(char::from_u32(*char).unwrap(), out);
+            }
+        }
+        Err(_) => out.push(char),
+    }
+}
+
+/// Canonical decomposition (NFD) of a string: full decompositions of the characters, with runs
+/// of combining marks sorted by combining class
+fn nfd(chars: &[char]) -> Vec<char> {
+    let mut nfd: Vec<char> = vec![];
+    for char in chars {
+        decompose(*char, &mut nfd);
+    }
+
+    // Canonical ordering. Sorts are stable, so marks with the same class stay in order.
+    let mut run_start = 0;
+    for idx in 0..=nfd.len() {
+        if idx == nfd.len() || combining_class(nfd[idx]) == 0 {
+            nfd[run_start..idx].sort_by_key(|char| combining_class(*char));
+            run_start = idx + 1;
+        }
+    }
+
+    nfd
+}
+
+/// Split a string in NFD to units of starters and the combining marks after them. Starters are a
+/// single character, the jamo of a Hangul syllable, or empty for marks at the beginning of the
+/// string.
+fn units(nfd: &[char]) -> Vec<(Vec<char>, Vec<char>)> {
+    let mut units: Vec<(Vec<char>, Vec<char>)> = vec![];
+
+    let mut idx = 0;
+    while idx < nfd.len() {
+        let mut starters: Vec<char> = vec![];
+        if combining_class(nfd[idx]) == 0 {
+            starters.push(nfd[idx]);
+            idx += 1;
+            if is_hangul_l(starters[0]) && idx < nfd.len() && is_hangul_v(nfd[idx]) {
+                starters.push(nfd[idx]);
+                idx += 1;
+                if idx < nfd.len() && is_hangul_t(nfd[idx]) {
+                    starters.push(nfd[idx]);
+                    idx += 1;
+                }
+            }
+        }
+
+        let mut marks: Vec<char> = vec![];
+        while idx < nfd.len() && combining_class(nfd[idx]) != 0 {
+            marks.push(nfd[idx]);
+            idx += 1;
+        }
+
+        units.push((starters, marks));
+    }
+
+    units
+}
+
+/// Characters with a canonical decomposition, other than Hangul syllables, mapped from their full
+/// decompositions
+static COMPOSITIONS: OnceLock<Map<Vec<char>, Vec<char>>> = OnceLock::new();
+
+/// Characters with the full canonical decomposition `decomposition`
+fn chars_with_decomposition(decomposition: &[char]) -> Vec<char> {
+    let compositions = COMPOSITIONS.get_or_init(|| {
+        let mut compositions: Map<Vec<char>, Vec<char>> = Default::default();
+        for (char, _) in CANONICAL_DECOMPOSITIONS.iter() {
+            let char = char::from_u32(*char).unwrap();
+            compositions.entry(nfd(&[char])).or_default().push(char);
+        }
+        compositions
+    });
+
+    let mut chars: Vec<char> = compositions.get(decomposition).cloned().unwrap_or_default();
+    if decomposition.len() == 1 {
+        // Characters in NFD decompose to themselves
+        chars.push(decomposition[0]);
+    }
+    chars
+}
+
+/// Strings with the canonical decomposition `starters` followed by `marks`
+fn unit_equivalents(starters: &[char], marks: &[char]) -> Vec<String> {
+    // Ways to write the starters, with the marks that are not written yet
+    let mut prefixes: Vec<(String, Vec<char>)> = vec![];
+
+    match starters {
+        [] => prefixes.push((String::new(), marks.to_vec())),
+        [starter] => {
+            for (absorbed, rest) in mark_splits(marks) {
+                let mut decomposition = vec![*starter];
+                decomposition.extend(absorbed);
+                for char in chars_with_decomposition(&decomposition) {
+                    prefixes.push((char.to_string(), rest.clone()));
+                }
+            }
+        }
+        [l, v, t @ ..] => {
+            let t = t.first().copied();
+            let jamo: String = starters.iter().collect();
+            prefixes.push((jamo, marks.to_vec()));
+            let mut lv = hangul_syllable(*l, *v, None).to_string();
+            if let Some(t) = t {
+                prefixes.push((hangul_syllable(*l, *v, Some(t)).to_string(), marks.to_vec()));
+                lv.push(t);
+            }
+            prefixes.push((lv, marks.to_vec()));
+        }
+    }
+
+    let mut strings: Set<String> = Default::default();
+    for (prefix, rest) in prefixes {
+        for suffix in mark_equivalents(&rest) {
+            strings.insert(format!("{}{}", prefix, suffix));
+        }
+    }
+
+    let mut strings: Vec<String> = strings.into_iter().collect();
+    strings.sort();
+    strings
+}
+
+/// Strings of combining marks with the canonical decomposition `marks`
+fn mark_equivalents(marks: &[char]) -> Vec<String> {
+    if marks.is_empty() {
+        return vec![String::new()];
+    }
+
+    let mut strings: Vec<String> = vec![];
+    for (first, rest) in mark_splits(marks) {
+        if first.is_empty() {
+            continue;
+        }
+        for char in chars_with_decomposition(&first) {
+            for suffix in mark_equivalents(&rest) {
+                strings.push(format!("{}{}", char, suffix));
+            }
+        }
+    }
+    strings
+}
+
+/// Ways to split canonically ordered marks into marks that come first in a string, and the rest.
+/// Marks with the same combining class can't be reordered, so the first marks have a prefix of the
+/// marks of each combining class. Both parts are canonically ordered.
+fn mark_splits(marks: &[char]) -> Vec<(Vec<char>, Vec<char>)> {
+    // Marks are sorted by class, so marks of a class are a contiguous run
+    let mut classes: Vec<&[char]> = vec![];
+    let mut run_start = 0;
+    for idx in 1..=marks.len() {
+        if idx == marks.len() || combining_class(marks[idx]) != combining_class(marks[run_start]) {
+            classes.push(&marks[run_start..idx]);
+            run_start = idx;
+        }
+    }
+
+    let mut splits: Vec<(Vec<char>, Vec<char>)> = vec![(vec![], vec![])];
+    for class in classes {
+        splits = splits
+            .into_iter()
+            .flat_map(|(first, rest)| {
+                (0..=class.len()).map(move |n| {
+                    let mut first = first.clone();
+                    let mut rest = rest.clone();
+                    first.extend_from_slice(&class[..n]);
+                    rest.extend_from_slice(&class[n..]);
+                    (first, rest)
+                })
+            })
+            .collect();
+    }
+    splits
+}
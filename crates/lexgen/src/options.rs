@@ -0,0 +1,420 @@
+//! Lexer options, declared with `<name> = <value>;` syntax at the top level of a lexer definition
+
+use crate::ast::{
+    DriverStyle, Encoding, GeneratedType, Indentation, InvalidUtf8, LexerOption, LineTerminators,
+    MatchUnit, RangeChecks, RegexCtx, TieBreaking,
+};
+use crate::semantic_action_table::SemanticActionIdx;
+
+use proc_macro2::Span;
+
+#[derive(Debug, Default)]
+pub struct Options {
+    driver: Option<DriverStyle>,
+    const_fn: Option<bool>,
+    encoding: Option<Encoding>,
+    layout_profile: Option<syn::LitStr>,
+    range_checks: Option<RangeChecks>,
+    tie_breaking: Option<TieBreaking>,
+    ambiguity_warnings: Option<bool>,
+    statistics: Option<bool>,
+    snapshot: Option<String>,
+    // Span of the option name, for reporting uncovered characters
+    init_coverage: Option<(Encoding, Span)>,
+    case_insensitive: Option<bool>,
+    match_unit: Option<MatchUnit>,
+    normalization_insensitive: Option<bool>,
+    // Span of the option name, for reporting a missing `$$invalid_utf8` rule
+    invalid_utf8: Option<(InvalidUtf8, Span)>,
+    line_terminators: Option<LineTerminators>,
+    perl_classes: Option<Encoding>,
+    lalrpop: Option<bool>,
+    dfa_table: Option<bool>,
+    grammar_json: Option<bool>,
+    tag_rule_sets: Option<bool>,
+    next_token: Option<bool>,
+    token_kind: Option<bool>,
+    spanned_tokens: Option<bool>,
+    cancellation: Option<bool>,
+    counters: Option<bool>,
+    indentation: Option<Indentation>,
+    on_error: Option<syn::Path>,
+    type_names: Vec<(GeneratedType, syn::Ident)>,
+    // Span of the option name, used as the span of the skip rules
+    skip: Option<(Vec<RegexCtx>, SemanticActionIdx, Span)>,
+}
+
+impl Options {
+    pub fn set(&mut self, name: &syn::Ident, option: LexerOption) -> syn::Result<()> {
+        match option {
+            LexerOption::Driver(style) => {
+                if self.driver.replace(style).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::ConstFn(const_fn) => {
+                if self.const_fn.replace(const_fn).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::Encoding(encoding) => {
+                if self.encoding.replace(encoding).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::LayoutProfile(path) => {
+                if self.layout_profile.replace(path).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::RangeChecks(range_checks) => {
+                if self.range_checks.replace(range_checks).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::TieBreaking(tie_breaking) => {
+                if self.tie_breaking.replace(tie_breaking).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::AmbiguityWarnings(ambiguity_warnings) => {
+                if self
+                    .ambiguity_warnings
+                    .replace(ambiguity_warnings)
+                    .is_some()
+                {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::Statistics(statistics) => {
+                if self.statistics.replace(statistics).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::Snapshot(path) => {
+                if self.snapshot.replace(path).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::InitCoverage(encoding) => {
+                if self
+                    .init_coverage
+                    .replace((encoding, name.span()))
+                    .is_some()
+                {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::CaseInsensitive(case_insensitive) => {
+                if self.case_insensitive.replace(case_insensitive).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::MatchUnit(match_unit) => {
+                if self.match_unit.replace(match_unit).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::NormalizationInsensitive(normalization_insensitive) => {
+                if self
+                    .normalization_insensitive
+                    .replace(normalization_insensitive)
+                    .is_some()
+                {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::InvalidUtf8(invalid_utf8) => {
+                if self
+                    .invalid_utf8
+                    .replace((invalid_utf8, name.span()))
+                    .is_some()
+                {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::LineTerminators(line_terminators) => {
+                if self.line_terminators.replace(line_terminators).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::PerlClasses(perl_classes) => {
+                if self.perl_classes.replace(perl_classes).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::Lalrpop(lalrpop) => {
+                if self.lalrpop.replace(lalrpop).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+                if lalrpop && self.spanned_tokens() {
+                    return Err(spanned_tokens_lalrpop_error(name));
+                }
+            }
+            LexerOption::DfaTable(dfa_table) => {
+                if self.dfa_table.replace(dfa_table).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::GrammarJson(grammar_json) => {
+                if self.grammar_json.replace(grammar_json).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::TagRuleSets(tag_rule_sets) => {
+                if self.tag_rule_sets.replace(tag_rule_sets).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+                if tag_rule_sets && self.indentation.is_some() {
+                    return Err(tag_rule_sets_indentation_error(name));
+                }
+            }
+            LexerOption::NextToken(next_token) => {
+                if self.next_token.replace(next_token).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+                if next_token && self.indentation.is_some() {
+                    return Err(next_token_indentation_error(name));
+                }
+            }
+            LexerOption::TokenKind(token_kind) => {
+                if self.token_kind.replace(token_kind).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::SpannedTokens(spanned_tokens) => {
+                if self.spanned_tokens.replace(spanned_tokens).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+                if spanned_tokens && self.lalrpop() {
+                    return Err(spanned_tokens_lalrpop_error(name));
+                }
+            }
+            LexerOption::Cancellation(cancellation) => {
+                if self.cancellation.replace(cancellation).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::Counters(counters) => {
+                if self.counters.replace(counters).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::Indentation(indentation) => {
+                if self.indentation.replace(indentation).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+                if self.tag_rule_sets() {
+                    return Err(tag_rule_sets_indentation_error(name));
+                }
+                if self.next_token() {
+                    return Err(next_token_indentation_error(name));
+                }
+            }
+            LexerOption::OnError(hook) => {
+                if self.on_error.replace(hook).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+            LexerOption::TypeName(ty, type_name) => {
+                if self.type_names.iter().any(|(ty_, _)| *ty_ == ty) {
+                    return Err(duplicate_option_error(name));
+                }
+                self.type_names.push((ty, type_name));
+            }
+            LexerOption::Skip { regexes, action } => {
+                if self.skip.replace((regexes, action, name.span())).is_some() {
+                    return Err(duplicate_option_error(name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn driver(&self) -> DriverStyle {
+        self.driver.unwrap_or_default()
+    }
+
+    pub fn const_fn(&self) -> bool {
+        self.const_fn.unwrap_or(false)
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding.unwrap_or_default()
+    }
+
+    /// Path of the sample input used to order states in the generated code, relative to the
+    /// crate root
+    pub fn layout_profile(&self) -> Option<&syn::LitStr> {
+        self.layout_profile.as_ref()
+    }
+
+    pub fn range_checks(&self) -> RangeChecks {
+        self.range_checks.unwrap_or_default()
+    }
+
+    /// How a rule is chosen when multiple rules match the same longest input
+    pub fn tie_breaking(&self) -> TieBreaking {
+        self.tie_breaking.unwrap_or_default()
+    }
+
+    pub fn ambiguity_warnings(&self) -> bool {
+        self.ambiguity_warnings.unwrap_or(false)
+    }
+
+    pub fn statistics(&self) -> bool {
+        self.statistics.unwrap_or(false)
+    }
+
+    /// Characters that the `Init` rule set should have transitions for, with the span of the option
+    pub fn init_coverage(&self) -> Option<(Encoding, Span)> {
+        self.init_coverage
+    }
+
+    /// Whether characters in rules match all characters with the same simple case folding
+    pub fn case_insensitive(&self) -> bool {
+        self.case_insensitive.unwrap_or(false)
+    }
+
+    // Only used with the `unicode` feature
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    pub fn match_unit(&self) -> MatchUnit {
+        self.match_unit.unwrap_or_default()
+    }
+
+    /// Whether strings and characters in rules match all canonically equivalent strings
+    // Only used with the `unicode` feature
+    #[cfg_attr(not(feature = "unicode"), allow(dead_code))]
+    pub fn normalization_insensitive(&self) -> bool {
+        self.normalization_insensitive.unwrap_or(false)
+    }
+
+    /// How lexers created with `new_from_bytes` handle invalid UTF-8, with the span of the option.
+    /// Lexers don't have `new_from_bytes` constructors when this is `None`.
+    pub fn invalid_utf8(&self) -> Option<(InvalidUtf8, Span)> {
+        self.invalid_utf8
+    }
+
+    pub fn line_terminators(&self) -> LineTerminators {
+        self.line_terminators.unwrap_or_default()
+    }
+
+    /// Whether `$$d`, `$$w`, and `$$s` match ASCII or Unicode characters
+    pub fn perl_classes(&self) -> Encoding {
+        self.perl_classes.unwrap_or_default()
+    }
+
+    /// Whether to generate type aliases for LALRPOP parsers, and write an `extern` block for the
+    /// LALRPOP grammar to `OUT_DIR`
+    pub fn lalrpop(&self) -> bool {
+        self.lalrpop.unwrap_or(false)
+    }
+
+    /// Whether to generate a `dfa_table` function that returns the DFA as a
+    /// `lexgen_util::DfaTable`
+    pub fn dfa_table(&self) -> bool {
+        self.dfa_table.unwrap_or(false)
+    }
+
+    /// Whether to write a JSON description of the lexer to `OUT_DIR`
+    pub fn grammar_json(&self) -> bool {
+        self.grammar_json.unwrap_or(false)
+    }
+
+    /// Whether to yield tokens tagged with the rule set they were matched in, as
+    /// `lexgen_util::Tagged`
+    pub fn tag_rule_sets(&self) -> bool {
+        self.tag_rule_sets.unwrap_or(false)
+    }
+
+    /// Whether to generate a `next_token` function that lexes one token at the location of a
+    /// `lexgen_util::CharCursor`
+    pub fn next_token(&self) -> bool {
+        self.next_token.unwrap_or(false)
+    }
+
+    /// Whether to generate a `<lexer name>TokenKind` enum with a variant for each token returned by
+    /// simple rules
+    pub fn token_kind(&self) -> bool {
+        self.token_kind.unwrap_or(false)
+    }
+
+    /// Whether to yield tokens as `lexgen_util::Spanned` instead of `(start, token, end)` tuples
+    pub fn spanned_tokens(&self) -> bool {
+        self.spanned_tokens.unwrap_or(false)
+    }
+
+    /// Whether to check a cancellation flag before each token
+    pub fn cancellation(&self) -> bool {
+        self.cancellation.unwrap_or(false)
+    }
+
+    /// Whether to count tokens, bytes, and backtracking at runtime, returned by the `stats` method
+    pub fn counters(&self) -> bool {
+        self.counters.unwrap_or(false)
+    }
+
+    /// Tokens to generate from indentation of lines. Lexers don't track indentation when this is
+    /// `None`.
+    pub fn indentation(&self) -> Option<&Indentation> {
+        self.indentation.as_ref()
+    }
+
+    /// Function to call with errors before they're returned by the lexer
+    pub fn on_error(&self) -> Option<&syn::Path> {
+        self.on_error.as_ref()
+    }
+
+    /// Name of a type generated for the lexer, `<lexer name><suffix>` unless renamed with an option
+    pub fn type_name(&self, ty: GeneratedType, lexer_name: &syn::Ident) -> syn::Ident {
+        match self.type_names.iter().find(|(ty_, _)| *ty_ == ty) {
+            Some((_, name)) => name.clone(),
+            None => syn::Ident::new(
+                &(lexer_name.to_string() + ty.default_suffix()),
+                lexer_name.span(),
+            ),
+        }
+    }
+
+    /// Regexes of the `skip` option, with the semantic action and span of the skip rules. The rules
+    /// are added to the end of all rule sets without the `exclusive` modifier.
+    pub fn skip(&self) -> Option<(&[RegexCtx], SemanticActionIdx, Span)> {
+        self.skip
+            .as_ref()
+            .map(|(regexes, action, span)| (regexes.as_slice(), *action, *span))
+    }
+
+    /// Path of the DFA snapshot of the lexer, relative to the crate root
+    pub fn snapshot(&self) -> Option<&str> {
+        self.snapshot.as_deref()
+    }
+}
+
+fn duplicate_option_error(name: &syn::Ident) -> syn::Error {
+    syn::Error::new(
+        name.span(),
+        format!("Option \"{}\" is defined multiple times", name),
+    )
+}
+
+fn tag_rule_sets_indentation_error(name: &syn::Ident) -> syn::Error {
+    syn::Error::new(
+        name.span(),
+        "`tag_rule_sets = true;` is not supported with `indentation`",
+    )
+}
+
+fn next_token_indentation_error(name: &syn::Ident) -> syn::Error {
+    syn::Error::new(
+        name.span(),
+        "`next_token = true;` is not supported with `indentation`",
+    )
+}
+
+fn spanned_tokens_lalrpop_error(name: &syn::Ident) -> syn::Error {
+    syn::Error::new(
+        name.span(),
+        "`spanned_tokens = true;` is not supported with `lalrpop = true;`, LALRPOP parsers take \
+        `(start, token, end)` tuples",
+    )
+}
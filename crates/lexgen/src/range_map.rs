@@ -53,6 +53,25 @@ impl<A> RangeMap<A> {
         self.ranges.iter()
     }
 
+    /// Value of the range that contains `char`.
+    ///
+    /// O(log n)
+    pub fn get(&self, char: char) -> Option<&A> {
+        let char = char as u32;
+        self.ranges
+            .binary_search_by(|range| {
+                if range.end < char {
+                    Ordering::Less
+                } else if range.start > char {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|idx| &self.ranges[idx].value)
+    }
+
     pub fn into_iter(self) -> impl Iterator<Item = Range<A>> {
         self.ranges.into_iter()
     }
@@ -191,6 +210,43 @@ impl<A: Clone> RangeMap<A> {
         self.ranges = new_ranges;
     }
 
+    /// Insert ranges in any order, possibly overlapping each other. Same as inserting the ranges
+    /// one by one with `insert`, without the quadratic cost.
+    ///
+    /// O(N+M) where N is the number of current ranges and M is the number of inserted ranges when
+    /// the inserted ranges are sorted and non-overlapping, O(N + M log M) otherwise.
+    pub fn insert_many<F>(&mut self, ranges: Vec<Range<A>>, merge: F)
+    where
+        F: Fn(&mut A, A),
+    {
+        let inserted = RangeMap::from_ranges(ranges, &merge);
+        if self.ranges.is_empty() {
+            self.ranges = inserted.ranges;
+        } else {
+            self.insert_ranges(inserted.into_iter(), merge);
+        }
+    }
+
+    /// Build a range map from ranges in any order. Ranges are sorted, and overlapping ranges are
+    /// merged by merging maps of the two halves of the ranges.
+    fn from_ranges<F>(mut ranges: Vec<Range<A>>, merge: &F) -> RangeMap<A>
+    where
+        F: Fn(&mut A, A),
+    {
+        if !ranges.windows(2).all(|pair| pair[0].start <= pair[1].start) {
+            ranges.sort_by_key(|range| range.start);
+        }
+
+        if ranges.windows(2).all(|pair| pair[0].end < pair[1].start) {
+            return RangeMap { ranges };
+        }
+
+        let second_half = ranges.split_off(ranges.len() / 2);
+        let mut map = RangeMap::from_ranges(ranges, merge);
+        map.insert_ranges(RangeMap::from_ranges(second_half, merge).into_iter(), merge);
+        map
+    }
+
     /// O(n) where n is the number of existing ranges in the map
     pub fn insert<F>(&mut self, mut new_range_start: u32, new_range_end: u32, value: A, merge: F)
     where
@@ -312,7 +368,10 @@ impl<A: Clone> RangeMap<A> {
                         let overlap = max(old_range_.start, removed_range_.start)
                             ..=min(old_range_.end, removed_range_.end);
 
-                        // Three cases to consider:
+                        // Four cases to consider:
+                        //
+                        // (0) overlap is the whole old range:
+                        //     increment A (B may overlap with the next old range)
                         //
                         // (1) overlap starts from the left end of old range:
                         //     update A, increment B
@@ -323,8 +382,13 @@ impl<A: Clone> RangeMap<A> {
                         // (3) overlap is in the middle of old range:
                         //     push left of overlap, update A
 
+                        // (0)
+                        if *overlap.start() == old_range_.start && *overlap.end() == old_range_.end
+                        {
+                            old_range = old_ranges_iter.next();
+                        }
                         // (1)
-                        if *overlap.start() == old_range_.start {
+                        else if *overlap.start() == old_range_.start {
                             old_range_.start = *overlap.end() + 1;
                             removed_range = removed_ranges_iter.next();
                         }
@@ -380,7 +444,7 @@ fn insert<A: Clone>(map: &mut RangeMap<Vec<A>>, range_start: u32, range_end: u32
     map2.insert(range_start, range_end, vec![value], |_, _| panic!());
 
     map.insert_ranges(map2.into_iter(), |values_1, values_2| {
-        values_1.extend(values_2.into_iter())
+        values_1.extend(values_2)
     });
 }
 
@@ -659,3 +723,101 @@ fn remove_overlap_middle() {
         ]
     );
 }
+
+#[test]
+fn remove_overlap_multiple() {
+    let mut ranges: RangeMap<Vec<u32>> = RangeMap::new();
+
+    insert(&mut ranges, 10, 20, 0);
+    insert(&mut ranges, 30, 40, 1);
+    insert(&mut ranges, 50, 60, 2);
+
+    remove(&mut ranges, &[(0, 45)]);
+
+    assert_eq!(to_vec(&ranges), vec![(50, 60, vec![2])]);
+
+    remove(&mut ranges, &[(50, 60)]);
+
+    assert_eq!(to_vec(&ranges), vec![]);
+}
+
+#[test]
+fn insert_many() {
+    let range = |start: u32, end: u32, value: u32| Range {
+        start,
+        end,
+        value: vec![value],
+    };
+
+    // Same as inserting one by one
+    let inserted = vec![
+        range(30, 40, 0),
+        range(5, 15, 1),
+        range(10, 20, 2),
+        range(1, 1, 3),
+        range(12, 35, 4),
+        range(10, 20, 5),
+    ];
+
+    let mut ranges: RangeMap<Vec<u32>> = RangeMap::new();
+    insert(&mut ranges, 8, 11, 6);
+    for range in &inserted {
+        insert(&mut ranges, range.start, range.end, range.value[0]);
+    }
+
+    let mut ranges_many: RangeMap<Vec<u32>> = RangeMap::new();
+    insert(&mut ranges_many, 8, 11, 6);
+    ranges_many.insert_many(inserted, |values_1, values_2| values_1.extend(values_2));
+
+    // Values of overlapping parts can be merged in a different order
+    let sorted = |map: &RangeMap<Vec<u32>>| -> Vec<(u32, u32, Vec<u32>)> {
+        to_vec(map)
+            .into_iter()
+            .map(|(start, end, mut values)| {
+                values.sort_unstable();
+                (start, end, values)
+            })
+            .collect()
+    };
+
+    assert_eq!(sorted(&ranges_many), sorted(&ranges));
+    assert_eq!(
+        sorted(&ranges_many),
+        vec![
+            (1, 1, vec![3]),
+            (5, 7, vec![1]),
+            (8, 9, vec![1, 6]),
+            (10, 11, vec![1, 2, 5, 6]),
+            (12, 15, vec![1, 2, 4, 5]),
+            (16, 20, vec![2, 4, 5]),
+            (21, 29, vec![4]),
+            (30, 35, vec![0, 4]),
+            (36, 40, vec![0]),
+        ]
+    );
+}
+
+#[test]
+fn get() {
+    let range = |start: char, end: char, value: u32| Range {
+        start: start as u32,
+        end: end as u32,
+        value,
+    };
+
+    let ranges = RangeMap::from_non_overlapping_sorted_ranges(vec![
+        range('b', 'd', 0),
+        range('f', 'f', 1),
+        range('x', 'z', 2),
+    ]);
+
+    assert_eq!(ranges.get('a'), None);
+    assert_eq!(ranges.get('b'), Some(&0));
+    assert_eq!(ranges.get('c'), Some(&0));
+    assert_eq!(ranges.get('d'), Some(&0));
+    assert_eq!(ranges.get('e'), None);
+    assert_eq!(ranges.get('f'), Some(&1));
+    assert_eq!(ranges.get('g'), None);
+    assert_eq!(ranges.get('y'), Some(&2));
+    assert_eq!(ranges.get('{'), None);
+}
@@ -0,0 +1,227 @@
+//! Simplification of regexes before compiling them to NFAs
+//!
+//! Regexes are simplified to make NFAs smaller, which makes determinization faster, especially in
+//! lexers with many keywords in alternations, like `"if" | "in" | "int" | ...`:
+//!
+//! - Characters and character sets in alternations are merged into one character set.
+//! - Characters and strings in concatenations are merged into one string.
+//! - Common prefixes of strings in alternations are hoisted: `"if" | "in" | "int"` becomes
+//!   `'i' ('f' | 'n' 't'?)`.
+//!
+//! Simplified regexes match the same inputs as the original regexes. Variables are not expanded,
+//! and character set differences (`#`) are kept as they are.
+
+use crate::ast::{CharOrRange, CharSet, Regex};
+
+pub fn simplify(re: &Regex) -> Regex {
+    match re {
+        Regex::Builtin(_)
+        | Regex::Var(_)
+        | Regex::Char(_)
+        | Regex::String(_)
+        | Regex::CharSet(_)
+        | Regex::Any
+        | Regex::EndOfInput
+        | Regex::Diff(_, _) => re.clone(),
+        Regex::ZeroOrMore(re) => Regex::ZeroOrMore(Box::new(simplify(re))),
+        Regex::OneOrMore(re) => Regex::OneOrMore(Box::new(simplify(re))),
+        Regex::ZeroOrOne(re) => Regex::ZeroOrOne(Box::new(simplify(re))),
+        Regex::Capture(re, name) => Regex::Capture(Box::new(simplify(re)), name.clone()),
+        Regex::Concat(_, _) => simplify_concat(re),
+        Regex::Or(_, _) => simplify_or(re),
+    }
+}
+
+fn simplify_concat(re: &Regex) -> Regex {
+    let mut items: Vec<&Regex> = vec![];
+    flatten(re, &mut items, &|re| match re {
+        Regex::Concat(re1, re2) => Some((re1, re2)),
+        _ => None,
+    });
+
+    let mut simplified: Vec<Regex> = vec![];
+
+    for item in items {
+        let item = simplify(item);
+        let string = match &item {
+            Regex::Char(char) => char.to_string(),
+            Regex::String(string) => string.clone(),
+            _ => {
+                simplified.push(item);
+                continue;
+            }
+        };
+        // Characters are added as strings, and converted back to characters below
+        match simplified.last_mut() {
+            Some(Regex::String(last)) => last.push_str(&string),
+            _ => simplified.push(Regex::String(string)),
+        }
+    }
+
+    simplified
+        .into_iter()
+        .map(single_char_string)
+        .reduce(|re1, re2| Regex::Concat(Box::new(re1), Box::new(re2)))
+        .unwrap()
+}
+
+fn simplify_or(re: &Regex) -> Regex {
+    let mut alternatives: Vec<&Regex> = vec![];
+    flatten(re, &mut alternatives, &|re| match re {
+        Regex::Or(re1, re2) => Some((re1, re2)),
+        _ => None,
+    });
+
+    // Order of alternatives doesn't matter, as alternatives of a regex are all in the same rule.
+    // Merged character sets and strings are added in the position of the first character set and
+    // string, respectively.
+    let mut simplified: Vec<Regex> = vec![];
+    let mut chars: Option<(usize, Vec<CharOrRange>)> = None;
+    let mut strings: Option<(usize, Vec<String>)> = None;
+
+    for alternative in alternatives {
+        match simplify(alternative) {
+            Regex::Char(char) => chars
+                .get_or_insert_with(|| (simplified.len(), vec![]))
+                .1
+                .push(CharOrRange::Char(char)),
+            Regex::CharSet(CharSet(set)) => chars
+                .get_or_insert_with(|| (simplified.len(), vec![]))
+                .1
+                .extend(set),
+            Regex::String(string) => strings
+                .get_or_insert_with(|| (simplified.len(), vec![]))
+                .1
+                .push(string),
+            alternative => simplified.push(alternative),
+        }
+    }
+
+    // Insert the merged alternative that comes later first, so that the index of the other one is
+    // still valid
+    let mut merged: Vec<(usize, Regex)> = vec![];
+    if let Some((idx, chars)) = chars {
+        merged.push((idx, char_set(chars)));
+    }
+    if let Some((idx, strings)) = strings {
+        merged.push((idx, hoist_prefixes(strings)));
+    }
+    merged.sort_by_key(|(idx, _)| std::cmp::Reverse(*idx));
+    for (idx, re) in merged {
+        simplified.insert(idx, re);
+    }
+
+    simplified
+        .into_iter()
+        .reduce(|re1, re2| Regex::Or(Box::new(re1), Box::new(re2)))
+        .unwrap()
+}
+
+/// Alternation of `strings`, with common prefixes of the strings hoisted out of the alternation
+fn hoist_prefixes(mut strings: Vec<String>) -> Regex {
+    strings.dedup();
+
+    let has_empty = strings.iter().any(|string| string.is_empty());
+
+    // Non-empty strings grouped by their first characters, in the order they first appear
+    let mut groups: Vec<(char, Vec<String>)> = vec![];
+    for string in strings {
+        let first = match string.chars().next() {
+            Some(first) => first,
+            None => continue,
+        };
+        match groups.iter_mut().find(|(char, _)| *char == first) {
+            Some((_, group)) => group.push(string),
+            None => groups.push((first, vec![string])),
+        }
+    }
+
+    // Single characters are merged into a character set, in the position of the first one
+    let mut alternatives: Vec<Regex> = vec![];
+    let mut chars: Option<(usize, Vec<CharOrRange>)> = None;
+
+    for (first, mut group) in groups {
+        if group.len() == 1 && group[0].len() == first.len_utf8() {
+            chars
+                .get_or_insert_with(|| (alternatives.len(), vec![]))
+                .1
+                .push(CharOrRange::Char(first));
+            continue;
+        }
+        if group.len() == 1 {
+            alternatives.push(Regex::String(group.pop().unwrap()));
+            continue;
+        }
+        let prefix_len = common_prefix_len(&group);
+        let prefix = group[0][..prefix_len].to_owned();
+        let rests = group
+            .into_iter()
+            .map(|string| string[prefix_len..].to_owned())
+            .collect();
+        alternatives.push(Regex::Concat(
+            Box::new(single_char_string(Regex::String(prefix))),
+            Box::new(hoist_prefixes(rests)),
+        ));
+    }
+
+    if let Some((idx, chars)) = chars {
+        alternatives.insert(idx, char_set(chars));
+    }
+
+    match alternatives
+        .into_iter()
+        .reduce(|re1, re2| Regex::Or(Box::new(re1), Box::new(re2)))
+    {
+        None => Regex::String(String::new()),
+        Some(re) if has_empty => Regex::ZeroOrOne(Box::new(re)),
+        Some(re) => re,
+    }
+}
+
+/// Length of the common prefix of `strings` in bytes, at a character boundary
+fn common_prefix_len(strings: &[String]) -> usize {
+    let (first, rest) = strings.split_first().unwrap();
+    let mut len = first.len();
+    for string in rest {
+        len = first
+            .char_indices()
+            .zip(string.chars())
+            .take_while(|((_, char1), char2)| char1 == char2)
+            .map(|((idx, char), _)| idx + char.len_utf8())
+            .last()
+            .unwrap_or(0)
+            .min(len);
+    }
+    len
+}
+
+/// Character set of `chars`, or a character when there's only one
+fn char_set(chars: Vec<CharOrRange>) -> Regex {
+    match chars.as_slice() {
+        [CharOrRange::Char(char)] => Regex::Char(*char),
+        _ => Regex::CharSet(CharSet(chars)),
+    }
+}
+
+fn single_char_string(re: Regex) -> Regex {
+    match re {
+        Regex::String(string) if string.chars().count() == 1 => {
+            Regex::Char(string.chars().next().unwrap())
+        }
+        re => re,
+    }
+}
+
+/// Collect operands of nested binary regexes split by `split`, left to right
+fn flatten<'a, F>(re: &'a Regex, items: &mut Vec<&'a Regex>, split: &F)
+where
+    F: Fn(&'a Regex) -> Option<(&'a Regex, &'a Regex)>,
+{
+    match split(re) {
+        Some((re1, re2)) => {
+            flatten(re1, items, split);
+            flatten(re2, items, split);
+        }
+        None => items.push(re),
+    }
+}
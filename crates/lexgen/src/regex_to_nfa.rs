@@ -1,9 +1,18 @@
-use crate::ast::{Builtin, CharOrRange, Regex, Var};
-use crate::builtin::{BuiltinCharRange, BUILTIN_RANGES};
+use crate::ast::{Builtin, CharOrRange, CharSet, Regex, Var};
+use crate::builtin::BUILTIN_RANGES;
 use crate::collections::Map;
 use crate::nfa::{StateIdx, NFA};
 use crate::range_map::{Range, RangeMap};
+use crate::unicode_properties;
 
+use std::borrow::Cow;
+
+/// Add states and transitions for `re` to `nfa`, from `current` to `cont`.
+///
+/// Structurally equal sub-regexes (e.g. uses of a binding) with the same continuation share NFA
+/// states: the first occurrence is compiled as usual, later occurrences add an empty transition
+/// to the initial state of the first one. This is common in alternations with a common suffix,
+/// like `"0x" $hex_digit+ $int_suffix | $digit+ $int_suffix`.
 pub fn add_re<A>(
     nfa: &mut NFA<A>,
     bindings: &Map<Var, Regex>,
@@ -11,12 +20,44 @@ pub fn add_re<A>(
     current: StateIdx,
     cont: StateIdx,
 ) {
-    match re {
-        Regex::Builtin(builtin_name) => {
-            let builtin = get_builtin_regex(builtin_name);
+    add_re_shared(nfa, bindings, &mut Default::default(), re, current, cont)
+}
+
+/// Initial states of the compiled sub-regexes, by the sub-regexes and their continuations.
+///
+/// The transitions from the initial state of a sub-regex are all added for the sub-regex, and
+/// the states it creates only have transitions to each other and to the continuation. A state
+/// that transitions to the initial state with an empty transition matches the same inputs as the
+/// initial state.
+type SharedStates<'a> = Map<(&'a Regex, StateIdx), StateIdx>;
+
+fn add_re_shared<'a, A>(
+    nfa: &mut NFA<A>,
+    bindings: &'a Map<Var, Regex>,
+    shared: &mut SharedStates<'a>,
+    re: &'a Regex,
+    current: StateIdx,
+    cont: StateIdx,
+) {
+    if is_shareable(bindings, re) {
+        match shared.get(&(re, cont)) {
+            Some(shared_initial) => {
+                nfa.add_empty_transition(current, *shared_initial);
+                return;
+            }
+            None => {
+                shared.insert((re, cont), current);
+            }
+        }
+    }
 
-            let ranges: Vec<Range<()>> = builtin
-                .get_ranges()
+    let mut add_re = |nfa: &mut NFA<A>, re: &'a Regex, current: StateIdx, cont: StateIdx| {
+        add_re_shared(nfa, bindings, shared, re, current, cont)
+    };
+
+    match re {
+        Regex::Builtin(builtin) => {
+            let ranges: Vec<Range<()>> = builtin_ranges(builtin)
                 .iter()
                 .copied()
                 .map(|(start, end)| Range {
@@ -36,7 +77,7 @@ pub fn add_re<A>(
                 .get(var)
                 .unwrap_or_else(|| panic!("Unbound variable {:?}", var.0));
 
-            add_re(nfa, bindings, re, current, cont);
+            add_re(nfa, re, current, cont);
         }
 
         Regex::Char(char) => {
@@ -44,6 +85,11 @@ pub fn add_re<A>(
         }
 
         Regex::String(str) => {
+            if str.is_empty() {
+                nfa.add_empty_transition(current, cont);
+                return;
+            }
+
             let mut iter = str.chars().peekable();
             let mut current = current;
             while let Some(char) = iter.next() {
@@ -58,22 +104,13 @@ pub fn add_re<A>(
         }
 
         Regex::CharSet(set) => {
-            for char in &set.0 {
-                match char {
-                    CharOrRange::Char(char) => {
-                        nfa.add_char_transition(current, *char, cont);
-                    }
-                    CharOrRange::Range(range_start, range_end) => {
-                        nfa.add_range_transition(current, *range_start, *range_end, cont);
-                    }
-                }
-            }
+            nfa.add_range_transitions(current, char_set_range_map(set), cont);
         }
 
         Regex::ZeroOrMore(re) => {
             let re_init = nfa.new_state();
             let re_cont = nfa.new_state();
-            add_re(nfa, bindings, re, re_init, re_cont);
+            add_re(nfa, re, re_init, re_cont);
             nfa.add_empty_transition(current, cont);
             nfa.add_empty_transition(current, re_init);
             nfa.add_empty_transition(re_cont, cont);
@@ -83,7 +120,7 @@ pub fn add_re<A>(
         Regex::OneOrMore(re) => {
             let re_init = nfa.new_state();
             let re_cont = nfa.new_state();
-            add_re(nfa, bindings, re, re_init, re_cont);
+            add_re(nfa, re, re_init, re_cont);
             nfa.add_empty_transition(current, re_init);
             nfa.add_empty_transition(re_cont, cont);
             nfa.add_empty_transition(re_cont, re_init);
@@ -91,22 +128,22 @@ pub fn add_re<A>(
 
         Regex::ZeroOrOne(re) => {
             let re_init = nfa.new_state();
-            add_re(nfa, bindings, re, re_init, cont);
+            add_re(nfa, re, re_init, cont);
             nfa.add_empty_transition(current, cont);
             nfa.add_empty_transition(current, re_init);
         }
 
         Regex::Concat(re1, re2) => {
             let re1_cont = nfa.new_state();
-            add_re(nfa, bindings, re1, current, re1_cont);
-            add_re(nfa, bindings, re2, re1_cont, cont);
+            add_re(nfa, re1, current, re1_cont);
+            add_re(nfa, re2, re1_cont, cont);
         }
 
         Regex::Or(re1, re2) => {
             let re1_init = nfa.new_state();
             let re2_init = nfa.new_state();
-            add_re(nfa, bindings, re1, re1_init, cont);
-            add_re(nfa, bindings, re2, re2_init, cont);
+            add_re(nfa, re1, re1_init, cont);
+            add_re(nfa, re2, re2_init, cont);
             nfa.add_empty_transition(current, re1_init);
             nfa.add_empty_transition(current, re2_init);
         }
@@ -123,28 +160,66 @@ pub fn add_re<A>(
             let map = regex_to_range_map(bindings, re);
             nfa.add_range_transitions(current, map, cont);
         }
+
+        // Capture boundaries are found after matching, see `compile::capture_parts`
+        Regex::Capture(re, _) => add_re(nfa, re, current, cont),
     }
 }
 
-fn get_builtin_regex(builtin: &Builtin) -> BuiltinCharRange {
+/// Whether to share NFA states of the regex. Regexes compiled to a single transition are not
+/// shared, as an empty transition to a shared state is not smaller.
+fn is_shareable(bindings: &Map<Var, Regex>, re: &Regex) -> bool {
+    match re {
+        Regex::Builtin(_)
+        | Regex::Char(_)
+        | Regex::CharSet(_)
+        | Regex::Any
+        | Regex::EndOfInput
+        | Regex::Diff(_, _)
+        | Regex::Capture(_, _) => false,
+        Regex::String(str) => str.chars().nth(1).is_some(),
+        Regex::Var(var) => bindings
+            .get(var)
+            .is_some_and(|re| is_shareable(bindings, re)),
+        Regex::ZeroOrMore(_)
+        | Regex::OneOrMore(_)
+        | Regex::ZeroOrOne(_)
+        | Regex::Concat(_, _)
+        | Regex::Or(_, _) => true,
+    }
+}
+
+/// Sorted, non-overlapping ranges of a built-in regex. Unicode properties (`$$p{<name>}`) are
+/// stored as `p{<name>}`.
+fn builtin_ranges(builtin: &Builtin) -> Cow<'static, [(u32, u32)]> {
+    if let Some(property) = builtin
+        .0
+        .strip_prefix("p{")
+        .and_then(|name| name.strip_suffix('}'))
+    {
+        return Cow::Owned(
+            unicode_properties::property_ranges(property)
+                .unwrap_or_else(|| panic!("Unknown Unicode property: {}", property)),
+        );
+    }
+
     BUILTIN_RANGES
         .iter()
         .find_map(|(name, builtin_)| {
             if *name == builtin.0 {
-                Some(*builtin_)
+                Some(builtin_.get_ranges())
             } else {
                 None
             }
         })
+        .map(Cow::Borrowed)
         .unwrap_or_else(|| panic!("Unknown builtin regex: {}", builtin.0))
 }
 
 fn regex_to_range_map(bindings: &Map<Var, Regex>, re: &Regex) -> RangeMap<()> {
     match re {
         Regex::Builtin(builtin) => {
-            let builtin = get_builtin_regex(builtin);
-            let ranges: Vec<Range<()>> = builtin
-                .get_ranges()
+            let ranges: Vec<Range<()>> = builtin_ranges(builtin)
                 .iter()
                 .copied()
                 .map(|(start, end)| Range {
@@ -172,23 +247,7 @@ fn regex_to_range_map(bindings: &Map<Var, Regex>, re: &Regex) -> RangeMap<()> {
 
         Regex::String(_) => panic!("strings cannot be used in char sets (`#`)"),
 
-        Regex::CharSet(char_set) => {
-            let mut map = RangeMap::new();
-
-            // TODO: Quadratic behavior below, `RangeMap::insert` is O(number of ranges)
-            for char_or_range in char_set.0.iter() {
-                match char_or_range {
-                    CharOrRange::Char(char) => {
-                        map.insert(*char as u32, *char as u32, (), merge_values);
-                    }
-                    CharOrRange::Range(start, end) => {
-                        map.insert(*start as u32, *end as u32, (), merge_values);
-                    }
-                }
-            }
-
-            map
-        }
+        Regex::CharSet(char_set) => char_set_range_map(char_set),
 
         Regex::ZeroOrMore(_) => {
             panic!("`*` cannot be used in char sets (`#`)");
@@ -223,6 +282,8 @@ fn regex_to_range_map(bindings: &Map<Var, Regex>, re: &Regex) -> RangeMap<()> {
 
         Regex::EndOfInput => panic!("`$` cannot be used in char sets (`#`)"),
 
+        Regex::Capture(_, _) => panic!("Captures cannot be used in char sets (`#`)"),
+
         Regex::Diff(re1, re2) => {
             let mut map1 = regex_to_range_map(bindings, re1);
             let map2 = regex_to_range_map(bindings, re2);
@@ -232,4 +293,27 @@ fn regex_to_range_map(bindings: &Map<Var, Regex>, re: &Regex) -> RangeMap<()> {
     }
 }
 
+/// Ranges of the characters in a character set, inserted at once as character sets can be large
+fn char_set_range_map(char_set: &CharSet) -> RangeMap<()> {
+    let ranges: Vec<Range<()>> = char_set
+        .0
+        .iter()
+        .map(|char_or_range| {
+            let (start, end) = match char_or_range {
+                CharOrRange::Char(char) => (*char, *char),
+                CharOrRange::Range(start, end) => (*start, *end),
+            };
+            Range {
+                start: start as u32,
+                end: end as u32,
+                value: (),
+            }
+        })
+        .collect();
+
+    let mut map = RangeMap::new();
+    map.insert_many(ranges, merge_values);
+    map
+}
+
 fn merge_values(_val1: &mut (), _val2: ()) {}
@@ -41,19 +41,28 @@ impl<A> RightCtxDFAs<A> {
 }
 
 impl RightCtxDFAs<StateIdx> {
-    pub fn new_right_ctx(&mut self, bindings: &Map<Var, Regex>, right_ctx: &Regex) -> RightCtxIdx {
+    pub fn new_right_ctx(
+        &mut self,
+        bindings: &Map<Var, Regex>,
+        right_ctx: &Regex,
+        case_insensitive: bool,
+    ) -> RightCtxIdx {
         let idx = self.dfas.len();
 
         let mut nfa: NFA<()> = NFA::new();
         nfa.add_regex(bindings, right_ctx, None, ());
 
+        if case_insensitive {
+            nfa.case_fold();
+        }
+
         let dfa = nfa_to_dfa(&nfa);
         self.dfas.push(dfa);
 
         RightCtxIdx(idx)
     }
 
-    #[cfg(test)]
+    #[cfg_attr(not(test), allow(dead_code))]
     pub fn get(&self, right_ctx: &RightCtxIdx) -> &DFA<StateIdx, ()> {
         &self.dfas[right_ctx.as_usize()]
     }
@@ -1,11 +1,33 @@
 use crate::ast::{CharOrRange, CharSet, Regex, Var};
 use crate::collections::Map;
+use crate::dfa::analysis::{conflicts, matched_values};
 use crate::dfa::StateIdx as DfaStateIdx;
 use crate::nfa::simulate::{ErrorLoc, Matches};
 use crate::nfa::NFA;
-use crate::nfa_to_dfa::nfa_to_dfa;
+use crate::nfa_to_dfa::{nfa_to_dfa, nfas_to_dfas};
 use crate::right_ctx::RightCtxDFAs;
 
+/// Parses a lexer definition that should fail to parse, and returns the error
+fn parse_error(lexer: &str) -> syn::Error {
+    use crate::ast::make_lexer_parser;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    match make_lexer_parser(&mut SemanticActionTable::new()).parse_str(lexer) {
+        Ok(_) => panic!("Lexer parsed without errors: {}", lexer),
+        Err(error) => error,
+    }
+}
+
+/// Generates a lexer from a definition that should fail to compile, and returns the error
+fn compile_error(lexer: &str) -> syn::Error {
+    match crate::lexer_(lexer.parse().unwrap()) {
+        Ok(_) => panic!("Lexer compiled without errors: {}", lexer),
+        Err(error) => error,
+    }
+}
+
 fn test_simulate<'input, A: Copy + std::fmt::Debug + Eq>(
     nfa: &NFA<A>,
     test_cases: Vec<(&'input str, Matches<'input, A>, Option<ErrorLoc>)>,
@@ -382,6 +404,60 @@ fn simulate_variables() {
     );
 }
 
+#[test]
+fn shared_sub_regexes() {
+    let mut bindings: Map<Var, Regex> = Default::default();
+    bindings.insert(
+        Var("digits1".to_owned()),
+        Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range(
+            '0', '9',
+        )])))),
+    );
+    // Same as `digits1`, but not structurally equal
+    bindings.insert(
+        Var("digits2".to_owned()),
+        Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![
+            CharOrRange::Char('0'),
+            CharOrRange::Range('1', '9'),
+        ])))),
+    );
+
+    // `'a' $digits1 | 'b' $digits<n>`
+    let re = |var: &str| {
+        Regex::Or(
+            Box::new(Regex::Concat(
+                Box::new(Regex::Char('a')),
+                Box::new(Regex::Var(Var("digits1".to_owned()))),
+            )),
+            Box::new(Regex::Concat(
+                Box::new(Regex::Char('b')),
+                Box::new(Regex::Var(Var(var.to_owned()))),
+            )),
+        )
+    };
+
+    let mut shared_nfa: NFA<usize> = NFA::new();
+    shared_nfa.add_regex(&bindings, &re("digits1"), None, 1);
+
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(&bindings, &re("digits2"), None, 1);
+
+    // The second `$digits1` is an empty transition to the states of the first one
+    assert!(shared_nfa.num_states() < nfa.num_states());
+
+    for nfa in [&shared_nfa, &nfa] {
+        test_simulate(
+            nfa,
+            vec![
+                ("a1", vec![("a1", 1)], None),
+                ("b12", vec![("b12", 1)], None),
+                ("ab", vec![], Some(0)),
+                ("c", vec![], Some(0)),
+            ],
+        );
+    }
+}
+
 #[test]
 fn zero_or_more_concat_confusion_1() {
     let mut nfa: NFA<usize> = NFA::new();
@@ -618,12 +694,70 @@ fn overlapping_ranges() {
     );
 }
 
+#[test]
+fn overlapping_ranges_are_split() {
+    let mut nfa: NFA<usize> = NFA::new();
+
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::CharSet(CharSet(vec![
+            CharOrRange::Range('a', 'c'),
+            CharOrRange::Range('d', 'f'),
+        ])),
+        None,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::Concat(
+            Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range('c', 'd')]))),
+            Box::new(Regex::Char('!')),
+        ),
+        None,
+        2,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::CharSet(CharSet(vec![CharOrRange::Range('x', 'z')])),
+        None,
+        1,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+
+    // Ranges of the initial state are disjoint, and 'a'-'b' and 'e'-'f' lead to the same state
+    let dfa_str = dfa.to_string();
+    let initial_state: Vec<&str> = dfa_str.lines().take(4).collect();
+    assert_eq!(
+        initial_state,
+        vec![
+            "   i0:'a' - 'b' -> 1",
+            "      'c' - 'd' -> 2",
+            "      'e' - 'f' -> 1",
+            "      'x' - 'z' -> 3",
+        ]
+    );
+
+    test_simulate(
+        &nfa,
+        vec![
+            ("a", vec![("a", 1)], None),
+            ("c", vec![("c", 1)], None),
+            ("b!", vec![("b", 1)], Some(1)),
+            ("c!", vec![("c!", 2)], None),
+            ("d!", vec![("d!", 2)], None),
+            ("f", vec![("f", 1)], None),
+            ("y", vec![("y", 1)], None),
+        ],
+    );
+}
+
 #[test]
 fn right_context_1() {
     let mut nfa: NFA<usize> = NFA::new();
     let mut right_ctxs = RightCtxDFAs::new();
 
-    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::Char('a'));
+    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::Char('a'), false);
     nfa.add_regex(&Default::default(), &Regex::Char('a'), Some(right_ctx), 1);
 
     test_simulate_right_ctx(&nfa, &right_ctxs, vec![("aa", vec![("a", 1)], Some(1))]);
@@ -635,7 +769,7 @@ fn right_context_2() {
     let mut nfa: NFA<usize> = NFA::new();
     let mut right_ctxs = RightCtxDFAs::new();
 
-    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::Any);
+    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::Any, false);
     nfa.add_regex(&Default::default(), &Regex::Char('a'), Some(right_ctx), 1);
 
     test_simulate_right_ctx(&nfa, &right_ctxs, vec![("aa", vec![("a", 1)], Some(1))]);
@@ -648,7 +782,7 @@ fn right_context_3() {
     let mut nfa: NFA<usize> = NFA::new();
     let mut right_ctxs = RightCtxDFAs::new();
 
-    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::EndOfInput);
+    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::EndOfInput, false);
     nfa.add_regex(&Default::default(), &Regex::Char('a'), Some(right_ctx), 1);
 
     test_simulate_right_ctx(&nfa, &right_ctxs, vec![("a", vec![("a", 1)], None)]);
@@ -660,10 +794,10 @@ fn right_context_4() {
     let mut nfa: NFA<usize> = NFA::new();
     let mut right_ctxs = RightCtxDFAs::new();
 
-    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::Char('a'));
+    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::Char('a'), false);
     nfa.add_regex(&Default::default(), &Regex::Char('a'), Some(right_ctx), 1);
 
-    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::EndOfInput);
+    let right_ctx = right_ctxs.new_right_ctx(&Default::default(), &Regex::EndOfInput, false);
     nfa.add_regex(&Default::default(), &Regex::Char('a'), Some(right_ctx), 2);
 
     test_simulate_right_ctx(
@@ -672,3 +806,2170 @@ fn right_context_4() {
         vec![("aa", vec![("a", 1), ("a", 2)], None)],
     );
 }
+
+#[test]
+fn parallel_determinization() {
+    let nfas: Vec<NFA<usize>> = (0..8)
+        .map(|i| {
+            let mut nfa: NFA<usize> = NFA::new();
+            nfa.add_regex(
+                &Default::default(),
+                &Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range(
+                    'a', 'z',
+                )])))),
+                None,
+                i,
+            );
+            nfa.add_regex(
+                &Default::default(),
+                &Regex::String("x".repeat(i + 1)),
+                None,
+                i + 100,
+            );
+            nfa
+        })
+        .collect();
+
+    let dfas = nfas_to_dfas(&nfas);
+
+    assert_eq!(dfas.len(), nfas.len());
+
+    for (nfa, dfa) in nfas.iter().zip(dfas.iter()) {
+        assert_eq!(nfa_to_dfa(nfa).to_string(), dfa.to_string());
+    }
+}
+
+#[test]
+fn unreachable_rules() {
+    let mut nfa: NFA<usize> = NFA::new();
+
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range(
+            'a', 'z',
+        )])))),
+        None,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        2,
+    );
+    nfa.add_regex(&Default::default(), &Regex::Char('0'), None, 3);
+
+    let dfa = nfa_to_dfa(&nfa);
+
+    let mut matched: Vec<usize> = matched_values(&dfa).into_iter().collect();
+    matched.sort_unstable();
+
+    // "if" is shadowed by the identifier rule
+    assert_eq!(matched, vec![1, 3]);
+
+    let conflicts = conflicts(&dfa);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[&(1, 2)], Some("if".to_owned()));
+}
+
+#[test]
+fn guarded_rules_are_not_shadowing() {
+    let mut nfa: NFA<usize> = NFA::new();
+
+    nfa.add_guarded_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        true,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range(
+            'a', 'z',
+        )])))),
+        None,
+        2,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        3,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+
+    let mut matched: Vec<usize> = matched_values(&dfa).into_iter().collect();
+    matched.sort_unstable();
+
+    // The identifier rule matches "if" when the guard of the keyword rule fails
+    assert_eq!(matched, vec![1, 2]);
+
+    let conflicts = conflicts(&dfa);
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[&(2, 3)], Some("if".to_owned()));
+}
+
+#[test]
+fn accepting_inputs() {
+    use crate::dfa::analysis::accepting_inputs;
+
+    let mut nfa: NFA<usize> = NFA::new();
+
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range(
+            'a', 'z',
+        )])))),
+        None,
+        2,
+    );
+    nfa.add_regex(&Default::default(), &Regex::Char('0'), None, 3);
+
+    let dfa = nfa_to_dfa(&nfa);
+
+    let inputs: Vec<(String, Vec<usize>)> = accepting_inputs(&dfa)
+        .into_iter()
+        .map(|(input, accepting_states)| {
+            (
+                input,
+                accepting_states
+                    .into_iter()
+                    .map(|accepting_state| accepting_state.value)
+                    .collect(),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        inputs,
+        vec![
+            ("0".to_owned(), vec![3]),
+            ("a".to_owned(), vec![2]),
+            ("i".to_owned(), vec![2]),
+            ("if".to_owned(), vec![1]),
+        ]
+    );
+
+    // Every input is lexed as a single token
+    for (input, values) in &inputs {
+        let (matches, error) = dfa.simulate(input, &RightCtxDFAs::new());
+        assert_eq!(error, None);
+        assert_eq!(matches, vec![(input.as_str(), values[0])]);
+    }
+}
+
+#[test]
+fn empty_matches() {
+    use crate::dfa::analysis::empty_matches;
+
+    let ws = || Regex::CharSet(CharSet(vec![CharOrRange::Char(' ')]));
+
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::ZeroOrMore(Box::new(ws())),
+        None,
+        1,
+    );
+    nfa.add_regex(&Default::default(), &Regex::Char('a'), None, 2);
+
+    let dfa = nfa_to_dfa(&nfa);
+    assert_eq!(empty_matches(&dfa).into_iter().collect::<Vec<_>>(), vec![1]);
+
+    // Empty match is not possible when the initial state has 'any' and end-of-input transitions
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(&Default::default(), &Regex::EndOfInput, None, 1);
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::ZeroOrMore(Box::new(Regex::Any)),
+        None,
+        2,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+    assert!(empty_matches(&dfa).is_empty());
+}
+
+#[test]
+fn binding_cycles() {
+    use crate::compile::find_binding_cycle;
+
+    let var = |name: &str| Var(name.to_owned());
+
+    let mut bindings: Map<Var, Regex> = Default::default();
+    bindings.insert(
+        var("a"),
+        Regex::Concat(Box::new(Regex::Char('x')), Box::new(Regex::Var(var("b")))),
+    );
+    bindings.insert(var("c"), Regex::Char('y'));
+    assert_eq!(find_binding_cycle(&bindings, &var("a")), None);
+
+    bindings.insert(
+        var("b"),
+        Regex::Or(
+            Box::new(Regex::Var(var("c"))),
+            Box::new(Regex::OneOrMore(Box::new(Regex::Var(var("a"))))),
+        ),
+    );
+    assert_eq!(
+        find_binding_cycle(&bindings, &var("b")),
+        Some(vec![var("b"), var("a"), var("b")])
+    );
+
+    bindings.insert(var("d"), Regex::Var(var("d")));
+    assert_eq!(
+        find_binding_cycle(&bindings, &var("d")),
+        Some(vec![var("d"), var("d")])
+    );
+}
+
+#[test]
+fn dfa_to_dot() {
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("a\"".to_owned()),
+        None,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::CharSet(CharSet(vec![CharOrRange::Range('0', '9')])),
+        None,
+        2,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+    let dot = dfa.to_dot(
+        "Lexer",
+        |accepting| format!("rule {}", accepting.value),
+        |rule| rule.to_string(),
+    );
+
+    assert!(dot.starts_with("digraph \"Lexer\" {\n"));
+    assert!(dot.contains("    start0 -> 0;\n"));
+    assert!(dot.contains("[label=\"'\\\"'\"]"));
+    assert!(dot.contains("[label=\"'0'-'9'\"]"));
+    assert!(dot.contains("\\nrule 1\", shape=doublecircle]"));
+    assert!(dot.contains("\\nrule 2\", shape=doublecircle]"));
+}
+
+#[test]
+fn dfa_to_mermaid() {
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("a;".to_owned()),
+        None,
+        1,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+    let mermaid = dfa.to_mermaid(
+        |accepting| format!("rule {}", accepting.value),
+        |rule| rule.to_string(),
+    );
+
+    assert_eq!(
+        mermaid,
+        "stateDiagram-v2
+    direction LR
+    s0 : 0
+    [*] --> s0
+    s1 : 1<br>1@1
+    s2 : 2<br>1@2<br>rule 1
+    s2 --> [*]
+    s0 --> s1 : 'a'
+    s1 --> s2 : '#59;'
+"
+    );
+}
+
+#[test]
+fn dfa_report() {
+    use crate::ast::DriverStyle;
+    use crate::dfa::report::report;
+
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![
+            CharOrRange::Range('a', 'c'),
+            CharOrRange::Char('d'),
+            CharOrRange::Char('x'),
+        ])))),
+        None,
+        1,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+
+    let mut rule_sets: Map<String, DfaStateIdx> = Default::default();
+    rule_sets.insert("Init".to_owned(), dfa.initial_state());
+
+    let report = report(
+        "Lexer",
+        &dfa,
+        DriverStyle::MatchLoop,
+        &rule_sets,
+        |accepting| format!("rule {}", accepting.value),
+        |rule| format!("Init:{}", rule),
+    );
+
+    assert_eq!(
+        report,
+        "Lexer: Lexer
+States: 2
+Transitions: 4
+Character classes: 2
+Estimated dense transition table size: 2 states * 2 classes * 1 bytes = 4 bytes
+Driver: match_loop
+Note: code for all 2 states is generated in a single `match`. Large `match`es can be difficult to optimize; if the lexer is slow, try `driver = state_fns;` and benchmark both on a representative input.
+
+State 0 (initial state of Init)
+  'a'-'d', 'x' -> 1
+
+State 1 (Init:1@1)
+  accepts: rule 1
+  'a'-'d', 'x' -> 1
+"
+    );
+}
+
+#[test]
+fn regex_syntax_error_spans() {
+    // Returns the error message and the line and column of the error
+    let error = |lexer: &str| -> (String, usize, usize) {
+        let error = parse_error(lexer);
+        let start = error.span().start();
+        (error.to_string(), start.line, start.column)
+    };
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' | * = (),");
+    assert!(msg.starts_with("Expected a regex"), "{}", msg);
+    assert_eq!((line, column), (2, 6));
+
+    let (msg, line, column) = error("Lexer -> ();\n['a' \"b\"] = (),");
+    assert!(
+        msg.starts_with("Expected a character or a character range"),
+        "{}",
+        msg
+    );
+    assert_eq!((line, column), (2, 5));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' $$foo = (),");
+    assert_eq!(msg, "Unknown built-in regex \"foo\"");
+    assert_eq!((line, column), (2, 6));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' $$p{Foo} = (),");
+    if cfg!(feature = "unicode") {
+        assert_eq!(msg, "Unknown Unicode general category or script \"Foo\"");
+    } else {
+        assert_eq!(
+            msg,
+            "Unicode properties (`$$p{...}`) require the \"unicode\" feature of lexgen"
+        );
+    }
+    assert_eq!((line, column), (2, 8));
+
+    if cfg!(feature = "unicode") {
+        let (msg, line, column) = error("Lexer -> ();\n'a' $$p{Block=Foo} = (),");
+        assert_eq!(msg, "Unknown Unicode Block \"Foo\"");
+        assert_eq!((line, column), (2, 14));
+
+        let (msg, line, column) = error("Lexer -> ();\n'a' $$p{Age=12.5} = (),");
+        assert_eq!(msg, "Unknown Unicode Age \"12.5\"");
+        assert_eq!((line, column), (2, 12));
+
+        let (msg, line, column) = error("Lexer -> ();\n'a' $$p{Script=Greek} = (),");
+        assert_eq!(
+            msg,
+            "Unknown Unicode property \"Script\", expected `Block` or `Age`"
+        );
+        assert_eq!((line, column), (2, 8));
+    }
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' ($$alphabetic # \"ab\") = (),");
+    assert_eq!(msg, "Strings cannot be used in character sets (`#`)");
+    assert_eq!((line, column), (2, 20));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' [0x41-0x110000] = (),");
+    assert_eq!(
+        msg,
+        "0x110000 is not a valid code point: code points are at most 0x10FFFF, and surrogates \
+        (0xD800 to 0xDFFF) are not characters"
+    );
+    assert_eq!((line, column), (2, 10));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' [0xD800] = (),");
+    assert!(
+        msg.starts_with("0xD800 is not a valid code point"),
+        "{}",
+        msg
+    );
+    assert_eq!((line, column), (2, 5));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' [0x41u8] = (),");
+    assert_eq!(
+        msg,
+        "Code points in character sets can't have a type suffix"
+    );
+    assert_eq!((line, column), (2, 5));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' [0x0-0x10FFFF] = (),");
+    assert!(
+        msg.starts_with("Character range contains surrogates"),
+        "{}",
+        msg
+    );
+    assert_eq!((line, column), (2, 5));
+
+    let (msg, line, column) = error("Lexer -> ();\n'a' $$invalid_utf8 = (),");
+    assert_eq!(
+        msg,
+        "`$$invalid_utf8` can only be used as the whole left-hand side of a rule"
+    );
+    assert_eq!((line, column), (2, 6));
+}
+
+#[test]
+fn char_range_error_spans() {
+    // Returns the error message and the line and columns of the start and end of the error
+    let error = |lexer: &str| -> (String, usize, usize, usize) {
+        let error = parse_error(lexer);
+        let start = error.span().start();
+        let end = error.span().end();
+        (error.to_string(), start.line, start.column, end.column)
+    };
+
+    let (msg, line, start, end) = error("Lexer -> ();\n['a' 'z'-'a'] = (),");
+    assert_eq!(msg, "Invalid character range: 'z' is greater than 'a'");
+    assert_eq!((line, start, end), (2, 5, 12));
+
+    let (msg, line, start, end) = error("Lexer -> ();\n[0x7A-0x61] = (),");
+    assert_eq!(msg, "Invalid character range: 'z' is greater than 'a'");
+    assert_eq!((line, start, end), (2, 1, 10));
+
+    let (msg, line, start, end) = error("Lexer -> ();\n['a' '\\0'-'\\u{10FFFF}'] = (),");
+    assert_eq!(
+        msg,
+        "Character range contains surrogates (U+D800 to U+DFFF), which are not characters. \
+        Split the range into '\\0'-'\\u{d7ff}' and '\\u{e000}'-'\\u{10FFFF}'"
+    );
+    assert_eq!((line, start, end), (2, 5, 22));
+}
+
+#[test]
+fn lalrpop_extern_block() {
+    use crate::ast::make_lexer_parser;
+    use crate::lalrpop::extern_block;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let mut semantic_action_table = SemanticActionTable::new();
+    let lexer = make_lexer_parser(&mut semantic_action_table)
+        .parse_str(
+            r#"
+            Lexer -> Token<'input>;
+            type Error = MyError;
+            lalrpop = true;
+
+            rule Init {
+                ' ',
+                "+" = Token::Plus,
+                '(' = Token::LParen,
+                "add" = Token::Plus,
+                ['a'-'z']+ = Token::Ident,
+                ['0'-'9']+ => |lexer| lexer.return_(Token::Int(lexer.match_())),
+            }
+
+            rule Comment {
+                "*/" = Token::EndComment,
+            }
+            "#,
+        )
+        .unwrap();
+
+    assert_eq!(
+        extern_block(&lexer, &semantic_action_table),
+        "// LALRPOP `extern` block for `Lexer`, generated by lexgen. Tokens with fields and tokens
+// returned by semantic actions need to be added manually.
+extern {
+    type Location = lexgen_util::Loc;
+    type Error = lexgen_util::LexerError<MyError>;
+
+    enum Token<'input> {
+        \"+\" => Token::Plus,
+        \"(\" => Token::LParen,
+        Ident => Token::Ident,
+        \"*/\" => Token::EndComment,
+    }
+}
+"
+    );
+}
+
+#[test]
+fn dfa_snapshot() {
+    use crate::dfa::snapshot::snapshot;
+
+    // 'a' 'b' | 'a' 'c'
+    let mut nfa1: NFA<usize> = NFA::new();
+    nfa1.add_regex(
+        &Default::default(),
+        &Regex::Or(
+            Box::new(Regex::String("ab".to_owned())),
+            Box::new(Regex::String("ac".to_owned())),
+        ),
+        None,
+        1,
+    );
+
+    // 'a' ['b' 'c']
+    let mut nfa2: NFA<usize> = NFA::new();
+    nfa2.add_regex(
+        &Default::default(),
+        &Regex::Concat(
+            Box::new(Regex::Char('a')),
+            Box::new(Regex::CharSet(CharSet(vec![
+                CharOrRange::Char('b'),
+                CharOrRange::Char('c'),
+            ]))),
+        ),
+        None,
+        1,
+    );
+
+    let snapshots: Vec<String> = [nfa1, nfa2]
+        .iter()
+        .map(|nfa| {
+            let dfa = nfa_to_dfa(nfa);
+            let rule_sets = vec![("Init".to_owned(), dfa.initial_state())];
+            snapshot(
+                "Lexer",
+                &dfa,
+                &rule_sets,
+                &RightCtxDFAs::new(),
+                |accepting| format!("rule {}", accepting.value),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        snapshots[0],
+        "Lexer: Lexer
+Rule set Init: state 0
+
+State 0
+  'a' -> 1
+
+State 1
+  'b'-'c' -> 2
+
+State 2
+  accepts: rule 1
+"
+    );
+
+    assert_eq!(snapshots[0], snapshots[1]);
+}
+
+#[test]
+fn check_snapshot() {
+    use crate::dfa::snapshot::check_snapshot;
+
+    let path = std::env::temp_dir().join(format!("lexgen_snapshot_{}", std::process::id()));
+
+    // Missing snapshots are written
+    assert_eq!(check_snapshot(&path, "State 0\n  'a' -> 1\n"), Ok(()));
+    assert_eq!(check_snapshot(&path, "State 0\n  'a' -> 1\n"), Ok(()));
+
+    let err = check_snapshot(&path, "State 0\n  'b' -> 1\n").unwrap_err();
+    assert!(
+        err.contains(r#"First difference at line 2: expected "  'a' -> 1", found "  'b' -> 1""#),
+        "{}",
+        err
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn lexer_equivalence() {
+    use crate::dfa::equivalence::{find_difference, Difference};
+
+    let lower = || Regex::CharSet(CharSet(vec![CharOrRange::Range('a', 'z')]));
+
+    // "if" = 1, ['a'-'z']+ = 2
+    let mut nfa1: NFA<usize> = NFA::new();
+    nfa1.add_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        1,
+    );
+    nfa1.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(lower())),
+        None,
+        2,
+    );
+
+    // Same as above, with different values and the identifier regex split into two parts
+    let mut nfa2: NFA<usize> = NFA::new();
+    nfa2.add_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        10,
+    );
+    nfa2.add_regex(
+        &Default::default(),
+        &Regex::Concat(
+            Box::new(lower()),
+            Box::new(Regex::ZeroOrMore(Box::new(lower()))),
+        ),
+        None,
+        20,
+    );
+
+    // Identifiers listed before "if"
+    let mut nfa3: NFA<usize> = NFA::new();
+    nfa3.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(lower())),
+        None,
+        2,
+    );
+    nfa3.add_regex(
+        &Default::default(),
+        &Regex::String("if".to_owned()),
+        None,
+        1,
+    );
+
+    let dfa1 = nfa_to_dfa(&nfa1);
+    let dfa2 = nfa_to_dfa(&nfa2);
+    let dfa3 = nfa_to_dfa(&nfa3);
+
+    let rule_sets = vec![("Init".to_owned(), dfa1.initial_state())];
+
+    assert!(find_difference(
+        &dfa1,
+        &rule_sets,
+        |accepting| accepting.value,
+        &dfa2,
+        &rule_sets,
+        |accepting| accepting.value / 10,
+    )
+    .is_none());
+
+    match find_difference(
+        &dfa1,
+        &rule_sets,
+        |accepting| accepting.value,
+        &dfa3,
+        &rule_sets,
+        |accepting| accepting.value,
+    ) {
+        Some(Difference::Input {
+            rule_set,
+            input,
+            end_of_input,
+            first,
+            second,
+        }) => {
+            assert_eq!(rule_set, "Init");
+            assert_eq!(input, "if");
+            assert!(!end_of_input);
+            assert_eq!(first.len(), 1);
+            assert_eq!(first[0].value, 1);
+            assert_eq!(second.len(), 1);
+            assert_eq!(second[0].value, 2);
+        }
+        other => panic!("Unexpected difference: {:?}", other),
+    }
+
+    let rule_sets2 = vec![
+        ("Init".to_owned(), dfa1.initial_state()),
+        ("Comment".to_owned(), dfa1.initial_state()),
+    ];
+
+    assert!(matches!(
+        find_difference(
+            &dfa1,
+            &rule_sets,
+            |accepting| accepting.value,
+            &dfa1,
+            &rule_sets2,
+            |accepting| accepting.value,
+        ),
+        Some(Difference::RuleSet { name, in_first: false }) if name == "Comment"
+    ));
+}
+
+/// Translate a regex to the syntax of the `regex` crate. Returns `None` for regexes that can't be
+/// translated (`$`).
+fn regex_crate_pattern(re: &Regex, bindings: &Map<Var, Regex>) -> Option<String> {
+    Some(match re {
+        Regex::Builtin(_) | Regex::CharSet(_) | Regex::Diff(_, _) => {
+            format!("[{}]", regex_crate_class(re, bindings)?)
+        }
+        Regex::Var(var) => regex_crate_pattern(&bindings[var], bindings)?,
+        Regex::Char(char) => regex::escape(&char.to_string()),
+        Regex::String(str) => format!("(?:{})", regex::escape(str)),
+        Regex::ZeroOrMore(re) => format!("(?:{})*", regex_crate_pattern(re, bindings)?),
+        Regex::OneOrMore(re) => format!("(?:{})+", regex_crate_pattern(re, bindings)?),
+        Regex::ZeroOrOne(re) => format!("(?:{})?", regex_crate_pattern(re, bindings)?),
+        Regex::Capture(re, _) => regex_crate_pattern(re, bindings)?,
+        Regex::Concat(re1, re2) => format!(
+            "(?:{})(?:{})",
+            regex_crate_pattern(re1, bindings)?,
+            regex_crate_pattern(re2, bindings)?
+        ),
+        Regex::Or(re1, re2) => format!(
+            "(?:{}|{})",
+            regex_crate_pattern(re1, bindings)?,
+            regex_crate_pattern(re2, bindings)?
+        ),
+        Regex::Any => "(?s:.)".to_owned(),
+        Regex::EndOfInput => return None,
+    })
+}
+
+/// Translate a character set regex (operands of `#`) to the contents of a `regex` crate character
+/// class
+fn regex_crate_class(re: &Regex, bindings: &Map<Var, Regex>) -> Option<String> {
+    Some(match re {
+        Regex::Builtin(builtin) => match builtin.0.as_str() {
+            "alphabetic" => r"\p{Alphabetic}",
+            "alphanumeric" => r"\p{Alphabetic}\p{N}",
+            "ascii" => r"\x00-\x7F",
+            "ascii_alphabetic" => "A-Za-z",
+            "ascii_alphanumeric" => "0-9A-Za-z",
+            "ascii_control" => r"\x00-\x1F\x7F",
+            "ascii_digit" => "0-9",
+            "ascii_graphic" => "!-~",
+            "ascii_hexdigit" => "0-9A-Fa-f",
+            "ascii_lowercase" => "a-z",
+            "ascii_punctuation" => "[:punct:]",
+            "ascii_uppercase" => "A-Z",
+            "ascii_whitespace" => r"\t\n\x0C\r ",
+            "control" => r"\p{Cc}",
+            "lowercase" => r"\p{Lowercase}",
+            "numeric" => r"\p{N}",
+            "uppercase" => r"\p{Uppercase}",
+            "whitespace" => r"\p{White_Space}",
+            "XID_Start" => r"\p{XID_Start}",
+            "XID_Continue" => r"\p{XID_Continue}",
+            property if property.starts_with("p{") => return Some(format!(r"\{}", property)),
+            other => panic!("Unknown built-in regex {:?}", other),
+        }
+        .to_owned(),
+        Regex::CharSet(CharSet(chars)) => chars
+            .iter()
+            .map(|char_or_range| match char_or_range {
+                CharOrRange::Char(char) => format!(r"\x{{{:X}}}", *char as u32),
+                CharOrRange::Range(start, end) => {
+                    format!(r"\x{{{:X}}}-\x{{{:X}}}", *start as u32, *end as u32)
+                }
+            })
+            .collect(),
+        Regex::Char(char) => format!(r"\x{{{:X}}}", *char as u32),
+        Regex::Any => r"\x{0}-\x{10FFFF}".to_owned(),
+        Regex::Var(var) => regex_crate_class(&bindings[var], bindings)?,
+        Regex::Or(re1, re2) => format!(
+            "{}{}",
+            regex_crate_class(re1, bindings)?,
+            regex_crate_class(re2, bindings)?
+        ),
+        Regex::Diff(re1, re2) => format!(
+            "[{}--[{}]]",
+            regex_crate_class(re1, bindings)?,
+            regex_crate_class(re2, bindings)?
+        ),
+        _ => return None,
+    })
+}
+
+/// Characters used in the regex, and characters next to the ends of ranges in the regex
+fn regex_chars(re: &Regex, bindings: &Map<Var, Regex>, chars: &mut Vec<char>) {
+    match re {
+        Regex::Char(char) => chars.push(*char),
+        Regex::String(str) => chars.extend(str.chars()),
+        Regex::CharSet(CharSet(char_set)) => {
+            for char_or_range in char_set {
+                let (start, end) = match char_or_range {
+                    CharOrRange::Char(char) => (*char, *char),
+                    CharOrRange::Range(start, end) => (*start, *end),
+                };
+                chars.extend(
+                    [
+                        (start as u32).checked_sub(1),
+                        Some(start as u32),
+                        Some(end as u32),
+                        Some(end as u32 + 1),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(char::from_u32),
+                );
+            }
+        }
+        Regex::Var(var) => regex_chars(&bindings[var], bindings, chars),
+        Regex::ZeroOrMore(re)
+        | Regex::OneOrMore(re)
+        | Regex::ZeroOrOne(re)
+        | Regex::Capture(re, _) => regex_chars(re, bindings, chars),
+        Regex::Concat(re1, re2) | Regex::Or(re1, re2) | Regex::Diff(re1, re2) => {
+            regex_chars(re1, bindings, chars);
+            regex_chars(re2, bindings, chars);
+        }
+        Regex::Builtin(_) | Regex::Any | Regex::EndOfInput => {}
+    }
+}
+
+/// Inputs to compare lexgen and `regex` crate matches of a regex on: all strings of up to two
+/// characters of the regex and a few ASCII and Unicode characters, shortest inputs of the
+/// accepting states of the DFA and their one character extensions and truncations, and
+/// pseudo-random strings.
+fn differential_inputs(
+    re: &Regex,
+    bindings: &Map<Var, Regex>,
+    dfa: &crate::dfa::DFA<DfaStateIdx, usize>,
+) -> Vec<String> {
+    let mut alphabet: Vec<char> = vec![
+        'a',
+        'z',
+        'A',
+        'Z',
+        '0',
+        '9',
+        '_',
+        '-',
+        ' ',
+        '\t',
+        '\n',
+        '\0',
+        '\x7F',
+        '\u{80}',
+        'é',
+        'λ',
+        'Ω',
+        '٣',
+        '中',
+        '\u{2028}',
+        '\u{1F980}',
+    ];
+    regex_chars(re, bindings, &mut alphabet);
+    alphabet.sort_unstable();
+    alphabet.dedup();
+
+    let mut inputs: Vec<String> = vec![String::new()];
+
+    for char1 in &alphabet {
+        inputs.push(char1.to_string());
+        for char2 in &alphabet {
+            inputs.push([*char1, *char2].iter().collect());
+        }
+    }
+
+    for (input, _) in crate::dfa::analysis::accepting_inputs(dfa) {
+        let mut truncated = input.clone();
+        truncated.pop();
+        inputs.push(truncated);
+        for char in &alphabet {
+            let mut extended = input.clone();
+            extended.push(*char);
+            inputs.push(extended);
+        }
+        inputs.push(input);
+    }
+
+    // Linear congruential generator, for reproducible inputs
+    let mut seed: u64 = 0x2545_F491_4F6C_DD1D;
+    let mut random = |n: usize| -> usize {
+        seed = seed
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (seed >> 33) as usize % n
+    };
+
+    for _ in 0..500 {
+        let len = 3 + random(6);
+        inputs.push((0..len).map(|_| alphabet[random(alphabet.len())]).collect());
+    }
+
+    inputs
+}
+
+/// Differential testing of `regex_to_nfa` and `nfa_to_dfa` against the `regex` crate: for each
+/// rule in the lexer definition, checks that the NFA and DFA of the rule's regex match the same
+/// inputs as the equivalent `regex` crate pattern. Rules with right contexts and regexes that
+/// can't be translated (`$`) are skipped.
+fn check_against_regex_crate(lexer: &str) {
+    use crate::ast::{make_lexer_parser, RegexCtx, Rule, SingleRule};
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let lexer = make_lexer_parser(&mut SemanticActionTable::new())
+        .parse_str(lexer)
+        .unwrap();
+
+    let mut bindings: Map<Var, Regex> = Default::default();
+    let mut regexes: Vec<Regex> = vec![];
+
+    for rule in lexer.rules {
+        match rule {
+            Rule::Binding { var, re } => {
+                bindings.insert(Var(var.to_string()), re.re);
+            }
+            Rule::RuleSet { rules, .. } | Rule::UnnamedRules { rules, .. } => {
+                for SingleRule { lhs, .. } in rules {
+                    if let RegexCtx {
+                        re,
+                        right_ctx: None,
+                        ..
+                    } = lhs
+                    {
+                        regexes.push(re);
+                    }
+                }
+            }
+            Rule::SharedRule { rule, .. } => {
+                if let RegexCtx {
+                    re,
+                    right_ctx: None,
+                    ..
+                } = rule.lhs
+                {
+                    regexes.push(re);
+                }
+            }
+            Rule::ErrorType { .. }
+            | Rule::FeedbackType { .. }
+            | Rule::InternerType { .. }
+            | Rule::Option { .. }
+            | Rule::Tests { .. } => {}
+        }
+    }
+
+    // Simplified regexes should match the same inputs as the originals
+    let simplified: Vec<Regex> = regexes
+        .iter()
+        .map(crate::regex_simplify::simplify)
+        .collect();
+
+    for (re, nfa_re) in regexes
+        .iter()
+        .zip(simplified.iter())
+        .flat_map(|(re, simplified)| [(re, re), (re, simplified)])
+    {
+        let pattern = match regex_crate_pattern(re, &bindings) {
+            Some(pattern) => pattern,
+            None => continue,
+        };
+
+        let regex = regex::Regex::new(&format!("^(?:{})$", pattern)).unwrap();
+
+        let mut nfa: NFA<usize> = NFA::new();
+        nfa.add_regex(&bindings, nfa_re, None, 0);
+        let dfa = nfa_to_dfa(&nfa);
+
+        for input in differential_inputs(re, &bindings, &dfa) {
+            let expected = regex.is_match(&input);
+
+            // A non-empty input is matched by the regex when it's lexed as a single token
+            let (nfa_matched, dfa_matched) = if input.is_empty() {
+                let matched = dfa.is_accepting_state(dfa.initial_state());
+                (matched, matched)
+            } else {
+                let token = (vec![(input.as_str(), 0)], None);
+                (
+                    nfa.simulate(&input, &RightCtxDFAs::new()) == token,
+                    dfa.simulate(&input, &RightCtxDFAs::new()) == token,
+                )
+            };
+
+            assert_eq!(
+                nfa_matched, expected,
+                "NFA of {:?} (regex crate pattern {:?}) on input {:?}",
+                nfa_re, pattern, input
+            );
+            assert_eq!(
+                dfa_matched, expected,
+                "DFA of {:?} (regex crate pattern {:?}) on input {:?}",
+                nfa_re, pattern, input
+            );
+        }
+    }
+}
+
+#[test]
+fn regex_crate_differential() {
+    check_against_regex_crate(
+        r##"
+        Lexer -> ();
+
+        let ws = [' ' '\t' '\n']+;
+        let digit = ['0'-'9'];
+        let hex_digit = $digit | ['a'-'f' 'A'-'F'];
+        let id_start = $$XID_Start | '_';
+
+        $ws,
+        "let" = (),
+        "letrec" = (),
+        'λ' = (),
+        "中文" = (),
+        $id_start $$XID_Continue* = (),
+        $digit+ ('.' $digit+)? (['e' 'E'] ['+' '-']? $digit+)? = (),
+        "0x" $hex_digit+ = (),
+        '"' (_ # ['"' '\\'] | '\\' _)* '"' = (),
+        "/*" (_ # '*' | '*'+ (_ # ['*' '/']))* '*'+ '/' = (),
+        ($$ascii_alphabetic | $$ascii_digit)+ '!'? = (),
+        ['a'-'c' 'x'-'z' 'é'-'λ'] = (),
+        ($$alphabetic # $$ascii) ($$alphanumeric # $$lowercase)* = (),
+        $$whitespace+ $$numeric = (),
+        ($$uppercase $$ascii_hexdigit?)+ = (),
+        $$ascii_punctuation $$ascii_graphic $$ascii_control? = (),
+        $$control | $$ascii_whitespace | $$ascii_lowercase $$ascii_uppercase = (),
+        ((_ # ['a'-'z']) # $$numeric)+ = (),
+        ("ab" | "a" | "abc")* 'c' = (),
+        "if" | "in" | "int" | "i" | 'a' | ['b'-'d'] | "" | "else" | "elif" | 'x' "y" = (),
+        ("λx" | "λy" | 'λ' ['a'-'b'])+ = (),
+        "" = (),
+        ('a'? 'b'?)* = (),
+        "@" > 'x' = (),
+        "end" $ = (),
+        "#",
+    "##,
+    );
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn regex_crate_differential_unicode_properties() {
+    check_against_regex_crate(
+        r#"
+        Lexer -> ();
+
+        ' ',
+        $$p{Greek}+ = (),
+        $$p{Lu} $$p{Ll}* = (),
+        ($$p{L} # $$p{Latin}) $$p{Nd}? = (),
+        $$p{Space_Separator} | $$p{Sc} | $$p{Cyrillic} = (),
+    "#,
+    );
+}
+
+#[test]
+fn init_coverage() {
+    use crate::ast::make_lexer_parser;
+    use crate::dfa::analysis::uncovered_chars;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::CharSet(CharSet(vec![
+            CharOrRange::Char(' '),
+            CharOrRange::Range('a', 'z'),
+        ])),
+        None,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("0x".to_owned()),
+        None,
+        2,
+    );
+
+    let dfa = nfa_to_dfa(&nfa);
+
+    assert_eq!(
+        uncovered_chars(&dfa, '\x7F'),
+        vec![('\0', '\x1F'), ('!', '/'), ('1', '`'), ('{', '\x7F')]
+    );
+
+    assert_eq!(
+        uncovered_chars(&dfa, char::MAX),
+        vec![
+            ('\0', '\x1F'),
+            ('!', '/'),
+            ('1', '`'),
+            ('{', '\u{D7FF}'),
+            ('\u{E000}', char::MAX)
+        ]
+    );
+
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(&Default::default(), &Regex::Char('a'), None, 1);
+    nfa.add_regex(&Default::default(), &Regex::Any, None, 2);
+
+    assert!(uncovered_chars(&nfa_to_dfa(&nfa), char::MAX).is_empty());
+
+    let compile = |lexer: &str| {
+        make_lexer_parser(&mut SemanticActionTable::new())
+            .parse_str(lexer)
+            .and_then(crate::compile::compile)
+    };
+
+    let error = compile_error("Lexer -> ();\ninit_coverage = ascii;\n['\\0'-'a'] = (),");
+    assert_eq!(
+        error.to_string(),
+        "Rule set \"Init\" doesn't handle characters 'b'-'\\u{7f}'. Add rules for the \
+        characters, or a catch-all rule with `_`."
+    );
+    assert_eq!(error.span().start().line, 2);
+
+    assert!(compile("Lexer -> ();\ninit_coverage = ascii;\n$$ascii = (),").is_ok());
+    assert!(compile("Lexer -> ();\ninit_coverage = unicode;\n$$ascii = (),").is_err());
+}
+
+#[test]
+fn case_fold_classes() {
+    use crate::case_fold::case_fold_class;
+
+    assert_eq!(case_fold_class('a'), &['A']);
+    assert_eq!(case_fold_class('A'), &['a']);
+    assert_eq!(case_fold_class('ß'), &['ẞ']);
+    assert_eq!(case_fold_class('ẞ'), &['ß']);
+    assert_eq!(case_fold_class('0'), &[] as &[char]);
+
+    let mut k = case_fold_class('k').to_vec();
+    k.sort();
+    assert_eq!(k, vec!['K', '\u{212A}']);
+
+    let mut sigma = case_fold_class('σ').to_vec();
+    sigma.sort();
+    assert_eq!(sigma, vec!['Σ', 'ς']);
+
+    // Only full case foldings or Turkic foldings relate these to other characters
+    assert_eq!(case_fold_class('İ'), &[] as &[char]);
+    assert_eq!(case_fold_class('ı'), &[] as &[char]);
+    assert_eq!(case_fold_class('i'), &['I']);
+}
+
+#[test]
+fn case_fold_nfa() {
+    let mut nfa: NFA<usize> = NFA::new();
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::String("sk".to_owned()),
+        None,
+        1,
+    );
+    nfa.add_regex(
+        &Default::default(),
+        &Regex::OneOrMore(Box::new(Regex::CharSet(CharSet(vec![CharOrRange::Range(
+            'a', 'z',
+        )])))),
+        None,
+        2,
+    );
+    nfa.case_fold();
+
+    test_simulate(
+        &nfa,
+        vec![
+            ("SK", vec![("SK", 1)], None),
+            ("s\u{212A}", vec![("s\u{212A}", 1)], None),
+            ("aBc", vec![("aBc", 2)], None),
+            ("ſ", vec![("ſ", 2)], None),
+        ],
+    );
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn unicode_properties() {
+    use crate::unicode_properties::property_ranges;
+
+    let contains = |name: &str, char: char| {
+        property_ranges(name)
+            .unwrap()
+            .iter()
+            .any(|(start, end)| *start <= char as u32 && char as u32 <= *end)
+    };
+
+    assert!(contains("Lu", 'A'));
+    assert!(!contains("Lu", 'a'));
+    assert!(contains("Uppercase_Letter", 'Σ'));
+    assert!(contains("L", 'a'));
+    assert!(contains("Letter", '中'));
+    assert!(!contains("L", '1'));
+    assert!(contains("Nd", '٣'));
+    assert!(contains("Greek", 'λ'));
+    assert!(!contains("Greek", 'a'));
+    assert!(contains("Latin", 'a'));
+    assert!(contains("Han", '中'));
+    assert!(contains("Zs", ' '));
+    assert!(contains("C", '\u{7F}'));
+    assert!(!contains("C", 'a'));
+    assert!(property_ranges("Foo").is_none());
+    assert!(property_ranges("lu").is_none());
+
+    // Groups are merged
+    let letters = property_ranges("L").unwrap();
+    for ranges in letters.windows(2) {
+        assert!(ranges[0].1 + 1 < ranges[1].0);
+    }
+    assert_eq!(letters[0], (65, 90));
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn unicode_blocks_and_ages() {
+    use crate::unicode_properties::property_ranges;
+
+    let contains = |ranges: &[(u32, u32)], char: char| {
+        ranges
+            .iter()
+            .any(|(start, end)| *start <= char as u32 && char as u32 <= *end)
+    };
+
+    let arabic = property_ranges("Block=Arabic").unwrap();
+    assert_eq!(arabic, vec![(0x600, 0x6FF)]);
+    assert_eq!(property_ranges("blk=Arabic"), Some(arabic));
+    assert_eq!(
+        property_ranges("Block=Latin_1_Supplement"),
+        Some(vec![(0x80, 0xFF)])
+    );
+    assert_eq!(property_ranges("Block=basic_latin"), Some(vec![(0, 0x7F)]));
+    assert!(property_ranges("Block=Foo").is_none());
+
+    // Ages are cumulative, compare with the regex crate on all characters in the first few
+    // blocks, and a sample of the rest
+    let chars: Vec<char> = (0..0x3000u32)
+        .chain((0x3000..=char::MAX as u32).step_by(7))
+        .filter_map(char::from_u32)
+        .collect();
+    for version in ["1.1", "3.0", "6.1", "12.0", "16.0"] {
+        let ranges = property_ranges(&format!("Age={}", version)).unwrap();
+        let regex = regex::Regex::new(&format!(r"^\p{{Age={}}}$", version)).unwrap();
+        for &char in &chars {
+            assert_eq!(
+                contains(&ranges, char),
+                regex.is_match(char.encode_utf8(&mut [0; 4])),
+                "Age={} {:?}",
+                version,
+                char
+            );
+        }
+    }
+    assert!(contains(&property_ranges("age=1.1").unwrap(), 'a'));
+    assert!(!contains(&property_ranges("Age=6.0").unwrap(), '\u{1F600}'));
+    assert!(property_ranges("Age=12.2").is_none());
+    assert!(property_ranges("Age=twelve").is_none());
+    assert!(property_ranges("Script=Greek").is_none());
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn canonical_equivalents() {
+    use crate::normalization::expand_canonical_equivalents;
+
+    let mut nfa: NFA<usize> = NFA::new();
+    for (re, value) in [
+        (Regex::String("é".to_owned()), 1),
+        (Regex::Char('Å'), 2),
+        (Regex::String("x\u{323}\u{301}".to_owned()), 3),
+        (Regex::String("한".to_owned()), 4),
+        (Regex::String(";".to_owned()), 5),
+    ] {
+        nfa.add_regex(
+            &Default::default(),
+            &expand_canonical_equivalents(&re, &Default::default()),
+            None,
+            value,
+        );
+    }
+
+    let mut test_cases: Vec<(&str, Matches<usize>, Option<ErrorLoc>)> = vec![];
+    for (inputs, value) in [
+        (&["é", "e\u{301}", "e\u{341}"][..], 1),
+        (&["Å", "A\u{30A}", "\u{212B}"][..], 2),
+        (
+            &[
+                "x\u{323}\u{301}",
+                "x\u{301}\u{323}",
+                "x\u{341}\u{323}",
+                "x\u{323}\u{341}",
+            ][..],
+            3,
+        ),
+        (&["한", "\u{1112}\u{1161}\u{11AB}", "하\u{11AB}"][..], 4),
+        (&[";", "\u{37E}"][..], 5),
+    ] {
+        for input in inputs {
+            test_cases.push((input, vec![(input, value)], None));
+        }
+    }
+
+    test_simulate(&nfa, test_cases);
+}
+
+#[test]
+fn perl_classes() {
+    use crate::ast::Encoding;
+    use crate::builtin::perl_class;
+
+    let mut classes = vec![
+        ("d", Encoding::Ascii, r"(?-u:\d)"),
+        ("D", Encoding::Ascii, r"[^0-9]"),
+        ("w", Encoding::Ascii, r"(?-u:\w)"),
+        ("W", Encoding::Ascii, r"[^0-9A-Za-z_]"),
+        ("s", Encoding::Ascii, r"(?-u:\s)"),
+        ("S", Encoding::Ascii, r"[^\t-\r ]"),
+        ("s", Encoding::Unicode, r"\s"),
+        ("S", Encoding::Unicode, r"\S"),
+    ];
+
+    if cfg!(feature = "unicode") {
+        classes.extend([
+            ("d", Encoding::Unicode, r"\d"),
+            ("D", Encoding::Unicode, r"\D"),
+            ("w", Encoding::Unicode, r"\w"),
+            ("W", Encoding::Unicode, r"\W"),
+        ]);
+    } else {
+        assert!(perl_class("d", Encoding::Unicode).is_none());
+        assert!(perl_class("w", Encoding::Unicode).is_none());
+    }
+
+    // All characters in the first few blocks, and a sample of the rest
+    let chars: Vec<char> = (0..0x3000u32)
+        .chain((0x3000..=char::MAX as u32).step_by(7))
+        .filter_map(char::from_u32)
+        .collect();
+
+    for (name, encoding, pattern) in classes {
+        let mut nfa: NFA<usize> = NFA::new();
+        nfa.add_regex(
+            &Default::default(),
+            &perl_class(name, encoding).unwrap(),
+            None,
+            0,
+        );
+        let dfa = nfa_to_dfa(&nfa);
+
+        let regex = regex::Regex::new(&format!("^{}$", pattern)).unwrap();
+
+        for char in &chars {
+            let input = char.to_string();
+            let token = (vec![(input.as_str(), 0)], None);
+            assert_eq!(
+                dfa.simulate(&input, &RightCtxDFAs::new()) == token,
+                regex.is_match(&input),
+                "{} ({:?}) on {:?}",
+                name,
+                encoding,
+                char
+            );
+        }
+    }
+}
+
+#[test]
+fn derive_lexer_errors() {
+    use crate::derive::lexer_definition;
+
+    let error = |input: &str| -> String {
+        match lexer_definition(&syn::parse_str(input).unwrap()) {
+            Ok(definition) => panic!("Derive input accepted: {}", definition),
+            Err(error) => error.to_string(),
+        }
+    };
+
+    assert_eq!(
+        error("struct Token;"),
+        "`#[derive(Lexer)]` is only supported on enums"
+    );
+    assert_eq!(
+        error("enum Token<T> { #[lexer('a')] A(T) }"),
+        "Token enums with `#[derive(Lexer)]` can only have an `'input` lifetime parameter"
+    );
+    assert_eq!(
+        error("enum Token { #[lexer('a')] A(u32, u32) }"),
+        "Variants with regexes should have no fields, or one unnamed field that implements \
+        `From<&str>`"
+    );
+    assert_eq!(
+        error("#[lexer(foo = Bar)] enum Token { #[lexer('a')] A }"),
+        "Unknown `lexer` attribute, expected `name = <ident>` or `skip = <regex>`"
+    );
+    assert_eq!(
+        error("enum Token { #[lexer()] A }"),
+        "Expected a regex: `#[lexer(<regex>)]`"
+    );
+
+    let definition = lexer_definition(
+        &syn::parse_str("pub enum Token { #[lexer(\"+\")] Plus, #[lexer('-')] Minus, Eof }")
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        definition.to_string(),
+        "pub TokenLexer -> Token ; \"+\" = Token :: Plus , '-' = Token :: Minus ,"
+    );
+}
+
+#[test]
+fn grammar_json() {
+    use crate::ast::make_lexer_parser;
+    use crate::grammar::grammar_json;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let mut semantic_action_table = SemanticActionTable::new();
+    let lexer = make_lexer_parser(&mut semantic_action_table)
+        .parse_str(
+            r#"
+            Lexer -> Token<'input>;
+            grammar_json = true;
+
+            let id = ['a'-'z' '_'] ($$alphanumeric | '_')*;
+
+            rule Init {
+                [' ' '\t']+,
+                "\"" => |lexer| lexer.switch(LexerRule::String),
+                "+" = Token::Plus,
+                "async" if is_async = Token::Async,
+                $id > '(' = Token::Call,
+                $id => |lexer| lexer.return_(Token::Ident(lexer.match_())),
+            }
+
+            rule String {
+                '"' => |lexer| lexer.switch_and_return(LexerRule::Init, Token::Str),
+                (_ # ['"' '\\']) | "\\" _,
+                $ =? |lexer| lexer.return_(Err(Error::UnterminatedString)),
+            }
+            "#,
+        )
+        .unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&grammar_json(&lexer, &semantic_action_table)).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "name": "Lexer",
+            "token_type": "Token<'input>",
+            "bindings": [
+                { "name": "id", "pattern": "['a'-'z' '_'] ($$alphanumeric | '_')*" },
+            ],
+            "rule_sets": [
+                {
+                    "name": "Init",
+                    "rules": [
+                        {
+                            "pattern": "[' ' '\\t']+",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "skip",
+                            "token": null,
+                            "switches_to": [],
+                        },
+                        {
+                            "pattern": "\"\\\"\"",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "infallible",
+                            "token": "| lexer | lexer . switch (LexerRule::String)",
+                            "switches_to": ["String"],
+                        },
+                        {
+                            "pattern": "\"+\"",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "simple",
+                            "token": "Token::Plus",
+                            "switches_to": [],
+                        },
+                        {
+                            "pattern": "\"async\"",
+                            "right_context": null,
+                            "guard": "is_async",
+                            "kind": "simple",
+                            "token": "Token::Async",
+                            "switches_to": [],
+                        },
+                        {
+                            "pattern": "$id",
+                            "right_context": "'('",
+                            "guard": null,
+                            "kind": "simple",
+                            "token": "Token::Call",
+                            "switches_to": [],
+                        },
+                        {
+                            "pattern": "$id",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "infallible",
+                            "token": "| lexer | lexer . return_ (Token::Ident (lexer . match_ ()))",
+                            "switches_to": [],
+                        },
+                    ],
+                },
+                {
+                    "name": "String",
+                    "rules": [
+                        {
+                            "pattern": "'\"'",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "infallible",
+                            "token": "| lexer | lexer . switch_and_return (LexerRule::Init, Token::Str)",
+                            "switches_to": ["Init"],
+                        },
+                        {
+                            "pattern": "_ # ['\"' '\\\\'] | \"\\\\\" _",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "skip",
+                            "token": null,
+                            "switches_to": [],
+                        },
+                        {
+                            "pattern": "$",
+                            "right_context": null,
+                            "guard": null,
+                            "kind": "fallible",
+                            "token": "| lexer | lexer . return_ (Err (Error::UnterminatedString))",
+                            "switches_to": [],
+                        },
+                    ],
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn indentation_option_errors() {
+    assert_eq!(
+        parse_error("Lexer -> T; indentation = { newline: T::N, indent: T::I };").to_string(),
+        "Missing field \"dedent\" in `indentation` option"
+    );
+    assert_eq!(
+        parse_error("Lexer -> T; indentation = { newline: T::N, newline: T::N };").to_string(),
+        "Duplicate field \"newline\""
+    );
+    assert_eq!(
+        parse_error("Lexer -> T; indentation = { whitespace: tab_width(0) };").to_string(),
+        "Tab width can't be 0"
+    );
+    assert_eq!(
+        parse_error("Lexer -> T; indentation = { whitespace: mixed };").to_string(),
+        "Unknown indentation whitespace, expected \"spaces\", \"tabs\", or \"tab_width(<width>)\""
+    );
+}
+
+#[test]
+fn tag_rule_sets_option_errors() {
+    let indentation = "indentation = { newline: T::N, indent: T::I, dedent: T::D };";
+
+    assert_eq!(
+        compile_error(&format!(
+            "Lexer -> T; tag_rule_sets = true; {} ' ',",
+            indentation
+        ))
+        .to_string(),
+        "`tag_rule_sets = true;` is not supported with `indentation`"
+    );
+    assert_eq!(
+        compile_error(&format!(
+            "Lexer -> T; {} tag_rule_sets = true; ' ',",
+            indentation
+        ))
+        .to_string(),
+        "`tag_rule_sets = true;` is not supported with `indentation`"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; tag_rule_sets = true; tag_rule_sets = false; ' ',").to_string(),
+        "Option \"tag_rule_sets\" is defined multiple times"
+    );
+}
+
+#[test]
+fn include_rules_errors() {
+    let dir = std::env::temp_dir().join(format!("lexgen_include_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let recursive = dir.join("recursive.lexgen");
+    std::fs::write(
+        &recursive,
+        format!(
+            "\"a\" = 1, include_rules!({:?});",
+            recursive.to_str().unwrap()
+        ),
+    )
+    .unwrap();
+
+    let error = |lexer: &str| compile_error(lexer).to_string();
+
+    assert_eq!(
+        error(&format!(
+            "Lexer -> u32; include_rules!({:?});",
+            recursive.to_str().unwrap()
+        )),
+        format!("{} is included recursively", recursive.display())
+    );
+    assert!(
+        error("Lexer -> u32; rule Init { include_rules!(\"does_not_exist.lexgen\"); }")
+            .starts_with("Unable to read ")
+    );
+    assert_eq!(
+        error("Lexer -> u32; include_rules!(keywords);"),
+        "`include_rules` syntax is: `include_rules!(\"<path>\");`"
+    );
+    assert_eq!(
+        error("Lexer -> u32; include_rules!(\"keywords.lexgen\")"),
+        "`include_rules` syntax is: `include_rules!(\"<path>\");`"
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn lexer_input_lifetime_param_error() {
+    use crate::ast::make_lexer_parser;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let error = make_lexer_parser(&mut SemanticActionTable::new())
+        .parse_str("Lexer<'input> -> Token<'input>; ' ',")
+        .map(|_| ())
+        .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "`'input` is the lifetime of the input and can't be declared as a parameter"
+    );
+}
+
+#[test]
+fn options_block_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; options { driver } ' ',").to_string(),
+        "Option \"driver\" needs a value: `driver = <value>`"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; options { foo = true } ' ',").to_string(),
+        "Unknown option \"foo\""
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; options { lalrpop, lalrpop = false } ' ',").to_string(),
+        "Option \"lalrpop\" is defined multiple times"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; options { const_fn } const_fn = true; ' ',").to_string(),
+        "Option \"const_fn\" is defined multiple times"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; options { lalrpop dfa_table } ' ',").to_string(),
+        "expected `,`"
+    );
+}
+
+#[test]
+fn skip_option_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; skip = [' ']; skip = ['\\t']; ' ' = T,").to_string(),
+        "Option \"skip\" is defined multiple times"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init exclusive exclusive { ' ' = T, }").to_string(),
+        "Duplicate rule set modifier \"exclusive\""
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init inclusive { ' ' = T, }").to_string(),
+        "Unknown rule set modifier, expected \"nested\" or \"exclusive\""
+    );
+}
+
+#[test]
+fn fail_rule_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init { ' ', fail, }").to_string(),
+        "`fail` rules should have a right-hand side: `=>`, `=?`, or `=`"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init { fail = T::A, ' ', fail = T::B, }").to_string(),
+        "Rule set \"Init\" has multiple `fail` rules"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; ' ', fail = T::A, fail = T::B,").to_string(),
+        "Rule set \"Init\" has multiple `fail` rules"
+    );
+}
+
+#[test]
+fn tie_breaking_errors() {
+    use crate::ast::make_lexer_parser;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    assert_eq!(
+        compile_error("Lexer -> T; tie_breaking = error; ['a'-'z']+ = T::A, \"if\" = T::B,")
+            .to_string(),
+        "This rule and rule 1 in rule set Init both match \"if\". Rules can't match the same \
+        input with `tie_breaking = error;`."
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; 'a' = T::A, 'b' priority 1 = T::B,").to_string(),
+        "Rule priorities require `tie_breaking = priority;`"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; tie_breaking = first;").to_string(),
+        "Unknown tie-breaking policy, expected \"order\", \"priority\", or \"error\""
+    );
+
+    // Rules that match different inputs, and rules of the `skip` option, are fine
+    assert!(make_lexer_parser(&mut SemanticActionTable::new())
+        .parse_str(
+            "Lexer -> T; tie_breaking = error; skip = [' ', ' '+]; \
+            ['a'-'z']+ = T::A, ['0'-'9']+ = T::B,"
+        )
+        .and_then(crate::compile::compile)
+        .is_ok());
+}
+
+#[test]
+fn shortest_rule_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; 'a' _* 'b' if f shortest = T::A,").to_string(),
+        "`shortest` rules can't have a guard or a right context"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; 'a' _* 'b' > 'c' shortest = T::A,").to_string(),
+        "`shortest` rules can't have a guard or a right context"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; 'a' _* 'b' shortest shortest = T::A,").to_string(),
+        "Rule has multiple `shortest` modifiers"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; tie_breaking = priority; 'a' priority 1 priority 2 = T::A,")
+            .to_string(),
+        "Rule has multiple `priority` modifiers"
+    );
+}
+
+#[test]
+fn shared_rule_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init { 'a' = T::A, } <Init, Strng, Cmment> 'b' = T::B,")
+            .to_string(),
+        "Rule set \"Strng\" is not defined"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init { 'a' = T::A, } <Init, Init> 'b' = T::B,").to_string(),
+        "Rule set \"Init\" is listed multiple times"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; rule Init { 'a' = T::A, } <> 'b' = T::B,").to_string(),
+        "Expected at least one rule set name: `<<Ident>, ...> <rule>`"
+    );
+}
+
+#[test]
+fn token_kind_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; token_kind = true; 'a' = T::A, 'b' = T::Other,").to_string(),
+        "`token_kind = true;` generates a variant `Other` for tokens returned by semantic \
+        actions, which is also the name of this token"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; token_kind = true; 'a' = T::A, 'b' = U::A,").to_string(),
+        "Token kind `A` is generated for multiple tokens with `token_kind = true;`"
+    );
+}
+
+#[test]
+fn spanned_tokens_lalrpop_error() {
+    let message = "`spanned_tokens = true;` is not supported with `lalrpop = true;`, LALRPOP \
+        parsers take `(start, token, end)` tuples";
+    assert_eq!(
+        compile_error("Lexer -> T; spanned_tokens = true; lalrpop = true; 'a' = T::A,").to_string(),
+        message
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; lalrpop = true; spanned_tokens = true; 'a' = T::A,").to_string(),
+        message
+    );
+}
+
+#[test]
+fn capture_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; ('a' as x)* = T::A,").to_string(),
+        "Captures (`(<regex> as <name>)`) can only be used in the top-level concatenation of a \
+        rule's regex, not in `|`, `*`, `+`, or `?`"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; ('a' as x) | 'b' = T::A,").to_string(),
+        "Captures (`(<regex> as <name>)`) can only be used in the top-level concatenation of a \
+        rule's regex, not in `|`, `*`, `+`, or `?`"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; (('a' as x) 'b' as y) = T::A,").to_string(),
+        "Capture \"y\" has captures in it. Captures can't be nested."
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; ('a' as x) ('b' as x) = T::A,").to_string(),
+        "Capture \"x\" is defined multiple times"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; let a = ('a' as x); $a = T::A,").to_string(),
+        "Captures (`(<regex> as <name>)`) can't be used in variables"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; 'a' > ('b' as x) = T::A,").to_string(),
+        "Captures (`(<regex> as <name>)`) can't be used in right contexts"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; ('a' as x) # 'b' = T::A,").to_string(),
+        "Captures (`(<regex> as <name>)`) cannot be used in character sets (`#`)"
+    );
+}
+
+#[test]
+fn regex_simplification() {
+    use crate::ast::{make_lexer_parser, Rule, SingleRule};
+    use crate::display::RegexDisplay;
+    use crate::regex_simplify::simplify;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let simplified = |re: &str| -> String {
+        let lexer = make_lexer_parser(&mut SemanticActionTable::new())
+            .parse_str(&format!("Lexer -> (); {} = (),", re))
+            .unwrap();
+        match lexer.rules.into_iter().next().unwrap() {
+            Rule::UnnamedRules { rules, .. } => {
+                let SingleRule { lhs, .. } = rules.into_iter().next().unwrap();
+                RegexDisplay(&simplify(&lhs.re)).to_string()
+            }
+            _ => panic!(),
+        }
+    };
+
+    assert_eq!(
+        simplified(r#""if" | "in" | "int" | "else""#),
+        r#"'i' ('f' | 'n' 't'?) | "else""#
+    );
+    assert_eq!(
+        simplified(r#"'a' | $x | ['b'-'c'] | 'd'"#),
+        "['a' 'b'-'c' 'd'] | $x"
+    );
+    assert_eq!(simplified(r#"'a' "bc" 'd' $x 'e'"#), r#""abcd" $x 'e'"#);
+    assert_eq!(
+        simplified(r#"('a' 'b' as x) ("cd" | "ce")*"#),
+        r#"("ab" as x) ('c' ['d' 'e'])*"#
+    );
+}
+
+#[test]
+fn interner_type_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; type Interner = A; type Interner = B; ' ',").to_string(),
+        "Interner type defined multiple times"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; type Symbol = A; ' ',").to_string(),
+        "Type declaration syntax is: `type Error = ...;`, `type Feedback = ...;`, or \
+         `type Interner = ...;`"
+    );
+}
+
+#[test]
+fn rule_group_errors() {
+    assert_eq!(
+        compile_error("Lexer -> T; group A { group B { 'a' = T, } }").to_string(),
+        "Groups can't be nested"
+    );
+    assert_eq!(
+        compile_error("Lexer -> T; group A { 'a' shortest = T, }").to_string(),
+        "`fail`, `fn`, `$$invalid_utf8`, and `shortest` rules can't be in groups"
+    );
+
+    let groups: String = (0..65)
+        .map(|i| format!("group G{} {{ \"{}\" = T, }}", i, i))
+        .collect();
+    assert_eq!(
+        compile_error(&format!("Lexer -> T; {}", groups)).to_string(),
+        "Lexers can have at most 64 groups"
+    );
+}
+
+#[test]
+fn expand_pretty_print() {
+    use quote::quote;
+
+    let code = crate::lexer_(quote!(
+        Lexer -> usize;
+        "a" = 1,
+    ))
+    .unwrap();
+    let pretty = crate::expand::pretty_print(&code);
+
+    // Formatted with one item per line, rather than falling back to the unformatted tokens
+    assert!(pretty.contains("\nstruct Lexer<'input, I: Iterator<Item = char> + Clone>("));
+    assert!(syn::parse_file(&pretty).is_ok());
+}
+
+#[test]
+fn grammar_json_renamed_rule_enum() {
+    use crate::ast::make_lexer_parser;
+    use crate::grammar::grammar_json;
+    use crate::semantic_action_table::SemanticActionTable;
+
+    use syn::parse::Parser;
+
+    let mut semantic_action_table = SemanticActionTable::new();
+    let lexer = make_lexer_parser(&mut semantic_action_table)
+        .parse_str(
+            r#"
+            Lexer -> Token;
+            rule_enum_name = Mode;
+
+            rule Init {
+                '"' => |lexer| lexer.switch(Mode::String),
+            }
+
+            rule String {
+                '"' => |lexer| lexer.switch_and_return(Mode::Init, Token::Str),
+            }
+            "#,
+        )
+        .unwrap();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&grammar_json(&lexer, &semantic_action_table)).unwrap();
+    assert_eq!(
+        json["rule_sets"][0]["rules"][0]["switches_to"],
+        serde_json::json!(["String"])
+    );
+    assert_eq!(
+        json["rule_sets"][1]["rules"][0]["switches_to"],
+        serde_json::json!(["Init"])
+    );
+}
+
+#[test]
+fn inline_test_errors() {
+    use quote::quote;
+
+    let error = |lexer: proc_macro2::TokenStream| -> String {
+        match crate::lexer_(lexer) {
+            Ok(_) => panic!("Lexer compiled without errors"),
+            Err(error) => error.to_string(),
+        }
+    };
+
+    assert_eq!(
+        error(quote!(Lexer -> usize; "a" = 1, tests { "a" => fail@0, })),
+        "Expected a list of tokens `[...]` or an error location `error@<byte index>`"
+    );
+    assert_eq!(
+        error(quote!(Lexer -> usize; "a" = 1, tests { "a" => error@x, })),
+        "expected integer literal"
+    );
+    assert_eq!(
+        error(quote!(Lexer<T> -> T; tests { "" => [], })),
+        "`tests` sections are not supported in lexers with type or const parameters"
+    );
+}
+
+#[test]
+fn const_fn_errors() {
+    // Returns the error message and the line and column of the error
+    let error = |lexer: &str| -> (String, usize, usize) {
+        let error = compile_error(lexer);
+        let start = error.span().start();
+        (error.to_string(), start.line, start.column)
+    };
+
+    let (msg, line, column) =
+        error("Lexer -> usize;\nconst_fn = true;\n' ',\n\"a\" => |lexer| lexer.return_(1),");
+    assert_eq!(
+        msg,
+        "Only rules without a right-hand side (`<regex>,`) and simple rules \
+        (`<regex> = <token>,`) are supported with `const_fn = true;`"
+    );
+    assert_eq!((line, column), (4, 0));
+
+    let (msg, line, column) =
+        error("Lexer -> usize;\nconst_fn = true;\n\"b\" = 2,\n\"a\" > \"b\" = 1,");
+    assert_eq!(
+        msg,
+        "Right contexts are not supported with `const_fn = true;`"
+    );
+    assert_eq!((line, column), (4, 0));
+
+    let (msg, line, column) = error("Lexer -> usize;\nconst_fn = true;\n' ',\n'a'* = 1,");
+    assert_eq!(
+        msg,
+        "Rules that match the empty string are not supported with `const_fn = true;`"
+    );
+    assert_eq!((line, column), (4, 0));
+}
+
+#[test]
+fn next_token_errors() {
+    // Returns the error message and the line and column of the error
+    let error = |lexer: &str| -> (String, usize, usize) {
+        let error = compile_error(lexer);
+        let start = error.span().start();
+        (error.to_string(), start.line, start.column)
+    };
+
+    let (msg, line, column) = error(
+        "Lexer -> T;\nnext_token = true;\nindentation = { newline: T::N, indent: T::I, dedent: T::D };",
+    );
+    assert_eq!(
+        msg,
+        "`next_token = true;` is not supported with `indentation`"
+    );
+    assert_eq!((line, column), (3, 0));
+
+    let (msg, line, column) = error(
+        "Lexer -> T;\nnext_token = true;\nrule Init { '(' = T::A, }\nrule C nested('(', ')') { _, }",
+    );
+    assert_eq!(
+        msg,
+        "`nested` rule sets are not supported with `next_token = true;`"
+    );
+    assert_eq!((line, column), (4, 5));
+
+    let (msg, line, column) = error("Lexer -> T;\nnext_token = true;\ngroup G { 'a' = T::A, }");
+    assert_eq!(
+        msg,
+        "Rule groups are not supported with `next_token = true;`"
+    );
+    assert_eq!((line, column), (3, 6));
+
+    let (msg, line, column) =
+        error("Lexer -> T;\nnext_token = true;\ntype Feedback = F;\n'a' = T::A,");
+    assert_eq!(
+        msg,
+        "Feedback types are not supported with `next_token = true;`"
+    );
+    assert_eq!((line, column), (3, 16));
+
+    let (msg, line, column) =
+        error("Lexer -> T;\nnext_token = true;\ntype Interner = I;\n'a' = T::A,");
+    assert_eq!(
+        msg,
+        "Interner types are not supported with `next_token = true;`"
+    );
+    assert_eq!((line, column), (3, 16));
+}
+
+#[test]
+fn layout_profile_error() {
+    let error = compile_error(
+        "Lexer -> usize;\nlayout_profile = \"tests/no_such_profile.txt\";\n\"a\" = 1,",
+    );
+    assert!(
+        error
+            .to_string()
+            .starts_with("Unable to read layout profile"),
+        "{}",
+        error
+    );
+    let start = error.span().start();
+    assert_eq!((start.line, start.column), (2, 17));
+}
+
+#[test]
+fn lexer_definition_error_spans() {
+    // Returns the error message and the line and column of the error
+    let error = |lexer: &str| -> (String, usize, usize) {
+        let error = compile_error(lexer);
+        let start = error.span().start();
+        (error.to_string(), start.line, start.column)
+    };
+
+    let (msg, line, column) = error("Lexer -> u32;\n' ',\n$undefined = 1,");
+    assert_eq!(msg, "Variable \"undefined\" is not defined");
+    assert_eq!((line, column), (3, 0));
+
+    let (msg, line, column) = error("Lexer -> u32;\nlet a = $b;\n$a = 1,");
+    assert_eq!(msg, "Variable \"b\" is not defined");
+    assert_eq!((line, column), (2, 4));
+
+    let (msg, line, column) = error("Lexer -> u32;\n'a' > $undefined = 1,");
+    assert_eq!(msg, "Variable \"undefined\" is not defined");
+    assert_eq!((line, column), (2, 0));
+
+    let (msg, line, column) = error("Lexer -> u32;\nlet a = 'a';\nlet a = 'b';\n$a = 1,");
+    assert_eq!(msg, "Variable \"a\" is defined multiple times");
+    assert_eq!((line, column), (3, 4));
+
+    let (msg, line, column) =
+        error("Lexer -> u32;\nrule Init { 'a' = 1, }\nrule Init { 'b' = 2, }");
+    assert_eq!(msg, "Rule set \"Init\" is defined multiple times");
+    assert_eq!((line, column), (3, 5));
+
+    let (msg, line, column) = error("Lexer -> u32;\nrule Foo { 'a' = 1, }\nrule Init { }");
+    assert_eq!(msg, "First rule set should be named \"Init\"");
+    assert_eq!((line, column), (2, 5));
+
+    let (msg, line, column) = error("Lexer -> u32;\nrule Init { 'a' = 1, }\n'b' = 2,");
+    assert!(
+        msg.starts_with("Unnamed rules cannot be mixed with named rules"),
+        "{}",
+        msg
+    );
+    assert_eq!((line, column), (3, 0));
+
+    let (msg, line, column) = error("Lexer -> u32;\nlet a = 'a';");
+    assert_eq!(msg, "There should be a rule set named \"Init\"");
+    assert_eq!((line, column), (1, 0));
+
+    let (msg, line, column) = error("Lexer -> u32;\ntype Error = A;\ntype Error = B;\n'a' = 1,");
+    assert_eq!(msg, "Error type defined multiple times");
+    assert_eq!((line, column), (3, 13));
+
+    let (msg, line, column) = error("Lexer -> u32;\nlalrpop = true;\nlalrpop = false;\n'a' = 1,");
+    assert_eq!(msg, "Option \"lalrpop\" is defined multiple times");
+    assert_eq!((line, column), (3, 0));
+
+    let (msg, line, column) = error("Lexer -> u32;\nencoding = ascii;\n'a' = 1,\n\"é\" = 2,");
+    assert_eq!(msg, "Non-ASCII character 'é' used in an ASCII lexer");
+    assert_eq!((line, column), (4, 0));
+
+    let (msg, line, column) = error("Lexer -> u32;\nencoding = ascii;\n['a'-'z' 'é'] = 1,");
+    assert_eq!(msg, "Non-ASCII character 'é' used in an ASCII lexer");
+    assert_eq!((line, column), (3, 9));
+
+    let (msg, line, column) =
+        error("Lexer -> u32;\nlet x = 'a' ['b' 0xE9];\nencoding = ascii;\n$x = 1,");
+    assert_eq!(msg, "Non-ASCII character 'é' used in an ASCII lexer");
+    assert_eq!((line, column), (2, 17));
+
+    let (msg, line, column) =
+        error("Lexer -> u32;\nencoding = ascii;\nskip = [' ' | ('\\u{a0}')];\n'a' = 1,");
+    assert_eq!(msg, "Non-ASCII character '\\u{a0}' used in an ASCII lexer");
+    assert_eq!((line, column), (3, 15));
+}
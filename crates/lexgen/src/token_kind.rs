@@ -0,0 +1,140 @@
+//! Token kind enums, generated with `token_kind = true;`: a fieldless enum with a variant for each
+//! token returned by simple rules (`<regex> = <path>`), with `Display` descriptions derived from
+//! the patterns of the rules, and a function that maps tokens to their kinds.
+
+use crate::ast::{Lexer, Rule, RuleKind, RuleRhs, SingleRule};
+use crate::dfa::codegen::lifetime_params_args;
+use crate::display::RegexDisplay;
+use crate::semantic_action_table::SemanticActionTable;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+/// Variant for tokens that are not returned by simple rules
+const OTHER_VARIANT: &str = "Other";
+
+/// A token returned by simple rules, with the patterns of the rules
+pub struct TokenKind {
+    path: syn::Path,
+    patterns: Vec<String>,
+}
+
+/// Collects tokens returned by simple rules, in the order they're first returned in the lexer
+/// definition. Generated before compiling the lexer as it needs the rules.
+pub fn token_kinds(lexer: &Lexer, semantic_action_table: &SemanticActionTable) -> Vec<TokenKind> {
+    let mut kinds: Vec<TokenKind> = vec![];
+
+    for rule in &lexer.rules {
+        let rules: &[SingleRule] = match rule {
+            Rule::RuleSet { rules, .. } | Rule::UnnamedRules { rules, .. } => rules,
+            Rule::SharedRule { rule, .. } => std::slice::from_ref(rule),
+            Rule::Binding { .. }
+            | Rule::ErrorType { .. }
+            | Rule::FeedbackType { .. }
+            | Rule::InternerType { .. }
+            | Rule::Option { .. }
+            | Rule::Tests { .. } => continue,
+        };
+
+        for rule in rules {
+            let path = match semantic_action_table.get(rule.rhs) {
+                RuleRhs::Rhs {
+                    expr: syn::Expr::Path(path),
+                    kind: RuleKind::Simple,
+                } => &path.path,
+                _ => continue,
+            };
+
+            // `fn` and `fail` rules don't have patterns, they're described by the token name
+            let pattern = if rule.matcher.is_some() || rule.fail {
+                path.segments.last().unwrap().ident.to_string()
+            } else {
+                RegexDisplay(&rule.lhs.re).to_string()
+            };
+
+            match kinds.iter_mut().find(|kind| kind.path == *path) {
+                Some(kind) => {
+                    if !kind.patterns.contains(&pattern) {
+                        kind.patterns.push(pattern);
+                    }
+                }
+                None => kinds.push(TokenKind {
+                    path: path.clone(),
+                    patterns: vec![pattern],
+                }),
+            }
+        }
+    }
+
+    kinds
+}
+
+/// Generates the `<lexer name>TokenKind` enum (`kind_name`). Variants are named after the last
+/// segment of the token paths, with an `Other` variant for tokens returned by semantic actions.
+pub fn reify_token_kind(
+    kinds: &[TokenKind],
+    kind_name: &syn::Ident,
+    generics: &syn::Generics,
+    token_type: &syn::Type,
+    visibility: &syn::Visibility,
+) -> syn::Result<TokenStream> {
+    let mut variants: Vec<&syn::Ident> = Vec::with_capacity(kinds.len());
+    for kind in kinds {
+        let variant = &kind.path.segments.last().unwrap().ident;
+        if variant == OTHER_VARIANT {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!(
+                    "`token_kind = true;` generates a variant `{}` for tokens returned by \
+                    semantic actions, which is also the name of this token",
+                    OTHER_VARIANT
+                ),
+            ));
+        }
+        if variants.contains(&variant) {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!(
+                    "Token kind `{}` is generated for multiple tokens with `token_kind = true;`",
+                    variant
+                ),
+            ));
+        }
+        variants.push(variant);
+    }
+
+    let paths = kinds.iter().map(|kind| &kind.path);
+    let descriptions = kinds.iter().map(|kind| kind.patterns.join(" or "));
+    let other = syn::Ident::new(OTHER_VARIANT, Span::call_site());
+    let (lifetime_params, _) = lifetime_params_args(generics);
+
+    Ok(quote!(
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(missing_docs)]
+        #visibility enum #kind_name {
+            #(#variants,)*
+            #other,
+        }
+
+        #[allow(missing_docs)]
+        impl #kind_name {
+            #[allow(unreachable_patterns)]
+            #visibility fn kind<'input #lifetime_params>(token: &#token_type) -> #kind_name {
+                match token {
+                    #(#paths { .. } => #kind_name::#variants,)*
+                    _ => #kind_name::#other,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::fmt::Display for #kind_name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(match self {
+                    #(#kind_name::#variants => #descriptions,)*
+                    #kind_name::#other => "other token",
+                })
+            }
+        }
+    ))
+}
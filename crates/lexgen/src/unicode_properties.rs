@@ -0,0 +1,182 @@
+//! Unicode general categories, scripts, blocks, and ages, for `$$p{<name>}` regexes. Tables are generated from
+//! the Unicode Character Database by `char_range_gen`, and are only included with the `unicode`
+//! feature.
+
+#[cfg(feature = "unicode")]
+use crate::unicode_tables::{
+    AGES, BLOCKS, EMOJI_PROPERTIES, GENERAL_CATEGORIES, GRAPHEME_CLUSTER_BREAKS, SCRIPTS,
+};
+
+#[cfg(feature = "unicode")]
+/// General categories as short name, long name, and the two-letter categories in the category
+static CATEGORIES: [(&str, &str, &[&str]); 37] = [
+    ("C", "Other", &["Cc", "Cf", "Cn", "Co"]),
+    ("Cc", "Control", &["Cc"]),
+    ("Cf", "Format", &["Cf"]),
+    ("Cn", "Unassigned", &["Cn"]),
+    ("Co", "Private_Use", &["Co"]),
+    ("L", "Letter", &["Ll", "Lm", "Lo", "Lt", "Lu"]),
+    ("LC", "Cased_Letter", &["Ll", "Lt", "Lu"]),
+    ("Ll", "Lowercase_Letter", &["Ll"]),
+    ("Lm", "Modifier_Letter", &["Lm"]),
+    ("Lo", "Other_Letter", &["Lo"]),
+    ("Lt", "Titlecase_Letter", &["Lt"]),
+    ("Lu", "Uppercase_Letter", &["Lu"]),
+    ("M", "Mark", &["Mc", "Me", "Mn"]),
+    ("Mc", "Spacing_Mark", &["Mc"]),
+    ("Me", "Enclosing_Mark", &["Me"]),
+    ("Mn", "Nonspacing_Mark", &["Mn"]),
+    ("N", "Number", &["Nd", "Nl", "No"]),
+    ("Nd", "Decimal_Number", &["Nd"]),
+    ("Nl", "Letter_Number", &["Nl"]),
+    ("No", "Other_Number", &["No"]),
+    (
+        "P",
+        "Punctuation",
+        &["Pc", "Pd", "Pe", "Pf", "Pi", "Po", "Ps"],
+    ),
+    ("Pc", "Connector_Punctuation", &["Pc"]),
+    ("Pd", "Dash_Punctuation", &["Pd"]),
+    ("Pe", "Close_Punctuation", &["Pe"]),
+    ("Pf", "Final_Punctuation", &["Pf"]),
+    ("Pi", "Initial_Punctuation", &["Pi"]),
+    ("Po", "Other_Punctuation", &["Po"]),
+    ("Ps", "Open_Punctuation", &["Ps"]),
+    ("S", "Symbol", &["Sc", "Sk", "Sm", "So"]),
+    ("Sc", "Currency_Symbol", &["Sc"]),
+    ("Sk", "Modifier_Symbol", &["Sk"]),
+    ("Sm", "Math_Symbol", &["Sm"]),
+    ("So", "Other_Symbol", &["So"]),
+    ("Z", "Separator", &["Zl", "Zp", "Zs"]),
+    ("Zl", "Line_Separator", &["Zl"]),
+    ("Zp", "Paragraph_Separator", &["Zp"]),
+    ("Zs", "Space_Separator", &["Zs"]),
+];
+
+/// Whether `$$p{...}` regexes are supported, i.e. lexgen is built with the `unicode` feature
+pub const ENABLED: bool = cfg!(feature = "unicode");
+
+/// Sorted, non-overlapping ranges of characters in the general category or script with the given
+/// name. General categories can be given with short (`Lu`) or long (`Uppercase_Letter`) names,
+/// scripts with long names (`Greek`). Blocks and ages are given as `Block=<name>` and
+/// `Age=<version>`, see `property_value_ranges`. Returns `None` for unknown names, and when the
+/// `unicode` feature is disabled.
+#[cfg(feature = "unicode")]
+pub fn property_ranges(name: &str) -> Option<Vec<(u32, u32)>> {
+    if let Some((property, value)) = name.split_once('=') {
+        return property_value_ranges(property, value);
+    }
+
+    if let Some((_, _, categories)) = CATEGORIES
+        .iter()
+        .find(|(short, long, _)| *short == name || *long == name)
+    {
+        return Some(merge_ranges(
+            categories
+                .iter()
+                .flat_map(|category| find_table(&GENERAL_CATEGORIES, category).unwrap())
+                .copied()
+                .collect(),
+        ));
+    }
+
+    find_table(&SCRIPTS, name).map(|ranges| ranges.to_vec())
+}
+
+/// Ranges of characters for `Block=<name>` (or `blk=<name>`) and `Age=<version>` (or
+/// `age=<version>`).
+///
+/// Block names are matched loosely as in UAX #44: case, spaces, underscores, and dashes are
+/// ignored, so `Latin_1_Supplement` is the block `Latin-1 Supplement`.
+///
+/// Ages are cumulative as in UTS #18: `Age=12.0` is the characters assigned in Unicode 12.0 or
+/// earlier.
+#[cfg(feature = "unicode")]
+fn property_value_ranges(property: &str, value: &str) -> Option<Vec<(u32, u32)>> {
+    match property {
+        "Block" | "blk" => {
+            let value = loose_name(value);
+            BLOCKS
+                .iter()
+                .find(|(name, _)| loose_name(name) == value)
+                .map(|(_, ranges)| ranges.to_vec())
+        }
+        "Age" | "age" => {
+            let version = parse_version(value)?;
+            if !AGES
+                .iter()
+                .any(|(age, _)| parse_version(age) == Some(version))
+            {
+                return None;
+            }
+            Some(merge_ranges(
+                AGES.iter()
+                    .filter(|(age, _)| parse_version(age).unwrap() <= version)
+                    .flat_map(|(_, ranges)| ranges.iter())
+                    .copied()
+                    .collect(),
+            ))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "unicode")]
+fn loose_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Parse a Unicode version in `<major>.<minor>` form
+#[cfg(feature = "unicode")]
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let (major, minor) = version.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+/// Sort and merge overlapping and adjacent ranges
+#[cfg(feature = "unicode")]
+fn merge_ranges(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some((_, last_end)) if *last_end + 1 >= start => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[cfg(not(feature = "unicode"))]
+pub fn property_ranges(_name: &str) -> Option<Vec<(u32, u32)>> {
+    None
+}
+
+/// Sorted, non-overlapping ranges of characters with the Grapheme_Cluster_Break property value.
+/// Panics for unknown values.
+#[cfg(feature = "unicode")]
+pub fn grapheme_cluster_break_ranges(value: &str) -> &'static [(u32, u32)] {
+    find_table(&GRAPHEME_CLUSTER_BREAKS, value)
+        .unwrap_or_else(|| panic!("Unknown Grapheme_Cluster_Break value {:?}", value))
+}
+
+/// Sorted, non-overlapping ranges of Extended_Pictographic characters
+#[cfg(feature = "unicode")]
+pub fn extended_pictographic_ranges() -> &'static [(u32, u32)] {
+    find_table(&EMOJI_PROPERTIES, "Extended_Pictographic").unwrap()
+}
+
+#[cfg(feature = "unicode")]
+fn find_table(
+    tables: &[(&str, &'static [(u32, u32)])],
+    name: &str,
+) -> Option<&'static [(u32, u32)]> {
+    tables
+        .binary_search_by_key(&name, |(name, _)| name)
+        .ok()
+        .map(|idx| tables[idx].1)
+}
@@ -0,0 +1,12382 @@
+// Generated by char_range_gen from the Unicode Character Database 16.0.0, do not edit.
+
+pub static GC_CC: [(u32, u32); 2] = [(0, 31), (127, 159)];
+
+pub static GC_CF: [(u32, u32); 21] = [
+    (173, 173),
+    (1536, 1541),
+    (1564, 1564),
+    (1757, 1757),
+    (1807, 1807),
+    (2192, 2193),
+    (2274, 2274),
+    (6158, 6158),
+    (8203, 8207),
+    (8234, 8238),
+    (8288, 8292),
+    (8294, 8303),
+    (65279, 65279),
+    (65529, 65531),
+    (69821, 69821),
+    (69837, 69837),
+    (78896, 78911),
+    (113824, 113827),
+    (119155, 119162),
+    (917505, 917505),
+    (917536, 917631),
+];
+
+pub static GC_CN: [(u32, u32); 731] = [
+    (888, 889),
+    (896, 899),
+    (907, 907),
+    (909, 909),
+    (930, 930),
+    (1328, 1328),
+    (1367, 1368),
+    (1419, 1420),
+    (1424, 1424),
+    (1480, 1487),
+    (1515, 1518),
+    (1525, 1535),
+    (1806, 1806),
+    (1867, 1868),
+    (1970, 1983),
+    (2043, 2044),
+    (2094, 2095),
+    (2111, 2111),
+    (2140, 2141),
+    (2143, 2143),
+    (2155, 2159),
+    (2191, 2191),
+    (2194, 2198),
+    (2436, 2436),
+    (2445, 2446),
+    (2449, 2450),
+    (2473, 2473),
+    (2481, 2481),
+    (2483, 2485),
+    (2490, 2491),
+    (2501, 2502),
+    (2505, 2506),
+    (2511, 2518),
+    (2520, 2523),
+    (2526, 2526),
+    (2532, 2533),
+    (2559, 2560),
+    (2564, 2564),
+    (2571, 2574),
+    (2577, 2578),
+    (2601, 2601),
+    (2609, 2609),
+    (2612, 2612),
+    (2615, 2615),
+    (2618, 2619),
+    (2621, 2621),
+    (2627, 2630),
+    (2633, 2634),
+    (2638, 2640),
+    (2642, 2648),
+    (2653, 2653),
+    (2655, 2661),
+    (2679, 2688),
+    (2692, 2692),
+    (2702, 2702),
+    (2706, 2706),
+    (2729, 2729),
+    (2737, 2737),
+    (2740, 2740),
+    (2746, 2747),
+    (2758, 2758),
+    (2762, 2762),
+    (2766, 2767),
+    (2769, 2783),
+    (2788, 2789),
+    (2802, 2808),
+    (2816, 2816),
+    (2820, 2820),
+    (2829, 2830),
+    (2833, 2834),
+    (2857, 2857),
+    (2865, 2865),
+    (2868, 2868),
+    (2874, 2875),
+    (2885, 2886),
+    (2889, 2890),
+    (2894, 2900),
+    (2904, 2907),
+    (2910, 2910),
+    (2916, 2917),
+    (2936, 2945),
+    (2948, 2948),
+    (2955, 2957),
+    (2961, 2961),
+    (2966, 2968),
+    (2971, 2971),
+    (2973, 2973),
+    (2976, 2978),
+    (2981, 2983),
+    (2987, 2989),
+    (3002, 3005),
+    (3011, 3013),
+    (3017, 3017),
+    (3022, 3023),
+    (3025, 3030),
+    (3032, 3045),
+    (3067, 3071),
+    (3085, 3085),
+    (3089, 3089),
+    (3113, 3113),
+    (3130, 3131),
+    (3141, 3141),
+    (3145, 3145),
+    (3150, 3156),
+    (3159, 3159),
+    (3163, 3164),
+    (3166, 3167),
+    (3172, 3173),
+    (3184, 3190),
+    (3213, 3213),
+    (3217, 3217),
+    (3241, 3241),
+    (3252, 3252),
+    (3258, 3259),
+    (3269, 3269),
+    (3273, 3273),
+    (3278, 3284),
+    (3287, 3292),
+    (3295, 3295),
+    (3300, 3301),
+    (3312, 3312),
+    (3316, 3327),
+    (3341, 3341),
+    (3345, 3345),
+    (3397, 3397),
+    (3401, 3401),
+    (3408, 3411),
+    (3428, 3429),
+    (3456, 3456),
+    (3460, 3460),
+    (3479, 3481),
+    (3506, 3506),
+    (3516, 3516),
+    (3518, 3519),
+    (3527, 3529),
+    (3531, 3534),
+    (3541, 3541),
+    (3543, 3543),
+    (3552, 3557),
+    (3568, 3569),
+    (3573, 3584),
+    (3643, 3646),
+    (3676, 3712),
+    (3715, 3715),
+    (3717, 3717),
+    (3723, 3723),
+    (3748, 3748),
+    (3750, 3750),
+    (3774, 3775),
+    (3781, 3781),
+    (3783, 3783),
+    (3791, 3791),
+    (3802, 3803),
+    (3808, 3839),
+    (3912, 3912),
+    (3949, 3952),
+    (3992, 3992),
+    (4029, 4029),
+    (4045, 4045),
+    (4059, 4095),
+    (4294, 4294),
+    (4296, 4300),
+    (4302, 4303),
+    (4681, 4681),
+    (4686, 4687),
+    (4695, 4695),
+    (4697, 4697),
+    (4702, 4703),
+    (4745, 4745),
+    (4750, 4751),
+    (4785, 4785),
+    (4790, 4791),
+    (4799, 4799),
+    (4801, 4801),
+    (4806, 4807),
+    (4823, 4823),
+    (4881, 4881),
+    (4886, 4887),
+    (4955, 4956),
+    (4989, 4991),
+    (5018, 5023),
+    (5110, 5111),
+    (5118, 5119),
+    (5789, 5791),
+    (5881, 5887),
+    (5910, 5918),
+    (5943, 5951),
+    (5972, 5983),
+    (5997, 5997),
+    (6001, 6001),
+    (6004, 6015),
+    (6110, 6111),
+    (6122, 6127),
+    (6138, 6143),
+    (6170, 6175),
+    (6265, 6271),
+    (6315, 6319),
+    (6390, 6399),
+    (6431, 6431),
+    (6444, 6447),
+    (6460, 6463),
+    (6465, 6467),
+    (6510, 6511),
+    (6517, 6527),
+    (6572, 6575),
+    (6602, 6607),
+    (6619, 6621),
+    (6684, 6685),
+    (6751, 6751),
+    (6781, 6782),
+    (6794, 6799),
+    (6810, 6815),
+    (6830, 6831),
+    (6863, 6911),
+    (6989, 6989),
+    (7156, 7163),
+    (7224, 7226),
+    (7242, 7244),
+    (7307, 7311),
+    (7355, 7356),
+    (7368, 7375),
+    (7419, 7423),
+    (7958, 7959),
+    (7966, 7967),
+    (8006, 8007),
+    (8014, 8015),
+    (8024, 8024),
+    (8026, 8026),
+    (8028, 8028),
+    (8030, 8030),
+    (8062, 8063),
+    (8117, 8117),
+    (8133, 8133),
+    (8148, 8149),
+    (8156, 8156),
+    (8176, 8177),
+    (8181, 8181),
+    (8191, 8191),
+    (8293, 8293),
+    (8306, 8307),
+    (8335, 8335),
+    (8349, 8351),
+    (8385, 8399),
+    (8433, 8447),
+    (8588, 8591),
+    (9258, 9279),
+    (9291, 9311),
+    (11124, 11125),
+    (11158, 11158),
+    (11508, 11512),
+    (11558, 11558),
+    (11560, 11564),
+    (11566, 11567),
+    (11624, 11630),
+    (11633, 11646),
+    (11671, 11679),
+    (11687, 11687),
+    (11695, 11695),
+    (11703, 11703),
+    (11711, 11711),
+    (11719, 11719),
+    (11727, 11727),
+    (11735, 11735),
+    (11743, 11743),
+    (11870, 11903),
+    (11930, 11930),
+    (12020, 12031),
+    (12246, 12271),
+    (12352, 12352),
+    (12439, 12440),
+    (12544, 12548),
+    (12592, 12592),
+    (12687, 12687),
+    (12774, 12782),
+    (12831, 12831),
+    (42125, 42127),
+    (42183, 42191),
+    (42540, 42559),
+    (42744, 42751),
+    (42958, 42959),
+    (42962, 42962),
+    (42964, 42964),
+    (42973, 42993),
+    (43053, 43055),
+    (43066, 43071),
+    (43128, 43135),
+    (43206, 43213),
+    (43226, 43231),
+    (43348, 43358),
+    (43389, 43391),
+    (43470, 43470),
+    (43482, 43485),
+    (43519, 43519),
+    (43575, 43583),
+    (43598, 43599),
+    (43610, 43611),
+    (43715, 43738),
+    (43767, 43776),
+    (43783, 43784),
+    (43791, 43792),
+    (43799, 43807),
+    (43815, 43815),
+    (43823, 43823),
+    (43884, 43887),
+    (44014, 44015),
+    (44026, 44031),
+    (55204, 55215),
+    (55239, 55242),
+    (55292, 55295),
+    (64110, 64111),
+    (64218, 64255),
+    (64263, 64274),
+    (64280, 64284),
+    (64311, 64311),
+    (64317, 64317),
+    (64319, 64319),
+    (64322, 64322),
+    (64325, 64325),
+    (64451, 64466),
+    (64912, 64913),
+    (64968, 64974),
+    (64976, 65007),
+    (65050, 65055),
+    (65107, 65107),
+    (65127, 65127),
+    (65132, 65135),
+    (65141, 65141),
+    (65277, 65278),
+    (65280, 65280),
+    (65471, 65473),
+    (65480, 65481),
+    (65488, 65489),
+    (65496, 65497),
+    (65501, 65503),
+    (65511, 65511),
+    (65519, 65528),
+    (65534, 65535),
+    (65548, 65548),
+    (65575, 65575),
+    (65595, 65595),
+    (65598, 65598),
+    (65614, 65615),
+    (65630, 65663),
+    (65787, 65791),
+    (65795, 65798),
+    (65844, 65846),
+    (65935, 65935),
+    (65949, 65951),
+    (65953, 65999),
+    (66046, 66175),
+    (66205, 66207),
+    (66257, 66271),
+    (66300, 66303),
+    (66340, 66348),
+    (66379, 66383),
+    (66427, 66431),
+    (66462, 66462),
+    (66500, 66503),
+    (66518, 66559),
+    (66718, 66719),
+    (66730, 66735),
+    (66772, 66775),
+    (66812, 66815),
+    (66856, 66863),
+    (66916, 66926),
+    (66939, 66939),
+    (66955, 66955),
+    (66963, 66963),
+    (66966, 66966),
+    (66978, 66978),
+    (66994, 66994),
+    (67002, 67002),
+    (67005, 67007),
+    (67060, 67071),
+    (67383, 67391),
+    (67414, 67423),
+    (67432, 67455),
+    (67462, 67462),
+    (67505, 67505),
+    (67515, 67583),
+    (67590, 67591),
+    (67593, 67593),
+    (67638, 67638),
+    (67641, 67643),
+    (67645, 67646),
+    (67670, 67670),
+    (67743, 67750),
+    (67760, 67807),
+    (67827, 67827),
+    (67830, 67834),
+    (67868, 67870),
+    (67898, 67902),
+    (67904, 67967),
+    (68024, 68027),
+    (68048, 68049),
+    (68100, 68100),
+    (68103, 68107),
+    (68116, 68116),
+    (68120, 68120),
+    (68150, 68151),
+    (68155, 68158),
+    (68169, 68175),
+    (68185, 68191),
+    (68256, 68287),
+    (68327, 68330),
+    (68343, 68351),
+    (68406, 68408),
+    (68438, 68439),
+    (68467, 68471),
+    (68498, 68504),
+    (68509, 68520),
+    (68528, 68607),
+    (68681, 68735),
+    (68787, 68799),
+    (68851, 68857),
+    (68904, 68911),
+    (68922, 68927),
+    (68966, 68968),
+    (68998, 69005),
+    (69008, 69215),
+    (69247, 69247),
+    (69290, 69290),
+    (69294, 69295),
+    (69298, 69313),
+    (69317, 69371),
+    (69416, 69423),
+    (69466, 69487),
+    (69514, 69551),
+    (69580, 69599),
+    (69623, 69631),
+    (69710, 69713),
+    (69750, 69758),
+    (69827, 69836),
+    (69838, 69839),
+    (69865, 69871),
+    (69882, 69887),
+    (69941, 69941),
+    (69960, 69967),
+    (70007, 70015),
+    (70112, 70112),
+    (70133, 70143),
+    (70162, 70162),
+    (70210, 70271),
+    (70279, 70279),
+    (70281, 70281),
+    (70286, 70286),
+    (70302, 70302),
+    (70314, 70319),
+    (70379, 70383),
+    (70394, 70399),
+    (70404, 70404),
+    (70413, 70414),
+    (70417, 70418),
+    (70441, 70441),
+    (70449, 70449),
+    (70452, 70452),
+    (70458, 70458),
+    (70469, 70470),
+    (70473, 70474),
+    (70478, 70479),
+    (70481, 70486),
+    (70488, 70492),
+    (70500, 70501),
+    (70509, 70511),
+    (70517, 70527),
+    (70538, 70538),
+    (70540, 70541),
+    (70543, 70543),
+    (70582, 70582),
+    (70593, 70593),
+    (70595, 70596),
+    (70598, 70598),
+    (70603, 70603),
+    (70614, 70614),
+    (70617, 70624),
+    (70627, 70655),
+    (70748, 70748),
+    (70754, 70783),
+    (70856, 70863),
+    (70874, 71039),
+    (71094, 71095),
+    (71134, 71167),
+    (71237, 71247),
+    (71258, 71263),
+    (71277, 71295),
+    (71354, 71359),
+    (71370, 71375),
+    (71396, 71423),
+    (71451, 71452),
+    (71468, 71471),
+    (71495, 71679),
+    (71740, 71839),
+    (71923, 71934),
+    (71943, 71944),
+    (71946, 71947),
+    (71956, 71956),
+    (71959, 71959),
+    (71990, 71990),
+    (71993, 71994),
+    (72007, 72015),
+    (72026, 72095),
+    (72104, 72105),
+    (72152, 72153),
+    (72165, 72191),
+    (72264, 72271),
+    (72355, 72367),
+    (72441, 72447),
+    (72458, 72639),
+    (72674, 72687),
+    (72698, 72703),
+    (72713, 72713),
+    (72759, 72759),
+    (72774, 72783),
+    (72813, 72815),
+    (72848, 72849),
+    (72872, 72872),
+    (72887, 72959),
+    (72967, 72967),
+    (72970, 72970),
+    (73015, 73017),
+    (73019, 73019),
+    (73022, 73022),
+    (73032, 73039),
+    (73050, 73055),
+    (73062, 73062),
+    (73065, 73065),
+    (73103, 73103),
+    (73106, 73106),
+    (73113, 73119),
+    (73130, 73439),
+    (73465, 73471),
+    (73489, 73489),
+    (73531, 73533),
+    (73563, 73647),
+    (73649, 73663),
+    (73714, 73726),
+    (74650, 74751),
+    (74863, 74863),
+    (74869, 74879),
+    (75076, 77711),
+    (77811, 77823),
+    (78934, 78943),
+    (82939, 82943),
+    (83527, 90367),
+    (90426, 92159),
+    (92729, 92735),
+    (92767, 92767),
+    (92778, 92781),
+    (92863, 92863),
+    (92874, 92879),
+    (92910, 92911),
+    (92918, 92927),
+    (92998, 93007),
+    (93018, 93018),
+    (93026, 93026),
+    (93048, 93052),
+    (93072, 93503),
+    (93562, 93759),
+    (93851, 93951),
+    (94027, 94030),
+    (94088, 94094),
+    (94112, 94175),
+    (94181, 94191),
+    (94194, 94207),
+    (100344, 100351),
+    (101590, 101630),
+    (101641, 110575),
+    (110580, 110580),
+    (110588, 110588),
+    (110591, 110591),
+    (110883, 110897),
+    (110899, 110927),
+    (110931, 110932),
+    (110934, 110947),
+    (110952, 110959),
+    (111356, 113663),
+    (113771, 113775),
+    (113789, 113791),
+    (113801, 113807),
+    (113818, 113819),
+    (113828, 117759),
+    (118010, 118015),
+    (118452, 118527),
+    (118574, 118575),
+    (118599, 118607),
+    (118724, 118783),
+    (119030, 119039),
+    (119079, 119080),
+    (119275, 119295),
+    (119366, 119487),
+    (119508, 119519),
+    (119540, 119551),
+    (119639, 119647),
+    (119673, 119807),
+    (119893, 119893),
+    (119965, 119965),
+    (119968, 119969),
+    (119971, 119972),
+    (119975, 119976),
+    (119981, 119981),
+    (119994, 119994),
+    (119996, 119996),
+    (120004, 120004),
+    (120070, 120070),
+    (120075, 120076),
+    (120085, 120085),
+    (120093, 120093),
+    (120122, 120122),
+    (120127, 120127),
+    (120133, 120133),
+    (120135, 120137),
+    (120145, 120145),
+    (120486, 120487),
+    (120780, 120781),
+    (121484, 121498),
+    (121504, 121504),
+    (121520, 122623),
+    (122655, 122660),
+    (122667, 122879),
+    (122887, 122887),
+    (122905, 122906),
+    (122914, 122914),
+    (122917, 122917),
+    (122923, 122927),
+    (122990, 123022),
+    (123024, 123135),
+    (123181, 123183),
+    (123198, 123199),
+    (123210, 123213),
+    (123216, 123535),
+    (123567, 123583),
+    (123642, 123646),
+    (123648, 124111),
+    (124154, 124367),
+    (124411, 124414),
+    (124416, 124895),
+    (124903, 124903),
+    (124908, 124908),
+    (124911, 124911),
+    (124927, 124927),
+    (125125, 125126),
+    (125143, 125183),
+    (125260, 125263),
+    (125274, 125277),
+    (125280, 126064),
+    (126133, 126208),
+    (126270, 126463),
+    (126468, 126468),
+    (126496, 126496),
+    (126499, 126499),
+    (126501, 126502),
+    (126504, 126504),
+    (126515, 126515),
+    (126520, 126520),
+    (126522, 126522),
+    (126524, 126529),
+    (126531, 126534),
+    (126536, 126536),
+    (126538, 126538),
+    (126540, 126540),
+    (126544, 126544),
+    (126547, 126547),
+    (126549, 126550),
+    (126552, 126552),
+    (126554, 126554),
+    (126556, 126556),
+    (126558, 126558),
+    (126560, 126560),
+    (126563, 126563),
+    (126565, 126566),
+    (126571, 126571),
+    (126579, 126579),
+    (126584, 126584),
+    (126589, 126589),
+    (126591, 126591),
+    (126602, 126602),
+    (126620, 126624),
+    (126628, 126628),
+    (126634, 126634),
+    (126652, 126703),
+    (126706, 126975),
+    (127020, 127023),
+    (127124, 127135),
+    (127151, 127152),
+    (127168, 127168),
+    (127184, 127184),
+    (127222, 127231),
+    (127406, 127461),
+    (127491, 127503),
+    (127548, 127551),
+    (127561, 127567),
+    (127570, 127583),
+    (127590, 127743),
+    (128728, 128731),
+    (128749, 128751),
+    (128765, 128767),
+    (128887, 128890),
+    (128986, 128991),
+    (129004, 129007),
+    (129009, 129023),
+    (129036, 129039),
+    (129096, 129103),
+    (129114, 129119),
+    (129160, 129167),
+    (129198, 129199),
+    (129212, 129215),
+    (129218, 129279),
+    (129620, 129631),
+    (129646, 129647),
+    (129661, 129663),
+    (129674, 129678),
+    (129735, 129741),
+    (129757, 129758),
+    (129770, 129775),
+    (129785, 129791),
+    (129939, 129939),
+    (130042, 131071),
+    (173792, 173823),
+    (177978, 177983),
+    (178206, 178207),
+    (183970, 183983),
+    (191457, 191471),
+    (192094, 194559),
+    (195102, 196607),
+    (201547, 201551),
+    (205744, 917504),
+    (917506, 917535),
+    (917632, 917759),
+    (918000, 983039),
+    (1048574, 1048575),
+    (1114110, 1114111),
+];
+
+pub static GC_CO: [(u32, u32); 3] = [(57344, 63743), (983040, 1048573), (1048576, 1114109)];
+
+pub static GC_LL: [(u32, u32); 662] = [
+    (97, 122),
+    (181, 181),
+    (223, 246),
+    (248, 255),
+    (257, 257),
+    (259, 259),
+    (261, 261),
+    (263, 263),
+    (265, 265),
+    (267, 267),
+    (269, 269),
+    (271, 271),
+    (273, 273),
+    (275, 275),
+    (277, 277),
+    (279, 279),
+    (281, 281),
+    (283, 283),
+    (285, 285),
+    (287, 287),
+    (289, 289),
+    (291, 291),
+    (293, 293),
+    (295, 295),
+    (297, 297),
+    (299, 299),
+    (301, 301),
+    (303, 303),
+    (305, 305),
+    (307, 307),
+    (309, 309),
+    (311, 312),
+    (314, 314),
+    (316, 316),
+    (318, 318),
+    (320, 320),
+    (322, 322),
+    (324, 324),
+    (326, 326),
+    (328, 329),
+    (331, 331),
+    (333, 333),
+    (335, 335),
+    (337, 337),
+    (339, 339),
+    (341, 341),
+    (343, 343),
+    (345, 345),
+    (347, 347),
+    (349, 349),
+    (351, 351),
+    (353, 353),
+    (355, 355),
+    (357, 357),
+    (359, 359),
+    (361, 361),
+    (363, 363),
+    (365, 365),
+    (367, 367),
+    (369, 369),
+    (371, 371),
+    (373, 373),
+    (375, 375),
+    (378, 378),
+    (380, 380),
+    (382, 384),
+    (387, 387),
+    (389, 389),
+    (392, 392),
+    (396, 397),
+    (402, 402),
+    (405, 405),
+    (409, 411),
+    (414, 414),
+    (417, 417),
+    (419, 419),
+    (421, 421),
+    (424, 424),
+    (426, 427),
+    (429, 429),
+    (432, 432),
+    (436, 436),
+    (438, 438),
+    (441, 442),
+    (445, 447),
+    (454, 454),
+    (457, 457),
+    (460, 460),
+    (462, 462),
+    (464, 464),
+    (466, 466),
+    (468, 468),
+    (470, 470),
+    (472, 472),
+    (474, 474),
+    (476, 477),
+    (479, 479),
+    (481, 481),
+    (483, 483),
+    (485, 485),
+    (487, 487),
+    (489, 489),
+    (491, 491),
+    (493, 493),
+    (495, 496),
+    (499, 499),
+    (501, 501),
+    (505, 505),
+    (507, 507),
+    (509, 509),
+    (511, 511),
+    (513, 513),
+    (515, 515),
+    (517, 517),
+    (519, 519),
+    (521, 521),
+    (523, 523),
+    (525, 525),
+    (527, 527),
+    (529, 529),
+    (531, 531),
+    (533, 533),
+    (535, 535),
+    (537, 537),
+    (539, 539),
+    (541, 541),
+    (543, 543),
+    (545, 545),
+    (547, 547),
+    (549, 549),
+    (551, 551),
+    (553, 553),
+    (555, 555),
+    (557, 557),
+    (559, 559),
+    (561, 561),
+    (563, 569),
+    (572, 572),
+    (575, 576),
+    (578, 578),
+    (583, 583),
+    (585, 585),
+    (587, 587),
+    (589, 589),
+    (591, 659),
+    (661, 687),
+    (881, 881),
+    (883, 883),
+    (887, 887),
+    (891, 893),
+    (912, 912),
+    (940, 974),
+    (976, 977),
+    (981, 983),
+    (985, 985),
+    (987, 987),
+    (989, 989),
+    (991, 991),
+    (993, 993),
+    (995, 995),
+    (997, 997),
+    (999, 999),
+    (1001, 1001),
+    (1003, 1003),
+    (1005, 1005),
+    (1007, 1011),
+    (1013, 1013),
+    (1016, 1016),
+    (1019, 1020),
+    (1072, 1119),
+    (1121, 1121),
+    (1123, 1123),
+    (1125, 1125),
+    (1127, 1127),
+    (1129, 1129),
+    (1131, 1131),
+    (1133, 1133),
+    (1135, 1135),
+    (1137, 1137),
+    (1139, 1139),
+    (1141, 1141),
+    (1143, 1143),
+    (1145, 1145),
+    (1147, 1147),
+    (1149, 1149),
+    (1151, 1151),
+    (1153, 1153),
+    (1163, 1163),
+    (1165, 1165),
+    (1167, 1167),
+    (1169, 1169),
+    (1171, 1171),
+    (1173, 1173),
+    (1175, 1175),
+    (1177, 1177),
+    (1179, 1179),
+    (1181, 1181),
+    (1183, 1183),
+    (1185, 1185),
+    (1187, 1187),
+    (1189, 1189),
+    (1191, 1191),
+    (1193, 1193),
+    (1195, 1195),
+    (1197, 1197),
+    (1199, 1199),
+    (1201, 1201),
+    (1203, 1203),
+    (1205, 1205),
+    (1207, 1207),
+    (1209, 1209),
+    (1211, 1211),
+    (1213, 1213),
+    (1215, 1215),
+    (1218, 1218),
+    (1220, 1220),
+    (1222, 1222),
+    (1224, 1224),
+    (1226, 1226),
+    (1228, 1228),
+    (1230, 1231),
+    (1233, 1233),
+    (1235, 1235),
+    (1237, 1237),
+    (1239, 1239),
+    (1241, 1241),
+    (1243, 1243),
+    (1245, 1245),
+    (1247, 1247),
+    (1249, 1249),
+    (1251, 1251),
+    (1253, 1253),
+    (1255, 1255),
+    (1257, 1257),
+    (1259, 1259),
+    (1261, 1261),
+    (1263, 1263),
+    (1265, 1265),
+    (1267, 1267),
+    (1269, 1269),
+    (1271, 1271),
+    (1273, 1273),
+    (1275, 1275),
+    (1277, 1277),
+    (1279, 1279),
+    (1281, 1281),
+    (1283, 1283),
+    (1285, 1285),
+    (1287, 1287),
+    (1289, 1289),
+    (1291, 1291),
+    (1293, 1293),
+    (1295, 1295),
+    (1297, 1297),
+    (1299, 1299),
+    (1301, 1301),
+    (1303, 1303),
+    (1305, 1305),
+    (1307, 1307),
+    (1309, 1309),
+    (1311, 1311),
+    (1313, 1313),
+    (1315, 1315),
+    (1317, 1317),
+    (1319, 1319),
+    (1321, 1321),
+    (1323, 1323),
+    (1325, 1325),
+    (1327, 1327),
+    (1376, 1416),
+    (4304, 4346),
+    (4349, 4351),
+    (5112, 5117),
+    (7296, 7304),
+    (7306, 7306),
+    (7424, 7467),
+    (7531, 7543),
+    (7545, 7578),
+    (7681, 7681),
+    (7683, 7683),
+    (7685, 7685),
+    (7687, 7687),
+    (7689, 7689),
+    (7691, 7691),
+    (7693, 7693),
+    (7695, 7695),
+    (7697, 7697),
+    (7699, 7699),
+    (7701, 7701),
+    (7703, 7703),
+    (7705, 7705),
+    (7707, 7707),
+    (7709, 7709),
+    (7711, 7711),
+    (7713, 7713),
+    (7715, 7715),
+    (7717, 7717),
+    (7719, 7719),
+    (7721, 7721),
+    (7723, 7723),
+    (7725, 7725),
+    (7727, 7727),
+    (7729, 7729),
+    (7731, 7731),
+    (7733, 7733),
+    (7735, 7735),
+    (7737, 7737),
+    (7739, 7739),
+    (7741, 7741),
+    (7743, 7743),
+    (7745, 7745),
+    (7747, 7747),
+    (7749, 7749),
+    (7751, 7751),
+    (7753, 7753),
+    (7755, 7755),
+    (7757, 7757),
+    (7759, 7759),
+    (7761, 7761),
+    (7763, 7763),
+    (7765, 7765),
+    (7767, 7767),
+    (7769, 7769),
+    (7771, 7771),
+    (7773, 7773),
+    (7775, 7775),
+    (7777, 7777),
+    (7779, 7779),
+    (7781, 7781),
+    (7783, 7783),
+    (7785, 7785),
+    (7787, 7787),
+    (7789, 7789),
+    (7791, 7791),
+    (7793, 7793),
+    (7795, 7795),
+    (7797, 7797),
+    (7799, 7799),
+    (7801, 7801),
+    (7803, 7803),
+    (7805, 7805),
+    (7807, 7807),
+    (7809, 7809),
+    (7811, 7811),
+    (7813, 7813),
+    (7815, 7815),
+    (7817, 7817),
+    (7819, 7819),
+    (7821, 7821),
+    (7823, 7823),
+    (7825, 7825),
+    (7827, 7827),
+    (7829, 7837),
+    (7839, 7839),
+    (7841, 7841),
+    (7843, 7843),
+    (7845, 7845),
+    (7847, 7847),
+    (7849, 7849),
+    (7851, 7851),
+    (7853, 7853),
+    (7855, 7855),
+    (7857, 7857),
+    (7859, 7859),
+    (7861, 7861),
+    (7863, 7863),
+    (7865, 7865),
+    (7867, 7867),
+    (7869, 7869),
+    (7871, 7871),
+    (7873, 7873),
+    (7875, 7875),
+    (7877, 7877),
+    (7879, 7879),
+    (7881, 7881),
+    (7883, 7883),
+    (7885, 7885),
+    (7887, 7887),
+    (7889, 7889),
+    (7891, 7891),
+    (7893, 7893),
+    (7895, 7895),
+    (7897, 7897),
+    (7899, 7899),
+    (7901, 7901),
+    (7903, 7903),
+    (7905, 7905),
+    (7907, 7907),
+    (7909, 7909),
+    (7911, 7911),
+    (7913, 7913),
+    (7915, 7915),
+    (7917, 7917),
+    (7919, 7919),
+    (7921, 7921),
+    (7923, 7923),
+    (7925, 7925),
+    (7927, 7927),
+    (7929, 7929),
+    (7931, 7931),
+    (7933, 7933),
+    (7935, 7943),
+    (7952, 7957),
+    (7968, 7975),
+    (7984, 7991),
+    (8000, 8005),
+    (8016, 8023),
+    (8032, 8039),
+    (8048, 8061),
+    (8064, 8071),
+    (8080, 8087),
+    (8096, 8103),
+    (8112, 8116),
+    (8118, 8119),
+    (8126, 8126),
+    (8130, 8132),
+    (8134, 8135),
+    (8144, 8147),
+    (8150, 8151),
+    (8160, 8167),
+    (8178, 8180),
+    (8182, 8183),
+    (8458, 8458),
+    (8462, 8463),
+    (8467, 8467),
+    (8495, 8495),
+    (8500, 8500),
+    (8505, 8505),
+    (8508, 8509),
+    (8518, 8521),
+    (8526, 8526),
+    (8580, 8580),
+    (11312, 11359),
+    (11361, 11361),
+    (11365, 11366),
+    (11368, 11368),
+    (11370, 11370),
+    (11372, 11372),
+    (11377, 11377),
+    (11379, 11380),
+    (11382, 11387),
+    (11393, 11393),
+    (11395, 11395),
+    (11397, 11397),
+    (11399, 11399),
+    (11401, 11401),
+    (11403, 11403),
+    (11405, 11405),
+    (11407, 11407),
+    (11409, 11409),
+    (11411, 11411),
+    (11413, 11413),
+    (11415, 11415),
+    (11417, 11417),
+    (11419, 11419),
+    (11421, 11421),
+    (11423, 11423),
+    (11425, 11425),
+    (11427, 11427),
+    (11429, 11429),
+    (11431, 11431),
+    (11433, 11433),
+    (11435, 11435),
+    (11437, 11437),
+    (11439, 11439),
+    (11441, 11441),
+    (11443, 11443),
+    (11445, 11445),
+    (11447, 11447),
+    (11449, 11449),
+    (11451, 11451),
+    (11453, 11453),
+    (11455, 11455),
+    (11457, 11457),
+    (11459, 11459),
+    (11461, 11461),
+    (11463, 11463),
+    (11465, 11465),
+    (11467, 11467),
+    (11469, 11469),
+    (11471, 11471),
+    (11473, 11473),
+    (11475, 11475),
+    (11477, 11477),
+    (11479, 11479),
+    (11481, 11481),
+    (11483, 11483),
+    (11485, 11485),
+    (11487, 11487),
+    (11489, 11489),
+    (11491, 11492),
+    (11500, 11500),
+    (11502, 11502),
+    (11507, 11507),
+    (11520, 11557),
+    (11559, 11559),
+    (11565, 11565),
+    (42561, 42561),
+    (42563, 42563),
+    (42565, 42565),
+    (42567, 42567),
+    (42569, 42569),
+    (42571, 42571),
+    (42573, 42573),
+    (42575, 42575),
+    (42577, 42577),
+    (42579, 42579),
+    (42581, 42581),
+    (42583, 42583),
+    (42585, 42585),
+    (42587, 42587),
+    (42589, 42589),
+    (42591, 42591),
+    (42593, 42593),
+    (42595, 42595),
+    (42597, 42597),
+    (42599, 42599),
+    (42601, 42601),
+    (42603, 42603),
+    (42605, 42605),
+    (42625, 42625),
+    (42627, 42627),
+    (42629, 42629),
+    (42631, 42631),
+    (42633, 42633),
+    (42635, 42635),
+    (42637, 42637),
+    (42639, 42639),
+    (42641, 42641),
+    (42643, 42643),
+    (42645, 42645),
+    (42647, 42647),
+    (42649, 42649),
+    (42651, 42651),
+    (42787, 42787),
+    (42789, 42789),
+    (42791, 42791),
+    (42793, 42793),
+    (42795, 42795),
+    (42797, 42797),
+    (42799, 42801),
+    (42803, 42803),
+    (42805, 42805),
+    (42807, 42807),
+    (42809, 42809),
+    (42811, 42811),
+    (42813, 42813),
+    (42815, 42815),
+    (42817, 42817),
+    (42819, 42819),
+    (42821, 42821),
+    (42823, 42823),
+    (42825, 42825),
+    (42827, 42827),
+    (42829, 42829),
+    (42831, 42831),
+    (42833, 42833),
+    (42835, 42835),
+    (42837, 42837),
+    (42839, 42839),
+    (42841, 42841),
+    (42843, 42843),
+    (42845, 42845),
+    (42847, 42847),
+    (42849, 42849),
+    (42851, 42851),
+    (42853, 42853),
+    (42855, 42855),
+    (42857, 42857),
+    (42859, 42859),
+    (42861, 42861),
+    (42863, 42863),
+    (42865, 42872),
+    (42874, 42874),
+    (42876, 42876),
+    (42879, 42879),
+    (42881, 42881),
+    (42883, 42883),
+    (42885, 42885),
+    (42887, 42887),
+    (42892, 42892),
+    (42894, 42894),
+    (42897, 42897),
+    (42899, 42901),
+    (42903, 42903),
+    (42905, 42905),
+    (42907, 42907),
+    (42909, 42909),
+    (42911, 42911),
+    (42913, 42913),
+    (42915, 42915),
+    (42917, 42917),
+    (42919, 42919),
+    (42921, 42921),
+    (42927, 42927),
+    (42933, 42933),
+    (42935, 42935),
+    (42937, 42937),
+    (42939, 42939),
+    (42941, 42941),
+    (42943, 42943),
+    (42945, 42945),
+    (42947, 42947),
+    (42952, 42952),
+    (42954, 42954),
+    (42957, 42957),
+    (42961, 42961),
+    (42963, 42963),
+    (42965, 42965),
+    (42967, 42967),
+    (42969, 42969),
+    (42971, 42971),
+    (42998, 42998),
+    (43002, 43002),
+    (43824, 43866),
+    (43872, 43880),
+    (43888, 43967),
+    (64256, 64262),
+    (64275, 64279),
+    (65345, 65370),
+    (66600, 66639),
+    (66776, 66811),
+    (66967, 66977),
+    (66979, 66993),
+    (66995, 67001),
+    (67003, 67004),
+    (68800, 68850),
+    (68976, 68997),
+    (71872, 71903),
+    (93792, 93823),
+    (119834, 119859),
+    (119886, 119892),
+    (119894, 119911),
+    (119938, 119963),
+    (119990, 119993),
+    (119995, 119995),
+    (119997, 120003),
+    (120005, 120015),
+    (120042, 120067),
+    (120094, 120119),
+    (120146, 120171),
+    (120198, 120223),
+    (120250, 120275),
+    (120302, 120327),
+    (120354, 120379),
+    (120406, 120431),
+    (120458, 120485),
+    (120514, 120538),
+    (120540, 120545),
+    (120572, 120596),
+    (120598, 120603),
+    (120630, 120654),
+    (120656, 120661),
+    (120688, 120712),
+    (120714, 120719),
+    (120746, 120770),
+    (120772, 120777),
+    (120779, 120779),
+    (122624, 122633),
+    (122635, 122654),
+    (122661, 122666),
+    (125218, 125251),
+];
+
+pub static GC_LM: [(u32, u32); 75] = [
+    (688, 705),
+    (710, 721),
+    (736, 740),
+    (748, 748),
+    (750, 750),
+    (884, 884),
+    (890, 890),
+    (1369, 1369),
+    (1600, 1600),
+    (1765, 1766),
+    (2036, 2037),
+    (2042, 2042),
+    (2074, 2074),
+    (2084, 2084),
+    (2088, 2088),
+    (2249, 2249),
+    (2417, 2417),
+    (3654, 3654),
+    (3782, 3782),
+    (4348, 4348),
+    (6103, 6103),
+    (6211, 6211),
+    (6823, 6823),
+    (7288, 7293),
+    (7468, 7530),
+    (7544, 7544),
+    (7579, 7615),
+    (8305, 8305),
+    (8319, 8319),
+    (8336, 8348),
+    (11388, 11389),
+    (11631, 11631),
+    (11823, 11823),
+    (12293, 12293),
+    (12337, 12341),
+    (12347, 12347),
+    (12445, 12446),
+    (12540, 12542),
+    (40981, 40981),
+    (42232, 42237),
+    (42508, 42508),
+    (42623, 42623),
+    (42652, 42653),
+    (42775, 42783),
+    (42864, 42864),
+    (42888, 42888),
+    (42994, 42996),
+    (43000, 43001),
+    (43471, 43471),
+    (43494, 43494),
+    (43632, 43632),
+    (43741, 43741),
+    (43763, 43764),
+    (43868, 43871),
+    (43881, 43881),
+    (65392, 65392),
+    (65438, 65439),
+    (67456, 67461),
+    (67463, 67504),
+    (67506, 67514),
+    (68942, 68942),
+    (68975, 68975),
+    (92992, 92995),
+    (93504, 93506),
+    (93547, 93548),
+    (94099, 94111),
+    (94176, 94177),
+    (94179, 94179),
+    (110576, 110579),
+    (110581, 110587),
+    (110589, 110590),
+    (122928, 122989),
+    (123191, 123197),
+    (124139, 124139),
+    (125259, 125259),
+];
+
+pub static GC_LO: [(u32, u32); 528] = [
+    (170, 170),
+    (186, 186),
+    (443, 443),
+    (448, 451),
+    (660, 660),
+    (1488, 1514),
+    (1519, 1522),
+    (1568, 1599),
+    (1601, 1610),
+    (1646, 1647),
+    (1649, 1747),
+    (1749, 1749),
+    (1774, 1775),
+    (1786, 1788),
+    (1791, 1791),
+    (1808, 1808),
+    (1810, 1839),
+    (1869, 1957),
+    (1969, 1969),
+    (1994, 2026),
+    (2048, 2069),
+    (2112, 2136),
+    (2144, 2154),
+    (2160, 2183),
+    (2185, 2190),
+    (2208, 2248),
+    (2308, 2361),
+    (2365, 2365),
+    (2384, 2384),
+    (2392, 2401),
+    (2418, 2432),
+    (2437, 2444),
+    (2447, 2448),
+    (2451, 2472),
+    (2474, 2480),
+    (2482, 2482),
+    (2486, 2489),
+    (2493, 2493),
+    (2510, 2510),
+    (2524, 2525),
+    (2527, 2529),
+    (2544, 2545),
+    (2556, 2556),
+    (2565, 2570),
+    (2575, 2576),
+    (2579, 2600),
+    (2602, 2608),
+    (2610, 2611),
+    (2613, 2614),
+    (2616, 2617),
+    (2649, 2652),
+    (2654, 2654),
+    (2674, 2676),
+    (2693, 2701),
+    (2703, 2705),
+    (2707, 2728),
+    (2730, 2736),
+    (2738, 2739),
+    (2741, 2745),
+    (2749, 2749),
+    (2768, 2768),
+    (2784, 2785),
+    (2809, 2809),
+    (2821, 2828),
+    (2831, 2832),
+    (2835, 2856),
+    (2858, 2864),
+    (2866, 2867),
+    (2869, 2873),
+    (2877, 2877),
+    (2908, 2909),
+    (2911, 2913),
+    (2929, 2929),
+    (2947, 2947),
+    (2949, 2954),
+    (2958, 2960),
+    (2962, 2965),
+    (2969, 2970),
+    (2972, 2972),
+    (2974, 2975),
+    (2979, 2980),
+    (2984, 2986),
+    (2990, 3001),
+    (3024, 3024),
+    (3077, 3084),
+    (3086, 3088),
+    (3090, 3112),
+    (3114, 3129),
+    (3133, 3133),
+    (3160, 3162),
+    (3165, 3165),
+    (3168, 3169),
+    (3200, 3200),
+    (3205, 3212),
+    (3214, 3216),
+    (3218, 3240),
+    (3242, 3251),
+    (3253, 3257),
+    (3261, 3261),
+    (3293, 3294),
+    (3296, 3297),
+    (3313, 3314),
+    (3332, 3340),
+    (3342, 3344),
+    (3346, 3386),
+    (3389, 3389),
+    (3406, 3406),
+    (3412, 3414),
+    (3423, 3425),
+    (3450, 3455),
+    (3461, 3478),
+    (3482, 3505),
+    (3507, 3515),
+    (3517, 3517),
+    (3520, 3526),
+    (3585, 3632),
+    (3634, 3635),
+    (3648, 3653),
+    (3713, 3714),
+    (3716, 3716),
+    (3718, 3722),
+    (3724, 3747),
+    (3749, 3749),
+    (3751, 3760),
+    (3762, 3763),
+    (3773, 3773),
+    (3776, 3780),
+    (3804, 3807),
+    (3840, 3840),
+    (3904, 3911),
+    (3913, 3948),
+    (3976, 3980),
+    (4096, 4138),
+    (4159, 4159),
+    (4176, 4181),
+    (4186, 4189),
+    (4193, 4193),
+    (4197, 4198),
+    (4206, 4208),
+    (4213, 4225),
+    (4238, 4238),
+    (4352, 4680),
+    (4682, 4685),
+    (4688, 4694),
+    (4696, 4696),
+    (4698, 4701),
+    (4704, 4744),
+    (4746, 4749),
+    (4752, 4784),
+    (4786, 4789),
+    (4792, 4798),
+    (4800, 4800),
+    (4802, 4805),
+    (4808, 4822),
+    (4824, 4880),
+    (4882, 4885),
+    (4888, 4954),
+    (4992, 5007),
+    (5121, 5740),
+    (5743, 5759),
+    (5761, 5786),
+    (5792, 5866),
+    (5873, 5880),
+    (5888, 5905),
+    (5919, 5937),
+    (5952, 5969),
+    (5984, 5996),
+    (5998, 6000),
+    (6016, 6067),
+    (6108, 6108),
+    (6176, 6210),
+    (6212, 6264),
+    (6272, 6276),
+    (6279, 6312),
+    (6314, 6314),
+    (6320, 6389),
+    (6400, 6430),
+    (6480, 6509),
+    (6512, 6516),
+    (6528, 6571),
+    (6576, 6601),
+    (6656, 6678),
+    (6688, 6740),
+    (6917, 6963),
+    (6981, 6988),
+    (7043, 7072),
+    (7086, 7087),
+    (7098, 7141),
+    (7168, 7203),
+    (7245, 7247),
+    (7258, 7287),
+    (7401, 7404),
+    (7406, 7411),
+    (7413, 7414),
+    (7418, 7418),
+    (8501, 8504),
+    (11568, 11623),
+    (11648, 11670),
+    (11680, 11686),
+    (11688, 11694),
+    (11696, 11702),
+    (11704, 11710),
+    (11712, 11718),
+    (11720, 11726),
+    (11728, 11734),
+    (11736, 11742),
+    (12294, 12294),
+    (12348, 12348),
+    (12353, 12438),
+    (12447, 12447),
+    (12449, 12538),
+    (12543, 12543),
+    (12549, 12591),
+    (12593, 12686),
+    (12704, 12735),
+    (12784, 12799),
+    (13312, 19903),
+    (19968, 40980),
+    (40982, 42124),
+    (42192, 42231),
+    (42240, 42507),
+    (42512, 42527),
+    (42538, 42539),
+    (42606, 42606),
+    (42656, 42725),
+    (42895, 42895),
+    (42999, 42999),
+    (43003, 43009),
+    (43011, 43013),
+    (43015, 43018),
+    (43020, 43042),
+    (43072, 43123),
+    (43138, 43187),
+    (43250, 43255),
+    (43259, 43259),
+    (43261, 43262),
+    (43274, 43301),
+    (43312, 43334),
+    (43360, 43388),
+    (43396, 43442),
+    (43488, 43492),
+    (43495, 43503),
+    (43514, 43518),
+    (43520, 43560),
+    (43584, 43586),
+    (43588, 43595),
+    (43616, 43631),
+    (43633, 43638),
+    (43642, 43642),
+    (43646, 43695),
+    (43697, 43697),
+    (43701, 43702),
+    (43705, 43709),
+    (43712, 43712),
+    (43714, 43714),
+    (43739, 43740),
+    (43744, 43754),
+    (43762, 43762),
+    (43777, 43782),
+    (43785, 43790),
+    (43793, 43798),
+    (43808, 43814),
+    (43816, 43822),
+    (43968, 44002),
+    (44032, 55203),
+    (55216, 55238),
+    (55243, 55291),
+    (63744, 64109),
+    (64112, 64217),
+    (64285, 64285),
+    (64287, 64296),
+    (64298, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64433),
+    (64467, 64829),
+    (64848, 64911),
+    (64914, 64967),
+    (65008, 65019),
+    (65136, 65140),
+    (65142, 65276),
+    (65382, 65391),
+    (65393, 65437),
+    (65440, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+    (65536, 65547),
+    (65549, 65574),
+    (65576, 65594),
+    (65596, 65597),
+    (65599, 65613),
+    (65616, 65629),
+    (65664, 65786),
+    (66176, 66204),
+    (66208, 66256),
+    (66304, 66335),
+    (66349, 66368),
+    (66370, 66377),
+    (66384, 66421),
+    (66432, 66461),
+    (66464, 66499),
+    (66504, 66511),
+    (66640, 66717),
+    (66816, 66855),
+    (66864, 66915),
+    (67008, 67059),
+    (67072, 67382),
+    (67392, 67413),
+    (67424, 67431),
+    (67584, 67589),
+    (67592, 67592),
+    (67594, 67637),
+    (67639, 67640),
+    (67644, 67644),
+    (67647, 67669),
+    (67680, 67702),
+    (67712, 67742),
+    (67808, 67826),
+    (67828, 67829),
+    (67840, 67861),
+    (67872, 67897),
+    (67968, 68023),
+    (68030, 68031),
+    (68096, 68096),
+    (68112, 68115),
+    (68117, 68119),
+    (68121, 68149),
+    (68192, 68220),
+    (68224, 68252),
+    (68288, 68295),
+    (68297, 68324),
+    (68352, 68405),
+    (68416, 68437),
+    (68448, 68466),
+    (68480, 68497),
+    (68608, 68680),
+    (68864, 68899),
+    (68938, 68941),
+    (68943, 68943),
+    (69248, 69289),
+    (69296, 69297),
+    (69314, 69316),
+    (69376, 69404),
+    (69415, 69415),
+    (69424, 69445),
+    (69488, 69505),
+    (69552, 69572),
+    (69600, 69622),
+    (69635, 69687),
+    (69745, 69746),
+    (69749, 69749),
+    (69763, 69807),
+    (69840, 69864),
+    (69891, 69926),
+    (69956, 69956),
+    (69959, 69959),
+    (69968, 70002),
+    (70006, 70006),
+    (70019, 70066),
+    (70081, 70084),
+    (70106, 70106),
+    (70108, 70108),
+    (70144, 70161),
+    (70163, 70187),
+    (70207, 70208),
+    (70272, 70278),
+    (70280, 70280),
+    (70282, 70285),
+    (70287, 70301),
+    (70303, 70312),
+    (70320, 70366),
+    (70405, 70412),
+    (70415, 70416),
+    (70419, 70440),
+    (70442, 70448),
+    (70450, 70451),
+    (70453, 70457),
+    (70461, 70461),
+    (70480, 70480),
+    (70493, 70497),
+    (70528, 70537),
+    (70539, 70539),
+    (70542, 70542),
+    (70544, 70581),
+    (70583, 70583),
+    (70609, 70609),
+    (70611, 70611),
+    (70656, 70708),
+    (70727, 70730),
+    (70751, 70753),
+    (70784, 70831),
+    (70852, 70853),
+    (70855, 70855),
+    (71040, 71086),
+    (71128, 71131),
+    (71168, 71215),
+    (71236, 71236),
+    (71296, 71338),
+    (71352, 71352),
+    (71424, 71450),
+    (71488, 71494),
+    (71680, 71723),
+    (71935, 71942),
+    (71945, 71945),
+    (71948, 71955),
+    (71957, 71958),
+    (71960, 71983),
+    (71999, 71999),
+    (72001, 72001),
+    (72096, 72103),
+    (72106, 72144),
+    (72161, 72161),
+    (72163, 72163),
+    (72192, 72192),
+    (72203, 72242),
+    (72250, 72250),
+    (72272, 72272),
+    (72284, 72329),
+    (72349, 72349),
+    (72368, 72440),
+    (72640, 72672),
+    (72704, 72712),
+    (72714, 72750),
+    (72768, 72768),
+    (72818, 72847),
+    (72960, 72966),
+    (72968, 72969),
+    (72971, 73008),
+    (73030, 73030),
+    (73056, 73061),
+    (73063, 73064),
+    (73066, 73097),
+    (73112, 73112),
+    (73440, 73458),
+    (73474, 73474),
+    (73476, 73488),
+    (73490, 73523),
+    (73648, 73648),
+    (73728, 74649),
+    (74880, 75075),
+    (77712, 77808),
+    (77824, 78895),
+    (78913, 78918),
+    (78944, 82938),
+    (82944, 83526),
+    (90368, 90397),
+    (92160, 92728),
+    (92736, 92766),
+    (92784, 92862),
+    (92880, 92909),
+    (92928, 92975),
+    (93027, 93047),
+    (93053, 93071),
+    (93507, 93546),
+    (93952, 94026),
+    (94032, 94032),
+    (94208, 100343),
+    (100352, 101589),
+    (101631, 101640),
+    (110592, 110882),
+    (110898, 110898),
+    (110928, 110930),
+    (110933, 110933),
+    (110948, 110951),
+    (110960, 111355),
+    (113664, 113770),
+    (113776, 113788),
+    (113792, 113800),
+    (113808, 113817),
+    (122634, 122634),
+    (123136, 123180),
+    (123214, 123214),
+    (123536, 123565),
+    (123584, 123627),
+    (124112, 124138),
+    (124368, 124397),
+    (124400, 124400),
+    (124896, 124902),
+    (124904, 124907),
+    (124909, 124910),
+    (124912, 124926),
+    (124928, 125124),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+    (131072, 173791),
+    (173824, 177977),
+    (177984, 178205),
+    (178208, 183969),
+    (183984, 191456),
+    (191472, 192093),
+    (194560, 195101),
+    (196608, 201546),
+    (201552, 205743),
+];
+
+pub static GC_LT: [(u32, u32); 10] = [
+    (453, 453),
+    (456, 456),
+    (459, 459),
+    (498, 498),
+    (8072, 8079),
+    (8088, 8095),
+    (8104, 8111),
+    (8124, 8124),
+    (8140, 8140),
+    (8188, 8188),
+];
+
+pub static GC_LU: [(u32, u32); 651] = [
+    (65, 90),
+    (192, 214),
+    (216, 222),
+    (256, 256),
+    (258, 258),
+    (260, 260),
+    (262, 262),
+    (264, 264),
+    (266, 266),
+    (268, 268),
+    (270, 270),
+    (272, 272),
+    (274, 274),
+    (276, 276),
+    (278, 278),
+    (280, 280),
+    (282, 282),
+    (284, 284),
+    (286, 286),
+    (288, 288),
+    (290, 290),
+    (292, 292),
+    (294, 294),
+    (296, 296),
+    (298, 298),
+    (300, 300),
+    (302, 302),
+    (304, 304),
+    (306, 306),
+    (308, 308),
+    (310, 310),
+    (313, 313),
+    (315, 315),
+    (317, 317),
+    (319, 319),
+    (321, 321),
+    (323, 323),
+    (325, 325),
+    (327, 327),
+    (330, 330),
+    (332, 332),
+    (334, 334),
+    (336, 336),
+    (338, 338),
+    (340, 340),
+    (342, 342),
+    (344, 344),
+    (346, 346),
+    (348, 348),
+    (350, 350),
+    (352, 352),
+    (354, 354),
+    (356, 356),
+    (358, 358),
+    (360, 360),
+    (362, 362),
+    (364, 364),
+    (366, 366),
+    (368, 368),
+    (370, 370),
+    (372, 372),
+    (374, 374),
+    (376, 377),
+    (379, 379),
+    (381, 381),
+    (385, 386),
+    (388, 388),
+    (390, 391),
+    (393, 395),
+    (398, 401),
+    (403, 404),
+    (406, 408),
+    (412, 413),
+    (415, 416),
+    (418, 418),
+    (420, 420),
+    (422, 423),
+    (425, 425),
+    (428, 428),
+    (430, 431),
+    (433, 435),
+    (437, 437),
+    (439, 440),
+    (444, 444),
+    (452, 452),
+    (455, 455),
+    (458, 458),
+    (461, 461),
+    (463, 463),
+    (465, 465),
+    (467, 467),
+    (469, 469),
+    (471, 471),
+    (473, 473),
+    (475, 475),
+    (478, 478),
+    (480, 480),
+    (482, 482),
+    (484, 484),
+    (486, 486),
+    (488, 488),
+    (490, 490),
+    (492, 492),
+    (494, 494),
+    (497, 497),
+    (500, 500),
+    (502, 504),
+    (506, 506),
+    (508, 508),
+    (510, 510),
+    (512, 512),
+    (514, 514),
+    (516, 516),
+    (518, 518),
+    (520, 520),
+    (522, 522),
+    (524, 524),
+    (526, 526),
+    (528, 528),
+    (530, 530),
+    (532, 532),
+    (534, 534),
+    (536, 536),
+    (538, 538),
+    (540, 540),
+    (542, 542),
+    (544, 544),
+    (546, 546),
+    (548, 548),
+    (550, 550),
+    (552, 552),
+    (554, 554),
+    (556, 556),
+    (558, 558),
+    (560, 560),
+    (562, 562),
+    (570, 571),
+    (573, 574),
+    (577, 577),
+    (579, 582),
+    (584, 584),
+    (586, 586),
+    (588, 588),
+    (590, 590),
+    (880, 880),
+    (882, 882),
+    (886, 886),
+    (895, 895),
+    (902, 902),
+    (904, 906),
+    (908, 908),
+    (910, 911),
+    (913, 929),
+    (931, 939),
+    (975, 975),
+    (978, 980),
+    (984, 984),
+    (986, 986),
+    (988, 988),
+    (990, 990),
+    (992, 992),
+    (994, 994),
+    (996, 996),
+    (998, 998),
+    (1000, 1000),
+    (1002, 1002),
+    (1004, 1004),
+    (1006, 1006),
+    (1012, 1012),
+    (1015, 1015),
+    (1017, 1018),
+    (1021, 1071),
+    (1120, 1120),
+    (1122, 1122),
+    (1124, 1124),
+    (1126, 1126),
+    (1128, 1128),
+    (1130, 1130),
+    (1132, 1132),
+    (1134, 1134),
+    (1136, 1136),
+    (1138, 1138),
+    (1140, 1140),
+    (1142, 1142),
+    (1144, 1144),
+    (1146, 1146),
+    (1148, 1148),
+    (1150, 1150),
+    (1152, 1152),
+    (1162, 1162),
+    (1164, 1164),
+    (1166, 1166),
+    (1168, 1168),
+    (1170, 1170),
+    (1172, 1172),
+    (1174, 1174),
+    (1176, 1176),
+    (1178, 1178),
+    (1180, 1180),
+    (1182, 1182),
+    (1184, 1184),
+    (1186, 1186),
+    (1188, 1188),
+    (1190, 1190),
+    (1192, 1192),
+    (1194, 1194),
+    (1196, 1196),
+    (1198, 1198),
+    (1200, 1200),
+    (1202, 1202),
+    (1204, 1204),
+    (1206, 1206),
+    (1208, 1208),
+    (1210, 1210),
+    (1212, 1212),
+    (1214, 1214),
+    (1216, 1217),
+    (1219, 1219),
+    (1221, 1221),
+    (1223, 1223),
+    (1225, 1225),
+    (1227, 1227),
+    (1229, 1229),
+    (1232, 1232),
+    (1234, 1234),
+    (1236, 1236),
+    (1238, 1238),
+    (1240, 1240),
+    (1242, 1242),
+    (1244, 1244),
+    (1246, 1246),
+    (1248, 1248),
+    (1250, 1250),
+    (1252, 1252),
+    (1254, 1254),
+    (1256, 1256),
+    (1258, 1258),
+    (1260, 1260),
+    (1262, 1262),
+    (1264, 1264),
+    (1266, 1266),
+    (1268, 1268),
+    (1270, 1270),
+    (1272, 1272),
+    (1274, 1274),
+    (1276, 1276),
+    (1278, 1278),
+    (1280, 1280),
+    (1282, 1282),
+    (1284, 1284),
+    (1286, 1286),
+    (1288, 1288),
+    (1290, 1290),
+    (1292, 1292),
+    (1294, 1294),
+    (1296, 1296),
+    (1298, 1298),
+    (1300, 1300),
+    (1302, 1302),
+    (1304, 1304),
+    (1306, 1306),
+    (1308, 1308),
+    (1310, 1310),
+    (1312, 1312),
+    (1314, 1314),
+    (1316, 1316),
+    (1318, 1318),
+    (1320, 1320),
+    (1322, 1322),
+    (1324, 1324),
+    (1326, 1326),
+    (1329, 1366),
+    (4256, 4293),
+    (4295, 4295),
+    (4301, 4301),
+    (5024, 5109),
+    (7305, 7305),
+    (7312, 7354),
+    (7357, 7359),
+    (7680, 7680),
+    (7682, 7682),
+    (7684, 7684),
+    (7686, 7686),
+    (7688, 7688),
+    (7690, 7690),
+    (7692, 7692),
+    (7694, 7694),
+    (7696, 7696),
+    (7698, 7698),
+    (7700, 7700),
+    (7702, 7702),
+    (7704, 7704),
+    (7706, 7706),
+    (7708, 7708),
+    (7710, 7710),
+    (7712, 7712),
+    (7714, 7714),
+    (7716, 7716),
+    (7718, 7718),
+    (7720, 7720),
+    (7722, 7722),
+    (7724, 7724),
+    (7726, 7726),
+    (7728, 7728),
+    (7730, 7730),
+    (7732, 7732),
+    (7734, 7734),
+    (7736, 7736),
+    (7738, 7738),
+    (7740, 7740),
+    (7742, 7742),
+    (7744, 7744),
+    (7746, 7746),
+    (7748, 7748),
+    (7750, 7750),
+    (7752, 7752),
+    (7754, 7754),
+    (7756, 7756),
+    (7758, 7758),
+    (7760, 7760),
+    (7762, 7762),
+    (7764, 7764),
+    (7766, 7766),
+    (7768, 7768),
+    (7770, 7770),
+    (7772, 7772),
+    (7774, 7774),
+    (7776, 7776),
+    (7778, 7778),
+    (7780, 7780),
+    (7782, 7782),
+    (7784, 7784),
+    (7786, 7786),
+    (7788, 7788),
+    (7790, 7790),
+    (7792, 7792),
+    (7794, 7794),
+    (7796, 7796),
+    (7798, 7798),
+    (7800, 7800),
+    (7802, 7802),
+    (7804, 7804),
+    (7806, 7806),
+    (7808, 7808),
+    (7810, 7810),
+    (7812, 7812),
+    (7814, 7814),
+    (7816, 7816),
+    (7818, 7818),
+    (7820, 7820),
+    (7822, 7822),
+    (7824, 7824),
+    (7826, 7826),
+    (7828, 7828),
+    (7838, 7838),
+    (7840, 7840),
+    (7842, 7842),
+    (7844, 7844),
+    (7846, 7846),
+    (7848, 7848),
+    (7850, 7850),
+    (7852, 7852),
+    (7854, 7854),
+    (7856, 7856),
+    (7858, 7858),
+    (7860, 7860),
+    (7862, 7862),
+    (7864, 7864),
+    (7866, 7866),
+    (7868, 7868),
+    (7870, 7870),
+    (7872, 7872),
+    (7874, 7874),
+    (7876, 7876),
+    (7878, 7878),
+    (7880, 7880),
+    (7882, 7882),
+    (7884, 7884),
+    (7886, 7886),
+    (7888, 7888),
+    (7890, 7890),
+    (7892, 7892),
+    (7894, 7894),
+    (7896, 7896),
+    (7898, 7898),
+    (7900, 7900),
+    (7902, 7902),
+    (7904, 7904),
+    (7906, 7906),
+    (7908, 7908),
+    (7910, 7910),
+    (7912, 7912),
+    (7914, 7914),
+    (7916, 7916),
+    (7918, 7918),
+    (7920, 7920),
+    (7922, 7922),
+    (7924, 7924),
+    (7926, 7926),
+    (7928, 7928),
+    (7930, 7930),
+    (7932, 7932),
+    (7934, 7934),
+    (7944, 7951),
+    (7960, 7965),
+    (7976, 7983),
+    (7992, 7999),
+    (8008, 8013),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8031),
+    (8040, 8047),
+    (8120, 8123),
+    (8136, 8139),
+    (8152, 8155),
+    (8168, 8172),
+    (8184, 8187),
+    (8450, 8450),
+    (8455, 8455),
+    (8459, 8461),
+    (8464, 8466),
+    (8469, 8469),
+    (8473, 8477),
+    (8484, 8484),
+    (8486, 8486),
+    (8488, 8488),
+    (8490, 8493),
+    (8496, 8499),
+    (8510, 8511),
+    (8517, 8517),
+    (8579, 8579),
+    (11264, 11311),
+    (11360, 11360),
+    (11362, 11364),
+    (11367, 11367),
+    (11369, 11369),
+    (11371, 11371),
+    (11373, 11376),
+    (11378, 11378),
+    (11381, 11381),
+    (11390, 11392),
+    (11394, 11394),
+    (11396, 11396),
+    (11398, 11398),
+    (11400, 11400),
+    (11402, 11402),
+    (11404, 11404),
+    (11406, 11406),
+    (11408, 11408),
+    (11410, 11410),
+    (11412, 11412),
+    (11414, 11414),
+    (11416, 11416),
+    (11418, 11418),
+    (11420, 11420),
+    (11422, 11422),
+    (11424, 11424),
+    (11426, 11426),
+    (11428, 11428),
+    (11430, 11430),
+    (11432, 11432),
+    (11434, 11434),
+    (11436, 11436),
+    (11438, 11438),
+    (11440, 11440),
+    (11442, 11442),
+    (11444, 11444),
+    (11446, 11446),
+    (11448, 11448),
+    (11450, 11450),
+    (11452, 11452),
+    (11454, 11454),
+    (11456, 11456),
+    (11458, 11458),
+    (11460, 11460),
+    (11462, 11462),
+    (11464, 11464),
+    (11466, 11466),
+    (11468, 11468),
+    (11470, 11470),
+    (11472, 11472),
+    (11474, 11474),
+    (11476, 11476),
+    (11478, 11478),
+    (11480, 11480),
+    (11482, 11482),
+    (11484, 11484),
+    (11486, 11486),
+    (11488, 11488),
+    (11490, 11490),
+    (11499, 11499),
+    (11501, 11501),
+    (11506, 11506),
+    (42560, 42560),
+    (42562, 42562),
+    (42564, 42564),
+    (42566, 42566),
+    (42568, 42568),
+    (42570, 42570),
+    (42572, 42572),
+    (42574, 42574),
+    (42576, 42576),
+    (42578, 42578),
+    (42580, 42580),
+    (42582, 42582),
+    (42584, 42584),
+    (42586, 42586),
+    (42588, 42588),
+    (42590, 42590),
+    (42592, 42592),
+    (42594, 42594),
+    (42596, 42596),
+    (42598, 42598),
+    (42600, 42600),
+    (42602, 42602),
+    (42604, 42604),
+    (42624, 42624),
+    (42626, 42626),
+    (42628, 42628),
+    (42630, 42630),
+    (42632, 42632),
+    (42634, 42634),
+    (42636, 42636),
+    (42638, 42638),
+    (42640, 42640),
+    (42642, 42642),
+    (42644, 42644),
+    (42646, 42646),
+    (42648, 42648),
+    (42650, 42650),
+    (42786, 42786),
+    (42788, 42788),
+    (42790, 42790),
+    (42792, 42792),
+    (42794, 42794),
+    (42796, 42796),
+    (42798, 42798),
+    (42802, 42802),
+    (42804, 42804),
+    (42806, 42806),
+    (42808, 42808),
+    (42810, 42810),
+    (42812, 42812),
+    (42814, 42814),
+    (42816, 42816),
+    (42818, 42818),
+    (42820, 42820),
+    (42822, 42822),
+    (42824, 42824),
+    (42826, 42826),
+    (42828, 42828),
+    (42830, 42830),
+    (42832, 42832),
+    (42834, 42834),
+    (42836, 42836),
+    (42838, 42838),
+    (42840, 42840),
+    (42842, 42842),
+    (42844, 42844),
+    (42846, 42846),
+    (42848, 42848),
+    (42850, 42850),
+    (42852, 42852),
+    (42854, 42854),
+    (42856, 42856),
+    (42858, 42858),
+    (42860, 42860),
+    (42862, 42862),
+    (42873, 42873),
+    (42875, 42875),
+    (42877, 42878),
+    (42880, 42880),
+    (42882, 42882),
+    (42884, 42884),
+    (42886, 42886),
+    (42891, 42891),
+    (42893, 42893),
+    (42896, 42896),
+    (42898, 42898),
+    (42902, 42902),
+    (42904, 42904),
+    (42906, 42906),
+    (42908, 42908),
+    (42910, 42910),
+    (42912, 42912),
+    (42914, 42914),
+    (42916, 42916),
+    (42918, 42918),
+    (42920, 42920),
+    (42922, 42926),
+    (42928, 42932),
+    (42934, 42934),
+    (42936, 42936),
+    (42938, 42938),
+    (42940, 42940),
+    (42942, 42942),
+    (42944, 42944),
+    (42946, 42946),
+    (42948, 42951),
+    (42953, 42953),
+    (42955, 42956),
+    (42960, 42960),
+    (42966, 42966),
+    (42968, 42968),
+    (42970, 42970),
+    (42972, 42972),
+    (42997, 42997),
+    (65313, 65338),
+    (66560, 66599),
+    (66736, 66771),
+    (66928, 66938),
+    (66940, 66954),
+    (66956, 66962),
+    (66964, 66965),
+    (68736, 68786),
+    (68944, 68965),
+    (71840, 71871),
+    (93760, 93791),
+    (119808, 119833),
+    (119860, 119885),
+    (119912, 119937),
+    (119964, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119989),
+    (120016, 120041),
+    (120068, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120120, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120172, 120197),
+    (120224, 120249),
+    (120276, 120301),
+    (120328, 120353),
+    (120380, 120405),
+    (120432, 120457),
+    (120488, 120512),
+    (120546, 120570),
+    (120604, 120628),
+    (120662, 120686),
+    (120720, 120744),
+    (120778, 120778),
+    (125184, 125217),
+];
+
+pub static GC_MC: [(u32, u32); 190] = [
+    (2307, 2307),
+    (2363, 2363),
+    (2366, 2368),
+    (2377, 2380),
+    (2382, 2383),
+    (2434, 2435),
+    (2494, 2496),
+    (2503, 2504),
+    (2507, 2508),
+    (2519, 2519),
+    (2563, 2563),
+    (2622, 2624),
+    (2691, 2691),
+    (2750, 2752),
+    (2761, 2761),
+    (2763, 2764),
+    (2818, 2819),
+    (2878, 2878),
+    (2880, 2880),
+    (2887, 2888),
+    (2891, 2892),
+    (2903, 2903),
+    (3006, 3007),
+    (3009, 3010),
+    (3014, 3016),
+    (3018, 3020),
+    (3031, 3031),
+    (3073, 3075),
+    (3137, 3140),
+    (3202, 3203),
+    (3262, 3262),
+    (3264, 3268),
+    (3271, 3272),
+    (3274, 3275),
+    (3285, 3286),
+    (3315, 3315),
+    (3330, 3331),
+    (3390, 3392),
+    (3398, 3400),
+    (3402, 3404),
+    (3415, 3415),
+    (3458, 3459),
+    (3535, 3537),
+    (3544, 3551),
+    (3570, 3571),
+    (3902, 3903),
+    (3967, 3967),
+    (4139, 4140),
+    (4145, 4145),
+    (4152, 4152),
+    (4155, 4156),
+    (4182, 4183),
+    (4194, 4196),
+    (4199, 4205),
+    (4227, 4228),
+    (4231, 4236),
+    (4239, 4239),
+    (4250, 4252),
+    (5909, 5909),
+    (5940, 5940),
+    (6070, 6070),
+    (6078, 6085),
+    (6087, 6088),
+    (6435, 6438),
+    (6441, 6443),
+    (6448, 6449),
+    (6451, 6456),
+    (6681, 6682),
+    (6741, 6741),
+    (6743, 6743),
+    (6753, 6753),
+    (6755, 6756),
+    (6765, 6770),
+    (6916, 6916),
+    (6965, 6965),
+    (6971, 6971),
+    (6973, 6977),
+    (6979, 6980),
+    (7042, 7042),
+    (7073, 7073),
+    (7078, 7079),
+    (7082, 7082),
+    (7143, 7143),
+    (7146, 7148),
+    (7150, 7150),
+    (7154, 7155),
+    (7204, 7211),
+    (7220, 7221),
+    (7393, 7393),
+    (7415, 7415),
+    (12334, 12335),
+    (43043, 43044),
+    (43047, 43047),
+    (43136, 43137),
+    (43188, 43203),
+    (43346, 43347),
+    (43395, 43395),
+    (43444, 43445),
+    (43450, 43451),
+    (43454, 43456),
+    (43567, 43568),
+    (43571, 43572),
+    (43597, 43597),
+    (43643, 43643),
+    (43645, 43645),
+    (43755, 43755),
+    (43758, 43759),
+    (43765, 43765),
+    (44003, 44004),
+    (44006, 44007),
+    (44009, 44010),
+    (44012, 44012),
+    (69632, 69632),
+    (69634, 69634),
+    (69762, 69762),
+    (69808, 69810),
+    (69815, 69816),
+    (69932, 69932),
+    (69957, 69958),
+    (70018, 70018),
+    (70067, 70069),
+    (70079, 70080),
+    (70094, 70094),
+    (70188, 70190),
+    (70194, 70195),
+    (70197, 70197),
+    (70368, 70370),
+    (70402, 70403),
+    (70462, 70463),
+    (70465, 70468),
+    (70471, 70472),
+    (70475, 70477),
+    (70487, 70487),
+    (70498, 70499),
+    (70584, 70586),
+    (70594, 70594),
+    (70597, 70597),
+    (70599, 70602),
+    (70604, 70605),
+    (70607, 70607),
+    (70709, 70711),
+    (70720, 70721),
+    (70725, 70725),
+    (70832, 70834),
+    (70841, 70841),
+    (70843, 70846),
+    (70849, 70849),
+    (71087, 71089),
+    (71096, 71099),
+    (71102, 71102),
+    (71216, 71218),
+    (71227, 71228),
+    (71230, 71230),
+    (71340, 71340),
+    (71342, 71343),
+    (71350, 71350),
+    (71454, 71454),
+    (71456, 71457),
+    (71462, 71462),
+    (71724, 71726),
+    (71736, 71736),
+    (71984, 71989),
+    (71991, 71992),
+    (71997, 71997),
+    (72000, 72000),
+    (72002, 72002),
+    (72145, 72147),
+    (72156, 72159),
+    (72164, 72164),
+    (72249, 72249),
+    (72279, 72280),
+    (72343, 72343),
+    (72751, 72751),
+    (72766, 72766),
+    (72873, 72873),
+    (72881, 72881),
+    (72884, 72884),
+    (73098, 73102),
+    (73107, 73108),
+    (73110, 73110),
+    (73461, 73462),
+    (73475, 73475),
+    (73524, 73525),
+    (73534, 73535),
+    (73537, 73537),
+    (90410, 90412),
+    (94033, 94087),
+    (94192, 94193),
+    (119141, 119142),
+    (119149, 119154),
+];
+
+pub static GC_ME: [(u32, u32); 5] = [
+    (1160, 1161),
+    (6846, 6846),
+    (8413, 8416),
+    (8418, 8420),
+    (42608, 42610),
+];
+
+pub static GC_MN: [(u32, u32); 357] = [
+    (768, 879),
+    (1155, 1159),
+    (1425, 1469),
+    (1471, 1471),
+    (1473, 1474),
+    (1476, 1477),
+    (1479, 1479),
+    (1552, 1562),
+    (1611, 1631),
+    (1648, 1648),
+    (1750, 1756),
+    (1759, 1764),
+    (1767, 1768),
+    (1770, 1773),
+    (1809, 1809),
+    (1840, 1866),
+    (1958, 1968),
+    (2027, 2035),
+    (2045, 2045),
+    (2070, 2073),
+    (2075, 2083),
+    (2085, 2087),
+    (2089, 2093),
+    (2137, 2139),
+    (2199, 2207),
+    (2250, 2273),
+    (2275, 2306),
+    (2362, 2362),
+    (2364, 2364),
+    (2369, 2376),
+    (2381, 2381),
+    (2385, 2391),
+    (2402, 2403),
+    (2433, 2433),
+    (2492, 2492),
+    (2497, 2500),
+    (2509, 2509),
+    (2530, 2531),
+    (2558, 2558),
+    (2561, 2562),
+    (2620, 2620),
+    (2625, 2626),
+    (2631, 2632),
+    (2635, 2637),
+    (2641, 2641),
+    (2672, 2673),
+    (2677, 2677),
+    (2689, 2690),
+    (2748, 2748),
+    (2753, 2757),
+    (2759, 2760),
+    (2765, 2765),
+    (2786, 2787),
+    (2810, 2815),
+    (2817, 2817),
+    (2876, 2876),
+    (2879, 2879),
+    (2881, 2884),
+    (2893, 2893),
+    (2901, 2902),
+    (2914, 2915),
+    (2946, 2946),
+    (3008, 3008),
+    (3021, 3021),
+    (3072, 3072),
+    (3076, 3076),
+    (3132, 3132),
+    (3134, 3136),
+    (3142, 3144),
+    (3146, 3149),
+    (3157, 3158),
+    (3170, 3171),
+    (3201, 3201),
+    (3260, 3260),
+    (3263, 3263),
+    (3270, 3270),
+    (3276, 3277),
+    (3298, 3299),
+    (3328, 3329),
+    (3387, 3388),
+    (3393, 3396),
+    (3405, 3405),
+    (3426, 3427),
+    (3457, 3457),
+    (3530, 3530),
+    (3538, 3540),
+    (3542, 3542),
+    (3633, 3633),
+    (3636, 3642),
+    (3655, 3662),
+    (3761, 3761),
+    (3764, 3772),
+    (3784, 3790),
+    (3864, 3865),
+    (3893, 3893),
+    (3895, 3895),
+    (3897, 3897),
+    (3953, 3966),
+    (3968, 3972),
+    (3974, 3975),
+    (3981, 3991),
+    (3993, 4028),
+    (4038, 4038),
+    (4141, 4144),
+    (4146, 4151),
+    (4153, 4154),
+    (4157, 4158),
+    (4184, 4185),
+    (4190, 4192),
+    (4209, 4212),
+    (4226, 4226),
+    (4229, 4230),
+    (4237, 4237),
+    (4253, 4253),
+    (4957, 4959),
+    (5906, 5908),
+    (5938, 5939),
+    (5970, 5971),
+    (6002, 6003),
+    (6068, 6069),
+    (6071, 6077),
+    (6086, 6086),
+    (6089, 6099),
+    (6109, 6109),
+    (6155, 6157),
+    (6159, 6159),
+    (6277, 6278),
+    (6313, 6313),
+    (6432, 6434),
+    (6439, 6440),
+    (6450, 6450),
+    (6457, 6459),
+    (6679, 6680),
+    (6683, 6683),
+    (6742, 6742),
+    (6744, 6750),
+    (6752, 6752),
+    (6754, 6754),
+    (6757, 6764),
+    (6771, 6780),
+    (6783, 6783),
+    (6832, 6845),
+    (6847, 6862),
+    (6912, 6915),
+    (6964, 6964),
+    (6966, 6970),
+    (6972, 6972),
+    (6978, 6978),
+    (7019, 7027),
+    (7040, 7041),
+    (7074, 7077),
+    (7080, 7081),
+    (7083, 7085),
+    (7142, 7142),
+    (7144, 7145),
+    (7149, 7149),
+    (7151, 7153),
+    (7212, 7219),
+    (7222, 7223),
+    (7376, 7378),
+    (7380, 7392),
+    (7394, 7400),
+    (7405, 7405),
+    (7412, 7412),
+    (7416, 7417),
+    (7616, 7679),
+    (8400, 8412),
+    (8417, 8417),
+    (8421, 8432),
+    (11503, 11505),
+    (11647, 11647),
+    (11744, 11775),
+    (12330, 12333),
+    (12441, 12442),
+    (42607, 42607),
+    (42612, 42621),
+    (42654, 42655),
+    (42736, 42737),
+    (43010, 43010),
+    (43014, 43014),
+    (43019, 43019),
+    (43045, 43046),
+    (43052, 43052),
+    (43204, 43205),
+    (43232, 43249),
+    (43263, 43263),
+    (43302, 43309),
+    (43335, 43345),
+    (43392, 43394),
+    (43443, 43443),
+    (43446, 43449),
+    (43452, 43453),
+    (43493, 43493),
+    (43561, 43566),
+    (43569, 43570),
+    (43573, 43574),
+    (43587, 43587),
+    (43596, 43596),
+    (43644, 43644),
+    (43696, 43696),
+    (43698, 43700),
+    (43703, 43704),
+    (43710, 43711),
+    (43713, 43713),
+    (43756, 43757),
+    (43766, 43766),
+    (44005, 44005),
+    (44008, 44008),
+    (44013, 44013),
+    (64286, 64286),
+    (65024, 65039),
+    (65056, 65071),
+    (66045, 66045),
+    (66272, 66272),
+    (66422, 66426),
+    (68097, 68099),
+    (68101, 68102),
+    (68108, 68111),
+    (68152, 68154),
+    (68159, 68159),
+    (68325, 68326),
+    (68900, 68903),
+    (68969, 68973),
+    (69291, 69292),
+    (69372, 69375),
+    (69446, 69456),
+    (69506, 69509),
+    (69633, 69633),
+    (69688, 69702),
+    (69744, 69744),
+    (69747, 69748),
+    (69759, 69761),
+    (69811, 69814),
+    (69817, 69818),
+    (69826, 69826),
+    (69888, 69890),
+    (69927, 69931),
+    (69933, 69940),
+    (70003, 70003),
+    (70016, 70017),
+    (70070, 70078),
+    (70089, 70092),
+    (70095, 70095),
+    (70191, 70193),
+    (70196, 70196),
+    (70198, 70199),
+    (70206, 70206),
+    (70209, 70209),
+    (70367, 70367),
+    (70371, 70378),
+    (70400, 70401),
+    (70459, 70460),
+    (70464, 70464),
+    (70502, 70508),
+    (70512, 70516),
+    (70587, 70592),
+    (70606, 70606),
+    (70608, 70608),
+    (70610, 70610),
+    (70625, 70626),
+    (70712, 70719),
+    (70722, 70724),
+    (70726, 70726),
+    (70750, 70750),
+    (70835, 70840),
+    (70842, 70842),
+    (70847, 70848),
+    (70850, 70851),
+    (71090, 71093),
+    (71100, 71101),
+    (71103, 71104),
+    (71132, 71133),
+    (71219, 71226),
+    (71229, 71229),
+    (71231, 71232),
+    (71339, 71339),
+    (71341, 71341),
+    (71344, 71349),
+    (71351, 71351),
+    (71453, 71453),
+    (71455, 71455),
+    (71458, 71461),
+    (71463, 71467),
+    (71727, 71735),
+    (71737, 71738),
+    (71995, 71996),
+    (71998, 71998),
+    (72003, 72003),
+    (72148, 72151),
+    (72154, 72155),
+    (72160, 72160),
+    (72193, 72202),
+    (72243, 72248),
+    (72251, 72254),
+    (72263, 72263),
+    (72273, 72278),
+    (72281, 72283),
+    (72330, 72342),
+    (72344, 72345),
+    (72752, 72758),
+    (72760, 72765),
+    (72767, 72767),
+    (72850, 72871),
+    (72874, 72880),
+    (72882, 72883),
+    (72885, 72886),
+    (73009, 73014),
+    (73018, 73018),
+    (73020, 73021),
+    (73023, 73029),
+    (73031, 73031),
+    (73104, 73105),
+    (73109, 73109),
+    (73111, 73111),
+    (73459, 73460),
+    (73472, 73473),
+    (73526, 73530),
+    (73536, 73536),
+    (73538, 73538),
+    (73562, 73562),
+    (78912, 78912),
+    (78919, 78933),
+    (90398, 90409),
+    (90413, 90415),
+    (92912, 92916),
+    (92976, 92982),
+    (94031, 94031),
+    (94095, 94098),
+    (94180, 94180),
+    (113821, 113822),
+    (118528, 118573),
+    (118576, 118598),
+    (119143, 119145),
+    (119163, 119170),
+    (119173, 119179),
+    (119210, 119213),
+    (119362, 119364),
+    (121344, 121398),
+    (121403, 121452),
+    (121461, 121461),
+    (121476, 121476),
+    (121499, 121503),
+    (121505, 121519),
+    (122880, 122886),
+    (122888, 122904),
+    (122907, 122913),
+    (122915, 122916),
+    (122918, 122922),
+    (123023, 123023),
+    (123184, 123190),
+    (123566, 123566),
+    (123628, 123631),
+    (124140, 124143),
+    (124398, 124399),
+    (125136, 125142),
+    (125252, 125258),
+    (917760, 917999),
+];
+
+pub static GC_ND: [(u32, u32); 71] = [
+    (48, 57),
+    (1632, 1641),
+    (1776, 1785),
+    (1984, 1993),
+    (2406, 2415),
+    (2534, 2543),
+    (2662, 2671),
+    (2790, 2799),
+    (2918, 2927),
+    (3046, 3055),
+    (3174, 3183),
+    (3302, 3311),
+    (3430, 3439),
+    (3558, 3567),
+    (3664, 3673),
+    (3792, 3801),
+    (3872, 3881),
+    (4160, 4169),
+    (4240, 4249),
+    (6112, 6121),
+    (6160, 6169),
+    (6470, 6479),
+    (6608, 6617),
+    (6784, 6793),
+    (6800, 6809),
+    (6992, 7001),
+    (7088, 7097),
+    (7232, 7241),
+    (7248, 7257),
+    (42528, 42537),
+    (43216, 43225),
+    (43264, 43273),
+    (43472, 43481),
+    (43504, 43513),
+    (43600, 43609),
+    (44016, 44025),
+    (65296, 65305),
+    (66720, 66729),
+    (68912, 68921),
+    (68928, 68937),
+    (69734, 69743),
+    (69872, 69881),
+    (69942, 69951),
+    (70096, 70105),
+    (70384, 70393),
+    (70736, 70745),
+    (70864, 70873),
+    (71248, 71257),
+    (71360, 71369),
+    (71376, 71395),
+    (71472, 71481),
+    (71904, 71913),
+    (72016, 72025),
+    (72688, 72697),
+    (72784, 72793),
+    (73040, 73049),
+    (73120, 73129),
+    (73552, 73561),
+    (90416, 90425),
+    (92768, 92777),
+    (92864, 92873),
+    (93008, 93017),
+    (93552, 93561),
+    (118000, 118009),
+    (120782, 120831),
+    (123200, 123209),
+    (123632, 123641),
+    (124144, 124153),
+    (124401, 124410),
+    (125264, 125273),
+    (130032, 130041),
+];
+
+pub static GC_NL: [(u32, u32); 12] = [
+    (5870, 5872),
+    (8544, 8578),
+    (8581, 8584),
+    (12295, 12295),
+    (12321, 12329),
+    (12344, 12346),
+    (42726, 42735),
+    (65856, 65908),
+    (66369, 66369),
+    (66378, 66378),
+    (66513, 66517),
+    (74752, 74862),
+];
+
+pub static GC_NO: [(u32, u32); 72] = [
+    (178, 179),
+    (185, 185),
+    (188, 190),
+    (2548, 2553),
+    (2930, 2935),
+    (3056, 3058),
+    (3192, 3198),
+    (3416, 3422),
+    (3440, 3448),
+    (3882, 3891),
+    (4969, 4988),
+    (6128, 6137),
+    (6618, 6618),
+    (8304, 8304),
+    (8308, 8313),
+    (8320, 8329),
+    (8528, 8543),
+    (8585, 8585),
+    (9312, 9371),
+    (9450, 9471),
+    (10102, 10131),
+    (11517, 11517),
+    (12690, 12693),
+    (12832, 12841),
+    (12872, 12879),
+    (12881, 12895),
+    (12928, 12937),
+    (12977, 12991),
+    (43056, 43061),
+    (65799, 65843),
+    (65909, 65912),
+    (65930, 65931),
+    (66273, 66299),
+    (66336, 66339),
+    (67672, 67679),
+    (67705, 67711),
+    (67751, 67759),
+    (67835, 67839),
+    (67862, 67867),
+    (68028, 68029),
+    (68032, 68047),
+    (68050, 68095),
+    (68160, 68168),
+    (68221, 68222),
+    (68253, 68255),
+    (68331, 68335),
+    (68440, 68447),
+    (68472, 68479),
+    (68521, 68527),
+    (68858, 68863),
+    (69216, 69246),
+    (69405, 69414),
+    (69457, 69460),
+    (69573, 69579),
+    (69714, 69733),
+    (70113, 70132),
+    (71482, 71483),
+    (71914, 71922),
+    (72794, 72812),
+    (73664, 73684),
+    (93019, 93025),
+    (93824, 93846),
+    (119488, 119507),
+    (119520, 119539),
+    (119648, 119672),
+    (125127, 125135),
+    (126065, 126123),
+    (126125, 126127),
+    (126129, 126132),
+    (126209, 126253),
+    (126255, 126269),
+    (127232, 127244),
+];
+
+pub static GC_PC: [(u32, u32); 6] = [
+    (95, 95),
+    (8255, 8256),
+    (8276, 8276),
+    (65075, 65076),
+    (65101, 65103),
+    (65343, 65343),
+];
+
+pub static GC_PD: [(u32, u32); 20] = [
+    (45, 45),
+    (1418, 1418),
+    (1470, 1470),
+    (5120, 5120),
+    (6150, 6150),
+    (8208, 8213),
+    (11799, 11799),
+    (11802, 11802),
+    (11834, 11835),
+    (11840, 11840),
+    (11869, 11869),
+    (12316, 12316),
+    (12336, 12336),
+    (12448, 12448),
+    (65073, 65074),
+    (65112, 65112),
+    (65123, 65123),
+    (65293, 65293),
+    (68974, 68974),
+    (69293, 69293),
+];
+
+pub static GC_PE: [(u32, u32); 76] = [
+    (41, 41),
+    (93, 93),
+    (125, 125),
+    (3899, 3899),
+    (3901, 3901),
+    (5788, 5788),
+    (8262, 8262),
+    (8318, 8318),
+    (8334, 8334),
+    (8969, 8969),
+    (8971, 8971),
+    (9002, 9002),
+    (10089, 10089),
+    (10091, 10091),
+    (10093, 10093),
+    (10095, 10095),
+    (10097, 10097),
+    (10099, 10099),
+    (10101, 10101),
+    (10182, 10182),
+    (10215, 10215),
+    (10217, 10217),
+    (10219, 10219),
+    (10221, 10221),
+    (10223, 10223),
+    (10628, 10628),
+    (10630, 10630),
+    (10632, 10632),
+    (10634, 10634),
+    (10636, 10636),
+    (10638, 10638),
+    (10640, 10640),
+    (10642, 10642),
+    (10644, 10644),
+    (10646, 10646),
+    (10648, 10648),
+    (10713, 10713),
+    (10715, 10715),
+    (10749, 10749),
+    (11811, 11811),
+    (11813, 11813),
+    (11815, 11815),
+    (11817, 11817),
+    (11862, 11862),
+    (11864, 11864),
+    (11866, 11866),
+    (11868, 11868),
+    (12297, 12297),
+    (12299, 12299),
+    (12301, 12301),
+    (12303, 12303),
+    (12305, 12305),
+    (12309, 12309),
+    (12311, 12311),
+    (12313, 12313),
+    (12315, 12315),
+    (12318, 12319),
+    (64830, 64830),
+    (65048, 65048),
+    (65078, 65078),
+    (65080, 65080),
+    (65082, 65082),
+    (65084, 65084),
+    (65086, 65086),
+    (65088, 65088),
+    (65090, 65090),
+    (65092, 65092),
+    (65096, 65096),
+    (65114, 65114),
+    (65116, 65116),
+    (65118, 65118),
+    (65289, 65289),
+    (65341, 65341),
+    (65373, 65373),
+    (65376, 65376),
+    (65379, 65379),
+];
+
+pub static GC_PF: [(u32, u32); 10] = [
+    (187, 187),
+    (8217, 8217),
+    (8221, 8221),
+    (8250, 8250),
+    (11779, 11779),
+    (11781, 11781),
+    (11786, 11786),
+    (11789, 11789),
+    (11805, 11805),
+    (11809, 11809),
+];
+
+pub static GC_PI: [(u32, u32); 11] = [
+    (171, 171),
+    (8216, 8216),
+    (8219, 8220),
+    (8223, 8223),
+    (8249, 8249),
+    (11778, 11778),
+    (11780, 11780),
+    (11785, 11785),
+    (11788, 11788),
+    (11804, 11804),
+    (11808, 11808),
+];
+
+pub static GC_PO: [(u32, u32); 193] = [
+    (33, 35),
+    (37, 39),
+    (42, 42),
+    (44, 44),
+    (46, 47),
+    (58, 59),
+    (63, 64),
+    (92, 92),
+    (161, 161),
+    (167, 167),
+    (182, 183),
+    (191, 191),
+    (894, 894),
+    (903, 903),
+    (1370, 1375),
+    (1417, 1417),
+    (1472, 1472),
+    (1475, 1475),
+    (1478, 1478),
+    (1523, 1524),
+    (1545, 1546),
+    (1548, 1549),
+    (1563, 1563),
+    (1565, 1567),
+    (1642, 1645),
+    (1748, 1748),
+    (1792, 1805),
+    (2039, 2041),
+    (2096, 2110),
+    (2142, 2142),
+    (2404, 2405),
+    (2416, 2416),
+    (2557, 2557),
+    (2678, 2678),
+    (2800, 2800),
+    (3191, 3191),
+    (3204, 3204),
+    (3572, 3572),
+    (3663, 3663),
+    (3674, 3675),
+    (3844, 3858),
+    (3860, 3860),
+    (3973, 3973),
+    (4048, 4052),
+    (4057, 4058),
+    (4170, 4175),
+    (4347, 4347),
+    (4960, 4968),
+    (5742, 5742),
+    (5867, 5869),
+    (5941, 5942),
+    (6100, 6102),
+    (6104, 6106),
+    (6144, 6149),
+    (6151, 6154),
+    (6468, 6469),
+    (6686, 6687),
+    (6816, 6822),
+    (6824, 6829),
+    (6990, 6991),
+    (7002, 7008),
+    (7037, 7039),
+    (7164, 7167),
+    (7227, 7231),
+    (7294, 7295),
+    (7360, 7367),
+    (7379, 7379),
+    (8214, 8215),
+    (8224, 8231),
+    (8240, 8248),
+    (8251, 8254),
+    (8257, 8259),
+    (8263, 8273),
+    (8275, 8275),
+    (8277, 8286),
+    (11513, 11516),
+    (11518, 11519),
+    (11632, 11632),
+    (11776, 11777),
+    (11782, 11784),
+    (11787, 11787),
+    (11790, 11798),
+    (11800, 11801),
+    (11803, 11803),
+    (11806, 11807),
+    (11818, 11822),
+    (11824, 11833),
+    (11836, 11839),
+    (11841, 11841),
+    (11843, 11855),
+    (11858, 11860),
+    (12289, 12291),
+    (12349, 12349),
+    (12539, 12539),
+    (42238, 42239),
+    (42509, 42511),
+    (42611, 42611),
+    (42622, 42622),
+    (42738, 42743),
+    (43124, 43127),
+    (43214, 43215),
+    (43256, 43258),
+    (43260, 43260),
+    (43310, 43311),
+    (43359, 43359),
+    (43457, 43469),
+    (43486, 43487),
+    (43612, 43615),
+    (43742, 43743),
+    (43760, 43761),
+    (44011, 44011),
+    (65040, 65046),
+    (65049, 65049),
+    (65072, 65072),
+    (65093, 65094),
+    (65097, 65100),
+    (65104, 65106),
+    (65108, 65111),
+    (65119, 65121),
+    (65128, 65128),
+    (65130, 65131),
+    (65281, 65283),
+    (65285, 65287),
+    (65290, 65290),
+    (65292, 65292),
+    (65294, 65295),
+    (65306, 65307),
+    (65311, 65312),
+    (65340, 65340),
+    (65377, 65377),
+    (65380, 65381),
+    (65792, 65794),
+    (66463, 66463),
+    (66512, 66512),
+    (66927, 66927),
+    (67671, 67671),
+    (67871, 67871),
+    (67903, 67903),
+    (68176, 68184),
+    (68223, 68223),
+    (68336, 68342),
+    (68409, 68415),
+    (68505, 68508),
+    (69461, 69465),
+    (69510, 69513),
+    (69703, 69709),
+    (69819, 69820),
+    (69822, 69825),
+    (69952, 69955),
+    (70004, 70005),
+    (70085, 70088),
+    (70093, 70093),
+    (70107, 70107),
+    (70109, 70111),
+    (70200, 70205),
+    (70313, 70313),
+    (70612, 70613),
+    (70615, 70616),
+    (70731, 70735),
+    (70746, 70747),
+    (70749, 70749),
+    (70854, 70854),
+    (71105, 71127),
+    (71233, 71235),
+    (71264, 71276),
+    (71353, 71353),
+    (71484, 71486),
+    (71739, 71739),
+    (72004, 72006),
+    (72162, 72162),
+    (72255, 72262),
+    (72346, 72348),
+    (72350, 72354),
+    (72448, 72457),
+    (72673, 72673),
+    (72769, 72773),
+    (72816, 72817),
+    (73463, 73464),
+    (73539, 73551),
+    (73727, 73727),
+    (74864, 74868),
+    (77809, 77810),
+    (92782, 92783),
+    (92917, 92917),
+    (92983, 92987),
+    (92996, 92996),
+    (93549, 93551),
+    (93847, 93850),
+    (94178, 94178),
+    (113823, 113823),
+    (121479, 121483),
+    (124415, 124415),
+    (125278, 125279),
+];
+
+pub static GC_PS: [(u32, u32); 79] = [
+    (40, 40),
+    (91, 91),
+    (123, 123),
+    (3898, 3898),
+    (3900, 3900),
+    (5787, 5787),
+    (8218, 8218),
+    (8222, 8222),
+    (8261, 8261),
+    (8317, 8317),
+    (8333, 8333),
+    (8968, 8968),
+    (8970, 8970),
+    (9001, 9001),
+    (10088, 10088),
+    (10090, 10090),
+    (10092, 10092),
+    (10094, 10094),
+    (10096, 10096),
+    (10098, 10098),
+    (10100, 10100),
+    (10181, 10181),
+    (10214, 10214),
+    (10216, 10216),
+    (10218, 10218),
+    (10220, 10220),
+    (10222, 10222),
+    (10627, 10627),
+    (10629, 10629),
+    (10631, 10631),
+    (10633, 10633),
+    (10635, 10635),
+    (10637, 10637),
+    (10639, 10639),
+    (10641, 10641),
+    (10643, 10643),
+    (10645, 10645),
+    (10647, 10647),
+    (10712, 10712),
+    (10714, 10714),
+    (10748, 10748),
+    (11810, 11810),
+    (11812, 11812),
+    (11814, 11814),
+    (11816, 11816),
+    (11842, 11842),
+    (11861, 11861),
+    (11863, 11863),
+    (11865, 11865),
+    (11867, 11867),
+    (12296, 12296),
+    (12298, 12298),
+    (12300, 12300),
+    (12302, 12302),
+    (12304, 12304),
+    (12308, 12308),
+    (12310, 12310),
+    (12312, 12312),
+    (12314, 12314),
+    (12317, 12317),
+    (64831, 64831),
+    (65047, 65047),
+    (65077, 65077),
+    (65079, 65079),
+    (65081, 65081),
+    (65083, 65083),
+    (65085, 65085),
+    (65087, 65087),
+    (65089, 65089),
+    (65091, 65091),
+    (65095, 65095),
+    (65113, 65113),
+    (65115, 65115),
+    (65117, 65117),
+    (65288, 65288),
+    (65339, 65339),
+    (65371, 65371),
+    (65375, 65375),
+    (65378, 65378),
+];
+
+pub static GC_SC: [(u32, u32); 21] = [
+    (36, 36),
+    (162, 165),
+    (1423, 1423),
+    (1547, 1547),
+    (2046, 2047),
+    (2546, 2547),
+    (2555, 2555),
+    (2801, 2801),
+    (3065, 3065),
+    (3647, 3647),
+    (6107, 6107),
+    (8352, 8384),
+    (43064, 43064),
+    (65020, 65020),
+    (65129, 65129),
+    (65284, 65284),
+    (65504, 65505),
+    (65509, 65510),
+    (73693, 73696),
+    (123647, 123647),
+    (126128, 126128),
+];
+
+pub static GC_SK: [(u32, u32); 31] = [
+    (94, 94),
+    (96, 96),
+    (168, 168),
+    (175, 175),
+    (180, 180),
+    (184, 184),
+    (706, 709),
+    (722, 735),
+    (741, 747),
+    (749, 749),
+    (751, 767),
+    (885, 885),
+    (900, 901),
+    (2184, 2184),
+    (8125, 8125),
+    (8127, 8129),
+    (8141, 8143),
+    (8157, 8159),
+    (8173, 8175),
+    (8189, 8190),
+    (12443, 12444),
+    (42752, 42774),
+    (42784, 42785),
+    (42889, 42890),
+    (43867, 43867),
+    (43882, 43883),
+    (64434, 64450),
+    (65342, 65342),
+    (65344, 65344),
+    (65507, 65507),
+    (127995, 127999),
+];
+
+pub static GC_SM: [(u32, u32); 65] = [
+    (43, 43),
+    (60, 62),
+    (124, 124),
+    (126, 126),
+    (172, 172),
+    (177, 177),
+    (215, 215),
+    (247, 247),
+    (1014, 1014),
+    (1542, 1544),
+    (8260, 8260),
+    (8274, 8274),
+    (8314, 8316),
+    (8330, 8332),
+    (8472, 8472),
+    (8512, 8516),
+    (8523, 8523),
+    (8592, 8596),
+    (8602, 8603),
+    (8608, 8608),
+    (8611, 8611),
+    (8614, 8614),
+    (8622, 8622),
+    (8654, 8655),
+    (8658, 8658),
+    (8660, 8660),
+    (8692, 8959),
+    (8992, 8993),
+    (9084, 9084),
+    (9115, 9139),
+    (9180, 9185),
+    (9655, 9655),
+    (9665, 9665),
+    (9720, 9727),
+    (9839, 9839),
+    (10176, 10180),
+    (10183, 10213),
+    (10224, 10239),
+    (10496, 10626),
+    (10649, 10711),
+    (10716, 10747),
+    (10750, 11007),
+    (11056, 11076),
+    (11079, 11084),
+    (64297, 64297),
+    (65122, 65122),
+    (65124, 65126),
+    (65291, 65291),
+    (65308, 65310),
+    (65372, 65372),
+    (65374, 65374),
+    (65506, 65506),
+    (65513, 65516),
+    (69006, 69007),
+    (120513, 120513),
+    (120539, 120539),
+    (120571, 120571),
+    (120597, 120597),
+    (120629, 120629),
+    (120655, 120655),
+    (120687, 120687),
+    (120713, 120713),
+    (120745, 120745),
+    (120771, 120771),
+    (126704, 126705),
+];
+
+pub static GC_SO: [(u32, u32); 187] = [
+    (166, 166),
+    (169, 169),
+    (174, 174),
+    (176, 176),
+    (1154, 1154),
+    (1421, 1422),
+    (1550, 1551),
+    (1758, 1758),
+    (1769, 1769),
+    (1789, 1790),
+    (2038, 2038),
+    (2554, 2554),
+    (2928, 2928),
+    (3059, 3064),
+    (3066, 3066),
+    (3199, 3199),
+    (3407, 3407),
+    (3449, 3449),
+    (3841, 3843),
+    (3859, 3859),
+    (3861, 3863),
+    (3866, 3871),
+    (3892, 3892),
+    (3894, 3894),
+    (3896, 3896),
+    (4030, 4037),
+    (4039, 4044),
+    (4046, 4047),
+    (4053, 4056),
+    (4254, 4255),
+    (5008, 5017),
+    (5741, 5741),
+    (6464, 6464),
+    (6622, 6655),
+    (7009, 7018),
+    (7028, 7036),
+    (8448, 8449),
+    (8451, 8454),
+    (8456, 8457),
+    (8468, 8468),
+    (8470, 8471),
+    (8478, 8483),
+    (8485, 8485),
+    (8487, 8487),
+    (8489, 8489),
+    (8494, 8494),
+    (8506, 8507),
+    (8522, 8522),
+    (8524, 8525),
+    (8527, 8527),
+    (8586, 8587),
+    (8597, 8601),
+    (8604, 8607),
+    (8609, 8610),
+    (8612, 8613),
+    (8615, 8621),
+    (8623, 8653),
+    (8656, 8657),
+    (8659, 8659),
+    (8661, 8691),
+    (8960, 8967),
+    (8972, 8991),
+    (8994, 9000),
+    (9003, 9083),
+    (9085, 9114),
+    (9140, 9179),
+    (9186, 9257),
+    (9280, 9290),
+    (9372, 9449),
+    (9472, 9654),
+    (9656, 9664),
+    (9666, 9719),
+    (9728, 9838),
+    (9840, 10087),
+    (10132, 10175),
+    (10240, 10495),
+    (11008, 11055),
+    (11077, 11078),
+    (11085, 11123),
+    (11126, 11157),
+    (11159, 11263),
+    (11493, 11498),
+    (11856, 11857),
+    (11904, 11929),
+    (11931, 12019),
+    (12032, 12245),
+    (12272, 12287),
+    (12292, 12292),
+    (12306, 12307),
+    (12320, 12320),
+    (12342, 12343),
+    (12350, 12351),
+    (12688, 12689),
+    (12694, 12703),
+    (12736, 12773),
+    (12783, 12783),
+    (12800, 12830),
+    (12842, 12871),
+    (12880, 12880),
+    (12896, 12927),
+    (12938, 12976),
+    (12992, 13311),
+    (19904, 19967),
+    (42128, 42182),
+    (43048, 43051),
+    (43062, 43063),
+    (43065, 43065),
+    (43639, 43641),
+    (64832, 64847),
+    (64975, 64975),
+    (65021, 65023),
+    (65508, 65508),
+    (65512, 65512),
+    (65517, 65518),
+    (65532, 65533),
+    (65847, 65855),
+    (65913, 65929),
+    (65932, 65934),
+    (65936, 65948),
+    (65952, 65952),
+    (66000, 66044),
+    (67703, 67704),
+    (68296, 68296),
+    (71487, 71487),
+    (73685, 73692),
+    (73697, 73713),
+    (92988, 92991),
+    (92997, 92997),
+    (113820, 113820),
+    (117760, 117999),
+    (118016, 118451),
+    (118608, 118723),
+    (118784, 119029),
+    (119040, 119078),
+    (119081, 119140),
+    (119146, 119148),
+    (119171, 119172),
+    (119180, 119209),
+    (119214, 119274),
+    (119296, 119361),
+    (119365, 119365),
+    (119552, 119638),
+    (120832, 121343),
+    (121399, 121402),
+    (121453, 121460),
+    (121462, 121475),
+    (121477, 121478),
+    (123215, 123215),
+    (126124, 126124),
+    (126254, 126254),
+    (126976, 127019),
+    (127024, 127123),
+    (127136, 127150),
+    (127153, 127167),
+    (127169, 127183),
+    (127185, 127221),
+    (127245, 127405),
+    (127462, 127490),
+    (127504, 127547),
+    (127552, 127560),
+    (127568, 127569),
+    (127584, 127589),
+    (127744, 127994),
+    (128000, 128727),
+    (128732, 128748),
+    (128752, 128764),
+    (128768, 128886),
+    (128891, 128985),
+    (128992, 129003),
+    (129008, 129008),
+    (129024, 129035),
+    (129040, 129095),
+    (129104, 129113),
+    (129120, 129159),
+    (129168, 129197),
+    (129200, 129211),
+    (129216, 129217),
+    (129280, 129619),
+    (129632, 129645),
+    (129648, 129660),
+    (129664, 129673),
+    (129679, 129734),
+    (129742, 129756),
+    (129759, 129769),
+    (129776, 129784),
+    (129792, 129938),
+    (129940, 130031),
+];
+
+pub static GC_ZL: [(u32, u32); 1] = [(8232, 8232)];
+
+pub static GC_ZP: [(u32, u32); 1] = [(8233, 8233)];
+
+pub static GC_ZS: [(u32, u32); 7] = [
+    (32, 32),
+    (160, 160),
+    (5760, 5760),
+    (8192, 8202),
+    (8239, 8239),
+    (8287, 8287),
+    (12288, 12288),
+];
+
+pub static GENERAL_CATEGORIES: [(&str, &[(u32, u32)]); 29] = [
+    ("Cc", &GC_CC),
+    ("Cf", &GC_CF),
+    ("Cn", &GC_CN),
+    ("Co", &GC_CO),
+    ("Ll", &GC_LL),
+    ("Lm", &GC_LM),
+    ("Lo", &GC_LO),
+    ("Lt", &GC_LT),
+    ("Lu", &GC_LU),
+    ("Mc", &GC_MC),
+    ("Me", &GC_ME),
+    ("Mn", &GC_MN),
+    ("Nd", &GC_ND),
+    ("Nl", &GC_NL),
+    ("No", &GC_NO),
+    ("Pc", &GC_PC),
+    ("Pd", &GC_PD),
+    ("Pe", &GC_PE),
+    ("Pf", &GC_PF),
+    ("Pi", &GC_PI),
+    ("Po", &GC_PO),
+    ("Ps", &GC_PS),
+    ("Sc", &GC_SC),
+    ("Sk", &GC_SK),
+    ("Sm", &GC_SM),
+    ("So", &GC_SO),
+    ("Zl", &GC_ZL),
+    ("Zp", &GC_ZP),
+    ("Zs", &GC_ZS),
+];
+
+pub static SC_ADLAM: [(u32, u32); 3] = [(125184, 125259), (125264, 125273), (125278, 125279)];
+
+pub static SC_AHOM: [(u32, u32); 3] = [(71424, 71450), (71453, 71467), (71472, 71494)];
+
+pub static SC_ANATOLIAN_HIEROGLYPHS: [(u32, u32); 1] = [(82944, 83526)];
+
+pub static SC_ARABIC: [(u32, u32); 59] = [
+    (1536, 1540),
+    (1542, 1547),
+    (1549, 1562),
+    (1564, 1566),
+    (1568, 1599),
+    (1601, 1610),
+    (1622, 1647),
+    (1649, 1756),
+    (1758, 1791),
+    (1872, 1919),
+    (2160, 2190),
+    (2192, 2193),
+    (2199, 2273),
+    (2275, 2303),
+    (64336, 64450),
+    (64467, 64829),
+    (64832, 64911),
+    (64914, 64967),
+    (64975, 64975),
+    (65008, 65023),
+    (65136, 65140),
+    (65142, 65276),
+    (69216, 69246),
+    (69314, 69316),
+    (69372, 69375),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+    (126704, 126705),
+];
+
+pub static SC_ARMENIAN: [(u32, u32); 4] =
+    [(1329, 1366), (1369, 1418), (1421, 1423), (64275, 64279)];
+
+pub static SC_AVESTAN: [(u32, u32); 2] = [(68352, 68405), (68409, 68415)];
+
+pub static SC_BALINESE: [(u32, u32); 2] = [(6912, 6988), (6990, 7039)];
+
+pub static SC_BAMUM: [(u32, u32); 2] = [(42656, 42743), (92160, 92728)];
+
+pub static SC_BASSA_VAH: [(u32, u32); 2] = [(92880, 92909), (92912, 92917)];
+
+pub static SC_BATAK: [(u32, u32); 2] = [(7104, 7155), (7164, 7167)];
+
+pub static SC_BENGALI: [(u32, u32); 14] = [
+    (2432, 2435),
+    (2437, 2444),
+    (2447, 2448),
+    (2451, 2472),
+    (2474, 2480),
+    (2482, 2482),
+    (2486, 2489),
+    (2492, 2500),
+    (2503, 2504),
+    (2507, 2510),
+    (2519, 2519),
+    (2524, 2525),
+    (2527, 2531),
+    (2534, 2558),
+];
+
+pub static SC_BHAIKSUKI: [(u32, u32); 4] = [
+    (72704, 72712),
+    (72714, 72758),
+    (72760, 72773),
+    (72784, 72812),
+];
+
+pub static SC_BOPOMOFO: [(u32, u32); 3] = [(746, 747), (12549, 12591), (12704, 12735)];
+
+pub static SC_BRAHMI: [(u32, u32); 3] = [(69632, 69709), (69714, 69749), (69759, 69759)];
+
+pub static SC_BRAILLE: [(u32, u32); 1] = [(10240, 10495)];
+
+pub static SC_BUGINESE: [(u32, u32); 2] = [(6656, 6683), (6686, 6687)];
+
+pub static SC_BUHID: [(u32, u32); 1] = [(5952, 5971)];
+
+pub static SC_CANADIAN_ABORIGINAL: [(u32, u32); 3] = [(5120, 5759), (6320, 6389), (72368, 72383)];
+
+pub static SC_CARIAN: [(u32, u32); 1] = [(66208, 66256)];
+
+pub static SC_CAUCASIAN_ALBANIAN: [(u32, u32); 2] = [(66864, 66915), (66927, 66927)];
+
+pub static SC_CHAKMA: [(u32, u32); 2] = [(69888, 69940), (69942, 69959)];
+
+pub static SC_CHAM: [(u32, u32); 4] = [
+    (43520, 43574),
+    (43584, 43597),
+    (43600, 43609),
+    (43612, 43615),
+];
+
+pub static SC_CHEROKEE: [(u32, u32); 3] = [(5024, 5109), (5112, 5117), (43888, 43967)];
+
+pub static SC_CHORASMIAN: [(u32, u32); 1] = [(69552, 69579)];
+
+pub static SC_COMMON: [(u32, u32); 174] = [
+    (0, 64),
+    (91, 96),
+    (123, 169),
+    (171, 185),
+    (187, 191),
+    (215, 215),
+    (247, 247),
+    (697, 735),
+    (741, 745),
+    (748, 767),
+    (884, 884),
+    (894, 894),
+    (901, 901),
+    (903, 903),
+    (1541, 1541),
+    (1548, 1548),
+    (1563, 1563),
+    (1567, 1567),
+    (1600, 1600),
+    (1757, 1757),
+    (2274, 2274),
+    (2404, 2405),
+    (3647, 3647),
+    (4053, 4056),
+    (4347, 4347),
+    (5867, 5869),
+    (5941, 5942),
+    (6146, 6147),
+    (6149, 6149),
+    (7379, 7379),
+    (7393, 7393),
+    (7401, 7404),
+    (7406, 7411),
+    (7413, 7415),
+    (7418, 7418),
+    (8192, 8203),
+    (8206, 8292),
+    (8294, 8304),
+    (8308, 8318),
+    (8320, 8334),
+    (8352, 8384),
+    (8448, 8485),
+    (8487, 8489),
+    (8492, 8497),
+    (8499, 8525),
+    (8527, 8543),
+    (8585, 8587),
+    (8592, 9257),
+    (9280, 9290),
+    (9312, 10239),
+    (10496, 11123),
+    (11126, 11157),
+    (11159, 11263),
+    (11776, 11869),
+    (12272, 12292),
+    (12294, 12294),
+    (12296, 12320),
+    (12336, 12343),
+    (12348, 12351),
+    (12443, 12444),
+    (12448, 12448),
+    (12539, 12540),
+    (12688, 12703),
+    (12736, 12773),
+    (12783, 12783),
+    (12832, 12895),
+    (12927, 13007),
+    (13055, 13055),
+    (13144, 13311),
+    (19904, 19967),
+    (42752, 42785),
+    (42888, 42890),
+    (43056, 43065),
+    (43310, 43310),
+    (43471, 43471),
+    (43867, 43867),
+    (43882, 43883),
+    (64830, 64831),
+    (65040, 65049),
+    (65072, 65106),
+    (65108, 65126),
+    (65128, 65131),
+    (65279, 65279),
+    (65281, 65312),
+    (65339, 65344),
+    (65371, 65381),
+    (65392, 65392),
+    (65438, 65439),
+    (65504, 65510),
+    (65512, 65518),
+    (65529, 65533),
+    (65792, 65794),
+    (65799, 65843),
+    (65847, 65855),
+    (65936, 65948),
+    (66000, 66044),
+    (66273, 66299),
+    (113824, 113827),
+    (117760, 118009),
+    (118016, 118451),
+    (118608, 118723),
+    (118784, 119029),
+    (119040, 119078),
+    (119081, 119142),
+    (119146, 119162),
+    (119171, 119172),
+    (119180, 119209),
+    (119214, 119274),
+    (119488, 119507),
+    (119520, 119539),
+    (119552, 119638),
+    (119648, 119672),
+    (119808, 119892),
+    (119894, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119993),
+    (119995, 119995),
+    (119997, 120003),
+    (120005, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120094, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120146, 120485),
+    (120488, 120779),
+    (120782, 120831),
+    (126065, 126132),
+    (126209, 126269),
+    (126976, 127019),
+    (127024, 127123),
+    (127136, 127150),
+    (127153, 127167),
+    (127169, 127183),
+    (127185, 127221),
+    (127232, 127405),
+    (127462, 127487),
+    (127489, 127490),
+    (127504, 127547),
+    (127552, 127560),
+    (127568, 127569),
+    (127584, 127589),
+    (127744, 128727),
+    (128732, 128748),
+    (128752, 128764),
+    (128768, 128886),
+    (128891, 128985),
+    (128992, 129003),
+    (129008, 129008),
+    (129024, 129035),
+    (129040, 129095),
+    (129104, 129113),
+    (129120, 129159),
+    (129168, 129197),
+    (129200, 129211),
+    (129216, 129217),
+    (129280, 129619),
+    (129632, 129645),
+    (129648, 129660),
+    (129664, 129673),
+    (129679, 129734),
+    (129742, 129756),
+    (129759, 129769),
+    (129776, 129784),
+    (129792, 129938),
+    (129940, 130041),
+    (917505, 917505),
+    (917536, 917631),
+];
+
+pub static SC_COPTIC: [(u32, u32); 3] = [(994, 1007), (11392, 11507), (11513, 11519)];
+
+pub static SC_CUNEIFORM: [(u32, u32); 4] = [
+    (73728, 74649),
+    (74752, 74862),
+    (74864, 74868),
+    (74880, 75075),
+];
+
+pub static SC_CYPRIOT: [(u32, u32); 6] = [
+    (67584, 67589),
+    (67592, 67592),
+    (67594, 67637),
+    (67639, 67640),
+    (67644, 67644),
+    (67647, 67647),
+];
+
+pub static SC_CYPRO_MINOAN: [(u32, u32); 1] = [(77712, 77810)];
+
+pub static SC_CYRILLIC: [(u32, u32); 10] = [
+    (1024, 1156),
+    (1159, 1327),
+    (7296, 7306),
+    (7467, 7467),
+    (7544, 7544),
+    (11744, 11775),
+    (42560, 42655),
+    (65070, 65071),
+    (122928, 122989),
+    (123023, 123023),
+];
+
+pub static SC_DESERET: [(u32, u32); 1] = [(66560, 66639)];
+
+pub static SC_DEVANAGARI: [(u32, u32); 5] = [
+    (2304, 2384),
+    (2389, 2403),
+    (2406, 2431),
+    (43232, 43263),
+    (72448, 72457),
+];
+
+pub static SC_DIVES_AKURU: [(u32, u32); 8] = [
+    (71936, 71942),
+    (71945, 71945),
+    (71948, 71955),
+    (71957, 71958),
+    (71960, 71989),
+    (71991, 71992),
+    (71995, 72006),
+    (72016, 72025),
+];
+
+pub static SC_DOGRA: [(u32, u32); 1] = [(71680, 71739)];
+
+pub static SC_DUPLOYAN: [(u32, u32); 5] = [
+    (113664, 113770),
+    (113776, 113788),
+    (113792, 113800),
+    (113808, 113817),
+    (113820, 113823),
+];
+
+pub static SC_EGYPTIAN_HIEROGLYPHS: [(u32, u32); 2] = [(77824, 78933), (78944, 82938)];
+
+pub static SC_ELBASAN: [(u32, u32); 1] = [(66816, 66855)];
+
+pub static SC_ELYMAIC: [(u32, u32); 1] = [(69600, 69622)];
+
+pub static SC_ETHIOPIC: [(u32, u32); 36] = [
+    (4608, 4680),
+    (4682, 4685),
+    (4688, 4694),
+    (4696, 4696),
+    (4698, 4701),
+    (4704, 4744),
+    (4746, 4749),
+    (4752, 4784),
+    (4786, 4789),
+    (4792, 4798),
+    (4800, 4800),
+    (4802, 4805),
+    (4808, 4822),
+    (4824, 4880),
+    (4882, 4885),
+    (4888, 4954),
+    (4957, 4988),
+    (4992, 5017),
+    (11648, 11670),
+    (11680, 11686),
+    (11688, 11694),
+    (11696, 11702),
+    (11704, 11710),
+    (11712, 11718),
+    (11720, 11726),
+    (11728, 11734),
+    (11736, 11742),
+    (43777, 43782),
+    (43785, 43790),
+    (43793, 43798),
+    (43808, 43814),
+    (43816, 43822),
+    (124896, 124902),
+    (124904, 124907),
+    (124909, 124910),
+    (124912, 124926),
+];
+
+pub static SC_GARAY: [(u32, u32); 3] = [(68928, 68965), (68969, 68997), (69006, 69007)];
+
+pub static SC_GEORGIAN: [(u32, u32); 10] = [
+    (4256, 4293),
+    (4295, 4295),
+    (4301, 4301),
+    (4304, 4346),
+    (4348, 4351),
+    (7312, 7354),
+    (7357, 7359),
+    (11520, 11557),
+    (11559, 11559),
+    (11565, 11565),
+];
+
+pub static SC_GLAGOLITIC: [(u32, u32); 6] = [
+    (11264, 11359),
+    (122880, 122886),
+    (122888, 122904),
+    (122907, 122913),
+    (122915, 122916),
+    (122918, 122922),
+];
+
+pub static SC_GOTHIC: [(u32, u32); 1] = [(66352, 66378)];
+
+pub static SC_GRANTHA: [(u32, u32); 15] = [
+    (70400, 70403),
+    (70405, 70412),
+    (70415, 70416),
+    (70419, 70440),
+    (70442, 70448),
+    (70450, 70451),
+    (70453, 70457),
+    (70460, 70468),
+    (70471, 70472),
+    (70475, 70477),
+    (70480, 70480),
+    (70487, 70487),
+    (70493, 70499),
+    (70502, 70508),
+    (70512, 70516),
+];
+
+pub static SC_GREEK: [(u32, u32); 36] = [
+    (880, 883),
+    (885, 887),
+    (890, 893),
+    (895, 895),
+    (900, 900),
+    (902, 902),
+    (904, 906),
+    (908, 908),
+    (910, 929),
+    (931, 993),
+    (1008, 1023),
+    (7462, 7466),
+    (7517, 7521),
+    (7526, 7530),
+    (7615, 7615),
+    (7936, 7957),
+    (7960, 7965),
+    (7968, 8005),
+    (8008, 8013),
+    (8016, 8023),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8061),
+    (8064, 8116),
+    (8118, 8132),
+    (8134, 8147),
+    (8150, 8155),
+    (8157, 8175),
+    (8178, 8180),
+    (8182, 8190),
+    (8486, 8486),
+    (43877, 43877),
+    (65856, 65934),
+    (65952, 65952),
+    (119296, 119365),
+];
+
+pub static SC_GUJARATI: [(u32, u32); 14] = [
+    (2689, 2691),
+    (2693, 2701),
+    (2703, 2705),
+    (2707, 2728),
+    (2730, 2736),
+    (2738, 2739),
+    (2741, 2745),
+    (2748, 2757),
+    (2759, 2761),
+    (2763, 2765),
+    (2768, 2768),
+    (2784, 2787),
+    (2790, 2801),
+    (2809, 2815),
+];
+
+pub static SC_GUNJALA_GONDI: [(u32, u32); 6] = [
+    (73056, 73061),
+    (73063, 73064),
+    (73066, 73102),
+    (73104, 73105),
+    (73107, 73112),
+    (73120, 73129),
+];
+
+pub static SC_GURMUKHI: [(u32, u32); 16] = [
+    (2561, 2563),
+    (2565, 2570),
+    (2575, 2576),
+    (2579, 2600),
+    (2602, 2608),
+    (2610, 2611),
+    (2613, 2614),
+    (2616, 2617),
+    (2620, 2620),
+    (2622, 2626),
+    (2631, 2632),
+    (2635, 2637),
+    (2641, 2641),
+    (2649, 2652),
+    (2654, 2654),
+    (2662, 2678),
+];
+
+pub static SC_GURUNG_KHEMA: [(u32, u32); 1] = [(90368, 90425)];
+
+pub static SC_HAN: [(u32, u32); 22] = [
+    (11904, 11929),
+    (11931, 12019),
+    (12032, 12245),
+    (12293, 12293),
+    (12295, 12295),
+    (12321, 12329),
+    (12344, 12347),
+    (13312, 19903),
+    (19968, 40959),
+    (63744, 64109),
+    (64112, 64217),
+    (94178, 94179),
+    (94192, 94193),
+    (131072, 173791),
+    (173824, 177977),
+    (177984, 178205),
+    (178208, 183969),
+    (183984, 191456),
+    (191472, 192093),
+    (194560, 195101),
+    (196608, 201546),
+    (201552, 205743),
+];
+
+pub static SC_HANGUL: [(u32, u32); 14] = [
+    (4352, 4607),
+    (12334, 12335),
+    (12593, 12686),
+    (12800, 12830),
+    (12896, 12926),
+    (43360, 43388),
+    (44032, 55203),
+    (55216, 55238),
+    (55243, 55291),
+    (65440, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+];
+
+pub static SC_HANIFI_ROHINGYA: [(u32, u32); 2] = [(68864, 68903), (68912, 68921)];
+
+pub static SC_HANUNOO: [(u32, u32); 1] = [(5920, 5940)];
+
+pub static SC_HATRAN: [(u32, u32); 3] = [(67808, 67826), (67828, 67829), (67835, 67839)];
+
+pub static SC_HEBREW: [(u32, u32); 9] = [
+    (1425, 1479),
+    (1488, 1514),
+    (1519, 1524),
+    (64285, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64335),
+];
+
+pub static SC_HIRAGANA: [(u32, u32); 6] = [
+    (12353, 12438),
+    (12445, 12447),
+    (110593, 110879),
+    (110898, 110898),
+    (110928, 110930),
+    (127488, 127488),
+];
+
+pub static SC_IMPERIAL_ARAMAIC: [(u32, u32); 2] = [(67648, 67669), (67671, 67679)];
+
+pub static SC_INHERITED: [(u32, u32); 29] = [
+    (768, 879),
+    (1157, 1158),
+    (1611, 1621),
+    (1648, 1648),
+    (2385, 2388),
+    (6832, 6862),
+    (7376, 7378),
+    (7380, 7392),
+    (7394, 7400),
+    (7405, 7405),
+    (7412, 7412),
+    (7416, 7417),
+    (7616, 7679),
+    (8204, 8205),
+    (8400, 8432),
+    (12330, 12333),
+    (12441, 12442),
+    (65024, 65039),
+    (65056, 65069),
+    (66045, 66045),
+    (66272, 66272),
+    (70459, 70459),
+    (118528, 118573),
+    (118576, 118598),
+    (119143, 119145),
+    (119163, 119170),
+    (119173, 119179),
+    (119210, 119213),
+    (917760, 917999),
+];
+
+pub static SC_INSCRIPTIONAL_PAHLAVI: [(u32, u32); 2] = [(68448, 68466), (68472, 68479)];
+
+pub static SC_INSCRIPTIONAL_PARTHIAN: [(u32, u32); 2] = [(68416, 68437), (68440, 68447)];
+
+pub static SC_JAVANESE: [(u32, u32); 3] = [(43392, 43469), (43472, 43481), (43486, 43487)];
+
+pub static SC_KAITHI: [(u32, u32); 2] = [(69760, 69826), (69837, 69837)];
+
+pub static SC_KANNADA: [(u32, u32); 13] = [
+    (3200, 3212),
+    (3214, 3216),
+    (3218, 3240),
+    (3242, 3251),
+    (3253, 3257),
+    (3260, 3268),
+    (3270, 3272),
+    (3274, 3277),
+    (3285, 3286),
+    (3293, 3294),
+    (3296, 3299),
+    (3302, 3311),
+    (3313, 3315),
+];
+
+pub static SC_KATAKANA: [(u32, u32); 14] = [
+    (12449, 12538),
+    (12541, 12543),
+    (12784, 12799),
+    (13008, 13054),
+    (13056, 13143),
+    (65382, 65391),
+    (65393, 65437),
+    (110576, 110579),
+    (110581, 110587),
+    (110589, 110590),
+    (110592, 110592),
+    (110880, 110882),
+    (110933, 110933),
+    (110948, 110951),
+];
+
+pub static SC_KAWI: [(u32, u32); 3] = [(73472, 73488), (73490, 73530), (73534, 73562)];
+
+pub static SC_KAYAH_LI: [(u32, u32); 2] = [(43264, 43309), (43311, 43311)];
+
+pub static SC_KHAROSHTHI: [(u32, u32); 8] = [
+    (68096, 68099),
+    (68101, 68102),
+    (68108, 68115),
+    (68117, 68119),
+    (68121, 68149),
+    (68152, 68154),
+    (68159, 68168),
+    (68176, 68184),
+];
+
+pub static SC_KHITAN_SMALL_SCRIPT: [(u32, u32); 3] =
+    [(94180, 94180), (101120, 101589), (101631, 101631)];
+
+pub static SC_KHMER: [(u32, u32); 4] = [(6016, 6109), (6112, 6121), (6128, 6137), (6624, 6655)];
+
+pub static SC_KHOJKI: [(u32, u32); 2] = [(70144, 70161), (70163, 70209)];
+
+pub static SC_KHUDAWADI: [(u32, u32); 2] = [(70320, 70378), (70384, 70393)];
+
+pub static SC_KIRAT_RAI: [(u32, u32); 1] = [(93504, 93561)];
+
+pub static SC_LAO: [(u32, u32); 11] = [
+    (3713, 3714),
+    (3716, 3716),
+    (3718, 3722),
+    (3724, 3747),
+    (3749, 3749),
+    (3751, 3773),
+    (3776, 3780),
+    (3782, 3782),
+    (3784, 3790),
+    (3792, 3801),
+    (3804, 3807),
+];
+
+pub static SC_LATIN: [(u32, u32); 39] = [
+    (65, 90),
+    (97, 122),
+    (170, 170),
+    (186, 186),
+    (192, 214),
+    (216, 246),
+    (248, 696),
+    (736, 740),
+    (7424, 7461),
+    (7468, 7516),
+    (7522, 7525),
+    (7531, 7543),
+    (7545, 7614),
+    (7680, 7935),
+    (8305, 8305),
+    (8319, 8319),
+    (8336, 8348),
+    (8490, 8491),
+    (8498, 8498),
+    (8526, 8526),
+    (8544, 8584),
+    (11360, 11391),
+    (42786, 42887),
+    (42891, 42957),
+    (42960, 42961),
+    (42963, 42963),
+    (42965, 42972),
+    (42994, 43007),
+    (43824, 43866),
+    (43868, 43876),
+    (43878, 43881),
+    (64256, 64262),
+    (65313, 65338),
+    (65345, 65370),
+    (67456, 67461),
+    (67463, 67504),
+    (67506, 67514),
+    (122624, 122654),
+    (122661, 122666),
+];
+
+pub static SC_LEPCHA: [(u32, u32); 3] = [(7168, 7223), (7227, 7241), (7245, 7247)];
+
+pub static SC_LIMBU: [(u32, u32); 5] = [
+    (6400, 6430),
+    (6432, 6443),
+    (6448, 6459),
+    (6464, 6464),
+    (6468, 6479),
+];
+
+pub static SC_LINEAR_A: [(u32, u32); 3] = [(67072, 67382), (67392, 67413), (67424, 67431)];
+
+pub static SC_LINEAR_B: [(u32, u32); 7] = [
+    (65536, 65547),
+    (65549, 65574),
+    (65576, 65594),
+    (65596, 65597),
+    (65599, 65613),
+    (65616, 65629),
+    (65664, 65786),
+];
+
+pub static SC_LISU: [(u32, u32); 2] = [(42192, 42239), (73648, 73648)];
+
+pub static SC_LYCIAN: [(u32, u32); 1] = [(66176, 66204)];
+
+pub static SC_LYDIAN: [(u32, u32); 2] = [(67872, 67897), (67903, 67903)];
+
+pub static SC_MAHAJANI: [(u32, u32); 1] = [(69968, 70006)];
+
+pub static SC_MAKASAR: [(u32, u32); 1] = [(73440, 73464)];
+
+pub static SC_MALAYALAM: [(u32, u32); 7] = [
+    (3328, 3340),
+    (3342, 3344),
+    (3346, 3396),
+    (3398, 3400),
+    (3402, 3407),
+    (3412, 3427),
+    (3430, 3455),
+];
+
+pub static SC_MANDAIC: [(u32, u32); 2] = [(2112, 2139), (2142, 2142)];
+
+pub static SC_MANICHAEAN: [(u32, u32); 2] = [(68288, 68326), (68331, 68342)];
+
+pub static SC_MARCHEN: [(u32, u32); 3] = [(72816, 72847), (72850, 72871), (72873, 72886)];
+
+pub static SC_MASARAM_GONDI: [(u32, u32); 7] = [
+    (72960, 72966),
+    (72968, 72969),
+    (72971, 73014),
+    (73018, 73018),
+    (73020, 73021),
+    (73023, 73031),
+    (73040, 73049),
+];
+
+pub static SC_MEDEFAIDRIN: [(u32, u32); 1] = [(93760, 93850)];
+
+pub static SC_MEETEI_MAYEK: [(u32, u32); 3] = [(43744, 43766), (43968, 44013), (44016, 44025)];
+
+pub static SC_MENDE_KIKAKUI: [(u32, u32); 2] = [(124928, 125124), (125127, 125142)];
+
+pub static SC_MEROITIC_CURSIVE: [(u32, u32); 3] = [(68000, 68023), (68028, 68047), (68050, 68095)];
+
+pub static SC_MEROITIC_HIEROGLYPHS: [(u32, u32); 1] = [(67968, 67999)];
+
+pub static SC_MIAO: [(u32, u32); 3] = [(93952, 94026), (94031, 94087), (94095, 94111)];
+
+pub static SC_MODI: [(u32, u32); 2] = [(71168, 71236), (71248, 71257)];
+
+pub static SC_MONGOLIAN: [(u32, u32); 6] = [
+    (6144, 6145),
+    (6148, 6148),
+    (6150, 6169),
+    (6176, 6264),
+    (6272, 6314),
+    (71264, 71276),
+];
+
+pub static SC_MRO: [(u32, u32); 3] = [(92736, 92766), (92768, 92777), (92782, 92783)];
+
+pub static SC_MULTANI: [(u32, u32); 5] = [
+    (70272, 70278),
+    (70280, 70280),
+    (70282, 70285),
+    (70287, 70301),
+    (70303, 70313),
+];
+
+pub static SC_MYANMAR: [(u32, u32); 4] =
+    [(4096, 4255), (43488, 43518), (43616, 43647), (71376, 71395)];
+
+pub static SC_NABATAEAN: [(u32, u32); 2] = [(67712, 67742), (67751, 67759)];
+
+pub static SC_NAG_MUNDARI: [(u32, u32); 1] = [(124112, 124153)];
+
+pub static SC_NANDINAGARI: [(u32, u32); 3] = [(72096, 72103), (72106, 72151), (72154, 72164)];
+
+pub static SC_NEW_TAI_LUE: [(u32, u32); 4] =
+    [(6528, 6571), (6576, 6601), (6608, 6618), (6622, 6623)];
+
+pub static SC_NEWA: [(u32, u32); 2] = [(70656, 70747), (70749, 70753)];
+
+pub static SC_NKO: [(u32, u32); 2] = [(1984, 2042), (2045, 2047)];
+
+pub static SC_NUSHU: [(u32, u32); 2] = [(94177, 94177), (110960, 111355)];
+
+pub static SC_NYIAKENG_PUACHUE_HMONG: [(u32, u32); 4] = [
+    (123136, 123180),
+    (123184, 123197),
+    (123200, 123209),
+    (123214, 123215),
+];
+
+pub static SC_OGHAM: [(u32, u32); 1] = [(5760, 5788)];
+
+pub static SC_OL_CHIKI: [(u32, u32); 1] = [(7248, 7295)];
+
+pub static SC_OL_ONAL: [(u32, u32); 2] = [(124368, 124410), (124415, 124415)];
+
+pub static SC_OLD_HUNGARIAN: [(u32, u32); 3] = [(68736, 68786), (68800, 68850), (68858, 68863)];
+
+pub static SC_OLD_ITALIC: [(u32, u32); 2] = [(66304, 66339), (66349, 66351)];
+
+pub static SC_OLD_NORTH_ARABIAN: [(u32, u32); 1] = [(68224, 68255)];
+
+pub static SC_OLD_PERMIC: [(u32, u32); 1] = [(66384, 66426)];
+
+pub static SC_OLD_PERSIAN: [(u32, u32); 2] = [(66464, 66499), (66504, 66517)];
+
+pub static SC_OLD_SOGDIAN: [(u32, u32); 1] = [(69376, 69415)];
+
+pub static SC_OLD_SOUTH_ARABIAN: [(u32, u32); 1] = [(68192, 68223)];
+
+pub static SC_OLD_TURKIC: [(u32, u32); 1] = [(68608, 68680)];
+
+pub static SC_OLD_UYGHUR: [(u32, u32); 1] = [(69488, 69513)];
+
+pub static SC_ORIYA: [(u32, u32); 14] = [
+    (2817, 2819),
+    (2821, 2828),
+    (2831, 2832),
+    (2835, 2856),
+    (2858, 2864),
+    (2866, 2867),
+    (2869, 2873),
+    (2876, 2884),
+    (2887, 2888),
+    (2891, 2893),
+    (2901, 2903),
+    (2908, 2909),
+    (2911, 2915),
+    (2918, 2935),
+];
+
+pub static SC_OSAGE: [(u32, u32); 2] = [(66736, 66771), (66776, 66811)];
+
+pub static SC_OSMANYA: [(u32, u32); 2] = [(66688, 66717), (66720, 66729)];
+
+pub static SC_PAHAWH_HMONG: [(u32, u32); 5] = [
+    (92928, 92997),
+    (93008, 93017),
+    (93019, 93025),
+    (93027, 93047),
+    (93053, 93071),
+];
+
+pub static SC_PALMYRENE: [(u32, u32); 1] = [(67680, 67711)];
+
+pub static SC_PAU_CIN_HAU: [(u32, u32); 1] = [(72384, 72440)];
+
+pub static SC_PHAGS_PA: [(u32, u32); 1] = [(43072, 43127)];
+
+pub static SC_PHOENICIAN: [(u32, u32); 2] = [(67840, 67867), (67871, 67871)];
+
+pub static SC_PSALTER_PAHLAVI: [(u32, u32); 3] = [(68480, 68497), (68505, 68508), (68521, 68527)];
+
+pub static SC_REJANG: [(u32, u32); 2] = [(43312, 43347), (43359, 43359)];
+
+pub static SC_RUNIC: [(u32, u32); 2] = [(5792, 5866), (5870, 5880)];
+
+pub static SC_SAMARITAN: [(u32, u32); 2] = [(2048, 2093), (2096, 2110)];
+
+pub static SC_SAURASHTRA: [(u32, u32); 2] = [(43136, 43205), (43214, 43225)];
+
+pub static SC_SHARADA: [(u32, u32); 1] = [(70016, 70111)];
+
+pub static SC_SHAVIAN: [(u32, u32); 1] = [(66640, 66687)];
+
+pub static SC_SIDDHAM: [(u32, u32); 2] = [(71040, 71093), (71096, 71133)];
+
+pub static SC_SIGNWRITING: [(u32, u32); 3] = [(120832, 121483), (121499, 121503), (121505, 121519)];
+
+pub static SC_SINHALA: [(u32, u32); 13] = [
+    (3457, 3459),
+    (3461, 3478),
+    (3482, 3505),
+    (3507, 3515),
+    (3517, 3517),
+    (3520, 3526),
+    (3530, 3530),
+    (3535, 3540),
+    (3542, 3542),
+    (3544, 3551),
+    (3558, 3567),
+    (3570, 3572),
+    (70113, 70132),
+];
+
+pub static SC_SOGDIAN: [(u32, u32); 1] = [(69424, 69465)];
+
+pub static SC_SORA_SOMPENG: [(u32, u32); 2] = [(69840, 69864), (69872, 69881)];
+
+pub static SC_SOYOMBO: [(u32, u32); 1] = [(72272, 72354)];
+
+pub static SC_SUNDANESE: [(u32, u32); 2] = [(7040, 7103), (7360, 7367)];
+
+pub static SC_SUNUWAR: [(u32, u32); 2] = [(72640, 72673), (72688, 72697)];
+
+pub static SC_SYLOTI_NAGRI: [(u32, u32); 1] = [(43008, 43052)];
+
+pub static SC_SYRIAC: [(u32, u32); 4] = [(1792, 1805), (1807, 1866), (1869, 1871), (2144, 2154)];
+
+pub static SC_TAGALOG: [(u32, u32); 2] = [(5888, 5909), (5919, 5919)];
+
+pub static SC_TAGBANWA: [(u32, u32); 3] = [(5984, 5996), (5998, 6000), (6002, 6003)];
+
+pub static SC_TAI_LE: [(u32, u32); 2] = [(6480, 6509), (6512, 6516)];
+
+pub static SC_TAI_THAM: [(u32, u32); 5] = [
+    (6688, 6750),
+    (6752, 6780),
+    (6783, 6793),
+    (6800, 6809),
+    (6816, 6829),
+];
+
+pub static SC_TAI_VIET: [(u32, u32); 2] = [(43648, 43714), (43739, 43743)];
+
+pub static SC_TAKRI: [(u32, u32); 2] = [(71296, 71353), (71360, 71369)];
+
+pub static SC_TAMIL: [(u32, u32); 18] = [
+    (2946, 2947),
+    (2949, 2954),
+    (2958, 2960),
+    (2962, 2965),
+    (2969, 2970),
+    (2972, 2972),
+    (2974, 2975),
+    (2979, 2980),
+    (2984, 2986),
+    (2990, 3001),
+    (3006, 3010),
+    (3014, 3016),
+    (3018, 3021),
+    (3024, 3024),
+    (3031, 3031),
+    (3046, 3066),
+    (73664, 73713),
+    (73727, 73727),
+];
+
+pub static SC_TANGSA: [(u32, u32); 2] = [(92784, 92862), (92864, 92873)];
+
+pub static SC_TANGUT: [(u32, u32); 4] = [
+    (94176, 94176),
+    (94208, 100343),
+    (100352, 101119),
+    (101632, 101640),
+];
+
+pub static SC_TELUGU: [(u32, u32); 13] = [
+    (3072, 3084),
+    (3086, 3088),
+    (3090, 3112),
+    (3114, 3129),
+    (3132, 3140),
+    (3142, 3144),
+    (3146, 3149),
+    (3157, 3158),
+    (3160, 3162),
+    (3165, 3165),
+    (3168, 3171),
+    (3174, 3183),
+    (3191, 3199),
+];
+
+pub static SC_THAANA: [(u32, u32); 1] = [(1920, 1969)];
+
+pub static SC_THAI: [(u32, u32); 2] = [(3585, 3642), (3648, 3675)];
+
+pub static SC_TIBETAN: [(u32, u32); 7] = [
+    (3840, 3911),
+    (3913, 3948),
+    (3953, 3991),
+    (3993, 4028),
+    (4030, 4044),
+    (4046, 4052),
+    (4057, 4058),
+];
+
+pub static SC_TIFINAGH: [(u32, u32); 3] = [(11568, 11623), (11631, 11632), (11647, 11647)];
+
+pub static SC_TIRHUTA: [(u32, u32); 2] = [(70784, 70855), (70864, 70873)];
+
+pub static SC_TODHRI: [(u32, u32); 1] = [(67008, 67059)];
+
+pub static SC_TOTO: [(u32, u32); 1] = [(123536, 123566)];
+
+pub static SC_TULU_TIGALARI: [(u32, u32); 11] = [
+    (70528, 70537),
+    (70539, 70539),
+    (70542, 70542),
+    (70544, 70581),
+    (70583, 70592),
+    (70594, 70594),
+    (70597, 70597),
+    (70599, 70602),
+    (70604, 70613),
+    (70615, 70616),
+    (70625, 70626),
+];
+
+pub static SC_UGARITIC: [(u32, u32); 2] = [(66432, 66461), (66463, 66463)];
+
+pub static SC_VAI: [(u32, u32); 1] = [(42240, 42539)];
+
+pub static SC_VITHKUQI: [(u32, u32); 8] = [
+    (66928, 66938),
+    (66940, 66954),
+    (66956, 66962),
+    (66964, 66965),
+    (66967, 66977),
+    (66979, 66993),
+    (66995, 67001),
+    (67003, 67004),
+];
+
+pub static SC_WANCHO: [(u32, u32); 2] = [(123584, 123641), (123647, 123647)];
+
+pub static SC_WARANG_CITI: [(u32, u32); 2] = [(71840, 71922), (71935, 71935)];
+
+pub static SC_YEZIDI: [(u32, u32); 3] = [(69248, 69289), (69291, 69293), (69296, 69297)];
+
+pub static SC_YI: [(u32, u32); 2] = [(40960, 42124), (42128, 42182)];
+
+pub static SC_ZANABAZAR_SQUARE: [(u32, u32); 1] = [(72192, 72263)];
+
+pub static SCRIPTS: [(&str, &[(u32, u32)]); 170] = [
+    ("Adlam", &SC_ADLAM),
+    ("Ahom", &SC_AHOM),
+    ("Anatolian_Hieroglyphs", &SC_ANATOLIAN_HIEROGLYPHS),
+    ("Arabic", &SC_ARABIC),
+    ("Armenian", &SC_ARMENIAN),
+    ("Avestan", &SC_AVESTAN),
+    ("Balinese", &SC_BALINESE),
+    ("Bamum", &SC_BAMUM),
+    ("Bassa_Vah", &SC_BASSA_VAH),
+    ("Batak", &SC_BATAK),
+    ("Bengali", &SC_BENGALI),
+    ("Bhaiksuki", &SC_BHAIKSUKI),
+    ("Bopomofo", &SC_BOPOMOFO),
+    ("Brahmi", &SC_BRAHMI),
+    ("Braille", &SC_BRAILLE),
+    ("Buginese", &SC_BUGINESE),
+    ("Buhid", &SC_BUHID),
+    ("Canadian_Aboriginal", &SC_CANADIAN_ABORIGINAL),
+    ("Carian", &SC_CARIAN),
+    ("Caucasian_Albanian", &SC_CAUCASIAN_ALBANIAN),
+    ("Chakma", &SC_CHAKMA),
+    ("Cham", &SC_CHAM),
+    ("Cherokee", &SC_CHEROKEE),
+    ("Chorasmian", &SC_CHORASMIAN),
+    ("Common", &SC_COMMON),
+    ("Coptic", &SC_COPTIC),
+    ("Cuneiform", &SC_CUNEIFORM),
+    ("Cypriot", &SC_CYPRIOT),
+    ("Cypro_Minoan", &SC_CYPRO_MINOAN),
+    ("Cyrillic", &SC_CYRILLIC),
+    ("Deseret", &SC_DESERET),
+    ("Devanagari", &SC_DEVANAGARI),
+    ("Dives_Akuru", &SC_DIVES_AKURU),
+    ("Dogra", &SC_DOGRA),
+    ("Duployan", &SC_DUPLOYAN),
+    ("Egyptian_Hieroglyphs", &SC_EGYPTIAN_HIEROGLYPHS),
+    ("Elbasan", &SC_ELBASAN),
+    ("Elymaic", &SC_ELYMAIC),
+    ("Ethiopic", &SC_ETHIOPIC),
+    ("Garay", &SC_GARAY),
+    ("Georgian", &SC_GEORGIAN),
+    ("Glagolitic", &SC_GLAGOLITIC),
+    ("Gothic", &SC_GOTHIC),
+    ("Grantha", &SC_GRANTHA),
+    ("Greek", &SC_GREEK),
+    ("Gujarati", &SC_GUJARATI),
+    ("Gunjala_Gondi", &SC_GUNJALA_GONDI),
+    ("Gurmukhi", &SC_GURMUKHI),
+    ("Gurung_Khema", &SC_GURUNG_KHEMA),
+    ("Han", &SC_HAN),
+    ("Hangul", &SC_HANGUL),
+    ("Hanifi_Rohingya", &SC_HANIFI_ROHINGYA),
+    ("Hanunoo", &SC_HANUNOO),
+    ("Hatran", &SC_HATRAN),
+    ("Hebrew", &SC_HEBREW),
+    ("Hiragana", &SC_HIRAGANA),
+    ("Imperial_Aramaic", &SC_IMPERIAL_ARAMAIC),
+    ("Inherited", &SC_INHERITED),
+    ("Inscriptional_Pahlavi", &SC_INSCRIPTIONAL_PAHLAVI),
+    ("Inscriptional_Parthian", &SC_INSCRIPTIONAL_PARTHIAN),
+    ("Javanese", &SC_JAVANESE),
+    ("Kaithi", &SC_KAITHI),
+    ("Kannada", &SC_KANNADA),
+    ("Katakana", &SC_KATAKANA),
+    ("Kawi", &SC_KAWI),
+    ("Kayah_Li", &SC_KAYAH_LI),
+    ("Kharoshthi", &SC_KHAROSHTHI),
+    ("Khitan_Small_Script", &SC_KHITAN_SMALL_SCRIPT),
+    ("Khmer", &SC_KHMER),
+    ("Khojki", &SC_KHOJKI),
+    ("Khudawadi", &SC_KHUDAWADI),
+    ("Kirat_Rai", &SC_KIRAT_RAI),
+    ("Lao", &SC_LAO),
+    ("Latin", &SC_LATIN),
+    ("Lepcha", &SC_LEPCHA),
+    ("Limbu", &SC_LIMBU),
+    ("Linear_A", &SC_LINEAR_A),
+    ("Linear_B", &SC_LINEAR_B),
+    ("Lisu", &SC_LISU),
+    ("Lycian", &SC_LYCIAN),
+    ("Lydian", &SC_LYDIAN),
+    ("Mahajani", &SC_MAHAJANI),
+    ("Makasar", &SC_MAKASAR),
+    ("Malayalam", &SC_MALAYALAM),
+    ("Mandaic", &SC_MANDAIC),
+    ("Manichaean", &SC_MANICHAEAN),
+    ("Marchen", &SC_MARCHEN),
+    ("Masaram_Gondi", &SC_MASARAM_GONDI),
+    ("Medefaidrin", &SC_MEDEFAIDRIN),
+    ("Meetei_Mayek", &SC_MEETEI_MAYEK),
+    ("Mende_Kikakui", &SC_MENDE_KIKAKUI),
+    ("Meroitic_Cursive", &SC_MEROITIC_CURSIVE),
+    ("Meroitic_Hieroglyphs", &SC_MEROITIC_HIEROGLYPHS),
+    ("Miao", &SC_MIAO),
+    ("Modi", &SC_MODI),
+    ("Mongolian", &SC_MONGOLIAN),
+    ("Mro", &SC_MRO),
+    ("Multani", &SC_MULTANI),
+    ("Myanmar", &SC_MYANMAR),
+    ("Nabataean", &SC_NABATAEAN),
+    ("Nag_Mundari", &SC_NAG_MUNDARI),
+    ("Nandinagari", &SC_NANDINAGARI),
+    ("New_Tai_Lue", &SC_NEW_TAI_LUE),
+    ("Newa", &SC_NEWA),
+    ("Nko", &SC_NKO),
+    ("Nushu", &SC_NUSHU),
+    ("Nyiakeng_Puachue_Hmong", &SC_NYIAKENG_PUACHUE_HMONG),
+    ("Ogham", &SC_OGHAM),
+    ("Ol_Chiki", &SC_OL_CHIKI),
+    ("Ol_Onal", &SC_OL_ONAL),
+    ("Old_Hungarian", &SC_OLD_HUNGARIAN),
+    ("Old_Italic", &SC_OLD_ITALIC),
+    ("Old_North_Arabian", &SC_OLD_NORTH_ARABIAN),
+    ("Old_Permic", &SC_OLD_PERMIC),
+    ("Old_Persian", &SC_OLD_PERSIAN),
+    ("Old_Sogdian", &SC_OLD_SOGDIAN),
+    ("Old_South_Arabian", &SC_OLD_SOUTH_ARABIAN),
+    ("Old_Turkic", &SC_OLD_TURKIC),
+    ("Old_Uyghur", &SC_OLD_UYGHUR),
+    ("Oriya", &SC_ORIYA),
+    ("Osage", &SC_OSAGE),
+    ("Osmanya", &SC_OSMANYA),
+    ("Pahawh_Hmong", &SC_PAHAWH_HMONG),
+    ("Palmyrene", &SC_PALMYRENE),
+    ("Pau_Cin_Hau", &SC_PAU_CIN_HAU),
+    ("Phags_Pa", &SC_PHAGS_PA),
+    ("Phoenician", &SC_PHOENICIAN),
+    ("Psalter_Pahlavi", &SC_PSALTER_PAHLAVI),
+    ("Rejang", &SC_REJANG),
+    ("Runic", &SC_RUNIC),
+    ("Samaritan", &SC_SAMARITAN),
+    ("Saurashtra", &SC_SAURASHTRA),
+    ("Sharada", &SC_SHARADA),
+    ("Shavian", &SC_SHAVIAN),
+    ("Siddham", &SC_SIDDHAM),
+    ("SignWriting", &SC_SIGNWRITING),
+    ("Sinhala", &SC_SINHALA),
+    ("Sogdian", &SC_SOGDIAN),
+    ("Sora_Sompeng", &SC_SORA_SOMPENG),
+    ("Soyombo", &SC_SOYOMBO),
+    ("Sundanese", &SC_SUNDANESE),
+    ("Sunuwar", &SC_SUNUWAR),
+    ("Syloti_Nagri", &SC_SYLOTI_NAGRI),
+    ("Syriac", &SC_SYRIAC),
+    ("Tagalog", &SC_TAGALOG),
+    ("Tagbanwa", &SC_TAGBANWA),
+    ("Tai_Le", &SC_TAI_LE),
+    ("Tai_Tham", &SC_TAI_THAM),
+    ("Tai_Viet", &SC_TAI_VIET),
+    ("Takri", &SC_TAKRI),
+    ("Tamil", &SC_TAMIL),
+    ("Tangsa", &SC_TANGSA),
+    ("Tangut", &SC_TANGUT),
+    ("Telugu", &SC_TELUGU),
+    ("Thaana", &SC_THAANA),
+    ("Thai", &SC_THAI),
+    ("Tibetan", &SC_TIBETAN),
+    ("Tifinagh", &SC_TIFINAGH),
+    ("Tirhuta", &SC_TIRHUTA),
+    ("Todhri", &SC_TODHRI),
+    ("Toto", &SC_TOTO),
+    ("Tulu_Tigalari", &SC_TULU_TIGALARI),
+    ("Ugaritic", &SC_UGARITIC),
+    ("Vai", &SC_VAI),
+    ("Vithkuqi", &SC_VITHKUQI),
+    ("Wancho", &SC_WANCHO),
+    ("Warang_Citi", &SC_WARANG_CITI),
+    ("Yezidi", &SC_YEZIDI),
+    ("Yi", &SC_YI),
+    ("Zanabazar_Square", &SC_ZANABAZAR_SQUARE),
+];
+
+// Blocks are from the Unicode Character Database 14.0.0
+
+pub static BLK_ADLAM: [(u32, u32); 1] = [(125184, 125279)];
+
+pub static BLK_AEGEAN_NUMBERS: [(u32, u32); 1] = [(65792, 65855)];
+
+pub static BLK_AHOM: [(u32, u32); 1] = [(71424, 71503)];
+
+pub static BLK_ALCHEMICAL_SYMBOLS: [(u32, u32); 1] = [(128768, 128895)];
+
+pub static BLK_ALPHABETIC_PRESENTATION_FORMS: [(u32, u32); 1] = [(64256, 64335)];
+
+pub static BLK_ANATOLIAN_HIEROGLYPHS: [(u32, u32); 1] = [(82944, 83583)];
+
+pub static BLK_ANCIENT_GREEK_MUSICAL_NOTATION: [(u32, u32); 1] = [(119296, 119375)];
+
+pub static BLK_ANCIENT_GREEK_NUMBERS: [(u32, u32); 1] = [(65856, 65935)];
+
+pub static BLK_ANCIENT_SYMBOLS: [(u32, u32); 1] = [(65936, 65999)];
+
+pub static BLK_ARABIC: [(u32, u32); 1] = [(1536, 1791)];
+
+pub static BLK_ARABIC_EXTENDED_A: [(u32, u32); 1] = [(2208, 2303)];
+
+pub static BLK_ARABIC_EXTENDED_B: [(u32, u32); 1] = [(2160, 2207)];
+
+pub static BLK_ARABIC_MATHEMATICAL_ALPHABETIC_SYMBOLS: [(u32, u32); 1] = [(126464, 126719)];
+
+pub static BLK_ARABIC_PRESENTATION_FORMS_A: [(u32, u32); 1] = [(64336, 65023)];
+
+pub static BLK_ARABIC_PRESENTATION_FORMS_B: [(u32, u32); 1] = [(65136, 65279)];
+
+pub static BLK_ARABIC_SUPPLEMENT: [(u32, u32); 1] = [(1872, 1919)];
+
+pub static BLK_ARMENIAN: [(u32, u32); 1] = [(1328, 1423)];
+
+pub static BLK_ARROWS: [(u32, u32); 1] = [(8592, 8703)];
+
+pub static BLK_AVESTAN: [(u32, u32); 1] = [(68352, 68415)];
+
+pub static BLK_BALINESE: [(u32, u32); 1] = [(6912, 7039)];
+
+pub static BLK_BAMUM: [(u32, u32); 1] = [(42656, 42751)];
+
+pub static BLK_BAMUM_SUPPLEMENT: [(u32, u32); 1] = [(92160, 92735)];
+
+pub static BLK_BASIC_LATIN: [(u32, u32); 1] = [(0, 127)];
+
+pub static BLK_BASSA_VAH: [(u32, u32); 1] = [(92880, 92927)];
+
+pub static BLK_BATAK: [(u32, u32); 1] = [(7104, 7167)];
+
+pub static BLK_BENGALI: [(u32, u32); 1] = [(2432, 2559)];
+
+pub static BLK_BHAIKSUKI: [(u32, u32); 1] = [(72704, 72815)];
+
+pub static BLK_BLOCK_ELEMENTS: [(u32, u32); 1] = [(9600, 9631)];
+
+pub static BLK_BOPOMOFO: [(u32, u32); 1] = [(12544, 12591)];
+
+pub static BLK_BOPOMOFO_EXTENDED: [(u32, u32); 1] = [(12704, 12735)];
+
+pub static BLK_BOX_DRAWING: [(u32, u32); 1] = [(9472, 9599)];
+
+pub static BLK_BRAHMI: [(u32, u32); 1] = [(69632, 69759)];
+
+pub static BLK_BRAILLE_PATTERNS: [(u32, u32); 1] = [(10240, 10495)];
+
+pub static BLK_BUGINESE: [(u32, u32); 1] = [(6656, 6687)];
+
+pub static BLK_BUHID: [(u32, u32); 1] = [(5952, 5983)];
+
+pub static BLK_BYZANTINE_MUSICAL_SYMBOLS: [(u32, u32); 1] = [(118784, 119039)];
+
+pub static BLK_CJK_COMPATIBILITY: [(u32, u32); 1] = [(13056, 13311)];
+
+pub static BLK_CJK_COMPATIBILITY_FORMS: [(u32, u32); 1] = [(65072, 65103)];
+
+pub static BLK_CJK_COMPATIBILITY_IDEOGRAPHS: [(u32, u32); 1] = [(63744, 64255)];
+
+pub static BLK_CJK_COMPATIBILITY_IDEOGRAPHS_SUPPLEMENT: [(u32, u32); 1] = [(194560, 195103)];
+
+pub static BLK_CJK_RADICALS_SUPPLEMENT: [(u32, u32); 1] = [(11904, 12031)];
+
+pub static BLK_CJK_STROKES: [(u32, u32); 1] = [(12736, 12783)];
+
+pub static BLK_CJK_SYMBOLS_AND_PUNCTUATION: [(u32, u32); 1] = [(12288, 12351)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS: [(u32, u32); 1] = [(19968, 40959)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_A: [(u32, u32); 1] = [(13312, 19903)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_B: [(u32, u32); 1] = [(131072, 173791)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_C: [(u32, u32); 1] = [(173824, 177983)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_D: [(u32, u32); 1] = [(177984, 178207)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_E: [(u32, u32); 1] = [(178208, 183983)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_F: [(u32, u32); 1] = [(183984, 191471)];
+
+pub static BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_G: [(u32, u32); 1] = [(196608, 201551)];
+
+pub static BLK_CARIAN: [(u32, u32); 1] = [(66208, 66271)];
+
+pub static BLK_CAUCASIAN_ALBANIAN: [(u32, u32); 1] = [(66864, 66927)];
+
+pub static BLK_CHAKMA: [(u32, u32); 1] = [(69888, 69967)];
+
+pub static BLK_CHAM: [(u32, u32); 1] = [(43520, 43615)];
+
+pub static BLK_CHEROKEE: [(u32, u32); 1] = [(5024, 5119)];
+
+pub static BLK_CHEROKEE_SUPPLEMENT: [(u32, u32); 1] = [(43888, 43967)];
+
+pub static BLK_CHESS_SYMBOLS: [(u32, u32); 1] = [(129536, 129647)];
+
+pub static BLK_CHORASMIAN: [(u32, u32); 1] = [(69552, 69599)];
+
+pub static BLK_COMBINING_DIACRITICAL_MARKS: [(u32, u32); 1] = [(768, 879)];
+
+pub static BLK_COMBINING_DIACRITICAL_MARKS_EXTENDED: [(u32, u32); 1] = [(6832, 6911)];
+
+pub static BLK_COMBINING_DIACRITICAL_MARKS_SUPPLEMENT: [(u32, u32); 1] = [(7616, 7679)];
+
+pub static BLK_COMBINING_DIACRITICAL_MARKS_FOR_SYMBOLS: [(u32, u32); 1] = [(8400, 8447)];
+
+pub static BLK_COMBINING_HALF_MARKS: [(u32, u32); 1] = [(65056, 65071)];
+
+pub static BLK_COMMON_INDIC_NUMBER_FORMS: [(u32, u32); 1] = [(43056, 43071)];
+
+pub static BLK_CONTROL_PICTURES: [(u32, u32); 1] = [(9216, 9279)];
+
+pub static BLK_COPTIC: [(u32, u32); 1] = [(11392, 11519)];
+
+pub static BLK_COPTIC_EPACT_NUMBERS: [(u32, u32); 1] = [(66272, 66303)];
+
+pub static BLK_COUNTING_ROD_NUMERALS: [(u32, u32); 1] = [(119648, 119679)];
+
+pub static BLK_CUNEIFORM: [(u32, u32); 1] = [(73728, 74751)];
+
+pub static BLK_CUNEIFORM_NUMBERS_AND_PUNCTUATION: [(u32, u32); 1] = [(74752, 74879)];
+
+pub static BLK_CURRENCY_SYMBOLS: [(u32, u32); 1] = [(8352, 8399)];
+
+pub static BLK_CYPRIOT_SYLLABARY: [(u32, u32); 1] = [(67584, 67647)];
+
+pub static BLK_CYPRO_MINOAN: [(u32, u32); 1] = [(77712, 77823)];
+
+pub static BLK_CYRILLIC: [(u32, u32); 1] = [(1024, 1279)];
+
+pub static BLK_CYRILLIC_EXTENDED_A: [(u32, u32); 1] = [(11744, 11775)];
+
+pub static BLK_CYRILLIC_EXTENDED_B: [(u32, u32); 1] = [(42560, 42655)];
+
+pub static BLK_CYRILLIC_EXTENDED_C: [(u32, u32); 1] = [(7296, 7311)];
+
+pub static BLK_CYRILLIC_SUPPLEMENT: [(u32, u32); 1] = [(1280, 1327)];
+
+pub static BLK_DESERET: [(u32, u32); 1] = [(66560, 66639)];
+
+pub static BLK_DEVANAGARI: [(u32, u32); 1] = [(2304, 2431)];
+
+pub static BLK_DEVANAGARI_EXTENDED: [(u32, u32); 1] = [(43232, 43263)];
+
+pub static BLK_DINGBATS: [(u32, u32); 1] = [(9984, 10175)];
+
+pub static BLK_DIVES_AKURU: [(u32, u32); 1] = [(71936, 72031)];
+
+pub static BLK_DOGRA: [(u32, u32); 1] = [(71680, 71759)];
+
+pub static BLK_DOMINO_TILES: [(u32, u32); 1] = [(127024, 127135)];
+
+pub static BLK_DUPLOYAN: [(u32, u32); 1] = [(113664, 113823)];
+
+pub static BLK_EARLY_DYNASTIC_CUNEIFORM: [(u32, u32); 1] = [(74880, 75087)];
+
+pub static BLK_EGYPTIAN_HIEROGLYPH_FORMAT_CONTROLS: [(u32, u32); 1] = [(78896, 78911)];
+
+pub static BLK_EGYPTIAN_HIEROGLYPHS: [(u32, u32); 1] = [(77824, 78895)];
+
+pub static BLK_ELBASAN: [(u32, u32); 1] = [(66816, 66863)];
+
+pub static BLK_ELYMAIC: [(u32, u32); 1] = [(69600, 69631)];
+
+pub static BLK_EMOTICONS: [(u32, u32); 1] = [(128512, 128591)];
+
+pub static BLK_ENCLOSED_ALPHANUMERIC_SUPPLEMENT: [(u32, u32); 1] = [(127232, 127487)];
+
+pub static BLK_ENCLOSED_ALPHANUMERICS: [(u32, u32); 1] = [(9312, 9471)];
+
+pub static BLK_ENCLOSED_CJK_LETTERS_AND_MONTHS: [(u32, u32); 1] = [(12800, 13055)];
+
+pub static BLK_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT: [(u32, u32); 1] = [(127488, 127743)];
+
+pub static BLK_ETHIOPIC: [(u32, u32); 1] = [(4608, 4991)];
+
+pub static BLK_ETHIOPIC_EXTENDED: [(u32, u32); 1] = [(11648, 11743)];
+
+pub static BLK_ETHIOPIC_EXTENDED_A: [(u32, u32); 1] = [(43776, 43823)];
+
+pub static BLK_ETHIOPIC_EXTENDED_B: [(u32, u32); 1] = [(124896, 124927)];
+
+pub static BLK_ETHIOPIC_SUPPLEMENT: [(u32, u32); 1] = [(4992, 5023)];
+
+pub static BLK_GENERAL_PUNCTUATION: [(u32, u32); 1] = [(8192, 8303)];
+
+pub static BLK_GEOMETRIC_SHAPES: [(u32, u32); 1] = [(9632, 9727)];
+
+pub static BLK_GEOMETRIC_SHAPES_EXTENDED: [(u32, u32); 1] = [(128896, 129023)];
+
+pub static BLK_GEORGIAN: [(u32, u32); 1] = [(4256, 4351)];
+
+pub static BLK_GEORGIAN_EXTENDED: [(u32, u32); 1] = [(7312, 7359)];
+
+pub static BLK_GEORGIAN_SUPPLEMENT: [(u32, u32); 1] = [(11520, 11567)];
+
+pub static BLK_GLAGOLITIC: [(u32, u32); 1] = [(11264, 11359)];
+
+pub static BLK_GLAGOLITIC_SUPPLEMENT: [(u32, u32); 1] = [(122880, 122927)];
+
+pub static BLK_GOTHIC: [(u32, u32); 1] = [(66352, 66383)];
+
+pub static BLK_GRANTHA: [(u32, u32); 1] = [(70400, 70527)];
+
+pub static BLK_GREEK_EXTENDED: [(u32, u32); 1] = [(7936, 8191)];
+
+pub static BLK_GREEK_AND_COPTIC: [(u32, u32); 1] = [(880, 1023)];
+
+pub static BLK_GUJARATI: [(u32, u32); 1] = [(2688, 2815)];
+
+pub static BLK_GUNJALA_GONDI: [(u32, u32); 1] = [(73056, 73135)];
+
+pub static BLK_GURMUKHI: [(u32, u32); 1] = [(2560, 2687)];
+
+pub static BLK_HALFWIDTH_AND_FULLWIDTH_FORMS: [(u32, u32); 1] = [(65280, 65519)];
+
+pub static BLK_HANGUL_COMPATIBILITY_JAMO: [(u32, u32); 1] = [(12592, 12687)];
+
+pub static BLK_HANGUL_JAMO: [(u32, u32); 1] = [(4352, 4607)];
+
+pub static BLK_HANGUL_JAMO_EXTENDED_A: [(u32, u32); 1] = [(43360, 43391)];
+
+pub static BLK_HANGUL_JAMO_EXTENDED_B: [(u32, u32); 1] = [(55216, 55295)];
+
+pub static BLK_HANGUL_SYLLABLES: [(u32, u32); 1] = [(44032, 55215)];
+
+pub static BLK_HANIFI_ROHINGYA: [(u32, u32); 1] = [(68864, 68927)];
+
+pub static BLK_HANUNOO: [(u32, u32); 1] = [(5920, 5951)];
+
+pub static BLK_HATRAN: [(u32, u32); 1] = [(67808, 67839)];
+
+pub static BLK_HEBREW: [(u32, u32); 1] = [(1424, 1535)];
+
+pub static BLK_HIRAGANA: [(u32, u32); 1] = [(12352, 12447)];
+
+pub static BLK_IPA_EXTENSIONS: [(u32, u32); 1] = [(592, 687)];
+
+pub static BLK_IDEOGRAPHIC_DESCRIPTION_CHARACTERS: [(u32, u32); 1] = [(12272, 12287)];
+
+pub static BLK_IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION: [(u32, u32); 1] = [(94176, 94207)];
+
+pub static BLK_IMPERIAL_ARAMAIC: [(u32, u32); 1] = [(67648, 67679)];
+
+pub static BLK_INDIC_SIYAQ_NUMBERS: [(u32, u32); 1] = [(126064, 126143)];
+
+pub static BLK_INSCRIPTIONAL_PAHLAVI: [(u32, u32); 1] = [(68448, 68479)];
+
+pub static BLK_INSCRIPTIONAL_PARTHIAN: [(u32, u32); 1] = [(68416, 68447)];
+
+pub static BLK_JAVANESE: [(u32, u32); 1] = [(43392, 43487)];
+
+pub static BLK_KAITHI: [(u32, u32); 1] = [(69760, 69839)];
+
+pub static BLK_KANA_EXTENDED_A: [(u32, u32); 1] = [(110848, 110895)];
+
+pub static BLK_KANA_EXTENDED_B: [(u32, u32); 1] = [(110576, 110591)];
+
+pub static BLK_KANA_SUPPLEMENT: [(u32, u32); 1] = [(110592, 110847)];
+
+pub static BLK_KANBUN: [(u32, u32); 1] = [(12688, 12703)];
+
+pub static BLK_KANGXI_RADICALS: [(u32, u32); 1] = [(12032, 12255)];
+
+pub static BLK_KANNADA: [(u32, u32); 1] = [(3200, 3327)];
+
+pub static BLK_KATAKANA: [(u32, u32); 1] = [(12448, 12543)];
+
+pub static BLK_KATAKANA_PHONETIC_EXTENSIONS: [(u32, u32); 1] = [(12784, 12799)];
+
+pub static BLK_KAYAH_LI: [(u32, u32); 1] = [(43264, 43311)];
+
+pub static BLK_KHAROSHTHI: [(u32, u32); 1] = [(68096, 68191)];
+
+pub static BLK_KHITAN_SMALL_SCRIPT: [(u32, u32); 1] = [(101120, 101631)];
+
+pub static BLK_KHMER: [(u32, u32); 1] = [(6016, 6143)];
+
+pub static BLK_KHMER_SYMBOLS: [(u32, u32); 1] = [(6624, 6655)];
+
+pub static BLK_KHOJKI: [(u32, u32); 1] = [(70144, 70223)];
+
+pub static BLK_KHUDAWADI: [(u32, u32); 1] = [(70320, 70399)];
+
+pub static BLK_LAO: [(u32, u32); 1] = [(3712, 3839)];
+
+pub static BLK_LATIN_EXTENDED_ADDITIONAL: [(u32, u32); 1] = [(7680, 7935)];
+
+pub static BLK_LATIN_EXTENDED_A: [(u32, u32); 1] = [(256, 383)];
+
+pub static BLK_LATIN_EXTENDED_B: [(u32, u32); 1] = [(384, 591)];
+
+pub static BLK_LATIN_EXTENDED_C: [(u32, u32); 1] = [(11360, 11391)];
+
+pub static BLK_LATIN_EXTENDED_D: [(u32, u32); 1] = [(42784, 43007)];
+
+pub static BLK_LATIN_EXTENDED_E: [(u32, u32); 1] = [(43824, 43887)];
+
+pub static BLK_LATIN_EXTENDED_F: [(u32, u32); 1] = [(67456, 67519)];
+
+pub static BLK_LATIN_EXTENDED_G: [(u32, u32); 1] = [(122624, 122879)];
+
+pub static BLK_LATIN_1_SUPPLEMENT: [(u32, u32); 1] = [(128, 255)];
+
+pub static BLK_LEPCHA: [(u32, u32); 1] = [(7168, 7247)];
+
+pub static BLK_LETTERLIKE_SYMBOLS: [(u32, u32); 1] = [(8448, 8527)];
+
+pub static BLK_LIMBU: [(u32, u32); 1] = [(6400, 6479)];
+
+pub static BLK_LINEAR_A: [(u32, u32); 1] = [(67072, 67455)];
+
+pub static BLK_LINEAR_B_IDEOGRAMS: [(u32, u32); 1] = [(65664, 65791)];
+
+pub static BLK_LINEAR_B_SYLLABARY: [(u32, u32); 1] = [(65536, 65663)];
+
+pub static BLK_LISU: [(u32, u32); 1] = [(42192, 42239)];
+
+pub static BLK_LISU_SUPPLEMENT: [(u32, u32); 1] = [(73648, 73663)];
+
+pub static BLK_LYCIAN: [(u32, u32); 1] = [(66176, 66207)];
+
+pub static BLK_LYDIAN: [(u32, u32); 1] = [(67872, 67903)];
+
+pub static BLK_MAHAJANI: [(u32, u32); 1] = [(69968, 70015)];
+
+pub static BLK_MAHJONG_TILES: [(u32, u32); 1] = [(126976, 127023)];
+
+pub static BLK_MAKASAR: [(u32, u32); 1] = [(73440, 73471)];
+
+pub static BLK_MALAYALAM: [(u32, u32); 1] = [(3328, 3455)];
+
+pub static BLK_MANDAIC: [(u32, u32); 1] = [(2112, 2143)];
+
+pub static BLK_MANICHAEAN: [(u32, u32); 1] = [(68288, 68351)];
+
+pub static BLK_MARCHEN: [(u32, u32); 1] = [(72816, 72895)];
+
+pub static BLK_MASARAM_GONDI: [(u32, u32); 1] = [(72960, 73055)];
+
+pub static BLK_MATHEMATICAL_ALPHANUMERIC_SYMBOLS: [(u32, u32); 1] = [(119808, 120831)];
+
+pub static BLK_MATHEMATICAL_OPERATORS: [(u32, u32); 1] = [(8704, 8959)];
+
+pub static BLK_MAYAN_NUMERALS: [(u32, u32); 1] = [(119520, 119551)];
+
+pub static BLK_MEDEFAIDRIN: [(u32, u32); 1] = [(93760, 93855)];
+
+pub static BLK_MEETEI_MAYEK: [(u32, u32); 1] = [(43968, 44031)];
+
+pub static BLK_MEETEI_MAYEK_EXTENSIONS: [(u32, u32); 1] = [(43744, 43775)];
+
+pub static BLK_MENDE_KIKAKUI: [(u32, u32); 1] = [(124928, 125151)];
+
+pub static BLK_MEROITIC_CURSIVE: [(u32, u32); 1] = [(68000, 68095)];
+
+pub static BLK_MEROITIC_HIEROGLYPHS: [(u32, u32); 1] = [(67968, 67999)];
+
+pub static BLK_MIAO: [(u32, u32); 1] = [(93952, 94111)];
+
+pub static BLK_MISCELLANEOUS_MATHEMATICAL_SYMBOLS_A: [(u32, u32); 1] = [(10176, 10223)];
+
+pub static BLK_MISCELLANEOUS_MATHEMATICAL_SYMBOLS_B: [(u32, u32); 1] = [(10624, 10751)];
+
+pub static BLK_MISCELLANEOUS_SYMBOLS: [(u32, u32); 1] = [(9728, 9983)];
+
+pub static BLK_MISCELLANEOUS_SYMBOLS_AND_ARROWS: [(u32, u32); 1] = [(11008, 11263)];
+
+pub static BLK_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS: [(u32, u32); 1] = [(127744, 128511)];
+
+pub static BLK_MISCELLANEOUS_TECHNICAL: [(u32, u32); 1] = [(8960, 9215)];
+
+pub static BLK_MODI: [(u32, u32); 1] = [(71168, 71263)];
+
+pub static BLK_MODIFIER_TONE_LETTERS: [(u32, u32); 1] = [(42752, 42783)];
+
+pub static BLK_MONGOLIAN: [(u32, u32); 1] = [(6144, 6319)];
+
+pub static BLK_MONGOLIAN_SUPPLEMENT: [(u32, u32); 1] = [(71264, 71295)];
+
+pub static BLK_MRO: [(u32, u32); 1] = [(92736, 92783)];
+
+pub static BLK_MULTANI: [(u32, u32); 1] = [(70272, 70319)];
+
+pub static BLK_MUSICAL_SYMBOLS: [(u32, u32); 1] = [(119040, 119295)];
+
+pub static BLK_MYANMAR: [(u32, u32); 1] = [(4096, 4255)];
+
+pub static BLK_MYANMAR_EXTENDED_A: [(u32, u32); 1] = [(43616, 43647)];
+
+pub static BLK_MYANMAR_EXTENDED_B: [(u32, u32); 1] = [(43488, 43519)];
+
+pub static BLK_NKO: [(u32, u32); 1] = [(1984, 2047)];
+
+pub static BLK_NABATAEAN: [(u32, u32); 1] = [(67712, 67759)];
+
+pub static BLK_NANDINAGARI: [(u32, u32); 1] = [(72096, 72191)];
+
+pub static BLK_NEW_TAI_LUE: [(u32, u32); 1] = [(6528, 6623)];
+
+pub static BLK_NEWA: [(u32, u32); 1] = [(70656, 70783)];
+
+pub static BLK_NUMBER_FORMS: [(u32, u32); 1] = [(8528, 8591)];
+
+pub static BLK_NUSHU: [(u32, u32); 1] = [(110960, 111359)];
+
+pub static BLK_NYIAKENG_PUACHUE_HMONG: [(u32, u32); 1] = [(123136, 123215)];
+
+pub static BLK_OGHAM: [(u32, u32); 1] = [(5760, 5791)];
+
+pub static BLK_OL_CHIKI: [(u32, u32); 1] = [(7248, 7295)];
+
+pub static BLK_OLD_HUNGARIAN: [(u32, u32); 1] = [(68736, 68863)];
+
+pub static BLK_OLD_ITALIC: [(u32, u32); 1] = [(66304, 66351)];
+
+pub static BLK_OLD_NORTH_ARABIAN: [(u32, u32); 1] = [(68224, 68255)];
+
+pub static BLK_OLD_PERMIC: [(u32, u32); 1] = [(66384, 66431)];
+
+pub static BLK_OLD_PERSIAN: [(u32, u32); 1] = [(66464, 66527)];
+
+pub static BLK_OLD_SOGDIAN: [(u32, u32); 1] = [(69376, 69423)];
+
+pub static BLK_OLD_SOUTH_ARABIAN: [(u32, u32); 1] = [(68192, 68223)];
+
+pub static BLK_OLD_TURKIC: [(u32, u32); 1] = [(68608, 68687)];
+
+pub static BLK_OLD_UYGHUR: [(u32, u32); 1] = [(69488, 69551)];
+
+pub static BLK_OPTICAL_CHARACTER_RECOGNITION: [(u32, u32); 1] = [(9280, 9311)];
+
+pub static BLK_ORIYA: [(u32, u32); 1] = [(2816, 2943)];
+
+pub static BLK_ORNAMENTAL_DINGBATS: [(u32, u32); 1] = [(128592, 128639)];
+
+pub static BLK_OSAGE: [(u32, u32); 1] = [(66736, 66815)];
+
+pub static BLK_OSMANYA: [(u32, u32); 1] = [(66688, 66735)];
+
+pub static BLK_OTTOMAN_SIYAQ_NUMBERS: [(u32, u32); 1] = [(126208, 126287)];
+
+pub static BLK_PAHAWH_HMONG: [(u32, u32); 1] = [(92928, 93071)];
+
+pub static BLK_PALMYRENE: [(u32, u32); 1] = [(67680, 67711)];
+
+pub static BLK_PAU_CIN_HAU: [(u32, u32); 1] = [(72384, 72447)];
+
+pub static BLK_PHAGS_PA: [(u32, u32); 1] = [(43072, 43135)];
+
+pub static BLK_PHAISTOS_DISC: [(u32, u32); 1] = [(66000, 66047)];
+
+pub static BLK_PHOENICIAN: [(u32, u32); 1] = [(67840, 67871)];
+
+pub static BLK_PHONETIC_EXTENSIONS: [(u32, u32); 1] = [(7424, 7551)];
+
+pub static BLK_PHONETIC_EXTENSIONS_SUPPLEMENT: [(u32, u32); 1] = [(7552, 7615)];
+
+pub static BLK_PLAYING_CARDS: [(u32, u32); 1] = [(127136, 127231)];
+
+pub static BLK_PRIVATE_USE_AREA: [(u32, u32); 1] = [(57344, 63743)];
+
+pub static BLK_PSALTER_PAHLAVI: [(u32, u32); 1] = [(68480, 68527)];
+
+pub static BLK_REJANG: [(u32, u32); 1] = [(43312, 43359)];
+
+pub static BLK_RUMI_NUMERAL_SYMBOLS: [(u32, u32); 1] = [(69216, 69247)];
+
+pub static BLK_RUNIC: [(u32, u32); 1] = [(5792, 5887)];
+
+pub static BLK_SAMARITAN: [(u32, u32); 1] = [(2048, 2111)];
+
+pub static BLK_SAURASHTRA: [(u32, u32); 1] = [(43136, 43231)];
+
+pub static BLK_SHARADA: [(u32, u32); 1] = [(70016, 70111)];
+
+pub static BLK_SHAVIAN: [(u32, u32); 1] = [(66640, 66687)];
+
+pub static BLK_SHORTHAND_FORMAT_CONTROLS: [(u32, u32); 1] = [(113824, 113839)];
+
+pub static BLK_SIDDHAM: [(u32, u32); 1] = [(71040, 71167)];
+
+pub static BLK_SINHALA: [(u32, u32); 1] = [(3456, 3583)];
+
+pub static BLK_SINHALA_ARCHAIC_NUMBERS: [(u32, u32); 1] = [(70112, 70143)];
+
+pub static BLK_SMALL_FORM_VARIANTS: [(u32, u32); 1] = [(65104, 65135)];
+
+pub static BLK_SMALL_KANA_EXTENSION: [(u32, u32); 1] = [(110896, 110959)];
+
+pub static BLK_SOGDIAN: [(u32, u32); 1] = [(69424, 69487)];
+
+pub static BLK_SORA_SOMPENG: [(u32, u32); 1] = [(69840, 69887)];
+
+pub static BLK_SOYOMBO: [(u32, u32); 1] = [(72272, 72367)];
+
+pub static BLK_SPACING_MODIFIER_LETTERS: [(u32, u32); 1] = [(688, 767)];
+
+pub static BLK_SPECIALS: [(u32, u32); 1] = [(65520, 65535)];
+
+pub static BLK_SUNDANESE: [(u32, u32); 1] = [(7040, 7103)];
+
+pub static BLK_SUNDANESE_SUPPLEMENT: [(u32, u32); 1] = [(7360, 7375)];
+
+pub static BLK_SUPERSCRIPTS_AND_SUBSCRIPTS: [(u32, u32); 1] = [(8304, 8351)];
+
+pub static BLK_SUPPLEMENTAL_ARROWS_A: [(u32, u32); 1] = [(10224, 10239)];
+
+pub static BLK_SUPPLEMENTAL_ARROWS_B: [(u32, u32); 1] = [(10496, 10623)];
+
+pub static BLK_SUPPLEMENTAL_ARROWS_C: [(u32, u32); 1] = [(129024, 129279)];
+
+pub static BLK_SUPPLEMENTAL_MATHEMATICAL_OPERATORS: [(u32, u32); 1] = [(10752, 11007)];
+
+pub static BLK_SUPPLEMENTAL_PUNCTUATION: [(u32, u32); 1] = [(11776, 11903)];
+
+pub static BLK_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS: [(u32, u32); 1] = [(129280, 129535)];
+
+pub static BLK_SUPPLEMENTARY_PRIVATE_USE_AREA_A: [(u32, u32); 1] = [(983040, 1048575)];
+
+pub static BLK_SUPPLEMENTARY_PRIVATE_USE_AREA_B: [(u32, u32); 1] = [(1048576, 1114111)];
+
+pub static BLK_SUTTON_SIGNWRITING: [(u32, u32); 1] = [(120832, 121519)];
+
+pub static BLK_SYLOTI_NAGRI: [(u32, u32); 1] = [(43008, 43055)];
+
+pub static BLK_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A: [(u32, u32); 1] = [(129648, 129791)];
+
+pub static BLK_SYMBOLS_FOR_LEGACY_COMPUTING: [(u32, u32); 1] = [(129792, 130047)];
+
+pub static BLK_SYRIAC: [(u32, u32); 1] = [(1792, 1871)];
+
+pub static BLK_SYRIAC_SUPPLEMENT: [(u32, u32); 1] = [(2144, 2159)];
+
+pub static BLK_TAGALOG: [(u32, u32); 1] = [(5888, 5919)];
+
+pub static BLK_TAGBANWA: [(u32, u32); 1] = [(5984, 6015)];
+
+pub static BLK_TAGS: [(u32, u32); 1] = [(917504, 917631)];
+
+pub static BLK_TAI_LE: [(u32, u32); 1] = [(6480, 6527)];
+
+pub static BLK_TAI_THAM: [(u32, u32); 1] = [(6688, 6831)];
+
+pub static BLK_TAI_VIET: [(u32, u32); 1] = [(43648, 43743)];
+
+pub static BLK_TAI_XUAN_JING_SYMBOLS: [(u32, u32); 1] = [(119552, 119647)];
+
+pub static BLK_TAKRI: [(u32, u32); 1] = [(71296, 71375)];
+
+pub static BLK_TAMIL: [(u32, u32); 1] = [(2944, 3071)];
+
+pub static BLK_TAMIL_SUPPLEMENT: [(u32, u32); 1] = [(73664, 73727)];
+
+pub static BLK_TANGSA: [(u32, u32); 1] = [(92784, 92879)];
+
+pub static BLK_TANGUT: [(u32, u32); 1] = [(94208, 100351)];
+
+pub static BLK_TANGUT_COMPONENTS: [(u32, u32); 1] = [(100352, 101119)];
+
+pub static BLK_TANGUT_SUPPLEMENT: [(u32, u32); 1] = [(101632, 101759)];
+
+pub static BLK_TELUGU: [(u32, u32); 1] = [(3072, 3199)];
+
+pub static BLK_THAANA: [(u32, u32); 1] = [(1920, 1983)];
+
+pub static BLK_THAI: [(u32, u32); 1] = [(3584, 3711)];
+
+pub static BLK_TIBETAN: [(u32, u32); 1] = [(3840, 4095)];
+
+pub static BLK_TIFINAGH: [(u32, u32); 1] = [(11568, 11647)];
+
+pub static BLK_TIRHUTA: [(u32, u32); 1] = [(70784, 70879)];
+
+pub static BLK_TOTO: [(u32, u32); 1] = [(123536, 123583)];
+
+pub static BLK_TRANSPORT_AND_MAP_SYMBOLS: [(u32, u32); 1] = [(128640, 128767)];
+
+pub static BLK_UGARITIC: [(u32, u32); 1] = [(66432, 66463)];
+
+pub static BLK_UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS: [(u32, u32); 1] = [(5120, 5759)];
+
+pub static BLK_UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS_EXTENDED: [(u32, u32); 1] = [(6320, 6399)];
+
+pub static BLK_UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS_EXTENDED_A: [(u32, u32); 1] = [(72368, 72383)];
+
+pub static BLK_VAI: [(u32, u32); 1] = [(42240, 42559)];
+
+pub static BLK_VARIATION_SELECTORS: [(u32, u32); 1] = [(65024, 65039)];
+
+pub static BLK_VARIATION_SELECTORS_SUPPLEMENT: [(u32, u32); 1] = [(917760, 917999)];
+
+pub static BLK_VEDIC_EXTENSIONS: [(u32, u32); 1] = [(7376, 7423)];
+
+pub static BLK_VERTICAL_FORMS: [(u32, u32); 1] = [(65040, 65055)];
+
+pub static BLK_VITHKUQI: [(u32, u32); 1] = [(66928, 67007)];
+
+pub static BLK_WANCHO: [(u32, u32); 1] = [(123584, 123647)];
+
+pub static BLK_WARANG_CITI: [(u32, u32); 1] = [(71840, 71935)];
+
+pub static BLK_YEZIDI: [(u32, u32); 1] = [(69248, 69311)];
+
+pub static BLK_YI_RADICALS: [(u32, u32); 1] = [(42128, 42191)];
+
+pub static BLK_YI_SYLLABLES: [(u32, u32); 1] = [(40960, 42127)];
+
+pub static BLK_YIJING_HEXAGRAM_SYMBOLS: [(u32, u32); 1] = [(19904, 19967)];
+
+pub static BLK_ZANABAZAR_SQUARE: [(u32, u32); 1] = [(72192, 72271)];
+
+pub static BLK_ZNAMENNY_MUSICAL_NOTATION: [(u32, u32); 1] = [(118528, 118735)];
+
+pub static BLOCKS: [(&str, &[(u32, u32)]); 317] = [
+    ("Adlam", &BLK_ADLAM),
+    ("Aegean Numbers", &BLK_AEGEAN_NUMBERS),
+    ("Ahom", &BLK_AHOM),
+    ("Alchemical Symbols", &BLK_ALCHEMICAL_SYMBOLS),
+    (
+        "Alphabetic Presentation Forms",
+        &BLK_ALPHABETIC_PRESENTATION_FORMS,
+    ),
+    ("Anatolian Hieroglyphs", &BLK_ANATOLIAN_HIEROGLYPHS),
+    (
+        "Ancient Greek Musical Notation",
+        &BLK_ANCIENT_GREEK_MUSICAL_NOTATION,
+    ),
+    ("Ancient Greek Numbers", &BLK_ANCIENT_GREEK_NUMBERS),
+    ("Ancient Symbols", &BLK_ANCIENT_SYMBOLS),
+    ("Arabic", &BLK_ARABIC),
+    ("Arabic Extended-A", &BLK_ARABIC_EXTENDED_A),
+    ("Arabic Extended-B", &BLK_ARABIC_EXTENDED_B),
+    (
+        "Arabic Mathematical Alphabetic Symbols",
+        &BLK_ARABIC_MATHEMATICAL_ALPHABETIC_SYMBOLS,
+    ),
+    (
+        "Arabic Presentation Forms-A",
+        &BLK_ARABIC_PRESENTATION_FORMS_A,
+    ),
+    (
+        "Arabic Presentation Forms-B",
+        &BLK_ARABIC_PRESENTATION_FORMS_B,
+    ),
+    ("Arabic Supplement", &BLK_ARABIC_SUPPLEMENT),
+    ("Armenian", &BLK_ARMENIAN),
+    ("Arrows", &BLK_ARROWS),
+    ("Avestan", &BLK_AVESTAN),
+    ("Balinese", &BLK_BALINESE),
+    ("Bamum", &BLK_BAMUM),
+    ("Bamum Supplement", &BLK_BAMUM_SUPPLEMENT),
+    ("Basic Latin", &BLK_BASIC_LATIN),
+    ("Bassa Vah", &BLK_BASSA_VAH),
+    ("Batak", &BLK_BATAK),
+    ("Bengali", &BLK_BENGALI),
+    ("Bhaiksuki", &BLK_BHAIKSUKI),
+    ("Block Elements", &BLK_BLOCK_ELEMENTS),
+    ("Bopomofo", &BLK_BOPOMOFO),
+    ("Bopomofo Extended", &BLK_BOPOMOFO_EXTENDED),
+    ("Box Drawing", &BLK_BOX_DRAWING),
+    ("Brahmi", &BLK_BRAHMI),
+    ("Braille Patterns", &BLK_BRAILLE_PATTERNS),
+    ("Buginese", &BLK_BUGINESE),
+    ("Buhid", &BLK_BUHID),
+    ("Byzantine Musical Symbols", &BLK_BYZANTINE_MUSICAL_SYMBOLS),
+    ("CJK Compatibility", &BLK_CJK_COMPATIBILITY),
+    ("CJK Compatibility Forms", &BLK_CJK_COMPATIBILITY_FORMS),
+    (
+        "CJK Compatibility Ideographs",
+        &BLK_CJK_COMPATIBILITY_IDEOGRAPHS,
+    ),
+    (
+        "CJK Compatibility Ideographs Supplement",
+        &BLK_CJK_COMPATIBILITY_IDEOGRAPHS_SUPPLEMENT,
+    ),
+    ("CJK Radicals Supplement", &BLK_CJK_RADICALS_SUPPLEMENT),
+    ("CJK Strokes", &BLK_CJK_STROKES),
+    (
+        "CJK Symbols and Punctuation",
+        &BLK_CJK_SYMBOLS_AND_PUNCTUATION,
+    ),
+    ("CJK Unified Ideographs", &BLK_CJK_UNIFIED_IDEOGRAPHS),
+    (
+        "CJK Unified Ideographs Extension A",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_A,
+    ),
+    (
+        "CJK Unified Ideographs Extension B",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_B,
+    ),
+    (
+        "CJK Unified Ideographs Extension C",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_C,
+    ),
+    (
+        "CJK Unified Ideographs Extension D",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_D,
+    ),
+    (
+        "CJK Unified Ideographs Extension E",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_E,
+    ),
+    (
+        "CJK Unified Ideographs Extension F",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_F,
+    ),
+    (
+        "CJK Unified Ideographs Extension G",
+        &BLK_CJK_UNIFIED_IDEOGRAPHS_EXTENSION_G,
+    ),
+    ("Carian", &BLK_CARIAN),
+    ("Caucasian Albanian", &BLK_CAUCASIAN_ALBANIAN),
+    ("Chakma", &BLK_CHAKMA),
+    ("Cham", &BLK_CHAM),
+    ("Cherokee", &BLK_CHEROKEE),
+    ("Cherokee Supplement", &BLK_CHEROKEE_SUPPLEMENT),
+    ("Chess Symbols", &BLK_CHESS_SYMBOLS),
+    ("Chorasmian", &BLK_CHORASMIAN),
+    (
+        "Combining Diacritical Marks",
+        &BLK_COMBINING_DIACRITICAL_MARKS,
+    ),
+    (
+        "Combining Diacritical Marks Extended",
+        &BLK_COMBINING_DIACRITICAL_MARKS_EXTENDED,
+    ),
+    (
+        "Combining Diacritical Marks Supplement",
+        &BLK_COMBINING_DIACRITICAL_MARKS_SUPPLEMENT,
+    ),
+    (
+        "Combining Diacritical Marks for Symbols",
+        &BLK_COMBINING_DIACRITICAL_MARKS_FOR_SYMBOLS,
+    ),
+    ("Combining Half Marks", &BLK_COMBINING_HALF_MARKS),
+    ("Common Indic Number Forms", &BLK_COMMON_INDIC_NUMBER_FORMS),
+    ("Control Pictures", &BLK_CONTROL_PICTURES),
+    ("Coptic", &BLK_COPTIC),
+    ("Coptic Epact Numbers", &BLK_COPTIC_EPACT_NUMBERS),
+    ("Counting Rod Numerals", &BLK_COUNTING_ROD_NUMERALS),
+    ("Cuneiform", &BLK_CUNEIFORM),
+    (
+        "Cuneiform Numbers and Punctuation",
+        &BLK_CUNEIFORM_NUMBERS_AND_PUNCTUATION,
+    ),
+    ("Currency Symbols", &BLK_CURRENCY_SYMBOLS),
+    ("Cypriot Syllabary", &BLK_CYPRIOT_SYLLABARY),
+    ("Cypro-Minoan", &BLK_CYPRO_MINOAN),
+    ("Cyrillic", &BLK_CYRILLIC),
+    ("Cyrillic Extended-A", &BLK_CYRILLIC_EXTENDED_A),
+    ("Cyrillic Extended-B", &BLK_CYRILLIC_EXTENDED_B),
+    ("Cyrillic Extended-C", &BLK_CYRILLIC_EXTENDED_C),
+    ("Cyrillic Supplement", &BLK_CYRILLIC_SUPPLEMENT),
+    ("Deseret", &BLK_DESERET),
+    ("Devanagari", &BLK_DEVANAGARI),
+    ("Devanagari Extended", &BLK_DEVANAGARI_EXTENDED),
+    ("Dingbats", &BLK_DINGBATS),
+    ("Dives Akuru", &BLK_DIVES_AKURU),
+    ("Dogra", &BLK_DOGRA),
+    ("Domino Tiles", &BLK_DOMINO_TILES),
+    ("Duployan", &BLK_DUPLOYAN),
+    ("Early Dynastic Cuneiform", &BLK_EARLY_DYNASTIC_CUNEIFORM),
+    (
+        "Egyptian Hieroglyph Format Controls",
+        &BLK_EGYPTIAN_HIEROGLYPH_FORMAT_CONTROLS,
+    ),
+    ("Egyptian Hieroglyphs", &BLK_EGYPTIAN_HIEROGLYPHS),
+    ("Elbasan", &BLK_ELBASAN),
+    ("Elymaic", &BLK_ELYMAIC),
+    ("Emoticons", &BLK_EMOTICONS),
+    (
+        "Enclosed Alphanumeric Supplement",
+        &BLK_ENCLOSED_ALPHANUMERIC_SUPPLEMENT,
+    ),
+    ("Enclosed Alphanumerics", &BLK_ENCLOSED_ALPHANUMERICS),
+    (
+        "Enclosed CJK Letters and Months",
+        &BLK_ENCLOSED_CJK_LETTERS_AND_MONTHS,
+    ),
+    (
+        "Enclosed Ideographic Supplement",
+        &BLK_ENCLOSED_IDEOGRAPHIC_SUPPLEMENT,
+    ),
+    ("Ethiopic", &BLK_ETHIOPIC),
+    ("Ethiopic Extended", &BLK_ETHIOPIC_EXTENDED),
+    ("Ethiopic Extended-A", &BLK_ETHIOPIC_EXTENDED_A),
+    ("Ethiopic Extended-B", &BLK_ETHIOPIC_EXTENDED_B),
+    ("Ethiopic Supplement", &BLK_ETHIOPIC_SUPPLEMENT),
+    ("General Punctuation", &BLK_GENERAL_PUNCTUATION),
+    ("Geometric Shapes", &BLK_GEOMETRIC_SHAPES),
+    ("Geometric Shapes Extended", &BLK_GEOMETRIC_SHAPES_EXTENDED),
+    ("Georgian", &BLK_GEORGIAN),
+    ("Georgian Extended", &BLK_GEORGIAN_EXTENDED),
+    ("Georgian Supplement", &BLK_GEORGIAN_SUPPLEMENT),
+    ("Glagolitic", &BLK_GLAGOLITIC),
+    ("Glagolitic Supplement", &BLK_GLAGOLITIC_SUPPLEMENT),
+    ("Gothic", &BLK_GOTHIC),
+    ("Grantha", &BLK_GRANTHA),
+    ("Greek Extended", &BLK_GREEK_EXTENDED),
+    ("Greek and Coptic", &BLK_GREEK_AND_COPTIC),
+    ("Gujarati", &BLK_GUJARATI),
+    ("Gunjala Gondi", &BLK_GUNJALA_GONDI),
+    ("Gurmukhi", &BLK_GURMUKHI),
+    (
+        "Halfwidth and Fullwidth Forms",
+        &BLK_HALFWIDTH_AND_FULLWIDTH_FORMS,
+    ),
+    ("Hangul Compatibility Jamo", &BLK_HANGUL_COMPATIBILITY_JAMO),
+    ("Hangul Jamo", &BLK_HANGUL_JAMO),
+    ("Hangul Jamo Extended-A", &BLK_HANGUL_JAMO_EXTENDED_A),
+    ("Hangul Jamo Extended-B", &BLK_HANGUL_JAMO_EXTENDED_B),
+    ("Hangul Syllables", &BLK_HANGUL_SYLLABLES),
+    ("Hanifi Rohingya", &BLK_HANIFI_ROHINGYA),
+    ("Hanunoo", &BLK_HANUNOO),
+    ("Hatran", &BLK_HATRAN),
+    ("Hebrew", &BLK_HEBREW),
+    ("Hiragana", &BLK_HIRAGANA),
+    ("IPA Extensions", &BLK_IPA_EXTENSIONS),
+    (
+        "Ideographic Description Characters",
+        &BLK_IDEOGRAPHIC_DESCRIPTION_CHARACTERS,
+    ),
+    (
+        "Ideographic Symbols and Punctuation",
+        &BLK_IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION,
+    ),
+    ("Imperial Aramaic", &BLK_IMPERIAL_ARAMAIC),
+    ("Indic Siyaq Numbers", &BLK_INDIC_SIYAQ_NUMBERS),
+    ("Inscriptional Pahlavi", &BLK_INSCRIPTIONAL_PAHLAVI),
+    ("Inscriptional Parthian", &BLK_INSCRIPTIONAL_PARTHIAN),
+    ("Javanese", &BLK_JAVANESE),
+    ("Kaithi", &BLK_KAITHI),
+    ("Kana Extended-A", &BLK_KANA_EXTENDED_A),
+    ("Kana Extended-B", &BLK_KANA_EXTENDED_B),
+    ("Kana Supplement", &BLK_KANA_SUPPLEMENT),
+    ("Kanbun", &BLK_KANBUN),
+    ("Kangxi Radicals", &BLK_KANGXI_RADICALS),
+    ("Kannada", &BLK_KANNADA),
+    ("Katakana", &BLK_KATAKANA),
+    (
+        "Katakana Phonetic Extensions",
+        &BLK_KATAKANA_PHONETIC_EXTENSIONS,
+    ),
+    ("Kayah Li", &BLK_KAYAH_LI),
+    ("Kharoshthi", &BLK_KHAROSHTHI),
+    ("Khitan Small Script", &BLK_KHITAN_SMALL_SCRIPT),
+    ("Khmer", &BLK_KHMER),
+    ("Khmer Symbols", &BLK_KHMER_SYMBOLS),
+    ("Khojki", &BLK_KHOJKI),
+    ("Khudawadi", &BLK_KHUDAWADI),
+    ("Lao", &BLK_LAO),
+    ("Latin Extended Additional", &BLK_LATIN_EXTENDED_ADDITIONAL),
+    ("Latin Extended-A", &BLK_LATIN_EXTENDED_A),
+    ("Latin Extended-B", &BLK_LATIN_EXTENDED_B),
+    ("Latin Extended-C", &BLK_LATIN_EXTENDED_C),
+    ("Latin Extended-D", &BLK_LATIN_EXTENDED_D),
+    ("Latin Extended-E", &BLK_LATIN_EXTENDED_E),
+    ("Latin Extended-F", &BLK_LATIN_EXTENDED_F),
+    ("Latin Extended-G", &BLK_LATIN_EXTENDED_G),
+    ("Latin-1 Supplement", &BLK_LATIN_1_SUPPLEMENT),
+    ("Lepcha", &BLK_LEPCHA),
+    ("Letterlike Symbols", &BLK_LETTERLIKE_SYMBOLS),
+    ("Limbu", &BLK_LIMBU),
+    ("Linear A", &BLK_LINEAR_A),
+    ("Linear B Ideograms", &BLK_LINEAR_B_IDEOGRAMS),
+    ("Linear B Syllabary", &BLK_LINEAR_B_SYLLABARY),
+    ("Lisu", &BLK_LISU),
+    ("Lisu Supplement", &BLK_LISU_SUPPLEMENT),
+    ("Lycian", &BLK_LYCIAN),
+    ("Lydian", &BLK_LYDIAN),
+    ("Mahajani", &BLK_MAHAJANI),
+    ("Mahjong Tiles", &BLK_MAHJONG_TILES),
+    ("Makasar", &BLK_MAKASAR),
+    ("Malayalam", &BLK_MALAYALAM),
+    ("Mandaic", &BLK_MANDAIC),
+    ("Manichaean", &BLK_MANICHAEAN),
+    ("Marchen", &BLK_MARCHEN),
+    ("Masaram Gondi", &BLK_MASARAM_GONDI),
+    (
+        "Mathematical Alphanumeric Symbols",
+        &BLK_MATHEMATICAL_ALPHANUMERIC_SYMBOLS,
+    ),
+    ("Mathematical Operators", &BLK_MATHEMATICAL_OPERATORS),
+    ("Mayan Numerals", &BLK_MAYAN_NUMERALS),
+    ("Medefaidrin", &BLK_MEDEFAIDRIN),
+    ("Meetei Mayek", &BLK_MEETEI_MAYEK),
+    ("Meetei Mayek Extensions", &BLK_MEETEI_MAYEK_EXTENSIONS),
+    ("Mende Kikakui", &BLK_MENDE_KIKAKUI),
+    ("Meroitic Cursive", &BLK_MEROITIC_CURSIVE),
+    ("Meroitic Hieroglyphs", &BLK_MEROITIC_HIEROGLYPHS),
+    ("Miao", &BLK_MIAO),
+    (
+        "Miscellaneous Mathematical Symbols-A",
+        &BLK_MISCELLANEOUS_MATHEMATICAL_SYMBOLS_A,
+    ),
+    (
+        "Miscellaneous Mathematical Symbols-B",
+        &BLK_MISCELLANEOUS_MATHEMATICAL_SYMBOLS_B,
+    ),
+    ("Miscellaneous Symbols", &BLK_MISCELLANEOUS_SYMBOLS),
+    (
+        "Miscellaneous Symbols and Arrows",
+        &BLK_MISCELLANEOUS_SYMBOLS_AND_ARROWS,
+    ),
+    (
+        "Miscellaneous Symbols and Pictographs",
+        &BLK_MISCELLANEOUS_SYMBOLS_AND_PICTOGRAPHS,
+    ),
+    ("Miscellaneous Technical", &BLK_MISCELLANEOUS_TECHNICAL),
+    ("Modi", &BLK_MODI),
+    ("Modifier Tone Letters", &BLK_MODIFIER_TONE_LETTERS),
+    ("Mongolian", &BLK_MONGOLIAN),
+    ("Mongolian Supplement", &BLK_MONGOLIAN_SUPPLEMENT),
+    ("Mro", &BLK_MRO),
+    ("Multani", &BLK_MULTANI),
+    ("Musical Symbols", &BLK_MUSICAL_SYMBOLS),
+    ("Myanmar", &BLK_MYANMAR),
+    ("Myanmar Extended-A", &BLK_MYANMAR_EXTENDED_A),
+    ("Myanmar Extended-B", &BLK_MYANMAR_EXTENDED_B),
+    ("NKo", &BLK_NKO),
+    ("Nabataean", &BLK_NABATAEAN),
+    ("Nandinagari", &BLK_NANDINAGARI),
+    ("New Tai Lue", &BLK_NEW_TAI_LUE),
+    ("Newa", &BLK_NEWA),
+    ("Number Forms", &BLK_NUMBER_FORMS),
+    ("Nushu", &BLK_NUSHU),
+    ("Nyiakeng Puachue Hmong", &BLK_NYIAKENG_PUACHUE_HMONG),
+    ("Ogham", &BLK_OGHAM),
+    ("Ol Chiki", &BLK_OL_CHIKI),
+    ("Old Hungarian", &BLK_OLD_HUNGARIAN),
+    ("Old Italic", &BLK_OLD_ITALIC),
+    ("Old North Arabian", &BLK_OLD_NORTH_ARABIAN),
+    ("Old Permic", &BLK_OLD_PERMIC),
+    ("Old Persian", &BLK_OLD_PERSIAN),
+    ("Old Sogdian", &BLK_OLD_SOGDIAN),
+    ("Old South Arabian", &BLK_OLD_SOUTH_ARABIAN),
+    ("Old Turkic", &BLK_OLD_TURKIC),
+    ("Old Uyghur", &BLK_OLD_UYGHUR),
+    (
+        "Optical Character Recognition",
+        &BLK_OPTICAL_CHARACTER_RECOGNITION,
+    ),
+    ("Oriya", &BLK_ORIYA),
+    ("Ornamental Dingbats", &BLK_ORNAMENTAL_DINGBATS),
+    ("Osage", &BLK_OSAGE),
+    ("Osmanya", &BLK_OSMANYA),
+    ("Ottoman Siyaq Numbers", &BLK_OTTOMAN_SIYAQ_NUMBERS),
+    ("Pahawh Hmong", &BLK_PAHAWH_HMONG),
+    ("Palmyrene", &BLK_PALMYRENE),
+    ("Pau Cin Hau", &BLK_PAU_CIN_HAU),
+    ("Phags-pa", &BLK_PHAGS_PA),
+    ("Phaistos Disc", &BLK_PHAISTOS_DISC),
+    ("Phoenician", &BLK_PHOENICIAN),
+    ("Phonetic Extensions", &BLK_PHONETIC_EXTENSIONS),
+    (
+        "Phonetic Extensions Supplement",
+        &BLK_PHONETIC_EXTENSIONS_SUPPLEMENT,
+    ),
+    ("Playing Cards", &BLK_PLAYING_CARDS),
+    ("Private Use Area", &BLK_PRIVATE_USE_AREA),
+    ("Psalter Pahlavi", &BLK_PSALTER_PAHLAVI),
+    ("Rejang", &BLK_REJANG),
+    ("Rumi Numeral Symbols", &BLK_RUMI_NUMERAL_SYMBOLS),
+    ("Runic", &BLK_RUNIC),
+    ("Samaritan", &BLK_SAMARITAN),
+    ("Saurashtra", &BLK_SAURASHTRA),
+    ("Sharada", &BLK_SHARADA),
+    ("Shavian", &BLK_SHAVIAN),
+    ("Shorthand Format Controls", &BLK_SHORTHAND_FORMAT_CONTROLS),
+    ("Siddham", &BLK_SIDDHAM),
+    ("Sinhala", &BLK_SINHALA),
+    ("Sinhala Archaic Numbers", &BLK_SINHALA_ARCHAIC_NUMBERS),
+    ("Small Form Variants", &BLK_SMALL_FORM_VARIANTS),
+    ("Small Kana Extension", &BLK_SMALL_KANA_EXTENSION),
+    ("Sogdian", &BLK_SOGDIAN),
+    ("Sora Sompeng", &BLK_SORA_SOMPENG),
+    ("Soyombo", &BLK_SOYOMBO),
+    ("Spacing Modifier Letters", &BLK_SPACING_MODIFIER_LETTERS),
+    ("Specials", &BLK_SPECIALS),
+    ("Sundanese", &BLK_SUNDANESE),
+    ("Sundanese Supplement", &BLK_SUNDANESE_SUPPLEMENT),
+    (
+        "Superscripts and Subscripts",
+        &BLK_SUPERSCRIPTS_AND_SUBSCRIPTS,
+    ),
+    ("Supplemental Arrows-A", &BLK_SUPPLEMENTAL_ARROWS_A),
+    ("Supplemental Arrows-B", &BLK_SUPPLEMENTAL_ARROWS_B),
+    ("Supplemental Arrows-C", &BLK_SUPPLEMENTAL_ARROWS_C),
+    (
+        "Supplemental Mathematical Operators",
+        &BLK_SUPPLEMENTAL_MATHEMATICAL_OPERATORS,
+    ),
+    ("Supplemental Punctuation", &BLK_SUPPLEMENTAL_PUNCTUATION),
+    (
+        "Supplemental Symbols and Pictographs",
+        &BLK_SUPPLEMENTAL_SYMBOLS_AND_PICTOGRAPHS,
+    ),
+    (
+        "Supplementary Private Use Area-A",
+        &BLK_SUPPLEMENTARY_PRIVATE_USE_AREA_A,
+    ),
+    (
+        "Supplementary Private Use Area-B",
+        &BLK_SUPPLEMENTARY_PRIVATE_USE_AREA_B,
+    ),
+    ("Sutton SignWriting", &BLK_SUTTON_SIGNWRITING),
+    ("Syloti Nagri", &BLK_SYLOTI_NAGRI),
+    (
+        "Symbols and Pictographs Extended-A",
+        &BLK_SYMBOLS_AND_PICTOGRAPHS_EXTENDED_A,
+    ),
+    (
+        "Symbols for Legacy Computing",
+        &BLK_SYMBOLS_FOR_LEGACY_COMPUTING,
+    ),
+    ("Syriac", &BLK_SYRIAC),
+    ("Syriac Supplement", &BLK_SYRIAC_SUPPLEMENT),
+    ("Tagalog", &BLK_TAGALOG),
+    ("Tagbanwa", &BLK_TAGBANWA),
+    ("Tags", &BLK_TAGS),
+    ("Tai Le", &BLK_TAI_LE),
+    ("Tai Tham", &BLK_TAI_THAM),
+    ("Tai Viet", &BLK_TAI_VIET),
+    ("Tai Xuan Jing Symbols", &BLK_TAI_XUAN_JING_SYMBOLS),
+    ("Takri", &BLK_TAKRI),
+    ("Tamil", &BLK_TAMIL),
+    ("Tamil Supplement", &BLK_TAMIL_SUPPLEMENT),
+    ("Tangsa", &BLK_TANGSA),
+    ("Tangut", &BLK_TANGUT),
+    ("Tangut Components", &BLK_TANGUT_COMPONENTS),
+    ("Tangut Supplement", &BLK_TANGUT_SUPPLEMENT),
+    ("Telugu", &BLK_TELUGU),
+    ("Thaana", &BLK_THAANA),
+    ("Thai", &BLK_THAI),
+    ("Tibetan", &BLK_TIBETAN),
+    ("Tifinagh", &BLK_TIFINAGH),
+    ("Tirhuta", &BLK_TIRHUTA),
+    ("Toto", &BLK_TOTO),
+    ("Transport and Map Symbols", &BLK_TRANSPORT_AND_MAP_SYMBOLS),
+    ("Ugaritic", &BLK_UGARITIC),
+    (
+        "Unified Canadian Aboriginal Syllabics",
+        &BLK_UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS,
+    ),
+    (
+        "Unified Canadian Aboriginal Syllabics Extended",
+        &BLK_UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS_EXTENDED,
+    ),
+    (
+        "Unified Canadian Aboriginal Syllabics Extended-A",
+        &BLK_UNIFIED_CANADIAN_ABORIGINAL_SYLLABICS_EXTENDED_A,
+    ),
+    ("Vai", &BLK_VAI),
+    ("Variation Selectors", &BLK_VARIATION_SELECTORS),
+    (
+        "Variation Selectors Supplement",
+        &BLK_VARIATION_SELECTORS_SUPPLEMENT,
+    ),
+    ("Vedic Extensions", &BLK_VEDIC_EXTENSIONS),
+    ("Vertical Forms", &BLK_VERTICAL_FORMS),
+    ("Vithkuqi", &BLK_VITHKUQI),
+    ("Wancho", &BLK_WANCHO),
+    ("Warang Citi", &BLK_WARANG_CITI),
+    ("Yezidi", &BLK_YEZIDI),
+    ("Yi Radicals", &BLK_YI_RADICALS),
+    ("Yi Syllables", &BLK_YI_SYLLABLES),
+    ("Yijing Hexagram Symbols", &BLK_YIJING_HEXAGRAM_SYMBOLS),
+    ("Zanabazar Square", &BLK_ZANABAZAR_SQUARE),
+    ("Znamenny Musical Notation", &BLK_ZNAMENNY_MUSICAL_NOTATION),
+];
+
+pub static AGE_1_1: [(u32, u32); 288] = [
+    (0, 501),
+    (506, 535),
+    (592, 680),
+    (688, 734),
+    (736, 745),
+    (768, 837),
+    (864, 865),
+    (884, 885),
+    (890, 890),
+    (894, 894),
+    (900, 906),
+    (908, 908),
+    (910, 929),
+    (931, 974),
+    (976, 982),
+    (986, 986),
+    (988, 988),
+    (990, 990),
+    (992, 992),
+    (994, 1011),
+    (1025, 1036),
+    (1038, 1103),
+    (1105, 1116),
+    (1118, 1158),
+    (1168, 1220),
+    (1223, 1224),
+    (1227, 1228),
+    (1232, 1259),
+    (1262, 1269),
+    (1272, 1273),
+    (1329, 1366),
+    (1369, 1375),
+    (1377, 1415),
+    (1417, 1417),
+    (1456, 1465),
+    (1467, 1475),
+    (1488, 1514),
+    (1520, 1524),
+    (1548, 1548),
+    (1563, 1563),
+    (1567, 1567),
+    (1569, 1594),
+    (1600, 1618),
+    (1632, 1645),
+    (1648, 1719),
+    (1722, 1726),
+    (1728, 1742),
+    (1744, 1773),
+    (1776, 1785),
+    (2305, 2307),
+    (2309, 2361),
+    (2364, 2381),
+    (2384, 2388),
+    (2392, 2416),
+    (2433, 2435),
+    (2437, 2444),
+    (2447, 2448),
+    (2451, 2472),
+    (2474, 2480),
+    (2482, 2482),
+    (2486, 2489),
+    (2492, 2492),
+    (2494, 2500),
+    (2503, 2504),
+    (2507, 2509),
+    (2519, 2519),
+    (2524, 2525),
+    (2527, 2531),
+    (2534, 2554),
+    (2562, 2562),
+    (2565, 2570),
+    (2575, 2576),
+    (2579, 2600),
+    (2602, 2608),
+    (2610, 2611),
+    (2613, 2614),
+    (2616, 2617),
+    (2620, 2620),
+    (2622, 2626),
+    (2631, 2632),
+    (2635, 2637),
+    (2649, 2652),
+    (2654, 2654),
+    (2662, 2676),
+    (2689, 2691),
+    (2693, 2699),
+    (2701, 2701),
+    (2703, 2705),
+    (2707, 2728),
+    (2730, 2736),
+    (2738, 2739),
+    (2741, 2745),
+    (2748, 2757),
+    (2759, 2761),
+    (2763, 2765),
+    (2768, 2768),
+    (2784, 2784),
+    (2790, 2799),
+    (2817, 2819),
+    (2821, 2828),
+    (2831, 2832),
+    (2835, 2856),
+    (2858, 2864),
+    (2866, 2867),
+    (2870, 2873),
+    (2876, 2883),
+    (2887, 2888),
+    (2891, 2893),
+    (2902, 2903),
+    (2908, 2909),
+    (2911, 2913),
+    (2918, 2928),
+    (2946, 2947),
+    (2949, 2954),
+    (2958, 2960),
+    (2962, 2965),
+    (2969, 2970),
+    (2972, 2972),
+    (2974, 2975),
+    (2979, 2980),
+    (2984, 2986),
+    (2990, 2997),
+    (2999, 3001),
+    (3006, 3010),
+    (3014, 3016),
+    (3018, 3021),
+    (3031, 3031),
+    (3047, 3058),
+    (3073, 3075),
+    (3077, 3084),
+    (3086, 3088),
+    (3090, 3112),
+    (3114, 3123),
+    (3125, 3129),
+    (3134, 3140),
+    (3142, 3144),
+    (3146, 3149),
+    (3157, 3158),
+    (3168, 3169),
+    (3174, 3183),
+    (3202, 3203),
+    (3205, 3212),
+    (3214, 3216),
+    (3218, 3240),
+    (3242, 3251),
+    (3253, 3257),
+    (3262, 3268),
+    (3270, 3272),
+    (3274, 3277),
+    (3285, 3286),
+    (3294, 3294),
+    (3296, 3297),
+    (3302, 3311),
+    (3330, 3331),
+    (3333, 3340),
+    (3342, 3344),
+    (3346, 3368),
+    (3370, 3385),
+    (3390, 3395),
+    (3398, 3400),
+    (3402, 3405),
+    (3415, 3415),
+    (3424, 3425),
+    (3430, 3439),
+    (3585, 3642),
+    (3647, 3675),
+    (3713, 3714),
+    (3716, 3716),
+    (3719, 3720),
+    (3722, 3722),
+    (3725, 3725),
+    (3732, 3735),
+    (3737, 3743),
+    (3745, 3747),
+    (3749, 3749),
+    (3751, 3751),
+    (3754, 3755),
+    (3757, 3769),
+    (3771, 3773),
+    (3776, 3780),
+    (3782, 3782),
+    (3784, 3789),
+    (3792, 3801),
+    (3804, 3805),
+    (4256, 4293),
+    (4304, 4342),
+    (4347, 4347),
+    (4352, 4441),
+    (4447, 4514),
+    (4520, 4601),
+    (7680, 7834),
+    (7840, 7929),
+    (7936, 7957),
+    (7960, 7965),
+    (7968, 8005),
+    (8008, 8013),
+    (8016, 8023),
+    (8025, 8025),
+    (8027, 8027),
+    (8029, 8029),
+    (8031, 8061),
+    (8064, 8116),
+    (8118, 8132),
+    (8134, 8147),
+    (8150, 8155),
+    (8157, 8175),
+    (8178, 8180),
+    (8182, 8190),
+    (8192, 8238),
+    (8240, 8262),
+    (8298, 8304),
+    (8308, 8334),
+    (8352, 8362),
+    (8400, 8417),
+    (8448, 8504),
+    (8531, 8578),
+    (8592, 8682),
+    (8704, 8945),
+    (8960, 8960),
+    (8962, 9082),
+    (9216, 9252),
+    (9280, 9290),
+    (9312, 9450),
+    (9472, 9621),
+    (9632, 9711),
+    (9728, 9747),
+    (9754, 9839),
+    (9985, 9988),
+    (9990, 9993),
+    (9996, 10023),
+    (10025, 10059),
+    (10061, 10061),
+    (10063, 10066),
+    (10070, 10070),
+    (10072, 10078),
+    (10081, 10087),
+    (10102, 10132),
+    (10136, 10159),
+    (10161, 10174),
+    (12288, 12343),
+    (12351, 12351),
+    (12353, 12436),
+    (12441, 12446),
+    (12449, 12542),
+    (12549, 12588),
+    (12593, 12686),
+    (12688, 12703),
+    (12800, 12828),
+    (12832, 12867),
+    (12896, 12923),
+    (12927, 12976),
+    (12992, 13003),
+    (13008, 13054),
+    (13056, 13174),
+    (13179, 13277),
+    (13280, 13310),
+    (19968, 40869),
+    (57344, 64045),
+    (64256, 64262),
+    (64275, 64279),
+    (64286, 64310),
+    (64312, 64316),
+    (64318, 64318),
+    (64320, 64321),
+    (64323, 64324),
+    (64326, 64433),
+    (64467, 64831),
+    (64848, 64911),
+    (64914, 64967),
+    (65008, 65019),
+    (65056, 65059),
+    (65072, 65092),
+    (65097, 65106),
+    (65108, 65126),
+    (65128, 65131),
+    (65136, 65138),
+    (65140, 65140),
+    (65142, 65276),
+    (65279, 65279),
+    (65281, 65374),
+    (65377, 65470),
+    (65474, 65479),
+    (65482, 65487),
+    (65490, 65495),
+    (65498, 65500),
+    (65504, 65510),
+    (65512, 65518),
+    (65533, 65535),
+];
+
+pub static AGE_10_0: [(u32, u32); 40] = [
+    (2144, 2154),
+    (2556, 2557),
+    (2810, 2815),
+    (3328, 3328),
+    (3387, 3388),
+    (7415, 7415),
+    (7670, 7673),
+    (8383, 8383),
+    (9215, 9215),
+    (11218, 11218),
+    (11845, 11849),
+    (12590, 12590),
+    (40918, 40938),
+    (66349, 66351),
+    (72192, 72263),
+    (72272, 72323),
+    (72326, 72348),
+    (72350, 72354),
+    (72960, 72966),
+    (72968, 72969),
+    (72971, 73014),
+    (73018, 73018),
+    (73020, 73021),
+    (73023, 73031),
+    (73040, 73049),
+    (94177, 94177),
+    (110594, 110878),
+    (110960, 111355),
+    (127584, 127589),
+    (128723, 128724),
+    (128759, 128760),
+    (129280, 129291),
+    (129311, 129311),
+    (129320, 129327),
+    (129329, 129330),
+    (129356, 129356),
+    (129375, 129387),
+    (129426, 129431),
+    (129488, 129510),
+    (183984, 191456),
+];
+
+pub static AGE_11_0: [(u32, u32); 59] = [
+    (1376, 1376),
+    (1416, 1416),
+    (1519, 1519),
+    (2045, 2047),
+    (2259, 2259),
+    (2558, 2558),
+    (2678, 2678),
+    (3076, 3076),
+    (3204, 3204),
+    (6264, 6264),
+    (7312, 7354),
+    (7357, 7359),
+    (11194, 11196),
+    (11219, 11243),
+    (11248, 11262),
+    (11850, 11854),
+    (12591, 12591),
+    (40939, 40943),
+    (42927, 42927),
+    (42936, 42937),
+    (43262, 43263),
+    (68148, 68149),
+    (68168, 68168),
+    (68864, 68903),
+    (68912, 68921),
+    (69376, 69415),
+    (69424, 69465),
+    (69837, 69837),
+    (69956, 69958),
+    (70459, 70459),
+    (70750, 70750),
+    (71450, 71450),
+    (71680, 71739),
+    (72349, 72349),
+    (73056, 73061),
+    (73063, 73064),
+    (73066, 73102),
+    (73104, 73105),
+    (73107, 73112),
+    (73120, 73129),
+    (73440, 73464),
+    (93760, 93850),
+    (100333, 100337),
+    (119520, 119539),
+    (119666, 119672),
+    (126065, 126132),
+    (127279, 127279),
+    (128761, 128761),
+    (128981, 128984),
+    (129357, 129359),
+    (129388, 129392),
+    (129395, 129398),
+    (129402, 129402),
+    (129404, 129407),
+    (129432, 129442),
+    (129456, 129465),
+    (129473, 129474),
+    (129511, 129535),
+    (129632, 129645),
+];
+
+pub static AGE_12_0: [(u32, u32); 60] = [
+    (3191, 3191),
+    (3718, 3718),
+    (3721, 3721),
+    (3724, 3724),
+    (3726, 3731),
+    (3736, 3736),
+    (3744, 3744),
+    (3752, 3753),
+    (3756, 3756),
+    (3770, 3770),
+    (7418, 7418),
+    (11209, 11209),
+    (11263, 11263),
+    (11855, 11855),
+    (42938, 42943),
+    (42946, 42950),
+    (43878, 43879),
+    (69600, 69622),
+    (70751, 70751),
+    (71352, 71352),
+    (72096, 72103),
+    (72106, 72151),
+    (72154, 72164),
+    (72324, 72325),
+    (73664, 73713),
+    (73727, 73727),
+    (78896, 78904),
+    (94021, 94026),
+    (94031, 94031),
+    (94079, 94087),
+    (94178, 94179),
+    (100338, 100343),
+    (110928, 110930),
+    (110948, 110951),
+    (123136, 123180),
+    (123184, 123197),
+    (123200, 123209),
+    (123214, 123215),
+    (123584, 123641),
+    (123647, 123647),
+    (125259, 125259),
+    (126209, 126269),
+    (127340, 127340),
+    (128725, 128725),
+    (128762, 128762),
+    (128992, 129003),
+    (129293, 129295),
+    (129343, 129343),
+    (129393, 129393),
+    (129403, 129403),
+    (129445, 129450),
+    (129454, 129455),
+    (129466, 129471),
+    (129475, 129482),
+    (129485, 129487),
+    (129536, 129619),
+    (129648, 129651),
+    (129656, 129658),
+    (129664, 129666),
+    (129680, 129685),
+];
+
+pub static AGE_12_1: [(u32, u32); 1] = [(13055, 13055)];
+
+pub static AGE_13_0: [(u32, u32); 59] = [
+    (2238, 2247),
+    (2901, 2901),
+    (3332, 3332),
+    (3457, 3457),
+    (6847, 6848),
+    (11159, 11159),
+    (11856, 11858),
+    (12731, 12735),
+    (19894, 19903),
+    (40944, 40956),
+    (42951, 42954),
+    (42997, 42998),
+    (43052, 43052),
+    (43880, 43883),
+    (65948, 65948),
+    (69248, 69289),
+    (69291, 69293),
+    (69296, 69297),
+    (69552, 69579),
+    (69959, 69959),
+    (70094, 70095),
+    (70746, 70746),
+    (70752, 70753),
+    (71936, 71942),
+    (71945, 71945),
+    (71948, 71955),
+    (71957, 71958),
+    (71960, 71989),
+    (71991, 71992),
+    (71995, 72006),
+    (72016, 72025),
+    (73648, 73648),
+    (94180, 94180),
+    (94192, 94193),
+    (101107, 101589),
+    (101632, 101640),
+    (127245, 127247),
+    (127341, 127343),
+    (127405, 127405),
+    (128726, 128727),
+    (128763, 128764),
+    (129200, 129201),
+    (129292, 129292),
+    (129394, 129394),
+    (129399, 129400),
+    (129443, 129444),
+    (129451, 129453),
+    (129483, 129483),
+    (129652, 129652),
+    (129667, 129670),
+    (129686, 129704),
+    (129712, 129718),
+    (129728, 129730),
+    (129744, 129750),
+    (129792, 129938),
+    (129940, 129994),
+    (130032, 130041),
+    (173783, 173789),
+    (196608, 201546),
+];
+
+pub static AGE_14_0: [(u32, u32); 78] = [
+    (1565, 1565),
+    (2160, 2190),
+    (2192, 2193),
+    (2200, 2207),
+    (2229, 2229),
+    (2248, 2258),
+    (3132, 3132),
+    (3165, 3165),
+    (3293, 3293),
+    (5901, 5901),
+    (5909, 5909),
+    (5919, 5919),
+    (6159, 6159),
+    (6849, 6862),
+    (6988, 6988),
+    (7037, 7038),
+    (7674, 7674),
+    (8384, 8384),
+    (11311, 11311),
+    (11359, 11359),
+    (11859, 11869),
+    (40957, 40959),
+    (42944, 42945),
+    (42960, 42961),
+    (42963, 42963),
+    (42965, 42969),
+    (42994, 42996),
+    (64450, 64450),
+    (64832, 64847),
+    (64975, 64975),
+    (65022, 65023),
+    (66928, 66938),
+    (66940, 66954),
+    (66956, 66962),
+    (66964, 66965),
+    (66967, 66977),
+    (66979, 66993),
+    (66995, 67001),
+    (67003, 67004),
+    (67456, 67461),
+    (67463, 67504),
+    (67506, 67514),
+    (69488, 69513),
+    (69744, 69749),
+    (69826, 69826),
+    (71353, 71353),
+    (71488, 71494),
+    (72368, 72383),
+    (77712, 77810),
+    (92784, 92862),
+    (92864, 92873),
+    (110576, 110579),
+    (110581, 110587),
+    (110589, 110590),
+    (110879, 110882),
+    (118528, 118573),
+    (118576, 118598),
+    (118608, 118723),
+    (119273, 119274),
+    (122624, 122654),
+    (123536, 123566),
+    (124896, 124902),
+    (124904, 124907),
+    (124909, 124910),
+    (124912, 124926),
+    (128733, 128735),
+    (129008, 129008),
+    (129401, 129401),
+    (129484, 129484),
+    (129659, 129660),
+    (129705, 129708),
+    (129719, 129722),
+    (129731, 129733),
+    (129751, 129753),
+    (129760, 129767),
+    (129776, 129782),
+    (173790, 173791),
+    (177973, 177976),
+];
+
+pub static AGE_15_0: [(u32, u32); 32] = [
+    (3315, 3315),
+    (3790, 3790),
+    (69373, 69375),
+    (70207, 70209),
+    (72448, 72457),
+    (73472, 73488),
+    (73490, 73530),
+    (73534, 73561),
+    (78895, 78895),
+    (78905, 78933),
+    (110898, 110898),
+    (110933, 110933),
+    (119488, 119507),
+    (122661, 122666),
+    (122928, 122989),
+    (123023, 123023),
+    (124112, 124153),
+    (128732, 128732),
+    (128884, 128886),
+    (128891, 128895),
+    (128985, 128985),
+    (129653, 129655),
+    (129671, 129672),
+    (129709, 129711),
+    (129723, 129725),
+    (129727, 129727),
+    (129742, 129743),
+    (129754, 129755),
+    (129768, 129768),
+    (129783, 129784),
+    (177977, 177977),
+    (201552, 205743),
+];
+
+pub static AGE_15_1: [(u32, u32); 3] = [(12284, 12287), (12783, 12783), (191472, 192093)];
+
+pub static AGE_16_0: [(u32, u32); 47] = [
+    (2199, 2199),
+    (6990, 6991),
+    (7039, 7039),
+    (7305, 7306),
+    (9255, 9257),
+    (12772, 12773),
+    (42955, 42957),
+    (42970, 42972),
+    (67008, 67059),
+    (68928, 68965),
+    (68969, 68997),
+    (69006, 69007),
+    (69314, 69316),
+    (69372, 69372),
+    (70528, 70537),
+    (70539, 70539),
+    (70542, 70542),
+    (70544, 70581),
+    (70583, 70592),
+    (70594, 70594),
+    (70597, 70597),
+    (70599, 70602),
+    (70604, 70613),
+    (70615, 70616),
+    (70625, 70626),
+    (71376, 71395),
+    (72640, 72673),
+    (72688, 72697),
+    (73562, 73562),
+    (78944, 82938),
+    (90368, 90425),
+    (93504, 93561),
+    (101631, 101631),
+    (117760, 118009),
+    (118016, 118451),
+    (124368, 124410),
+    (124415, 124415),
+    (129202, 129211),
+    (129216, 129217),
+    (129673, 129673),
+    (129679, 129679),
+    (129726, 129726),
+    (129734, 129734),
+    (129756, 129756),
+    (129759, 129759),
+    (129769, 129769),
+    (129995, 130031),
+];
+
+pub static AGE_2_0: [(u32, u32); 28] = [
+    (1425, 1441),
+    (1443, 1455),
+    (1476, 1476),
+    (3840, 3911),
+    (3913, 3945),
+    (3953, 3979),
+    (3984, 3989),
+    (3991, 3991),
+    (3993, 4013),
+    (4017, 4023),
+    (4025, 4025),
+    (7835, 7835),
+    (8363, 8363),
+    (44032, 55203),
+    (131070, 131071),
+    (196606, 196607),
+    (262142, 262143),
+    (327678, 327679),
+    (393214, 393215),
+    (458750, 458751),
+    (524286, 524287),
+    (589822, 589823),
+    (655358, 655359),
+    (720894, 720895),
+    (786430, 786431),
+    (851966, 851967),
+    (917502, 917503),
+    (983038, 1114111),
+];
+
+pub static AGE_2_1: [(u32, u32); 2] = [(8364, 8364), (65532, 65532)];
+
+pub static AGE_3_0: [(u32, u32); 121] = [
+    (502, 505),
+    (536, 543),
+    (546, 563),
+    (681, 685),
+    (735, 735),
+    (746, 750),
+    (838, 846),
+    (866, 866),
+    (983, 983),
+    (987, 987),
+    (989, 989),
+    (991, 991),
+    (993, 993),
+    (1024, 1024),
+    (1037, 1037),
+    (1104, 1104),
+    (1117, 1117),
+    (1160, 1161),
+    (1164, 1167),
+    (1260, 1261),
+    (1418, 1418),
+    (1619, 1621),
+    (1720, 1721),
+    (1727, 1727),
+    (1743, 1743),
+    (1786, 1790),
+    (1792, 1805),
+    (1807, 1836),
+    (1840, 1866),
+    (1920, 1968),
+    (3458, 3459),
+    (3461, 3478),
+    (3482, 3505),
+    (3507, 3515),
+    (3517, 3517),
+    (3520, 3526),
+    (3530, 3530),
+    (3535, 3540),
+    (3542, 3542),
+    (3544, 3551),
+    (3570, 3572),
+    (3946, 3946),
+    (3990, 3990),
+    (4014, 4016),
+    (4024, 4024),
+    (4026, 4028),
+    (4030, 4044),
+    (4047, 4047),
+    (4096, 4129),
+    (4131, 4135),
+    (4137, 4138),
+    (4140, 4146),
+    (4150, 4153),
+    (4160, 4185),
+    (4608, 4614),
+    (4616, 4678),
+    (4680, 4680),
+    (4682, 4685),
+    (4688, 4694),
+    (4696, 4696),
+    (4698, 4701),
+    (4704, 4742),
+    (4744, 4744),
+    (4746, 4749),
+    (4752, 4782),
+    (4784, 4784),
+    (4786, 4789),
+    (4792, 4798),
+    (4800, 4800),
+    (4802, 4805),
+    (4808, 4814),
+    (4816, 4822),
+    (4824, 4846),
+    (4848, 4878),
+    (4880, 4880),
+    (4882, 4885),
+    (4888, 4894),
+    (4896, 4934),
+    (4936, 4954),
+    (4961, 4988),
+    (5024, 5108),
+    (5121, 5750),
+    (5760, 5788),
+    (5792, 5872),
+    (6016, 6108),
+    (6112, 6121),
+    (6144, 6158),
+    (6160, 6169),
+    (6176, 6263),
+    (6272, 6313),
+    (8239, 8239),
+    (8264, 8269),
+    (8365, 8367),
+    (8418, 8419),
+    (8505, 8506),
+    (8579, 8579),
+    (8683, 8691),
+    (8961, 8961),
+    (9083, 9083),
+    (9085, 9114),
+    (9253, 9254),
+    (9712, 9719),
+    (9753, 9753),
+    (9840, 9841),
+    (10240, 10495),
+    (11904, 11929),
+    (11931, 12019),
+    (12032, 12245),
+    (12272, 12283),
+    (12344, 12346),
+    (12350, 12350),
+    (12704, 12727),
+    (13312, 19893),
+    (40960, 42124),
+    (42128, 42145),
+    (42148, 42163),
+    (42165, 42176),
+    (42178, 42180),
+    (42182, 42182),
+    (64285, 64285),
+    (65529, 65531),
+];
+
+pub static AGE_3_1: [(u32, u32); 36] = [
+    (1012, 1013),
+    (64976, 65007),
+    (66304, 66334),
+    (66336, 66339),
+    (66352, 66378),
+    (66560, 66597),
+    (66600, 66637),
+    (118784, 119029),
+    (119040, 119078),
+    (119082, 119261),
+    (119808, 119892),
+    (119894, 119964),
+    (119966, 119967),
+    (119970, 119970),
+    (119973, 119974),
+    (119977, 119980),
+    (119982, 119993),
+    (119995, 119995),
+    (119997, 120000),
+    (120002, 120003),
+    (120005, 120069),
+    (120071, 120074),
+    (120077, 120084),
+    (120086, 120092),
+    (120094, 120121),
+    (120123, 120126),
+    (120128, 120132),
+    (120134, 120134),
+    (120138, 120144),
+    (120146, 120483),
+    (120488, 120777),
+    (120782, 120831),
+    (131072, 173782),
+    (194560, 195101),
+    (917505, 917505),
+    (917536, 917631),
+];
+
+pub static AGE_3_2: [(u32, u32); 59] = [
+    (544, 544),
+    (847, 847),
+    (867, 879),
+    (984, 985),
+    (1014, 1014),
+    (1162, 1163),
+    (1221, 1222),
+    (1225, 1226),
+    (1229, 1230),
+    (1280, 1295),
+    (1646, 1647),
+    (1969, 1969),
+    (4343, 4344),
+    (5888, 5900),
+    (5902, 5908),
+    (5920, 5942),
+    (5952, 5971),
+    (5984, 5996),
+    (5998, 6000),
+    (6002, 6003),
+    (8263, 8263),
+    (8270, 8274),
+    (8279, 8279),
+    (8287, 8291),
+    (8305, 8305),
+    (8368, 8369),
+    (8420, 8426),
+    (8509, 8523),
+    (8692, 8703),
+    (8946, 8959),
+    (9084, 9084),
+    (9115, 9166),
+    (9451, 9470),
+    (9622, 9631),
+    (9720, 9727),
+    (9750, 9751),
+    (9842, 9853),
+    (9856, 9865),
+    (10088, 10101),
+    (10192, 10219),
+    (10224, 10239),
+    (10496, 11007),
+    (12347, 12349),
+    (12437, 12438),
+    (12447, 12448),
+    (12543, 12543),
+    (12784, 12799),
+    (12881, 12895),
+    (12977, 12991),
+    (42146, 42147),
+    (42164, 42164),
+    (42177, 42177),
+    (42181, 42181),
+    (64048, 64106),
+    (65020, 65020),
+    (65024, 65039),
+    (65093, 65094),
+    (65139, 65139),
+    (65375, 65376),
+];
+
+pub static AGE_4_0: [(u32, u32); 77] = [
+    (545, 545),
+    (564, 566),
+    (686, 687),
+    (751, 767),
+    (848, 855),
+    (861, 863),
+    (1015, 1019),
+    (1536, 1539),
+    (1549, 1557),
+    (1622, 1624),
+    (1774, 1775),
+    (1791, 1791),
+    (1837, 1839),
+    (1869, 1871),
+    (2308, 2308),
+    (2493, 2493),
+    (2561, 2561),
+    (2563, 2563),
+    (2700, 2700),
+    (2785, 2787),
+    (2801, 2801),
+    (2869, 2869),
+    (2929, 2929),
+    (3059, 3066),
+    (3260, 3261),
+    (6109, 6109),
+    (6128, 6137),
+    (6400, 6428),
+    (6432, 6443),
+    (6448, 6459),
+    (6464, 6464),
+    (6468, 6509),
+    (6512, 6516),
+    (6624, 6655),
+    (7424, 7531),
+    (8275, 8276),
+    (8507, 8507),
+    (9167, 9168),
+    (9471, 9471),
+    (9748, 9749),
+    (9866, 9873),
+    (9888, 9889),
+    (11008, 11021),
+    (12829, 12830),
+    (12880, 12880),
+    (12924, 12925),
+    (13004, 13007),
+    (13175, 13178),
+    (13278, 13279),
+    (13311, 13311),
+    (19904, 19967),
+    (65021, 65021),
+    (65095, 65096),
+    (65536, 65547),
+    (65549, 65574),
+    (65576, 65594),
+    (65596, 65597),
+    (65599, 65613),
+    (65616, 65629),
+    (65664, 65786),
+    (65792, 65794),
+    (65799, 65843),
+    (65847, 65855),
+    (66432, 66461),
+    (66463, 66463),
+    (66598, 66599),
+    (66638, 66717),
+    (66720, 66729),
+    (67584, 67589),
+    (67592, 67592),
+    (67594, 67637),
+    (67639, 67640),
+    (67644, 67644),
+    (67647, 67647),
+    (119552, 119638),
+    (120001, 120001),
+    (917760, 917999),
+];
+
+pub static AGE_4_1: [(u32, u32); 86] = [
+    (567, 577),
+    (856, 860),
+    (1020, 1023),
+    (1270, 1271),
+    (1442, 1442),
+    (1477, 1479),
+    (1547, 1547),
+    (1566, 1566),
+    (1625, 1630),
+    (1872, 1901),
+    (2429, 2429),
+    (2510, 2510),
+    (2998, 2998),
+    (3046, 3046),
+    (4048, 4049),
+    (4345, 4346),
+    (4348, 4348),
+    (4615, 4615),
+    (4679, 4679),
+    (4743, 4743),
+    (4783, 4783),
+    (4815, 4815),
+    (4847, 4847),
+    (4879, 4879),
+    (4895, 4895),
+    (4935, 4935),
+    (4959, 4960),
+    (4992, 5017),
+    (6528, 6569),
+    (6576, 6601),
+    (6608, 6617),
+    (6622, 6623),
+    (6656, 6683),
+    (6686, 6687),
+    (7532, 7619),
+    (8277, 8278),
+    (8280, 8286),
+    (8336, 8340),
+    (8370, 8373),
+    (8427, 8427),
+    (8508, 8508),
+    (8524, 8524),
+    (9169, 9179),
+    (9752, 9752),
+    (9854, 9855),
+    (9874, 9884),
+    (9890, 9905),
+    (10176, 10182),
+    (11022, 11027),
+    (11264, 11310),
+    (11312, 11358),
+    (11392, 11498),
+    (11513, 11557),
+    (11568, 11621),
+    (11631, 11631),
+    (11648, 11670),
+    (11680, 11686),
+    (11688, 11694),
+    (11696, 11702),
+    (11704, 11710),
+    (11712, 11718),
+    (11720, 11726),
+    (11728, 11734),
+    (11736, 11742),
+    (11776, 11799),
+    (11804, 11805),
+    (12736, 12751),
+    (12926, 12926),
+    (40870, 40891),
+    (42752, 42774),
+    (43008, 43051),
+    (64112, 64217),
+    (65040, 65049),
+    (65856, 65930),
+    (66464, 66499),
+    (66504, 66517),
+    (68096, 68099),
+    (68101, 68102),
+    (68108, 68115),
+    (68117, 68119),
+    (68121, 68147),
+    (68152, 68154),
+    (68159, 68167),
+    (68176, 68184),
+    (119296, 119365),
+    (120484, 120485),
+];
+
+pub static AGE_5_0: [(u32, u32); 35] = [
+    (578, 591),
+    (891, 893),
+    (1231, 1231),
+    (1274, 1279),
+    (1296, 1299),
+    (1466, 1466),
+    (1984, 2042),
+    (2427, 2428),
+    (2430, 2431),
+    (3298, 3299),
+    (3313, 3314),
+    (6912, 6987),
+    (6992, 7036),
+    (7620, 7626),
+    (7678, 7679),
+    (8428, 8431),
+    (8525, 8526),
+    (8580, 8580),
+    (9180, 9191),
+    (9906, 9906),
+    (10183, 10186),
+    (11028, 11034),
+    (11040, 11043),
+    (11360, 11372),
+    (11380, 11383),
+    (42775, 42778),
+    (42784, 42785),
+    (43072, 43127),
+    (67840, 67865),
+    (67871, 67871),
+    (73728, 74606),
+    (74752, 74850),
+    (74864, 74867),
+    (119648, 119665),
+    (120778, 120779),
+];
+
+pub static AGE_5_1: [(u32, u32); 89] = [
+    (880, 883),
+    (886, 887),
+    (975, 975),
+    (1159, 1159),
+    (1300, 1315),
+    (1542, 1546),
+    (1558, 1562),
+    (1595, 1599),
+    (1902, 1919),
+    (2417, 2418),
+    (2641, 2641),
+    (2677, 2677),
+    (2884, 2884),
+    (2914, 2915),
+    (3024, 3024),
+    (3133, 3133),
+    (3160, 3161),
+    (3170, 3171),
+    (3192, 3199),
+    (3389, 3389),
+    (3396, 3396),
+    (3426, 3427),
+    (3440, 3445),
+    (3449, 3455),
+    (3947, 3948),
+    (4046, 4046),
+    (4050, 4052),
+    (4130, 4130),
+    (4136, 4136),
+    (4139, 4139),
+    (4147, 4149),
+    (4154, 4159),
+    (4186, 4249),
+    (4254, 4255),
+    (6314, 6314),
+    (7040, 7082),
+    (7086, 7097),
+    (7168, 7223),
+    (7227, 7241),
+    (7245, 7295),
+    (7627, 7654),
+    (7836, 7839),
+    (7930, 7935),
+    (8292, 8292),
+    (8432, 8432),
+    (8527, 8527),
+    (8581, 8584),
+    (9885, 9885),
+    (9907, 9916),
+    (9920, 9923),
+    (10188, 10188),
+    (10220, 10223),
+    (11035, 11039),
+    (11044, 11084),
+    (11088, 11092),
+    (11373, 11375),
+    (11377, 11379),
+    (11384, 11389),
+    (11744, 11775),
+    (11800, 11803),
+    (11806, 11824),
+    (12589, 12589),
+    (12752, 12771),
+    (40892, 40899),
+    (42240, 42539),
+    (42560, 42591),
+    (42594, 42611),
+    (42620, 42647),
+    (42779, 42783),
+    (42786, 42892),
+    (43003, 43007),
+    (43136, 43204),
+    (43214, 43225),
+    (43264, 43347),
+    (43359, 43359),
+    (43520, 43574),
+    (43584, 43597),
+    (43600, 43609),
+    (43612, 43615),
+    (65060, 65062),
+    (65936, 65947),
+    (66000, 66045),
+    (66176, 66204),
+    (66208, 66256),
+    (67872, 67897),
+    (67903, 67903),
+    (119081, 119081),
+    (126976, 127019),
+    (127024, 127123),
+];
+
+pub static AGE_5_2: [(u32, u32); 90] = [
+    (1316, 1317),
+    (2048, 2093),
+    (2096, 2110),
+    (2304, 2304),
+    (2382, 2382),
+    (2389, 2389),
+    (2425, 2426),
+    (2555, 2555),
+    (4053, 4056),
+    (4250, 4253),
+    (4442, 4446),
+    (4515, 4519),
+    (4602, 4607),
+    (5120, 5120),
+    (5751, 5759),
+    (6320, 6389),
+    (6570, 6571),
+    (6618, 6618),
+    (6688, 6750),
+    (6752, 6780),
+    (6783, 6793),
+    (6800, 6809),
+    (6816, 6829),
+    (7376, 7410),
+    (7677, 7677),
+    (8374, 8376),
+    (8528, 8530),
+    (8585, 8585),
+    (9192, 9192),
+    (9886, 9887),
+    (9917, 9919),
+    (9924, 9933),
+    (9935, 9953),
+    (9955, 9955),
+    (9960, 9983),
+    (10071, 10071),
+    (11093, 11097),
+    (11376, 11376),
+    (11390, 11391),
+    (11499, 11505),
+    (11825, 11825),
+    (12868, 12879),
+    (40900, 40907),
+    (42192, 42239),
+    (42656, 42743),
+    (43056, 43065),
+    (43232, 43259),
+    (43360, 43388),
+    (43392, 43469),
+    (43471, 43481),
+    (43486, 43487),
+    (43616, 43643),
+    (43648, 43714),
+    (43739, 43743),
+    (43968, 44013),
+    (44016, 44025),
+    (55216, 55238),
+    (55243, 55291),
+    (64107, 64109),
+    (67648, 67669),
+    (67671, 67679),
+    (67866, 67867),
+    (68192, 68223),
+    (68352, 68405),
+    (68409, 68437),
+    (68440, 68466),
+    (68472, 68479),
+    (68608, 68680),
+    (69216, 69246),
+    (69760, 69825),
+    (77824, 78894),
+    (127232, 127242),
+    (127248, 127278),
+    (127281, 127281),
+    (127293, 127293),
+    (127295, 127295),
+    (127298, 127298),
+    (127302, 127302),
+    (127306, 127310),
+    (127319, 127319),
+    (127327, 127327),
+    (127353, 127353),
+    (127355, 127356),
+    (127359, 127359),
+    (127370, 127373),
+    (127376, 127376),
+    (127488, 127488),
+    (127504, 127537),
+    (127552, 127560),
+    (173824, 177972),
+];
+
+pub static AGE_6_0: [(u32, u32); 106] = [
+    (1318, 1319),
+    (1568, 1568),
+    (1631, 1631),
+    (2112, 2139),
+    (2142, 2142),
+    (2362, 2363),
+    (2383, 2383),
+    (2390, 2391),
+    (2419, 2423),
+    (2930, 2935),
+    (3369, 3369),
+    (3386, 3386),
+    (3406, 3406),
+    (3980, 3983),
+    (4057, 4058),
+    (4957, 4958),
+    (7104, 7155),
+    (7164, 7167),
+    (7676, 7676),
+    (8341, 8348),
+    (8377, 8377),
+    (9193, 9203),
+    (9934, 9934),
+    (9954, 9954),
+    (9956, 9959),
+    (9989, 9989),
+    (9994, 9995),
+    (10024, 10024),
+    (10060, 10060),
+    (10062, 10062),
+    (10067, 10069),
+    (10079, 10080),
+    (10133, 10135),
+    (10160, 10160),
+    (10175, 10175),
+    (10190, 10191),
+    (11632, 11632),
+    (11647, 11647),
+    (12728, 12730),
+    (42592, 42593),
+    (42893, 42894),
+    (42896, 42897),
+    (42912, 42921),
+    (43002, 43002),
+    (43777, 43782),
+    (43785, 43790),
+    (43793, 43798),
+    (43808, 43814),
+    (43816, 43822),
+    (64434, 64449),
+    (69632, 69709),
+    (69714, 69743),
+    (92160, 92728),
+    (110592, 110593),
+    (127136, 127150),
+    (127153, 127166),
+    (127169, 127183),
+    (127185, 127199),
+    (127280, 127280),
+    (127282, 127292),
+    (127294, 127294),
+    (127296, 127297),
+    (127299, 127301),
+    (127303, 127305),
+    (127311, 127318),
+    (127320, 127326),
+    (127328, 127337),
+    (127344, 127352),
+    (127354, 127354),
+    (127357, 127358),
+    (127360, 127369),
+    (127374, 127375),
+    (127377, 127386),
+    (127462, 127487),
+    (127489, 127490),
+    (127538, 127546),
+    (127568, 127569),
+    (127744, 127776),
+    (127792, 127797),
+    (127799, 127868),
+    (127872, 127891),
+    (127904, 127940),
+    (127942, 127946),
+    (127968, 127984),
+    (128000, 128062),
+    (128064, 128064),
+    (128066, 128247),
+    (128249, 128252),
+    (128256, 128317),
+    (128336, 128359),
+    (128507, 128511),
+    (128513, 128528),
+    (128530, 128532),
+    (128534, 128534),
+    (128536, 128536),
+    (128538, 128538),
+    (128540, 128542),
+    (128544, 128549),
+    (128552, 128555),
+    (128557, 128557),
+    (128560, 128563),
+    (128565, 128576),
+    (128581, 128591),
+    (128640, 128709),
+    (128768, 128883),
+    (177984, 178205),
+];
+
+pub static AGE_6_1: [(u32, u32); 89] = [
+    (1423, 1423),
+    (1540, 1540),
+    (2208, 2208),
+    (2210, 2220),
+    (2276, 2302),
+    (2800, 2800),
+    (3806, 3807),
+    (4295, 4295),
+    (4301, 4301),
+    (4349, 4351),
+    (7083, 7085),
+    (7098, 7103),
+    (7360, 7367),
+    (7411, 7414),
+    (10187, 10187),
+    (10189, 10189),
+    (11506, 11507),
+    (11559, 11559),
+    (11565, 11565),
+    (11622, 11623),
+    (11826, 11835),
+    (40908, 40908),
+    (42612, 42619),
+    (42655, 42655),
+    (42898, 42899),
+    (42922, 42922),
+    (43000, 43001),
+    (43744, 43766),
+    (64046, 64047),
+    (67968, 68023),
+    (68030, 68031),
+    (69840, 69864),
+    (69872, 69881),
+    (69888, 69940),
+    (69942, 69955),
+    (70016, 70088),
+    (70096, 70105),
+    (71296, 71351),
+    (71360, 71369),
+    (93952, 94020),
+    (94032, 94078),
+    (94095, 94111),
+    (126464, 126467),
+    (126469, 126495),
+    (126497, 126498),
+    (126500, 126500),
+    (126503, 126503),
+    (126505, 126514),
+    (126516, 126519),
+    (126521, 126521),
+    (126523, 126523),
+    (126530, 126530),
+    (126535, 126535),
+    (126537, 126537),
+    (126539, 126539),
+    (126541, 126543),
+    (126545, 126546),
+    (126548, 126548),
+    (126551, 126551),
+    (126553, 126553),
+    (126555, 126555),
+    (126557, 126557),
+    (126559, 126559),
+    (126561, 126562),
+    (126564, 126564),
+    (126567, 126570),
+    (126572, 126578),
+    (126580, 126583),
+    (126585, 126588),
+    (126590, 126590),
+    (126592, 126601),
+    (126603, 126619),
+    (126625, 126627),
+    (126629, 126633),
+    (126635, 126651),
+    (126704, 126705),
+    (127338, 127339),
+    (128320, 128323),
+    (128512, 128512),
+    (128529, 128529),
+    (128533, 128533),
+    (128535, 128535),
+    (128537, 128537),
+    (128539, 128539),
+    (128543, 128543),
+    (128550, 128551),
+    (128556, 128556),
+    (128558, 128559),
+    (128564, 128564),
+];
+
+pub static AGE_6_2: [(u32, u32); 1] = [(8378, 8378)];
+
+pub static AGE_6_3: [(u32, u32); 2] = [(1564, 1564), (8294, 8297)];
+
+pub static AGE_7_0: [(u32, u32); 141] = [
+    (895, 895),
+    (1320, 1327),
+    (1421, 1422),
+    (1541, 1541),
+    (2209, 2209),
+    (2221, 2226),
+    (2303, 2303),
+    (2424, 2424),
+    (2432, 2432),
+    (3072, 3072),
+    (3124, 3124),
+    (3201, 3201),
+    (3329, 3329),
+    (3558, 3567),
+    (5873, 5880),
+    (6429, 6430),
+    (6832, 6846),
+    (7416, 7417),
+    (7655, 7669),
+    (8379, 8381),
+    (9204, 9210),
+    (9984, 9984),
+    (11085, 11087),
+    (11098, 11123),
+    (11126, 11157),
+    (11160, 11193),
+    (11197, 11208),
+    (11210, 11217),
+    (11836, 11842),
+    (42648, 42653),
+    (42900, 42911),
+    (42923, 42925),
+    (42928, 42929),
+    (42999, 42999),
+    (43488, 43518),
+    (43644, 43647),
+    (43824, 43871),
+    (43876, 43877),
+    (65063, 65069),
+    (65931, 65932),
+    (65952, 65952),
+    (66272, 66299),
+    (66335, 66335),
+    (66384, 66426),
+    (66816, 66855),
+    (66864, 66915),
+    (66927, 66927),
+    (67072, 67382),
+    (67392, 67413),
+    (67424, 67431),
+    (67680, 67742),
+    (67751, 67759),
+    (68224, 68255),
+    (68288, 68326),
+    (68331, 68342),
+    (68480, 68497),
+    (68505, 68508),
+    (68521, 68527),
+    (69759, 69759),
+    (69968, 70006),
+    (70093, 70093),
+    (70106, 70106),
+    (70113, 70132),
+    (70144, 70161),
+    (70163, 70205),
+    (70320, 70378),
+    (70384, 70393),
+    (70401, 70403),
+    (70405, 70412),
+    (70415, 70416),
+    (70419, 70440),
+    (70442, 70448),
+    (70450, 70451),
+    (70453, 70457),
+    (70460, 70468),
+    (70471, 70472),
+    (70475, 70477),
+    (70487, 70487),
+    (70493, 70499),
+    (70502, 70508),
+    (70512, 70516),
+    (70784, 70855),
+    (70864, 70873),
+    (71040, 71093),
+    (71096, 71113),
+    (71168, 71236),
+    (71248, 71257),
+    (71840, 71922),
+    (71935, 71935),
+    (72384, 72440),
+    (74607, 74648),
+    (74851, 74862),
+    (74868, 74868),
+    (92736, 92766),
+    (92768, 92777),
+    (92782, 92783),
+    (92880, 92909),
+    (92912, 92917),
+    (92928, 92997),
+    (93008, 93017),
+    (93019, 93025),
+    (93027, 93047),
+    (93053, 93071),
+    (113664, 113770),
+    (113776, 113788),
+    (113792, 113800),
+    (113808, 113817),
+    (113820, 113827),
+    (124928, 125124),
+    (125127, 125142),
+    (127167, 127167),
+    (127200, 127221),
+    (127243, 127244),
+    (127777, 127788),
+    (127798, 127798),
+    (127869, 127869),
+    (127892, 127903),
+    (127941, 127941),
+    (127947, 127950),
+    (127956, 127967),
+    (127985, 127991),
+    (128063, 128063),
+    (128065, 128065),
+    (128248, 128248),
+    (128253, 128254),
+    (128318, 128319),
+    (128324, 128330),
+    (128360, 128377),
+    (128379, 128419),
+    (128421, 128506),
+    (128577, 128578),
+    (128592, 128639),
+    (128710, 128719),
+    (128736, 128748),
+    (128752, 128755),
+    (128896, 128980),
+    (129024, 129035),
+    (129040, 129095),
+    (129104, 129113),
+    (129120, 129159),
+    (129168, 129197),
+];
+
+pub static AGE_8_0: [(u32, u32); 59] = [
+    (2227, 2228),
+    (2275, 2275),
+    (2809, 2809),
+    (3162, 3162),
+    (3423, 3423),
+    (5109, 5109),
+    (5112, 5117),
+    (8382, 8382),
+    (8586, 8587),
+    (11244, 11247),
+    (40909, 40917),
+    (42654, 42654),
+    (42895, 42895),
+    (42930, 42935),
+    (43260, 43261),
+    (43872, 43875),
+    (43888, 43967),
+    (65070, 65071),
+    (67808, 67826),
+    (67828, 67829),
+    (67835, 67839),
+    (68028, 68029),
+    (68032, 68047),
+    (68050, 68095),
+    (68736, 68786),
+    (68800, 68850),
+    (68858, 68863),
+    (70089, 70092),
+    (70107, 70111),
+    (70272, 70278),
+    (70280, 70280),
+    (70282, 70285),
+    (70287, 70301),
+    (70303, 70313),
+    (70400, 70400),
+    (70480, 70480),
+    (71114, 71133),
+    (71424, 71449),
+    (71453, 71467),
+    (71472, 71487),
+    (74649, 74649),
+    (74880, 75075),
+    (82944, 83526),
+    (119262, 119272),
+    (120832, 121483),
+    (121499, 121503),
+    (121505, 121519),
+    (127789, 127791),
+    (127870, 127871),
+    (127951, 127955),
+    (127992, 127999),
+    (128255, 128255),
+    (128331, 128335),
+    (128579, 128580),
+    (128720, 128720),
+    (129296, 129304),
+    (129408, 129412),
+    (129472, 129472),
+    (178208, 183969),
+];
+
+pub static AGE_9_0: [(u32, u32); 52] = [
+    (2230, 2237),
+    (2260, 2274),
+    (3200, 3200),
+    (3407, 3407),
+    (3412, 3414),
+    (3416, 3422),
+    (3446, 3448),
+    (7296, 7304),
+    (7675, 7675),
+    (9211, 9214),
+    (11843, 11844),
+    (42926, 42926),
+    (43205, 43205),
+    (65933, 65934),
+    (66736, 66771),
+    (66776, 66811),
+    (70206, 70206),
+    (70656, 70745),
+    (70747, 70747),
+    (70749, 70749),
+    (71264, 71276),
+    (72704, 72712),
+    (72714, 72758),
+    (72760, 72773),
+    (72784, 72812),
+    (72816, 72847),
+    (72850, 72871),
+    (72873, 72886),
+    (94176, 94176),
+    (94208, 100332),
+    (100352, 101106),
+    (122880, 122886),
+    (122888, 122904),
+    (122907, 122913),
+    (122915, 122916),
+    (122918, 122922),
+    (125184, 125258),
+    (125264, 125273),
+    (125278, 125279),
+    (127387, 127404),
+    (127547, 127547),
+    (128378, 128378),
+    (128420, 128420),
+    (128721, 128722),
+    (128756, 128758),
+    (129305, 129310),
+    (129312, 129319),
+    (129328, 129328),
+    (129331, 129342),
+    (129344, 129355),
+    (129360, 129374),
+    (129413, 129425),
+];
+
+pub static AGES: [(&str, &[(u32, u32)]); 27] = [
+    ("1.1", &AGE_1_1),
+    ("10.0", &AGE_10_0),
+    ("11.0", &AGE_11_0),
+    ("12.0", &AGE_12_0),
+    ("12.1", &AGE_12_1),
+    ("13.0", &AGE_13_0),
+    ("14.0", &AGE_14_0),
+    ("15.0", &AGE_15_0),
+    ("15.1", &AGE_15_1),
+    ("16.0", &AGE_16_0),
+    ("2.0", &AGE_2_0),
+    ("2.1", &AGE_2_1),
+    ("3.0", &AGE_3_0),
+    ("3.1", &AGE_3_1),
+    ("3.2", &AGE_3_2),
+    ("4.0", &AGE_4_0),
+    ("4.1", &AGE_4_1),
+    ("5.0", &AGE_5_0),
+    ("5.1", &AGE_5_1),
+    ("5.2", &AGE_5_2),
+    ("6.0", &AGE_6_0),
+    ("6.1", &AGE_6_1),
+    ("6.2", &AGE_6_2),
+    ("6.3", &AGE_6_3),
+    ("7.0", &AGE_7_0),
+    ("8.0", &AGE_8_0),
+    ("9.0", &AGE_9_0),
+];
+
+pub static GCB_CR: [(u32, u32); 1] = [(13, 13)];
+
+pub static GCB_CONTROL: [(u32, u32); 19] = [
+    (0, 9),
+    (11, 12),
+    (14, 31),
+    (127, 159),
+    (173, 173),
+    (1564, 1564),
+    (6158, 6158),
+    (8203, 8203),
+    (8206, 8207),
+    (8232, 8238),
+    (8288, 8303),
+    (65279, 65279),
+    (65520, 65531),
+    (78896, 78911),
+    (113824, 113827),
+    (119155, 119162),
+    (917504, 917535),
+    (917632, 917759),
+    (918000, 921599),
+];
+
+pub static GCB_EXTEND: [(u32, u32); 376] = [
+    (768, 879),
+    (1155, 1161),
+    (1425, 1469),
+    (1471, 1471),
+    (1473, 1474),
+    (1476, 1477),
+    (1479, 1479),
+    (1552, 1562),
+    (1611, 1631),
+    (1648, 1648),
+    (1750, 1756),
+    (1759, 1764),
+    (1767, 1768),
+    (1770, 1773),
+    (1809, 1809),
+    (1840, 1866),
+    (1958, 1968),
+    (2027, 2035),
+    (2045, 2045),
+    (2070, 2073),
+    (2075, 2083),
+    (2085, 2087),
+    (2089, 2093),
+    (2137, 2139),
+    (2199, 2207),
+    (2250, 2273),
+    (2275, 2306),
+    (2362, 2362),
+    (2364, 2364),
+    (2369, 2376),
+    (2381, 2381),
+    (2385, 2391),
+    (2402, 2403),
+    (2433, 2433),
+    (2492, 2492),
+    (2494, 2494),
+    (2497, 2500),
+    (2509, 2509),
+    (2519, 2519),
+    (2530, 2531),
+    (2558, 2558),
+    (2561, 2562),
+    (2620, 2620),
+    (2625, 2626),
+    (2631, 2632),
+    (2635, 2637),
+    (2641, 2641),
+    (2672, 2673),
+    (2677, 2677),
+    (2689, 2690),
+    (2748, 2748),
+    (2753, 2757),
+    (2759, 2760),
+    (2765, 2765),
+    (2786, 2787),
+    (2810, 2815),
+    (2817, 2817),
+    (2876, 2876),
+    (2878, 2879),
+    (2881, 2884),
+    (2893, 2893),
+    (2901, 2903),
+    (2914, 2915),
+    (2946, 2946),
+    (3006, 3006),
+    (3008, 3008),
+    (3021, 3021),
+    (3031, 3031),
+    (3072, 3072),
+    (3076, 3076),
+    (3132, 3132),
+    (3134, 3136),
+    (3142, 3144),
+    (3146, 3149),
+    (3157, 3158),
+    (3170, 3171),
+    (3201, 3201),
+    (3260, 3260),
+    (3263, 3264),
+    (3266, 3266),
+    (3270, 3272),
+    (3274, 3277),
+    (3285, 3286),
+    (3298, 3299),
+    (3328, 3329),
+    (3387, 3388),
+    (3390, 3390),
+    (3393, 3396),
+    (3405, 3405),
+    (3415, 3415),
+    (3426, 3427),
+    (3457, 3457),
+    (3530, 3530),
+    (3535, 3535),
+    (3538, 3540),
+    (3542, 3542),
+    (3551, 3551),
+    (3633, 3633),
+    (3636, 3642),
+    (3655, 3662),
+    (3761, 3761),
+    (3764, 3772),
+    (3784, 3790),
+    (3864, 3865),
+    (3893, 3893),
+    (3895, 3895),
+    (3897, 3897),
+    (3953, 3966),
+    (3968, 3972),
+    (3974, 3975),
+    (3981, 3991),
+    (3993, 4028),
+    (4038, 4038),
+    (4141, 4144),
+    (4146, 4151),
+    (4153, 4154),
+    (4157, 4158),
+    (4184, 4185),
+    (4190, 4192),
+    (4209, 4212),
+    (4226, 4226),
+    (4229, 4230),
+    (4237, 4237),
+    (4253, 4253),
+    (4957, 4959),
+    (5906, 5909),
+    (5938, 5940),
+    (5970, 5971),
+    (6002, 6003),
+    (6068, 6069),
+    (6071, 6077),
+    (6086, 6086),
+    (6089, 6099),
+    (6109, 6109),
+    (6155, 6157),
+    (6159, 6159),
+    (6277, 6278),
+    (6313, 6313),
+    (6432, 6434),
+    (6439, 6440),
+    (6450, 6450),
+    (6457, 6459),
+    (6679, 6680),
+    (6683, 6683),
+    (6742, 6742),
+    (6744, 6750),
+    (6752, 6752),
+    (6754, 6754),
+    (6757, 6764),
+    (6771, 6780),
+    (6783, 6783),
+    (6832, 6862),
+    (6912, 6915),
+    (6964, 6973),
+    (6978, 6980),
+    (7019, 7027),
+    (7040, 7041),
+    (7074, 7077),
+    (7080, 7085),
+    (7142, 7142),
+    (7144, 7145),
+    (7149, 7149),
+    (7151, 7155),
+    (7212, 7219),
+    (7222, 7223),
+    (7376, 7378),
+    (7380, 7392),
+    (7394, 7400),
+    (7405, 7405),
+    (7412, 7412),
+    (7416, 7417),
+    (7616, 7679),
+    (8204, 8204),
+    (8400, 8432),
+    (11503, 11505),
+    (11647, 11647),
+    (11744, 11775),
+    (12330, 12335),
+    (12441, 12442),
+    (42607, 42610),
+    (42612, 42621),
+    (42654, 42655),
+    (42736, 42737),
+    (43010, 43010),
+    (43014, 43014),
+    (43019, 43019),
+    (43045, 43046),
+    (43052, 43052),
+    (43204, 43205),
+    (43232, 43249),
+    (43263, 43263),
+    (43302, 43309),
+    (43335, 43345),
+    (43347, 43347),
+    (43392, 43394),
+    (43443, 43443),
+    (43446, 43449),
+    (43452, 43453),
+    (43456, 43456),
+    (43493, 43493),
+    (43561, 43566),
+    (43569, 43570),
+    (43573, 43574),
+    (43587, 43587),
+    (43596, 43596),
+    (43644, 43644),
+    (43696, 43696),
+    (43698, 43700),
+    (43703, 43704),
+    (43710, 43711),
+    (43713, 43713),
+    (43756, 43757),
+    (43766, 43766),
+    (44005, 44005),
+    (44008, 44008),
+    (44013, 44013),
+    (64286, 64286),
+    (65024, 65039),
+    (65056, 65071),
+    (65438, 65439),
+    (66045, 66045),
+    (66272, 66272),
+    (66422, 66426),
+    (68097, 68099),
+    (68101, 68102),
+    (68108, 68111),
+    (68152, 68154),
+    (68159, 68159),
+    (68325, 68326),
+    (68900, 68903),
+    (68969, 68973),
+    (69291, 69292),
+    (69372, 69375),
+    (69446, 69456),
+    (69506, 69509),
+    (69633, 69633),
+    (69688, 69702),
+    (69744, 69744),
+    (69747, 69748),
+    (69759, 69761),
+    (69811, 69814),
+    (69817, 69818),
+    (69826, 69826),
+    (69888, 69890),
+    (69927, 69931),
+    (69933, 69940),
+    (70003, 70003),
+    (70016, 70017),
+    (70070, 70078),
+    (70080, 70080),
+    (70089, 70092),
+    (70095, 70095),
+    (70191, 70193),
+    (70196, 70199),
+    (70206, 70206),
+    (70209, 70209),
+    (70367, 70367),
+    (70371, 70378),
+    (70400, 70401),
+    (70459, 70460),
+    (70462, 70462),
+    (70464, 70464),
+    (70477, 70477),
+    (70487, 70487),
+    (70502, 70508),
+    (70512, 70516),
+    (70584, 70584),
+    (70587, 70592),
+    (70594, 70594),
+    (70597, 70597),
+    (70599, 70601),
+    (70606, 70608),
+    (70610, 70610),
+    (70625, 70626),
+    (70712, 70719),
+    (70722, 70724),
+    (70726, 70726),
+    (70750, 70750),
+    (70832, 70832),
+    (70835, 70840),
+    (70842, 70842),
+    (70845, 70845),
+    (70847, 70848),
+    (70850, 70851),
+    (71087, 71087),
+    (71090, 71093),
+    (71100, 71101),
+    (71103, 71104),
+    (71132, 71133),
+    (71219, 71226),
+    (71229, 71229),
+    (71231, 71232),
+    (71339, 71339),
+    (71341, 71341),
+    (71344, 71351),
+    (71453, 71453),
+    (71455, 71455),
+    (71458, 71461),
+    (71463, 71467),
+    (71727, 71735),
+    (71737, 71738),
+    (71984, 71984),
+    (71995, 71998),
+    (72003, 72003),
+    (72148, 72151),
+    (72154, 72155),
+    (72160, 72160),
+    (72193, 72202),
+    (72243, 72248),
+    (72251, 72254),
+    (72263, 72263),
+    (72273, 72278),
+    (72281, 72283),
+    (72330, 72342),
+    (72344, 72345),
+    (72752, 72758),
+    (72760, 72765),
+    (72767, 72767),
+    (72850, 72871),
+    (72874, 72880),
+    (72882, 72883),
+    (72885, 72886),
+    (73009, 73014),
+    (73018, 73018),
+    (73020, 73021),
+    (73023, 73029),
+    (73031, 73031),
+    (73104, 73105),
+    (73109, 73109),
+    (73111, 73111),
+    (73459, 73460),
+    (73472, 73473),
+    (73526, 73530),
+    (73536, 73538),
+    (73562, 73562),
+    (78912, 78912),
+    (78919, 78933),
+    (90398, 90409),
+    (90413, 90415),
+    (92912, 92916),
+    (92976, 92982),
+    (94031, 94031),
+    (94095, 94098),
+    (94180, 94180),
+    (94192, 94193),
+    (113821, 113822),
+    (118528, 118573),
+    (118576, 118598),
+    (119141, 119145),
+    (119149, 119154),
+    (119163, 119170),
+    (119173, 119179),
+    (119210, 119213),
+    (119362, 119364),
+    (121344, 121398),
+    (121403, 121452),
+    (121461, 121461),
+    (121476, 121476),
+    (121499, 121503),
+    (121505, 121519),
+    (122880, 122886),
+    (122888, 122904),
+    (122907, 122913),
+    (122915, 122916),
+    (122918, 122922),
+    (123023, 123023),
+    (123184, 123190),
+    (123566, 123566),
+    (123628, 123631),
+    (124140, 124143),
+    (124398, 124399),
+    (125136, 125142),
+    (125252, 125258),
+    (127995, 127999),
+    (917536, 917631),
+    (917760, 917999),
+];
+
+pub static GCB_L: [(u32, u32); 2] = [(4352, 4447), (43360, 43388)];
+
+pub static GCB_LF: [(u32, u32); 1] = [(10, 10)];
+
+pub static GCB_LV: [(u32, u32); 399] = [
+    (44032, 44032),
+    (44060, 44060),
+    (44088, 44088),
+    (44116, 44116),
+    (44144, 44144),
+    (44172, 44172),
+    (44200, 44200),
+    (44228, 44228),
+    (44256, 44256),
+    (44284, 44284),
+    (44312, 44312),
+    (44340, 44340),
+    (44368, 44368),
+    (44396, 44396),
+    (44424, 44424),
+    (44452, 44452),
+    (44480, 44480),
+    (44508, 44508),
+    (44536, 44536),
+    (44564, 44564),
+    (44592, 44592),
+    (44620, 44620),
+    (44648, 44648),
+    (44676, 44676),
+    (44704, 44704),
+    (44732, 44732),
+    (44760, 44760),
+    (44788, 44788),
+    (44816, 44816),
+    (44844, 44844),
+    (44872, 44872),
+    (44900, 44900),
+    (44928, 44928),
+    (44956, 44956),
+    (44984, 44984),
+    (45012, 45012),
+    (45040, 45040),
+    (45068, 45068),
+    (45096, 45096),
+    (45124, 45124),
+    (45152, 45152),
+    (45180, 45180),
+    (45208, 45208),
+    (45236, 45236),
+    (45264, 45264),
+    (45292, 45292),
+    (45320, 45320),
+    (45348, 45348),
+    (45376, 45376),
+    (45404, 45404),
+    (45432, 45432),
+    (45460, 45460),
+    (45488, 45488),
+    (45516, 45516),
+    (45544, 45544),
+    (45572, 45572),
+    (45600, 45600),
+    (45628, 45628),
+    (45656, 45656),
+    (45684, 45684),
+    (45712, 45712),
+    (45740, 45740),
+    (45768, 45768),
+    (45796, 45796),
+    (45824, 45824),
+    (45852, 45852),
+    (45880, 45880),
+    (45908, 45908),
+    (45936, 45936),
+    (45964, 45964),
+    (45992, 45992),
+    (46020, 46020),
+    (46048, 46048),
+    (46076, 46076),
+    (46104, 46104),
+    (46132, 46132),
+    (46160, 46160),
+    (46188, 46188),
+    (46216, 46216),
+    (46244, 46244),
+    (46272, 46272),
+    (46300, 46300),
+    (46328, 46328),
+    (46356, 46356),
+    (46384, 46384),
+    (46412, 46412),
+    (46440, 46440),
+    (46468, 46468),
+    (46496, 46496),
+    (46524, 46524),
+    (46552, 46552),
+    (46580, 46580),
+    (46608, 46608),
+    (46636, 46636),
+    (46664, 46664),
+    (46692, 46692),
+    (46720, 46720),
+    (46748, 46748),
+    (46776, 46776),
+    (46804, 46804),
+    (46832, 46832),
+    (46860, 46860),
+    (46888, 46888),
+    (46916, 46916),
+    (46944, 46944),
+    (46972, 46972),
+    (47000, 47000),
+    (47028, 47028),
+    (47056, 47056),
+    (47084, 47084),
+    (47112, 47112),
+    (47140, 47140),
+    (47168, 47168),
+    (47196, 47196),
+    (47224, 47224),
+    (47252, 47252),
+    (47280, 47280),
+    (47308, 47308),
+    (47336, 47336),
+    (47364, 47364),
+    (47392, 47392),
+    (47420, 47420),
+    (47448, 47448),
+    (47476, 47476),
+    (47504, 47504),
+    (47532, 47532),
+    (47560, 47560),
+    (47588, 47588),
+    (47616, 47616),
+    (47644, 47644),
+    (47672, 47672),
+    (47700, 47700),
+    (47728, 47728),
+    (47756, 47756),
+    (47784, 47784),
+    (47812, 47812),
+    (47840, 47840),
+    (47868, 47868),
+    (47896, 47896),
+    (47924, 47924),
+    (47952, 47952),
+    (47980, 47980),
+    (48008, 48008),
+    (48036, 48036),
+    (48064, 48064),
+    (48092, 48092),
+    (48120, 48120),
+    (48148, 48148),
+    (48176, 48176),
+    (48204, 48204),
+    (48232, 48232),
+    (48260, 48260),
+    (48288, 48288),
+    (48316, 48316),
+    (48344, 48344),
+    (48372, 48372),
+    (48400, 48400),
+    (48428, 48428),
+    (48456, 48456),
+    (48484, 48484),
+    (48512, 48512),
+    (48540, 48540),
+    (48568, 48568),
+    (48596, 48596),
+    (48624, 48624),
+    (48652, 48652),
+    (48680, 48680),
+    (48708, 48708),
+    (48736, 48736),
+    (48764, 48764),
+    (48792, 48792),
+    (48820, 48820),
+    (48848, 48848),
+    (48876, 48876),
+    (48904, 48904),
+    (48932, 48932),
+    (48960, 48960),
+    (48988, 48988),
+    (49016, 49016),
+    (49044, 49044),
+    (49072, 49072),
+    (49100, 49100),
+    (49128, 49128),
+    (49156, 49156),
+    (49184, 49184),
+    (49212, 49212),
+    (49240, 49240),
+    (49268, 49268),
+    (49296, 49296),
+    (49324, 49324),
+    (49352, 49352),
+    (49380, 49380),
+    (49408, 49408),
+    (49436, 49436),
+    (49464, 49464),
+    (49492, 49492),
+    (49520, 49520),
+    (49548, 49548),
+    (49576, 49576),
+    (49604, 49604),
+    (49632, 49632),
+    (49660, 49660),
+    (49688, 49688),
+    (49716, 49716),
+    (49744, 49744),
+    (49772, 49772),
+    (49800, 49800),
+    (49828, 49828),
+    (49856, 49856),
+    (49884, 49884),
+    (49912, 49912),
+    (49940, 49940),
+    (49968, 49968),
+    (49996, 49996),
+    (50024, 50024),
+    (50052, 50052),
+    (50080, 50080),
+    (50108, 50108),
+    (50136, 50136),
+    (50164, 50164),
+    (50192, 50192),
+    (50220, 50220),
+    (50248, 50248),
+    (50276, 50276),
+    (50304, 50304),
+    (50332, 50332),
+    (50360, 50360),
+    (50388, 50388),
+    (50416, 50416),
+    (50444, 50444),
+    (50472, 50472),
+    (50500, 50500),
+    (50528, 50528),
+    (50556, 50556),
+    (50584, 50584),
+    (50612, 50612),
+    (50640, 50640),
+    (50668, 50668),
+    (50696, 50696),
+    (50724, 50724),
+    (50752, 50752),
+    (50780, 50780),
+    (50808, 50808),
+    (50836, 50836),
+    (50864, 50864),
+    (50892, 50892),
+    (50920, 50920),
+    (50948, 50948),
+    (50976, 50976),
+    (51004, 51004),
+    (51032, 51032),
+    (51060, 51060),
+    (51088, 51088),
+    (51116, 51116),
+    (51144, 51144),
+    (51172, 51172),
+    (51200, 51200),
+    (51228, 51228),
+    (51256, 51256),
+    (51284, 51284),
+    (51312, 51312),
+    (51340, 51340),
+    (51368, 51368),
+    (51396, 51396),
+    (51424, 51424),
+    (51452, 51452),
+    (51480, 51480),
+    (51508, 51508),
+    (51536, 51536),
+    (51564, 51564),
+    (51592, 51592),
+    (51620, 51620),
+    (51648, 51648),
+    (51676, 51676),
+    (51704, 51704),
+    (51732, 51732),
+    (51760, 51760),
+    (51788, 51788),
+    (51816, 51816),
+    (51844, 51844),
+    (51872, 51872),
+    (51900, 51900),
+    (51928, 51928),
+    (51956, 51956),
+    (51984, 51984),
+    (52012, 52012),
+    (52040, 52040),
+    (52068, 52068),
+    (52096, 52096),
+    (52124, 52124),
+    (52152, 52152),
+    (52180, 52180),
+    (52208, 52208),
+    (52236, 52236),
+    (52264, 52264),
+    (52292, 52292),
+    (52320, 52320),
+    (52348, 52348),
+    (52376, 52376),
+    (52404, 52404),
+    (52432, 52432),
+    (52460, 52460),
+    (52488, 52488),
+    (52516, 52516),
+    (52544, 52544),
+    (52572, 52572),
+    (52600, 52600),
+    (52628, 52628),
+    (52656, 52656),
+    (52684, 52684),
+    (52712, 52712),
+    (52740, 52740),
+    (52768, 52768),
+    (52796, 52796),
+    (52824, 52824),
+    (52852, 52852),
+    (52880, 52880),
+    (52908, 52908),
+    (52936, 52936),
+    (52964, 52964),
+    (52992, 52992),
+    (53020, 53020),
+    (53048, 53048),
+    (53076, 53076),
+    (53104, 53104),
+    (53132, 53132),
+    (53160, 53160),
+    (53188, 53188),
+    (53216, 53216),
+    (53244, 53244),
+    (53272, 53272),
+    (53300, 53300),
+    (53328, 53328),
+    (53356, 53356),
+    (53384, 53384),
+    (53412, 53412),
+    (53440, 53440),
+    (53468, 53468),
+    (53496, 53496),
+    (53524, 53524),
+    (53552, 53552),
+    (53580, 53580),
+    (53608, 53608),
+    (53636, 53636),
+    (53664, 53664),
+    (53692, 53692),
+    (53720, 53720),
+    (53748, 53748),
+    (53776, 53776),
+    (53804, 53804),
+    (53832, 53832),
+    (53860, 53860),
+    (53888, 53888),
+    (53916, 53916),
+    (53944, 53944),
+    (53972, 53972),
+    (54000, 54000),
+    (54028, 54028),
+    (54056, 54056),
+    (54084, 54084),
+    (54112, 54112),
+    (54140, 54140),
+    (54168, 54168),
+    (54196, 54196),
+    (54224, 54224),
+    (54252, 54252),
+    (54280, 54280),
+    (54308, 54308),
+    (54336, 54336),
+    (54364, 54364),
+    (54392, 54392),
+    (54420, 54420),
+    (54448, 54448),
+    (54476, 54476),
+    (54504, 54504),
+    (54532, 54532),
+    (54560, 54560),
+    (54588, 54588),
+    (54616, 54616),
+    (54644, 54644),
+    (54672, 54672),
+    (54700, 54700),
+    (54728, 54728),
+    (54756, 54756),
+    (54784, 54784),
+    (54812, 54812),
+    (54840, 54840),
+    (54868, 54868),
+    (54896, 54896),
+    (54924, 54924),
+    (54952, 54952),
+    (54980, 54980),
+    (55008, 55008),
+    (55036, 55036),
+    (55064, 55064),
+    (55092, 55092),
+    (55120, 55120),
+    (55148, 55148),
+    (55176, 55176),
+];
+
+pub static GCB_LVT: [(u32, u32); 399] = [
+    (44033, 44059),
+    (44061, 44087),
+    (44089, 44115),
+    (44117, 44143),
+    (44145, 44171),
+    (44173, 44199),
+    (44201, 44227),
+    (44229, 44255),
+    (44257, 44283),
+    (44285, 44311),
+    (44313, 44339),
+    (44341, 44367),
+    (44369, 44395),
+    (44397, 44423),
+    (44425, 44451),
+    (44453, 44479),
+    (44481, 44507),
+    (44509, 44535),
+    (44537, 44563),
+    (44565, 44591),
+    (44593, 44619),
+    (44621, 44647),
+    (44649, 44675),
+    (44677, 44703),
+    (44705, 44731),
+    (44733, 44759),
+    (44761, 44787),
+    (44789, 44815),
+    (44817, 44843),
+    (44845, 44871),
+    (44873, 44899),
+    (44901, 44927),
+    (44929, 44955),
+    (44957, 44983),
+    (44985, 45011),
+    (45013, 45039),
+    (45041, 45067),
+    (45069, 45095),
+    (45097, 45123),
+    (45125, 45151),
+    (45153, 45179),
+    (45181, 45207),
+    (45209, 45235),
+    (45237, 45263),
+    (45265, 45291),
+    (45293, 45319),
+    (45321, 45347),
+    (45349, 45375),
+    (45377, 45403),
+    (45405, 45431),
+    (45433, 45459),
+    (45461, 45487),
+    (45489, 45515),
+    (45517, 45543),
+    (45545, 45571),
+    (45573, 45599),
+    (45601, 45627),
+    (45629, 45655),
+    (45657, 45683),
+    (45685, 45711),
+    (45713, 45739),
+    (45741, 45767),
+    (45769, 45795),
+    (45797, 45823),
+    (45825, 45851),
+    (45853, 45879),
+    (45881, 45907),
+    (45909, 45935),
+    (45937, 45963),
+    (45965, 45991),
+    (45993, 46019),
+    (46021, 46047),
+    (46049, 46075),
+    (46077, 46103),
+    (46105, 46131),
+    (46133, 46159),
+    (46161, 46187),
+    (46189, 46215),
+    (46217, 46243),
+    (46245, 46271),
+    (46273, 46299),
+    (46301, 46327),
+    (46329, 46355),
+    (46357, 46383),
+    (46385, 46411),
+    (46413, 46439),
+    (46441, 46467),
+    (46469, 46495),
+    (46497, 46523),
+    (46525, 46551),
+    (46553, 46579),
+    (46581, 46607),
+    (46609, 46635),
+    (46637, 46663),
+    (46665, 46691),
+    (46693, 46719),
+    (46721, 46747),
+    (46749, 46775),
+    (46777, 46803),
+    (46805, 46831),
+    (46833, 46859),
+    (46861, 46887),
+    (46889, 46915),
+    (46917, 46943),
+    (46945, 46971),
+    (46973, 46999),
+    (47001, 47027),
+    (47029, 47055),
+    (47057, 47083),
+    (47085, 47111),
+    (47113, 47139),
+    (47141, 47167),
+    (47169, 47195),
+    (47197, 47223),
+    (47225, 47251),
+    (47253, 47279),
+    (47281, 47307),
+    (47309, 47335),
+    (47337, 47363),
+    (47365, 47391),
+    (47393, 47419),
+    (47421, 47447),
+    (47449, 47475),
+    (47477, 47503),
+    (47505, 47531),
+    (47533, 47559),
+    (47561, 47587),
+    (47589, 47615),
+    (47617, 47643),
+    (47645, 47671),
+    (47673, 47699),
+    (47701, 47727),
+    (47729, 47755),
+    (47757, 47783),
+    (47785, 47811),
+    (47813, 47839),
+    (47841, 47867),
+    (47869, 47895),
+    (47897, 47923),
+    (47925, 47951),
+    (47953, 47979),
+    (47981, 48007),
+    (48009, 48035),
+    (48037, 48063),
+    (48065, 48091),
+    (48093, 48119),
+    (48121, 48147),
+    (48149, 48175),
+    (48177, 48203),
+    (48205, 48231),
+    (48233, 48259),
+    (48261, 48287),
+    (48289, 48315),
+    (48317, 48343),
+    (48345, 48371),
+    (48373, 48399),
+    (48401, 48427),
+    (48429, 48455),
+    (48457, 48483),
+    (48485, 48511),
+    (48513, 48539),
+    (48541, 48567),
+    (48569, 48595),
+    (48597, 48623),
+    (48625, 48651),
+    (48653, 48679),
+    (48681, 48707),
+    (48709, 48735),
+    (48737, 48763),
+    (48765, 48791),
+    (48793, 48819),
+    (48821, 48847),
+    (48849, 48875),
+    (48877, 48903),
+    (48905, 48931),
+    (48933, 48959),
+    (48961, 48987),
+    (48989, 49015),
+    (49017, 49043),
+    (49045, 49071),
+    (49073, 49099),
+    (49101, 49127),
+    (49129, 49155),
+    (49157, 49183),
+    (49185, 49211),
+    (49213, 49239),
+    (49241, 49267),
+    (49269, 49295),
+    (49297, 49323),
+    (49325, 49351),
+    (49353, 49379),
+    (49381, 49407),
+    (49409, 49435),
+    (49437, 49463),
+    (49465, 49491),
+    (49493, 49519),
+    (49521, 49547),
+    (49549, 49575),
+    (49577, 49603),
+    (49605, 49631),
+    (49633, 49659),
+    (49661, 49687),
+    (49689, 49715),
+    (49717, 49743),
+    (49745, 49771),
+    (49773, 49799),
+    (49801, 49827),
+    (49829, 49855),
+    (49857, 49883),
+    (49885, 49911),
+    (49913, 49939),
+    (49941, 49967),
+    (49969, 49995),
+    (49997, 50023),
+    (50025, 50051),
+    (50053, 50079),
+    (50081, 50107),
+    (50109, 50135),
+    (50137, 50163),
+    (50165, 50191),
+    (50193, 50219),
+    (50221, 50247),
+    (50249, 50275),
+    (50277, 50303),
+    (50305, 50331),
+    (50333, 50359),
+    (50361, 50387),
+    (50389, 50415),
+    (50417, 50443),
+    (50445, 50471),
+    (50473, 50499),
+    (50501, 50527),
+    (50529, 50555),
+    (50557, 50583),
+    (50585, 50611),
+    (50613, 50639),
+    (50641, 50667),
+    (50669, 50695),
+    (50697, 50723),
+    (50725, 50751),
+    (50753, 50779),
+    (50781, 50807),
+    (50809, 50835),
+    (50837, 50863),
+    (50865, 50891),
+    (50893, 50919),
+    (50921, 50947),
+    (50949, 50975),
+    (50977, 51003),
+    (51005, 51031),
+    (51033, 51059),
+    (51061, 51087),
+    (51089, 51115),
+    (51117, 51143),
+    (51145, 51171),
+    (51173, 51199),
+    (51201, 51227),
+    (51229, 51255),
+    (51257, 51283),
+    (51285, 51311),
+    (51313, 51339),
+    (51341, 51367),
+    (51369, 51395),
+    (51397, 51423),
+    (51425, 51451),
+    (51453, 51479),
+    (51481, 51507),
+    (51509, 51535),
+    (51537, 51563),
+    (51565, 51591),
+    (51593, 51619),
+    (51621, 51647),
+    (51649, 51675),
+    (51677, 51703),
+    (51705, 51731),
+    (51733, 51759),
+    (51761, 51787),
+    (51789, 51815),
+    (51817, 51843),
+    (51845, 51871),
+    (51873, 51899),
+    (51901, 51927),
+    (51929, 51955),
+    (51957, 51983),
+    (51985, 52011),
+    (52013, 52039),
+    (52041, 52067),
+    (52069, 52095),
+    (52097, 52123),
+    (52125, 52151),
+    (52153, 52179),
+    (52181, 52207),
+    (52209, 52235),
+    (52237, 52263),
+    (52265, 52291),
+    (52293, 52319),
+    (52321, 52347),
+    (52349, 52375),
+    (52377, 52403),
+    (52405, 52431),
+    (52433, 52459),
+    (52461, 52487),
+    (52489, 52515),
+    (52517, 52543),
+    (52545, 52571),
+    (52573, 52599),
+    (52601, 52627),
+    (52629, 52655),
+    (52657, 52683),
+    (52685, 52711),
+    (52713, 52739),
+    (52741, 52767),
+    (52769, 52795),
+    (52797, 52823),
+    (52825, 52851),
+    (52853, 52879),
+    (52881, 52907),
+    (52909, 52935),
+    (52937, 52963),
+    (52965, 52991),
+    (52993, 53019),
+    (53021, 53047),
+    (53049, 53075),
+    (53077, 53103),
+    (53105, 53131),
+    (53133, 53159),
+    (53161, 53187),
+    (53189, 53215),
+    (53217, 53243),
+    (53245, 53271),
+    (53273, 53299),
+    (53301, 53327),
+    (53329, 53355),
+    (53357, 53383),
+    (53385, 53411),
+    (53413, 53439),
+    (53441, 53467),
+    (53469, 53495),
+    (53497, 53523),
+    (53525, 53551),
+    (53553, 53579),
+    (53581, 53607),
+    (53609, 53635),
+    (53637, 53663),
+    (53665, 53691),
+    (53693, 53719),
+    (53721, 53747),
+    (53749, 53775),
+    (53777, 53803),
+    (53805, 53831),
+    (53833, 53859),
+    (53861, 53887),
+    (53889, 53915),
+    (53917, 53943),
+    (53945, 53971),
+    (53973, 53999),
+    (54001, 54027),
+    (54029, 54055),
+    (54057, 54083),
+    (54085, 54111),
+    (54113, 54139),
+    (54141, 54167),
+    (54169, 54195),
+    (54197, 54223),
+    (54225, 54251),
+    (54253, 54279),
+    (54281, 54307),
+    (54309, 54335),
+    (54337, 54363),
+    (54365, 54391),
+    (54393, 54419),
+    (54421, 54447),
+    (54449, 54475),
+    (54477, 54503),
+    (54505, 54531),
+    (54533, 54559),
+    (54561, 54587),
+    (54589, 54615),
+    (54617, 54643),
+    (54645, 54671),
+    (54673, 54699),
+    (54701, 54727),
+    (54729, 54755),
+    (54757, 54783),
+    (54785, 54811),
+    (54813, 54839),
+    (54841, 54867),
+    (54869, 54895),
+    (54897, 54923),
+    (54925, 54951),
+    (54953, 54979),
+    (54981, 55007),
+    (55009, 55035),
+    (55037, 55063),
+    (55065, 55091),
+    (55093, 55119),
+    (55121, 55147),
+    (55149, 55175),
+    (55177, 55203),
+];
+
+pub static GCB_PREPEND: [(u32, u32); 16] = [
+    (1536, 1541),
+    (1757, 1757),
+    (1807, 1807),
+    (2192, 2193),
+    (2274, 2274),
+    (3406, 3406),
+    (69821, 69821),
+    (69837, 69837),
+    (70082, 70083),
+    (70609, 70609),
+    (71999, 71999),
+    (72001, 72001),
+    (72250, 72250),
+    (72324, 72329),
+    (73030, 73030),
+    (73474, 73474),
+];
+
+pub static GCB_REGIONAL_INDICATOR: [(u32, u32); 1] = [(127462, 127487)];
+
+pub static GCB_SPACINGMARK: [(u32, u32); 155] = [
+    (2307, 2307),
+    (2363, 2363),
+    (2366, 2368),
+    (2377, 2380),
+    (2382, 2383),
+    (2434, 2435),
+    (2495, 2496),
+    (2503, 2504),
+    (2507, 2508),
+    (2563, 2563),
+    (2622, 2624),
+    (2691, 2691),
+    (2750, 2752),
+    (2761, 2761),
+    (2763, 2764),
+    (2818, 2819),
+    (2880, 2880),
+    (2887, 2888),
+    (2891, 2892),
+    (3007, 3007),
+    (3009, 3010),
+    (3014, 3016),
+    (3018, 3020),
+    (3073, 3075),
+    (3137, 3140),
+    (3202, 3203),
+    (3262, 3262),
+    (3265, 3265),
+    (3267, 3268),
+    (3315, 3315),
+    (3330, 3331),
+    (3391, 3392),
+    (3398, 3400),
+    (3402, 3404),
+    (3458, 3459),
+    (3536, 3537),
+    (3544, 3550),
+    (3570, 3571),
+    (3635, 3635),
+    (3763, 3763),
+    (3902, 3903),
+    (3967, 3967),
+    (4145, 4145),
+    (4155, 4156),
+    (4182, 4183),
+    (4228, 4228),
+    (6070, 6070),
+    (6078, 6085),
+    (6087, 6088),
+    (6435, 6438),
+    (6441, 6443),
+    (6448, 6449),
+    (6451, 6456),
+    (6681, 6682),
+    (6741, 6741),
+    (6743, 6743),
+    (6765, 6770),
+    (6916, 6916),
+    (6974, 6977),
+    (7042, 7042),
+    (7073, 7073),
+    (7078, 7079),
+    (7143, 7143),
+    (7146, 7148),
+    (7150, 7150),
+    (7204, 7211),
+    (7220, 7221),
+    (7393, 7393),
+    (7415, 7415),
+    (43043, 43044),
+    (43047, 43047),
+    (43136, 43137),
+    (43188, 43203),
+    (43346, 43346),
+    (43395, 43395),
+    (43444, 43445),
+    (43450, 43451),
+    (43454, 43455),
+    (43567, 43568),
+    (43571, 43572),
+    (43597, 43597),
+    (43755, 43755),
+    (43758, 43759),
+    (43765, 43765),
+    (44003, 44004),
+    (44006, 44007),
+    (44009, 44010),
+    (44012, 44012),
+    (69632, 69632),
+    (69634, 69634),
+    (69762, 69762),
+    (69808, 69810),
+    (69815, 69816),
+    (69932, 69932),
+    (69957, 69958),
+    (70018, 70018),
+    (70067, 70069),
+    (70079, 70079),
+    (70094, 70094),
+    (70188, 70190),
+    (70194, 70195),
+    (70368, 70370),
+    (70402, 70403),
+    (70463, 70463),
+    (70465, 70468),
+    (70471, 70472),
+    (70475, 70476),
+    (70498, 70499),
+    (70585, 70586),
+    (70602, 70602),
+    (70604, 70605),
+    (70709, 70711),
+    (70720, 70721),
+    (70725, 70725),
+    (70833, 70834),
+    (70841, 70841),
+    (70843, 70844),
+    (70846, 70846),
+    (70849, 70849),
+    (71088, 71089),
+    (71096, 71099),
+    (71102, 71102),
+    (71216, 71218),
+    (71227, 71228),
+    (71230, 71230),
+    (71340, 71340),
+    (71342, 71343),
+    (71454, 71454),
+    (71462, 71462),
+    (71724, 71726),
+    (71736, 71736),
+    (71985, 71989),
+    (71991, 71992),
+    (72000, 72000),
+    (72002, 72002),
+    (72145, 72147),
+    (72156, 72159),
+    (72164, 72164),
+    (72249, 72249),
+    (72279, 72280),
+    (72343, 72343),
+    (72751, 72751),
+    (72766, 72766),
+    (72873, 72873),
+    (72881, 72881),
+    (72884, 72884),
+    (73098, 73102),
+    (73107, 73108),
+    (73110, 73110),
+    (73461, 73462),
+    (73475, 73475),
+    (73524, 73525),
+    (73534, 73535),
+    (90410, 90412),
+    (94033, 94087),
+];
+
+pub static GCB_T: [(u32, u32); 2] = [(4520, 4607), (55243, 55291)];
+
+pub static GCB_V: [(u32, u32); 4] = [(4448, 4519), (55216, 55238), (93539, 93539), (93543, 93546)];
+
+pub static GCB_ZWJ: [(u32, u32); 1] = [(8205, 8205)];
+
+pub static GRAPHEME_CLUSTER_BREAKS: [(&str, &[(u32, u32)]); 13] = [
+    ("CR", &GCB_CR),
+    ("Control", &GCB_CONTROL),
+    ("Extend", &GCB_EXTEND),
+    ("L", &GCB_L),
+    ("LF", &GCB_LF),
+    ("LV", &GCB_LV),
+    ("LVT", &GCB_LVT),
+    ("Prepend", &GCB_PREPEND),
+    ("Regional_Indicator", &GCB_REGIONAL_INDICATOR),
+    ("SpacingMark", &GCB_SPACINGMARK),
+    ("T", &GCB_T),
+    ("V", &GCB_V),
+    ("ZWJ", &GCB_ZWJ),
+];
+
+pub static EMOJI_EXTENDED_PICTOGRAPHIC: [(u32, u32); 78] = [
+    (169, 169),
+    (174, 174),
+    (8252, 8252),
+    (8265, 8265),
+    (8482, 8482),
+    (8505, 8505),
+    (8596, 8601),
+    (8617, 8618),
+    (8986, 8987),
+    (9000, 9000),
+    (9096, 9096),
+    (9167, 9167),
+    (9193, 9203),
+    (9208, 9210),
+    (9410, 9410),
+    (9642, 9643),
+    (9654, 9654),
+    (9664, 9664),
+    (9723, 9726),
+    (9728, 9733),
+    (9735, 9746),
+    (9748, 9861),
+    (9872, 9989),
+    (9992, 10002),
+    (10004, 10004),
+    (10006, 10006),
+    (10013, 10013),
+    (10017, 10017),
+    (10024, 10024),
+    (10035, 10036),
+    (10052, 10052),
+    (10055, 10055),
+    (10060, 10060),
+    (10062, 10062),
+    (10067, 10069),
+    (10071, 10071),
+    (10083, 10087),
+    (10133, 10135),
+    (10145, 10145),
+    (10160, 10160),
+    (10175, 10175),
+    (10548, 10549),
+    (11013, 11015),
+    (11035, 11036),
+    (11088, 11088),
+    (11093, 11093),
+    (12336, 12336),
+    (12349, 12349),
+    (12951, 12951),
+    (12953, 12953),
+    (126976, 127231),
+    (127245, 127247),
+    (127279, 127279),
+    (127340, 127345),
+    (127358, 127359),
+    (127374, 127374),
+    (127377, 127386),
+    (127405, 127461),
+    (127489, 127503),
+    (127514, 127514),
+    (127535, 127535),
+    (127538, 127546),
+    (127548, 127551),
+    (127561, 127994),
+    (128000, 128317),
+    (128326, 128591),
+    (128640, 128767),
+    (128884, 128895),
+    (128981, 129023),
+    (129036, 129039),
+    (129096, 129103),
+    (129114, 129119),
+    (129160, 129167),
+    (129198, 129279),
+    (129292, 129338),
+    (129340, 129349),
+    (129351, 129791),
+    (130048, 131069),
+];
+
+pub static EMOJI_PROPERTIES: [(&str, &[(u32, u32)]); 1] =
+    [("Extended_Pictographic", &EMOJI_EXTENDED_PICTOGRAPHIC)];
+
+pub static COMBINING_CLASSES: [(u32, u32, u8); 382] = [
+    (768, 788, 230),
+    (789, 789, 232),
+    (790, 793, 220),
+    (794, 794, 232),
+    (795, 795, 216),
+    (796, 800, 220),
+    (801, 802, 202),
+    (803, 806, 220),
+    (807, 808, 202),
+    (809, 819, 220),
+    (820, 824, 1),
+    (825, 828, 220),
+    (829, 836, 230),
+    (837, 837, 240),
+    (838, 838, 230),
+    (839, 841, 220),
+    (842, 844, 230),
+    (845, 846, 220),
+    (848, 850, 230),
+    (851, 854, 220),
+    (855, 855, 230),
+    (856, 856, 232),
+    (857, 858, 220),
+    (859, 859, 230),
+    (860, 860, 233),
+    (861, 862, 234),
+    (863, 863, 233),
+    (864, 865, 234),
+    (866, 866, 233),
+    (867, 879, 230),
+    (1155, 1159, 230),
+    (1425, 1425, 220),
+    (1426, 1429, 230),
+    (1430, 1430, 220),
+    (1431, 1433, 230),
+    (1434, 1434, 222),
+    (1435, 1435, 220),
+    (1436, 1441, 230),
+    (1442, 1447, 220),
+    (1448, 1449, 230),
+    (1450, 1450, 220),
+    (1451, 1452, 230),
+    (1453, 1453, 222),
+    (1454, 1454, 228),
+    (1455, 1455, 230),
+    (1456, 1456, 10),
+    (1457, 1457, 11),
+    (1458, 1458, 12),
+    (1459, 1459, 13),
+    (1460, 1460, 14),
+    (1461, 1461, 15),
+    (1462, 1462, 16),
+    (1463, 1463, 17),
+    (1464, 1464, 18),
+    (1465, 1466, 19),
+    (1467, 1467, 20),
+    (1468, 1468, 21),
+    (1469, 1469, 22),
+    (1471, 1471, 23),
+    (1473, 1473, 24),
+    (1474, 1474, 25),
+    (1476, 1476, 230),
+    (1477, 1477, 220),
+    (1479, 1479, 18),
+    (1552, 1559, 230),
+    (1560, 1560, 30),
+    (1561, 1561, 31),
+    (1562, 1562, 32),
+    (1611, 1611, 27),
+    (1612, 1612, 28),
+    (1613, 1613, 29),
+    (1614, 1614, 30),
+    (1615, 1615, 31),
+    (1616, 1616, 32),
+    (1617, 1617, 33),
+    (1618, 1618, 34),
+    (1619, 1620, 230),
+    (1621, 1622, 220),
+    (1623, 1627, 230),
+    (1628, 1628, 220),
+    (1629, 1630, 230),
+    (1631, 1631, 220),
+    (1648, 1648, 35),
+    (1750, 1756, 230),
+    (1759, 1762, 230),
+    (1763, 1763, 220),
+    (1764, 1764, 230),
+    (1767, 1768, 230),
+    (1770, 1770, 220),
+    (1771, 1772, 230),
+    (1773, 1773, 220),
+    (1809, 1809, 36),
+    (1840, 1840, 230),
+    (1841, 1841, 220),
+    (1842, 1843, 230),
+    (1844, 1844, 220),
+    (1845, 1846, 230),
+    (1847, 1849, 220),
+    (1850, 1850, 230),
+    (1851, 1852, 220),
+    (1853, 1853, 230),
+    (1854, 1854, 220),
+    (1855, 1857, 230),
+    (1858, 1858, 220),
+    (1859, 1859, 230),
+    (1860, 1860, 220),
+    (1861, 1861, 230),
+    (1862, 1862, 220),
+    (1863, 1863, 230),
+    (1864, 1864, 220),
+    (1865, 1866, 230),
+    (2027, 2033, 230),
+    (2034, 2034, 220),
+    (2035, 2035, 230),
+    (2045, 2045, 220),
+    (2070, 2073, 230),
+    (2075, 2083, 230),
+    (2085, 2087, 230),
+    (2089, 2093, 230),
+    (2137, 2139, 220),
+    (2200, 2200, 230),
+    (2201, 2203, 220),
+    (2204, 2207, 230),
+    (2250, 2254, 230),
+    (2255, 2259, 220),
+    (2260, 2273, 230),
+    (2275, 2275, 220),
+    (2276, 2277, 230),
+    (2278, 2278, 220),
+    (2279, 2280, 230),
+    (2281, 2281, 220),
+    (2282, 2284, 230),
+    (2285, 2287, 220),
+    (2288, 2288, 27),
+    (2289, 2289, 28),
+    (2290, 2290, 29),
+    (2291, 2293, 230),
+    (2294, 2294, 220),
+    (2295, 2296, 230),
+    (2297, 2298, 220),
+    (2299, 2303, 230),
+    (2364, 2364, 7),
+    (2381, 2381, 9),
+    (2385, 2385, 230),
+    (2386, 2386, 220),
+    (2387, 2388, 230),
+    (2492, 2492, 7),
+    (2509, 2509, 9),
+    (2558, 2558, 230),
+    (2620, 2620, 7),
+    (2637, 2637, 9),
+    (2748, 2748, 7),
+    (2765, 2765, 9),
+    (2876, 2876, 7),
+    (2893, 2893, 9),
+    (3021, 3021, 9),
+    (3132, 3132, 7),
+    (3149, 3149, 9),
+    (3157, 3157, 84),
+    (3158, 3158, 91),
+    (3260, 3260, 7),
+    (3277, 3277, 9),
+    (3387, 3388, 9),
+    (3405, 3405, 9),
+    (3530, 3530, 9),
+    (3640, 3641, 103),
+    (3642, 3642, 9),
+    (3656, 3659, 107),
+    (3768, 3769, 118),
+    (3770, 3770, 9),
+    (3784, 3787, 122),
+    (3864, 3865, 220),
+    (3893, 3893, 220),
+    (3895, 3895, 220),
+    (3897, 3897, 216),
+    (3953, 3953, 129),
+    (3954, 3954, 130),
+    (3956, 3956, 132),
+    (3962, 3965, 130),
+    (3968, 3968, 130),
+    (3970, 3971, 230),
+    (3972, 3972, 9),
+    (3974, 3975, 230),
+    (4038, 4038, 220),
+    (4151, 4151, 7),
+    (4153, 4154, 9),
+    (4237, 4237, 220),
+    (4957, 4959, 230),
+    (5908, 5909, 9),
+    (5940, 5940, 9),
+    (6098, 6098, 9),
+    (6109, 6109, 230),
+    (6313, 6313, 228),
+    (6457, 6457, 222),
+    (6458, 6458, 230),
+    (6459, 6459, 220),
+    (6679, 6679, 230),
+    (6680, 6680, 220),
+    (6752, 6752, 9),
+    (6773, 6780, 230),
+    (6783, 6783, 220),
+    (6832, 6836, 230),
+    (6837, 6842, 220),
+    (6843, 6844, 230),
+    (6845, 6845, 220),
+    (6847, 6848, 220),
+    (6849, 6850, 230),
+    (6851, 6852, 220),
+    (6853, 6857, 230),
+    (6858, 6858, 220),
+    (6859, 6862, 230),
+    (6964, 6964, 7),
+    (6980, 6980, 9),
+    (7019, 7019, 230),
+    (7020, 7020, 220),
+    (7021, 7027, 230),
+    (7082, 7083, 9),
+    (7142, 7142, 7),
+    (7154, 7155, 9),
+    (7223, 7223, 7),
+    (7376, 7378, 230),
+    (7380, 7380, 1),
+    (7381, 7385, 220),
+    (7386, 7387, 230),
+    (7388, 7391, 220),
+    (7392, 7392, 230),
+    (7394, 7400, 1),
+    (7405, 7405, 220),
+    (7412, 7412, 230),
+    (7416, 7417, 230),
+    (7616, 7617, 230),
+    (7618, 7618, 220),
+    (7619, 7625, 230),
+    (7626, 7626, 220),
+    (7627, 7628, 230),
+    (7629, 7629, 234),
+    (7630, 7630, 214),
+    (7631, 7631, 220),
+    (7632, 7632, 202),
+    (7633, 7669, 230),
+    (7670, 7670, 232),
+    (7671, 7672, 228),
+    (7673, 7673, 220),
+    (7674, 7674, 218),
+    (7675, 7675, 230),
+    (7676, 7676, 233),
+    (7677, 7677, 220),
+    (7678, 7678, 230),
+    (7679, 7679, 220),
+    (8400, 8401, 230),
+    (8402, 8403, 1),
+    (8404, 8407, 230),
+    (8408, 8410, 1),
+    (8411, 8412, 230),
+    (8417, 8417, 230),
+    (8421, 8422, 1),
+    (8423, 8423, 230),
+    (8424, 8424, 220),
+    (8425, 8425, 230),
+    (8426, 8427, 1),
+    (8428, 8431, 220),
+    (8432, 8432, 230),
+    (11503, 11505, 230),
+    (11647, 11647, 9),
+    (11744, 11775, 230),
+    (12330, 12330, 218),
+    (12331, 12331, 228),
+    (12332, 12332, 232),
+    (12333, 12333, 222),
+    (12334, 12335, 224),
+    (12441, 12442, 8),
+    (42607, 42607, 230),
+    (42612, 42621, 230),
+    (42654, 42655, 230),
+    (42736, 42737, 230),
+    (43014, 43014, 9),
+    (43052, 43052, 9),
+    (43204, 43204, 9),
+    (43232, 43249, 230),
+    (43307, 43309, 220),
+    (43347, 43347, 9),
+    (43443, 43443, 7),
+    (43456, 43456, 9),
+    (43696, 43696, 230),
+    (43698, 43699, 230),
+    (43700, 43700, 220),
+    (43703, 43704, 230),
+    (43710, 43711, 230),
+    (43713, 43713, 230),
+    (43766, 43766, 9),
+    (44013, 44013, 9),
+    (64286, 64286, 26),
+    (65056, 65062, 230),
+    (65063, 65069, 220),
+    (65070, 65071, 230),
+    (66045, 66045, 220),
+    (66272, 66272, 220),
+    (66422, 66426, 230),
+    (68109, 68109, 220),
+    (68111, 68111, 230),
+    (68152, 68152, 230),
+    (68153, 68153, 1),
+    (68154, 68154, 220),
+    (68159, 68159, 9),
+    (68325, 68325, 230),
+    (68326, 68326, 220),
+    (68900, 68903, 230),
+    (69291, 69292, 230),
+    (69446, 69447, 220),
+    (69448, 69450, 230),
+    (69451, 69451, 220),
+    (69452, 69452, 230),
+    (69453, 69456, 220),
+    (69506, 69506, 230),
+    (69507, 69507, 220),
+    (69508, 69508, 230),
+    (69509, 69509, 220),
+    (69702, 69702, 9),
+    (69744, 69744, 9),
+    (69759, 69759, 9),
+    (69817, 69817, 9),
+    (69818, 69818, 7),
+    (69888, 69890, 230),
+    (69939, 69940, 9),
+    (70003, 70003, 7),
+    (70080, 70080, 9),
+    (70090, 70090, 7),
+    (70197, 70197, 9),
+    (70198, 70198, 7),
+    (70377, 70377, 7),
+    (70378, 70378, 9),
+    (70459, 70460, 7),
+    (70477, 70477, 9),
+    (70502, 70508, 230),
+    (70512, 70516, 230),
+    (70722, 70722, 9),
+    (70726, 70726, 7),
+    (70750, 70750, 230),
+    (70850, 70850, 9),
+    (70851, 70851, 7),
+    (71103, 71103, 9),
+    (71104, 71104, 7),
+    (71231, 71231, 9),
+    (71350, 71350, 9),
+    (71351, 71351, 7),
+    (71467, 71467, 9),
+    (71737, 71737, 9),
+    (71738, 71738, 7),
+    (71997, 71998, 9),
+    (72003, 72003, 7),
+    (72160, 72160, 9),
+    (72244, 72244, 9),
+    (72263, 72263, 9),
+    (72345, 72345, 9),
+    (72767, 72767, 9),
+    (73026, 73026, 7),
+    (73028, 73029, 9),
+    (73111, 73111, 9),
+    (92912, 92916, 1),
+    (92976, 92982, 230),
+    (94192, 94193, 6),
+    (113822, 113822, 1),
+    (119141, 119142, 216),
+    (119143, 119145, 1),
+    (119149, 119149, 226),
+    (119150, 119154, 216),
+    (119163, 119170, 220),
+    (119173, 119177, 230),
+    (119178, 119179, 220),
+    (119210, 119213, 230),
+    (119362, 119364, 230),
+    (122880, 122886, 230),
+    (122888, 122904, 230),
+    (122907, 122913, 230),
+    (122915, 122916, 230),
+    (122918, 122922, 230),
+    (123184, 123190, 230),
+    (123566, 123566, 230),
+    (123628, 123631, 230),
+    (125136, 125142, 220),
+    (125252, 125257, 230),
+    (125258, 125258, 7),
+];
+
+pub static CANONICAL_DECOMPOSITIONS: [(u32, &[u32]); 2061] = [
+    (192, &[65, 768]),
+    (193, &[65, 769]),
+    (194, &[65, 770]),
+    (195, &[65, 771]),
+    (196, &[65, 776]),
+    (197, &[65, 778]),
+    (199, &[67, 807]),
+    (200, &[69, 768]),
+    (201, &[69, 769]),
+    (202, &[69, 770]),
+    (203, &[69, 776]),
+    (204, &[73, 768]),
+    (205, &[73, 769]),
+    (206, &[73, 770]),
+    (207, &[73, 776]),
+    (209, &[78, 771]),
+    (210, &[79, 768]),
+    (211, &[79, 769]),
+    (212, &[79, 770]),
+    (213, &[79, 771]),
+    (214, &[79, 776]),
+    (217, &[85, 768]),
+    (218, &[85, 769]),
+    (219, &[85, 770]),
+    (220, &[85, 776]),
+    (221, &[89, 769]),
+    (224, &[97, 768]),
+    (225, &[97, 769]),
+    (226, &[97, 770]),
+    (227, &[97, 771]),
+    (228, &[97, 776]),
+    (229, &[97, 778]),
+    (231, &[99, 807]),
+    (232, &[101, 768]),
+    (233, &[101, 769]),
+    (234, &[101, 770]),
+    (235, &[101, 776]),
+    (236, &[105, 768]),
+    (237, &[105, 769]),
+    (238, &[105, 770]),
+    (239, &[105, 776]),
+    (241, &[110, 771]),
+    (242, &[111, 768]),
+    (243, &[111, 769]),
+    (244, &[111, 770]),
+    (245, &[111, 771]),
+    (246, &[111, 776]),
+    (249, &[117, 768]),
+    (250, &[117, 769]),
+    (251, &[117, 770]),
+    (252, &[117, 776]),
+    (253, &[121, 769]),
+    (255, &[121, 776]),
+    (256, &[65, 772]),
+    (257, &[97, 772]),
+    (258, &[65, 774]),
+    (259, &[97, 774]),
+    (260, &[65, 808]),
+    (261, &[97, 808]),
+    (262, &[67, 769]),
+    (263, &[99, 769]),
+    (264, &[67, 770]),
+    (265, &[99, 770]),
+    (266, &[67, 775]),
+    (267, &[99, 775]),
+    (268, &[67, 780]),
+    (269, &[99, 780]),
+    (270, &[68, 780]),
+    (271, &[100, 780]),
+    (274, &[69, 772]),
+    (275, &[101, 772]),
+    (276, &[69, 774]),
+    (277, &[101, 774]),
+    (278, &[69, 775]),
+    (279, &[101, 775]),
+    (280, &[69, 808]),
+    (281, &[101, 808]),
+    (282, &[69, 780]),
+    (283, &[101, 780]),
+    (284, &[71, 770]),
+    (285, &[103, 770]),
+    (286, &[71, 774]),
+    (287, &[103, 774]),
+    (288, &[71, 775]),
+    (289, &[103, 775]),
+    (290, &[71, 807]),
+    (291, &[103, 807]),
+    (292, &[72, 770]),
+    (293, &[104, 770]),
+    (296, &[73, 771]),
+    (297, &[105, 771]),
+    (298, &[73, 772]),
+    (299, &[105, 772]),
+    (300, &[73, 774]),
+    (301, &[105, 774]),
+    (302, &[73, 808]),
+    (303, &[105, 808]),
+    (304, &[73, 775]),
+    (308, &[74, 770]),
+    (309, &[106, 770]),
+    (310, &[75, 807]),
+    (311, &[107, 807]),
+    (313, &[76, 769]),
+    (314, &[108, 769]),
+    (315, &[76, 807]),
+    (316, &[108, 807]),
+    (317, &[76, 780]),
+    (318, &[108, 780]),
+    (323, &[78, 769]),
+    (324, &[110, 769]),
+    (325, &[78, 807]),
+    (326, &[110, 807]),
+    (327, &[78, 780]),
+    (328, &[110, 780]),
+    (332, &[79, 772]),
+    (333, &[111, 772]),
+    (334, &[79, 774]),
+    (335, &[111, 774]),
+    (336, &[79, 779]),
+    (337, &[111, 779]),
+    (340, &[82, 769]),
+    (341, &[114, 769]),
+    (342, &[82, 807]),
+    (343, &[114, 807]),
+    (344, &[82, 780]),
+    (345, &[114, 780]),
+    (346, &[83, 769]),
+    (347, &[115, 769]),
+    (348, &[83, 770]),
+    (349, &[115, 770]),
+    (350, &[83, 807]),
+    (351, &[115, 807]),
+    (352, &[83, 780]),
+    (353, &[115, 780]),
+    (354, &[84, 807]),
+    (355, &[116, 807]),
+    (356, &[84, 780]),
+    (357, &[116, 780]),
+    (360, &[85, 771]),
+    (361, &[117, 771]),
+    (362, &[85, 772]),
+    (363, &[117, 772]),
+    (364, &[85, 774]),
+    (365, &[117, 774]),
+    (366, &[85, 778]),
+    (367, &[117, 778]),
+    (368, &[85, 779]),
+    (369, &[117, 779]),
+    (370, &[85, 808]),
+    (371, &[117, 808]),
+    (372, &[87, 770]),
+    (373, &[119, 770]),
+    (374, &[89, 770]),
+    (375, &[121, 770]),
+    (376, &[89, 776]),
+    (377, &[90, 769]),
+    (378, &[122, 769]),
+    (379, &[90, 775]),
+    (380, &[122, 775]),
+    (381, &[90, 780]),
+    (382, &[122, 780]),
+    (416, &[79, 795]),
+    (417, &[111, 795]),
+    (431, &[85, 795]),
+    (432, &[117, 795]),
+    (461, &[65, 780]),
+    (462, &[97, 780]),
+    (463, &[73, 780]),
+    (464, &[105, 780]),
+    (465, &[79, 780]),
+    (466, &[111, 780]),
+    (467, &[85, 780]),
+    (468, &[117, 780]),
+    (469, &[220, 772]),
+    (470, &[252, 772]),
+    (471, &[220, 769]),
+    (472, &[252, 769]),
+    (473, &[220, 780]),
+    (474, &[252, 780]),
+    (475, &[220, 768]),
+    (476, &[252, 768]),
+    (478, &[196, 772]),
+    (479, &[228, 772]),
+    (480, &[550, 772]),
+    (481, &[551, 772]),
+    (482, &[198, 772]),
+    (483, &[230, 772]),
+    (486, &[71, 780]),
+    (487, &[103, 780]),
+    (488, &[75, 780]),
+    (489, &[107, 780]),
+    (490, &[79, 808]),
+    (491, &[111, 808]),
+    (492, &[490, 772]),
+    (493, &[491, 772]),
+    (494, &[439, 780]),
+    (495, &[658, 780]),
+    (496, &[106, 780]),
+    (500, &[71, 769]),
+    (501, &[103, 769]),
+    (504, &[78, 768]),
+    (505, &[110, 768]),
+    (506, &[197, 769]),
+    (507, &[229, 769]),
+    (508, &[198, 769]),
+    (509, &[230, 769]),
+    (510, &[216, 769]),
+    (511, &[248, 769]),
+    (512, &[65, 783]),
+    (513, &[97, 783]),
+    (514, &[65, 785]),
+    (515, &[97, 785]),
+    (516, &[69, 783]),
+    (517, &[101, 783]),
+    (518, &[69, 785]),
+    (519, &[101, 785]),
+    (520, &[73, 783]),
+    (521, &[105, 783]),
+    (522, &[73, 785]),
+    (523, &[105, 785]),
+    (524, &[79, 783]),
+    (525, &[111, 783]),
+    (526, &[79, 785]),
+    (527, &[111, 785]),
+    (528, &[82, 783]),
+    (529, &[114, 783]),
+    (530, &[82, 785]),
+    (531, &[114, 785]),
+    (532, &[85, 783]),
+    (533, &[117, 783]),
+    (534, &[85, 785]),
+    (535, &[117, 785]),
+    (536, &[83, 806]),
+    (537, &[115, 806]),
+    (538, &[84, 806]),
+    (539, &[116, 806]),
+    (542, &[72, 780]),
+    (543, &[104, 780]),
+    (550, &[65, 775]),
+    (551, &[97, 775]),
+    (552, &[69, 807]),
+    (553, &[101, 807]),
+    (554, &[214, 772]),
+    (555, &[246, 772]),
+    (556, &[213, 772]),
+    (557, &[245, 772]),
+    (558, &[79, 775]),
+    (559, &[111, 775]),
+    (560, &[558, 772]),
+    (561, &[559, 772]),
+    (562, &[89, 772]),
+    (563, &[121, 772]),
+    (832, &[768]),
+    (833, &[769]),
+    (835, &[787]),
+    (836, &[776, 769]),
+    (884, &[697]),
+    (894, &[59]),
+    (901, &[168, 769]),
+    (902, &[913, 769]),
+    (903, &[183]),
+    (904, &[917, 769]),
+    (905, &[919, 769]),
+    (906, &[921, 769]),
+    (908, &[927, 769]),
+    (910, &[933, 769]),
+    (911, &[937, 769]),
+    (912, &[970, 769]),
+    (938, &[921, 776]),
+    (939, &[933, 776]),
+    (940, &[945, 769]),
+    (941, &[949, 769]),
+    (942, &[951, 769]),
+    (943, &[953, 769]),
+    (944, &[971, 769]),
+    (970, &[953, 776]),
+    (971, &[965, 776]),
+    (972, &[959, 769]),
+    (973, &[965, 769]),
+    (974, &[969, 769]),
+    (979, &[978, 769]),
+    (980, &[978, 776]),
+    (1024, &[1045, 768]),
+    (1025, &[1045, 776]),
+    (1027, &[1043, 769]),
+    (1031, &[1030, 776]),
+    (1036, &[1050, 769]),
+    (1037, &[1048, 768]),
+    (1038, &[1059, 774]),
+    (1049, &[1048, 774]),
+    (1081, &[1080, 774]),
+    (1104, &[1077, 768]),
+    (1105, &[1077, 776]),
+    (1107, &[1075, 769]),
+    (1111, &[1110, 776]),
+    (1116, &[1082, 769]),
+    (1117, &[1080, 768]),
+    (1118, &[1091, 774]),
+    (1142, &[1140, 783]),
+    (1143, &[1141, 783]),
+    (1217, &[1046, 774]),
+    (1218, &[1078, 774]),
+    (1232, &[1040, 774]),
+    (1233, &[1072, 774]),
+    (1234, &[1040, 776]),
+    (1235, &[1072, 776]),
+    (1238, &[1045, 774]),
+    (1239, &[1077, 774]),
+    (1242, &[1240, 776]),
+    (1243, &[1241, 776]),
+    (1244, &[1046, 776]),
+    (1245, &[1078, 776]),
+    (1246, &[1047, 776]),
+    (1247, &[1079, 776]),
+    (1250, &[1048, 772]),
+    (1251, &[1080, 772]),
+    (1252, &[1048, 776]),
+    (1253, &[1080, 776]),
+    (1254, &[1054, 776]),
+    (1255, &[1086, 776]),
+    (1258, &[1256, 776]),
+    (1259, &[1257, 776]),
+    (1260, &[1069, 776]),
+    (1261, &[1101, 776]),
+    (1262, &[1059, 772]),
+    (1263, &[1091, 772]),
+    (1264, &[1059, 776]),
+    (1265, &[1091, 776]),
+    (1266, &[1059, 779]),
+    (1267, &[1091, 779]),
+    (1268, &[1063, 776]),
+    (1269, &[1095, 776]),
+    (1272, &[1067, 776]),
+    (1273, &[1099, 776]),
+    (1570, &[1575, 1619]),
+    (1571, &[1575, 1620]),
+    (1572, &[1608, 1620]),
+    (1573, &[1575, 1621]),
+    (1574, &[1610, 1620]),
+    (1728, &[1749, 1620]),
+    (1730, &[1729, 1620]),
+    (1747, &[1746, 1620]),
+    (2345, &[2344, 2364]),
+    (2353, &[2352, 2364]),
+    (2356, &[2355, 2364]),
+    (2392, &[2325, 2364]),
+    (2393, &[2326, 2364]),
+    (2394, &[2327, 2364]),
+    (2395, &[2332, 2364]),
+    (2396, &[2337, 2364]),
+    (2397, &[2338, 2364]),
+    (2398, &[2347, 2364]),
+    (2399, &[2351, 2364]),
+    (2507, &[2503, 2494]),
+    (2508, &[2503, 2519]),
+    (2524, &[2465, 2492]),
+    (2525, &[2466, 2492]),
+    (2527, &[2479, 2492]),
+    (2611, &[2610, 2620]),
+    (2614, &[2616, 2620]),
+    (2649, &[2582, 2620]),
+    (2650, &[2583, 2620]),
+    (2651, &[2588, 2620]),
+    (2654, &[2603, 2620]),
+    (2888, &[2887, 2902]),
+    (2891, &[2887, 2878]),
+    (2892, &[2887, 2903]),
+    (2908, &[2849, 2876]),
+    (2909, &[2850, 2876]),
+    (2964, &[2962, 3031]),
+    (3018, &[3014, 3006]),
+    (3019, &[3015, 3006]),
+    (3020, &[3014, 3031]),
+    (3144, &[3142, 3158]),
+    (3264, &[3263, 3285]),
+    (3271, &[3270, 3285]),
+    (3272, &[3270, 3286]),
+    (3274, &[3270, 3266]),
+    (3275, &[3274, 3285]),
+    (3402, &[3398, 3390]),
+    (3403, &[3399, 3390]),
+    (3404, &[3398, 3415]),
+    (3546, &[3545, 3530]),
+    (3548, &[3545, 3535]),
+    (3549, &[3548, 3530]),
+    (3550, &[3545, 3551]),
+    (3907, &[3906, 4023]),
+    (3917, &[3916, 4023]),
+    (3922, &[3921, 4023]),
+    (3927, &[3926, 4023]),
+    (3932, &[3931, 4023]),
+    (3945, &[3904, 4021]),
+    (3955, &[3953, 3954]),
+    (3957, &[3953, 3956]),
+    (3958, &[4018, 3968]),
+    (3960, &[4019, 3968]),
+    (3969, &[3953, 3968]),
+    (3987, &[3986, 4023]),
+    (3997, &[3996, 4023]),
+    (4002, &[4001, 4023]),
+    (4007, &[4006, 4023]),
+    (4012, &[4011, 4023]),
+    (4025, &[3984, 4021]),
+    (4134, &[4133, 4142]),
+    (6918, &[6917, 6965]),
+    (6920, &[6919, 6965]),
+    (6922, &[6921, 6965]),
+    (6924, &[6923, 6965]),
+    (6926, &[6925, 6965]),
+    (6930, &[6929, 6965]),
+    (6971, &[6970, 6965]),
+    (6973, &[6972, 6965]),
+    (6976, &[6974, 6965]),
+    (6977, &[6975, 6965]),
+    (6979, &[6978, 6965]),
+    (7680, &[65, 805]),
+    (7681, &[97, 805]),
+    (7682, &[66, 775]),
+    (7683, &[98, 775]),
+    (7684, &[66, 803]),
+    (7685, &[98, 803]),
+    (7686, &[66, 817]),
+    (7687, &[98, 817]),
+    (7688, &[199, 769]),
+    (7689, &[231, 769]),
+    (7690, &[68, 775]),
+    (7691, &[100, 775]),
+    (7692, &[68, 803]),
+    (7693, &[100, 803]),
+    (7694, &[68, 817]),
+    (7695, &[100, 817]),
+    (7696, &[68, 807]),
+    (7697, &[100, 807]),
+    (7698, &[68, 813]),
+    (7699, &[100, 813]),
+    (7700, &[274, 768]),
+    (7701, &[275, 768]),
+    (7702, &[274, 769]),
+    (7703, &[275, 769]),
+    (7704, &[69, 813]),
+    (7705, &[101, 813]),
+    (7706, &[69, 816]),
+    (7707, &[101, 816]),
+    (7708, &[552, 774]),
+    (7709, &[553, 774]),
+    (7710, &[70, 775]),
+    (7711, &[102, 775]),
+    (7712, &[71, 772]),
+    (7713, &[103, 772]),
+    (7714, &[72, 775]),
+    (7715, &[104, 775]),
+    (7716, &[72, 803]),
+    (7717, &[104, 803]),
+    (7718, &[72, 776]),
+    (7719, &[104, 776]),
+    (7720, &[72, 807]),
+    (7721, &[104, 807]),
+    (7722, &[72, 814]),
+    (7723, &[104, 814]),
+    (7724, &[73, 816]),
+    (7725, &[105, 816]),
+    (7726, &[207, 769]),
+    (7727, &[239, 769]),
+    (7728, &[75, 769]),
+    (7729, &[107, 769]),
+    (7730, &[75, 803]),
+    (7731, &[107, 803]),
+    (7732, &[75, 817]),
+    (7733, &[107, 817]),
+    (7734, &[76, 803]),
+    (7735, &[108, 803]),
+    (7736, &[7734, 772]),
+    (7737, &[7735, 772]),
+    (7738, &[76, 817]),
+    (7739, &[108, 817]),
+    (7740, &[76, 813]),
+    (7741, &[108, 813]),
+    (7742, &[77, 769]),
+    (7743, &[109, 769]),
+    (7744, &[77, 775]),
+    (7745, &[109, 775]),
+    (7746, &[77, 803]),
+    (7747, &[109, 803]),
+    (7748, &[78, 775]),
+    (7749, &[110, 775]),
+    (7750, &[78, 803]),
+    (7751, &[110, 803]),
+    (7752, &[78, 817]),
+    (7753, &[110, 817]),
+    (7754, &[78, 813]),
+    (7755, &[110, 813]),
+    (7756, &[213, 769]),
+    (7757, &[245, 769]),
+    (7758, &[213, 776]),
+    (7759, &[245, 776]),
+    (7760, &[332, 768]),
+    (7761, &[333, 768]),
+    (7762, &[332, 769]),
+    (7763, &[333, 769]),
+    (7764, &[80, 769]),
+    (7765, &[112, 769]),
+    (7766, &[80, 775]),
+    (7767, &[112, 775]),
+    (7768, &[82, 775]),
+    (7769, &[114, 775]),
+    (7770, &[82, 803]),
+    (7771, &[114, 803]),
+    (7772, &[7770, 772]),
+    (7773, &[7771, 772]),
+    (7774, &[82, 817]),
+    (7775, &[114, 817]),
+    (7776, &[83, 775]),
+    (7777, &[115, 775]),
+    (7778, &[83, 803]),
+    (7779, &[115, 803]),
+    (7780, &[346, 775]),
+    (7781, &[347, 775]),
+    (7782, &[352, 775]),
+    (7783, &[353, 775]),
+    (7784, &[7778, 775]),
+    (7785, &[7779, 775]),
+    (7786, &[84, 775]),
+    (7787, &[116, 775]),
+    (7788, &[84, 803]),
+    (7789, &[116, 803]),
+    (7790, &[84, 817]),
+    (7791, &[116, 817]),
+    (7792, &[84, 813]),
+    (7793, &[116, 813]),
+    (7794, &[85, 804]),
+    (7795, &[117, 804]),
+    (7796, &[85, 816]),
+    (7797, &[117, 816]),
+    (7798, &[85, 813]),
+    (7799, &[117, 813]),
+    (7800, &[360, 769]),
+    (7801, &[361, 769]),
+    (7802, &[362, 776]),
+    (7803, &[363, 776]),
+    (7804, &[86, 771]),
+    (7805, &[118, 771]),
+    (7806, &[86, 803]),
+    (7807, &[118, 803]),
+    (7808, &[87, 768]),
+    (7809, &[119, 768]),
+    (7810, &[87, 769]),
+    (7811, &[119, 769]),
+    (7812, &[87, 776]),
+    (7813, &[119, 776]),
+    (7814, &[87, 775]),
+    (7815, &[119, 775]),
+    (7816, &[87, 803]),
+    (7817, &[119, 803]),
+    (7818, &[88, 775]),
+    (7819, &[120, 775]),
+    (7820, &[88, 776]),
+    (7821, &[120, 776]),
+    (7822, &[89, 775]),
+    (7823, &[121, 775]),
+    (7824, &[90, 770]),
+    (7825, &[122, 770]),
+    (7826, &[90, 803]),
+    (7827, &[122, 803]),
+    (7828, &[90, 817]),
+    (7829, &[122, 817]),
+    (7830, &[104, 817]),
+    (7831, &[116, 776]),
+    (7832, &[119, 778]),
+    (7833, &[121, 778]),
+    (7835, &[383, 775]),
+    (7840, &[65, 803]),
+    (7841, &[97, 803]),
+    (7842, &[65, 777]),
+    (7843, &[97, 777]),
+    (7844, &[194, 769]),
+    (7845, &[226, 769]),
+    (7846, &[194, 768]),
+    (7847, &[226, 768]),
+    (7848, &[194, 777]),
+    (7849, &[226, 777]),
+    (7850, &[194, 771]),
+    (7851, &[226, 771]),
+    (7852, &[7840, 770]),
+    (7853, &[7841, 770]),
+    (7854, &[258, 769]),
+    (7855, &[259, 769]),
+    (7856, &[258, 768]),
+    (7857, &[259, 768]),
+    (7858, &[258, 777]),
+    (7859, &[259, 777]),
+    (7860, &[258, 771]),
+    (7861, &[259, 771]),
+    (7862, &[7840, 774]),
+    (7863, &[7841, 774]),
+    (7864, &[69, 803]),
+    (7865, &[101, 803]),
+    (7866, &[69, 777]),
+    (7867, &[101, 777]),
+    (7868, &[69, 771]),
+    (7869, &[101, 771]),
+    (7870, &[202, 769]),
+    (7871, &[234, 769]),
+    (7872, &[202, 768]),
+    (7873, &[234, 768]),
+    (7874, &[202, 777]),
+    (7875, &[234, 777]),
+    (7876, &[202, 771]),
+    (7877, &[234, 771]),
+    (7878, &[7864, 770]),
+    (7879, &[7865, 770]),
+    (7880, &[73, 777]),
+    (7881, &[105, 777]),
+    (7882, &[73, 803]),
+    (7883, &[105, 803]),
+    (7884, &[79, 803]),
+    (7885, &[111, 803]),
+    (7886, &[79, 777]),
+    (7887, &[111, 777]),
+    (7888, &[212, 769]),
+    (7889, &[244, 769]),
+    (7890, &[212, 768]),
+    (7891, &[244, 768]),
+    (7892, &[212, 777]),
+    (7893, &[244, 777]),
+    (7894, &[212, 771]),
+    (7895, &[244, 771]),
+    (7896, &[7884, 770]),
+    (7897, &[7885, 770]),
+    (7898, &[416, 769]),
+    (7899, &[417, 769]),
+    (7900, &[416, 768]),
+    (7901, &[417, 768]),
+    (7902, &[416, 777]),
+    (7903, &[417, 777]),
+    (7904, &[416, 771]),
+    (7905, &[417, 771]),
+    (7906, &[416, 803]),
+    (7907, &[417, 803]),
+    (7908, &[85, 803]),
+    (7909, &[117, 803]),
+    (7910, &[85, 777]),
+    (7911, &[117, 777]),
+    (7912, &[431, 769]),
+    (7913, &[432, 769]),
+    (7914, &[431, 768]),
+    (7915, &[432, 768]),
+    (7916, &[431, 777]),
+    (7917, &[432, 777]),
+    (7918, &[431, 771]),
+    (7919, &[432, 771]),
+    (7920, &[431, 803]),
+    (7921, &[432, 803]),
+    (7922, &[89, 768]),
+    (7923, &[121, 768]),
+    (7924, &[89, 803]),
+    (7925, &[121, 803]),
+    (7926, &[89, 777]),
+    (7927, &[121, 777]),
+    (7928, &[89, 771]),
+    (7929, &[121, 771]),
+    (7936, &[945, 787]),
+    (7937, &[945, 788]),
+    (7938, &[7936, 768]),
+    (7939, &[7937, 768]),
+    (7940, &[7936, 769]),
+    (7941, &[7937, 769]),
+    (7942, &[7936, 834]),
+    (7943, &[7937, 834]),
+    (7944, &[913, 787]),
+    (7945, &[913, 788]),
+    (7946, &[7944, 768]),
+    (7947, &[7945, 768]),
+    (7948, &[7944, 769]),
+    (7949, &[7945, 769]),
+    (7950, &[7944, 834]),
+    (7951, &[7945, 834]),
+    (7952, &[949, 787]),
+    (7953, &[949, 788]),
+    (7954, &[7952, 768]),
+    (7955, &[7953, 768]),
+    (7956, &[7952, 769]),
+    (7957, &[7953, 769]),
+    (7960, &[917, 787]),
+    (7961, &[917, 788]),
+    (7962, &[7960, 768]),
+    (7963, &[7961, 768]),
+    (7964, &[7960, 769]),
+    (7965, &[7961, 769]),
+    (7968, &[951, 787]),
+    (7969, &[951, 788]),
+    (7970, &[7968, 768]),
+    (7971, &[7969, 768]),
+    (7972, &[7968, 769]),
+    (7973, &[7969, 769]),
+    (7974, &[7968, 834]),
+    (7975, &[7969, 834]),
+    (7976, &[919, 787]),
+    (7977, &[919, 788]),
+    (7978, &[7976, 768]),
+    (7979, &[7977, 768]),
+    (7980, &[7976, 769]),
+    (7981, &[7977, 769]),
+    (7982, &[7976, 834]),
+    (7983, &[7977, 834]),
+    (7984, &[953, 787]),
+    (7985, &[953, 788]),
+    (7986, &[7984, 768]),
+    (7987, &[7985, 768]),
+    (7988, &[7984, 769]),
+    (7989, &[7985, 769]),
+    (7990, &[7984, 834]),
+    (7991, &[7985, 834]),
+    (7992, &[921, 787]),
+    (7993, &[921, 788]),
+    (7994, &[7992, 768]),
+    (7995, &[7993, 768]),
+    (7996, &[7992, 769]),
+    (7997, &[7993, 769]),
+    (7998, &[7992, 834]),
+    (7999, &[7993, 834]),
+    (8000, &[959, 787]),
+    (8001, &[959, 788]),
+    (8002, &[8000, 768]),
+    (8003, &[8001, 768]),
+    (8004, &[8000, 769]),
+    (8005, &[8001, 769]),
+    (8008, &[927, 787]),
+    (8009, &[927, 788]),
+    (8010, &[8008, 768]),
+    (8011, &[8009, 768]),
+    (8012, &[8008, 769]),
+    (8013, &[8009, 769]),
+    (8016, &[965, 787]),
+    (8017, &[965, 788]),
+    (8018, &[8016, 768]),
+    (8019, &[8017, 768]),
+    (8020, &[8016, 769]),
+    (8021, &[8017, 769]),
+    (8022, &[8016, 834]),
+    (8023, &[8017, 834]),
+    (8025, &[933, 788]),
+    (8027, &[8025, 768]),
+    (8029, &[8025, 769]),
+    (8031, &[8025, 834]),
+    (8032, &[969, 787]),
+    (8033, &[969, 788]),
+    (8034, &[8032, 768]),
+    (8035, &[8033, 768]),
+    (8036, &[8032, 769]),
+    (8037, &[8033, 769]),
+    (8038, &[8032, 834]),
+    (8039, &[8033, 834]),
+    (8040, &[937, 787]),
+    (8041, &[937, 788]),
+    (8042, &[8040, 768]),
+    (8043, &[8041, 768]),
+    (8044, &[8040, 769]),
+    (8045, &[8041, 769]),
+    (8046, &[8040, 834]),
+    (8047, &[8041, 834]),
+    (8048, &[945, 768]),
+    (8049, &[940]),
+    (8050, &[949, 768]),
+    (8051, &[941]),
+    (8052, &[951, 768]),
+    (8053, &[942]),
+    (8054, &[953, 768]),
+    (8055, &[943]),
+    (8056, &[959, 768]),
+    (8057, &[972]),
+    (8058, &[965, 768]),
+    (8059, &[973]),
+    (8060, &[969, 768]),
+    (8061, &[974]),
+    (8064, &[7936, 837]),
+    (8065, &[7937, 837]),
+    (8066, &[7938, 837]),
+    (8067, &[7939, 837]),
+    (8068, &[7940, 837]),
+    (8069, &[7941, 837]),
+    (8070, &[7942, 837]),
+    (8071, &[7943, 837]),
+    (8072, &[7944, 837]),
+    (8073, &[7945, 837]),
+    (8074, &[7946, 837]),
+    (8075, &[7947, 837]),
+    (8076, &[7948, 837]),
+    (8077, &[7949, 837]),
+    (8078, &[7950, 837]),
+    (8079, &[7951, 837]),
+    (8080, &[7968, 837]),
+    (8081, &[7969, 837]),
+    (8082, &[7970, 837]),
+    (8083, &[7971, 837]),
+    (8084, &[7972, 837]),
+    (8085, &[7973, 837]),
+    (8086, &[7974, 837]),
+    (8087, &[7975, 837]),
+    (8088, &[7976, 837]),
+    (8089, &[7977, 837]),
+    (8090, &[7978, 837]),
+    (8091, &[7979, 837]),
+    (8092, &[7980, 837]),
+    (8093, &[7981, 837]),
+    (8094, &[7982, 837]),
+    (8095, &[7983, 837]),
+    (8096, &[8032, 837]),
+    (8097, &[8033, 837]),
+    (8098, &[8034, 837]),
+    (8099, &[8035, 837]),
+    (8100, &[8036, 837]),
+    (8101, &[8037, 837]),
+    (8102, &[8038, 837]),
+    (8103, &[8039, 837]),
+    (8104, &[8040, 837]),
+    (8105, &[8041, 837]),
+    (8106, &[8042, 837]),
+    (8107, &[8043, 837]),
+    (8108, &[8044, 837]),
+    (8109, &[8045, 837]),
+    (8110, &[8046, 837]),
+    (8111, &[8047, 837]),
+    (8112, &[945, 774]),
+    (8113, &[945, 772]),
+    (8114, &[8048, 837]),
+    (8115, &[945, 837]),
+    (8116, &[940, 837]),
+    (8118, &[945, 834]),
+    (8119, &[8118, 837]),
+    (8120, &[913, 774]),
+    (8121, &[913, 772]),
+    (8122, &[913, 768]),
+    (8123, &[902]),
+    (8124, &[913, 837]),
+    (8126, &[953]),
+    (8129, &[168, 834]),
+    (8130, &[8052, 837]),
+    (8131, &[951, 837]),
+    (8132, &[942, 837]),
+    (8134, &[951, 834]),
+    (8135, &[8134, 837]),
+    (8136, &[917, 768]),
+    (8137, &[904]),
+    (8138, &[919, 768]),
+    (8139, &[905]),
+    (8140, &[919, 837]),
+    (8141, &[8127, 768]),
+    (8142, &[8127, 769]),
+    (8143, &[8127, 834]),
+    (8144, &[953, 774]),
+    (8145, &[953, 772]),
+    (8146, &[970, 768]),
+    (8147, &[912]),
+    (8150, &[953, 834]),
+    (8151, &[970, 834]),
+    (8152, &[921, 774]),
+    (8153, &[921, 772]),
+    (8154, &[921, 768]),
+    (8155, &[906]),
+    (8157, &[8190, 768]),
+    (8158, &[8190, 769]),
+    (8159, &[8190, 834]),
+    (8160, &[965, 774]),
+    (8161, &[965, 772]),
+    (8162, &[971, 768]),
+    (8163, &[944]),
+    (8164, &[961, 787]),
+    (8165, &[961, 788]),
+    (8166, &[965, 834]),
+    (8167, &[971, 834]),
+    (8168, &[933, 774]),
+    (8169, &[933, 772]),
+    (8170, &[933, 768]),
+    (8171, &[910]),
+    (8172, &[929, 788]),
+    (8173, &[168, 768]),
+    (8174, &[901]),
+    (8175, &[96]),
+    (8178, &[8060, 837]),
+    (8179, &[969, 837]),
+    (8180, &[974, 837]),
+    (8182, &[969, 834]),
+    (8183, &[8182, 837]),
+    (8184, &[927, 768]),
+    (8185, &[908]),
+    (8186, &[937, 768]),
+    (8187, &[911]),
+    (8188, &[937, 837]),
+    (8189, &[180]),
+    (8192, &[8194]),
+    (8193, &[8195]),
+    (8486, &[937]),
+    (8490, &[75]),
+    (8491, &[197]),
+    (8602, &[8592, 824]),
+    (8603, &[8594, 824]),
+    (8622, &[8596, 824]),
+    (8653, &[8656, 824]),
+    (8654, &[8660, 824]),
+    (8655, &[8658, 824]),
+    (8708, &[8707, 824]),
+    (8713, &[8712, 824]),
+    (8716, &[8715, 824]),
+    (8740, &[8739, 824]),
+    (8742, &[8741, 824]),
+    (8769, &[8764, 824]),
+    (8772, &[8771, 824]),
+    (8775, &[8773, 824]),
+    (8777, &[8776, 824]),
+    (8800, &[61, 824]),
+    (8802, &[8801, 824]),
+    (8813, &[8781, 824]),
+    (8814, &[60, 824]),
+    (8815, &[62, 824]),
+    (8816, &[8804, 824]),
+    (8817, &[8805, 824]),
+    (8820, &[8818, 824]),
+    (8821, &[8819, 824]),
+    (8824, &[8822, 824]),
+    (8825, &[8823, 824]),
+    (8832, &[8826, 824]),
+    (8833, &[8827, 824]),
+    (8836, &[8834, 824]),
+    (8837, &[8835, 824]),
+    (8840, &[8838, 824]),
+    (8841, &[8839, 824]),
+    (8876, &[8866, 824]),
+    (8877, &[8872, 824]),
+    (8878, &[8873, 824]),
+    (8879, &[8875, 824]),
+    (8928, &[8828, 824]),
+    (8929, &[8829, 824]),
+    (8930, &[8849, 824]),
+    (8931, &[8850, 824]),
+    (8938, &[8882, 824]),
+    (8939, &[8883, 824]),
+    (8940, &[8884, 824]),
+    (8941, &[8885, 824]),
+    (9001, &[12296]),
+    (9002, &[12297]),
+    (10972, &[10973, 824]),
+    (12364, &[12363, 12441]),
+    (12366, &[12365, 12441]),
+    (12368, &[12367, 12441]),
+    (12370, &[12369, 12441]),
+    (12372, &[12371, 12441]),
+    (12374, &[12373, 12441]),
+    (12376, &[12375, 12441]),
+    (12378, &[12377, 12441]),
+    (12380, &[12379, 12441]),
+    (12382, &[12381, 12441]),
+    (12384, &[12383, 12441]),
+    (12386, &[12385, 12441]),
+    (12389, &[12388, 12441]),
+    (12391, &[12390, 12441]),
+    (12393, &[12392, 12441]),
+    (12400, &[12399, 12441]),
+    (12401, &[12399, 12442]),
+    (12403, &[12402, 12441]),
+    (12404, &[12402, 12442]),
+    (12406, &[12405, 12441]),
+    (12407, &[12405, 12442]),
+    (12409, &[12408, 12441]),
+    (12410, &[12408, 12442]),
+    (12412, &[12411, 12441]),
+    (12413, &[12411, 12442]),
+    (12436, &[12358, 12441]),
+    (12446, &[12445, 12441]),
+    (12460, &[12459, 12441]),
+    (12462, &[12461, 12441]),
+    (12464, &[12463, 12441]),
+    (12466, &[12465, 12441]),
+    (12468, &[12467, 12441]),
+    (12470, &[12469, 12441]),
+    (12472, &[12471, 12441]),
+    (12474, &[12473, 12441]),
+    (12476, &[12475, 12441]),
+    (12478, &[12477, 12441]),
+    (12480, &[12479, 12441]),
+    (12482, &[12481, 12441]),
+    (12485, &[12484, 12441]),
+    (12487, &[12486, 12441]),
+    (12489, &[12488, 12441]),
+    (12496, &[12495, 12441]),
+    (12497, &[12495, 12442]),
+    (12499, &[12498, 12441]),
+    (12500, &[12498, 12442]),
+    (12502, &[12501, 12441]),
+    (12503, &[12501, 12442]),
+    (12505, &[12504, 12441]),
+    (12506, &[12504, 12442]),
+    (12508, &[12507, 12441]),
+    (12509, &[12507, 12442]),
+    (12532, &[12454, 12441]),
+    (12535, &[12527, 12441]),
+    (12536, &[12528, 12441]),
+    (12537, &[12529, 12441]),
+    (12538, &[12530, 12441]),
+    (12542, &[12541, 12441]),
+    (63744, &[35912]),
+    (63745, &[26356]),
+    (63746, &[36554]),
+    (63747, &[36040]),
+    (63748, &[28369]),
+    (63749, &[20018]),
+    (63750, &[21477]),
+    (63751, &[40860]),
+    (63752, &[40860]),
+    (63753, &[22865]),
+    (63754, &[37329]),
+    (63755, &[21895]),
+    (63756, &[22856]),
+    (63757, &[25078]),
+    (63758, &[30313]),
+    (63759, &[32645]),
+    (63760, &[34367]),
+    (63761, &[34746]),
+    (63762, &[35064]),
+    (63763, &[37007]),
+    (63764, &[27138]),
+    (63765, &[27931]),
+    (63766, &[28889]),
+    (63767, &[29662]),
+    (63768, &[33853]),
+    (63769, &[37226]),
+    (63770, &[39409]),
+    (63771, &[20098]),
+    (63772, &[21365]),
+    (63773, &[27396]),
+    (63774, &[29211]),
+    (63775, &[34349]),
+    (63776, &[40478]),
+    (63777, &[23888]),
+    (63778, &[28651]),
+    (63779, &[34253]),
+    (63780, &[35172]),
+    (63781, &[25289]),
+    (63782, &[33240]),
+    (63783, &[34847]),
+    (63784, &[24266]),
+    (63785, &[26391]),
+    (63786, &[28010]),
+    (63787, &[29436]),
+    (63788, &[37070]),
+    (63789, &[20358]),
+    (63790, &[20919]),
+    (63791, &[21214]),
+    (63792, &[25796]),
+    (63793, &[27347]),
+    (63794, &[29200]),
+    (63795, &[30439]),
+    (63796, &[32769]),
+    (63797, &[34310]),
+    (63798, &[34396]),
+    (63799, &[36335]),
+    (63800, &[38706]),
+    (63801, &[39791]),
+    (63802, &[40442]),
+    (63803, &[30860]),
+    (63804, &[31103]),
+    (63805, &[32160]),
+    (63806, &[33737]),
+    (63807, &[37636]),
+    (63808, &[40575]),
+    (63809, &[35542]),
+    (63810, &[22751]),
+    (63811, &[24324]),
+    (63812, &[31840]),
+    (63813, &[32894]),
+    (63814, &[29282]),
+    (63815, &[30922]),
+    (63816, &[36034]),
+    (63817, &[38647]),
+    (63818, &[22744]),
+    (63819, &[23650]),
+    (63820, &[27155]),
+    (63821, &[28122]),
+    (63822, &[28431]),
+    (63823, &[32047]),
+    (63824, &[32311]),
+    (63825, &[38475]),
+    (63826, &[21202]),
+    (63827, &[32907]),
+    (63828, &[20956]),
+    (63829, &[20940]),
+    (63830, &[31260]),
+    (63831, &[32190]),
+    (63832, &[33777]),
+    (63833, &[38517]),
+    (63834, &[35712]),
+    (63835, &[25295]),
+    (63836, &[27138]),
+    (63837, &[35582]),
+    (63838, &[20025]),
+    (63839, &[23527]),
+    (63840, &[24594]),
+    (63841, &[29575]),
+    (63842, &[30064]),
+    (63843, &[21271]),
+    (63844, &[30971]),
+    (63845, &[20415]),
+    (63846, &[24489]),
+    (63847, &[19981]),
+    (63848, &[27852]),
+    (63849, &[25976]),
+    (63850, &[32034]),
+    (63851, &[21443]),
+    (63852, &[22622]),
+    (63853, &[30465]),
+    (63854, &[33865]),
+    (63855, &[35498]),
+    (63856, &[27578]),
+    (63857, &[36784]),
+    (63858, &[27784]),
+    (63859, &[25342]),
+    (63860, &[33509]),
+    (63861, &[25504]),
+    (63862, &[30053]),
+    (63863, &[20142]),
+    (63864, &[20841]),
+    (63865, &[20937]),
+    (63866, &[26753]),
+    (63867, &[31975]),
+    (63868, &[33391]),
+    (63869, &[35538]),
+    (63870, &[37327]),
+    (63871, &[21237]),
+    (63872, &[21570]),
+    (63873, &[22899]),
+    (63874, &[24300]),
+    (63875, &[26053]),
+    (63876, &[28670]),
+    (63877, &[31018]),
+    (63878, &[38317]),
+    (63879, &[39530]),
+    (63880, &[40599]),
+    (63881, &[40654]),
+    (63882, &[21147]),
+    (63883, &[26310]),
+    (63884, &[27511]),
+    (63885, &[36706]),
+    (63886, &[24180]),
+    (63887, &[24976]),
+    (63888, &[25088]),
+    (63889, &[25754]),
+    (63890, &[28451]),
+    (63891, &[29001]),
+    (63892, &[29833]),
+    (63893, &[31178]),
+    (63894, &[32244]),
+    (63895, &[32879]),
+    (63896, &[36646]),
+    (63897, &[34030]),
+    (63898, &[36899]),
+    (63899, &[37706]),
+    (63900, &[21015]),
+    (63901, &[21155]),
+    (63902, &[21693]),
+    (63903, &[28872]),
+    (63904, &[35010]),
+    (63905, &[35498]),
+    (63906, &[24265]),
+    (63907, &[24565]),
+    (63908, &[25467]),
+    (63909, &[27566]),
+    (63910, &[31806]),
+    (63911, &[29557]),
+    (63912, &[20196]),
+    (63913, &[22265]),
+    (63914, &[23527]),
+    (63915, &[23994]),
+    (63916, &[24604]),
+    (63917, &[29618]),
+    (63918, &[29801]),
+    (63919, &[32666]),
+    (63920, &[32838]),
+    (63921, &[37428]),
+    (63922, &[38646]),
+    (63923, &[38728]),
+    (63924, &[38936]),
+    (63925, &[20363]),
+    (63926, &[31150]),
+    (63927, &[37300]),
+    (63928, &[38584]),
+    (63929, &[24801]),
+    (63930, &[20102]),
+    (63931, &[20698]),
+    (63932, &[23534]),
+    (63933, &[23615]),
+    (63934, &[26009]),
+    (63935, &[27138]),
+    (63936, &[29134]),
+    (63937, &[30274]),
+    (63938, &[34044]),
+    (63939, &[36988]),
+    (63940, &[40845]),
+    (63941, &[26248]),
+    (63942, &[38446]),
+    (63943, &[21129]),
+    (63944, &[26491]),
+    (63945, &[26611]),
+    (63946, &[27969]),
+    (63947, &[28316]),
+    (63948, &[29705]),
+    (63949, &[30041]),
+    (63950, &[30827]),
+    (63951, &[32016]),
+    (63952, &[39006]),
+    (63953, &[20845]),
+    (63954, &[25134]),
+    (63955, &[38520]),
+    (63956, &[20523]),
+    (63957, &[23833]),
+    (63958, &[28138]),
+    (63959, &[36650]),
+    (63960, &[24459]),
+    (63961, &[24900]),
+    (63962, &[26647]),
+    (63963, &[29575]),
+    (63964, &[38534]),
+    (63965, &[21033]),
+    (63966, &[21519]),
+    (63967, &[23653]),
+    (63968, &[26131]),
+    (63969, &[26446]),
+    (63970, &[26792]),
+    (63971, &[27877]),
+    (63972, &[29702]),
+    (63973, &[30178]),
+    (63974, &[32633]),
+    (63975, &[35023]),
+    (63976, &[35041]),
+    (63977, &[37324]),
+    (63978, &[38626]),
+    (63979, &[21311]),
+    (63980, &[28346]),
+    (63981, &[21533]),
+    (63982, &[29136]),
+    (63983, &[29848]),
+    (63984, &[34298]),
+    (63985, &[38563]),
+    (63986, &[40023]),
+    (63987, &[40607]),
+    (63988, &[26519]),
+    (63989, &[28107]),
+    (63990, &[33256]),
+    (63991, &[31435]),
+    (63992, &[31520]),
+    (63993, &[31890]),
+    (63994, &[29376]),
+    (63995, &[28825]),
+    (63996, &[35672]),
+    (63997, &[20160]),
+    (63998, &[33590]),
+    (63999, &[21050]),
+    (64000, &[20999]),
+    (64001, &[24230]),
+    (64002, &[25299]),
+    (64003, &[31958]),
+    (64004, &[23429]),
+    (64005, &[27934]),
+    (64006, &[26292]),
+    (64007, &[36667]),
+    (64008, &[34892]),
+    (64009, &[38477]),
+    (64010, &[35211]),
+    (64011, &[24275]),
+    (64012, &[20800]),
+    (64013, &[21952]),
+    (64016, &[22618]),
+    (64018, &[26228]),
+    (64021, &[20958]),
+    (64022, &[29482]),
+    (64023, &[30410]),
+    (64024, &[31036]),
+    (64025, &[31070]),
+    (64026, &[31077]),
+    (64027, &[31119]),
+    (64028, &[38742]),
+    (64029, &[31934]),
+    (64030, &[32701]),
+    (64032, &[34322]),
+    (64034, &[35576]),
+    (64037, &[36920]),
+    (64038, &[37117]),
+    (64042, &[39151]),
+    (64043, &[39164]),
+    (64044, &[39208]),
+    (64045, &[40372]),
+    (64046, &[37086]),
+    (64047, &[38583]),
+    (64048, &[20398]),
+    (64049, &[20711]),
+    (64050, &[20813]),
+    (64051, &[21193]),
+    (64052, &[21220]),
+    (64053, &[21329]),
+    (64054, &[21917]),
+    (64055, &[22022]),
+    (64056, &[22120]),
+    (64057, &[22592]),
+    (64058, &[22696]),
+    (64059, &[23652]),
+    (64060, &[23662]),
+    (64061, &[24724]),
+    (64062, &[24936]),
+    (64063, &[24974]),
+    (64064, &[25074]),
+    (64065, &[25935]),
+    (64066, &[26082]),
+    (64067, &[26257]),
+    (64068, &[26757]),
+    (64069, &[28023]),
+    (64070, &[28186]),
+    (64071, &[28450]),
+    (64072, &[29038]),
+    (64073, &[29227]),
+    (64074, &[29730]),
+    (64075, &[30865]),
+    (64076, &[31038]),
+    (64077, &[31049]),
+    (64078, &[31048]),
+    (64079, &[31056]),
+    (64080, &[31062]),
+    (64081, &[31069]),
+    (64082, &[31117]),
+    (64083, &[31118]),
+    (64084, &[31296]),
+    (64085, &[31361]),
+    (64086, &[31680]),
+    (64087, &[32244]),
+    (64088, &[32265]),
+    (64089, &[32321]),
+    (64090, &[32626]),
+    (64091, &[32773]),
+    (64092, &[33261]),
+    (64093, &[33401]),
+    (64094, &[33401]),
+    (64095, &[33879]),
+    (64096, &[35088]),
+    (64097, &[35222]),
+    (64098, &[35585]),
+    (64099, &[35641]),
+    (64100, &[36051]),
+    (64101, &[36104]),
+    (64102, &[36790]),
+    (64103, &[36920]),
+    (64104, &[38627]),
+    (64105, &[38911]),
+    (64106, &[38971]),
+    (64107, &[24693]),
+    (64108, &[148206]),
+    (64109, &[33304]),
+    (64112, &[20006]),
+    (64113, &[20917]),
+    (64114, &[20840]),
+    (64115, &[20352]),
+    (64116, &[20805]),
+    (64117, &[20864]),
+    (64118, &[21191]),
+    (64119, &[21242]),
+    (64120, &[21917]),
+    (64121, &[21845]),
+    (64122, &[21913]),
+    (64123, &[21986]),
+    (64124, &[22618]),
+    (64125, &[22707]),
+    (64126, &[22852]),
+    (64127, &[22868]),
+    (64128, &[23138]),
+    (64129, &[23336]),
+    (64130, &[24274]),
+    (64131, &[24281]),
+    (64132, &[24425]),
+    (64133, &[24493]),
+    (64134, &[24792]),
+    (64135, &[24910]),
+    (64136, &[24840]),
+    (64137, &[24974]),
+    (64138, &[24928]),
+    (64139, &[25074]),
+    (64140, &[25140]),
+    (64141, &[25540]),
+    (64142, &[25628]),
+    (64143, &[25682]),
+    (64144, &[25942]),
+    (64145, &[26228]),
+    (64146, &[26391]),
+    (64147, &[26395]),
+    (64148, &[26454]),
+    (64149, &[27513]),
+    (64150, &[27578]),
+    (64151, &[27969]),
+    (64152, &[28379]),
+    (64153, &[28363]),
+    (64154, &[28450]),
+    (64155, &[28702]),
+    (64156, &[29038]),
+    (64157, &[30631]),
+    (64158, &[29237]),
+    (64159, &[29359]),
+    (64160, &[29482]),
+    (64161, &[29809]),
+    (64162, &[29958]),
+    (64163, &[30011]),
+    (64164, &[30237]),
+    (64165, &[30239]),
+    (64166, &[30410]),
+    (64167, &[30427]),
+    (64168, &[30452]),
+    (64169, &[30538]),
+    (64170, &[30528]),
+    (64171, &[30924]),
+    (64172, &[31409]),
+    (64173, &[31680]),
+    (64174, &[31867]),
+    (64175, &[32091]),
+    (64176, &[32244]),
+    (64177, &[32574]),
+    (64178, &[32773]),
+    (64179, &[33618]),
+    (64180, &[33775]),
+    (64181, &[34681]),
+    (64182, &[35137]),
+    (64183, &[35206]),
+    (64184, &[35222]),
+    (64185, &[35519]),
+    (64186, &[35576]),
+    (64187, &[35531]),
+    (64188, &[35585]),
+    (64189, &[35582]),
+    (64190, &[35565]),
+    (64191, &[35641]),
+    (64192, &[35722]),
+    (64193, &[36104]),
+    (64194, &[36664]),
+    (64195, &[36978]),
+    (64196, &[37273]),
+    (64197, &[37494]),
+    (64198, &[38524]),
+    (64199, &[38627]),
+    (64200, &[38742]),
+    (64201, &[38875]),
+    (64202, &[38911]),
+    (64203, &[38923]),
+    (64204, &[38971]),
+    (64205, &[39698]),
+    (64206, &[40860]),
+    (64207, &[141386]),
+    (64208, &[141380]),
+    (64209, &[144341]),
+    (64210, &[15261]),
+    (64211, &[16408]),
+    (64212, &[16441]),
+    (64213, &[152137]),
+    (64214, &[154832]),
+    (64215, &[163539]),
+    (64216, &[40771]),
+    (64217, &[40846]),
+    (64285, &[1497, 1460]),
+    (64287, &[1522, 1463]),
+    (64298, &[1513, 1473]),
+    (64299, &[1513, 1474]),
+    (64300, &[64329, 1473]),
+    (64301, &[64329, 1474]),
+    (64302, &[1488, 1463]),
+    (64303, &[1488, 1464]),
+    (64304, &[1488, 1468]),
+    (64305, &[1489, 1468]),
+    (64306, &[1490, 1468]),
+    (64307, &[1491, 1468]),
+    (64308, &[1492, 1468]),
+    (64309, &[1493, 1468]),
+    (64310, &[1494, 1468]),
+    (64312, &[1496, 1468]),
+    (64313, &[1497, 1468]),
+    (64314, &[1498, 1468]),
+    (64315, &[1499, 1468]),
+    (64316, &[1500, 1468]),
+    (64318, &[1502, 1468]),
+    (64320, &[1504, 1468]),
+    (64321, &[1505, 1468]),
+    (64323, &[1507, 1468]),
+    (64324, &[1508, 1468]),
+    (64326, &[1510, 1468]),
+    (64327, &[1511, 1468]),
+    (64328, &[1512, 1468]),
+    (64329, &[1513, 1468]),
+    (64330, &[1514, 1468]),
+    (64331, &[1493, 1465]),
+    (64332, &[1489, 1471]),
+    (64333, &[1499, 1471]),
+    (64334, &[1508, 1471]),
+    (69786, &[69785, 69818]),
+    (69788, &[69787, 69818]),
+    (69803, &[69797, 69818]),
+    (69934, &[69937, 69927]),
+    (69935, &[69938, 69927]),
+    (70475, &[70471, 70462]),
+    (70476, &[70471, 70487]),
+    (70843, &[70841, 70842]),
+    (70844, &[70841, 70832]),
+    (70846, &[70841, 70845]),
+    (71098, &[71096, 71087]),
+    (71099, &[71097, 71087]),
+    (71992, &[71989, 71984]),
+    (119134, &[119127, 119141]),
+    (119135, &[119128, 119141]),
+    (119136, &[119135, 119150]),
+    (119137, &[119135, 119151]),
+    (119138, &[119135, 119152]),
+    (119139, &[119135, 119153]),
+    (119140, &[119135, 119154]),
+    (119227, &[119225, 119141]),
+    (119228, &[119226, 119141]),
+    (119229, &[119227, 119150]),
+    (119230, &[119228, 119150]),
+    (119231, &[119227, 119151]),
+    (119232, &[119228, 119151]),
+    (194560, &[20029]),
+    (194561, &[20024]),
+    (194562, &[20033]),
+    (194563, &[131362]),
+    (194564, &[20320]),
+    (194565, &[20398]),
+    (194566, &[20411]),
+    (194567, &[20482]),
+    (194568, &[20602]),
+    (194569, &[20633]),
+    (194570, &[20711]),
+    (194571, &[20687]),
+    (194572, &[13470]),
+    (194573, &[132666]),
+    (194574, &[20813]),
+    (194575, &[20820]),
+    (194576, &[20836]),
+    (194577, &[20855]),
+    (194578, &[132380]),
+    (194579, &[13497]),
+    (194580, &[20839]),
+    (194581, &[20877]),
+    (194582, &[132427]),
+    (194583, &[20887]),
+    (194584, &[20900]),
+    (194585, &[20172]),
+    (194586, &[20908]),
+    (194587, &[20917]),
+    (194588, &[168415]),
+    (194589, &[20981]),
+    (194590, &[20995]),
+    (194591, &[13535]),
+    (194592, &[21051]),
+    (194593, &[21062]),
+    (194594, &[21106]),
+    (194595, &[21111]),
+    (194596, &[13589]),
+    (194597, &[21191]),
+    (194598, &[21193]),
+    (194599, &[21220]),
+    (194600, &[21242]),
+    (194601, &[21253]),
+    (194602, &[21254]),
+    (194603, &[21271]),
+    (194604, &[21321]),
+    (194605, &[21329]),
+    (194606, &[21338]),
+    (194607, &[21363]),
+    (194608, &[21373]),
+    (194609, &[21375]),
+    (194610, &[21375]),
+    (194611, &[21375]),
+    (194612, &[133676]),
+    (194613, &[28784]),
+    (194614, &[21450]),
+    (194615, &[21471]),
+    (194616, &[133987]),
+    (194617, &[21483]),
+    (194618, &[21489]),
+    (194619, &[21510]),
+    (194620, &[21662]),
+    (194621, &[21560]),
+    (194622, &[21576]),
+    (194623, &[21608]),
+    (194624, &[21666]),
+    (194625, &[21750]),
+    (194626, &[21776]),
+    (194627, &[21843]),
+    (194628, &[21859]),
+    (194629, &[21892]),
+    (194630, &[21892]),
+    (194631, &[21913]),
+    (194632, &[21931]),
+    (194633, &[21939]),
+    (194634, &[21954]),
+    (194635, &[22294]),
+    (194636, &[22022]),
+    (194637, &[22295]),
+    (194638, &[22097]),
+    (194639, &[22132]),
+    (194640, &[20999]),
+    (194641, &[22766]),
+    (194642, &[22478]),
+    (194643, &[22516]),
+    (194644, &[22541]),
+    (194645, &[22411]),
+    (194646, &[22578]),
+    (194647, &[22577]),
+    (194648, &[22700]),
+    (194649, &[136420]),
+    (194650, &[22770]),
+    (194651, &[22775]),
+    (194652, &[22790]),
+    (194653, &[22810]),
+    (194654, &[22818]),
+    (194655, &[22882]),
+    (194656, &[136872]),
+    (194657, &[136938]),
+    (194658, &[23020]),
+    (194659, &[23067]),
+    (194660, &[23079]),
+    (194661, &[23000]),
+    (194662, &[23142]),
+    (194663, &[14062]),
+    (194664, &[14076]),
+    (194665, &[23304]),
+    (194666, &[23358]),
+    (194667, &[23358]),
+    (194668, &[137672]),
+    (194669, &[23491]),
+    (194670, &[23512]),
+    (194671, &[23527]),
+    (194672, &[23539]),
+    (194673, &[138008]),
+    (194674, &[23551]),
+    (194675, &[23558]),
+    (194676, &[24403]),
+    (194677, &[23586]),
+    (194678, &[14209]),
+    (194679, &[23648]),
+    (194680, &[23662]),
+    (194681, &[23744]),
+    (194682, &[23693]),
+    (194683, &[138724]),
+    (194684, &[23875]),
+    (194685, &[138726]),
+    (194686, &[23918]),
+    (194687, &[23915]),
+    (194688, &[23932]),
+    (194689, &[24033]),
+    (194690, &[24034]),
+    (194691, &[14383]),
+    (194692, &[24061]),
+    (194693, &[24104]),
+    (194694, &[24125]),
+    (194695, &[24169]),
+    (194696, &[14434]),
+    (194697, &[139651]),
+    (194698, &[14460]),
+    (194699, &[24240]),
+    (194700, &[24243]),
+    (194701, &[24246]),
+    (194702, &[24266]),
+    (194703, &[172946]),
+    (194704, &[24318]),
+    (194705, &[140081]),
+    (194706, &[140081]),
+    (194707, &[33281]),
+    (194708, &[24354]),
+    (194709, &[24354]),
+    (194710, &[14535]),
+    (194711, &[144056]),
+    (194712, &[156122]),
+    (194713, &[24418]),
+    (194714, &[24427]),
+    (194715, &[14563]),
+    (194716, &[24474]),
+    (194717, &[24525]),
+    (194718, &[24535]),
+    (194719, &[24569]),
+    (194720, &[24705]),
+    (194721, &[14650]),
+    (194722, &[14620]),
+    (194723, &[24724]),
+    (194724, &[141012]),
+    (194725, &[24775]),
+    (194726, &[24904]),
+    (194727, &[24908]),
+    (194728, &[24910]),
+    (194729, &[24908]),
+    (194730, &[24954]),
+    (194731, &[24974]),
+    (194732, &[25010]),
+    (194733, &[24996]),
+    (194734, &[25007]),
+    (194735, &[25054]),
+    (194736, &[25074]),
+    (194737, &[25078]),
+    (194738, &[25104]),
+    (194739, &[25115]),
+    (194740, &[25181]),
+    (194741, &[25265]),
+    (194742, &[25300]),
+    (194743, &[25424]),
+    (194744, &[142092]),
+    (194745, &[25405]),
+    (194746, &[25340]),
+    (194747, &[25448]),
+    (194748, &[25475]),
+    (194749, &[25572]),
+    (194750, &[142321]),
+    (194751, &[25634]),
+    (194752, &[25541]),
+    (194753, &[25513]),
+    (194754, &[14894]),
+    (194755, &[25705]),
+    (194756, &[25726]),
+    (194757, &[25757]),
+    (194758, &[25719]),
+    (194759, &[14956]),
+    (194760, &[25935]),
+    (194761, &[25964]),
+    (194762, &[143370]),
+    (194763, &[26083]),
+    (194764, &[26360]),
+    (194765, &[26185]),
+    (194766, &[15129]),
+    (194767, &[26257]),
+    (194768, &[15112]),
+    (194769, &[15076]),
+    (194770, &[20882]),
+    (194771, &[20885]),
+    (194772, &[26368]),
+    (194773, &[26268]),
+    (194774, &[32941]),
+    (194775, &[17369]),
+    (194776, &[26391]),
+    (194777, &[26395]),
+    (194778, &[26401]),
+    (194779, &[26462]),
+    (194780, &[26451]),
+    (194781, &[144323]),
+    (194782, &[15177]),
+    (194783, &[26618]),
+    (194784, &[26501]),
+    (194785, &[26706]),
+    (194786, &[26757]),
+    (194787, &[144493]),
+    (194788, &[26766]),
+    (194789, &[26655]),
+    (194790, &[26900]),
+    (194791, &[15261]),
+    (194792, &[26946]),
+    (194793, &[27043]),
+    (194794, &[27114]),
+    (194795, &[27304]),
+    (194796, &[145059]),
+    (194797, &[27355]),
+    (194798, &[15384]),
+    (194799, &[27425]),
+    (194800, &[145575]),
+    (194801, &[27476]),
+    (194802, &[15438]),
+    (194803, &[27506]),
+    (194804, &[27551]),
+    (194805, &[27578]),
+    (194806, &[27579]),
+    (194807, &[146061]),
+    (194808, &[138507]),
+    (194809, &[146170]),
+    (194810, &[27726]),
+    (194811, &[146620]),
+    (194812, &[27839]),
+    (194813, &[27853]),
+    (194814, &[27751]),
+    (194815, &[27926]),
+    (194816, &[27966]),
+    (194817, &[28023]),
+    (194818, &[27969]),
+    (194819, &[28009]),
+    (194820, &[28024]),
+    (194821, &[28037]),
+    (194822, &[146718]),
+    (194823, &[27956]),
+    (194824, &[28207]),
+    (194825, &[28270]),
+    (194826, &[15667]),
+    (194827, &[28363]),
+    (194828, &[28359]),
+    (194829, &[147153]),
+    (194830, &[28153]),
+    (194831, &[28526]),
+    (194832, &[147294]),
+    (194833, &[147342]),
+    (194834, &[28614]),
+    (194835, &[28729]),
+    (194836, &[28702]),
+    (194837, &[28699]),
+    (194838, &[15766]),
+    (194839, &[28746]),
+    (194840, &[28797]),
+    (194841, &[28791]),
+    (194842, &[28845]),
+    (194843, &[132389]),
+    (194844, &[28997]),
+    (194845, &[148067]),
+    (194846, &[29084]),
+    (194847, &[148395]),
+    (194848, &[29224]),
+    (194849, &[29237]),
+    (194850, &[29264]),
+    (194851, &[149000]),
+    (194852, &[29312]),
+    (194853, &[29333]),
+    (194854, &[149301]),
+    (194855, &[149524]),
+    (194856, &[29562]),
+    (194857, &[29579]),
+    (194858, &[16044]),
+    (194859, &[29605]),
+    (194860, &[16056]),
+    (194861, &[16056]),
+    (194862, &[29767]),
+    (194863, &[29788]),
+    (194864, &[29809]),
+    (194865, &[29829]),
+    (194866, &[29898]),
+    (194867, &[16155]),
+    (194868, &[29988]),
+    (194869, &[150582]),
+    (194870, &[30014]),
+    (194871, &[150674]),
+    (194872, &[30064]),
+    (194873, &[139679]),
+    (194874, &[30224]),
+    (194875, &[151457]),
+    (194876, &[151480]),
+    (194877, &[151620]),
+    (194878, &[16380]),
+    (194879, &[16392]),
+    (194880, &[30452]),
+    (194881, &[151795]),
+    (194882, &[151794]),
+    (194883, &[151833]),
+    (194884, &[151859]),
+    (194885, &[30494]),
+    (194886, &[30495]),
+    (194887, &[30495]),
+    (194888, &[30538]),
+    (194889, &[16441]),
+    (194890, &[30603]),
+    (194891, &[16454]),
+    (194892, &[16534]),
+    (194893, &[152605]),
+    (194894, &[30798]),
+    (194895, &[30860]),
+    (194896, &[30924]),
+    (194897, &[16611]),
+    (194898, &[153126]),
+    (194899, &[31062]),
+    (194900, &[153242]),
+    (194901, &[153285]),
+    (194902, &[31119]),
+    (194903, &[31211]),
+    (194904, &[16687]),
+    (194905, &[31296]),
+    (194906, &[31306]),
+    (194907, &[31311]),
+    (194908, &[153980]),
+    (194909, &[154279]),
+    (194910, &[154279]),
+    (194911, &[31470]),
+    (194912, &[16898]),
+    (194913, &[154539]),
+    (194914, &[31686]),
+    (194915, &[31689]),
+    (194916, &[16935]),
+    (194917, &[154752]),
+    (194918, &[31954]),
+    (194919, &[17056]),
+    (194920, &[31976]),
+    (194921, &[31971]),
+    (194922, &[32000]),
+    (194923, &[155526]),
+    (194924, &[32099]),
+    (194925, &[17153]),
+    (194926, &[32199]),
+    (194927, &[32258]),
+    (194928, &[32325]),
+    (194929, &[17204]),
+    (194930, &[156200]),
+    (194931, &[156231]),
+    (194932, &[17241]),
+    (194933, &[156377]),
+    (194934, &[32634]),
+    (194935, &[156478]),
+    (194936, &[32661]),
+    (194937, &[32762]),
+    (194938, &[32773]),
+    (194939, &[156890]),
+    (194940, &[156963]),
+    (194941, &[32864]),
+    (194942, &[157096]),
+    (194943, &[32880]),
+    (194944, &[144223]),
+    (194945, &[17365]),
+    (194946, &[32946]),
+    (194947, &[33027]),
+    (194948, &[17419]),
+    (194949, &[33086]),
+    (194950, &[23221]),
+    (194951, &[157607]),
+    (194952, &[157621]),
+    (194953, &[144275]),
+    (194954, &[144284]),
+    (194955, &[33281]),
+    (194956, &[33284]),
+    (194957, &[36766]),
+    (194958, &[17515]),
+    (194959, &[33425]),
+    (194960, &[33419]),
+    (194961, &[33437]),
+    (194962, &[21171]),
+    (194963, &[33457]),
+    (194964, &[33459]),
+    (194965, &[33469]),
+    (194966, &[33510]),
+    (194967, &[158524]),
+    (194968, &[33509]),
+    (194969, &[33565]),
+    (194970, &[33635]),
+    (194971, &[33709]),
+    (194972, &[33571]),
+    (194973, &[33725]),
+    (194974, &[33767]),
+    (194975, &[33879]),
+    (194976, &[33619]),
+    (194977, &[33738]),
+    (194978, &[33740]),
+    (194979, &[33756]),
+    (194980, &[158774]),
+    (194981, &[159083]),
+    (194982, &[158933]),
+    (194983, &[17707]),
+    (194984, &[34033]),
+    (194985, &[34035]),
+    (194986, &[34070]),
+    (194987, &[160714]),
+    (194988, &[34148]),
+    (194989, &[159532]),
+    (194990, &[17757]),
+    (194991, &[17761]),
+    (194992, &[159665]),
+    (194993, &[159954]),
+    (194994, &[17771]),
+    (194995, &[34384]),
+    (194996, &[34396]),
+    (194997, &[34407]),
+    (194998, &[34409]),
+    (194999, &[34473]),
+    (195000, &[34440]),
+    (195001, &[34574]),
+    (195002, &[34530]),
+    (195003, &[34681]),
+    (195004, &[34600]),
+    (195005, &[34667]),
+    (195006, &[34694]),
+    (195007, &[17879]),
+    (195008, &[34785]),
+    (195009, &[34817]),
+    (195010, &[17913]),
+    (195011, &[34912]),
+    (195012, &[34915]),
+    (195013, &[161383]),
+    (195014, &[35031]),
+    (195015, &[35038]),
+    (195016, &[17973]),
+    (195017, &[35066]),
+    (195018, &[13499]),
+    (195019, &[161966]),
+    (195020, &[162150]),
+    (195021, &[18110]),
+    (195022, &[18119]),
+    (195023, &[35488]),
+    (195024, &[35565]),
+    (195025, &[35722]),
+    (195026, &[35925]),
+    (195027, &[162984]),
+    (195028, &[36011]),
+    (195029, &[36033]),
+    (195030, &[36123]),
+    (195031, &[36215]),
+    (195032, &[163631]),
+    (195033, &[133124]),
+    (195034, &[36299]),
+    (195035, &[36284]),
+    (195036, &[36336]),
+    (195037, &[133342]),
+    (195038, &[36564]),
+    (195039, &[36664]),
+    (195040, &[165330]),
+    (195041, &[165357]),
+    (195042, &[37012]),
+    (195043, &[37105]),
+    (195044, &[37137]),
+    (195045, &[165678]),
+    (195046, &[37147]),
+    (195047, &[37432]),
+    (195048, &[37591]),
+    (195049, &[37592]),
+    (195050, &[37500]),
+    (195051, &[37881]),
+    (195052, &[37909]),
+    (195053, &[166906]),
+    (195054, &[38283]),
+    (195055, &[18837]),
+    (195056, &[38327]),
+    (195057, &[167287]),
+    (195058, &[18918]),
+    (195059, &[38595]),
+    (195060, &[23986]),
+    (195061, &[38691]),
+    (195062, &[168261]),
+    (195063, &[168474]),
+    (195064, &[19054]),
+    (195065, &[19062]),
+    (195066, &[38880]),
+    (195067, &[168970]),
+    (195068, &[19122]),
+    (195069, &[169110]),
+    (195070, &[38923]),
+    (195071, &[38923]),
+    (195072, &[38953]),
+    (195073, &[169398]),
+    (195074, &[39138]),
+    (195075, &[19251]),
+    (195076, &[39209]),
+    (195077, &[39335]),
+    (195078, &[39362]),
+    (195079, &[39422]),
+    (195080, &[19406]),
+    (195081, &[170800]),
+    (195082, &[39698]),
+    (195083, &[40000]),
+    (195084, &[40189]),
+    (195085, &[19662]),
+    (195086, &[19693]),
+    (195087, &[40295]),
+    (195088, &[172238]),
+    (195089, &[19704]),
+    (195090, &[172293]),
+    (195091, &[172558]),
+    (195092, &[172689]),
+    (195093, &[40635]),
+    (195094, &[19798]),
+    (195095, &[40697]),
+    (195096, &[40702]),
+    (195097, &[40709]),
+    (195098, &[40719]),
+    (195099, &[40726]),
+    (195100, &[40763]),
+    (195101, &[173568]),
+];
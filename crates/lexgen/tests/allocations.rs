@@ -0,0 +1,115 @@
+// Tests that lexer errors are returned without heap allocation. This is a separate test binary as
+// it replaces the global allocator.
+
+use lexgen::lexer;
+use lexgen_util::{LexerError, LexerErrorKind};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Number of allocations made on the current thread while running `f`
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (usize, R) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let result = f();
+    (ALLOCATIONS.with(Cell::get) - before, result)
+}
+
+#[test]
+fn errors_dont_allocate() {
+    lexer! {
+        Lexer -> u32;
+
+        type Error = &'static str;
+
+        invalid_utf8 = error;
+
+        ' ',
+        ['0'-'9']+ =? |lexer| match lexer.match_().parse::<u32>() {
+            Ok(i) => lexer.return_(Ok(i)),
+            Err(_) => lexer.return_(Err("integer too large")),
+        },
+        "ab" = 0,
+    }
+
+    let input = "1 ! 99999999999 a 2 \u{FFFD}";
+    let mut lexer = Lexer::new(input);
+    let mut errors: Vec<LexerError<&'static str>> = Vec::with_capacity(10);
+    let (allocations, ()) = count_allocations(|| {
+        for token in &mut lexer {
+            if let Err(err) = token {
+                errors.push(err);
+            }
+        }
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(
+        errors.iter().map(|err| &err.kind).collect::<Vec<_>>(),
+        vec![
+            &LexerErrorKind::InvalidToken,
+            &LexerErrorKind::Custom("integer too large"),
+            &LexerErrorKind::InvalidToken,
+            &LexerErrorKind::InvalidToken,
+        ]
+    );
+
+    // Invalid UTF-8
+    let mut lexer = Lexer::new_from_bytes(b"1 \xFF 2");
+    let (allocations, errors) = count_allocations(|| {
+        let mut n_errors = 0;
+        for token in &mut lexer {
+            if let Err(err) = token {
+                assert!(matches!(err.kind, LexerErrorKind::InvalidUtf8 { .. }));
+                n_errors += 1;
+            }
+        }
+        n_errors
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(errors, 1);
+
+    // Errors are only formatted when displayed
+    let location = lexgen_util::Loc {
+        line: 0,
+        col: 1,
+        byte_idx: 1,
+    };
+    let errors: [LexerError<&'static str>; 2] = [
+        LexerError {
+            location,
+            kind: LexerErrorKind::InvalidToken,
+        },
+        LexerError {
+            location,
+            kind: LexerErrorKind::Custom("error"),
+        },
+    ];
+    let mut message = String::with_capacity(100);
+    let (allocations, ()) = count_allocations(|| {
+        for err in &errors {
+            message.clear();
+            write!(message, "{}", err).unwrap();
+        }
+    });
+    assert_eq!(allocations, 0);
+}
@@ -0,0 +1,397 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("lexgen_cli_{}_{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn cli_tokens_and_warnings() {
+    let definition = write_temp_file(
+        "definition.rs",
+        r#"
+lexer! {
+    Lexer -> usize;
+
+    [' ' '\n']+,
+    "let" = 1,
+    ['a'-'z']+ = 2,
+    "letx" = 3,
+}
+"#,
+    );
+    let input = write_temp_file("input.txt", "let x\n?");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg(&definition)
+        .arg(&input)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let warning = format!(
+        "warning: {}:8:5: rule 4 in rule set Init: This rule is never matched",
+        definition.display()
+    );
+
+    assert!(stdout.starts_with("Lexer: Lexer\nStates: "), "{}", stdout);
+    assert!(stdout.contains(&warning), "{}", stdout);
+    assert!(
+        stdout.ends_with(
+            "0..3 rule 2 in rule set Init \"let\"
+3..4 rule 1 in rule set Init \" \"
+4..5 rule 3 in rule set Init \"x\"
+5..6 rule 1 in rule set Init \"\\n\"
+error: no rules match the input at byte 6
+"
+        ),
+        "{}",
+        stdout
+    );
+    assert!(!output.status.success());
+
+    std::fs::remove_file(definition).unwrap();
+    std::fs::remove_file(input).unwrap();
+}
+
+#[test]
+fn cli_coverage() {
+    let definition = write_temp_file(
+        "coverage.rs",
+        r#"
+lexer! {
+    Lexer -> usize;
+
+    "let" = 1,
+    ['a'-'z']+ = 2,
+}
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--coverage")
+        .arg(&definition)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    assert!(
+        stdout.ends_with(
+            "\"a\" rule 2 in rule set Init
+\"l\" rule 2 in rule set Init
+\"le\" rule 2 in rule set Init
+\"let\" rule 1 in rule set Init
+"
+        ),
+        "{}",
+        stdout
+    );
+    assert!(output.status.success());
+
+    std::fs::remove_file(definition).unwrap();
+}
+
+#[test]
+fn cli_snapshot() {
+    let definition = write_temp_file(
+        "snapshot.rs",
+        r#"
+lexer! {
+    Lexer -> usize;
+
+    "ab" | "ac" = 1,
+}
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--snapshot")
+        .arg(&definition)
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Lexer: Lexer
+Rule set Init: state 0
+
+State 0
+  'a' -> 1
+
+State 1
+  'b'-'c' -> 2
+
+State 2
+  accepts: rule 1 in rule set Init
+"
+    );
+    assert!(output.status.success());
+
+    std::fs::remove_file(definition).unwrap();
+}
+
+#[test]
+fn cli_snapshot_multiple_lexers() {
+    let definition = write_temp_file(
+        "snapshot_multiple.rs",
+        r#"
+lexer! {
+    let ab = "ab";
+
+    Lexer1 -> usize;
+
+    $ab = 1,
+
+    Lexer2 -> usize;
+
+    $ab | "ac" = 1,
+}
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--snapshot")
+        .arg(&definition)
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Lexer: Lexer1
+Rule set Init: state 0
+
+State 0
+  'a' -> 1
+
+State 1
+  'b' -> 2
+
+State 2
+  accepts: rule 1 in rule set Init
+
+Lexer: Lexer2
+Rule set Init: state 0
+
+State 0
+  'a' -> 1
+
+State 1
+  'b'-'c' -> 2
+
+State 2
+  accepts: rule 1 in rule set Init
+"
+    );
+    assert!(output.status.success());
+
+    std::fs::remove_file(definition).unwrap();
+}
+
+#[test]
+fn cli_equivalent() {
+    let definition1 = write_temp_file(
+        "equivalent1.rs",
+        r#"
+lexer! {
+    Lexer -> usize;
+
+    let id = ['a'-'z'] ['a'-'z' '0'-'9']*;
+
+    "let" = 1,
+    $id = 2,
+}
+"#,
+    );
+    let definition2 = write_temp_file(
+        "equivalent2.rs",
+        r#"
+lexer! {
+    Lexer -> usize;
+
+    "let" = 1,
+    ['a'-'z']+ ['a'-'z' '0'-'9']* = 2,
+}
+"#,
+    );
+    let definition3 = write_temp_file(
+        "equivalent3.rs",
+        r#"
+lexer! {
+    Lexer -> usize;
+
+    "let" = 1,
+    ['a'-'z']+ = 2,
+}
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--equivalent")
+        .arg(&definition1)
+        .arg(&definition2)
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Lexer and Lexer are equivalent\n"
+    );
+    assert!(output.status.success());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--equivalent")
+        .arg(&definition1)
+        .arg(&definition3)
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!(
+            "Lexer and Lexer differ in rule set Init for input \"a0\":
+  {}: rule 2 in rule set Init `= 2`
+  {}: no match
+",
+            definition1.display(),
+            definition3.display(),
+        )
+    );
+    assert!(!output.status.success());
+
+    std::fs::remove_file(definition1).unwrap();
+    std::fs::remove_file(definition2).unwrap();
+    std::fs::remove_file(definition3).unwrap();
+}
+
+#[test]
+fn cli_from_flex() {
+    let scanner = write_temp_file(
+        "scanner.l",
+        r#"%{
+#include "tokens.h"
+%}
+%option noyywrap
+DIGIT    [0-9]
+ID       [a-z][a-z0-9_-]*
+%x COMMENT
+
+%%
+    /* Whitespace */
+[ \t\n]+            ;
+"/*"                BEGIN(COMMENT);
+<COMMENT>{
+"*/"                BEGIN(INITIAL);
+[^*]+|"*"           ;
+}
+{DIGIT}{1,3}        { yylval = atoi(yytext);
+                      return INT; }
+if                  |
+while               return KEYWORD;
+{ID}/"("            return CALL;
+{ID}                return ID;
+[^[:alnum:] \t\n]   return OTHER;
+%%
+int main() {}
+"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--from-flex")
+        .arg(&scanner)
+        .output()
+        .unwrap();
+
+    let definition = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(
+        definition,
+        r#"lexer! {
+    Lexer -> Token;
+
+    let DIGIT = ['0'-'9'];
+    let ID = ['a'-'z'] ['a'-'z' '0'-'9' '_' '-']*;
+
+    rule Init {
+        [' ' '\t' '\n']+,
+        "/*" => |lexer| lexer.switch(LexerRule::COMMENT),
+        // { yylval = atoi(yytext);
+        // return INT; }
+        $DIGIT $DIGIT? $DIGIT? => |_| todo!(),
+        // return KEYWORD;
+        "if" => |_| todo!(),
+        // return KEYWORD;
+        "while" => |_| todo!(),
+        // return CALL;
+        $ID > '(' => |_| todo!(),
+        // return ID;
+        $ID => |_| todo!(),
+        // return OTHER;
+        (_ # ['0'-'9' 'A'-'Z' 'a'-'z' ' ' '\t' '\n']) => |_| todo!(),
+    }
+
+    rule COMMENT {
+        "*/" => |lexer| lexer.switch(LexerRule::Init),
+        (_ # ['*'])+ | '*',
+    }
+}
+"#
+    );
+    assert!(output.status.success());
+
+    // The converted definition is a valid lexer. Semantic actions are not run by the tool, so
+    // the lexer doesn't switch to `COMMENT`.
+    let definition = write_temp_file("scanner.rs", &definition);
+    let input = write_temp_file("scanner_input.txt", "f(1234 /* if */ while");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg(&definition)
+        .arg(&input)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.ends_with(
+            "0..1 rule 6 in rule set Init \"f\"
+1..2 rule 8 in rule set Init \"(\"
+2..5 rule 3 in rule set Init \"123\"
+5..6 rule 3 in rule set Init \"4\"
+6..7 rule 1 in rule set Init \" \"
+7..9 rule 2 in rule set Init \"/*\"
+9..10 rule 1 in rule set Init \" \"
+10..12 rule 4 in rule set Init \"if\"
+12..13 rule 1 in rule set Init \" \"
+13..14 rule 8 in rule set Init \"*\"
+14..15 rule 8 in rule set Init \"/\"
+15..16 rule 1 in rule set Init \" \"
+16..21 rule 5 in rule set Init \"while\"
+"
+        ),
+        "{}",
+        stdout
+    );
+
+    // Unsupported flex features are reported with line numbers
+    let scanner_error = write_temp_file("scanner_error.l", "%%\n^abc    return 1;\n");
+    let output = Command::new(env!("CARGO_BIN_EXE_lexgen"))
+        .arg("--from-flex")
+        .arg(&scanner_error)
+        .output()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        format!(
+            "error: {}:2: Beginning of line anchors (`^`) are not supported\n",
+            scanner_error.display()
+        )
+    );
+    assert!(!output.status.success());
+
+    std::fs::remove_file(scanner).unwrap();
+    std::fs::remove_file(definition).unwrap();
+    std::fs::remove_file(input).unwrap();
+    std::fs::remove_file(scanner_error).unwrap();
+}
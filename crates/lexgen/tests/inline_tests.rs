@@ -0,0 +1,77 @@
+// Tests generated from `tests { ... }` sections. This is a separate test binary as the lexers need
+// to be defined at module level: `#[test]` functions in function bodies are not run.
+
+use lexgen::lexer;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'input> {
+    Int(u64),
+    Id(&'input str),
+    Plus,
+}
+
+lexer! {
+    Lexer -> Token<'input>;
+
+    type Error = ();
+
+    ' ',
+    "+" = Token::Plus,
+    ['0'-'9']+ =? |lexer| {
+        let int = lexer.match_().parse::<u64>();
+        lexer.return_(int.map(Token::Int).map_err(|_| ()))
+    },
+    ['a'-'z']+ => |lexer| {
+        let id = lexer.match_();
+        lexer.return_(Token::Id(id))
+    },
+
+    tests {
+        "123" => [Token::Int(123)],
+        "1 + x" => [Token::Int(1), Token::Plus, Token::Id("x")],
+        "" => [],
+        "1A" => error@1,
+        "a + 99999999999999999999" => error@24,
+    }
+}
+
+// Multiple sections, in a lexer with user state and a rule set
+#[derive(Default)]
+struct Depth(usize);
+
+lexer! {
+    CommentLexer(Depth) -> usize;
+
+    tests {
+        "/**/" => [4],
+    }
+
+    rule Init {
+        "/*" => |lexer| {
+            lexer.state().0 = 1;
+            lexer.switch(CommentLexerRule::Comment)
+        },
+    }
+
+    rule Comment {
+        "/*" => |lexer| {
+            lexer.state().0 += 1;
+            lexer.continue_()
+        },
+        "*/" => |lexer| {
+            lexer.state().0 -= 1;
+            if lexer.state().0 == 0 {
+                let len = lexer.match_().len();
+                lexer.switch_and_return(CommentLexerRule::Init, len)
+            } else {
+                lexer.continue_()
+            }
+        },
+        _ => |lexer| lexer.continue_(),
+    }
+
+    tests {
+        "/* /* */ */" => [11],
+        "/* /* */" => error@0,
+    }
+}
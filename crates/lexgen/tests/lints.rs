@@ -0,0 +1,143 @@
+// Tests that generated code doesn't cause warnings in crates with strict lint configurations. This
+// is a separate test binary as the lints are enabled for the whole crate. Clippy lints are checked
+// by `cargo clippy --all-targets`.
+
+#![deny(
+    missing_docs,
+    missing_debug_implementations,
+    rust_2018_idioms,
+    trivial_casts,
+    trivial_numeric_casts,
+    unreachable_patterns,
+    unused_qualifications
+)]
+#![deny(clippy::pedantic)]
+
+//! Lexers with strict lints
+
+use lexgen::lexer;
+
+/// Tokens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token<'input> {
+    /// Keyword `fn`
+    Fn,
+    /// Keyword `async`
+    Async,
+    /// Identifier
+    Id(&'input str),
+    /// Integer
+    Int(u64),
+    /// String literal, with the quotes
+    Str(&'input str),
+}
+
+/// Lexer state
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    strings: usize,
+}
+
+/// Errors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Integer doesn't fit into `u64`
+    IntOverflow,
+}
+
+fn not_fn(_: &State) -> bool {
+    true
+}
+
+lexer! {
+    /// A lexer with rule sets, user state, errors, guards, right contexts, captures, and groups
+    #[derive(Debug, Clone)]
+    pub Lexer(State) -> Token<'input>;
+
+    type Error = Error;
+
+    let id = ['a'-'z' '_'] ['a'-'z' '0'-'9' '_']*;
+
+    rule Init {
+        [' ' '\n']+,
+
+        "fn" = Token::Fn,
+
+        group Edition2018 {
+            "async" if not_fn = Token::Async,
+        }
+
+        $id > '(' => |lexer| lexer.return_(Token::Id(lexer.match_())),
+
+        $id => |lexer| lexer.return_(Token::Id(lexer.match_())),
+
+        ['0'-'9']+ =? |lexer| {
+            let int = lexer.match_().parse::<u64>().map_err(|_| Error::IntOverflow);
+            lexer.return_(int.map(Token::Int))
+        },
+
+        "0x" (['0'-'9' 'a'-'f']+ as digits) =? |lexer| {
+            let int = u64::from_str_radix(digits, 16).map_err(|_| Error::IntOverflow);
+            lexer.return_(int.map(Token::Int))
+        },
+
+        '"' => |lexer| {
+            lexer.state().strings += 1;
+            lexer.switch(LexerRule::String)
+        },
+
+        '(',
+        ')',
+    }
+
+    rule String {
+        '"' => |lexer| {
+            let string = lexer.match_();
+            lexer.switch_and_return(LexerRule::Init, Token::Str(string))
+        },
+
+        "\\" ['"' '\\'],
+
+        _,
+    }
+}
+
+lexer! {
+    /// A lexer with options that generate more methods
+    pub OptionsLexer -> u32;
+
+    const_fn = true;
+    dfa_table = true;
+    invalid_utf8 = replace;
+    counters = true;
+    cancellation = true;
+    token_kind = true;
+
+    ' ',
+    "a" = 1,
+    'b'+ = 2,
+}
+
+#[test]
+fn strict_lints() {
+    let mut lexer =
+        Lexer::new("fn async f() 12 0x1f \"a\\\"b\"").with_group(LexerGroup::Edition2018, true);
+    let tokens: Vec<Token<'_>> = lexer.by_ref().map(|token| token.unwrap().1).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Fn,
+            Token::Async,
+            Token::Id("f"),
+            Token::Int(12),
+            Token::Int(31),
+            Token::Str("\"a\\\"b\""),
+        ]
+    );
+
+    let mut lexer = OptionsLexer::new_from_bytes(b"a bb");
+    assert_eq!(lexer.next().map(|token| token.unwrap().1), Some(1));
+    assert_eq!(lexer.next().map(|token| token.unwrap().1), Some(2));
+    assert_eq!(OptionsLexer::next_const("a", 0).unwrap().unwrap().1, 1);
+    let _ = OptionsLexer::dfa_table();
+}
@@ -96,19 +96,13 @@ struct LexerState {
     in_comment: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 enum Quote {
+    #[default]
     Single,
     Double,
 }
 
-impl Default for Quote {
-    fn default() -> Self {
-        // arbitrary
-        Quote::Single
-    }
-}
-
 lexer! {
     Lexer(LexerState) -> Token<'input>;
 
@@ -543,9 +537,9 @@ fn lex_lua_windows_line_ending() {
 #[test]
 fn lex_lua_files() {
     let str = std::fs::read_to_string("tests/test_data").unwrap();
-    let mut lexer = Lexer::new(&str);
+    let lexer = Lexer::new(&str);
     let mut i = 0;
-    while let Some(tok) = lexer.next() {
+    for tok in lexer {
         assert!(tok.is_ok());
         i += 1;
     }
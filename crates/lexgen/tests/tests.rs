@@ -232,7 +232,7 @@ fn switch_user_state() {
         rule Comment {
             "/*" => |lexer| {
                 let state = lexer.state();
-                *state = *state + 1;
+                *state += 1;
                 lexer.continue_()
             },
 
@@ -241,7 +241,7 @@ fn switch_user_state() {
                 if *state == 1 {
                     lexer.switch_and_return(LexerRule::Init, Token::Comment)
                 } else {
-                    *state = *state - 1;
+                    *state -= 1;
                     lexer.continue_()
                 }
             },
@@ -569,6 +569,7 @@ fn overlapping_ranges_1() {
 }
 
 #[test]
+#[allow(deprecated)] // unreachable rule warnings
 fn overlapping_ranges_2() {
     lexer! {
         Lexer -> usize;
@@ -590,6 +591,7 @@ fn overlapping_ranges_2() {
 }
 
 #[test]
+#[allow(deprecated)] // unreachable rule and empty match warnings
 fn overlapping_ranges_3() {
     lexer! {
         Lexer1 -> usize;
@@ -733,6 +735,7 @@ fn any_transitions() {
 }
 
 #[test]
+#[allow(deprecated)] // unreachable rule warnings
 fn end_of_input_transition_1() {
     lexer! {
         Lexer -> usize;
@@ -992,7 +995,7 @@ fn diff_1() {
     assert!(matches!(next(&mut lexer), Some(Err(_))));
     assert!(matches!(next(&mut lexer), Some(Err(_))));
     assert!(matches!(next(&mut lexer), Some(Err(_))));
-    assert!(matches!(next(&mut lexer), None));
+    assert!(next(&mut lexer).is_none());
 }
 
 #[test]
@@ -1008,11 +1011,11 @@ fn diff_2() {
 
     let mut lexer = Lexer::new("b");
     assert_eq!(next(&mut lexer), Some(Ok("b")));
-    assert!(matches!(next(&mut lexer), None));
+    assert!(next(&mut lexer).is_none());
 
     let mut lexer = Lexer::new("a");
     assert!(matches!(next(&mut lexer), Some(Err(_))));
-    assert!(matches!(next(&mut lexer), None));
+    assert!(next(&mut lexer).is_none());
 }
 
 #[test]
@@ -1137,3 +1140,3756 @@ fn iter_interface_fallible() {
         }))
     ));
 }
+
+#[test]
+fn linear_chain() {
+    // States for "cdef" after "ab" are fused into one string match
+    lexer! {
+        Lexer -> usize;
+
+        ' ',
+        "abcdef" = 1,
+        "ab" = 2,
+        "abcdeg" = 3,
+    }
+
+    let mut lexer = Lexer::new("abcdef ab abcdeg");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), None);
+
+    // Mismatch in the middle of the chain backtracks to the last match
+    let mut lexer = Lexer::new("abcx");
+    assert_eq!(lexer.next(), Some(Ok((loc(0, 0, 0), 2, loc(0, 2, 2)))));
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 2, 2),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+
+    // End-of-input in the middle of the chain
+    let mut lexer = Lexer::new("abcd");
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert!(matches!(next(&mut lexer), Some(Err(_)))); // 'c'
+    assert!(matches!(next(&mut lexer), Some(Err(_)))); // 'd'
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn linear_chain_locations() {
+    // Chains are matched with `str::starts_with` for `&str` input, and character by character
+    // for iterators and for chains with tabs and newlines. Locations should be the same.
+    lexer! {
+        Lexer -> usize;
+
+        ' ',
+        "ab" = 1,
+        "abçdé" = 2,
+        "abc\tx" = 3,
+        "abd\nx" = 4,
+    }
+
+    let input = "ab abçdé abc\tx abd\nx ab";
+    let expected = vec![
+        (loc(0, 0, 0), 1, loc(0, 2, 2)),
+        (loc(0, 3, 3), 2, loc(0, 8, 10)),
+        (loc(0, 9, 11), 3, loc(0, 17, 16)),
+        (loc(0, 18, 17), 4, loc(1, 1, 22)),
+        (loc(1, 2, 23), 1, loc(1, 4, 25)),
+    ];
+
+    let tokens: Vec<_> = Lexer::new(input).map(Result::unwrap).collect();
+    assert_eq!(tokens, expected);
+
+    let tokens: Vec<_> = Lexer::new_from_iter(input.chars())
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(tokens, expected);
+}
+
+#[test]
+fn driver_state_fns() {
+    lexer! {
+        Lexer(usize) -> (usize, &'input str);
+
+        driver = state_fns;
+
+        rule Init {
+            ' ',
+
+            "aaab" => |lexer| lexer.return_((0, lexer.match_())),
+
+            'a' => |lexer| lexer.return_((1, lexer.match_())),
+
+            "/*" => |lexer| {
+                *lexer.state() = 1;
+                lexer.switch(LexerRule::Comment)
+            },
+        }
+
+        rule Comment {
+            "/*" => |lexer| {
+                *lexer.state() += 1;
+                lexer.continue_()
+            },
+
+            "*/" => |lexer| {
+                let depth = lexer.state();
+                *depth -= 1;
+                if *depth == 0 {
+                    let match_ = lexer.match_();
+                    lexer.switch_and_return(LexerRule::Init, (2, match_))
+                } else {
+                    lexer.continue_()
+                }
+            },
+
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new("aaab aa /* /* */ */ a");
+    assert_eq!(next(&mut lexer), Some(Ok((0, "aaab"))));
+    assert_eq!(next(&mut lexer), Some(Ok((1, "a"))));
+    assert_eq!(next(&mut lexer), Some(Ok((1, "a"))));
+    assert_eq!(next(&mut lexer), Some(Ok((2, "/* /* */ */"))));
+    assert_eq!(next(&mut lexer), Some(Ok((1, "a"))));
+    assert_eq!(next(&mut lexer), None);
+
+    let mut lexer = Lexer::new("ab");
+    assert_eq!(next(&mut lexer), Some(Ok((1, "a"))));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn const_fn() {
+    lexer! {
+        Lexer -> Token;
+
+        const_fn = true;
+
+        [' ' '\n'],
+        "let" = Token::Let,
+        $$XID_Start $$XID_Continue* = Token::Ident,
+        ['0'-'9']+ = Token::Int,
+        '=' = Token::Eq,
+        "->" = Token::Arrow,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Token {
+        Let,
+        Ident,
+        Int,
+        Eq,
+        Arrow,
+    }
+
+    const fn lex<const N: usize>(input: &str) -> [Option<Result<Token, usize>>; N] {
+        let mut tokens = [None; N];
+        let mut byte_idx = 0;
+        let mut i = 0;
+        while i < N {
+            match Lexer::next_const(input, byte_idx) {
+                None => break,
+                Some(Ok((_, token, end))) => {
+                    tokens[i] = Some(Ok(token));
+                    byte_idx = end;
+                }
+                Some(Err((error_idx, continue_idx))) => {
+                    tokens[i] = Some(Err(error_idx));
+                    byte_idx = continue_idx;
+                }
+            }
+            i += 1;
+        }
+        tokens
+    }
+
+    const TOKENS: [Option<Result<Token, usize>>; 7] = lex("let xü = 123 -> ?");
+
+    assert_eq!(
+        TOKENS,
+        [
+            Some(Ok(Token::Let)),
+            Some(Ok(Token::Ident)),
+            Some(Ok(Token::Eq)),
+            Some(Ok(Token::Int)),
+            Some(Ok(Token::Arrow)),
+            Some(Err(17)),
+            None,
+        ]
+    );
+
+    // Runtime lexer is still generated
+    let mut lexer = Lexer::new("let x");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Let)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident)));
+    assert_eq!(next(&mut lexer), None);
+
+    // Also works at runtime
+    assert_eq!(Lexer::next_const("  -", 0), Some(Err((2, 3))));
+    assert_eq!(Lexer::next_const("  ", 0), None);
+}
+
+#[test]
+fn encoding_ascii() {
+    lexer! {
+        Lexer -> usize;
+
+        encoding = ascii;
+
+        [' ' '\t' '\n'],
+        ['a'-'z' 'A'-'Z' '_'] ['a'-'z' 'A'-'Z' '0'-'9' '_']* = 1,
+        $$numeric+ = 2,
+        "//" (_ # '\n')* = 3,
+    }
+
+    let mut lexer = Lexer::new("foo_1 42 // comment\nbar");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), None);
+
+    // `_` and built-in regexes don't match non-ASCII characters
+    let mut lexer = Lexer::new("aé");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 1, 1),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+
+    let mut lexer = Lexer::new("// é");
+    assert_eq!(lexer.next(), Some(Ok((loc(0, 0, 0), 3, loc(0, 3, 3)))));
+}
+
+#[test]
+fn charset_code_points() {
+    lexer! {
+        Lexer -> &'input str;
+
+        ' ',
+        // Control characters, except tab and newline
+        ['\u{0}'-'\u{8}' 0xB-0x1F 0x7F]+ => |lexer| lexer.return_(lexer.match_()),
+        // Private use areas
+        [0xE000-0xF8FF '\u{F0000}'-'\u{10FFFF}']+ => |lexer| lexer.return_(lexer.match_()),
+        [97-122]+ => |lexer| lexer.return_(lexer.match_()),
+    }
+
+    let mut lexer = Lexer::new("\0\u{1}\u{1B}\u{7F} \u{E000}\u{10FFFF} abc");
+    assert_eq!(next(&mut lexer), Some(Ok("\0\u{1}\u{1B}\u{7F}")));
+    assert_eq!(next(&mut lexer), Some(Ok("\u{E000}\u{10FFFF}")));
+    assert_eq!(next(&mut lexer), Some(Ok("abc")));
+    assert_eq!(next(&mut lexer), None);
+
+    let mut lexer = Lexer::new("\t");
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+}
+
+#[test]
+fn lexer_error_display() {
+    lexer! {
+        Lexer -> usize;
+        type Error = String;
+
+        [' ' '\n'],
+        ['0'-'9']+ = 1,
+        '!' =? |lexer| lexer.return_(Err("unexpected '!'".to_owned())),
+    }
+
+    let mut lexer = Lexer::new("1\n 2 ! ?");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(
+        lexer.next().unwrap().unwrap_err().to_string(),
+        "2:5: unexpected '!'"
+    );
+    assert_eq!(
+        lexer.next().unwrap().unwrap_err().to_string(),
+        "2:6: invalid token"
+    );
+}
+
+#[test]
+fn lexer_error_span() {
+    lexer! {
+        Lexer -> usize;
+        type Error = String;
+
+        ' ',
+        ['0'-'9']+ = 1,
+        '!' =? |lexer| lexer.return_(Err("unexpected '!'".to_owned())),
+        "ab" = 2,
+    }
+
+    let input = "1 ! é a";
+    let mut lexer = Lexer::new(input);
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(lexer.next().unwrap().unwrap_err().span(input), 3..3);
+    let err = lexer.next().unwrap().unwrap_err();
+    assert_eq!(err.span(input), 4..6);
+    assert_eq!(&input[err.span(input)], "é");
+
+    let input = "a";
+    let err = Lexer::new(input).next().unwrap().unwrap_err();
+    assert_eq!(err.span(input), 0..1);
+}
+
+#[test]
+fn dfa_table() {
+    use lexgen_util::{DfaTable, TableLexer};
+
+    lexer! {
+        Lexer -> &'static str;
+
+        dfa_table = true;
+
+        [' ' '\n'],
+        "-" = "Minus",
+        "->" = "Arrow",
+        "..." = "Ellipsis",
+        ['a'-'z']+ = "Ident",
+        ['0'-'9']+ ('.' ['0'-'9']+)? = "Number",
+        "//" (_ # '\n')* = "Comment",
+    }
+
+    // Serialize and deserialize to make sure the table doesn't depend on the lexer
+    let table = Lexer::dfa_table();
+    let json = serde_json::to_string(&table).unwrap();
+    let table: DfaTable = serde_json::from_str(&json).unwrap();
+    assert_eq!(table, Lexer::dfa_table());
+
+    for input in [
+        "a -> b - c",
+        "12.5 12. 3..4",
+        "x ... y // comment\nz",
+        "// comment",
+        "abc ?? def",
+        "1.",
+    ] {
+        // Tokens in the table are the right-hand sides of the rules as written in the lexer
+        let expected: Vec<_> = Lexer::new(input)
+            .map(|token| token.map(|(start, token, end)| (start, format!("{:?}", token), end)))
+            .collect();
+        let found: Vec<_> = TableLexer::new(&table, input)
+            .unwrap()
+            .map(|token| token.map(|(start, token, end)| (start, token.to_owned(), end)))
+            .collect();
+        assert_eq!(expected, found, "{:?}", input);
+    }
+
+    let mut tokens = TableLexer::new(&table, "a -> 1.5").unwrap();
+    assert_eq!(tokens.next().unwrap().unwrap().1, "\"Ident\"");
+    assert_eq!(tokens.next().unwrap().unwrap().1, "\"Arrow\"");
+    assert_eq!(tokens.next().unwrap().unwrap().1, "\"Number\"");
+    assert!(tokens.next().is_none());
+
+    // Invalid tables are rejected
+    let mut invalid_table = table.clone();
+    invalid_table.states[0].any = Some(invalid_table.states.len());
+    assert!(TableLexer::new(&invalid_table, "").is_err());
+}
+
+#[test]
+fn dfa_table_regex_automata() {
+    use regex_automata::dfa::{sparse, Automaton};
+    use regex_automata::{Anchored, Input};
+
+    lexer! {
+        Lexer -> &'static str;
+
+        dfa_table = true;
+
+        [' ' '\n'],
+        "-" = "Minus",
+        "->" = "Arrow",
+        ['a'-'z' 'ç' 'ö']+ = "Ident",
+        ['0'-'9']+ ('.' ['0'-'9']+)? = "Number",
+        "//" (_ # '\n')* = "Comment",
+    }
+
+    let table = Lexer::dfa_table();
+    let dense_dfa = table.to_dense_dfa().unwrap();
+
+    // Serialize and deserialize the sparse DFA
+    let sparse_dfa_bytes = table.to_sparse_dfa().unwrap().to_bytes_native_endian();
+    let sparse_dfa = sparse::DFA::from_bytes(&sparse_dfa_bytes).unwrap().0;
+
+    // Tokenize with anchored searches, skipping rules without tokens
+    fn tokenize<A: Automaton>(
+        dfa: &A,
+        tokens: &[Option<String>],
+        input: &str,
+    ) -> Vec<(usize, String, usize)> {
+        let mut found = vec![];
+        let mut start = 0;
+        while start < input.len() {
+            let search = Input::new(input).range(start..).anchored(Anchored::Yes);
+            let half_match = match dfa.try_search_fwd(&search).unwrap() {
+                Some(half_match) => half_match,
+                None => break,
+            };
+            if let Some(token) = &tokens[half_match.pattern().as_usize()] {
+                found.push((start, token.clone(), half_match.offset()));
+            }
+            start = half_match.offset();
+        }
+        found
+    }
+
+    for input in [
+        "a -> b - c",
+        "12.5 12. 3..4",
+        "çö // cömment\nz",
+        "// comment",
+        "abc ?? def",
+    ] {
+        let mut expected = vec![];
+        for token in Lexer::new(input) {
+            match token {
+                Ok((start, token, end)) => {
+                    expected.push((start.byte_idx, format!("{:?}", token), end.byte_idx))
+                }
+                Err(_) => break,
+            }
+        }
+        assert_eq!(
+            tokenize(&dense_dfa, &table.tokens, input),
+            expected,
+            "{:?}",
+            input
+        );
+        assert_eq!(
+            tokenize(&sparse_dfa, &table.tokens, input),
+            expected,
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn syntax_tokens() {
+    use lexgen_util::SyntaxTokens;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum SyntaxKind {
+        Trivia,
+        Ident,
+        Plus,
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        [' ' '\n'],
+        "#" (_ # '\n')*,
+        "+" = Token::Plus,
+        ['a'-'z']+ => |lexer| {
+            let match_ = lexer.match_();
+            lexer.return_(Token::Ident(match_))
+        },
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Ident(&'input str),
+        Plus,
+    }
+
+    fn kind(token: Token) -> SyntaxKind {
+        match token {
+            Token::Ident(_) => SyntaxKind::Ident,
+            Token::Plus => SyntaxKind::Plus,
+        }
+    }
+
+    let input = "  a + # comment\nbc+d  ";
+    let tokens: Vec<(SyntaxKind, &str)> =
+        SyntaxTokens::new(input, Lexer::new(input), SyntaxKind::Trivia, kind)
+            .map(Result::unwrap)
+            .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            (SyntaxKind::Trivia, "  "),
+            (SyntaxKind::Ident, "a"),
+            (SyntaxKind::Trivia, " "),
+            (SyntaxKind::Plus, "+"),
+            (SyntaxKind::Trivia, " # comment\n"),
+            (SyntaxKind::Ident, "bc"),
+            (SyntaxKind::Plus, "+"),
+            (SyntaxKind::Ident, "d"),
+            (SyntaxKind::Trivia, "  "),
+        ]
+    );
+    assert_eq!(
+        tokens.iter().map(|(_, text)| *text).collect::<String>(),
+        input
+    );
+
+    let input = "a ? b";
+    let mut tokens = SyntaxTokens::new(input, Lexer::new(input), SyntaxKind::Trivia, kind);
+    assert_eq!(tokens.next(), Some(Ok((SyntaxKind::Ident, "a"))));
+    assert!(matches!(tokens.next(), Some(Err(_))));
+    assert_eq!(tokens.next(), None);
+}
+
+#[test]
+fn derive_lexer() {
+    #[derive(Debug, PartialEq, Eq, lexgen::Lexer)]
+    #[lexer(skip = [' ' '\n']+)]
+    #[lexer(skip = "//" (_ # '\n')*)]
+    enum Token<'input> {
+        #[lexer("+")]
+        Plus,
+        #[lexer("-")]
+        #[lexer("\u{2212}")]
+        Minus,
+        #[lexer("let")]
+        Let,
+        #[lexer(['a'-'z']+)]
+        Ident(&'input str),
+        #[lexer(['0'-'9']+)]
+        Number(String),
+        // Not lexed
+        #[allow(dead_code)]
+        Eof,
+    }
+
+    let mut lexer = TokenLexer::new("let x = 1");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Let)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident("x"))));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+
+    let mut lexer = TokenLexer::new("lets - 12 // comment\n\u{2212} a+b");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident("lets"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Minus)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Number("12".to_owned()))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Minus)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident("a"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Plus)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident("b"))));
+    assert_eq!(next(&mut lexer), None);
+
+    // Lexer name can be specified
+    #[derive(Debug, PartialEq, Eq, lexgen::Lexer)]
+    #[lexer(name = Lexer)]
+    enum Bit {
+        #[lexer('0')]
+        Zero,
+        #[lexer('1')]
+        One,
+    }
+
+    let mut lexer = Lexer::new("10");
+    assert_eq!(next(&mut lexer), Some(Ok(Bit::One)));
+    assert_eq!(next(&mut lexer), Some(Ok(Bit::Zero)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn collect_spanned() {
+    lexer! {
+        Lexer -> char;
+
+        ' ',
+        "+" = '+',
+        "λ" = 'λ',
+        ['a'-'z']+ = 'x',
+    }
+
+    assert_eq!(
+        lexgen_util::collect_spanned(Lexer::new("ab + λ")),
+        Ok(vec![('x', 0..2), ('+', 3..4), ('λ', 5..7)])
+    );
+
+    let err = lexgen_util::collect_spanned(Lexer::new("a ? b")).unwrap_err();
+    assert_eq!(err.location.byte_idx, 2);
+}
+
+#[test]
+fn range_checks() {
+    macro_rules! test_range_checks {
+        ($range_checks:ident) => {{
+            lexer! {
+                Lexer -> usize;
+
+                range_checks = $range_checks;
+
+                [' ' '\t' '\n'],
+                ['a'-'z' 'A'-'Z' '_'] ['a'-'z' 'A'-'Z' '0'-'9' '_']* = 1,
+                ['0'-'9']+ = 2,
+                ['À'-'Ö' 'Ø'-'Þ' 'Ā' 'Ă' 'Ą' 'Ć' 'Ĉ' 'Ċ' 'Č' 'Ď' 'Đ'] = 3,
+                ['α'-'ω' 'ά'-'ώ']+ = 4,
+            }
+
+            let mut lexer = Lexer::new("foo_1 42 Ä αβγ ab Č");
+            assert_eq!(next(&mut lexer), Some(Ok(1)));
+            assert_eq!(next(&mut lexer), Some(Ok(2)));
+            assert_eq!(next(&mut lexer), Some(Ok(3)));
+            assert_eq!(next(&mut lexer), Some(Ok(4)));
+            assert_eq!(next(&mut lexer), Some(Ok(1)));
+            assert_eq!(next(&mut lexer), Some(Ok(3)));
+            assert_eq!(next(&mut lexer), None);
+        }};
+    }
+
+    test_range_checks!(auto);
+    test_range_checks!(if_chain);
+    test_range_checks!(binary_search);
+    test_range_checks!(table);
+}
+
+#[test]
+#[allow(deprecated)] // statistics warning
+fn statistics() {
+    lexer! {
+        Lexer -> usize;
+
+        statistics = true;
+
+        rule Init {
+            [' ' '\n'],
+            ['a'-'z']+ = 1,
+            "/*" => |lexer| lexer.switch(LexerRule::Comment),
+        }
+
+        rule Comment {
+            "*/" => |lexer| lexer.switch(LexerRule::Init),
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new("a /* b */ c");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn snapshot() {
+    lexer! {
+        Lexer -> usize;
+
+        snapshot = "tests/snapshot.txt";
+
+        let id = ['a'-'z'] ['a'-'z' '0'-'9']*;
+
+        rule Init {
+            [' ' '\n'],
+            "let" = 1,
+            $id = 2,
+            "/*" => |lexer| lexer.switch(LexerRule::Comment),
+        }
+
+        rule Comment {
+            "*/" => |lexer| lexer.switch(LexerRule::Init),
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new("let /* b */ x1");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn layout_profile() {
+    lexer! {
+        Lexer -> usize;
+
+        layout_profile = "tests/layout_profile.txt";
+
+        let id = ['a'-'z'] ['a'-'z' '0'-'9']*;
+
+        rule Init {
+            [' ' '\n'],
+            "let" = 1,
+            $id = 2,
+            '=' = 3,
+            "/*" => |lexer| lexer.switch(LexerRule::Comment),
+        }
+
+        rule Comment {
+            "*/" => |lexer| lexer.switch(LexerRule::Init),
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new("let /* b */ x1 = lets");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn init_coverage() {
+    lexer! {
+        Lexer -> usize;
+
+        init_coverage = ascii;
+
+        $$ascii_whitespace,
+        $$ascii_alphanumeric+ = 1,
+        $$ascii_punctuation = 2,
+        $$ascii_control = 3,
+    }
+
+    let mut lexer = Lexer::new("a\tb;");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn case_insensitive() {
+    lexer! {
+        Lexer -> usize;
+
+        case_insensitive = true;
+
+        ' ',
+        "select" = 1,
+        "straße" = 2,
+        ['a'-'z']+ = 3,
+    }
+
+    let mut lexer = Lexer::new("SeLeCt STRAẞE Abc");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn unicode_properties() {
+    lexer! {
+        Lexer -> usize;
+
+        ' ',
+        $$p{Greek}+ = 1,
+        $$p{Lu} $$p{Ll}* = 2,
+        $$p{Nd}+ = 3,
+    }
+
+    let mut lexer = Lexer::new("λόγος Word ٣4");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn unicode_blocks_and_ages() {
+    lexer! {
+        Lexer -> usize;
+
+        ' ',
+        $$p{Block=Arabic}+ = 1,
+        $$p{blk=Latin_1_Supplement}+ = 2,
+        ($$p{Age=6.0} # ' ')+ = 3,
+        _ = 4,
+    }
+
+    // U+1F600 (😀) was added in Unicode 6.1
+    let mut lexer = Lexer::new("مرحبا éß abc 😀");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), Some(Ok(4)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn match_unit_grapheme() {
+    lexer! {
+        Lexer -> &'input str;
+
+        match_unit = grapheme;
+
+        let cell = _ _ _;
+
+        ' ',
+        '|' $cell '|' => |lexer| lexer.return_(lexer.match_()),
+        // `_` in character sets is still a single character
+        '#' (_ # ' ') => |lexer| lexer.return_(lexer.match_()),
+        _ => |lexer| lexer.return_(lexer.match_()),
+    }
+
+    let input =
+        "e\u{301} 👍🏽 🇫🇷 👨\u{200D}👩\u{200D}👧 \u{1112}\u{1161}\u{11AB} \r\n |a👍🏽b| #e\u{301}";
+    let mut lexer = Lexer::new(input);
+    let mut tokens: Vec<&str> = vec![];
+    while let Some(token) = next(&mut lexer) {
+        tokens.push(token.unwrap());
+    }
+    assert_eq!(
+        tokens,
+        vec![
+            "e\u{301}",
+            "👍🏽",
+            "🇫🇷",
+            "👨\u{200D}👩\u{200D}👧",
+            "\u{1112}\u{1161}\u{11AB}",
+            "\r\n",
+            "|a👍🏽b|",
+            "#e",
+            "\u{301}",
+        ]
+    );
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn normalization_insensitive() {
+    lexer! {
+        Lexer -> usize;
+
+        normalization_insensitive = true;
+
+        ' ',
+        "café" = 1,
+        "ñ" 'o' = 2,
+        $$XID_Start $$XID_Continue* = 3,
+    }
+
+    let mut lexer = Lexer::new("café cafe\u{301} n\u{303}o ño cafés");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn invalid_utf8_error() {
+    lexer! {
+        Lexer -> &'input str;
+
+        invalid_utf8 = error;
+
+        ' ',
+        "let" = "let",
+        ['a'-'z']+ => |lexer| {
+            let match_ = lexer.match_();
+            lexer.return_(match_)
+        },
+        $ = "eof",
+    }
+
+    // Invalid bytes are skipped after the error. \xE2\x82 is a truncated 3-byte character and
+    // reported as one error.
+    let mut lexer = Lexer::new_from_bytes(b"ab\xFF le\xE2\x82 c\xC3\xA9");
+    assert_eq!(lexer.next(), Some(Ok((loc(0, 0, 0), "ab", loc(0, 2, 2)))));
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 2, 2),
+            kind: LexerErrorKind::InvalidUtf8 { end: loc(0, 3, 3) },
+        }))
+    );
+    assert_eq!(next(&mut lexer), Some(Ok("le")));
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 6, 6),
+            kind: LexerErrorKind::InvalidUtf8 { end: loc(0, 7, 8) },
+        }))
+    );
+    assert_eq!(next(&mut lexer), Some(Ok("c")));
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 9, 10),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+
+    // String input is lexed as before
+    let mut lexer = Lexer::new("let x");
+    assert_eq!(next(&mut lexer), Some(Ok("let")));
+    assert_eq!(next(&mut lexer), Some(Ok("x")));
+    assert_eq!(next(&mut lexer), Some(Ok("eof")));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn invalid_utf8_replace() {
+    lexer! {
+        Lexer -> (&'input [u8], usize);
+
+        invalid_utf8 = replace;
+
+        ' ',
+        '\u{FFFD}'+ => |lexer| {
+            let match_ = lexer.match_bytes();
+            lexer.return_((match_, 0))
+        },
+        (_ # ['\u{FFFD}' ' '])+ => |lexer| {
+            let match_ = lexer.match_bytes();
+            lexer.return_((match_, 1))
+        },
+    }
+
+    let mut lexer = Lexer::new_from_bytes(b"\xF0\x9F\x98 \xEF\xBF\xBD\xC0\xAF ok\xFF");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), (&b"\xF0\x9F\x98"[..], 0), loc(0, 1, 3))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((
+            loc(0, 2, 4),
+            (&b"\xEF\xBF\xBD\xC0\xAF"[..], 0),
+            loc(0, 5, 9)
+        )))
+    );
+    assert_eq!(next(&mut lexer), Some(Ok((&b"ok"[..], 1))));
+    assert_eq!(next(&mut lexer), Some(Ok((&b"\xFF"[..], 0))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn invalid_utf8_rule() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Word(&'input str),
+        Bytes(&'input [u8]),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        invalid_utf8 = rule;
+
+        rule Init {
+            ' ',
+            "\"" => |lexer| lexer.switch(LexerRule::String),
+            ['a'-'z']+ => |lexer| {
+                let match_ = lexer.match_();
+                lexer.return_(Token::Word(match_))
+            },
+            $$invalid_utf8 => |lexer| {
+                let match_ = lexer.match_bytes();
+                lexer.return_(Token::Bytes(match_))
+            },
+        }
+
+        rule String {
+            "\"" => |lexer| {
+                let match_ = lexer.match_();
+                lexer.switch_and_return(LexerRule::Init, Token::Word(match_))
+            },
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new_from_bytes(b"a\xFF\xFEb \"x\x80\" c");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Word("a"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Bytes(b"\xFF"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Bytes(b"\xFE"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Word("b"))));
+    // Invalid UTF-8 in the middle of a match makes the token invalid
+    assert!(matches!(
+        lexer.next(),
+        Some(Err(LexerError {
+            kind: LexerErrorKind::InvalidToken,
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn line_terminators() {
+    lexer! {
+        Lexer -> ();
+
+        line_terminators = unicode;
+
+        [' ' '\n' '\r' '\u{85}' '\u{2028}' '\u{2029}'],
+        ['a'-'z']+ = (),
+    }
+
+    let mut lexer = Lexer::new("a\r\nb\rc\u{85}d\u{2028}e\u{2029}f\n\ng");
+    let lines: Vec<(u32, u32)> = (&mut lexer)
+        .map(|token| {
+            let (start, _, _) = token.unwrap();
+            (start.line, start.col)
+        })
+        .collect();
+    assert_eq!(
+        lines,
+        vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0), (7, 0)]
+    );
+
+    lexer! {
+        LfLexer -> ();
+
+        [' ' '\n' '\r' '\u{85}' '\u{2028}' '\u{2029}'],
+        ['a'-'z']+ = (),
+    }
+
+    let mut lexer = LfLexer::new("a\r\nb\rc\u{2028}d");
+    let lines: Vec<u32> = (&mut lexer).map(|token| token.unwrap().0.line).collect();
+    assert_eq!(lines, vec![0, 1, 1, 1]);
+}
+
+#[test]
+fn perl_classes() {
+    lexer! {
+        Lexer -> usize;
+
+        perl_classes = ascii;
+
+        let word = $$w+;
+
+        $$s+,
+        $$d+ = 0,
+        $word = 1,
+        ($$S # $$w)+ = 2,
+    }
+
+    let mut lexer = Lexer::new("42 foo_1 ٣ café\u{0B}+-");
+    assert_eq!(next(&mut lexer), Some(Ok(0)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn perl_classes_unicode() {
+    lexer! {
+        Lexer -> usize;
+
+        $$s+,
+        $$d+ = 0,
+        $$w+ = 1,
+        $$W = 2,
+    }
+
+    let mut lexer = Lexer::new("42٣ café\u{2003}+");
+    assert_eq!(next(&mut lexer), Some(Ok(0)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn single_byte_encodings() {
+    use lexgen_util::SingleByteEncoding;
+
+    lexer! {
+        Lexer -> &'input [u8];
+
+        line_terminators = unicode;
+
+        [' ' '\n' '\u{85}'],
+        ['a'-'z' 'é']+ => |lexer| {
+            let match_ = lexer.match_bytes();
+            lexer.return_(match_)
+        },
+    }
+
+    // "café x" in Latin-1
+    let mut lexer = Lexer::new_from_single_byte(b"caf\xE9 x", SingleByteEncoding::Latin1);
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), &b"caf\xE9"[..], loc(0, 4, 4))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 5, 5), &b"x"[..], loc(0, 6, 6))))
+    );
+    assert_eq!(lexer.next(), None);
+
+    // "ab\ncd\u{85}é" in EBCDIC code page 037
+    let mut lexer =
+        Lexer::new_from_single_byte(b"\x81\x82\x25\x83\x84\x15\x51", SingleByteEncoding::Cp037);
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), &b"\x81\x82"[..], loc(0, 2, 2))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(1, 0, 3), &b"\x83\x84"[..], loc(1, 2, 5))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(2, 0, 6), &b"\x51"[..], loc(2, 1, 7))))
+    );
+    assert_eq!(lexer.next(), None);
+
+    // Custom code page: bytes are shifted by one
+    static SHIFTED: [char; 256] = {
+        let mut table = ['\0'; 256];
+        let mut i = 0;
+        while i < 255 {
+            table[i] = (i as u8 + 1) as char;
+            i += 1;
+        }
+        table
+    };
+    let mut lexer = Lexer::new_from_single_byte(b"`a", SingleByteEncoding::Custom(&SHIFTED));
+    assert_eq!(next(&mut lexer), Some(Ok(&b"`a"[..])));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn indentation() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Newline,
+        Indent,
+        Dedent,
+        Colon,
+        Id(&'input str),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        indentation = {
+            newline: Token::Newline,
+            indent: Token::Indent,
+            dedent: Token::Dedent,
+        };
+
+        [' ' '\n']+,
+        '#' (_ # '\n')*,
+        ':' = Token::Colon,
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+    }
+
+    let tokens = |input| -> Vec<_> {
+        Lexer::new(input)
+            .map(|token| token.map(|(_, token, _)| token).map_err(|err| err.kind))
+            .collect()
+    };
+
+    assert_eq!(
+        tokens("if a:\n  b\n\n  # comment\n  if c:\n      d\ne\n"),
+        vec![
+            Ok(Token::Id("if")),
+            Ok(Token::Id("a")),
+            Ok(Token::Colon),
+            Ok(Token::Newline),
+            Ok(Token::Indent),
+            Ok(Token::Id("b")),
+            Ok(Token::Newline),
+            Ok(Token::Id("if")),
+            Ok(Token::Id("c")),
+            Ok(Token::Colon),
+            Ok(Token::Newline),
+            Ok(Token::Indent),
+            Ok(Token::Id("d")),
+            Ok(Token::Newline),
+            Ok(Token::Dedent),
+            Ok(Token::Dedent),
+            Ok(Token::Id("e")),
+            Ok(Token::Newline),
+        ]
+    );
+
+    // Blocks are closed at the end of the input
+    assert_eq!(
+        tokens("a:\n  b"),
+        vec![
+            Ok(Token::Id("a")),
+            Ok(Token::Colon),
+            Ok(Token::Newline),
+            Ok(Token::Indent),
+            Ok(Token::Id("b")),
+            Ok(Token::Newline),
+            Ok(Token::Dedent),
+        ]
+    );
+
+    assert_eq!(tokens(""), vec![]);
+
+    // Dedent to a column that doesn't match an enclosing block
+    assert_eq!(
+        tokens("a\n    b\n  c"),
+        vec![
+            Ok(Token::Id("a")),
+            Ok(Token::Newline),
+            Ok(Token::Indent),
+            Ok(Token::Id("b")),
+            Ok(Token::Newline),
+            Ok(Token::Dedent),
+            Err(LexerErrorKind::InconsistentDedent),
+            Ok(Token::Id("c")),
+            Ok(Token::Newline),
+        ]
+    );
+
+    // After an inconsistent dedent the line continues the enclosing block, so each `indent` is
+    // matched by a `dedent` at the end of the input
+    assert_eq!(
+        tokens("a:\n  b\n    c\n d\n"),
+        vec![
+            Ok(Token::Id("a")),
+            Ok(Token::Colon),
+            Ok(Token::Newline),
+            Ok(Token::Indent),
+            Ok(Token::Id("b")),
+            Ok(Token::Newline),
+            Ok(Token::Indent),
+            Ok(Token::Id("c")),
+            Ok(Token::Newline),
+            Ok(Token::Dedent),
+            Ok(Token::Dedent),
+            Err(LexerErrorKind::InconsistentDedent),
+            Ok(Token::Id("d")),
+            Ok(Token::Newline),
+        ]
+    );
+
+    // Indentation tokens are empty, at the end of the line and the start of the next token
+    let mut lexer = Lexer::new("a\n b");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), Token::Id("a"), loc(0, 1, 1))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 1, 1), Token::Newline, loc(0, 1, 1))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(1, 1, 3), Token::Indent, loc(1, 1, 3))))
+    );
+
+    lexer! {
+        TabLexer -> Token<'input>;
+
+        indentation = {
+            newline: Token::Newline,
+            indent: Token::Indent,
+            dedent: Token::Dedent,
+            whitespace: tab_width(8),
+        };
+
+        [' ' '\t' '\n']+,
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+    }
+
+    // A tab advances to the next multiple of 8, so `b` and `c` are in the same block
+    let tokens: Vec<_> = TabLexer::new("a\n\tb\n        c")
+        .map(|token| token.unwrap().1)
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Id("a"),
+            Token::Newline,
+            Token::Indent,
+            Token::Id("b"),
+            Token::Newline,
+            Token::Id("c"),
+            Token::Newline,
+            Token::Dedent,
+        ]
+    );
+
+    lexer! {
+        SpacesLexer -> Token<'input>;
+
+        indentation = {
+            newline: Token::Newline,
+            indent: Token::Indent,
+            dedent: Token::Dedent,
+            whitespace: spaces,
+        };
+
+        driver = state_fns;
+
+        [' ' '\t' '\n']+,
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+    }
+
+    let mut lexer = SpacesLexer::new("a\n \tb");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), Token::Id("a"), loc(0, 1, 1))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(1, 1, 3),
+            kind: LexerErrorKind::InvalidIndentation,
+        }))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(1, 5, 4), Token::Id("b"), loc(1, 6, 5))))
+    );
+}
+
+#[test]
+fn nested_rule_sets() {
+    lexer! {
+        Lexer -> &'input str;
+
+        rule Init {
+            ' ',
+            "(*" => |lexer| lexer.switch(LexerRule::Comment),
+            "\"" => |lexer| lexer.switch(LexerRule::String),
+            ['a'-'z']+ => |lexer| lexer.return_(lexer.match_()),
+        }
+
+        rule String {
+            "\"" => |lexer| {
+                let match_ = lexer.match_();
+                lexer.switch_and_return(LexerRule::Init, match_)
+            },
+            "(*" => |lexer| lexer.switch(LexerRule::Comment),
+            ['a'-'z' ' ']+,
+        }
+
+        rule Comment nested("(*", "*)") {
+            _,
+        }
+    }
+
+    let tokens = |input| -> Vec<&str> { Lexer::new(input).map(|token| token.unwrap().1).collect() };
+
+    assert_eq!(tokens("a (* b *) c"), vec!["a", "c"]);
+    assert_eq!(tokens("a (* b (* c *) d (**) *) e"), vec!["a", "e"]);
+    assert_eq!(tokens("a (* (* (* *) *) *)(* *) b"), vec!["a", "b"]);
+
+    // Closing the comment switches back to the rule set that switched to the comment. As usual,
+    // the match is only reset in `Init`, so the comment is a part of the string.
+    assert_eq!(
+        tokens("\"a (* b (* *) *) c\" d"),
+        vec!["\"a (* b (* *) *) c\"", "d"]
+    );
+
+    // Unterminated comments
+    let mut lexer = Lexer::new("a (* (* *)");
+    assert_eq!(next(&mut lexer), Some(Ok("a")));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+}
+
+#[test]
+fn sub_lexers() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum SqlToken<'input> {
+        Word(&'input str),
+        Star,
+        End,
+    }
+
+    lexer! {
+        SqlLexer -> SqlToken<'input>;
+
+        [' ' '\n'],
+        '*' = SqlToken::Star,
+        '}' = SqlToken::End,
+        ['a'-'z']+ => |lexer| lexer.return_(SqlToken::Word(lexer.match_())),
+    }
+
+    type Spanned<T> = (Loc, T, Loc);
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        Sql(Vec<Spanned<SqlToken<'input>>>),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        [' ' '\n'],
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+        "sql{" => |lexer| {
+            // Lex the SQL with `SqlLexer` until `}`, and continue after the `}`
+            let mut sql_lexer = SqlLexer::new_at(lexer.input(), lexer.match_loc().1);
+            let mut sql = vec![];
+            for token in &mut sql_lexer {
+                match token.unwrap() {
+                    (_, SqlToken::End, _) => break,
+                    token => sql.push(token),
+                }
+            }
+            lexer.skip_to(sql_lexer.location());
+            lexer.return_(Token::Sql(sql))
+        },
+    }
+
+    let mut lexer = Lexer::new("a sql{select *\n from t} b");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), Token::Id("a"), loc(0, 1, 1))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((
+            loc(0, 2, 2),
+            Token::Sql(vec![
+                (loc(0, 6, 6), SqlToken::Word("select"), loc(0, 12, 12)),
+                (loc(0, 13, 13), SqlToken::Star, loc(0, 14, 14)),
+                (loc(1, 1, 16), SqlToken::Word("from"), loc(1, 5, 20)),
+                (loc(1, 6, 21), SqlToken::Word("t"), loc(1, 7, 22)),
+            ]),
+            loc(1, 8, 23)
+        )))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(1, 9, 24), Token::Id("b"), loc(1, 10, 25))))
+    );
+    assert_eq!(lexer.next(), None);
+}
+
+#[test]
+fn external_scanners() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        Heredoc(&'input str),
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct UnterminatedHeredoc;
+
+    // Scans the rest of a heredoc after `<<`: the delimiter until the end of the line, then the
+    // lines until a line with the delimiter
+    fn heredoc<'input>(
+        cursor: &mut dyn lexgen_util::Cursor<'input>,
+    ) -> Result<Token<'input>, UnterminatedHeredoc> {
+        let delimiter_start = cursor.match_().len();
+        while let Some(char) = cursor.next() {
+            if char == '\n' {
+                break;
+            }
+        }
+        let delimiter = &cursor.match_()[delimiter_start..cursor.match_().len() - 1];
+
+        let mut line_start = cursor.match_().len();
+        loop {
+            match cursor.next() {
+                None => return Err(UnterminatedHeredoc),
+                Some('\n') => line_start = cursor.match_().len(),
+                Some(_) => {
+                    let match_ = cursor.match_();
+                    if &match_[line_start..] == delimiter
+                        && matches!(cursor.peek(), Some('\n') | None)
+                    {
+                        let body_start = delimiter_start + delimiter.len() + 1;
+                        return Ok(Token::Heredoc(&match_[body_start..line_start]));
+                    }
+                }
+            }
+        }
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        type Error = UnterminatedHeredoc;
+
+        [' ' '\n'],
+        ['a'-'z' 'A'-'Z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+        "<<" =? |lexer| {
+            let token = lexer.scan(heredoc);
+            lexer.return_(token)
+        },
+    }
+
+    let mut lexer = Lexer::new("a <<END\nx\nENDING\nEND\nb");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), Token::Id("a"), loc(0, 1, 1))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((
+            loc(0, 2, 2),
+            Token::Heredoc("x\nENDING\n"),
+            loc(3, 3, 20)
+        )))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(4, 0, 21), Token::Id("b"), loc(4, 1, 22))))
+    );
+    assert_eq!(lexer.next(), None);
+
+    let mut lexer = Lexer::new("<<END\nx");
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(1, 1, 7),
+            kind: LexerErrorKind::Custom(UnterminatedHeredoc),
+        }))
+    );
+}
+
+#[test]
+fn raw_strings() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct UnterminatedString;
+
+    lexer! {
+        Lexer -> &'input str;
+
+        type Error = UnterminatedString;
+
+        ' ',
+
+        // Rust raw strings: `r#"..."#` ends with `"` followed by as many `#`s as the opening
+        // delimiter has
+        'r' '#'* '"' =? |lexer| {
+            let hashes = &lexer.match_()[1..lexer.match_().len() - 1];
+            let end = format!("\"{}", hashes);
+            if lexer.scan(|cursor| cursor.consume_until(&end)) {
+                lexer.return_(Ok(lexer.match_()))
+            } else {
+                lexer.return_(Err(UnterminatedString))
+            }
+        },
+
+        // Lua long strings: `[==[...]==]`
+        '[' '='* '[' =? |lexer| {
+            let equals = &lexer.match_()[1..lexer.match_().len() - 1];
+            let end = format!("]{}]", equals);
+            if lexer.scan(|cursor| cursor.consume_until(&end)) {
+                lexer.return_(Ok(lexer.match_()))
+            } else {
+                lexer.return_(Err(UnterminatedString))
+            }
+        },
+    }
+
+    let tokens: Vec<&str> = Lexer::new(r####"r"a" r#"b"c"# r##"d"#"e"## [[f]] [=[g]]h]=]"####)
+        .map(|token| token.unwrap().1)
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            r#"r"a""#,
+            r##"r#"b"c"#"##,
+            r###"r##"d"#"e"##"###,
+            "[[f]]",
+            "[=[g]]h]=]",
+        ]
+    );
+
+    let mut lexer = Lexer::new(r##"r#"a" [==[b]=]"##);
+    assert!(matches!(
+        lexer.next(),
+        Some(Err(LexerError {
+            kind: LexerErrorKind::Custom(UnterminatedString),
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn heredocs() {
+    #[derive(Debug, PartialEq, Eq)]
+    struct UnterminatedHeredoc;
+
+    lexer! {
+        Lexer -> &'input str;
+
+        type Error = UnterminatedHeredoc;
+
+        [' ' '\r' '\n'],
+
+        "<<" '-'? $$ascii_uppercase+ =? |lexer| {
+            let match_ = lexer.match_();
+            let indented = match_.starts_with("<<-");
+            let terminator = match_.trim_start_matches(&['<', '-'][..]).to_owned();
+            if lexer.scan(|cursor| cursor.consume_heredoc(&terminator, indented)) {
+                lexer.return_(Ok(lexer.match_()))
+            } else {
+                lexer.return_(Err(UnterminatedHeredoc))
+            }
+        },
+
+        $$ascii_lowercase+ => |lexer| {
+            let word = lexer.match_();
+            lexer.return_(word)
+        },
+    }
+
+    let input = "cat <<EOF x\na\n EOF\nEOFF\nEOF\nb <<-END\n\tEND\r\nc";
+    let tokens: Vec<&str> = Lexer::new(input).map(|token| token.unwrap().1).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            "cat",
+            "<<EOF x\na\n EOF\nEOFF\nEOF",
+            "b",
+            "<<-END\n\tEND",
+            "c",
+        ]
+    );
+
+    let mut lexer = Lexer::new("<<EOF\na\n EOF\n");
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(3, 0, 13),
+            kind: LexerErrorKind::Custom(UnterminatedHeredoc),
+        }))
+    );
+}
+
+#[test]
+fn custom_matchers() {
+    use lexgen_util::{Cursor, MatchResult};
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        LParen,
+        Unit,
+        Id(&'input str),
+        Group(&'input str),
+    }
+
+    // Balanced parentheses. Records the deepest nesting in the user state.
+    fn balanced(cursor: &mut dyn Cursor, max_depth: &mut usize) -> MatchResult {
+        if cursor.next() != Some('(') {
+            return MatchResult::NoMatch;
+        }
+        let mut depth = 1;
+        while depth != 0 {
+            match cursor.next() {
+                Some('(') => {
+                    depth += 1;
+                    *max_depth = (*max_depth).max(depth);
+                }
+                Some(')') => depth -= 1,
+                Some(_) => {}
+                None => return MatchResult::NoMatch,
+            }
+        }
+        MatchResult::Match
+    }
+
+    // Square brackets, not handled by any regex rule
+    fn brackets(cursor: &mut dyn Cursor, _: &mut usize) -> MatchResult {
+        if cursor.next() == Some('[') && cursor.consume_until("]") {
+            MatchResult::Match
+        } else {
+            MatchResult::NoMatch
+        }
+    }
+
+    lexer! {
+        Lexer(usize) -> Token<'input>;
+
+        ' ',
+        "(" = Token::LParen,
+        "()" = Token::Unit,
+        ['a'-'z']+ => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(Token::Id(id))
+        },
+        fn balanced => |lexer| {
+            let group = lexer.match_();
+            lexer.return_(Token::Group(group))
+        },
+        fn brackets => |lexer| {
+            let group = lexer.match_();
+            lexer.return_(Token::Group(group))
+        },
+    }
+
+    // Longer match of the matcher wins over the regex rules
+    let mut lexer = Lexer::new("(a (b) c) x");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), Token::Group("(a (b) c)"), loc(0, 9, 9))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 10, 10), Token::Id("x"), loc(0, 11, 11))))
+    );
+    assert_eq!(lexer.next(), None);
+    assert_eq!(*lexer.state(), 2);
+
+    // Regex rules win ties, and match when the matcher doesn't
+    let mut lexer = Lexer::new("() (a");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Unit)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::LParen)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("a"))));
+    assert_eq!(next(&mut lexer), None);
+
+    // Matchers match when the regex rules fail
+    let mut lexer = Lexer::new("[a b] [c");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Group("[a b]"))));
+    assert!(matches!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: Loc {
+                line: 0,
+                col: 6,
+                byte_idx: 6
+            },
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    ));
+}
+
+#[test]
+fn unescape_strings() {
+    use lexgen_util::{unescape, InvalidEscape, InvalidEscapeKind};
+    use std::borrow::Cow;
+
+    lexer! {
+        Lexer -> Cow<'input, str>;
+
+        type Error = InvalidEscape;
+
+        ' ',
+
+        '"' ((_ # ['"' '\\']) | '\\' _)* '"' =? |lexer| {
+            let body = &lexer.match_()[1..lexer.match_().len() - 1];
+            lexer.return_(unescape(body))
+        },
+    }
+
+    let tokens: Vec<Cow<str>> = Lexer::new(r#""abc" "a\tb\"c\\" "\x41\u{e9}\u{1F600}\0""#)
+        .map(|token| token.unwrap().1)
+        .collect();
+    assert_eq!(tokens, vec!["abc", "a\tb\"c\\", "A\u{e9}\u{1F600}\0"]);
+    assert!(matches!(tokens[0], Cow::Borrowed(_)));
+    assert!(matches!(tokens[1], Cow::Owned(_)));
+
+    let error = |offset, kind| Err(InvalidEscape { offset, kind });
+    assert_eq!(unescape(r"a\qb"), error(1, InvalidEscapeKind::Unknown));
+    assert_eq!(unescape(r"ab\"), error(2, InvalidEscapeKind::Unknown));
+    assert_eq!(unescape(r"\x4"), error(0, InvalidEscapeKind::Hex));
+    assert_eq!(unescape(r"\x80"), error(0, InvalidEscapeKind::Hex));
+    assert_eq!(unescape(r"é\u{}"), error(2, InvalidEscapeKind::Unicode));
+    assert_eq!(
+        unescape(r"\u{1234567}"),
+        error(0, InvalidEscapeKind::Unicode)
+    );
+    assert_eq!(unescape(r"\u{D800}"), error(0, InvalidEscapeKind::Unicode));
+    assert_eq!(unescape(r"\u41"), error(0, InvalidEscapeKind::Unicode));
+
+    // Offsets are relative to the string body, errors are raised at the end of the match
+    let mut lexer = Lexer::new(r#""a\qb""#);
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 6, 6),
+            kind: LexerErrorKind::Custom(InvalidEscape {
+                offset: 1,
+                kind: InvalidEscapeKind::Unknown
+            }),
+        }))
+    );
+}
+
+#[test]
+fn rule_guards() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Fn,
+        Async,
+        Id(&'input str),
+    }
+
+    #[derive(Default)]
+    struct State {
+        edition_2018: bool,
+    }
+
+    fn edition_2018(state: &State) -> bool {
+        state.edition_2018
+    }
+
+    lexer! {
+        Lexer(State) -> Token<'input>;
+
+        let id = ['a'-'z' '0'-'9' '_']+;
+
+        ' ',
+        "fn" = Token::Fn,
+        "#edition2018" => |lexer| {
+            lexer.state().edition_2018 = true;
+            lexer.continue_()
+        },
+
+        // Keyword since Rust 2018, an identifier before
+        "async" if edition_2018 = Token::Async,
+
+        $id => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(Token::Id(id))
+        },
+    }
+
+    let mut lexer = Lexer::new("async fn #edition2018 async fn asyncx async");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("async"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Fn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Async)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Fn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("asyncx"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Async)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn rule_groups() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Fn,
+        Async,
+        Dyn,
+        Str(&'input str),
+        Id(&'input str),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        let id = ['a'-'z' '0'-'9' '_']+;
+
+        rule Init {
+            ' ',
+            "fn" = Token::Fn,
+
+            // Keywords since Rust 2018, identifiers before
+            group Edition2018 {
+                "async" = Token::Async,
+                "dyn" = Token::Dyn,
+            }
+
+            group RawStrings {
+                'r' '"' _* '"' => |lexer| lexer.return_(Token::Str(lexer.match_())),
+            }
+
+            $id => |lexer| lexer.return_(Token::Id(lexer.match_())),
+        }
+    }
+
+    let input = "async fn dyn r\"x\"";
+
+    // Groups are enabled by default
+    let mut lexer = Lexer::new(input);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Async)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Fn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Dyn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Str("r\"x\""))));
+    assert_eq!(next(&mut lexer), None);
+
+    // Disabled group rules fall back to the next rule matching the input
+    let mut lexer = Lexer::new("async fn dyn asyncx").with_group(LexerGroup::Edition2018, false);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("async"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Fn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("dyn"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("asyncx"))));
+    assert_eq!(next(&mut lexer), None);
+
+    // Disabling one group doesn't affect the others
+    let mut lexer = Lexer::new(input)
+        .with_group(LexerGroup::RawStrings, false)
+        .with_group(LexerGroup::Edition2018, false)
+        .with_group(LexerGroup::Edition2018, true);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Async)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Fn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Dyn)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("r"))));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+}
+
+#[test]
+fn parser_feedback() {
+    use std::collections::HashSet;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Typedef,
+        Semi,
+        Id(&'input str),
+        TypeName(&'input str),
+    }
+
+    fn is_type_name(_: &(), type_names: &HashSet<String>, id: &str) -> bool {
+        type_names.contains(id)
+    }
+
+    // C "lexer hack": identifiers declared with `typedef` are lexed as type names
+    lexer! {
+        Lexer -> Token<'input>;
+
+        type Feedback = HashSet<String>;
+
+        ' ',
+        "typedef" = Token::Typedef,
+        ";" = Token::Semi,
+
+        $$ascii_alphabetic+ if is_type_name => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(Token::TypeName(id))
+        },
+
+        $$ascii_alphabetic+ => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(Token::Id(id))
+        },
+    }
+
+    let mut lexer = Lexer::new("typedef int T; T x; int T;");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Typedef)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("int"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("T"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Semi)));
+
+    // Parser reduces the `typedef` declaration
+    lexer.feedback().insert("T".to_owned());
+
+    assert_eq!(next(&mut lexer), Some(Ok(Token::TypeName("T"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("x"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Semi)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("int"))));
+
+    // Parser sees a new scope with a variable shadowing the type name
+    lexer.feedback().remove("T");
+
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("T"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Semi)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn tag_rule_sets() {
+    use lexgen_util::Tagged;
+
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Token<'input> {
+        Id(&'input str),
+        Str(&'input str),
+        InterpStart,
+        InterpEnd,
+    }
+
+    lexer! {
+        pub Lexer -> Token<'input>;
+
+        tag_rule_sets = true;
+
+        rule Init {
+            ' ',
+
+            '"' => |lexer| {
+                lexer.reset_match();
+                lexer.switch(LexerRule::String)
+            },
+
+            $$ascii_alphabetic+ => |lexer| {
+                let id = lexer.match_();
+                lexer.return_(Token::Id(id))
+            },
+        }
+
+        rule String {
+            '"' => |lexer| lexer.switch(LexerRule::Init),
+
+            (_ # ['"' '$'])+ => |lexer| {
+                let str = lexer.match_();
+                lexer.return_(Token::Str(str))
+            },
+
+            "${" => |lexer| lexer.switch_and_return(LexerRule::Interp, Token::InterpStart),
+        }
+
+        rule Interp {
+            ' ',
+
+            '}' => |lexer| lexer.switch_and_return(LexerRule::String, Token::InterpEnd),
+
+            $$ascii_alphabetic+ => |lexer| {
+                let id = lexer.match_();
+                lexer.return_(Token::Id(id))
+            },
+        }
+    }
+
+    let tokens: Vec<Tagged<LexerRule, Token>> = Lexer::new(r#"a "b ${c}" d"#)
+        .map(|token| token.unwrap().1)
+        .collect();
+
+    let tagged = |rule_set, token| Tagged { rule_set, token };
+
+    assert_eq!(
+        tokens,
+        vec![
+            tagged(LexerRule::Init, Token::Id("a")),
+            tagged(LexerRule::String, Token::Str("b ")),
+            tagged(LexerRule::String, Token::InterpStart),
+            tagged(LexerRule::Interp, Token::Id("c")),
+            tagged(LexerRule::Interp, Token::InterpEnd),
+            tagged(LexerRule::Init, Token::Id("d")),
+        ]
+    );
+}
+
+#[test]
+fn multiple_lexers() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        Int(&'input str),
+        Error(&'input str),
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct InvalidInt;
+
+    // A strict lexer for the compiler and a lenient one for the IDE, with the same identifiers and
+    // integers
+    lexer! {
+        let id = $$alphabetic ($$alphanumeric | '_')*;
+        let int = ['0'-'9']+;
+
+        StrictLexer -> Token<'input>;
+
+        type Error = InvalidInt;
+
+        $$whitespace,
+
+        $id => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(Token::Id(id))
+        },
+
+        $int => |lexer| {
+            let int = lexer.match_();
+            lexer.return_(Token::Int(int))
+        },
+
+        $int $id =? |lexer| lexer.return_(Err(InvalidInt)),
+
+        IdeLexer -> Token<'input>;
+
+        $$whitespace,
+
+        $id => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(Token::Id(id))
+        },
+
+        $int => |lexer| {
+            let int = lexer.match_();
+            lexer.return_(Token::Int(int))
+        },
+
+        $int $id => |lexer| {
+            let error = lexer.match_();
+            lexer.return_(Token::Error(error))
+        },
+    }
+
+    let input = "ağaç 12 3x";
+
+    let mut lexer = StrictLexer::new(input);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("ağaç"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Int("12"))));
+    assert!(matches!(
+        next(&mut lexer),
+        Some(Err(LexerError {
+            kind: LexerErrorKind::Custom(InvalidInt),
+            ..
+        }))
+    ));
+
+    let mut lexer = IdeLexer::new(input);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("ağaç"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Int("12"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Error("3x"))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn include_rules() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        If,
+        Else,
+        Bool(bool),
+        Id(&'input str),
+        Str(&'input str),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        include_rules!("tests/include/bindings.lexgen");
+
+        rule Init {
+            $ws,
+
+            // Keywords are before the identifier rule, to have priority
+            include_rules!("tests/include/keywords.lexgen");
+
+            $id => |lexer| {
+                let id = lexer.match_();
+                lexer.return_(Token::Id(id))
+            },
+
+            '"' => |lexer| lexer.switch(LexerRule::String),
+        }
+
+        rule String {
+            '"' => |lexer| {
+                let str = lexer.match_();
+                lexer.switch_and_return(LexerRule::Init, Token::Str(&str[1..str.len() - 1]))
+            },
+
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new("if true else iff \"if\"");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::If)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Bool(true))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Else)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("iff"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Str("if"))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn visibility() {
+    mod lexers {
+        use lexgen::lexer;
+
+        lexer! {
+            pub(crate) Lexer -> u32;
+
+            rule Init {
+                'a' = 1,
+                '"' => |lexer| lexer.switch(LexerRule::String),
+            }
+
+            rule String {
+                '"' => |lexer| lexer.switch_and_return(LexerRule::Init, 2),
+            }
+        }
+
+        lexer! {
+            pub(super) SuperLexer -> u32;
+
+            'b' = 3,
+        }
+    }
+
+    let mut lexer = lexers::Lexer::new("a\"\"");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), None);
+
+    assert_ne!(lexers::LexerRule::Init, lexers::LexerRule::String);
+
+    let mut lexer = lexers::SuperLexer::new("b");
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+}
+
+#[test]
+fn lexer_attributes() {
+    #[derive(Debug, Clone, Default)]
+    struct State {
+        words: usize,
+    }
+
+    lexer! {
+        /// Lexer that can be cloned for lookahead
+        #[derive(Clone, Debug)]
+        #[allow(dead_code)]
+        Lexer(State) -> &'input str;
+
+        ' ',
+
+        ['a'-'z']+ => |lexer| {
+            lexer.state().words += 1;
+            let word = lexer.match_();
+            lexer.return_(word)
+        },
+    }
+
+    let mut lexer = Lexer::new("a bc d");
+    assert_eq!(next(&mut lexer), Some(Ok("a")));
+
+    let mut lookahead = lexer.clone();
+    assert_eq!(next(&mut lookahead), Some(Ok("bc")));
+    assert_eq!(next(&mut lookahead), Some(Ok("d")));
+    assert_eq!(lookahead.state().words, 3);
+
+    assert_eq!(next(&mut lexer), Some(Ok("bc")));
+    assert_eq!(lexer.state().words, 2);
+
+    assert!(format!("{:?}", lexer).contains("words: 2"));
+}
+
+#[test]
+fn line_directives() {
+    lexer! {
+        Lexer -> &'input str;
+
+        [' ' '\n'],
+
+        // `#line <line> "<file>"`: the next line is line `<line>` of `<file>`
+        "#line " ['0'-'9']+ " \"" (_ # ['"' '\n'])* "\"\n" => |lexer| {
+            let directive = lexer.match_();
+            let (line, file) = directive["#line ".len()..].split_once(' ').unwrap();
+            let line: u32 = line.parse().unwrap();
+            let file = &file[1..file.len() - 2];
+            lexer.set_location(file, line - 1);
+            lexer.continue_()
+        },
+
+        ['a'-'z']+ => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(id)
+        },
+    }
+
+    let input = "a\n#line 10 \"main.c\"\nb c\n\nd\n#line 3 \"header.h\"\ne";
+    let mut lexer = Lexer::new(input);
+
+    let mut tokens: Vec<(Loc, &str, Option<String>)> = vec![];
+    while let Some(token) = lexer.next() {
+        let (start, token, _) = token.unwrap();
+        tokens.push((start, token, lexer.file(start).map(str::to_owned)));
+    }
+
+    assert_eq!(
+        tokens,
+        vec![
+            (loc(0, 0, 0), "a", None),
+            (loc(9, 0, 20), "b", Some("main.c".to_owned())),
+            (loc(9, 2, 22), "c", Some("main.c".to_owned())),
+            (loc(11, 0, 25), "d", Some("main.c".to_owned())),
+            (loc(2, 0, 46), "e", Some("header.h".to_owned())),
+        ]
+    );
+}
+
+#[test]
+fn generic_lexer() {
+    use std::str::FromStr;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input, N> {
+        Num(N),
+        Ident(&'input str),
+    }
+
+    lexer! {
+        Lexer<N: FromStr> -> Token<'input, N>;
+
+        ' ',
+
+        ['0'-'9']+ => |lexer| {
+            let num = match N::from_str(lexer.match_()) {
+                Ok(num) => num,
+                Err(_) => panic!("invalid number"),
+            };
+            lexer.return_(Token::Num(num))
+        },
+
+        ['a'-'z']+ => |lexer| {
+            let ident = lexer.match_();
+            lexer.return_(Token::Ident(ident))
+        },
+    }
+
+    let mut lexer = Lexer::<_, u8>::new("a 12 b");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident("a"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Num(12u8))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Ident("b"))));
+    assert_eq!(next(&mut lexer), None);
+
+    let mut lexer = Lexer::<_, u64>::new("10000000000");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Num(10000000000u64))));
+
+    // Const parameters
+    #[derive(Debug, Default)]
+    struct State<const MAX: usize> {
+        words: usize,
+    }
+
+    lexer! {
+        BoundedLexer<const MAX: usize>(State<MAX>) -> &'input str;
+
+        type Error = usize;
+
+        ' ',
+
+        ['a'-'z']+ =? |lexer| {
+            if lexer.state().words == MAX {
+                return lexer.return_(Err(MAX));
+            }
+            lexer.state().words += 1;
+            let word = lexer.match_();
+            lexer.return_(Ok(word))
+        },
+    }
+
+    let mut lexer = BoundedLexer::<_, 2>::new("a b c");
+    assert_eq!(next(&mut lexer), Some(Ok("a")));
+    assert_eq!(next(&mut lexer), Some(Ok("b")));
+    assert!(matches!(
+        next(&mut lexer),
+        Some(Err(LexerError {
+            kind: LexerErrorKind::Custom(2),
+            ..
+        }))
+    ));
+}
+
+#[test]
+fn user_state_lifetimes_and_generics() {
+    use std::collections::HashMap;
+
+    // State borrowed from the caller, e.g. an interner shared by multiple lexers
+    #[derive(Default)]
+    struct Interner {
+        ids: HashMap<String, usize>,
+    }
+
+    impl Interner {
+        fn intern(&mut self, s: &str) -> usize {
+            let next_id = self.ids.len();
+            *self.ids.entry(s.to_owned()).or_insert(next_id)
+        }
+    }
+
+    struct State<'a> {
+        interner: &'a mut Interner,
+    }
+
+    lexer! {
+        Lexer<'a>(State<'a>) -> usize;
+
+        ' ',
+
+        ['a'-'z']+ => |lexer| {
+            let word = lexer.match_();
+            let id = lexer.state().interner.intern(word);
+            lexer.return_(id)
+        },
+    }
+
+    let mut interner = Interner::default();
+
+    let mut lexer = Lexer::new_with_state(
+        "a b a",
+        State {
+            interner: &mut interner,
+        },
+    );
+    assert_eq!(next(&mut lexer), Some(Ok(0)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(0)));
+    assert_eq!(next(&mut lexer), None);
+
+    let mut lexer = Lexer::new_with_state(
+        "c b",
+        State {
+            interner: &mut interner,
+        },
+    );
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+
+    assert_eq!(interner.ids.len(), 3);
+
+    // State with a type parameter
+    trait SymbolTable {
+        fn add(&mut self, s: &str) -> u32;
+    }
+
+    #[derive(Default)]
+    struct Counter(u32);
+
+    impl SymbolTable for Counter {
+        fn add(&mut self, _s: &str) -> u32 {
+            self.0 += 1;
+            self.0
+        }
+    }
+
+    lexer! {
+        SymbolLexer<S: SymbolTable>(S) -> u32;
+
+        ' ',
+
+        ['a'-'z']+ => |lexer| {
+            let word = lexer.match_();
+            let symbol = lexer.state().add(word);
+            lexer.return_(symbol)
+        },
+    }
+
+    let mut lexer = SymbolLexer::<_, Counter>::new("x y");
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+}
+
+#[test]
+fn options_block() {
+    lexer! {
+        Lexer -> &'input str;
+
+        options {
+            encoding = ascii,
+            case_insensitive,
+            range_checks = binary_search,
+        }
+
+        ' ',
+
+        "select" = "select",
+        ['a'-'z']+ => |lexer| {
+            let ident = lexer.match_();
+            lexer.return_(ident)
+        },
+    }
+
+    let mut lexer = Lexer::new("SELECT Foo");
+    assert_eq!(next(&mut lexer), Some(Ok("select")));
+    assert_eq!(next(&mut lexer), Some(Ok("Foo")));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn skip_option() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        Str(String),
+        Attr(&'input str),
+    }
+
+    lexer! {
+        Lexer(String) -> Token<'input>;
+
+        let whitespace = [' ' '\t' '\n']+;
+        let line_comment = "//" (_ # '\n')*;
+
+        skip = [$whitespace, $line_comment];
+
+        rule Init {
+            ['a'-'z']+ => |lexer| {
+                let id = lexer.match_();
+                lexer.return_(Token::Id(id))
+            },
+
+            '"' => |lexer| {
+                lexer.state().clear();
+                lexer.switch(LexerRule::String)
+            },
+
+            "#[" => |lexer| {
+                lexer.reset_match();
+                lexer.switch(LexerRule::Attr)
+            },
+        }
+
+        // Whitespace and comments are part of strings
+        rule String exclusive {
+            '"' => |lexer| {
+                let str = std::mem::take(lexer.state());
+                lexer.switch_and_return(LexerRule::Init, Token::Str(str))
+            },
+
+            _ => |lexer| {
+                let char = lexer.match_().chars().next_back().unwrap();
+                lexer.state().push(char);
+                lexer.continue_()
+            },
+        }
+
+        // Skip rules are added to rule sets without `exclusive`
+        rule Attr {
+            // Skipped input is a part of the match in rule sets other than `Init`, as with
+            // `<regex>,` rules
+            ['a'-'z']+ => |lexer| {
+                let attr = lexer.match_().trim_start();
+                lexer.return_(Token::Attr(attr))
+            },
+
+            ']' => |lexer| {
+                lexer.reset_match();
+                lexer.switch(LexerRule::Init)
+            },
+        }
+    }
+
+    let mut lexer = Lexer::new("a // b\n \"x // y\" #[ c // d\n ] e");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("a"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Str("x // y".to_owned()))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Attr("c"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("e"))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn fail_rules() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token {
+        Id,
+        Str,
+        Char,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Error {
+        // Location of the opening quote
+        UnterminatedString(Loc),
+        InvalidChar,
+    }
+
+    lexer! {
+        Lexer -> Token;
+
+        type Error = Error;
+
+        rule Init {
+            ' ',
+            ['a'-'z']+ = Token::Id,
+            '"' => |lexer| lexer.switch(LexerRule::String),
+            '\'' => |lexer| lexer.switch(LexerRule::Char),
+        }
+
+        rule String {
+            '"' => |lexer| lexer.switch_and_return(LexerRule::Init, Token::Str),
+            (_ # '\n'),
+
+            // The match starts at the opening quote
+            fail =? |lexer| {
+                let start = lexer.match_loc().0;
+                lexer.switch_and_return(LexerRule::Init, Err(Error::UnterminatedString(start)))
+            },
+        }
+
+        rule Char {
+            ['a'-'z'] '\'' => |lexer| lexer.switch_and_return(LexerRule::Init, Token::Char),
+
+            fail =? |lexer| {
+                lexer.reset_match();
+                lexer.switch_and_return(LexerRule::Init, Err(Error::InvalidChar))
+            },
+        }
+    }
+
+    let mut lexer = Lexer::new("a \"b\" 'c' \"d");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 0, 0), Token::Id, loc(0, 1, 1))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 2, 2), Token::Str, loc(0, 5, 5))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(0, 6, 6), Token::Char, loc(0, 9, 9))))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 12, 12),
+            kind: LexerErrorKind::Custom(Error::UnterminatedString(loc(0, 10, 10))),
+        }))
+    );
+    assert_eq!(lexer.next(), None);
+
+    // Newline in a string. The character that the rule set fails on is a part of the match.
+    let mut lexer = Lexer::new("\"a\nb");
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(1, 0, 3),
+            kind: LexerErrorKind::Custom(Error::UnterminatedString(loc(0, 0, 0))),
+        }))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok((loc(1, 0, 3), Token::Id, loc(1, 1, 4))))
+    );
+    assert_eq!(lexer.next(), None);
+
+    // Rule sets without `fail` rules raise `InvalidToken` errors
+    let mut lexer = Lexer::new("'ab' x");
+    assert!(matches!(
+        lexer.next(),
+        Some(Err(LexerError {
+            kind: LexerErrorKind::Custom(Error::InvalidChar),
+            ..
+        }))
+    ));
+    let mut lexer = Lexer::new("A");
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(0, 0, 0),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+}
+
+#[test]
+fn on_error_hook() {
+    #[derive(Debug, Default)]
+    struct State {
+        errors: Vec<(String, (Loc, Loc), LexerRule)>,
+    }
+
+    fn log_error(
+        state: &mut State,
+        error: &LexerError<String>,
+        span: (Loc, Loc),
+        rule_set: LexerRule,
+    ) {
+        let kind = match &error.kind {
+            LexerErrorKind::Custom(err) => err.clone(),
+            kind => format!("{:?}", kind),
+        };
+        state.errors.push((kind, span, rule_set));
+    }
+
+    lexer! {
+        Lexer(State) -> &'input str;
+
+        type Error = String;
+
+        on_error = log_error;
+
+        rule Init {
+            ' ',
+            ['a'-'z']+ => |lexer| {
+                let id = lexer.match_();
+                lexer.return_(id)
+            },
+            ['0'-'9']+ =? |lexer| lexer.return_(Err("number".to_owned())),
+            '"' => |lexer| lexer.switch(LexerRule::String),
+        }
+
+        rule String {
+            '"' => |lexer| {
+                let str = lexer.match_();
+                lexer.switch_and_return(LexerRule::Init, str)
+            },
+            ['a'-'z'],
+        }
+    }
+
+    let mut lexer = Lexer::new("a 12 \"b!");
+    assert_eq!(next(&mut lexer), Some(Ok("a")));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+
+    assert_eq!(
+        lexer.state().errors,
+        vec![
+            (
+                "number".to_owned(),
+                (loc(0, 2, 2), loc(0, 4, 4)),
+                LexerRule::Init
+            ),
+            (
+                "InvalidToken".to_owned(),
+                (loc(0, 5, 5), loc(0, 8, 8)),
+                LexerRule::String
+            ),
+        ]
+    );
+
+    // Indentation errors
+    fn count_error(
+        state: &mut usize,
+        _error: &LexerError<std::convert::Infallible>,
+        _span: (Loc, Loc),
+        _rule_set: IndentedLexerRule,
+    ) {
+        *state += 1;
+    }
+
+    lexer! {
+        IndentedLexer(usize) -> &'input str;
+
+        on_error = count_error;
+
+        indentation = {
+            newline: "newline",
+            indent: "indent",
+            dedent: "dedent",
+        };
+
+        [' ' '\n']+,
+        ['a'-'z']+ => |lexer| {
+            let id = lexer.match_();
+            lexer.return_(id)
+        },
+    }
+
+    let mut lexer = IndentedLexer::new("a\n    b\n  c");
+    let errors = lexer.by_ref().filter(|token| token.is_err()).count();
+    assert_eq!(errors, 1);
+    assert_eq!(*lexer.state(), 1);
+}
+
+#[test]
+fn tie_breaking_priority() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        If,
+        Num,
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        tie_breaking = priority;
+
+        ' ',
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+        "if" priority 1 = Token::If,
+        ['0'-'9']+ priority -1 = Token::Id("number"),
+        "0" = Token::Num,
+    }
+
+    let mut lexer = Lexer::new("if iff 0 01");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::If)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("iff"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Num)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("number"))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn shortest_match_rules() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Instruction(&'input str),
+        Comment(&'input str),
+        Id(&'input str),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        tie_breaking = priority;
+
+        ' ',
+        "<?" _* "?>" shortest => |lexer| lexer.return_(Token::Instruction(lexer.match_())),
+        "/*" _* "*/" priority 1 shortest => |lexer| lexer.return_(Token::Comment(lexer.match_())),
+        ['a'-'z' '*' '/']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+    }
+
+    let mut lexer = Lexer::new("<?a?> b <?c?> /*d*/ */");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Instruction("<?a?>"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("b"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Instruction("<?c?>"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Comment("/*d*/"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("*/"))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn shared_rules() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token {
+        Id,
+        Str,
+    }
+
+    #[derive(Debug, Default)]
+    struct State {
+        newlines: usize,
+    }
+
+    lexer! {
+        Lexer(State) -> Token;
+
+        rule Init {
+            ' ',
+            ['a'-'z']+ = Token::Id,
+            '"' => |lexer| lexer.switch(LexerRule::String),
+            "/*" => |lexer| lexer.switch(LexerRule::Comment),
+        }
+
+        rule String {
+            '"' => |lexer| lexer.switch_and_return(LexerRule::Init, Token::Str),
+            _ # ['\r' '\n'],
+        }
+
+        rule Comment {
+            "*/" => |lexer| lexer.switch(LexerRule::Init),
+            _ # ['\r' '\n'],
+        }
+
+        // Added to the end of the rule sets
+        <Init, String, Comment> '\r'? '\n' => |lexer| {
+            lexer.state().newlines += 1;
+            lexer.continue_()
+        },
+    }
+
+    let mut lexer = Lexer::new("a\n\"b\r\nc\" /*\n\n*/\nd");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Str)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id)));
+    assert_eq!(next(&mut lexer), None);
+    assert_eq!(lexer.state().newlines, 5);
+}
+
+#[test]
+fn generated_type_names() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token {
+        Str,
+        Int,
+    }
+
+    // Two lexers in the same module, with rule sets and groups, don't collide with renamed types
+    lexer! {
+        Lexer -> Token;
+
+        rule_enum_name = StrRule;
+        group_enum_name = StrGroup;
+        token_kind_name = StrTokenKind;
+        token_kind = true;
+
+        rule Init {
+            ' ',
+            '"' => |lexer| lexer.switch(StrRule::String),
+            group Ints {
+                ['0'-'9']+ = Token::Int,
+            }
+        }
+
+        rule String {
+            '"' => |lexer| lexer.switch_and_return(StrRule::Init, Token::Str),
+            _,
+        }
+    }
+
+    lexer! {
+        OtherLexer -> Token;
+
+        options {
+            rule_enum_name = IntRule,
+            group_enum_name = IntGroup,
+        }
+
+        rule Init {
+            ' ',
+            group Ints {
+                ['0'-'9']+ = Token::Int,
+            }
+            '#' => |lexer| lexer.switch(IntRule::Comment),
+        }
+
+        rule Comment {
+            '\n' => |lexer| lexer.switch(IntRule::Init),
+            _,
+        }
+    }
+
+    let mut lexer = Lexer::new("\"a\" 1").with_group(StrGroup::Ints, true);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Str)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Int)));
+    assert_eq!(next(&mut lexer), None);
+    assert_eq!(StrTokenKind::kind(&Token::Int), StrTokenKind::Int);
+
+    let mut lexer = OtherLexer::new("1 # x\n2").with_group(IntGroup::Ints, true);
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Int)));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Int)));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn token_kind() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        If,
+        Plus,
+        Int,
+        Id(&'input str),
+        Comment { text: &'input str },
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        token_kind = true;
+
+        ' ',
+        "if" = Token::If,
+        '+' | "plus" = Token::Plus,
+        ['0'-'9']+ = Token::Int,
+        "0x" ['0'-'9' 'a'-'f']+ = Token::Int,
+        "//" (_ # '\n')* => |lexer| lexer.return_(Token::Comment { text: lexer.match_() }),
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+    }
+
+    let kinds: Vec<LexerTokenKind> = Lexer::new("if + x 1 // c")
+        .map(|token| LexerTokenKind::kind(&token.unwrap().1))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            LexerTokenKind::If,
+            LexerTokenKind::Plus,
+            LexerTokenKind::Other,
+            LexerTokenKind::Int,
+            LexerTokenKind::Other,
+        ]
+    );
+
+    assert_eq!(LexerTokenKind::If.to_string(), "\"if\"");
+    assert_eq!(LexerTokenKind::Plus.to_string(), "'+' | \"plus\"");
+    assert_eq!(
+        LexerTokenKind::Int.to_string(),
+        "['0'-'9']+ or \"0x\" ['0'-'9' 'a'-'f']+"
+    );
+    assert_eq!(LexerTokenKind::Other.to_string(), "other token");
+}
+
+#[test]
+fn spanned_tokens() {
+    use lexgen_util::Spanned;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        Int,
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        spanned_tokens = true;
+
+        ' ' | '\n',
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+        ['0'-'9']+ = Token::Int,
+    }
+
+    let mut lexer = Lexer::new("ab\n 12 !");
+    assert_eq!(
+        lexer.next(),
+        Some(Ok(Spanned {
+            value: Token::Id("ab"),
+            start: loc(0, 0, 0),
+            end: loc(0, 2, 2),
+        }))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Ok(Spanned {
+            value: Token::Int,
+            start: loc(1, 1, 4),
+            end: loc(1, 3, 6),
+        }))
+    );
+    assert_eq!(
+        lexer.next(),
+        Some(Err(LexerError {
+            location: loc(1, 4, 7),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+}
+
+#[test]
+fn next_token_with_cursor() {
+    use lexgen_util::StrCursor;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Id(&'input str),
+        Str(&'input str),
+    }
+
+    #[derive(Debug, Default)]
+    struct State {
+        ids: usize,
+    }
+
+    lexer! {
+        Lexer(State) -> Token<'input>;
+
+        next_token = true;
+
+        rule Init {
+            ' ',
+            ['a'-'z']+ => |lexer| {
+                lexer.state().ids += 1;
+                let id = lexer.match_();
+                lexer.return_(Token::Id(id))
+            },
+            '"' => |lexer| lexer.switch(LexerRule::String),
+        }
+
+        rule String {
+            '"' => |lexer| {
+                let str = lexer.match_();
+                lexer.switch_and_return(LexerRule::Init, Token::Str(str))
+            },
+            _,
+        }
+    }
+
+    let input = "a \"b c\" ! d \"e";
+    let mut cursor = StrCursor::new(input);
+    let mut state = State::default();
+    let mut rule_set = LexerRule::Init;
+
+    let mut next = || Lexer::next_token(&mut cursor, &mut state, &mut rule_set);
+
+    assert_eq!(
+        next(),
+        Some(Ok((loc(0, 0, 0), Token::Id("a"), loc(0, 1, 1))))
+    );
+    assert_eq!(
+        next(),
+        Some(Ok((loc(0, 2, 2), Token::Str("\"b c\""), loc(0, 7, 7))))
+    );
+    assert_eq!(
+        next(),
+        Some(Err(LexerError {
+            location: loc(0, 8, 8),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+    assert_eq!(
+        next(),
+        Some(Ok((loc(0, 10, 10), Token::Id("d"), loc(0, 11, 11))))
+    );
+
+    // Unterminated string, the lexer continues in `Init` after the end of the input
+    assert_eq!(
+        next(),
+        Some(Err(LexerError {
+            location: loc(0, 12, 12),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+    assert_eq!(next(), None);
+
+    assert!(cursor.is_empty());
+    assert_eq!(state.ids, 2);
+    assert_eq!(rule_set, LexerRule::Init);
+}
+
+#[test]
+fn captures() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token<'input> {
+        Hex(u64),
+        Attr(&'input str, &'input str),
+        Float(&'input str, &'input str),
+        Id(&'input str),
+    }
+
+    lexer! {
+        Lexer -> Token<'input>;
+
+        let digit = ['0'-'9'];
+
+        ' ',
+        "0x" (['0'-'9' 'a'-'f']+ as digits) =>
+            |lexer| lexer.return_(Token::Hex(u64::from_str_radix(digits, 16).unwrap())),
+        '@' (['a'-'z']+ as name) '(' (_* as value) ')' shortest = Token::Attr(name, value),
+        // Earlier captures match as much as possible
+        ($digit+ as int) ($digit* '.' $digit* as frac) = Token::Float(int, frac),
+        ['a'-'z']+ => |lexer| lexer.return_(Token::Id(lexer.match_())),
+    }
+
+    let mut lexer = Lexer::new("0xff @cfg(a b) 12.5 123. ab");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Hex(255))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Attr("cfg", "a b"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Float("12", ".5"))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Float("123", "."))));
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id("ab"))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn captures_overlapping_parts() {
+    // Parts that can match the same input, split in one pass over the match
+    lexer! {
+        Lexer -> (&'input str, &'input str, &'input str);
+
+        ' ',
+        ($$d* as a) ($$d* as b) ($$d* as c) "0!" = (a, b, c),
+    }
+
+    let mut lexer = Lexer::new("1230! 0!");
+    assert_eq!(next(&mut lexer), Some(Ok(("123", "", ""))));
+    assert_eq!(next(&mut lexer), Some(Ok(("", "", ""))));
+    assert_eq!(next(&mut lexer), None);
+
+    let input = "1".repeat(100_000) + "0!";
+    let mut lexer = Lexer::new(&input);
+    assert_eq!(next(&mut lexer), Some(Ok((&input[..100_000], "", ""))));
+    assert_eq!(next(&mut lexer), None);
+}
+
+#[test]
+fn fill() {
+    lexer! {
+        Lexer -> u32;
+
+        ' ',
+        ['0'-'9']+ => |lexer| lexer.return_(lexer.match_().parse::<u32>().unwrap()),
+    }
+
+    let mut lexer = Lexer::new("1 22 333 4444 x 5");
+    let mut tokens = vec![];
+
+    assert_eq!(lexer.fill(&mut tokens, 3), Ok(3));
+    assert_eq!(
+        tokens,
+        vec![
+            (loc(0, 0, 0), 1, loc(0, 1, 1)),
+            (loc(0, 2, 2), 22, loc(0, 4, 4)),
+            (loc(0, 5, 5), 333, loc(0, 8, 8)),
+        ]
+    );
+
+    // Tokens before an error are added to the vector
+    assert_eq!(
+        lexer.fill(&mut tokens, 3),
+        Err(LexerError {
+            location: loc(0, 14, 14),
+            kind: LexerErrorKind::InvalidToken,
+        })
+    );
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(tokens[3], (loc(0, 9, 9), 4444, loc(0, 13, 13)));
+
+    // At the end of the input fewer than `n` tokens are added
+    tokens.clear();
+    assert_eq!(lexer.fill(&mut tokens, 3), Ok(1));
+    assert_eq!(tokens, vec![(loc(0, 16, 16), 5, loc(0, 17, 17))]);
+    assert_eq!(lexer.fill(&mut tokens, 3), Ok(0));
+}
+
+#[test]
+fn first_char_dispatch() {
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token {
+        Id,
+        Int,
+        Str,
+        Op,
+        Keyword,
+        Greek,
+    }
+
+    // The initial state has enough transitions on characters below U+0100 to be compiled to a
+    // dispatch table, with transitions on other characters looked up with binary search
+    lexer! {
+        Lexer -> Token;
+
+        [' ' '\t' '\n'],
+        "if" | "else" | "while" = Token::Keyword,
+        ['a'-'z' 'A'-'Z' '_' 'é'] ['a'-'z' 'A'-'Z' '_' '0'-'9' 'é']* = Token::Id,
+        ['0'-'9']+ = Token::Int,
+        '"' (_ # '"')* '"' = Token::Str,
+        '+' | '-' | '*' | '/' | '%' | '=' | "==" | '<' | '>' | '!' | "!=" = Token::Op,
+        ['α'-'ω']+ = Token::Greek,
+    }
+
+    let mut lexer = Lexer::new("if x1 == 42 é \"a b\" else αβ + while ! iff");
+    let tokens: Vec<Token> = std::iter::from_fn(|| next(&mut lexer))
+        .map(|token| token.unwrap())
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Keyword,
+            Token::Id,
+            Token::Op,
+            Token::Int,
+            Token::Id,
+            Token::Str,
+            Token::Keyword,
+            Token::Greek,
+            Token::Op,
+            Token::Keyword,
+            Token::Op,
+            Token::Id,
+        ]
+    );
+
+    let mut lexer = Lexer::new("x ~");
+    assert_eq!(next(&mut lexer), Some(Ok(Token::Id)));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+
+    let mut lexer = Lexer::new("Ж");
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+}
+
+#[test]
+fn read_chars_buffering() {
+    use lexgen_util::ReadChars;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token {
+        Word,
+        Str,
+    }
+
+    lexer! {
+        Lexer -> Token;
+
+        ' ',
+        ['a'-'z' 'é' '€']+ = Token::Word,
+        '"' (_ # '"')* '"' = Token::Str,
+    }
+
+    const CHUNK_SIZE: usize = 16;
+
+    // Words with multi-byte characters split across chunks, and strings much longer than a chunk
+    let string = format!("\"{}\"", "aé€ ".repeat(100));
+    let mut input = String::new();
+    for _ in 0..200 {
+        input.push_str("word€é ");
+        input.push_str(&string);
+        input.push(' ');
+    }
+
+    let chars = ReadChars::with_chunk_size(input.as_bytes(), CHUNK_SIZE);
+    let status = chars.status();
+    let mut lexer = Lexer::new_from_iter(chars);
+
+    let mut max_buffered = 0;
+    let mut n_tokens = 0;
+    for token in &mut lexer {
+        let (start, token, end) = token.unwrap();
+        let len = end.byte_idx - start.byte_idx;
+        if n_tokens % 2 == 0 {
+            assert_eq!(token, Token::Word);
+            assert_eq!(len, "word€é".len());
+        } else {
+            assert_eq!(token, Token::Str);
+            assert_eq!(len, string.len());
+        }
+        n_tokens += 1;
+        max_buffered = max_buffered.max(status.buffered_bytes());
+    }
+
+    assert_eq!(n_tokens, 400);
+    assert!(
+        max_buffered <= string.len() + 2 * CHUNK_SIZE,
+        "{} bytes buffered",
+        max_buffered
+    );
+    drop(lexer);
+    assert_eq!(status.buffered_bytes(), 0);
+    assert!(status.take_error().is_none());
+}
+
+#[test]
+fn read_chars_error() {
+    use lexgen_util::ReadChars;
+
+    struct FailingReader(&'static [u8]);
+
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(std::io::Error::other("failed"));
+            }
+            let n = buf.len().min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    lexer! {
+        Lexer -> usize;
+
+        ' ',
+        ['a'-'z']+ => |lexer| {
+            let len = lexer.match_loc().1.byte_idx - lexer.match_loc().0.byte_idx;
+            lexer.return_(len)
+        },
+    }
+
+    // Invalid UTF-8 is replaced with U+FFFD, a read error ends the input
+    let chars = ReadChars::with_chunk_size(FailingReader(b"ab \xFF cde"), 4);
+    let status = chars.status();
+    let mut lexer = Lexer::new_from_iter(chars);
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+    assert_eq!(next(&mut lexer), Some(Ok(3)));
+    assert_eq!(next(&mut lexer), None);
+    assert_eq!(status.take_error().unwrap().to_string(), "failed");
+}
+
+#[test]
+fn async_read() {
+    use lexgen_util::AsyncLexer;
+
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    // Returns one byte at a time, then fails
+    struct SlowReader(&'static [u8]);
+
+    impl tokio::io::AsyncRead for SlowReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            match self.0.split_first() {
+                Some((byte, rest)) => {
+                    buf.put_slice(&[*byte]);
+                    self.0 = rest;
+                    Poll::Ready(Ok(()))
+                }
+                None => Poll::Ready(Err(std::io::Error::other("failed"))),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Token {
+        Ab,
+        Word(usize),
+    }
+
+    lexer! {
+        #[derive(Clone)]
+        Lexer -> Token;
+
+        ' ',
+        "ab" = Token::Ab,
+        ['a'-'z' 'é']+ => |lexer| {
+            let len = lexer.match_loc().1.byte_idx - lexer.match_loc().0.byte_idx;
+            lexer.return_(Token::Word(len))
+        },
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        // Tokens and characters split across reads
+        let mut lexer = AsyncLexer::new(
+            SlowReader(b"ab abc \xC3\xA9\xC3\xA9 "),
+            Lexer::new_from_iter,
+        );
+        assert_eq!(
+            lexer.next_token().await.unwrap(),
+            Some(Ok((loc(0, 0, 0), Token::Ab, loc(0, 2, 2))))
+        );
+        assert_eq!(
+            lexer.next_token().await.unwrap(),
+            Some(Ok((loc(0, 3, 3), Token::Word(3), loc(0, 6, 6))))
+        );
+        assert_eq!(
+            lexer.next_token().await.unwrap(),
+            Some(Ok((loc(0, 7, 7), Token::Word(4), loc(0, 9, 11))))
+        );
+        assert_eq!(lexer.next_token().await.unwrap_err().to_string(), "failed");
+
+        // End of input, with larger chunks
+        let mut lexer = AsyncLexer::with_chunk_size(&b"abc ab"[..], 4, Lexer::new_from_iter);
+        assert_eq!(
+            lexer.next_token().await.unwrap(),
+            Some(Ok((loc(0, 0, 0), Token::Word(3), loc(0, 3, 3))))
+        );
+        assert_eq!(
+            lexer.next_token().await.unwrap(),
+            Some(Ok((loc(0, 4, 4), Token::Ab, loc(0, 6, 6))))
+        );
+        assert_eq!(lexer.next_token().await.unwrap(), None);
+    });
+}
+
+#[test]
+fn mapped_file() {
+    use lexgen_util::MappedFile;
+
+    lexer! {
+        Lexer -> &'input str;
+
+        ' ',
+        ['a'-'z']+ => |lexer| lexer.return_(lexer.match_()),
+    }
+
+    lexer! {
+        BytesLexer -> &'input [u8];
+
+        invalid_utf8 = replace;
+
+        ' ',
+        (_ # ' ')+ => |lexer| lexer.return_(lexer.match_bytes()),
+    }
+
+    let path = std::env::temp_dir().join(format!("lexgen_mapped_file_{}", std::process::id()));
+
+    std::fs::write(&path, "ab cd").unwrap();
+    let file = unsafe { MappedFile::open(&path) }.unwrap();
+    let input = file.as_str().unwrap();
+    let mut lexer = Lexer::new(input);
+    let token = next(&mut lexer).unwrap().unwrap();
+    assert_eq!(token, "ab");
+    // Tokens borrow from the mapping
+    assert_eq!(token.as_ptr(), file.as_bytes().as_ptr());
+    assert_eq!(next(&mut lexer), Some(Ok("cd")));
+    assert_eq!(next(&mut lexer), None);
+    drop(file);
+
+    // Invalid UTF-8 can be lexed as bytes
+    std::fs::write(&path, b"ab \xFFcd").unwrap();
+    let file = unsafe { MappedFile::open(&path) }.unwrap();
+    let err = file.as_str().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err.get_ref().unwrap().is::<std::str::Utf8Error>());
+    let mut lexer = BytesLexer::new_from_bytes(file.as_bytes());
+    assert_eq!(next(&mut lexer), Some(Ok(&b"ab"[..])));
+    assert_eq!(next(&mut lexer), Some(Ok(&b"\xFFcd"[..])));
+    assert_eq!(next(&mut lexer), None);
+    drop(file);
+
+    // Empty files
+    std::fs::write(&path, "").unwrap();
+    let file = unsafe { MappedFile::open(&path) }.unwrap();
+    let mut lexer = Lexer::new(file.as_str().unwrap());
+    assert_eq!(next(&mut lexer), None);
+    drop(file);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn token_source_trait_object() {
+    use lexgen_util::TokenSource;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Token {
+        Word,
+        Other,
+    }
+
+    lexer! {
+        StrictLexer -> Token;
+
+        ' ',
+        ['a'-'z']+ = Token::Word,
+    }
+
+    lexer! {
+        LenientLexer -> Token;
+
+        spanned_tokens = true;
+
+        ' ',
+        ['a'-'z']+ = Token::Word,
+        _ = Token::Other,
+    }
+
+    fn lexer(
+        input: &str,
+        strict: bool,
+    ) -> Box<dyn TokenSource<Token = Token, Error = std::convert::Infallible> + '_> {
+        if strict {
+            Box::new(StrictLexer::new(input))
+        } else {
+            Box::new(LenientLexer::new(input))
+        }
+    }
+
+    let mut strict = lexer("ab !", true);
+    assert_eq!(
+        strict.next_spanned(),
+        Some(Ok((loc(0, 0, 0), Token::Word, loc(0, 2, 2))))
+    );
+    assert_eq!(
+        strict.next_spanned(),
+        Some(Err(LexerError {
+            location: loc(0, 3, 3),
+            kind: LexerErrorKind::InvalidToken,
+        }))
+    );
+
+    // Lexers with `spanned_tokens` yield tuples too
+    let mut lenient = lexer("ab !", false);
+    assert_eq!(
+        lenient.next_spanned(),
+        Some(Ok((loc(0, 0, 0), Token::Word, loc(0, 2, 2))))
+    );
+    assert_eq!(
+        lenient.next_spanned(),
+        Some(Ok((loc(0, 3, 3), Token::Other, loc(0, 4, 4))))
+    );
+    assert_eq!(lenient.next_spanned(), None);
+}
+
+#[test]
+fn send_sync() {
+    #[derive(Debug, Default)]
+    struct State {
+        words: usize,
+    }
+
+    lexer! {
+        Lexer(State) -> usize;
+
+        ' ',
+        ['a'-'z']+ => |lexer| {
+            lexer.state().words += 1;
+            let words = lexer.state().words;
+            lexer.return_(words)
+        },
+    }
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let inputs = vec!["a bc", "d e f", "ghi"];
+    let handles: Vec<_> = inputs
+        .into_iter()
+        .map(|input| {
+            let lexer = Lexer::new(input);
+            assert_send_sync(&lexer);
+            std::thread::spawn(move || lexer.map(|token| token.unwrap().1).last())
+        })
+        .collect();
+    let counts: Vec<Option<usize>> = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(counts, vec![Some(2), Some(3), Some(1)]);
+}
+
+#[test]
+fn progress_callback() {
+    use lexgen_util::ProgressInterval;
+    use std::sync::{Arc, Mutex};
+
+    lexer! {
+        Lexer -> ();
+
+        ' ',
+        ['a'-'z']+ = (),
+    }
+
+    let input = "abc de fghij k lm nopq";
+
+    let reports = Arc::new(Mutex::new(vec![]));
+    let reports_ = reports.clone();
+    let mut lexer = Lexer::new(input);
+    lexer.set_progress_callback(ProgressInterval::Bytes(5), move |loc| {
+        reports_.lock().unwrap().push(loc.byte_idx)
+    });
+    assert_eq!(lexer.count(), 6);
+    // Called at the end of the first token at or after bytes 5, 10, 15, and 20
+    assert_eq!(*reports.lock().unwrap(), vec![6, 12, 17, 22]);
+
+    let reports = Arc::new(Mutex::new(vec![]));
+    let reports_ = reports.clone();
+    let mut lexer = Lexer::new(input);
+    lexer.set_progress_callback(ProgressInterval::Tokens(2), move |loc| {
+        reports_.lock().unwrap().push(loc.byte_idx)
+    });
+    assert_eq!(lexer.count(), 6);
+    assert_eq!(*reports.lock().unwrap(), vec![6, 14, 22]);
+}
+
+#[test]
+fn cancellation() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    lexer! {
+        Lexer -> usize;
+
+        cancellation = true;
+
+        rule Init {
+            ' ',
+            ['a'-'z']+ => |lexer| {
+                let len = lexer.match_().len();
+                lexer.return_(len)
+            },
+            '"' => |lexer| lexer.switch(LexerRule::Str),
+        }
+
+        rule Str {
+            '"' => |lexer| {
+                let len = lexer.match_().len();
+                lexer.switch_and_return(LexerRule::Init, len)
+            },
+            _,
+        }
+    }
+
+    let flag = Arc::new(AtomicBool::new(false));
+    let mut lexer = Lexer::new("ab \"c d\" efg hi");
+    lexer.set_cancellation_flag(flag.clone());
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(next(&mut lexer), Some(Ok(5)));
+
+    // Flag is checked at the start of the next match
+    flag.store(true, Ordering::Relaxed);
+    assert_eq!(
+        next(&mut lexer),
+        Some(Err(LexerError {
+            location: loc(0, 8, 8),
+            kind: LexerErrorKind::Cancelled,
+        }))
+    );
+    assert_eq!(next(&mut lexer), None);
+
+    // Lexers without a flag are not cancelled
+    let lexer = Lexer::new("ab \"c d\" efg hi");
+    assert_eq!(lexer.count(), 4);
+}
+
+#[test]
+fn counters() {
+    use lexgen_util::LexerStats;
+
+    lexer! {
+        Lexer -> u32;
+
+        counters = true;
+
+        ' ',
+        "a" = 1,
+        "abcd" = 2,
+    }
+
+    let mut lexer = Lexer::new("abcd abc");
+    assert_eq!(lexer.stats(), LexerStats::default());
+
+    // "abcd" can't be longer, so it's returned without reading the space
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+    assert_eq!(
+        lexer.stats(),
+        LexerStats {
+            tokens: 1,
+            bytes: 4,
+            backtracks: 0,
+            backtracked_bytes: 0,
+            max_lookahead: 0,
+        }
+    );
+
+    // "abc" backtracks to "a" at the end of the input
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(
+        lexer.stats(),
+        LexerStats {
+            tokens: 2,
+            bytes: 6,
+            backtracks: 1,
+            backtracked_bytes: 2,
+            max_lookahead: 2,
+        }
+    );
+
+    // Errors are not tokens
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+    assert!(matches!(next(&mut lexer), Some(Err(_))));
+    assert_eq!(next(&mut lexer), None);
+    assert_eq!(lexer.stats().tokens, 2);
+    assert_eq!(lexer.stats().bytes, 8);
+}
+
+#[test]
+fn interner() {
+    use lexgen_util::Interner;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct Symbols {
+        ids: HashMap<String, u32>,
+    }
+
+    impl Interner for Symbols {
+        type Symbol = u32;
+
+        fn intern(&mut self, string: &str) -> u32 {
+            if let Some(id) = self.ids.get(string) {
+                return *id;
+            }
+            let id = self.ids.len() as u32;
+            self.ids.insert(string.to_owned(), id);
+            id
+        }
+    }
+
+    lexer! {
+        Lexer -> u32;
+
+        type Interner = Symbols;
+
+        ' ',
+        ['a'-'z']+ => |lexer| {
+            let word = lexer.match_();
+            let symbol = lexer.intern(word);
+            lexer.return_(symbol)
+        },
+    }
+
+    let mut lexer = Lexer::new("a bc a d bc").with_interner(Symbols::default());
+    let symbols: Vec<u32> = std::iter::from_fn(|| next(&mut lexer))
+        .map(|token| token.unwrap())
+        .collect();
+    assert_eq!(symbols, vec![0, 1, 0, 2, 1]);
+    assert_eq!(lexer.interner().unwrap().ids.len(), 3);
+
+    // Interner shared by multiple lexers
+    lexer! {
+        BorrowingLexer<'s> -> u32;
+
+        type Interner = &'s mut Symbols;
+
+        ' ',
+        ['a'-'z']+ => |lexer| {
+            let word = lexer.match_();
+            let symbol = lexer.intern(word);
+            lexer.return_(symbol)
+        },
+    }
+
+    let mut symbols = Symbols::default();
+    let mut lexer = BorrowingLexer::new("x y").with_interner(&mut symbols);
+    assert_eq!(next(&mut lexer), Some(Ok(0)));
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    let mut lexer = BorrowingLexer::new("y z").with_interner(&mut symbols);
+    assert_eq!(next(&mut lexer), Some(Ok(1)));
+    assert_eq!(next(&mut lexer), Some(Ok(2)));
+}
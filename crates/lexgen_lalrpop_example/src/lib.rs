@@ -56,8 +56,6 @@ pub mod ast {
 #[allow(clippy::manual_range_contains)]
 pub mod lexer {
     use lexgen::lexer;
-    pub type LexerError = lexgen_util::LexerError<String>;
-    pub type Loc = lexgen_util::Loc;
 
     #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
     pub enum Token<'input> {
@@ -102,6 +100,10 @@ pub mod lexer {
         pub Lexer(LexerState) -> Token<'input>;
         type Error = String;
 
+        // Generates `LexerSpanned` and `LexerParseError` type aliases, and the `extern` block for
+        // the grammar in `$OUT_DIR/Lexer.extern.lalrpop`
+        lalrpop = true;
+
         let ws = [' ' '\t' '\n'] | "\r\n";
 
         rule Init {
@@ -162,19 +164,18 @@ lalrpop_mod!(#[allow(unused_imports, clippy::all)] pub parser, "/interpolation.r
 mod test {
     use super::{
         ast::Expression,
-        lexer::{Lexer, LexerError, Loc, Token},
+        lexer::{Lexer, LexerParseError},
         parser::ExpressionParser,
     };
 
-    type Result<'input, T> =
-        std::result::Result<T, lalrpop_util::ParseError<Loc, Token<'input>, LexerError>>;
+    type Result<'input, T> = std::result::Result<T, LexerParseError<'input>>;
 
-    fn parse(code: &str) -> Result<Expression> {
+    fn parse(code: &str) -> Result<'_, Expression<'_>> {
         let lexer = Lexer::new(code);
         ExpressionParser::new().parse(lexer)
     }
 
-    fn parse_and_eval(code: &str) -> Result<String> {
+    fn parse_and_eval(code: &str) -> Result<'_, String> {
         parse(code).map(|e| e.eval())
     }
 
@@ -0,0 +1,240 @@
+//! Lexing input from `tokio::io::AsyncRead`, with the `tokio` feature
+
+use crate::{decode_utf8, LexerError, Loc, TokenSource, DEFAULT_CHUNK_SIZE};
+
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Iterator over characters of UTF-8 encoded input read by an [`AsyncLexer`]. Lexers run by an
+/// `AsyncLexer` are created with `new_from_iter` (or `new_from_iter_with_state`) with this
+/// iterator.
+///
+/// Like [`ReadChars`](crate::ReadChars), input is kept in chunks shared by clones of the iterator,
+/// and a chunk is dropped as soon as no clone refers to it. When the iterator reaches the end of
+/// the bytes read so far it yields `None` and tells the `AsyncLexer` to read more input and run
+/// the lexer again.
+///
+/// Invalid UTF-8 is replaced with U+FFFD.
+#[derive(Clone)]
+pub struct AsyncChars {
+    // Chunk of the next character
+    chunk: Arc<Chunk>,
+
+    // Index of the next character in `chunk`
+    idx: usize,
+}
+
+struct Chunk {
+    bytes: Box<[u8]>,
+
+    // Next chunk. Not set until the `AsyncLexer` reads the next chunk, `None` at the end of the
+    // input.
+    next: OnceLock<Option<Arc<Chunk>>>,
+
+    // Set when an iterator reaches the end of the bytes read so far
+    starved: Arc<AtomicBool>,
+}
+
+impl AsyncChars {
+    // Advance `len` bytes. The bytes should already be read.
+    fn advance(&mut self, mut len: usize) {
+        while len != 0 {
+            let remaining = self.chunk.bytes.len() - self.idx;
+            if remaining == 0 {
+                self.chunk = self.chunk.next.get().unwrap().clone().unwrap();
+                self.idx = 0;
+                continue;
+            }
+            let n = remaining.min(len);
+            self.idx += n;
+            len -= n;
+        }
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // Drop the rest of the chain in a loop, to avoid recursion on long chains
+        let mut next = self.next.take().flatten();
+        while let Some(chunk) = next {
+            next = match Arc::try_unwrap(chunk) {
+                Ok(mut chunk) => chunk.next.take().flatten(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+// Length of the UTF-8 encoded character starting with `byte`. Invalid bytes are one byte long.
+fn utf8_len(byte: u8) -> usize {
+    match byte {
+        0xF0..=0xFF => 4,
+        0xE0..=0xEF => 3,
+        0xC0..=0xDF => 2,
+        _ => 1,
+    }
+}
+
+impl Iterator for AsyncChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let bytes = &self.chunk.bytes[self.idx..];
+        if let Some(byte) = bytes.first() {
+            if bytes.len() >= utf8_len(*byte) {
+                let (char, len) = decode_utf8(bytes).unwrap_or_else(|len| ('\u{FFFD}', len));
+                self.idx += len;
+                return Some(char);
+            }
+        }
+
+        // Character continues in the next chunks, which may not be read yet
+        let mut buf = [0u8; 4];
+        let mut buf_len = 0;
+        let mut char_len = buf.len();
+        let mut chunk = self.chunk.clone();
+        let mut idx = self.idx;
+        while buf_len != char_len {
+            if idx == chunk.bytes.len() {
+                match chunk.next.get() {
+                    Some(Some(next)) => {
+                        chunk = next.clone();
+                        idx = 0;
+                        continue;
+                    }
+                    Some(None) => break,
+                    None => {
+                        chunk.starved.store(true, Ordering::Relaxed);
+                        return None;
+                    }
+                }
+            }
+            if buf_len == 0 {
+                char_len = utf8_len(chunk.bytes[idx]);
+            }
+            buf[buf_len] = chunk.bytes[idx];
+            buf_len += 1;
+            idx += 1;
+        }
+        drop(chunk);
+
+        if buf_len == 0 {
+            return None;
+        }
+
+        let (char, len) = decode_utf8(&buf[..buf_len]).unwrap_or_else(|len| ('\u{FFFD}', len));
+        self.advance(len);
+        Some(char)
+    }
+}
+
+/// Runs a lexer on input read from a `tokio::io::AsyncRead`, e.g. a socket, returning tokens as
+/// they're read.
+///
+/// The lexer runs on the input read so far. When it needs more input to decide on a token, the
+/// lexer is restored to its state before the token (it needs to implement `Clone`), and run again
+/// after reading more input. Semantic actions of rules matched before the end of the input read so
+/// far can run again, so they should only update the lexer's state.
+pub struct AsyncLexer<R, L> {
+    reader: R,
+    lexer: L,
+
+    // Last chunk read, the next chunk is linked to it
+    tail: Arc<Chunk>,
+
+    starved: Arc<AtomicBool>,
+
+    chunk_size: usize,
+}
+
+impl<R: AsyncRead + Unpin, L: TokenSource + Clone> AsyncLexer<R, L> {
+    /// Create an `AsyncLexer` reading from `reader`. `lexer` creates the lexer from the input
+    /// characters, e.g. `Lexer::new_from_iter`.
+    pub fn new(reader: R, lexer: impl FnOnce(AsyncChars) -> L) -> Self {
+        Self::with_chunk_size(reader, DEFAULT_CHUNK_SIZE, lexer)
+    }
+
+    /// Same as `new`, but reads at most `chunk_size` bytes at a time
+    pub fn with_chunk_size(
+        reader: R,
+        chunk_size: usize,
+        lexer: impl FnOnce(AsyncChars) -> L,
+    ) -> Self {
+        assert!(chunk_size != 0, "chunk size must be positive");
+        let starved = Arc::new(AtomicBool::new(false));
+        let tail = Arc::new(Chunk {
+            bytes: Box::new([]),
+            next: OnceLock::new(),
+            starved: starved.clone(),
+        });
+        let lexer = lexer(AsyncChars {
+            chunk: tail.clone(),
+            idx: 0,
+        });
+        AsyncLexer {
+            reader,
+            lexer,
+            tail,
+            starved,
+            chunk_size,
+        }
+    }
+
+    /// The lexer, e.g. for updating its user state or parser feedback between tokens
+    pub fn lexer(&mut self) -> &mut L {
+        &mut self.lexer
+    }
+
+    /// Lex the next token, reading more input as needed. Returns the same values as
+    /// [`TokenSource::next_spanned`], or the error of the read.
+    ///
+    /// The method is cancel safe: when the returned future is dropped before completing, no input
+    /// is lost and the lexer state is as before the call.
+    #[allow(clippy::type_complexity)]
+    pub async fn next_token(
+        &mut self,
+    ) -> io::Result<Option<Result<(Loc, L::Token, Loc), LexerError<L::Error>>>> {
+        loop {
+            let lexer = self.lexer.clone();
+            self.starved.store(false, Ordering::Relaxed);
+            let token = self.lexer.next_spanned();
+            if !self.starved.load(Ordering::Relaxed) {
+                return Ok(token);
+            }
+            self.lexer = lexer;
+            self.read_chunk().await?;
+        }
+    }
+
+    async fn read_chunk(&mut self) -> io::Result<()> {
+        let mut bytes = vec![0; self.chunk_size];
+        let len = loop {
+            match self.reader.read(&mut bytes).await {
+                Ok(len) => break len,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        };
+
+        let next = if len == 0 {
+            None
+        } else {
+            bytes.truncate(len);
+            Some(Arc::new(Chunk {
+                bytes: bytes.into_boxed_slice(),
+                next: OnceLock::new(),
+                starved: self.starved.clone(),
+            }))
+        };
+
+        // Only the `AsyncLexer` links chunks, and it doesn't read after the end of the input
+        let _ = self.tail.next.set(next.clone());
+        if let Some(next) = next {
+            self.tail = next;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+//! Conversion of `DfaTable`s to `regex-automata` DFAs
+
+use crate::table::{DfaTable, InvalidDfaTable};
+
+use regex_automata::dfa::{dense, sparse, StartKind};
+use regex_automata::nfa::thompson::{self, Transition, NFA};
+use regex_automata::util::look::Look;
+use regex_automata::util::primitives::StateID;
+use regex_automata::MatchKind;
+use regex_syntax::utf8::Utf8Sequences;
+
+/// Error returned by `DfaTable` to `regex-automata` conversion functions.
+#[derive(Debug)]
+pub enum RegexAutomataError {
+    /// The table is not valid, see `InvalidDfaTable`
+    InvalidTable(InvalidDfaTable),
+
+    /// `regex-automata` failed to build the NFA of the table, e.g. because of a size limit
+    Nfa(Box<thompson::BuildError>),
+
+    /// `regex-automata` failed to build the DFA from the NFA
+    Dfa(Box<dense::BuildError>),
+}
+
+impl std::fmt::Display for RegexAutomataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegexAutomataError::InvalidTable(err) => err.fmt(f),
+            RegexAutomataError::Nfa(err) => write!(f, "unable to build NFA: {}", err),
+            RegexAutomataError::Dfa(err) => write!(f, "unable to build DFA: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RegexAutomataError {}
+
+impl From<InvalidDfaTable> for RegexAutomataError {
+    fn from(err: InvalidDfaTable) -> Self {
+        RegexAutomataError::InvalidTable(err)
+    }
+}
+
+impl From<thompson::BuildError> for RegexAutomataError {
+    fn from(err: thompson::BuildError) -> Self {
+        RegexAutomataError::Nfa(Box::new(err))
+    }
+}
+
+impl From<dense::BuildError> for RegexAutomataError {
+    fn from(err: dense::BuildError) -> Self {
+        RegexAutomataError::Dfa(Box::new(err))
+    }
+}
+
+impl DfaTable {
+    /// Convert the table to a `regex-automata` Thompson NFA over UTF-8 bytes. There is one pattern
+    /// for each rule in `tokens`, pattern IDs are indices in `tokens`.
+    ///
+    /// The NFA doesn't have capture groups, and only supports anchored searches.
+    pub fn to_regex_automata_nfa(&self) -> Result<NFA, RegexAutomataError> {
+        self.validate()?;
+
+        let mut builder = thompson::Builder::new();
+        builder.set_utf8(true);
+
+        // One union state for each table state, transitions are added as alternatives below
+        let mut nodes: Vec<StateID> = Vec::with_capacity(self.states.len());
+        for _ in &self.states {
+            nodes.push(builder.add_union(vec![])?);
+        }
+
+        let mut matches: Vec<StateID> = Vec::with_capacity(self.tokens.len());
+        for _ in &self.tokens {
+            builder.start_pattern()?;
+            matches.push(builder.add_match()?);
+            builder.finish_pattern(nodes[0])?;
+        }
+
+        for (state, node) in self.states.iter().zip(nodes.iter()) {
+            // End of input transition first, so that at the end of the input the rule accepted
+            // after the end of input transition is preferred, as in `TableLexer`
+            if let Some(next) = state.end_of_input {
+                let look = builder.add_look(nodes[next], Look::End)?;
+                builder.patch(*node, look)?;
+            }
+
+            let mut next_char: u32 = 0;
+
+            for (start, end, next) in &state.transitions {
+                if let Some(any) = state.any {
+                    if *start as u32 > next_char {
+                        add_char_range(
+                            &mut builder,
+                            *node,
+                            next_char,
+                            *start as u32 - 1,
+                            nodes[any],
+                        )?;
+                    }
+                }
+                add_char_range(
+                    &mut builder,
+                    *node,
+                    *start as u32,
+                    *end as u32,
+                    nodes[*next],
+                )?;
+                next_char = *end as u32 + 1;
+            }
+
+            if let Some(any) = state.any {
+                add_char_range(&mut builder, *node, next_char, char::MAX as u32, nodes[any])?;
+            }
+
+            if let Some(rule) = state.accepting {
+                builder.patch(*node, matches[rule])?;
+            }
+        }
+
+        Ok(builder.build(nodes[0], nodes[0])?)
+    }
+
+    /// Convert the table to a `regex-automata` dense DFA. There is one pattern for each rule in
+    /// `tokens`, pattern IDs are indices in `tokens`.
+    ///
+    /// The DFA only supports anchored searches. Searches report the longest match, as lexgen
+    /// lexers do. Use `DFA::to_bytes_little_endian` and similar to serialize the DFA.
+    pub fn to_dense_dfa(&self) -> Result<dense::DFA<Vec<u32>>, RegexAutomataError> {
+        let nfa = self.to_regex_automata_nfa()?;
+        Ok(dense::Builder::new()
+            .configure(
+                dense::Config::new()
+                    .match_kind(MatchKind::All)
+                    .start_kind(StartKind::Anchored),
+            )
+            .build_from_nfa(&nfa)?)
+    }
+
+    /// Convert the table to a `regex-automata` sparse DFA. See `to_dense_dfa` for details.
+    pub fn to_sparse_dfa(&self) -> Result<sparse::DFA<Vec<u8>>, RegexAutomataError> {
+        Ok(self.to_dense_dfa()?.to_sparse()?)
+    }
+}
+
+/// Add transitions from `node` to `next` for characters in range `start..=end` (code points,
+/// inclusive). Surrogates are skipped.
+fn add_char_range(
+    builder: &mut thompson::Builder,
+    node: StateID,
+    start: u32,
+    end: u32,
+    next: StateID,
+) -> Result<(), RegexAutomataError> {
+    let start = match char::from_u32(start) {
+        Some(start) => start,
+        None if start <= 0xDFFF => '\u{E000}',
+        None => return Ok(()),
+    };
+
+    let end = char::from_u32(end).unwrap_or('\u{D7FF}');
+
+    if start > end {
+        // Range only has surrogates
+        return Ok(());
+    }
+
+    for seq in Utf8Sequences::new(start, end) {
+        let mut seq_node = next;
+        for range in seq.as_slice().iter().rev() {
+            seq_node = builder.add_range(Transition {
+                start: range.start,
+                end: range.end,
+                next: seq_node,
+            })?;
+        }
+        builder.patch(node, seq_node)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+//! Splitting matches of rules with captures, `(<regex> as <name>)`, into the captured parts
+
+/// A state of the DFA of a part of a rule with captures. Generated lexers split the match of the
+/// rule into parts with `split_match`, and bind the captured parts to the capture names before
+/// running the semantic action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureState {
+    /// Sorted, non-overlapping character ranges (inclusive) and the states they transition to
+    pub transitions: &'static [(char, char, usize)],
+
+    /// Transition for characters not in `transitions`
+    pub any: Option<usize>,
+
+    /// Transition at the end of the match
+    pub end_of_input: Option<usize>,
+
+    /// Whether the part can end in this state
+    pub accepting: bool,
+}
+
+/// Split `match_` into consecutive parts matched by the DFAs in `parts`. Lexing starts in state 0
+/// of each DFA. On success the byte index of the start of each part is written to `bounds`,
+/// followed by the length of `match_`, so `BOUNDS` should be one more than the number of parts.
+/// `STATES` should be the total number of states in `parts`.
+///
+/// When the match can be split in multiple ways, earlier parts match as much of the input as
+/// possible. Returns `false` when the parts don't match `match_`.
+///
+/// The DFAs are run in parallel in one pass over `match_`, tracking the states of all possible
+/// splits. Splits that reach the same state of the same part at the same position continue the
+/// same way, so only the one with the longest earlier parts is kept, which is at most one split per
+/// state.
+pub fn split_match<const STATES: usize, const BOUNDS: usize>(
+    match_: &str,
+    parts: &[&[CaptureState]],
+    bounds: &mut [usize; BOUNDS],
+) -> bool {
+    assert_eq!(parts.len() + 1, BOUNDS);
+
+    // Index of the first state of each part in `STATES`-sized arrays
+    let mut offsets = [0usize; BOUNDS];
+    for (part_idx, part) in parts.iter().enumerate() {
+        offsets[part_idx + 1] = offsets[part_idx] + part.len();
+    }
+    assert_eq!(offsets[parts.len()], STATES);
+
+    let Some(last_part) = parts.len().checked_sub(1) else {
+        return match_.is_empty();
+    };
+
+    let empty = Split {
+        part: 0,
+        state: 0,
+        bounds: [0; BOUNDS],
+    };
+
+    let mut splits = Splits {
+        parts,
+        offsets: &offsets,
+        splits: [empty; STATES],
+        len: 0,
+        seen: [usize::MAX; STATES],
+    };
+
+    let mut next_splits = Splits {
+        parts,
+        offsets: &offsets,
+        splits: [empty; STATES],
+        len: 0,
+        seen: [usize::MAX; STATES],
+    };
+
+    splits.add(empty, 0, match_.is_empty());
+
+    for (char_idx, char) in match_.char_indices() {
+        let end = char_idx + char.len_utf8();
+        next_splits.len = 0;
+        for split in &splits.splits[..splits.len] {
+            if let Some(next) = next_state(&parts[split.part][split.state], char) {
+                next_splits.add(
+                    Split {
+                        state: next,
+                        ..*split
+                    },
+                    end,
+                    end == match_.len(),
+                );
+            }
+        }
+        std::mem::swap(&mut splits, &mut next_splits);
+        if splits.len == 0 {
+            return false;
+        }
+    }
+
+    // Splits are sorted by the lengths of their parts, longest first
+    match splits.splits[..splits.len]
+        .iter()
+        .find(|split| split.part == last_part && accepts_at_end(parts[last_part], split.state))
+    {
+        Some(split) => {
+            *bounds = split.bounds;
+            bounds[parts.len()] = match_.len();
+            true
+        }
+        None => false,
+    }
+}
+
+/// A possible split of a prefix of the match: current part and state in the part's DFA, and the
+/// start of each part up to the current one
+#[derive(Clone, Copy)]
+struct Split<const BOUNDS: usize> {
+    part: usize,
+    state: usize,
+    bounds: [usize; BOUNDS],
+}
+
+/// Splits at a position in the match, in the order of the lengths of their parts (longest first)
+struct Splits<'a, const STATES: usize, const BOUNDS: usize> {
+    parts: &'a [&'a [CaptureState]],
+    offsets: &'a [usize; BOUNDS],
+    splits: [Split<BOUNDS>; STATES],
+    len: usize,
+
+    /// Position of the last split added for each state, to skip splits with shorter parts that
+    /// reach the same state
+    seen: [usize; STATES],
+}
+
+impl<const STATES: usize, const BOUNDS: usize> Splits<'_, STATES, BOUNDS> {
+    /// Add `split` at byte index `pos`, followed by the splits that end the current part at `pos`
+    /// when the part can end there
+    fn add(&mut self, mut split: Split<BOUNDS>, pos: usize, at_end: bool) {
+        loop {
+            let state_idx = self.offsets[split.part] + split.state;
+            if self.seen[state_idx] == pos {
+                // Added before with longer parts, including the splits that follow
+                return;
+            }
+            self.seen[state_idx] = pos;
+            self.splits[self.len] = split;
+            self.len += 1;
+
+            let part = self.parts[split.part];
+            let accepting = if at_end {
+                accepts_at_end(part, split.state)
+            } else {
+                part[split.state].accepting
+            };
+
+            if !accepting || split.part + 1 == self.parts.len() {
+                return;
+            }
+
+            split.part += 1;
+            split.state = 0;
+            split.bounds[split.part] = pos;
+        }
+    }
+}
+
+/// Whether the part can end in the state at the end of the match. `$` can only match at the end of
+/// the match.
+fn accepts_at_end(part: &[CaptureState], state: usize) -> bool {
+    part[state].accepting
+        || part[state]
+            .end_of_input
+            .is_some_and(|next| part[next].accepting)
+}
+
+fn next_state(state: &CaptureState, char: char) -> Option<usize> {
+    crate::binary_search_transitions(char, state.transitions).or(state.any)
+}
@@ -0,0 +1,273 @@
+//! Character cursor for external scanners and custom matchers, for input that can't be described
+//! with regexes
+
+use crate::{advance_loc, Input, Lexer, Loc, SemanticActionResult};
+
+use std::collections::VecDeque;
+use std::iter::Peekable;
+
+/// Cursor for consuming characters of the input in external scanners: functions called by
+/// semantic actions with `lexer.scan(scanner)` that lex a part of the input by hand, such as
+/// heredocs with delimiters given in the input, or islands of template languages.
+///
+/// Consumed characters are added to the current match. After the semantic action the lexer
+/// continues after the consumed characters.
+pub trait Cursor<'input> {
+    /// Next character, without consuming it
+    fn peek(&mut self) -> Option<char>;
+
+    /// Consume the next character. Returns `None` at the end of the input, and for invalid UTF-8
+    /// in lexers created with `new_from_bytes`.
+    fn next(&mut self) -> Option<char>;
+
+    /// The current match: the input matched by the rule, followed by the consumed characters.
+    /// Panics in the same cases as `match_` in semantic actions.
+    fn match_(&self) -> &'input str;
+
+    /// Start and end locations of the current match
+    fn match_loc(&self) -> (Loc, Loc);
+
+    /// Consume characters until the end of the first occurrence of `end`. Returns `false` when
+    /// the input ends before `end`, after consuming the rest of the input.
+    ///
+    /// Useful for literals where the closing delimiter depends on the opening one, like Rust's
+    /// `r#"..."#` and Lua's `[==[...]==]`, which can't be described with regexes.
+    fn consume_until(&mut self, end: &str) -> bool {
+        let end: Vec<char> = end.chars().collect();
+        if end.is_empty() {
+            return true;
+        }
+
+        // Last `end.len()` consumed characters
+        let mut window: VecDeque<char> = VecDeque::with_capacity(end.len());
+
+        while let Some(char) = self.next() {
+            if window.len() == end.len() {
+                window.pop_front();
+            }
+            window.push_back(char);
+            if window.iter().eq(end.iter()) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Consume the body of a heredoc: the rest of the current line, then lines until a line that
+    /// is `terminator`, which is consumed without its line terminator. With `indented`, leading
+    /// spaces and tabs of the terminator line are ignored, as in `<<-EOF` in shells and `<<~EOF` in
+    /// Ruby. Returns `false` when the input ends before the terminator line, after consuming the
+    /// rest of the input.
+    ///
+    /// Useful for heredocs in shell, Ruby, and Perl, where the terminator is given in the input
+    /// after `<<`.
+    fn consume_heredoc(&mut self, terminator: &str, indented: bool) -> bool {
+        // Rest of the line with the heredoc operator
+        loop {
+            match self.next() {
+                Some('\n') => break,
+                Some(_) => {}
+                None => return false,
+            }
+        }
+
+        let mut line = String::new();
+        loop {
+            line.clear();
+            while let Some(char) = self.peek() {
+                if char == '\n' || char == '\r' {
+                    break;
+                }
+                self.next();
+                line.push(char);
+            }
+
+            let line = if indented {
+                line.trim_start_matches([' ', '\t'])
+            } else {
+                &line
+            };
+            if line == terminator {
+                return true;
+            }
+
+            if self.next().is_none() {
+                return false;
+            }
+        }
+    }
+}
+
+impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W, const O: u8> Cursor<'input>
+    for Lexer<'input, I, T, S, E, W, O>
+{
+    fn peek(&mut self) -> Option<char> {
+        Lexer::peek(self)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        Lexer::next(self)
+    }
+
+    fn match_(&self) -> &'input str {
+        Lexer::match_(self)
+    }
+
+    fn match_loc(&self) -> (Loc, Loc) {
+        Lexer::match_loc(self)
+    }
+}
+
+/// Result of a custom matcher: a function used in place of a regex in `fn <matcher>` rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResult {
+    /// The characters consumed from the cursor are a match of the rule
+    Match,
+
+    /// The rule doesn't match at the current location. Consumed characters are put back.
+    NoMatch,
+}
+
+// Cursor passed to custom matchers. Reads a copy of the lexer's iterator, so the lexer is not
+// affected when the matcher doesn't match, or another rule has a longer match.
+struct MatcherCursor<'input, I: Iterator<Item = char>, const O: u8> {
+    input: Input<'input>,
+    iter: Peekable<I>,
+    start: Loc,
+    end: Loc,
+}
+
+impl<'input, I: Iterator<Item = char>, const O: u8> Cursor<'input> for MatcherCursor<'input, I, O> {
+    fn peek(&mut self) -> Option<char> {
+        self.iter.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let char = self.iter.next()?;
+        let len = self.input.char_len(self.end.byte_idx, char);
+        advance_loc::<O>(&mut self.end, len, char, || self.iter.peek().copied());
+        Some(char)
+    }
+
+    fn match_(&self) -> &'input str {
+        self.input.str(self.start, self.end)
+    }
+
+    fn match_loc(&self) -> (Loc, Loc) {
+        (self.start, self.end)
+    }
+}
+
+impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W, const O: u8>
+    Lexer<'input, I, T, S, E, W, O>
+{
+    // Whether custom matchers should be run in the current initial state. Used in generated code
+    // for initial states of rule sets with `fn <matcher>` rules.
+    pub fn start_custom_matchers(&mut self) -> bool {
+        std::mem::replace(&mut self.run_custom_matchers, false)
+    }
+
+    // Run a custom matcher at the current location. Its match is recorded when it's longer than
+    // the matches of the custom matchers run before it, so earlier rules win ties.
+    pub fn run_custom_matcher(
+        &mut self,
+        matcher: fn(&mut dyn Cursor<'input>, &mut S) -> MatchResult,
+        semantic_action_fn: for<'lexer> fn(&'lexer mut W) -> SemanticActionResult<Result<T, E>>,
+    ) {
+        let mut cursor = MatcherCursor::<_, O> {
+            input: self.input,
+            iter: self.__iter.clone(),
+            start: self.current_match_start,
+            end: self.current_match_end,
+        };
+
+        if matcher(&mut cursor, &mut self.user_state) == MatchResult::NoMatch {
+            return;
+        }
+
+        let longest = match &self.custom_match {
+            Some((end, _, _)) => *end,
+            None => self.current_match_end,
+        };
+
+        if cursor.end.byte_idx > longest.byte_idx {
+            self.custom_match = Some((cursor.end, cursor.iter, semantic_action_fn));
+        }
+    }
+
+    // Called before running a semantic action. When a custom matcher matched more of the input
+    // than the current match, or when `fallback` is set because the regex rules failed to match,
+    // continues after the custom match and returns its semantic action. Regex rules win ties.
+    pub fn take_custom_match(
+        &mut self,
+        fallback: bool,
+    ) -> Option<for<'lexer> fn(&'lexer mut W) -> SemanticActionResult<Result<T, E>>> {
+        self.run_custom_matchers = true;
+
+        let (end, iter, semantic_action_fn) = self.custom_match.take()?;
+
+        if !fallback && end.byte_idx <= self.current_match_end.byte_idx {
+            return None;
+        }
+
+        self.__done = false;
+        self.current_match_end = end;
+        self.__iter = iter;
+        self.iter_loc = end;
+        self.last_match = None;
+        Some(semantic_action_fn)
+    }
+}
+
+/// A location in an input owned by the caller, for lexing one token at a time with the generated
+/// `next_token` function instead of a lexer that owns its position in the input. Embedders with
+/// their own driver loop (REPLs, incremental lexers) can implement this for their buffers.
+pub trait CharCursor<'input> {
+    /// The input. Locations are in this string.
+    fn input(&self) -> &'input str;
+
+    /// Location of the next character to lex
+    fn loc(&self) -> Loc;
+
+    /// Move the cursor to `loc`, after a token lexed by `next_token`
+    fn set_loc(&mut self, loc: Loc);
+}
+
+/// A `CharCursor` for a string
+#[derive(Debug, Clone, Copy)]
+pub struct StrCursor<'input> {
+    input: &'input str,
+    loc: Loc,
+}
+
+impl<'input> StrCursor<'input> {
+    /// A cursor at the start of `input`
+    pub fn new(input: &'input str) -> Self {
+        Self::new_at(input, Loc::ZERO)
+    }
+
+    /// A cursor at `loc`, which should be a location in `input`
+    pub fn new_at(input: &'input str, loc: Loc) -> Self {
+        StrCursor { input, loc }
+    }
+
+    /// Whether the cursor is at the end of the input
+    pub fn is_empty(&self) -> bool {
+        self.loc.byte_idx == self.input.len()
+    }
+}
+
+impl<'input> CharCursor<'input> for StrCursor<'input> {
+    fn input(&self) -> &'input str {
+        self.input
+    }
+
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+
+    fn set_loc(&mut self, loc: Loc) {
+        self.loc = loc;
+    }
+}
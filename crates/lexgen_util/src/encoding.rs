@@ -0,0 +1,87 @@
+//! Single-byte encodings for lexing legacy byte input
+
+/// A single-byte encoding, for lexers created with `new_from_single_byte`. Each byte in the input
+/// is mapped to a character before running the lexer.
+#[derive(Debug, Clone, Copy)]
+pub enum SingleByteEncoding {
+    /// ISO-8859-1 (Latin-1): byte values are the code points
+    Latin1,
+
+    /// EBCDIC code page 037 (US/Canada)
+    Cp037,
+
+    /// Characters of the byte values 0 to 255, for other code pages
+    Custom(&'static [char; 256]),
+}
+
+impl SingleByteEncoding {
+    pub fn decode(&self, byte: u8) -> char {
+        match self {
+            SingleByteEncoding::Latin1 => char::from(byte),
+            SingleByteEncoding::Cp037 => CP037[usize::from(byte)],
+            SingleByteEncoding::Custom(table) => table[usize::from(byte)],
+        }
+    }
+}
+
+/// Iterator over characters of single-byte encoded bytes, used in lexers created with
+/// `new_from_single_byte`.
+#[derive(Debug, Clone)]
+pub struct SingleByteChars<'input> {
+    bytes: std::slice::Iter<'input, u8>,
+    encoding: SingleByteEncoding,
+}
+
+impl<'input> SingleByteChars<'input> {
+    pub fn new(bytes: &'input [u8], encoding: SingleByteEncoding) -> Self {
+        SingleByteChars {
+            bytes: bytes.iter(),
+            encoding,
+        }
+    }
+}
+
+impl<'input> Iterator for SingleByteChars<'input> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.bytes.next().map(|byte| self.encoding.decode(*byte))
+    }
+}
+
+// Generated from Python's `cp037` codec. Newline is 0x25 (U+000A), 0x15 is NEL (U+0085).
+#[rustfmt::skip]
+static CP037: [char; 256] = [
+    '\u{0}', '\u{1}', '\u{2}', '\u{3}', '\u{9c}', '\u{9}', '\u{86}', '\u{7f}',
+    '\u{97}', '\u{8d}', '\u{8e}', '\u{b}', '\u{c}', '\u{d}', '\u{e}', '\u{f}',
+    '\u{10}', '\u{11}', '\u{12}', '\u{13}', '\u{9d}', '\u{85}', '\u{8}', '\u{87}',
+    '\u{18}', '\u{19}', '\u{92}', '\u{8f}', '\u{1c}', '\u{1d}', '\u{1e}', '\u{1f}',
+    '\u{80}', '\u{81}', '\u{82}', '\u{83}', '\u{84}', '\u{a}', '\u{17}', '\u{1b}',
+    '\u{88}', '\u{89}', '\u{8a}', '\u{8b}', '\u{8c}', '\u{5}', '\u{6}', '\u{7}',
+    '\u{90}', '\u{91}', '\u{16}', '\u{93}', '\u{94}', '\u{95}', '\u{96}', '\u{4}',
+    '\u{98}', '\u{99}', '\u{9a}', '\u{9b}', '\u{14}', '\u{15}', '\u{9e}', '\u{1a}',
+    '\u{20}', '\u{a0}', '\u{e2}', '\u{e4}', '\u{e0}', '\u{e1}', '\u{e3}', '\u{e5}',
+    '\u{e7}', '\u{f1}', '\u{a2}', '\u{2e}', '\u{3c}', '\u{28}', '\u{2b}', '\u{7c}',
+    '\u{26}', '\u{e9}', '\u{ea}', '\u{eb}', '\u{e8}', '\u{ed}', '\u{ee}', '\u{ef}',
+    '\u{ec}', '\u{df}', '\u{21}', '\u{24}', '\u{2a}', '\u{29}', '\u{3b}', '\u{ac}',
+    '\u{2d}', '\u{2f}', '\u{c2}', '\u{c4}', '\u{c0}', '\u{c1}', '\u{c3}', '\u{c5}',
+    '\u{c7}', '\u{d1}', '\u{a6}', '\u{2c}', '\u{25}', '\u{5f}', '\u{3e}', '\u{3f}',
+    '\u{f8}', '\u{c9}', '\u{ca}', '\u{cb}', '\u{c8}', '\u{cd}', '\u{ce}', '\u{cf}',
+    '\u{cc}', '\u{60}', '\u{3a}', '\u{23}', '\u{40}', '\u{27}', '\u{3d}', '\u{22}',
+    '\u{d8}', '\u{61}', '\u{62}', '\u{63}', '\u{64}', '\u{65}', '\u{66}', '\u{67}',
+    '\u{68}', '\u{69}', '\u{ab}', '\u{bb}', '\u{f0}', '\u{fd}', '\u{fe}', '\u{b1}',
+    '\u{b0}', '\u{6a}', '\u{6b}', '\u{6c}', '\u{6d}', '\u{6e}', '\u{6f}', '\u{70}',
+    '\u{71}', '\u{72}', '\u{aa}', '\u{ba}', '\u{e6}', '\u{b8}', '\u{c6}', '\u{a4}',
+    '\u{b5}', '\u{7e}', '\u{73}', '\u{74}', '\u{75}', '\u{76}', '\u{77}', '\u{78}',
+    '\u{79}', '\u{7a}', '\u{a1}', '\u{bf}', '\u{d0}', '\u{dd}', '\u{de}', '\u{ae}',
+    '\u{5e}', '\u{a3}', '\u{a5}', '\u{b7}', '\u{a9}', '\u{a7}', '\u{b6}', '\u{bc}',
+    '\u{bd}', '\u{be}', '\u{5b}', '\u{5d}', '\u{af}', '\u{a8}', '\u{b4}', '\u{d7}',
+    '\u{7b}', '\u{41}', '\u{42}', '\u{43}', '\u{44}', '\u{45}', '\u{46}', '\u{47}',
+    '\u{48}', '\u{49}', '\u{ad}', '\u{f4}', '\u{f6}', '\u{f2}', '\u{f3}', '\u{f5}',
+    '\u{7d}', '\u{4a}', '\u{4b}', '\u{4c}', '\u{4d}', '\u{4e}', '\u{4f}', '\u{50}',
+    '\u{51}', '\u{52}', '\u{b9}', '\u{fb}', '\u{fc}', '\u{f9}', '\u{fa}', '\u{ff}',
+    '\u{5c}', '\u{f7}', '\u{53}', '\u{54}', '\u{55}', '\u{56}', '\u{57}', '\u{58}',
+    '\u{59}', '\u{5a}', '\u{b2}', '\u{d4}', '\u{d6}', '\u{d2}', '\u{d3}', '\u{d5}',
+    '\u{30}', '\u{31}', '\u{32}', '\u{33}', '\u{34}', '\u{35}', '\u{36}', '\u{37}',
+    '\u{38}', '\u{39}', '\u{b3}', '\u{db}', '\u{dc}', '\u{d9}', '\u{da}', '\u{9f}',
+];
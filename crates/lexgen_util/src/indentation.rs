@@ -0,0 +1,261 @@
+//! Indentation-sensitive lexing, with the `indentation` option: `NEWLINE`, `INDENT`, and `DEDENT`
+//! tokens generated from indentation of lines, as in Python and Haskell.
+
+use crate::{Lexer, LexerError, LexerErrorKind, Loc};
+
+use std::collections::VecDeque;
+
+/// Characters allowed in indentation, selected with the `whitespace` field of the `indentation`
+/// option.
+///
+/// **Do not use**: this is passed to the `Lexer` by lexgen-generated code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentationWhitespace {
+    /// Only spaces, tabs are errors
+    Spaces,
+
+    /// Only tabs, spaces are errors
+    Tabs,
+
+    /// Spaces and tabs. A tab advances the indentation to the next multiple of the tab width.
+    TabWidth(u32),
+}
+
+// Indentation state of a lexer
+#[derive(Clone)]
+pub(crate) struct Indentation<T, E> {
+    whitespace: IndentationWhitespace,
+
+    newline: fn() -> T,
+    indent: fn() -> T,
+    dedent: fn() -> T,
+
+    // Byte index of the end of the scanned input. Characters are scanned again after
+    // backtracking, we skip them the second time.
+    scanned_to: usize,
+
+    // Line being scanned
+    line: u32,
+
+    // Width of the indentation of `line` so far
+    width: u32,
+
+    // Location of the first whitespace character in the indentation of `line` that is not allowed
+    invalid_char: Option<Loc>,
+
+    // Whether we've seen the first non-whitespace character of `line`
+    line_done: bool,
+
+    // Indentation of scanned lines with non-whitespace characters, which are not yet handled by
+    // `push`. Lexers may scan lines after the end of a match before returning a token, so there
+    // may be more than one.
+    lines: VecDeque<(u32, Result<u32, Loc>)>,
+
+    // Indentation levels of enclosing blocks, starting with 0
+    stack: Vec<u32>,
+
+    // Line and end location of the last token, `None` before the first token
+    last_token: Option<(u32, Loc)>,
+
+    // Whether the end of the input is handled
+    ended: bool,
+
+    // Tokens and errors to return
+    pending: VecDeque<Result<(Loc, T, Loc), LexerError<E>>>,
+}
+
+impl<T, E> Indentation<T, E> {
+    fn new(
+        whitespace: IndentationWhitespace,
+        newline: fn() -> T,
+        indent: fn() -> T,
+        dedent: fn() -> T,
+    ) -> Self {
+        Indentation {
+            whitespace,
+            newline,
+            indent,
+            dedent,
+            scanned_to: 0,
+            line: 0,
+            width: 0,
+            invalid_char: None,
+            line_done: false,
+            lines: VecDeque::new(),
+            stack: vec![0],
+            last_token: None,
+            ended: false,
+            pending: VecDeque::new(),
+        }
+    }
+
+    // Scan a character read by the lexer. `loc` is the location of the character, `end` is the
+    // byte index after the character.
+    pub(crate) fn scan(&mut self, loc: Loc, char: char, line_terminator: bool, end: usize) {
+        if loc.byte_idx < self.scanned_to {
+            return;
+        }
+        self.scanned_to = end;
+
+        if loc.line != self.line {
+            self.line = loc.line;
+            self.width = 0;
+            self.invalid_char = None;
+            self.line_done = false;
+        }
+
+        if self.line_done || line_terminator {
+            return;
+        }
+
+        match (char, self.whitespace) {
+            (' ', IndentationWhitespace::Tabs) | ('\t', IndentationWhitespace::Spaces) => {
+                self.invalid_char.get_or_insert(loc);
+                self.width += 1;
+            }
+            (' ', _) | ('\t', IndentationWhitespace::Tabs) => self.width += 1,
+            ('\t', IndentationWhitespace::TabWidth(tab_width)) => {
+                self.width += tab_width - self.width % tab_width;
+            }
+            // `\r` of `\r\n` when only `\n` is a line terminator
+            ('\r', _) => {}
+            _ => {
+                self.line_done = true;
+                let indentation = match self.invalid_char {
+                    Some(loc) => Err(loc),
+                    None => Ok(self.width),
+                };
+                self.lines.push_back((self.line, indentation));
+            }
+        }
+    }
+
+    // Indentation of the given line, or `None` if the line starts with a token that starts with
+    // whitespace
+    fn line_indentation(&mut self, line: u32) -> Option<Result<u32, Loc>> {
+        while let Some((scanned_line, indentation)) = self.lines.front().copied() {
+            if scanned_line < line {
+                self.lines.pop_front();
+            } else if scanned_line == line {
+                return Some(indentation);
+            } else {
+                break;
+            }
+        }
+        None
+    }
+
+    // Handle a token or error returned by the lexer, or the end of the input
+    fn push(&mut self, item: Option<Result<(Loc, T, Loc), LexerError<E>>>, input_end: Loc) {
+        let (start, token, end) = match item {
+            Some(Ok(token)) => token,
+            Some(Err(err)) => {
+                self.pending.push_back(Err(err));
+                return;
+            }
+            None => {
+                if !self.ended {
+                    self.ended = true;
+                    if self.last_token.is_some() {
+                        self.pending
+                            .push_back(Ok((input_end, (self.newline)(), input_end)));
+                    }
+                    while self.stack.len() > 1 {
+                        self.stack.pop();
+                        self.pending
+                            .push_back(Ok((input_end, (self.dedent)(), input_end)));
+                    }
+                }
+                return;
+            }
+        };
+
+        let new_line = match self.last_token {
+            None => true,
+            Some((line, _)) => start.line != line,
+        };
+
+        if new_line {
+            match self.line_indentation(start.line) {
+                None => {}
+                Some(Err(loc)) => {
+                    self.pending.push_back(Err(LexerError {
+                        location: loc,
+                        kind: LexerErrorKind::InvalidIndentation,
+                    }));
+                }
+                Some(Ok(width)) => {
+                    if let Some((_, last_end)) = self.last_token {
+                        self.pending
+                            .push_back(Ok((last_end, (self.newline)(), last_end)));
+                    }
+
+                    let top = *self.stack.last().unwrap();
+                    if width > top {
+                        self.stack.push(width);
+                        self.pending.push_back(Ok((start, (self.indent)(), start)));
+                    } else {
+                        while width < *self.stack.last().unwrap() {
+                            self.stack.pop();
+                            self.pending.push_back(Ok((start, (self.dedent)(), start)));
+                        }
+                        if width != *self.stack.last().unwrap() {
+                            // The line continues the enclosing block. Its indentation is not
+                            // pushed as a new level, so that `indent` and `dedent` tokens stay
+                            // balanced.
+                            self.pending.push_back(Err(LexerError {
+                                location: start,
+                                kind: LexerErrorKind::InconsistentDedent,
+                            }));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.last_token = Some((end.line, end));
+        self.pending.push_back(Ok((start, token, end)));
+    }
+}
+
+impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W, const O: u8>
+    Lexer<'input, I, T, S, E, W, O>
+{
+    pub fn with_indentation(
+        mut self,
+        whitespace: IndentationWhitespace,
+        newline: fn() -> T,
+        indent: fn() -> T,
+        dedent: fn() -> T,
+    ) -> Self {
+        assert!(
+            O & crate::OPTION_INDENTATION != 0,
+            "Lexers with indentation need `OPTION_INDENTATION`"
+        );
+        self.indentation = Some(Box::new(Indentation::new(
+            whitespace, newline, indent, dedent,
+        )));
+        self
+    }
+
+    // Generate indentation tokens for the token, error, or end of input returned by the lexer, and
+    // return the next token to yield. Used in generated `next` methods with the `indentation`
+    // option.
+    pub fn indentation_next(
+        &mut self,
+        item: Option<Result<(Loc, T, Loc), LexerError<E>>>,
+    ) -> Option<Result<(Loc, T, Loc), LexerError<E>>> {
+        let input_end = self.current_match_end;
+        let indentation = self
+            .indentation
+            .as_mut()
+            .expect("Lexer doesn't have the `indentation` option");
+        indentation.push(item, input_end);
+        indentation.pending.pop_front()
+    }
+
+    // Tokens generated by the last `indentation_next` call that are not yet returned
+    pub fn indentation_pending(&mut self) -> Option<Result<(Loc, T, Loc), LexerError<E>>> {
+        self.indentation.as_mut()?.pending.pop_front()
+    }
+}
@@ -0,0 +1,18 @@
+//! String interning in semantic actions, with `type Interner = ...;`
+
+/// An interner for the `intern` method of lexers with `type Interner = ...;`, which maps strings
+/// to symbols. Implementations usually return the same symbol for equal strings, so identifiers can
+/// be compared and stored without allocating a `String` for each identifier token.
+pub trait Interner {
+    type Symbol;
+
+    fn intern(&mut self, string: &str) -> Self::Symbol;
+}
+
+impl<I: Interner + ?Sized> Interner for &mut I {
+    type Symbol = I::Symbol;
+
+    fn intern(&mut self, string: &str) -> I::Symbol {
+        (**self).intern(string)
+    }
+}
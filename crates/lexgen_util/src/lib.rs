@@ -1,10 +1,56 @@
 #![allow(clippy::should_implement_trait, clippy::type_complexity)]
 
+#[cfg(feature = "tokio")]
+mod async_read;
+#[cfg(feature = "regex-automata")]
+mod automata;
+mod captures;
+mod cursor;
+mod encoding;
+mod indentation;
+mod interner;
+#[cfg(feature = "memmap2")]
+mod mmap;
+mod progress;
+mod read;
+mod spanned;
+mod stats;
+mod syntax;
+mod table;
+mod token_source;
+mod unescape;
+
+#[cfg(feature = "tokio")]
+pub use async_read::{AsyncChars, AsyncLexer};
+#[cfg(feature = "regex-automata")]
+pub use automata::RegexAutomataError;
+pub use captures::{split_match, CaptureState};
+pub use cursor::{CharCursor, Cursor, MatchResult, StrCursor};
+pub use encoding::{SingleByteChars, SingleByteEncoding};
+pub use indentation::IndentationWhitespace;
+pub use interner::Interner;
+#[cfg(feature = "memmap2")]
+pub use mmap::MappedFile;
+pub use progress::ProgressInterval;
+pub use read::{ReadChars, ReadStatus, DEFAULT_CHUNK_SIZE};
+pub use spanned::{collect_spanned, Spanned};
+pub use stats::LexerStats;
+pub use syntax::SyntaxTokens;
+pub use table::{DfaTable, DfaTableState, InvalidDfaTable, TableLexer};
+pub use token_source::TokenSource;
+pub use unescape::{unescape, InvalidEscape, InvalidEscapeKind};
+
 use std::iter::Peekable;
 use std::str::Chars;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 use unicode_width::UnicodeWidthChar;
 
+/// An error raised by a lexer: the location of the error and what went wrong.
+///
+/// Errors are plain values, creating and returning them doesn't allocate. Messages are only
+/// formatted in the `Display` implementation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LexerError<E> {
     pub location: Loc,
@@ -18,6 +64,32 @@ pub enum LexerErrorKind<E> {
 
     /// Custom error, raised by a semantic action
     Custom(E),
+
+    /// Invalid UTF-8 in byte input, with `invalid_utf8 = error;`. The invalid bytes start at the
+    /// error location and end at `end` (exclusive).
+    InvalidUtf8 { end: Loc },
+
+    /// Whitespace character in indentation that is not allowed with the `whitespace` setting of
+    /// the `indentation` option, e.g. a tab with `whitespace: spaces`. The error location is the
+    /// character.
+    InvalidIndentation,
+
+    /// Indentation of a line is less than the indentation of the previous line, but doesn't match
+    /// the indentation of an enclosing block, with the `indentation` option. The error location is
+    /// the first token of the line. The line continues the enclosing block with less indentation.
+    InconsistentDedent,
+
+    /// The cancellation flag of the lexer was set, with `cancellation = true;`. The error location
+    /// is where the next token would start. The lexer yields `None` after this error.
+    Cancelled,
+}
+
+/// A token tagged with the rule set of the lexer when the token was matched. Yielded by lexers
+/// with `tag_rule_sets = true;`, with the rule set enum of the lexer (e.g. `LexerRule`) as `R`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tagged<R, T> {
+    pub rule_set: R,
+    pub token: T,
 }
 
 /// A location, used in errors
@@ -36,6 +108,105 @@ impl Loc {
     };
 }
 
+/// Prints `<line>:<column>`, starting from 1 as in editors and compiler messages
+impl std::fmt::Display for Loc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.col + 1)
+    }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for LexerError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            LexerErrorKind::InvalidToken => write!(f, "{}: invalid token", self.location),
+            LexerErrorKind::Custom(error) => write!(f, "{}: {}", self.location, error),
+            LexerErrorKind::InvalidUtf8 { .. } => write!(f, "{}: invalid UTF-8", self.location),
+            LexerErrorKind::InvalidIndentation => {
+                write!(f, "{}: invalid character in indentation", self.location)
+            }
+            LexerErrorKind::InconsistentDedent => write!(
+                f,
+                "{}: indentation doesn't match any enclosing block",
+                self.location
+            ),
+            LexerErrorKind::Cancelled => write!(f, "{}: lexing cancelled", self.location),
+        }
+    }
+}
+
+impl<E> LexerError<E> {
+    /// Byte range of the offending text in `input`, for error reporting libraries like
+    /// codespan-reporting and miette. `input` should be the input of the lexer that raised the
+    /// error.
+    ///
+    /// - For `InvalidToken` errors this is the character at the error location, where the invalid
+    ///   token starts. At the end of the input the range is empty.
+    /// - For `InvalidUtf8` errors this is the invalid bytes.
+    /// - For `InvalidIndentation` errors this is the invalid whitespace character.
+    /// - For `Custom`, `InconsistentDedent`, and `Cancelled` errors this is an empty range at the
+    ///   error location.
+    pub fn span(&self, input: &str) -> std::ops::Range<usize> {
+        let start = self.location.byte_idx;
+        match &self.kind {
+            LexerErrorKind::InvalidToken | LexerErrorKind::InvalidIndentation => {
+                let len = input
+                    .get(start..)
+                    .and_then(|rest| rest.chars().next())
+                    .map_or(0, char::len_utf8);
+                start..start + len
+            }
+            LexerErrorKind::InvalidUtf8 { end } => start..end.byte_idx,
+            LexerErrorKind::Custom(_)
+            | LexerErrorKind::InconsistentDedent
+            | LexerErrorKind::Cancelled => start..start,
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for LexerError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            LexerErrorKind::Custom(error) => Some(error),
+            LexerErrorKind::InvalidToken
+            | LexerErrorKind::InvalidUtf8 { .. }
+            | LexerErrorKind::InvalidIndentation
+            | LexerErrorKind::InconsistentDedent
+            | LexerErrorKind::Cancelled => None,
+        }
+    }
+}
+
+/// What to do with invalid UTF-8 in byte input, selected with the `invalid_utf8` option.
+///
+/// **Do not use**: this is passed to the `Lexer` by lexgen-generated code.
+pub enum InvalidUtf8<A> {
+    /// Raise a `LexerErrorKind::InvalidUtf8` error
+    Error,
+
+    /// Pass U+FFFD REPLACEMENT CHARACTER to the rules
+    Replace,
+
+    /// Run the semantic action of the `$$invalid_utf8` rule
+    Rule(A),
+}
+
+// Bits of the `OPTIONS` parameter of `Lexer`, set by lexgen-generated code for options that need
+// work for every character. They're checked at compile time, so lexers without the options don't
+// pay for them.
+
+/// **Do not use**
+// Byte input with invalid UTF-8, with the `invalid_utf8` option
+pub const OPTION_INVALID_UTF8: u8 = 1 << 0;
+
+/// **Do not use**
+// `\r`, `\r\n` (as one line terminator), NEL (U+0085), LS (U+2028), and PS (U+2029) end lines in
+// locations, in addition to `\n`, with `line_terminators = unicode;`
+pub const OPTION_UNICODE_LINE_TERMINATORS: u8 = 1 << 1;
+
+/// **Do not use**
+// Indentation tracking, with the `indentation` option
+pub const OPTION_INDENTATION: u8 = 1 << 2;
+
 /// **Do not use**
 // Possible outcomes of a semantic action
 pub enum SemanticActionResult<T> {
@@ -57,10 +228,259 @@ impl<T> SemanticActionResult<T> {
     }
 }
 
+/// **Do not use**
+// Whether the character is in one of the sorted, non-overlapping, inclusive ranges. Used in
+// generated code for range checks with `range_checks = binary_search;`.
+pub fn binary_search_char_ranges(c: char, table: &[(char, char)]) -> bool {
+    table
+        .binary_search_by(|(start, end)| match c.cmp(start) {
+            std::cmp::Ordering::Greater => {
+                if c <= *end {
+                    std::cmp::Ordering::Equal
+                } else {
+                    std::cmp::Ordering::Less
+                }
+            }
+            std::cmp::Ordering::Equal => std::cmp::Ordering::Equal,
+            std::cmp::Ordering::Less => std::cmp::Ordering::Greater,
+        })
+        .is_ok()
+}
+
+/// **Do not use**
+// Decode the UTF-8 encoded character at the given byte index. Returns the character and its
+// length in bytes. Used in `const fn` lexers, where `str::chars` is not available.
+pub const fn decode_utf8_char(bytes: &[u8], idx: usize) -> (char, usize) {
+    let b0 = bytes[idx] as u32;
+
+    let (code_point, len) = if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xE0 {
+        (((b0 & 0x1F) << 6) | (bytes[idx + 1] as u32 & 0x3F), 2)
+    } else if b0 < 0xF0 {
+        (
+            ((b0 & 0x0F) << 12)
+                | ((bytes[idx + 1] as u32 & 0x3F) << 6)
+                | (bytes[idx + 2] as u32 & 0x3F),
+            3,
+        )
+    } else {
+        (
+            ((b0 & 0x07) << 18)
+                | ((bytes[idx + 1] as u32 & 0x3F) << 12)
+                | ((bytes[idx + 2] as u32 & 0x3F) << 6)
+                | (bytes[idx + 3] as u32 & 0x3F),
+            4,
+        )
+    };
+
+    match char::from_u32(code_point) {
+        Some(char) => (char, len),
+        None => panic!("invalid UTF-8"),
+    }
+}
+
+// Decode the UTF-8 encoded character at the beginning of `bytes`. On success returns the character
+// and its length in bytes. Otherwise returns the length of the maximal invalid subsequence, which
+// is replaced with one U+FFFD, as recommended by the Unicode standard.
+fn decode_utf8(bytes: &[u8]) -> Result<(char, usize), usize> {
+    let prefix = &bytes[..bytes.len().min(4)];
+    let valid = match std::str::from_utf8(prefix) {
+        Ok(valid) => valid,
+        Err(err) if err.valid_up_to() != 0 => {
+            std::str::from_utf8(&prefix[..err.valid_up_to()]).unwrap()
+        }
+        // `error_len` is `None` when the input ends in the middle of a character
+        Err(err) => return Err(err.error_len().unwrap_or(prefix.len())),
+    };
+    let char = valid.chars().next().unwrap();
+    Ok((char, char.len_utf8()))
+}
+
+/// Iterator over characters of UTF-8 encoded bytes, used in lexers created with
+/// `new_from_bytes`.
+///
+/// Invalid UTF-8 is replaced with U+FFFD with `invalid_utf8 = replace;`. Otherwise the iterator
+/// skips invalid bytes and yields `None` for them, and the lexer handles the invalid bytes.
+#[derive(Debug, Clone)]
+pub struct Utf8Chars<'input> {
+    bytes: &'input [u8],
+    replace: bool,
+}
+
+impl<'input> Iterator for Utf8Chars<'input> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        let (char, len) = match decode_utf8(self.bytes) {
+            Ok((char, len)) => (Some(char), len),
+            Err(len) => (
+                if self.replace {
+                    Some(char::REPLACEMENT_CHARACTER)
+                } else {
+                    None
+                },
+                len,
+            ),
+        };
+        self.bytes = &self.bytes[len..];
+        char
+    }
+}
+
+/// **Do not use**
+// Binary search a character in sorted, non-overlapping, inclusive ranges. Used in `const fn`
+// lexers, where slice methods for binary search are not available.
+pub const fn char_in_ranges(char: char, ranges: &[(char, char)]) -> bool {
+    let mut low = 0;
+    let mut high = ranges.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let (start, end) = ranges[mid];
+        if char < start {
+            high = mid;
+        } else if char > end {
+            low = mid + 1;
+        } else {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// **Do not use**
+// Binary search a character in the sorted, non-overlapping, inclusive ranges of the transitions of
+// a DFA state. Returns the value of the range with the character. Used in generated code for
+// states with many transitions, including `const fn` lexers.
+pub const fn binary_search_transitions(
+    char: char,
+    transitions: &[(char, char, usize)],
+) -> Option<usize> {
+    let mut low = 0;
+    let mut high = transitions.len();
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let (start, end, value) = transitions[mid];
+        if char < start {
+            high = mid;
+        } else if char > end {
+            low = mid + 1;
+        } else {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+// Input of a lexer, used to get the current match and the byte lengths of characters
+#[derive(Clone, Copy)]
+enum Input<'input> {
+    // Input of `new` and `new_with_state`. Empty for lexers created with `new_from_iter`.
+    Str(&'input str),
+
+    // Input of `new_from_bytes`, which may have invalid UTF-8
+    Utf8(&'input [u8]),
+
+    // Input of `new_from_single_byte`, with one byte per character
+    SingleByte(&'input [u8]),
+}
+
+impl<'input> Input<'input> {
+    // Length in bytes of `char`, which starts at `byte_idx` in the input
+    fn char_len(&self, byte_idx: usize, char: char) -> usize {
+        match self {
+            Input::Str(_) => char.len_utf8(),
+            Input::Utf8(bytes) => utf8_char_len(bytes, byte_idx, char),
+            Input::SingleByte(_) => 1,
+        }
+    }
+
+    // Input between the given locations
+    fn str(&self, start: Loc, end: Loc) -> &'input str {
+        match self {
+            Input::Str(input) => &input[start.byte_idx..end.byte_idx],
+            Input::Utf8(_) => std::str::from_utf8(self.bytes(start, end))
+                .expect("Match is not valid UTF-8, use `match_bytes` to get the matched bytes"),
+            Input::SingleByte(_) => {
+                panic!("Match is not UTF-8 encoded, use `match_bytes` to get the matched bytes")
+            }
+        }
+    }
+
+    // Bytes of the input between the given locations
+    fn bytes(&self, start: Loc, end: Loc) -> &'input [u8] {
+        let bytes = match self {
+            Input::Str(input) => input.as_bytes(),
+            Input::Utf8(bytes) | Input::SingleByte(bytes) => bytes,
+        };
+        &bytes[start.byte_idx..end.byte_idx]
+    }
+}
+
+// Length in bytes of `char`, which starts at `byte_idx` in UTF-8 input. Invalid bytes replaced
+// with U+FFFD can be longer or shorter than U+FFFD, only those are decoded again.
+fn utf8_char_len(bytes: &[u8], byte_idx: usize, char: char) -> usize {
+    if char != char::REPLACEMENT_CHARACTER {
+        return char.len_utf8();
+    }
+    match decode_utf8(&bytes[byte_idx..]) {
+        Ok((_, len)) | Err(len) => len,
+    }
+}
+
+// Advance `loc` over `char`, which starts at `loc` and is `len` bytes long. `next` is the
+// character after `char`. Returns whether `char` is a line terminator.
+fn advance_loc<const OPTIONS: u8>(
+    loc: &mut Loc,
+    len: usize,
+    char: char,
+    // Only called for `\r` with `OPTION_UNICODE_LINE_TERMINATORS`, so that the iterator is not
+    // advanced past the current character otherwise
+    next: impl FnOnce() -> Option<char>,
+) -> bool {
+    loc.byte_idx += len;
+    let line_terminator = if OPTIONS & OPTION_UNICODE_LINE_TERMINATORS == 0 {
+        char == '\n'
+    } else {
+        match char {
+            '\n' | '\u{85}' | '\u{2028}' | '\u{2029}' => true,
+            // `\r\n` is one line terminator, counted at `\n`
+            '\r' => next() != Some('\n'),
+            _ => false,
+        }
+    };
+    if line_terminator {
+        loc.line += 1;
+        loc.col = 0;
+    } else if char == '\t' {
+        loc.col += 4; // TODO: Make this configurable?
+    } else {
+        loc.col += UnicodeWidthChar::width(char).unwrap_or(1) as u32;
+    }
+    line_terminator
+}
+
 /// Common parts in lexers generated by lexgen.
 ///
 /// **Fields are used by lexgen-generated code and should not be used directly.**
-pub struct Lexer<'input, Iter: Iterator<Item = char> + Clone, Token, State, Error, Wrapper> {
+///
+/// `OPTIONS` is a set of `OPTION_*` bits, for options that need work for every character.
+pub struct Lexer<
+    'input,
+    Iter: Iterator<Item = char> + Clone,
+    Token,
+    State,
+    Error,
+    Wrapper,
+    const OPTIONS: u8 = 0,
+> {
     // Current lexer state
     pub __state: usize,
 
@@ -70,10 +490,36 @@ pub struct Lexer<'input, Iter: Iterator<Item = char> + Clone, Token, State, Erro
     // Which lexer state to switch to on successful match
     pub __initial_state: usize,
 
+    // Nesting depth in a `nested` rule set, not counting the delimiter that switched to the rule
+    // set
+    pub __nested_depth: usize,
+
+    // Initial state of the rule set that switched to the current rule set, switched back to when a
+    // `nested` rule set is closed
+    pub __nested_return_state: usize,
+
+    // Bits of `group <name> { ... }` groups disabled with the generated `with_group` method
+    pub __disabled_groups: u64,
+
     user_state: State,
 
-    // User-provided input string. Does not change after initialization.
-    input: &'input str,
+    // User-provided input. Does not change after initialization.
+    input: Input<'input>,
+
+    // Maximum length of a character in bytes in `input`: 1 for single-byte input, 4 otherwise.
+    // Lengths of characters are `char::len_utf8` capped to this, without matching `input`.
+    max_char_len: usize,
+
+    // Semantic action to run for invalid UTF-8, with `invalid_utf8 = rule;`. Invalid UTF-8 raises
+    // an error when this is `None`.
+    invalid_utf8_action:
+        Option<for<'lexer> fn(&'lexer mut Wrapper) -> SemanticActionResult<Result<Token, Error>>>,
+
+    // When the last `next` call skipped invalid UTF-8, start location of the invalid bytes
+    invalid_utf8_start: Option<Loc>,
+
+    // Indentation tracking and tokens, with the `indentation` option
+    indentation: Option<Box<indentation::Indentation<Token, Error>>>,
 
     // Start location of `iter`. We update this as we backtrack and update `iter`.
     iter_loc: Loc,
@@ -100,78 +546,397 @@ pub struct Lexer<'input, Iter: Iterator<Item = char> + Clone, Token, State, Erro
         for<'lexer> fn(&'lexer mut Wrapper) -> SemanticActionResult<Result<Token, Error>>,
         Loc,
     )>,
+
+    // Longest match of the custom matchers run at the start of the current match: end of the
+    // match, iterator after the match, semantic action of the rule
+    custom_match: Option<(
+        Loc,
+        Peekable<Iter>,
+        for<'lexer> fn(&'lexer mut Wrapper) -> SemanticActionResult<Result<Token, Error>>,
+    )>,
+
+    // Whether custom matchers should be run in the next initial state of a rule set. Set at the
+    // start of the input and after each match.
+    run_custom_matchers: bool,
+
+    // File names set with `set_location`, with the byte indices they start at, sorted by the byte
+    // indices
+    files: Vec<(usize, String)>,
+
+    // Callback set with `set_progress_callback`
+    progress: Option<progress::Progress>,
+
+    // Flag set with `set_cancellation_flag`, checked at the start of each match with
+    // `cancellation = true;`
+    cancellation: Option<Arc<AtomicBool>>,
+
+    // Counters returned by `stats`, with `counters = true;`
+    counters: Option<stats::Counters>,
+}
+
+// Implemented by hand as derived impls would require the wrapper type (`W`) to implement the
+// traits, which is the type implementing them with `#[derive(...)]` in the lexer definition
+impl<'input, I: Iterator<Item = char> + Clone, T: Clone, S: Clone, E: Clone, W, const O: u8> Clone
+    for Lexer<'input, I, T, S, E, W, O>
+{
+    fn clone(&self) -> Self {
+        Lexer {
+            __state: self.__state,
+            __done: self.__done,
+            __initial_state: self.__initial_state,
+            __nested_depth: self.__nested_depth,
+            __nested_return_state: self.__nested_return_state,
+            __disabled_groups: self.__disabled_groups,
+            user_state: self.user_state.clone(),
+            input: self.input,
+            max_char_len: self.max_char_len,
+            invalid_utf8_action: self.invalid_utf8_action,
+            invalid_utf8_start: self.invalid_utf8_start,
+            indentation: self.indentation.clone(),
+            iter_loc: self.iter_loc,
+            __iter: self.__iter.clone(),
+            current_match_start: self.current_match_start,
+            current_match_end: self.current_match_end,
+            last_match: self.last_match.clone(),
+            custom_match: self.custom_match.clone(),
+            run_custom_matchers: self.run_custom_matchers,
+            files: self.files.clone(),
+            progress: self.progress.clone(),
+            cancellation: self.cancellation.clone(),
+            counters: self.counters.clone(),
+        }
+    }
+}
+
+impl<'input, I: Iterator<Item = char> + Clone, T, S: std::fmt::Debug, E, W, const O: u8>
+    std::fmt::Debug for Lexer<'input, I, T, S, E, W, O>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lexer")
+            .field("state", &self.__state)
+            .field("initial_state", &self.__initial_state)
+            .field("done", &self.__done)
+            .field("user_state", &self.user_state)
+            .field("match_start", &self.current_match_start)
+            .field("match_end", &self.current_match_end)
+            .finish_non_exhaustive()
+    }
 }
 
-impl<I: Iterator<Item = char> + Clone, T, S: Default, E, W> Lexer<'static, I, T, S, E, W> {
+impl<I: Iterator<Item = char> + Clone, T, S: Default, E, W, const O: u8>
+    Lexer<'static, I, T, S, E, W, O>
+{
     pub fn new_from_iter(iter: I) -> Self {
         Self::new_from_iter_with_state(iter, Default::default())
     }
 }
 
-impl<I: Iterator<Item = char> + Clone, T, S, E, W> Lexer<'static, I, T, S, E, W> {
+impl<I: Iterator<Item = char> + Clone, T, S, E, W, const O: u8> Lexer<'static, I, T, S, E, W, O> {
     pub fn new_from_iter_with_state(iter: I, state: S) -> Self {
         Self {
             __state: 0,
             __done: false,
             __initial_state: 0,
+            __nested_depth: 0,
+            __nested_return_state: 0,
+            __disabled_groups: 0,
             user_state: state,
-            input: "",
+            input: Input::Str(""),
+            max_char_len: 4,
+            invalid_utf8_action: None,
+            invalid_utf8_start: None,
+            indentation: None,
             iter_loc: Loc::ZERO,
             __iter: iter.peekable(),
             current_match_start: Loc::ZERO,
             current_match_end: Loc::ZERO,
             last_match: None,
+            custom_match: None,
+            run_custom_matchers: true,
+            files: vec![],
+            progress: None,
+            cancellation: None,
+            counters: None,
         }
     }
 }
 
-impl<'input, T, S: Default, E, W> Lexer<'input, Chars<'input>, T, S, E, W> {
+impl<'input, T, S: Default, E, W, const O: u8> Lexer<'input, Chars<'input>, T, S, E, W, O> {
     pub fn new(input: &'input str) -> Self {
         Self::new_with_state(input, Default::default())
     }
+
+    pub fn new_at(input: &'input str, start: Loc) -> Self {
+        Self::new_at_with_state(input, start, Default::default())
+    }
 }
 
-impl<'input, T, S, E, W> Lexer<'input, Chars<'input>, T, S, E, W> {
+impl<'input, T, S, E, W, const O: u8> Lexer<'input, Chars<'input>, T, S, E, W, O> {
     pub fn new_with_state(input: &'input str, state: S) -> Self {
         Self {
             __state: 0,
             __done: false,
             __initial_state: 0,
+            __nested_depth: 0,
+            __nested_return_state: 0,
+            __disabled_groups: 0,
             user_state: state,
-            input,
+            input: Input::Str(input),
+            max_char_len: 4,
+            invalid_utf8_action: None,
+            invalid_utf8_start: None,
+            indentation: None,
             iter_loc: Loc::ZERO,
             __iter: input.chars().peekable(),
             current_match_start: Loc::ZERO,
             current_match_end: Loc::ZERO,
             last_match: None,
+            custom_match: None,
+            run_custom_matchers: true,
+            files: vec![],
+            progress: None,
+            cancellation: None,
+            counters: None,
+        }
+    }
+
+    // Lexer for the part of the input starting at `start`, which should be a location in `input`,
+    // e.g. the end of a match of another lexer. Locations are in the whole input.
+    pub fn new_at_with_state(input: &'input str, start: Loc, state: S) -> Self {
+        let mut lexer = Self::new_with_state(input, state);
+        lexer.__iter = input[start.byte_idx..].chars().peekable();
+        lexer.iter_loc = start;
+        lexer.current_match_start = start;
+        lexer.current_match_end = start;
+        lexer
+    }
+}
+
+impl<'input, T, S: Default, E, W, const O: u8> Lexer<'input, Utf8Chars<'input>, T, S, E, W, O> {
+    pub fn new_from_bytes(
+        input: &'input [u8],
+        invalid_utf8: InvalidUtf8<
+            for<'lexer> fn(&'lexer mut W) -> SemanticActionResult<Result<T, E>>,
+        >,
+    ) -> Self {
+        Self::new_from_bytes_with_state(input, invalid_utf8, Default::default())
+    }
+}
+
+impl<'input, T, S, E, W, const O: u8> Lexer<'input, Utf8Chars<'input>, T, S, E, W, O> {
+    pub fn new_from_bytes_with_state(
+        input: &'input [u8],
+        invalid_utf8: InvalidUtf8<
+            for<'lexer> fn(&'lexer mut W) -> SemanticActionResult<Result<T, E>>,
+        >,
+        state: S,
+    ) -> Self {
+        assert!(
+            O & OPTION_INVALID_UTF8 != 0,
+            "Lexers for byte input need `OPTION_INVALID_UTF8`"
+        );
+        let (replace, invalid_utf8_action) = match invalid_utf8 {
+            InvalidUtf8::Error => (false, None),
+            InvalidUtf8::Replace => (true, None),
+            InvalidUtf8::Rule(action) => (false, Some(action)),
+        };
+        Self {
+            __state: 0,
+            __done: false,
+            __initial_state: 0,
+            __nested_depth: 0,
+            __nested_return_state: 0,
+            __disabled_groups: 0,
+            user_state: state,
+            input: Input::Utf8(input),
+            max_char_len: 4,
+            invalid_utf8_action,
+            invalid_utf8_start: None,
+            indentation: None,
+            iter_loc: Loc::ZERO,
+            __iter: Utf8Chars {
+                bytes: input,
+                replace,
+            }
+            .peekable(),
+            current_match_start: Loc::ZERO,
+            current_match_end: Loc::ZERO,
+            last_match: None,
+            custom_match: None,
+            run_custom_matchers: true,
+            files: vec![],
+            progress: None,
+            cancellation: None,
+            counters: None,
+        }
+    }
+}
+
+impl<'input, T, S: Default, E, W, const O: u8>
+    Lexer<'input, SingleByteChars<'input>, T, S, E, W, O>
+{
+    pub fn new_from_single_byte(input: &'input [u8], encoding: SingleByteEncoding) -> Self {
+        Self::new_from_single_byte_with_state(input, encoding, Default::default())
+    }
+}
+
+impl<'input, T, S, E, W, const O: u8> Lexer<'input, SingleByteChars<'input>, T, S, E, W, O> {
+    pub fn new_from_single_byte_with_state(
+        input: &'input [u8],
+        encoding: SingleByteEncoding,
+        state: S,
+    ) -> Self {
+        Self {
+            __state: 0,
+            __done: false,
+            __initial_state: 0,
+            __nested_depth: 0,
+            __nested_return_state: 0,
+            __disabled_groups: 0,
+            user_state: state,
+            input: Input::SingleByte(input),
+            max_char_len: 1,
+            invalid_utf8_action: None,
+            invalid_utf8_start: None,
+            indentation: None,
+            iter_loc: Loc::ZERO,
+            __iter: SingleByteChars::new(input, encoding).peekable(),
+            current_match_start: Loc::ZERO,
+            current_match_end: Loc::ZERO,
+            last_match: None,
+            custom_match: None,
+            run_custom_matchers: true,
+            files: vec![],
+            progress: None,
+            cancellation: None,
+            counters: None,
         }
     }
 }
 
-impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W> Lexer<'input, I, T, S, E, W> {
+impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W, const O: u8>
+    Lexer<'input, I, T, S, E, W, O>
+{
+    pub fn with_counters(mut self) -> Self {
+        self.counters = Some(stats::Counters::new(self.current_match_end.byte_idx));
+        self
+    }
+
+    // Counters of the lexer so far. Panics if the lexer was not created with `with_counters`.
+    pub fn stats(&self) -> LexerStats {
+        self.counters
+            .as_ref()
+            .expect("Lexer doesn't collect counters")
+            .stats(self.current_match_end.byte_idx)
+    }
+
     // Read the next chracter
     pub fn next(&mut self) -> Option<char> {
+        if O & OPTION_INVALID_UTF8 != 0 {
+            self.invalid_utf8_start = None;
+        }
         match self.__iter.next() {
-            None => None,
+            None => {
+                // When lexing bytes, the iterator yields `None` for invalid UTF-8 before the end of
+                // the input, and skips the invalid bytes
+                if O & OPTION_INVALID_UTF8 == 0 {
+                    return None;
+                }
+                if let Input::Utf8(bytes) = self.input {
+                    let byte_idx = self.current_match_end.byte_idx;
+                    if byte_idx < bytes.len() {
+                        self.invalid_utf8_start = Some(self.current_match_end);
+                        self.current_match_end.byte_idx += decode_utf8(&bytes[byte_idx..])
+                            .expect_err("Iterator skipped valid UTF-8");
+                        self.current_match_end.col += 1;
+                    }
+                }
+                None
+            }
             Some(char) => {
-                self.current_match_end.byte_idx += char.len_utf8();
-                if char == '\n' {
-                    self.current_match_end.line += 1;
-                    self.current_match_end.col = 0;
-                } else if char == '\t' {
-                    self.current_match_end.col += 4; // TODO: Make this configurable?
-                } else {
-                    self.current_match_end.col += UnicodeWidthChar::width(char).unwrap_or(1) as u32;
+                let char_loc = self.current_match_end;
+                let len = match self.input {
+                    Input::Utf8(bytes) if O & OPTION_INVALID_UTF8 != 0 => {
+                        utf8_char_len(bytes, char_loc.byte_idx, char)
+                    }
+                    _ => char.len_utf8().min(self.max_char_len),
+                };
+                let line_terminator =
+                    advance_loc::<O>(&mut self.current_match_end, len, char, || {
+                        self.__iter.peek().copied()
+                    });
+                if O & OPTION_INDENTATION != 0 {
+                    if let Some(indentation) = &mut self.indentation {
+                        indentation.scan(
+                            char_loc,
+                            char,
+                            line_terminator,
+                            self.current_match_end.byte_idx,
+                        );
+                    }
                 }
                 Some(char)
             }
         }
     }
 
+    // Consume characters of the given string. On mismatch returns the first character that didn't
+    // match, or `None` if we reached end-of-input. Characters consumed until the mismatch
+    // (including the mismatching character) are not put back.
+    pub fn consume_str(&mut self, str: &str) -> Result<(), Option<char>> {
+        // With `&str` input, compare with the rest of the input and skip the whole string at once.
+        // Strings with line terminators or tabs, and lexers with indentation tracking, need to look
+        // at every character, and use the loop below.
+        if let Input::Str(input) = self.input {
+            let byte_idx = self.current_match_end.byte_idx;
+            if O & OPTION_INDENTATION == 0
+                && input
+                    .get(byte_idx..)
+                    .is_some_and(|rest| rest.starts_with(str))
+                && !str.contains(|char: char| {
+                    char.is_control() || char == '\u{2028}' || char == '\u{2029}'
+                })
+            {
+                let width = if str.is_ascii() {
+                    str.len() as u32
+                } else {
+                    str.chars()
+                        .map(|char| UnicodeWidthChar::width(char).unwrap_or(1) as u32)
+                        .sum()
+                };
+                self.current_match_end.byte_idx += str.len();
+                self.current_match_end.col += width;
+                if let Some(n) = str.chars().count().checked_sub(1) {
+                    self.__iter.nth(n);
+                }
+                return Ok(());
+            }
+        }
+
+        // Other inputs (including `new_from_iter` lexers, which have an empty `Input::Str`), and
+        // mismatches, which need the mismatching character
+        for expected in str.chars() {
+            match self.next() {
+                // Invalid UTF-8 is a mismatch, not end-of-input
+                None if self.invalid_utf8() => return Err(Some(char::REPLACEMENT_CHARACTER)),
+                None => return Err(None),
+                Some(char) if char == expected => {}
+                Some(char) => return Err(Some(char)),
+            }
+        }
+        Ok(())
+    }
+
     pub fn peek(&mut self) -> Option<char> {
         self.__iter.peek().copied()
     }
 
+    // Whether the last `next` call returned `None` because of invalid UTF-8, rather than
+    // end-of-input
+    pub fn invalid_utf8(&self) -> bool {
+        self.invalid_utf8_start.is_some()
+    }
+
     // On success returns semantic action function for the last match
     pub fn backtrack(
         &mut self,
@@ -179,6 +944,22 @@ impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W> Lexer<'input, I, T, S
     {
         match self.last_match.take() {
             None => {
+                // Invalid UTF-8 at the beginning of the match is handled as configured with the
+                // `invalid_utf8` option. In other places it's a part of an invalid token.
+                if let Some(invalid_utf8_start) = self.invalid_utf8_start {
+                    if invalid_utf8_start == self.current_match_start {
+                        if let Some(action) = self.invalid_utf8_action {
+                            return Ok(action);
+                        }
+                        self.__state = 0;
+                        return Err(LexerError {
+                            location: invalid_utf8_start,
+                            kind: LexerErrorKind::InvalidUtf8 {
+                                end: self.current_match_end,
+                            },
+                        });
+                    }
+                }
                 self.__state = 0;
                 Err(LexerError {
                     location: self.current_match_start,
@@ -186,6 +967,9 @@ impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W> Lexer<'input, I, T, S
                 })
             }
             Some((match_start, iter, semantic_action, match_end)) => {
+                if let Some(counters) = &mut self.counters {
+                    counters.backtrack(self.current_match_end.byte_idx, match_end.byte_idx);
+                }
                 self.__done = false;
                 self.current_match_start = match_start;
                 self.current_match_end = match_end;
@@ -216,14 +1000,96 @@ impl<'input, I: Iterator<Item = char> + Clone, T, S, E, W> Lexer<'input, I, T, S
         self.current_match_start = self.current_match_end;
     }
 
+    pub fn set_progress_callback(
+        &mut self,
+        interval: ProgressInterval,
+        callback: impl Fn(Loc) + Send + Sync + 'static,
+    ) {
+        self.progress = Some(progress::Progress::new(interval, Arc::new(callback)));
+    }
+
+    pub fn set_cancellation_flag(&mut self, flag: Arc<AtomicBool>) {
+        self.cancellation = Some(flag);
+    }
+
+    pub fn __cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
+    // Called by the generated code after returning a token, with the end of the token
+    pub fn __token_returned(&mut self, end: Loc) {
+        if let Some(progress) = &mut self.progress {
+            progress.token(end);
+        }
+        if let Some(counters) = &mut self.counters {
+            counters.token();
+        }
+    }
+
     pub fn match_(&self) -> &'input str {
-        &self.input[self.current_match_start.byte_idx..self.current_match_end.byte_idx]
+        self.input
+            .str(self.current_match_start, self.current_match_end)
+    }
+
+    pub fn match_bytes(&self) -> &'input [u8] {
+        self.input
+            .bytes(self.current_match_start, self.current_match_end)
     }
 
     pub fn match_loc(&self) -> (Loc, Loc) {
         (self.current_match_start, self.current_match_end)
     }
 
+    // End of the input consumed so far. After returning a token this is the end of the token.
+    pub fn location(&self) -> Loc {
+        self.current_match_end
+    }
+
+    // Input of lexers created with `new` and `new_at`, for starting sub-lexers on the rest of the
+    // input
+    pub fn input(&self) -> &'input str {
+        match self.input {
+            Input::Str(input) => input,
+            Input::Utf8(_) | Input::SingleByte(_) => {
+                panic!("`input` is only available in lexers created with `new` and `new_at`")
+            }
+        }
+    }
+
+    // Consume the input until the given location, e.g. the end of the input consumed by a
+    // sub-lexer, and add it to the current match
+    pub fn skip_to(&mut self, loc: Loc) {
+        while self.current_match_end.byte_idx < loc.byte_idx {
+            if self.next().is_none() && !self.invalid_utf8() {
+                break;
+            }
+        }
+    }
+
+    // Remap locations after the current match, for `#line` directives in preprocessed input: the
+    // line of the end of the match becomes `line`, and `file` returns `file` for the locations
+    pub fn set_location(&mut self, file: &str, line: u32) {
+        self.current_match_end.line = line;
+        let byte_idx = self.current_match_end.byte_idx;
+        if let Some((last_byte_idx, _)) = self.files.last() {
+            if *last_byte_idx == byte_idx {
+                self.files.pop();
+            }
+        }
+        self.files.push((byte_idx, file.to_owned()));
+    }
+
+    // File name of a location set with `set_location`, `None` for locations before the first
+    // `set_location` call
+    pub fn file(&self, loc: Loc) -> Option<&str> {
+        let idx = self
+            .files
+            .partition_point(|(byte_idx, _)| *byte_idx <= loc.byte_idx);
+        idx.checked_sub(1).map(|idx| self.files[idx].1.as_str())
+    }
+
     pub fn state(&mut self) -> &mut S {
         &mut self.user_state
     }
@@ -0,0 +1,47 @@
+//! Memory-mapped input files, with the `memmap2` feature
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// A file mapped into memory, for lexing large files without reading them into a `String`.
+/// Lexers borrow the input from the mapping, so matches (`match_`) and tokens borrowing the input
+/// point into the file without copying:
+///
+/// ```ignore
+/// let file = unsafe { MappedFile::open(path)? };
+/// let lexer = Lexer::new(file.as_str()?);
+/// ```
+///
+/// Files that are not valid UTF-8 can be lexed with `as_bytes` and the `new_from_bytes`
+/// constructors of lexers with the `invalid_utf8` option.
+pub struct MappedFile {
+    mmap: memmap2::Mmap,
+}
+
+impl MappedFile {
+    /// Map the file at `path` into memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the `MappedFile` is alive, e.g. by another
+    /// process. Changing the contents can break the UTF-8 check of `as_str` and invalidate
+    /// borrowed tokens, and truncating the file can crash the process.
+    pub unsafe fn open(path: impl AsRef<Path>) -> io::Result<MappedFile> {
+        let file = File::open(path)?;
+        let mmap = memmap2::Mmap::map(&file)?;
+        Ok(MappedFile { mmap })
+    }
+
+    /// Contents of the file
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    /// Contents of the file, as a string. Fails with an `InvalidData` error, with the
+    /// `std::str::Utf8Error` as the inner error, when the file is not valid UTF-8.
+    pub fn as_str(&self) -> io::Result<&str> {
+        std::str::from_utf8(&self.mmap)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
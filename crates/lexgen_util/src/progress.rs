@@ -0,0 +1,60 @@
+//! Progress reporting for lexers of large inputs
+
+use crate::Loc;
+
+use std::sync::Arc;
+
+/// How often the callback set with a lexer's `set_progress_callback` method is called
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressInterval {
+    /// After the first token that ends at or after each multiple of the given number of bytes
+    Bytes(usize),
+
+    /// After every given number of tokens
+    Tokens(usize),
+}
+
+// Progress callback of a lexer
+#[derive(Clone)]
+pub(crate) struct Progress {
+    interval: ProgressInterval,
+
+    // Byte index or number of tokens at which the callback is called next
+    next: usize,
+
+    // Number of tokens returned so far
+    tokens: usize,
+
+    callback: Arc<dyn Fn(Loc) + Send + Sync>,
+}
+
+impl Progress {
+    pub(crate) fn new(
+        interval: ProgressInterval,
+        callback: Arc<dyn Fn(Loc) + Send + Sync>,
+    ) -> Self {
+        let next = match interval {
+            ProgressInterval::Bytes(n) | ProgressInterval::Tokens(n) => n,
+        };
+        assert!(next != 0, "progress interval must be positive");
+        Progress {
+            interval,
+            next,
+            tokens: 0,
+            callback,
+        }
+    }
+
+    // Called after a token ending at `end` is returned
+    pub(crate) fn token(&mut self, end: Loc) {
+        self.tokens += 1;
+        let (current, n) = match self.interval {
+            ProgressInterval::Bytes(n) => (end.byte_idx, n),
+            ProgressInterval::Tokens(n) => (self.tokens, n),
+        };
+        if current >= self.next {
+            self.next = (current / n + 1) * n;
+            (self.callback)(end);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Streaming input from `std::io::Read`
+
+use crate::decode_utf8;
+
+use std::cell::{Cell, RefCell};
+use std::io::{self, Read};
+use std::rc::Rc;
+
+/// Default number of bytes read from the reader at a time
+pub const DEFAULT_CHUNK_SIZE: usize = 8 * 1024;
+
+/// Iterator over characters of UTF-8 encoded input read from a `std::io::Read`, for lexing input
+/// that is too large to read into memory, with `new_from_iter`.
+///
+/// Input is read in chunks. Clones of the iterator (which the lexer takes to backtrack) share the
+/// chunks, and a chunk is dropped as soon as no clone refers to it. The lexer only keeps clones
+/// within the current token, so the bytes kept in memory are bounded by the length of the longest
+/// token plus two chunks.
+///
+/// Invalid UTF-8 is replaced with U+FFFD. The iterator ends on the first read error, which can be
+/// taken with [`ReadStatus::take_error`].
+pub struct ReadChars<R: Read> {
+    // Chunk of the next character
+    chunk: Rc<Chunk<R>>,
+
+    // Index of the next character in `chunk`
+    idx: usize,
+}
+
+/// A handle to the state shared by a [`ReadChars`] and its clones, for checking the read error and
+/// memory use after passing the iterator to a lexer. Does not keep any of the input in memory.
+#[derive(Debug, Clone, Default)]
+pub struct ReadStatus {
+    buffered: Rc<Cell<usize>>,
+    error: Rc<RefCell<Option<io::Error>>>,
+}
+
+impl ReadStatus {
+    /// Number of bytes of the input currently kept in memory
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered.get()
+    }
+
+    /// Takes the error that ended the input, if reading failed
+    pub fn take_error(&self) -> Option<io::Error> {
+        self.error.borrow_mut().take()
+    }
+}
+
+struct Source<R> {
+    reader: RefCell<R>,
+    chunk_size: usize,
+    eof: Cell<bool>,
+    status: ReadStatus,
+}
+
+struct Chunk<R> {
+    bytes: Box<[u8]>,
+
+    // Next chunk, read when an iterator reaches the end of this chunk for the first time
+    next: RefCell<Option<Rc<Chunk<R>>>>,
+
+    source: Rc<Source<R>>,
+}
+
+impl<R: Read> ReadChars<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_chunk_size(reader, DEFAULT_CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(reader: R, chunk_size: usize) -> Self {
+        assert!(chunk_size != 0, "chunk size must be positive");
+        let source = Rc::new(Source {
+            reader: RefCell::new(reader),
+            chunk_size,
+            eof: Cell::new(false),
+            status: ReadStatus::default(),
+        });
+        ReadChars {
+            chunk: Rc::new(Chunk {
+                bytes: Box::new([]),
+                next: RefCell::new(None),
+                source,
+            }),
+            idx: 0,
+        }
+    }
+
+    pub fn status(&self) -> ReadStatus {
+        self.chunk.source.status.clone()
+    }
+
+    // Advance `len` bytes. The bytes should already be read.
+    fn advance(&mut self, mut len: usize) {
+        while len != 0 {
+            let remaining = self.chunk.bytes.len() - self.idx;
+            if remaining == 0 {
+                self.chunk = self.chunk.next_chunk().unwrap();
+                self.idx = 0;
+                continue;
+            }
+            let n = remaining.min(len);
+            self.idx += n;
+            len -= n;
+        }
+    }
+}
+
+impl<R: Read> Chunk<R> {
+    fn next_chunk(&self) -> Option<Rc<Chunk<R>>> {
+        if let Some(next) = &*self.next.borrow() {
+            return Some(next.clone());
+        }
+
+        let source = &self.source;
+        if source.eof.get() {
+            return None;
+        }
+
+        let mut bytes = vec![0; source.chunk_size];
+        let mut len = 0;
+        let mut reader = source.reader.borrow_mut();
+        while len != bytes.len() {
+            match reader.read(&mut bytes[len..]) {
+                Ok(0) => {
+                    source.eof.set(true);
+                    break;
+                }
+                Ok(n) => len += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => {
+                    *source.status.error.borrow_mut() = Some(err);
+                    source.eof.set(true);
+                    break;
+                }
+            }
+        }
+
+        if len == 0 {
+            return None;
+        }
+
+        bytes.truncate(len);
+        source
+            .status
+            .buffered
+            .set(source.status.buffered.get() + len);
+        let next = Rc::new(Chunk {
+            bytes: bytes.into_boxed_slice(),
+            next: RefCell::new(None),
+            source: source.clone(),
+        });
+        *self.next.borrow_mut() = Some(next.clone());
+        Some(next)
+    }
+}
+
+impl<R> Drop for Chunk<R> {
+    fn drop(&mut self) {
+        let status = &self.source.status;
+        status
+            .buffered
+            .set(status.buffered.get() - self.bytes.len());
+
+        // Drop the rest of the chain in a loop, to avoid recursion on long chains
+        let mut next = self.next.get_mut().take();
+        while let Some(chunk) = next {
+            next = match Rc::try_unwrap(chunk) {
+                Ok(mut chunk) => chunk.next.get_mut().take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+impl<R: Read> Clone for ReadChars<R> {
+    fn clone(&self) -> Self {
+        ReadChars {
+            chunk: self.chunk.clone(),
+            idx: self.idx,
+        }
+    }
+}
+
+impl<R: Read> Iterator for ReadChars<R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let bytes = &self.chunk.bytes[self.idx..];
+        if bytes.len() >= 4 || bytes.first().map(|byte| *byte < 0x80).unwrap_or(false) {
+            let (char, len) = decode_utf8(bytes).unwrap_or_else(|len| ('\u{FFFD}', len));
+            self.idx += len;
+            return Some(char);
+        }
+
+        // Character may continue in the next chunks
+        let mut buf = [0u8; 4];
+        let mut buf_len = 0;
+        let mut chunk = self.chunk.clone();
+        let mut idx = self.idx;
+        while buf_len != buf.len() {
+            if idx == chunk.bytes.len() {
+                match chunk.next_chunk() {
+                    Some(next) => {
+                        chunk = next;
+                        idx = 0;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+            buf[buf_len] = chunk.bytes[idx];
+            buf_len += 1;
+            idx += 1;
+        }
+        drop(chunk);
+
+        if buf_len == 0 {
+            return None;
+        }
+
+        let (char, len) = decode_utf8(&buf[..buf_len]).unwrap_or_else(|len| ('\u{FFFD}', len));
+        self.advance(len);
+        Some(char)
+    }
+}
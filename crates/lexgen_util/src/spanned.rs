@@ -0,0 +1,36 @@
+//! Tokens with locations and byte ranges, for parser combinator libraries
+
+use crate::{LexerError, Loc};
+
+use std::ops::Range;
+
+/// Collect tokens of a lexer, with their byte ranges in the input, or return the first error.
+///
+/// This is the input format parser combinator libraries expect, so the result can be parsed
+/// without writing a wrapper for the lexer. For example, with chumsky the tokens can be parsed
+/// with `tokens.as_slice().split_token_span(eoi)` (or `Stream::from_iter`), and with nom as a
+/// slice of tokens.
+pub fn collect_spanned<T, E, I>(tokens: I) -> Result<Vec<(T, Range<usize>)>, LexerError<E>>
+where
+    I: IntoIterator<Item = Result<(Loc, T, Loc), LexerError<E>>>,
+{
+    tokens
+        .into_iter()
+        .map(|token| token.map(|(start, token, end)| (token, start.byte_idx..end.byte_idx)))
+        .collect()
+}
+
+/// A token with its start and end locations. Yielded by lexers with `spanned_tokens = true;`
+/// instead of `(start, token, end)` tuples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub start: Loc,
+    pub end: Loc,
+}
+
+impl<T> From<(Loc, T, Loc)> for Spanned<T> {
+    fn from((start, value, end): (Loc, T, Loc)) -> Self {
+        Spanned { value, start, end }
+    }
+}
@@ -0,0 +1,60 @@
+//! Runtime counters of lexers, collected with `counters = true;`
+
+/// Counters of a lexer, returned by the `stats` method of lexers with `counters = true;`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LexerStats {
+    /// Number of tokens returned
+    pub tokens: usize,
+
+    /// Number of bytes of the input lexed, including skipped input
+    pub bytes: usize,
+
+    /// Number of times the lexer went back to the end of the last match, after reading characters
+    /// past it that didn't continue the match. Tokens that could be longer, like identifiers,
+    /// backtrack over at least the character after them.
+    pub backtracks: usize,
+
+    /// Number of bytes read again after backtracking
+    pub backtracked_bytes: usize,
+
+    /// Maximum number of bytes read past the end of a match before backtracking to it
+    pub max_lookahead: usize,
+}
+
+// Counters of a lexer, with the byte index the lexer started at
+#[derive(Debug, Clone)]
+pub(crate) struct Counters {
+    start: usize,
+    stats: LexerStats,
+}
+
+impl Counters {
+    pub(crate) fn new(start: usize) -> Self {
+        Counters {
+            start,
+            stats: LexerStats::default(),
+        }
+    }
+
+    pub(crate) fn token(&mut self) {
+        self.stats.tokens += 1;
+    }
+
+    // Called when backtracking from byte index `read_end` to the end of the last match
+    pub(crate) fn backtrack(&mut self, read_end: usize, match_end: usize) {
+        let lookahead = read_end - match_end;
+        if lookahead != 0 {
+            self.stats.backtracks += 1;
+            self.stats.backtracked_bytes += lookahead;
+            self.stats.max_lookahead = self.stats.max_lookahead.max(lookahead);
+        }
+    }
+
+    // Stats of a lexer that has lexed up to byte index `end`
+    pub(crate) fn stats(&self, end: usize) -> LexerStats {
+        LexerStats {
+            bytes: end - self.start,
+            ..self.stats
+        }
+    }
+}
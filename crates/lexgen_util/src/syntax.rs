@@ -0,0 +1,92 @@
+//! Lossless token streams, for building concrete syntax trees
+
+use crate::{LexerError, Loc};
+
+/// A lossless token stream for building concrete syntax trees (e.g. with rowan's
+/// `GreenNodeBuilder` or cstree), made from the tokens of a lexer and its input.
+///
+/// Yields syntax kinds of tokens with their text. Tokens of the lexer are mapped to syntax kinds
+/// with a function, and input skipped by the lexer (e.g. whitespace and comments matched by rules
+/// without a right-hand side) is yielded as trivia, so the texts of the yielded tokens concatenate
+/// to the input.
+///
+/// After an error the error is yielded and the stream ends. Input after the last token, including
+/// trivia before the error, is not yielded.
+pub struct SyntaxTokens<'input, I, F, K> {
+    input: &'input str,
+    tokens: I,
+    kind: F,
+    trivia: K,
+
+    // Byte index of the end of the last yielded token
+    pos: usize,
+
+    // Token to yield after the trivia before it
+    pending: Option<(K, &'input str)>,
+
+    done: bool,
+}
+
+impl<'input, I, F, K> SyntaxTokens<'input, I, F, K> {
+    /// `tokens` should be the tokens of `input`, e.g. `Lexer::new(input)`. `trivia` is the syntax
+    /// kind of input skipped by the lexer, `kind` maps tokens to syntax kinds.
+    pub fn new(input: &'input str, tokens: I, trivia: K, kind: F) -> Self {
+        SyntaxTokens {
+            input,
+            tokens,
+            kind,
+            trivia,
+            pos: 0,
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+impl<'input, T, E, I, F, K> Iterator for SyntaxTokens<'input, I, F, K>
+where
+    I: Iterator<Item = Result<(Loc, T, Loc), LexerError<E>>>,
+    F: FnMut(T) -> K,
+    K: Clone,
+{
+    type Item = Result<(K, &'input str), LexerError<E>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(token) = self.pending.take() {
+            return Some(Ok(token));
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let (start, end, kind) = match self.tokens.next() {
+            Some(Ok((start, token, end))) => (start.byte_idx, end.byte_idx, (self.kind)(token)),
+            Some(Err(err)) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+            None => {
+                self.done = true;
+                // Trailing input skipped by the lexer
+                if self.pos < self.input.len() {
+                    let trivia = &self.input[self.pos..];
+                    self.pos = self.input.len();
+                    return Some(Ok((self.trivia.clone(), trivia)));
+                }
+                return None;
+            }
+        };
+
+        let token = (kind, &self.input[start..end]);
+        let trivia_start = self.pos;
+        self.pos = end;
+
+        if trivia_start < start {
+            self.pending = Some(token);
+            Some(Ok((self.trivia.clone(), &self.input[trivia_start..start])))
+        } else {
+            Some(Ok(token))
+        }
+    }
+}
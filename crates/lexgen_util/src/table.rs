@@ -0,0 +1,208 @@
+//! Lexer DFAs as data, for loading lexers at runtime
+
+use crate::{Lexer, LexerError, Loc, SemanticActionResult, TokenSource};
+
+use std::convert::Infallible;
+use std::str::Chars;
+
+/// DFA of a lexer as data, generated by lexers with `dfa_table = true;` in the `dfa_table`
+/// associated function. With the `serde` feature this can be serialized, and deserialized tables
+/// can be run with `TableLexer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DfaTable {
+    /// States of the DFA. Lexing starts in state 0.
+    pub states: Vec<DfaTableState>,
+
+    /// Tokens of the rules, indexed by `DfaTableState::accepting`. Tokens are the right-hand sides
+    /// of the rules as written in the lexer definition, e.g. `Token::Plus`. `None` for rules
+    /// without a right-hand side, which skip the matched input.
+    pub tokens: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DfaTableState {
+    /// Sorted, non-overlapping character ranges (inclusive) and the states they transition to
+    pub transitions: Vec<(char, char, usize)>,
+
+    /// Transition for characters not in `transitions`
+    pub any: Option<usize>,
+
+    /// Transition at the end of the input
+    pub end_of_input: Option<usize>,
+
+    /// Rule accepted in this state, as an index in `DfaTable::tokens`
+    pub accepting: Option<usize>,
+}
+
+/// Error returned by `TableLexer::new` for tables with out of bounds state or token indices, or
+/// unsorted or overlapping transitions. Tables generated by lexgen are always valid, but tables
+/// loaded from untrusted sources may not be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDfaTable(String);
+
+impl std::fmt::Display for InvalidDfaTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid DFA table: {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDfaTable {}
+
+impl DfaTable {
+    pub(crate) fn validate(&self) -> Result<(), InvalidDfaTable> {
+        if self.states.is_empty() {
+            return Err(InvalidDfaTable("table doesn't have any states".to_owned()));
+        }
+
+        let check_state = |state_idx: usize, next: usize| {
+            if next >= self.states.len() {
+                Err(InvalidDfaTable(format!(
+                    "state {} has a transition to state {}, which doesn't exist",
+                    state_idx, next
+                )))
+            } else {
+                Ok(())
+            }
+        };
+
+        for (state_idx, state) in self.states.iter().enumerate() {
+            let mut last_end: Option<char> = None;
+            for (start, end, next) in &state.transitions {
+                if start > end || last_end.is_some_and(|last_end| last_end >= *start) {
+                    return Err(InvalidDfaTable(format!(
+                        "transitions of state {} are not sorted and non-overlapping",
+                        state_idx
+                    )));
+                }
+                last_end = Some(*end);
+                check_state(state_idx, *next)?;
+            }
+
+            for next in state.any.iter().chain(state.end_of_input.iter()) {
+                check_state(state_idx, *next)?;
+            }
+
+            if let Some(rule) = state.accepting {
+                if rule >= self.tokens.len() {
+                    return Err(InvalidDfaTable(format!(
+                        "state {} accepts rule {}, which doesn't exist",
+                        state_idx, rule
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DfaTableState {
+    fn next(&self, char: char) -> Option<usize> {
+        crate::binary_search_transitions(char, &self.transitions).or(self.any)
+    }
+}
+
+/// A lexer that runs a `DfaTable`. Like lexgen-generated lexers, this is an iterator of tokens
+/// with their start and end locations. Tokens are the ones in the table's `tokens`.
+pub struct TableLexer<'table, 'input> {
+    table: &'table DfaTable,
+
+    // Used for reading characters, tracking locations, and backtracking. Semantic actions are not
+    // used, the rule of the last match is in `last_match_rule`.
+    lexer: Lexer<'input, Chars<'input>, (), (), Infallible, ()>,
+
+    // Rule of the last accepting state, set with the semantic action of the `lexer`
+    last_match_rule: Option<usize>,
+}
+
+// Semantic action passed to the `lexer`, not called
+fn accept(_: &mut ()) -> SemanticActionResult<Result<(), Infallible>> {
+    SemanticActionResult::Continue
+}
+
+impl<'table, 'input> TableLexer<'table, 'input> {
+    pub fn new(table: &'table DfaTable, input: &'input str) -> Result<Self, InvalidDfaTable> {
+        table.validate()?;
+        Ok(TableLexer {
+            table,
+            lexer: Lexer::new(input),
+            last_match_rule: None,
+        })
+    }
+
+    // Backtrack to the last match, or return an error
+    fn fail(&mut self) -> Result<usize, LexerError<Infallible>> {
+        self.lexer.backtrack()?;
+        Ok(self.last_match_rule.take().unwrap())
+    }
+}
+
+impl<'table, 'input> Iterator for TableLexer<'table, 'input> {
+    type Item = Result<(Loc, &'table str, Loc), LexerError<Infallible>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.lexer.__done {
+                return None;
+            }
+
+            let state_idx = self.lexer.__state;
+            let state = &self.table.states[state_idx];
+
+            if state_idx == 0 {
+                self.lexer.reset_match();
+            }
+
+            if let Some(rule) = state.accepting {
+                self.lexer.set_accepting_state(accept);
+                self.last_match_rule = Some(rule);
+            }
+
+            let rule = match self.lexer.next() {
+                None => {
+                    self.lexer.__done = true;
+                    match state
+                        .end_of_input
+                        .and_then(|next| self.table.states[next].accepting)
+                    {
+                        Some(rule) => rule,
+                        // In the initial state end of the input is the end of the tokens
+                        None if state_idx == 0 => return None,
+                        None => match self.fail() {
+                            Ok(rule) => rule,
+                            Err(err) => return Some(Err(err)),
+                        },
+                    }
+                }
+                Some(char) => match state.next(char) {
+                    Some(next) => {
+                        self.lexer.__state = next;
+                        continue;
+                    }
+                    None => match self.fail() {
+                        Ok(rule) => rule,
+                        Err(err) => return Some(Err(err)),
+                    },
+                },
+            };
+
+            self.lexer.__state = 0;
+            if let Some(token) = &self.table.tokens[rule] {
+                let (match_start, match_end) = self.lexer.match_loc();
+                self.lexer.reset_match();
+                return Some(Ok((match_start, token, match_end)));
+            }
+        }
+    }
+}
+
+impl<'table, 'input> TokenSource for TableLexer<'table, 'input> {
+    type Token = &'table str;
+    type Error = Infallible;
+
+    fn next_spanned(&mut self) -> Option<Result<(Loc, &'table str, Loc), LexerError<Infallible>>> {
+        self.next()
+    }
+}
@@ -0,0 +1,44 @@
+//! A dyn-compatible trait for lexers, for choosing a lexer at runtime
+
+use crate::{LexerError, Loc};
+
+/// A source of tokens with their locations. Implemented by lexgen-generated lexers and
+/// `TableLexer`.
+///
+/// Unlike `Iterator` with the lexer's item type, the trait has the same item type for lexers with
+/// and without `spanned_tokens`, and can be used as a trait object, so lexers for the same token
+/// and error types can be chosen at runtime:
+///
+/// ```ignore
+/// let lexer: Box<dyn TokenSource<Token = Token, Error = Error> + '_> = if strict {
+///     Box::new(StrictLexer::new(input))
+/// } else {
+///     Box::new(LenientLexer::new(input))
+/// };
+/// ```
+pub trait TokenSource {
+    type Token;
+    type Error;
+
+    /// Lex the next token. Returns the same values as the lexer's `next` method with
+    /// `spanned_tokens = false;`, and `None` at the end of the input.
+    fn next_spanned(&mut self) -> Option<Result<(Loc, Self::Token, Loc), LexerError<Self::Error>>>;
+}
+
+impl<S: TokenSource + ?Sized> TokenSource for &mut S {
+    type Token = S::Token;
+    type Error = S::Error;
+
+    fn next_spanned(&mut self) -> Option<Result<(Loc, S::Token, Loc), LexerError<S::Error>>> {
+        (**self).next_spanned()
+    }
+}
+
+impl<S: TokenSource + ?Sized> TokenSource for Box<S> {
+    type Token = S::Token;
+    type Error = S::Error;
+
+    fn next_spanned(&mut self) -> Option<Result<(Loc, S::Token, Loc), LexerError<S::Error>>> {
+        (**self).next_spanned()
+    }
+}
@@ -0,0 +1,118 @@
+//! Decoding escape sequences in string literals, for semantic actions of string rules
+
+use std::borrow::Cow;
+
+/// Decode escape sequences in the body of a string literal (without the quotes):
+///
+/// - `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, and `\"`
+/// - `\xNN`: ASCII character with two hex digits, up to `\x7F`
+/// - `\u{N...}`: Unicode scalar value with one to six hex digits
+///
+/// The input is borrowed when it doesn't have escape sequences.
+pub fn unescape(input: &str) -> Result<Cow<'_, str>, InvalidEscape> {
+    let first_escape = match input.find('\\') {
+        None => return Ok(Cow::Borrowed(input)),
+        Some(idx) => idx,
+    };
+
+    let mut output = String::with_capacity(input.len());
+    output.push_str(&input[..first_escape]);
+
+    let mut chars = input[first_escape..].char_indices();
+
+    while let Some((idx, char)) = chars.next() {
+        if char != '\\' {
+            output.push(char);
+            continue;
+        }
+
+        let offset = first_escape + idx;
+        let error = |kind| InvalidEscape { offset, kind };
+
+        let escaped = match chars.next() {
+            Some((_, 'n')) => '\n',
+            Some((_, 'r')) => '\r',
+            Some((_, 't')) => '\t',
+            Some((_, '\\')) => '\\',
+            Some((_, '0')) => '\0',
+            Some((_, '\'')) => '\'',
+            Some((_, '"')) => '"',
+            Some((_, 'x')) => {
+                let mut value = 0;
+                for _ in 0..2 {
+                    match chars.next().and_then(|(_, char)| char.to_digit(16)) {
+                        Some(digit) => value = value * 16 + digit,
+                        None => return Err(error(InvalidEscapeKind::Hex)),
+                    }
+                }
+                if value > 0x7F {
+                    return Err(error(InvalidEscapeKind::Hex));
+                }
+                char::from_u32(value).unwrap()
+            }
+            Some((_, 'u')) => {
+                if chars.next().map(|(_, char)| char) != Some('{') {
+                    return Err(error(InvalidEscapeKind::Unicode));
+                }
+                let mut value: u32 = 0;
+                let mut digits = 0;
+                loop {
+                    match chars.next() {
+                        Some((_, '}')) if digits != 0 => break,
+                        Some((_, char)) if digits < 6 && char.is_ascii_hexdigit() => {
+                            value = value * 16 + char.to_digit(16).unwrap();
+                            digits += 1;
+                        }
+                        _ => return Err(error(InvalidEscapeKind::Unicode)),
+                    }
+                }
+                match char::from_u32(value) {
+                    Some(char) => char,
+                    None => return Err(error(InvalidEscapeKind::Unicode)),
+                }
+            }
+            _ => return Err(error(InvalidEscapeKind::Unknown)),
+        };
+
+        output.push(escaped);
+    }
+
+    Ok(Cow::Owned(output))
+}
+
+/// Error returned by `unescape` for invalid escape sequences
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidEscape {
+    /// Byte offset of the backslash of the escape sequence in the input of `unescape`. Add the
+    /// byte index of the start of the string body to get the location in the lexer input.
+    pub offset: usize,
+
+    pub kind: InvalidEscapeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidEscapeKind {
+    /// Backslash followed by a character that doesn't start an escape sequence, or at the end of
+    /// the input
+    Unknown,
+
+    /// `\x` not followed by two hex digits, or a value larger than `\x7F`
+    Hex,
+
+    /// `\u` not followed by one to six hex digits in braces, or a value that is not a Unicode
+    /// scalar value (a surrogate or larger than `\u{10FFFF}`)
+    Unicode,
+}
+
+impl std::fmt::Display for InvalidEscape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            InvalidEscapeKind::Unknown => "unknown escape sequence",
+            InvalidEscapeKind::Hex => "invalid `\\x` escape sequence",
+            InvalidEscapeKind::Unicode => "invalid `\\u` escape sequence",
+        };
+        write!(f, "{} at byte {}", kind, self.offset)
+    }
+}
+
+impl std::error::Error for InvalidEscape {}